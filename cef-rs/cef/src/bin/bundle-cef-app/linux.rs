@@ -10,6 +10,15 @@ struct Args {
     release: bool,
     #[arg(short, long)]
     output: Option<String>,
+    /// Create a relocatable .tar.gz package with a .desktop file and icons
+    #[arg(long, default_value_t = false)]
+    package: bool,
+    /// Also create an AppImage from the package with appimagetool
+    #[arg(long, default_value_t = false)]
+    appimage: bool,
+    /// Also write a flatpak-builder manifest for the package
+    #[arg(long, default_value_t = false)]
+    flatpak: bool,
 }
 
 pub fn main() -> anyhow::Result<()> {
@@ -19,6 +28,26 @@ pub fn main() -> anyhow::Result<()> {
         None => env::current_dir()?,
     };
 
+    if args.package || args.appimage || args.flatpak {
+        let package = build_package(output.as_path(), &args.name, args.release)?;
+        let archive = package.archive.display();
+        println!("Created package {archive}");
+
+        if args.flatpak {
+            let manifest = write_flatpak_manifest(&package)?;
+            let manifest = manifest.display();
+            println!(
+                "Build the Flatpak with: flatpak-builder --user --install build-dir {manifest}"
+            );
+        }
+        if args.appimage {
+            let app_image = build_appimage(output.as_path(), &package)?;
+            let app_image = app_image.display();
+            println!("Run the AppImage from {app_image}");
+        }
+        return Ok(());
+    }
+
     let bundle_path = build_bundle(output.as_path(), &args.name, args.release)?;
     let bundle_path = bundle_path.display();
     println!("Run the app from {bundle_path}");
@@ -5,7 +5,8 @@
     unused_variables,
     clippy::not_unsafe_ptr_arg_deref,
     clippy::too_many_arguments,
-    clippy::let_unit_value
+    clippy::let_unit_value,
+    clippy::type_complexity
 )]
 use crate::rc::{ConvertParam, ConvertReturnValue, Rc, RcImpl, RefGuard, WrapParamRef};
 use cef_dll_sys::*;
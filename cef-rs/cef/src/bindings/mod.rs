@@ -174,4 +174,26 @@ mod test {
             CefString::from(&extra_info.string(Some(&test_key))).to_string()
         );
     }
+
+    #[test]
+    fn closure_builder() {
+        let closed = std::rc::Rc::new(std::cell::Cell::new(0));
+        let handler = LifeSpanHandler::builder()
+            .on_before_close({
+                let closed = closed.clone();
+                move |browser| {
+                    assert!(browser.is_none());
+                    closed.set(closed.get() + 1);
+                }
+            })
+            .build();
+
+        handler.on_before_close(None);
+        handler.on_before_close(None);
+        assert_eq!(closed.get(), 2);
+        assert_eq!(handler.do_close(None), 0);
+
+        let handler = LifeSpanHandler::builder().do_close(|_| 1).build();
+        assert_eq!(handler.do_close(None), 1);
+    }
 }
@@ -5,7 +5,8 @@
     unused_variables,
     clippy::not_unsafe_ptr_arg_deref,
     clippy::too_many_arguments,
-    clippy::let_unit_value
+    clippy::let_unit_value,
+    clippy::type_complexity
 )]
 use crate::rc::{ConvertParam, ConvertReturnValue, Rc, RcImpl, RefGuard, WrapParamRef};
 use cef_dll_sys::*;
@@ -3743,6 +3744,41 @@ impl From<AccessibilityHandler> for *mut _cef_accessibility_handler_t {
         object
     }
 }
+impl AccessibilityHandler {
+    #[doc = "Create a [`AccessibilityHandlerBuilder`] to implement [`AccessibilityHandler`] with closures."]
+    pub fn builder() -> AccessibilityHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`AccessibilityHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplAccessibilityHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct AccessibilityHandlerBuilder {
+    on_accessibility_tree_change: Option<std::rc::Rc<dyn Fn(Option<&mut Value>)>>,
+    on_accessibility_location_change: Option<std::rc::Rc<dyn Fn(Option<&mut Value>)>>,
+}
+impl AccessibilityHandlerBuilder {
+    #[doc = "Implement [`ImplAccessibilityHandler::on_accessibility_tree_change`] with a closure."]
+    pub fn on_accessibility_tree_change(
+        mut self,
+        closure: impl Fn(Option<&mut Value>) + 'static,
+    ) -> Self {
+        self.on_accessibility_tree_change = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplAccessibilityHandler::on_accessibility_location_change`] with a closure."]
+    pub fn on_accessibility_location_change(
+        mut self,
+        closure: impl Fn(Option<&mut Value>) + 'static,
+    ) -> Self {
+        self.on_accessibility_location_change = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`AccessibilityHandler`] which calls the closures."]
+    pub fn build(self) -> AccessibilityHandler {
+        BuiltAccessibilityHandler::new(self)
+    }
+}
+wrap_accessibility_handler! { struct BuiltAccessibilityHandler { builder : AccessibilityHandlerBuilder , } impl AccessibilityHandler { fn on_accessibility_tree_change (& self , value : Option < & mut Value >) { if let Some (closure) = & self . builder . on_accessibility_tree_change { closure (value) ; } } fn on_accessibility_location_change (& self , value : Option < & mut Value >) { if let Some (closure) = & self . builder . on_accessibility_location_change { closure (value) ; } } } }
 
 /// See [`_cef_dev_tools_message_observer_t`] for more documentation.
 #[derive(Clone)]
@@ -4095,6 +4131,80 @@ impl From<DevToolsMessageObserver> for *mut _cef_dev_tools_message_observer_t {
         object
     }
 }
+impl DevToolsMessageObserver {
+    #[doc = "Create a [`DevToolsMessageObserverBuilder`] to implement [`DevToolsMessageObserver`] with closures."]
+    pub fn builder() -> DevToolsMessageObserverBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`DevToolsMessageObserver`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplDevToolsMessageObserver`] trait."]
+#[derive(Clone, Default)]
+pub struct DevToolsMessageObserverBuilder {
+    on_dev_tools_message:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&[u8]>) -> ::std::os::raw::c_int>>,
+    on_dev_tools_method_result: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+                Option<&[u8]>,
+            ),
+        >,
+    >,
+    on_dev_tools_event:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&CefString>, Option<&[u8]>)>>,
+    on_dev_tools_agent_attached: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>)>>,
+    on_dev_tools_agent_detached: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>)>>,
+}
+impl DevToolsMessageObserverBuilder {
+    #[doc = "Implement [`ImplDevToolsMessageObserver::on_dev_tools_message`] with a closure."]
+    pub fn on_dev_tools_message(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&[u8]>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.on_dev_tools_message = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDevToolsMessageObserver::on_dev_tools_method_result`] with a closure."]
+    pub fn on_dev_tools_method_result(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, ::std::os::raw::c_int, ::std::os::raw::c_int, Option<&[u8]>)
+            + 'static,
+    ) -> Self {
+        self.on_dev_tools_method_result = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDevToolsMessageObserver::on_dev_tools_event`] with a closure."]
+    pub fn on_dev_tools_event(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&CefString>, Option<&[u8]>) + 'static,
+    ) -> Self {
+        self.on_dev_tools_event = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDevToolsMessageObserver::on_dev_tools_agent_attached`] with a closure."]
+    pub fn on_dev_tools_agent_attached(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>) + 'static,
+    ) -> Self {
+        self.on_dev_tools_agent_attached = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDevToolsMessageObserver::on_dev_tools_agent_detached`] with a closure."]
+    pub fn on_dev_tools_agent_detached(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>) + 'static,
+    ) -> Self {
+        self.on_dev_tools_agent_detached = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`DevToolsMessageObserver`] which calls the closures."]
+    pub fn build(self) -> DevToolsMessageObserver {
+        BuiltDevToolsMessageObserver::new(self)
+    }
+}
+wrap_dev_tools_message_observer! { struct BuiltDevToolsMessageObserver { builder : DevToolsMessageObserverBuilder , } impl DevToolsMessageObserver { fn on_dev_tools_message (& self , browser : Option < & mut Browser > , message : Option < & [u8] >) -> :: std :: os :: raw :: c_int { match & self . builder . on_dev_tools_message { Some (closure) => closure (browser , message) , None => Default :: default () , } } fn on_dev_tools_method_result (& self , browser : Option < & mut Browser > , message_id : :: std :: os :: raw :: c_int , success : :: std :: os :: raw :: c_int , result : Option < & [u8] >) { if let Some (closure) = & self . builder . on_dev_tools_method_result { closure (browser , message_id , success , result) ; } } fn on_dev_tools_event (& self , browser : Option < & mut Browser > , method : Option < & CefString > , params : Option < & [u8] >) { if let Some (closure) = & self . builder . on_dev_tools_event { closure (browser , method , params) ; } } fn on_dev_tools_agent_attached (& self , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_dev_tools_agent_attached { closure (browser) ; } } fn on_dev_tools_agent_detached (& self , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_dev_tools_agent_detached { closure (browser) ; } } } }
 
 /// See [`_cef_image_t`] for more documentation.
 #[derive(Clone)]
@@ -4765,6 +4875,56 @@ impl From<ReadHandler> for *mut _cef_read_handler_t {
         object
     }
 }
+impl ReadHandler {
+    #[doc = "Create a [`ReadHandlerBuilder`] to implement [`ReadHandler`] with closures."]
+    pub fn builder() -> ReadHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`ReadHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplReadHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct ReadHandlerBuilder {
+    read: Option<std::rc::Rc<dyn Fn(*mut u8, usize, usize) -> usize>>,
+    seek: Option<std::rc::Rc<dyn Fn(i64, ::std::os::raw::c_int) -> ::std::os::raw::c_int>>,
+    tell: Option<std::rc::Rc<dyn Fn() -> i64>>,
+    eof: Option<std::rc::Rc<dyn Fn() -> ::std::os::raw::c_int>>,
+    may_block: Option<std::rc::Rc<dyn Fn() -> ::std::os::raw::c_int>>,
+}
+impl ReadHandlerBuilder {
+    #[doc = "Implement [`ImplReadHandler::read`] with a closure."]
+    pub fn read(mut self, closure: impl Fn(*mut u8, usize, usize) -> usize + 'static) -> Self {
+        self.read = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplReadHandler::seek`] with a closure."]
+    pub fn seek(
+        mut self,
+        closure: impl Fn(i64, ::std::os::raw::c_int) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.seek = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplReadHandler::tell`] with a closure."]
+    pub fn tell(mut self, closure: impl Fn() -> i64 + 'static) -> Self {
+        self.tell = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplReadHandler::eof`] with a closure."]
+    pub fn eof(mut self, closure: impl Fn() -> ::std::os::raw::c_int + 'static) -> Self {
+        self.eof = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplReadHandler::may_block`] with a closure."]
+    pub fn may_block(mut self, closure: impl Fn() -> ::std::os::raw::c_int + 'static) -> Self {
+        self.may_block = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`ReadHandler`] which calls the closures."]
+    pub fn build(self) -> ReadHandler {
+        BuiltReadHandler::new(self)
+    }
+}
+wrap_read_handler! { struct BuiltReadHandler { builder : ReadHandlerBuilder , } impl ReadHandler { fn read (& self , ptr : * mut u8 , size : usize , n : usize) -> usize { match & self . builder . read { Some (closure) => closure (ptr , size , n) , None => Default :: default () , } } fn seek (& self , offset : i64 , whence : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . seek { Some (closure) => closure (offset , whence) , None => Default :: default () , } } fn tell (& self) -> i64 { match & self . builder . tell { Some (closure) => closure () , None => Default :: default () , } } fn eof (& self) -> :: std :: os :: raw :: c_int { match & self . builder . eof { Some (closure) => closure () , None => Default :: default () , } } fn may_block (& self) -> :: std :: os :: raw :: c_int { match & self . builder . may_block { Some (closure) => closure () , None => Default :: default () , } } } }
 
 /// See [`_cef_stream_reader_t`] for more documentation.
 #[derive(Clone)]
@@ -5086,6 +5246,56 @@ impl From<WriteHandler> for *mut _cef_write_handler_t {
         object
     }
 }
+impl WriteHandler {
+    #[doc = "Create a [`WriteHandlerBuilder`] to implement [`WriteHandler`] with closures."]
+    pub fn builder() -> WriteHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`WriteHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplWriteHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct WriteHandlerBuilder {
+    write: Option<std::rc::Rc<dyn Fn(*const u8, usize, usize) -> usize>>,
+    seek: Option<std::rc::Rc<dyn Fn(i64, ::std::os::raw::c_int) -> ::std::os::raw::c_int>>,
+    tell: Option<std::rc::Rc<dyn Fn() -> i64>>,
+    flush: Option<std::rc::Rc<dyn Fn() -> ::std::os::raw::c_int>>,
+    may_block: Option<std::rc::Rc<dyn Fn() -> ::std::os::raw::c_int>>,
+}
+impl WriteHandlerBuilder {
+    #[doc = "Implement [`ImplWriteHandler::write`] with a closure."]
+    pub fn write(mut self, closure: impl Fn(*const u8, usize, usize) -> usize + 'static) -> Self {
+        self.write = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWriteHandler::seek`] with a closure."]
+    pub fn seek(
+        mut self,
+        closure: impl Fn(i64, ::std::os::raw::c_int) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.seek = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWriteHandler::tell`] with a closure."]
+    pub fn tell(mut self, closure: impl Fn() -> i64 + 'static) -> Self {
+        self.tell = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWriteHandler::flush`] with a closure."]
+    pub fn flush(mut self, closure: impl Fn() -> ::std::os::raw::c_int + 'static) -> Self {
+        self.flush = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWriteHandler::may_block`] with a closure."]
+    pub fn may_block(mut self, closure: impl Fn() -> ::std::os::raw::c_int + 'static) -> Self {
+        self.may_block = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`WriteHandler`] which calls the closures."]
+    pub fn build(self) -> WriteHandler {
+        BuiltWriteHandler::new(self)
+    }
+}
+wrap_write_handler! { struct BuiltWriteHandler { builder : WriteHandlerBuilder , } impl WriteHandler { fn write (& self , ptr : * const u8 , size : usize , n : usize) -> usize { match & self . builder . write { Some (closure) => closure (ptr , size , n) , None => Default :: default () , } } fn seek (& self , offset : i64 , whence : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . seek { Some (closure) => closure (offset , whence) , None => Default :: default () , } } fn tell (& self) -> i64 { match & self . builder . tell { Some (closure) => closure () , None => Default :: default () , } } fn flush (& self) -> :: std :: os :: raw :: c_int { match & self . builder . flush { Some (closure) => closure () , None => Default :: default () , } } fn may_block (& self) -> :: std :: os :: raw :: c_int { match & self . builder . may_block { Some (closure) => closure () , None => Default :: default () , } } } }
 
 /// See [`_cef_stream_writer_t`] for more documentation.
 #[derive(Clone)]
@@ -5745,6 +5955,29 @@ impl From<Domvisitor> for *mut _cef_domvisitor_t {
         object
     }
 }
+impl Domvisitor {
+    #[doc = "Create a [`DomvisitorBuilder`] to implement [`Domvisitor`] with closures."]
+    pub fn builder() -> DomvisitorBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`Domvisitor`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplDomvisitor`] trait."]
+#[derive(Clone, Default)]
+pub struct DomvisitorBuilder {
+    visit: Option<std::rc::Rc<dyn Fn(Option<&mut Domdocument>)>>,
+}
+impl DomvisitorBuilder {
+    #[doc = "Implement [`ImplDomvisitor::visit`] with a closure."]
+    pub fn visit(mut self, closure: impl Fn(Option<&mut Domdocument>) + 'static) -> Self {
+        self.visit = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`Domvisitor`] which calls the closures."]
+    pub fn build(self) -> Domvisitor {
+        BuiltDomvisitor::new(self)
+    }
+}
+wrap_domvisitor! { struct BuiltDomvisitor { builder : DomvisitorBuilder , } impl Domvisitor { fn visit (& self , document : Option < & mut Domdocument >) { if let Some (closure) = & self . builder . visit { closure (document) ; } } } }
 
 /// See [`_cef_domdocument_t`] for more documentation.
 #[derive(Clone)]
@@ -7550,6 +7783,29 @@ impl From<CefStringVisitor> for *mut _cef_string_visitor_t {
         object
     }
 }
+impl CefStringVisitor {
+    #[doc = "Create a [`CefStringVisitorBuilder`] to implement [`CefStringVisitor`] with closures."]
+    pub fn builder() -> CefStringVisitorBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`CefStringVisitor`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplCefStringVisitor`] trait."]
+#[derive(Clone, Default)]
+pub struct CefStringVisitorBuilder {
+    visit: Option<std::rc::Rc<dyn Fn(Option<&CefString>)>>,
+}
+impl CefStringVisitorBuilder {
+    #[doc = "Implement [`ImplCefStringVisitor::visit`] with a closure."]
+    pub fn visit(mut self, closure: impl Fn(Option<&CefString>) + 'static) -> Self {
+        self.visit = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`CefStringVisitor`] which calls the closures."]
+    pub fn build(self) -> CefStringVisitor {
+        BuiltCefStringVisitor::new(self)
+    }
+}
+wrap_string_visitor! { struct BuiltCefStringVisitor { builder : CefStringVisitorBuilder , } impl CefStringVisitor { fn visit (& self , string : Option < & CefString >) { if let Some (closure) = & self . builder . visit { closure (string) ; } } } }
 
 /// See [`_cef_frame_t`] for more documentation.
 #[derive(Clone)]
@@ -8911,6 +9167,29 @@ impl From<CompletionCallback> for *mut _cef_completion_callback_t {
         object
     }
 }
+impl CompletionCallback {
+    #[doc = "Create a [`CompletionCallbackBuilder`] to implement [`CompletionCallback`] with closures."]
+    pub fn builder() -> CompletionCallbackBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`CompletionCallback`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplCompletionCallback`] trait."]
+#[derive(Clone, Default)]
+pub struct CompletionCallbackBuilder {
+    on_complete: Option<std::rc::Rc<dyn Fn()>>,
+}
+impl CompletionCallbackBuilder {
+    #[doc = "Implement [`ImplCompletionCallback::on_complete`] with a closure."]
+    pub fn on_complete(mut self, closure: impl Fn() + 'static) -> Self {
+        self.on_complete = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`CompletionCallback`] which calls the closures."]
+    pub fn build(self) -> CompletionCallback {
+        BuiltCompletionCallback::new(self)
+    }
+}
+wrap_completion_callback! { struct BuiltCompletionCallback { builder : CompletionCallbackBuilder , } impl CompletionCallback { fn on_complete (& self) { if let Some (closure) = & self . builder . on_complete { closure () ; } } } }
 
 /// See [`_cef_cookie_manager_t`] for more documentation.
 #[derive(Clone)]
@@ -9260,6 +9539,47 @@ impl From<CookieVisitor> for *mut _cef_cookie_visitor_t {
         object
     }
 }
+impl CookieVisitor {
+    #[doc = "Create a [`CookieVisitorBuilder`] to implement [`CookieVisitor`] with closures."]
+    pub fn builder() -> CookieVisitorBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`CookieVisitor`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplCookieVisitor`] trait."]
+#[derive(Clone, Default)]
+pub struct CookieVisitorBuilder {
+    visit: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&Cookie>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+}
+impl CookieVisitorBuilder {
+    #[doc = "Implement [`ImplCookieVisitor::visit`] with a closure."]
+    pub fn visit(
+        mut self,
+        closure: impl Fn(
+                Option<&Cookie>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.visit = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`CookieVisitor`] which calls the closures."]
+    pub fn build(self) -> CookieVisitor {
+        BuiltCookieVisitor::new(self)
+    }
+}
+wrap_cookie_visitor! { struct BuiltCookieVisitor { builder : CookieVisitorBuilder , } impl CookieVisitor { fn visit (& self , cookie : Option < & Cookie > , count : :: std :: os :: raw :: c_int , total : :: std :: os :: raw :: c_int , delete_cookie : Option < & mut :: std :: os :: raw :: c_int >) -> :: std :: os :: raw :: c_int { match & self . builder . visit { Some (closure) => closure (cookie , count , total , delete_cookie) , None => Default :: default () , } } } }
 
 /// See [`_cef_set_cookie_callback_t`] for more documentation.
 #[derive(Clone)]
@@ -9354,6 +9674,29 @@ impl From<SetCookieCallback> for *mut _cef_set_cookie_callback_t {
         object
     }
 }
+impl SetCookieCallback {
+    #[doc = "Create a [`SetCookieCallbackBuilder`] to implement [`SetCookieCallback`] with closures."]
+    pub fn builder() -> SetCookieCallbackBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`SetCookieCallback`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplSetCookieCallback`] trait."]
+#[derive(Clone, Default)]
+pub struct SetCookieCallbackBuilder {
+    on_complete: Option<std::rc::Rc<dyn Fn(::std::os::raw::c_int)>>,
+}
+impl SetCookieCallbackBuilder {
+    #[doc = "Implement [`ImplSetCookieCallback::on_complete`] with a closure."]
+    pub fn on_complete(mut self, closure: impl Fn(::std::os::raw::c_int) + 'static) -> Self {
+        self.on_complete = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`SetCookieCallback`] which calls the closures."]
+    pub fn build(self) -> SetCookieCallback {
+        BuiltSetCookieCallback::new(self)
+    }
+}
+wrap_set_cookie_callback! { struct BuiltSetCookieCallback { builder : SetCookieCallbackBuilder , } impl SetCookieCallback { fn on_complete (& self , success : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_complete { closure (success) ; } } } }
 
 /// See [`_cef_delete_cookies_callback_t`] for more documentation.
 #[derive(Clone)]
@@ -9452,6 +9795,29 @@ impl From<DeleteCookiesCallback> for *mut _cef_delete_cookies_callback_t {
         object
     }
 }
+impl DeleteCookiesCallback {
+    #[doc = "Create a [`DeleteCookiesCallbackBuilder`] to implement [`DeleteCookiesCallback`] with closures."]
+    pub fn builder() -> DeleteCookiesCallbackBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`DeleteCookiesCallback`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplDeleteCookiesCallback`] trait."]
+#[derive(Clone, Default)]
+pub struct DeleteCookiesCallbackBuilder {
+    on_complete: Option<std::rc::Rc<dyn Fn(::std::os::raw::c_int)>>,
+}
+impl DeleteCookiesCallbackBuilder {
+    #[doc = "Implement [`ImplDeleteCookiesCallback::on_complete`] with a closure."]
+    pub fn on_complete(mut self, closure: impl Fn(::std::os::raw::c_int) + 'static) -> Self {
+        self.on_complete = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`DeleteCookiesCallback`] which calls the closures."]
+    pub fn build(self) -> DeleteCookiesCallback {
+        BuiltDeleteCookiesCallback::new(self)
+    }
+}
+wrap_delete_cookies_callback! { struct BuiltDeleteCookiesCallback { builder : DeleteCookiesCallbackBuilder , } impl DeleteCookiesCallback { fn on_complete (& self , num_deleted : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_complete { closure (num_deleted) ; } } } }
 
 /// See [`_cef_media_router_t`] for more documentation.
 #[derive(Clone)]
@@ -9875,6 +10241,54 @@ impl From<MediaObserver> for *mut _cef_media_observer_t {
         object
     }
 }
+impl MediaObserver {
+    #[doc = "Create a [`MediaObserverBuilder`] to implement [`MediaObserver`] with closures."]
+    pub fn builder() -> MediaObserverBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`MediaObserver`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplMediaObserver`] trait."]
+#[derive(Clone, Default)]
+pub struct MediaObserverBuilder {
+    on_sinks: Option<std::rc::Rc<dyn Fn(Option<&[Option<MediaSink>]>)>>,
+    on_routes: Option<std::rc::Rc<dyn Fn(Option<&[Option<MediaRoute>]>)>>,
+    on_route_state_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut MediaRoute>, MediaRouteConnectionState)>>,
+    on_route_message_received: Option<std::rc::Rc<dyn Fn(Option<&mut MediaRoute>, Option<&[u8]>)>>,
+}
+impl MediaObserverBuilder {
+    #[doc = "Implement [`ImplMediaObserver::on_sinks`] with a closure."]
+    pub fn on_sinks(mut self, closure: impl Fn(Option<&[Option<MediaSink>]>) + 'static) -> Self {
+        self.on_sinks = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMediaObserver::on_routes`] with a closure."]
+    pub fn on_routes(mut self, closure: impl Fn(Option<&[Option<MediaRoute>]>) + 'static) -> Self {
+        self.on_routes = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMediaObserver::on_route_state_changed`] with a closure."]
+    pub fn on_route_state_changed(
+        mut self,
+        closure: impl Fn(Option<&mut MediaRoute>, MediaRouteConnectionState) + 'static,
+    ) -> Self {
+        self.on_route_state_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMediaObserver::on_route_message_received`] with a closure."]
+    pub fn on_route_message_received(
+        mut self,
+        closure: impl Fn(Option<&mut MediaRoute>, Option<&[u8]>) + 'static,
+    ) -> Self {
+        self.on_route_message_received = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`MediaObserver`] which calls the closures."]
+    pub fn build(self) -> MediaObserver {
+        BuiltMediaObserver::new(self)
+    }
+}
+wrap_media_observer! { struct BuiltMediaObserver { builder : MediaObserverBuilder , } impl MediaObserver { fn on_sinks (& self , sinks : Option < & [Option < MediaSink >] >) { if let Some (closure) = & self . builder . on_sinks { closure (sinks) ; } } fn on_routes (& self , routes : Option < & [Option < MediaRoute >] >) { if let Some (closure) = & self . builder . on_routes { closure (routes) ; } } fn on_route_state_changed (& self , route : Option < & mut MediaRoute > , state : MediaRouteConnectionState) { if let Some (closure) = & self . builder . on_route_state_changed { closure (route , state) ; } } fn on_route_message_received (& self , route : Option < & mut MediaRoute > , message : Option < & [u8] >) { if let Some (closure) = & self . builder . on_route_message_received { closure (route , message) ; } } } }
 
 /// See [`_cef_media_route_t`] for more documentation.
 #[derive(Clone)]
@@ -10139,6 +10553,34 @@ impl From<MediaRouteCreateCallback> for *mut _cef_media_route_create_callback_t
         object
     }
 }
+impl MediaRouteCreateCallback {
+    #[doc = "Create a [`MediaRouteCreateCallbackBuilder`] to implement [`MediaRouteCreateCallback`] with closures."]
+    pub fn builder() -> MediaRouteCreateCallbackBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`MediaRouteCreateCallback`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplMediaRouteCreateCallback`] trait."]
+#[derive(Clone, Default)]
+pub struct MediaRouteCreateCallbackBuilder {
+    on_media_route_create_finished: Option<
+        std::rc::Rc<dyn Fn(MediaRouteCreateResult, Option<&CefString>, Option<&mut MediaRoute>)>,
+    >,
+}
+impl MediaRouteCreateCallbackBuilder {
+    #[doc = "Implement [`ImplMediaRouteCreateCallback::on_media_route_create_finished`] with a closure."]
+    pub fn on_media_route_create_finished(
+        mut self,
+        closure: impl Fn(MediaRouteCreateResult, Option<&CefString>, Option<&mut MediaRoute>) + 'static,
+    ) -> Self {
+        self.on_media_route_create_finished = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`MediaRouteCreateCallback`] which calls the closures."]
+    pub fn build(self) -> MediaRouteCreateCallback {
+        BuiltMediaRouteCreateCallback::new(self)
+    }
+}
+wrap_media_route_create_callback! { struct BuiltMediaRouteCreateCallback { builder : MediaRouteCreateCallbackBuilder , } impl MediaRouteCreateCallback { fn on_media_route_create_finished (& self , result : MediaRouteCreateResult , error : Option < & CefString > , route : Option < & mut MediaRoute >) { if let Some (closure) = & self . builder . on_media_route_create_finished { closure (result , error , route) ; } } } }
 
 /// See [`_cef_media_sink_t`] for more documentation.
 #[derive(Clone)]
@@ -10409,6 +10851,32 @@ impl From<MediaSinkDeviceInfoCallback> for *mut _cef_media_sink_device_info_call
         object
     }
 }
+impl MediaSinkDeviceInfoCallback {
+    #[doc = "Create a [`MediaSinkDeviceInfoCallbackBuilder`] to implement [`MediaSinkDeviceInfoCallback`] with closures."]
+    pub fn builder() -> MediaSinkDeviceInfoCallbackBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`MediaSinkDeviceInfoCallback`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplMediaSinkDeviceInfoCallback`] trait."]
+#[derive(Clone, Default)]
+pub struct MediaSinkDeviceInfoCallbackBuilder {
+    on_media_sink_device_info: Option<std::rc::Rc<dyn Fn(Option<&MediaSinkDeviceInfo>)>>,
+}
+impl MediaSinkDeviceInfoCallbackBuilder {
+    #[doc = "Implement [`ImplMediaSinkDeviceInfoCallback::on_media_sink_device_info`] with a closure."]
+    pub fn on_media_sink_device_info(
+        mut self,
+        closure: impl Fn(Option<&MediaSinkDeviceInfo>) + 'static,
+    ) -> Self {
+        self.on_media_sink_device_info = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`MediaSinkDeviceInfoCallback`] which calls the closures."]
+    pub fn build(self) -> MediaSinkDeviceInfoCallback {
+        BuiltMediaSinkDeviceInfoCallback::new(self)
+    }
+}
+wrap_media_sink_device_info_callback! { struct BuiltMediaSinkDeviceInfoCallback { builder : MediaSinkDeviceInfoCallbackBuilder , } impl MediaSinkDeviceInfoCallback { fn on_media_sink_device_info (& self , device_info : Option < & MediaSinkDeviceInfo >) { if let Some (closure) = & self . builder . on_media_sink_device_info { closure (device_info) ; } } } }
 
 /// See [`_cef_media_source_t`] for more documentation.
 #[derive(Clone)]
@@ -10691,6 +11159,29 @@ impl From<PreferenceObserver> for *mut _cef_preference_observer_t {
         object
     }
 }
+impl PreferenceObserver {
+    #[doc = "Create a [`PreferenceObserverBuilder`] to implement [`PreferenceObserver`] with closures."]
+    pub fn builder() -> PreferenceObserverBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`PreferenceObserver`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplPreferenceObserver`] trait."]
+#[derive(Clone, Default)]
+pub struct PreferenceObserverBuilder {
+    on_preference_changed: Option<std::rc::Rc<dyn Fn(Option<&CefString>)>>,
+}
+impl PreferenceObserverBuilder {
+    #[doc = "Implement [`ImplPreferenceObserver::on_preference_changed`] with a closure."]
+    pub fn on_preference_changed(mut self, closure: impl Fn(Option<&CefString>) + 'static) -> Self {
+        self.on_preference_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`PreferenceObserver`] which calls the closures."]
+    pub fn build(self) -> PreferenceObserver {
+        BuiltPreferenceObserver::new(self)
+    }
+}
+wrap_preference_observer! { struct BuiltPreferenceObserver { builder : PreferenceObserverBuilder , } impl PreferenceObserver { fn on_preference_changed (& self , name : Option < & CefString >) { if let Some (closure) = & self . builder . on_preference_changed { closure (name) ; } } } }
 
 /// See [`_cef_preference_manager_t`] for more documentation.
 #[derive(Clone)]
@@ -10994,6 +11485,32 @@ impl From<ResolveCallback> for *mut _cef_resolve_callback_t {
         object
     }
 }
+impl ResolveCallback {
+    #[doc = "Create a [`ResolveCallbackBuilder`] to implement [`ResolveCallback`] with closures."]
+    pub fn builder() -> ResolveCallbackBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`ResolveCallback`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplResolveCallback`] trait."]
+#[derive(Clone, Default)]
+pub struct ResolveCallbackBuilder {
+    on_resolve_completed: Option<std::rc::Rc<dyn Fn(Errorcode, Option<&mut CefStringList>)>>,
+}
+impl ResolveCallbackBuilder {
+    #[doc = "Implement [`ImplResolveCallback::on_resolve_completed`] with a closure."]
+    pub fn on_resolve_completed(
+        mut self,
+        closure: impl Fn(Errorcode, Option<&mut CefStringList>) + 'static,
+    ) -> Self {
+        self.on_resolve_completed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`ResolveCallback`] which calls the closures."]
+    pub fn build(self) -> ResolveCallback {
+        BuiltResolveCallback::new(self)
+    }
+}
+wrap_resolve_callback! { struct BuiltResolveCallback { builder : ResolveCallbackBuilder , } impl ResolveCallback { fn on_resolve_completed (& self , result : Errorcode , resolved_ips : Option < & mut CefStringList >) { if let Some (closure) = & self . builder . on_resolve_completed { closure (result , resolved_ips) ; } } } }
 
 /// See [`_cef_setting_observer_t`] for more documentation.
 #[derive(Clone)]
@@ -11132,6 +11649,33 @@ impl From<SettingObserver> for *mut _cef_setting_observer_t {
         object
     }
 }
+impl SettingObserver {
+    #[doc = "Create a [`SettingObserverBuilder`] to implement [`SettingObserver`] with closures."]
+    pub fn builder() -> SettingObserverBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`SettingObserver`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplSettingObserver`] trait."]
+#[derive(Clone, Default)]
+pub struct SettingObserverBuilder {
+    on_setting_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&CefString>, Option<&CefString>, ContentSettingTypes)>>,
+}
+impl SettingObserverBuilder {
+    #[doc = "Implement [`ImplSettingObserver::on_setting_changed`] with a closure."]
+    pub fn on_setting_changed(
+        mut self,
+        closure: impl Fn(Option<&CefString>, Option<&CefString>, ContentSettingTypes) + 'static,
+    ) -> Self {
+        self.on_setting_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`SettingObserver`] which calls the closures."]
+    pub fn build(self) -> SettingObserver {
+        BuiltSettingObserver::new(self)
+    }
+}
+wrap_setting_observer! { struct BuiltSettingObserver { builder : SettingObserverBuilder , } impl SettingObserver { fn on_setting_changed (& self , requesting_url : Option < & CefString > , top_level_url : Option < & CefString > , content_type : ContentSettingTypes) { if let Some (closure) = & self . builder . on_setting_changed { closure (requesting_url , top_level_url , content_type) ; } } } }
 
 /// See [`_cef_request_context_t`] for more documentation.
 #[derive(Clone)]
@@ -12175,6 +12719,32 @@ impl From<RunFileDialogCallback> for *mut _cef_run_file_dialog_callback_t {
         object
     }
 }
+impl RunFileDialogCallback {
+    #[doc = "Create a [`RunFileDialogCallbackBuilder`] to implement [`RunFileDialogCallback`] with closures."]
+    pub fn builder() -> RunFileDialogCallbackBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`RunFileDialogCallback`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplRunFileDialogCallback`] trait."]
+#[derive(Clone, Default)]
+pub struct RunFileDialogCallbackBuilder {
+    on_file_dialog_dismissed: Option<std::rc::Rc<dyn Fn(Option<&mut CefStringList>)>>,
+}
+impl RunFileDialogCallbackBuilder {
+    #[doc = "Implement [`ImplRunFileDialogCallback::on_file_dialog_dismissed`] with a closure."]
+    pub fn on_file_dialog_dismissed(
+        mut self,
+        closure: impl Fn(Option<&mut CefStringList>) + 'static,
+    ) -> Self {
+        self.on_file_dialog_dismissed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`RunFileDialogCallback`] which calls the closures."]
+    pub fn build(self) -> RunFileDialogCallback {
+        BuiltRunFileDialogCallback::new(self)
+    }
+}
+wrap_run_file_dialog_callback! { struct BuiltRunFileDialogCallback { builder : RunFileDialogCallbackBuilder , } impl RunFileDialogCallback { fn on_file_dialog_dismissed (& self , file_paths : Option < & mut CefStringList >) { if let Some (closure) = & self . builder . on_file_dialog_dismissed { closure (file_paths) ; } } } }
 
 /// See [`_cef_navigation_entry_visitor_t`] for more documentation.
 #[derive(Clone)]
@@ -12313,6 +12883,47 @@ impl From<NavigationEntryVisitor> for *mut _cef_navigation_entry_visitor_t {
         object
     }
 }
+impl NavigationEntryVisitor {
+    #[doc = "Create a [`NavigationEntryVisitorBuilder`] to implement [`NavigationEntryVisitor`] with closures."]
+    pub fn builder() -> NavigationEntryVisitorBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`NavigationEntryVisitor`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplNavigationEntryVisitor`] trait."]
+#[derive(Clone, Default)]
+pub struct NavigationEntryVisitorBuilder {
+    visit: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut NavigationEntry>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+}
+impl NavigationEntryVisitorBuilder {
+    #[doc = "Implement [`ImplNavigationEntryVisitor::visit`] with a closure."]
+    pub fn visit(
+        mut self,
+        closure: impl Fn(
+                Option<&mut NavigationEntry>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.visit = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`NavigationEntryVisitor`] which calls the closures."]
+    pub fn build(self) -> NavigationEntryVisitor {
+        BuiltNavigationEntryVisitor::new(self)
+    }
+}
+wrap_navigation_entry_visitor! { struct BuiltNavigationEntryVisitor { builder : NavigationEntryVisitorBuilder , } impl NavigationEntryVisitor { fn visit (& self , entry : Option < & mut NavigationEntry > , current : :: std :: os :: raw :: c_int , index : :: std :: os :: raw :: c_int , total : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . visit { Some (closure) => closure (entry , current , index , total) , None => Default :: default () , } } } }
 
 /// See [`_cef_pdf_print_callback_t`] for more documentation.
 #[derive(Clone)]
@@ -12417,6 +13028,32 @@ impl From<PdfPrintCallback> for *mut _cef_pdf_print_callback_t {
         object
     }
 }
+impl PdfPrintCallback {
+    #[doc = "Create a [`PdfPrintCallbackBuilder`] to implement [`PdfPrintCallback`] with closures."]
+    pub fn builder() -> PdfPrintCallbackBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`PdfPrintCallback`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplPdfPrintCallback`] trait."]
+#[derive(Clone, Default)]
+pub struct PdfPrintCallbackBuilder {
+    on_pdf_print_finished: Option<std::rc::Rc<dyn Fn(Option<&CefString>, ::std::os::raw::c_int)>>,
+}
+impl PdfPrintCallbackBuilder {
+    #[doc = "Implement [`ImplPdfPrintCallback::on_pdf_print_finished`] with a closure."]
+    pub fn on_pdf_print_finished(
+        mut self,
+        closure: impl Fn(Option<&CefString>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_pdf_print_finished = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`PdfPrintCallback`] which calls the closures."]
+    pub fn build(self) -> PdfPrintCallback {
+        BuiltPdfPrintCallback::new(self)
+    }
+}
+wrap_pdf_print_callback! { struct BuiltPdfPrintCallback { builder : PdfPrintCallbackBuilder , } impl PdfPrintCallback { fn on_pdf_print_finished (& self , path : Option < & CefString > , ok : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_pdf_print_finished { closure (path , ok) ; } } } }
 
 /// See [`_cef_download_image_callback_t`] for more documentation.
 #[derive(Clone)]
@@ -12553,6 +13190,33 @@ impl From<DownloadImageCallback> for *mut _cef_download_image_callback_t {
         object
     }
 }
+impl DownloadImageCallback {
+    #[doc = "Create a [`DownloadImageCallbackBuilder`] to implement [`DownloadImageCallback`] with closures."]
+    pub fn builder() -> DownloadImageCallbackBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`DownloadImageCallback`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplDownloadImageCallback`] trait."]
+#[derive(Clone, Default)]
+pub struct DownloadImageCallbackBuilder {
+    on_download_image_finished:
+        Option<std::rc::Rc<dyn Fn(Option<&CefString>, ::std::os::raw::c_int, Option<&mut Image>)>>,
+}
+impl DownloadImageCallbackBuilder {
+    #[doc = "Implement [`ImplDownloadImageCallback::on_download_image_finished`] with a closure."]
+    pub fn on_download_image_finished(
+        mut self,
+        closure: impl Fn(Option<&CefString>, ::std::os::raw::c_int, Option<&mut Image>) + 'static,
+    ) -> Self {
+        self.on_download_image_finished = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`DownloadImageCallback`] which calls the closures."]
+    pub fn build(self) -> DownloadImageCallback {
+        BuiltDownloadImageCallback::new(self)
+    }
+}
+wrap_download_image_callback! { struct BuiltDownloadImageCallback { builder : DownloadImageCallbackBuilder , } impl DownloadImageCallback { fn on_download_image_finished (& self , image_url : Option < & CefString > , http_status_code : :: std :: os :: raw :: c_int , image : Option < & mut Image >) { if let Some (closure) = & self . builder . on_download_image_finished { closure (image_url , http_status_code , image) ; } } } }
 
 /// See [`_cef_browser_host_t`] for more documentation.
 #[derive(Clone)]
@@ -14188,6 +14852,78 @@ impl From<AudioHandler> for *mut _cef_audio_handler_t {
         object
     }
 }
+impl AudioHandler {
+    #[doc = "Create a [`AudioHandlerBuilder`] to implement [`AudioHandler`] with closures."]
+    pub fn builder() -> AudioHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`AudioHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplAudioHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct AudioHandlerBuilder {
+    audio_parameters: Option<
+        std::rc::Rc<
+            dyn Fn(Option<&mut Browser>, Option<&mut AudioParameters>) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_audio_stream_started: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&AudioParameters>, ::std::os::raw::c_int)>,
+    >,
+    on_audio_stream_packet: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Browser>, *mut *const f32, ::std::os::raw::c_int, i64)>,
+    >,
+    on_audio_stream_stopped: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>)>>,
+    on_audio_stream_error: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&CefString>)>>,
+}
+impl AudioHandlerBuilder {
+    #[doc = "Implement [`ImplAudioHandler::audio_parameters`] with a closure."]
+    pub fn audio_parameters(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut AudioParameters>) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.audio_parameters = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplAudioHandler::on_audio_stream_started`] with a closure."]
+    pub fn on_audio_stream_started(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&AudioParameters>, ::std::os::raw::c_int)
+            + 'static,
+    ) -> Self {
+        self.on_audio_stream_started = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplAudioHandler::on_audio_stream_packet`] with a closure."]
+    pub fn on_audio_stream_packet(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, *mut *const f32, ::std::os::raw::c_int, i64) + 'static,
+    ) -> Self {
+        self.on_audio_stream_packet = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplAudioHandler::on_audio_stream_stopped`] with a closure."]
+    pub fn on_audio_stream_stopped(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>) + 'static,
+    ) -> Self {
+        self.on_audio_stream_stopped = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplAudioHandler::on_audio_stream_error`] with a closure."]
+    pub fn on_audio_stream_error(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&CefString>) + 'static,
+    ) -> Self {
+        self.on_audio_stream_error = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`AudioHandler`] which calls the closures."]
+    pub fn build(self) -> AudioHandler {
+        BuiltAudioHandler::new(self)
+    }
+}
+wrap_audio_handler! { struct BuiltAudioHandler { builder : AudioHandlerBuilder , } impl AudioHandler { fn audio_parameters (& self , browser : Option < & mut Browser > , params : Option < & mut AudioParameters >) -> :: std :: os :: raw :: c_int { match & self . builder . audio_parameters { Some (closure) => closure (browser , params) , None => Default :: default () , } } fn on_audio_stream_started (& self , browser : Option < & mut Browser > , params : Option < & AudioParameters > , channels : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_audio_stream_started { closure (browser , params , channels) ; } } fn on_audio_stream_packet (& self , browser : Option < & mut Browser > , data : * mut * const f32 , frames : :: std :: os :: raw :: c_int , pts : i64) { if let Some (closure) = & self . builder . on_audio_stream_packet { closure (browser , data , frames , pts) ; } } fn on_audio_stream_stopped (& self , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_audio_stream_stopped { closure (browser) ; } } fn on_audio_stream_error (& self , browser : Option < & mut Browser > , message : Option < & CefString >) { if let Some (closure) = & self . builder . on_audio_stream_error { closure (browser , message) ; } } } }
 
 /// See [`_cef_command_handler_t`] for more documentation.
 #[derive(Clone)]
@@ -14485,6 +15221,87 @@ impl From<CommandHandler> for *mut _cef_command_handler_t {
         object
     }
 }
+impl CommandHandler {
+    #[doc = "Create a [`CommandHandlerBuilder`] to implement [`CommandHandler`] with closures."]
+    pub fn builder() -> CommandHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`CommandHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplCommandHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct CommandHandlerBuilder {
+    on_chrome_command: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                ::std::os::raw::c_int,
+                WindowOpenDisposition,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    is_chrome_app_menu_item_visible: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Browser>, ::std::os::raw::c_int) -> ::std::os::raw::c_int>,
+    >,
+    is_chrome_app_menu_item_enabled: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Browser>, ::std::os::raw::c_int) -> ::std::os::raw::c_int>,
+    >,
+    is_chrome_page_action_icon_visible:
+        Option<std::rc::Rc<dyn Fn(ChromePageActionIconType) -> ::std::os::raw::c_int>>,
+    is_chrome_toolbar_button_visible:
+        Option<std::rc::Rc<dyn Fn(ChromeToolbarButtonType) -> ::std::os::raw::c_int>>,
+}
+impl CommandHandlerBuilder {
+    #[doc = "Implement [`ImplCommandHandler::on_chrome_command`] with a closure."]
+    pub fn on_chrome_command(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                ::std::os::raw::c_int,
+                WindowOpenDisposition,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_chrome_command = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplCommandHandler::is_chrome_app_menu_item_visible`] with a closure."]
+    pub fn is_chrome_app_menu_item_visible(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, ::std::os::raw::c_int) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.is_chrome_app_menu_item_visible = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplCommandHandler::is_chrome_app_menu_item_enabled`] with a closure."]
+    pub fn is_chrome_app_menu_item_enabled(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, ::std::os::raw::c_int) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.is_chrome_app_menu_item_enabled = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplCommandHandler::is_chrome_page_action_icon_visible`] with a closure."]
+    pub fn is_chrome_page_action_icon_visible(
+        mut self,
+        closure: impl Fn(ChromePageActionIconType) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.is_chrome_page_action_icon_visible = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplCommandHandler::is_chrome_toolbar_button_visible`] with a closure."]
+    pub fn is_chrome_toolbar_button_visible(
+        mut self,
+        closure: impl Fn(ChromeToolbarButtonType) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.is_chrome_toolbar_button_visible = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`CommandHandler`] which calls the closures."]
+    pub fn build(self) -> CommandHandler {
+        BuiltCommandHandler::new(self)
+    }
+}
+wrap_command_handler! { struct BuiltCommandHandler { builder : CommandHandlerBuilder , } impl CommandHandler { fn on_chrome_command (& self , browser : Option < & mut Browser > , command_id : :: std :: os :: raw :: c_int , disposition : WindowOpenDisposition) -> :: std :: os :: raw :: c_int { match & self . builder . on_chrome_command { Some (closure) => closure (browser , command_id , disposition) , None => Default :: default () , } } fn is_chrome_app_menu_item_visible (& self , browser : Option < & mut Browser > , command_id : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . is_chrome_app_menu_item_visible { Some (closure) => closure (browser , command_id) , None => Default :: default () , } } fn is_chrome_app_menu_item_enabled (& self , browser : Option < & mut Browser > , command_id : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . is_chrome_app_menu_item_enabled { Some (closure) => closure (browser , command_id) , None => Default :: default () , } } fn is_chrome_page_action_icon_visible (& self , icon_type : ChromePageActionIconType) -> :: std :: os :: raw :: c_int { match & self . builder . is_chrome_page_action_icon_visible { Some (closure) => closure (icon_type) , None => Default :: default () , } } fn is_chrome_toolbar_button_visible (& self , button_type : ChromeToolbarButtonType) -> :: std :: os :: raw :: c_int { match & self . builder . is_chrome_toolbar_button_visible { Some (closure) => closure (button_type) , None => Default :: default () , } } } }
 
 /// See [`_cef_menu_model_delegate_t`] for more documentation.
 #[derive(Clone)]
@@ -14855,6 +15672,88 @@ impl From<MenuModelDelegate> for *mut _cef_menu_model_delegate_t {
         object
     }
 }
+impl MenuModelDelegate {
+    #[doc = "Create a [`MenuModelDelegateBuilder`] to implement [`MenuModelDelegate`] with closures."]
+    pub fn builder() -> MenuModelDelegateBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`MenuModelDelegate`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplMenuModelDelegate`] trait."]
+#[derive(Clone, Default)]
+pub struct MenuModelDelegateBuilder {
+    execute_command:
+        Option<std::rc::Rc<dyn Fn(Option<&mut MenuModel>, ::std::os::raw::c_int, EventFlags)>>,
+    mouse_outside_menu: Option<std::rc::Rc<dyn Fn(Option<&mut MenuModel>, Option<&Point>)>>,
+    unhandled_open_submenu:
+        Option<std::rc::Rc<dyn Fn(Option<&mut MenuModel>, ::std::os::raw::c_int)>>,
+    unhandled_close_submenu:
+        Option<std::rc::Rc<dyn Fn(Option<&mut MenuModel>, ::std::os::raw::c_int)>>,
+    menu_will_show: Option<std::rc::Rc<dyn Fn(Option<&mut MenuModel>)>>,
+    menu_closed: Option<std::rc::Rc<dyn Fn(Option<&mut MenuModel>)>>,
+    format_label: Option<
+        std::rc::Rc<
+            dyn Fn(Option<&mut MenuModel>, Option<&mut CefString>) -> ::std::os::raw::c_int,
+        >,
+    >,
+}
+impl MenuModelDelegateBuilder {
+    #[doc = "Implement [`ImplMenuModelDelegate::execute_command`] with a closure."]
+    pub fn execute_command(
+        mut self,
+        closure: impl Fn(Option<&mut MenuModel>, ::std::os::raw::c_int, EventFlags) + 'static,
+    ) -> Self {
+        self.execute_command = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuModelDelegate::mouse_outside_menu`] with a closure."]
+    pub fn mouse_outside_menu(
+        mut self,
+        closure: impl Fn(Option<&mut MenuModel>, Option<&Point>) + 'static,
+    ) -> Self {
+        self.mouse_outside_menu = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuModelDelegate::unhandled_open_submenu`] with a closure."]
+    pub fn unhandled_open_submenu(
+        mut self,
+        closure: impl Fn(Option<&mut MenuModel>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.unhandled_open_submenu = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuModelDelegate::unhandled_close_submenu`] with a closure."]
+    pub fn unhandled_close_submenu(
+        mut self,
+        closure: impl Fn(Option<&mut MenuModel>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.unhandled_close_submenu = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuModelDelegate::menu_will_show`] with a closure."]
+    pub fn menu_will_show(mut self, closure: impl Fn(Option<&mut MenuModel>) + 'static) -> Self {
+        self.menu_will_show = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuModelDelegate::menu_closed`] with a closure."]
+    pub fn menu_closed(mut self, closure: impl Fn(Option<&mut MenuModel>) + 'static) -> Self {
+        self.menu_closed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuModelDelegate::format_label`] with a closure."]
+    pub fn format_label(
+        mut self,
+        closure: impl Fn(Option<&mut MenuModel>, Option<&mut CefString>) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.format_label = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`MenuModelDelegate`] which calls the closures."]
+    pub fn build(self) -> MenuModelDelegate {
+        BuiltMenuModelDelegate::new(self)
+    }
+}
+wrap_menu_model_delegate! { struct BuiltMenuModelDelegate { builder : MenuModelDelegateBuilder , } impl MenuModelDelegate { fn execute_command (& self , menu_model : Option < & mut MenuModel > , command_id : :: std :: os :: raw :: c_int , event_flags : EventFlags) { if let Some (closure) = & self . builder . execute_command { closure (menu_model , command_id , event_flags) ; } } fn mouse_outside_menu (& self , menu_model : Option < & mut MenuModel > , screen_point : Option < & Point >) { if let Some (closure) = & self . builder . mouse_outside_menu { closure (menu_model , screen_point) ; } } fn unhandled_open_submenu (& self , menu_model : Option < & mut MenuModel > , is_rtl : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . unhandled_open_submenu { closure (menu_model , is_rtl) ; } } fn unhandled_close_submenu (& self , menu_model : Option < & mut MenuModel > , is_rtl : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . unhandled_close_submenu { closure (menu_model , is_rtl) ; } } fn menu_will_show (& self , menu_model : Option < & mut MenuModel >) { if let Some (closure) = & self . builder . menu_will_show { closure (menu_model) ; } } fn menu_closed (& self , menu_model : Option < & mut MenuModel >) { if let Some (closure) = & self . builder . menu_closed { closure (menu_model) ; } } fn format_label (& self , menu_model : Option < & mut MenuModel > , label : Option < & mut CefString >) -> :: std :: os :: raw :: c_int { match & self . builder . format_label { Some (closure) => closure (menu_model , label) , None => Default :: default () , } } } }
 
 /// See [`_cef_menu_model_t`] for more documentation.
 #[derive(Clone)]
@@ -16934,6 +17833,169 @@ impl From<ContextMenuHandler> for *mut _cef_context_menu_handler_t {
         object
     }
 }
+impl ContextMenuHandler {
+    #[doc = "Create a [`ContextMenuHandlerBuilder`] to implement [`ContextMenuHandler`] with closures."]
+    pub fn builder() -> ContextMenuHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`ContextMenuHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplContextMenuHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct ContextMenuHandlerBuilder {
+    on_before_context_menu: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut ContextMenuParams>,
+                Option<&mut MenuModel>,
+            ),
+        >,
+    >,
+    run_context_menu: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut ContextMenuParams>,
+                Option<&mut MenuModel>,
+                Option<&mut RunContextMenuCallback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_context_menu_command: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut ContextMenuParams>,
+                ::std::os::raw::c_int,
+                EventFlags,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_context_menu_dismissed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Frame>)>>,
+    run_quick_menu: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&Point>,
+                Option<&Size>,
+                QuickMenuEditStateFlags,
+                Option<&mut RunQuickMenuCallback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_quick_menu_command: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                ::std::os::raw::c_int,
+                EventFlags,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_quick_menu_dismissed: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Frame>)>>,
+}
+impl ContextMenuHandlerBuilder {
+    #[doc = "Implement [`ImplContextMenuHandler::on_before_context_menu`] with a closure."]
+    pub fn on_before_context_menu(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut ContextMenuParams>,
+                Option<&mut MenuModel>,
+            ) + 'static,
+    ) -> Self {
+        self.on_before_context_menu = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplContextMenuHandler::run_context_menu`] with a closure."]
+    pub fn run_context_menu(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut ContextMenuParams>,
+                Option<&mut MenuModel>,
+                Option<&mut RunContextMenuCallback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.run_context_menu = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplContextMenuHandler::on_context_menu_command`] with a closure."]
+    pub fn on_context_menu_command(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut ContextMenuParams>,
+                ::std::os::raw::c_int,
+                EventFlags,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_context_menu_command = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplContextMenuHandler::on_context_menu_dismissed`] with a closure."]
+    pub fn on_context_menu_dismissed(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Frame>) + 'static,
+    ) -> Self {
+        self.on_context_menu_dismissed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplContextMenuHandler::run_quick_menu`] with a closure."]
+    pub fn run_quick_menu(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&Point>,
+                Option<&Size>,
+                QuickMenuEditStateFlags,
+                Option<&mut RunQuickMenuCallback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.run_quick_menu = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplContextMenuHandler::on_quick_menu_command`] with a closure."]
+    pub fn on_quick_menu_command(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                ::std::os::raw::c_int,
+                EventFlags,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_quick_menu_command = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplContextMenuHandler::on_quick_menu_dismissed`] with a closure."]
+    pub fn on_quick_menu_dismissed(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Frame>) + 'static,
+    ) -> Self {
+        self.on_quick_menu_dismissed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`ContextMenuHandler`] which calls the closures."]
+    pub fn build(self) -> ContextMenuHandler {
+        BuiltContextMenuHandler::new(self)
+    }
+}
+wrap_context_menu_handler! { struct BuiltContextMenuHandler { builder : ContextMenuHandlerBuilder , } impl ContextMenuHandler { fn on_before_context_menu (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , params : Option < & mut ContextMenuParams > , model : Option < & mut MenuModel >) { if let Some (closure) = & self . builder . on_before_context_menu { closure (browser , frame , params , model) ; } } fn run_context_menu (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , params : Option < & mut ContextMenuParams > , model : Option < & mut MenuModel > , callback : Option < & mut RunContextMenuCallback >) -> :: std :: os :: raw :: c_int { match & self . builder . run_context_menu { Some (closure) => closure (browser , frame , params , model , callback) , None => Default :: default () , } } fn on_context_menu_command (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , params : Option < & mut ContextMenuParams > , command_id : :: std :: os :: raw :: c_int , event_flags : EventFlags) -> :: std :: os :: raw :: c_int { match & self . builder . on_context_menu_command { Some (closure) => closure (browser , frame , params , command_id , event_flags) , None => Default :: default () , } } fn on_context_menu_dismissed (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame >) { if let Some (closure) = & self . builder . on_context_menu_dismissed { closure (browser , frame) ; } } fn run_quick_menu (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , location : Option < & Point > , size : Option < & Size > , edit_state_flags : QuickMenuEditStateFlags , callback : Option < & mut RunQuickMenuCallback >) -> :: std :: os :: raw :: c_int { match & self . builder . run_quick_menu { Some (closure) => closure (browser , frame , location , size , edit_state_flags , callback) , None => Default :: default () , } } fn on_quick_menu_command (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , command_id : :: std :: os :: raw :: c_int , event_flags : EventFlags) -> :: std :: os :: raw :: c_int { match & self . builder . on_quick_menu_command { Some (closure) => closure (browser , frame , command_id , event_flags) , None => Default :: default () , } } fn on_quick_menu_dismissed (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame >) { if let Some (closure) = & self . builder . on_quick_menu_dismissed { closure (browser , frame) ; } } } }
 
 /// See [`_cef_context_menu_params_t`] for more documentation.
 #[derive(Clone)]
@@ -17585,6 +18647,55 @@ impl From<DialogHandler> for *mut _cef_dialog_handler_t {
         object
     }
 }
+impl DialogHandler {
+    #[doc = "Create a [`DialogHandlerBuilder`] to implement [`DialogHandler`] with closures."]
+    pub fn builder() -> DialogHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`DialogHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplDialogHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct DialogHandlerBuilder {
+    on_file_dialog: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                FileDialogMode,
+                Option<&CefString>,
+                Option<&CefString>,
+                Option<&mut CefStringList>,
+                Option<&mut CefStringList>,
+                Option<&mut CefStringList>,
+                Option<&mut FileDialogCallback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+}
+impl DialogHandlerBuilder {
+    #[doc = "Implement [`ImplDialogHandler::on_file_dialog`] with a closure."]
+    pub fn on_file_dialog(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                FileDialogMode,
+                Option<&CefString>,
+                Option<&CefString>,
+                Option<&mut CefStringList>,
+                Option<&mut CefStringList>,
+                Option<&mut CefStringList>,
+                Option<&mut FileDialogCallback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_file_dialog = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`DialogHandler`] which calls the closures."]
+    pub fn build(self) -> DialogHandler {
+        BuiltDialogHandler::new(self)
+    }
+}
+wrap_dialog_handler! { struct BuiltDialogHandler { builder : DialogHandlerBuilder , } impl DialogHandler { fn on_file_dialog (& self , browser : Option < & mut Browser > , mode : FileDialogMode , title : Option < & CefString > , default_file_path : Option < & CefString > , accept_filters : Option < & mut CefStringList > , accept_extensions : Option < & mut CefStringList > , accept_descriptions : Option < & mut CefStringList > , callback : Option < & mut FileDialogCallback >) -> :: std :: os :: raw :: c_int { match & self . builder . on_file_dialog { Some (closure) => closure (browser , mode , title , default_file_path , accept_filters , accept_extensions , accept_descriptions , callback) , None => Default :: default () , } } } }
 
 /// See [`_cef_display_handler_t`] for more documentation.
 #[derive(Clone)]
@@ -18370,6 +19481,184 @@ impl From<DisplayHandler> for *mut _cef_display_handler_t {
         object
     }
 }
+impl DisplayHandler {
+    #[doc = "Create a [`DisplayHandlerBuilder`] to implement [`DisplayHandler`] with closures."]
+    pub fn builder() -> DisplayHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`DisplayHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplDisplayHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct DisplayHandlerBuilder {
+    on_address_change:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Frame>, Option<&CefString>)>>,
+    on_title_change: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&CefString>)>>,
+    on_favicon_urlchange:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut CefStringList>)>>,
+    on_fullscreen_mode_change:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, ::std::os::raw::c_int)>>,
+    on_tooltip: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut CefString>) -> ::std::os::raw::c_int>,
+    >,
+    on_status_message: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&CefString>)>>,
+    on_console_message: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                LogSeverity,
+                Option<&CefString>,
+                Option<&CefString>,
+                ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_auto_resize:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&Size>) -> ::std::os::raw::c_int>>,
+    on_loading_progress_change: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, f64)>>,
+    on_cursor_change: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                ::std::os::raw::c_ulong,
+                CursorType,
+                Option<&CursorInfo>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_media_access_change: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Browser>, ::std::os::raw::c_int, ::std::os::raw::c_int)>,
+    >,
+    on_contents_bounds_change:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&Rect>) -> ::std::os::raw::c_int>>,
+    root_window_screen_rect: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Rect>) -> ::std::os::raw::c_int>,
+    >,
+}
+impl DisplayHandlerBuilder {
+    #[doc = "Implement [`ImplDisplayHandler::on_address_change`] with a closure."]
+    pub fn on_address_change(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Frame>, Option<&CefString>) + 'static,
+    ) -> Self {
+        self.on_address_change = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDisplayHandler::on_title_change`] with a closure."]
+    pub fn on_title_change(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&CefString>) + 'static,
+    ) -> Self {
+        self.on_title_change = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDisplayHandler::on_favicon_urlchange`] with a closure."]
+    pub fn on_favicon_urlchange(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut CefStringList>) + 'static,
+    ) -> Self {
+        self.on_favicon_urlchange = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDisplayHandler::on_fullscreen_mode_change`] with a closure."]
+    pub fn on_fullscreen_mode_change(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_fullscreen_mode_change = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDisplayHandler::on_tooltip`] with a closure."]
+    pub fn on_tooltip(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut CefString>) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_tooltip = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDisplayHandler::on_status_message`] with a closure."]
+    pub fn on_status_message(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&CefString>) + 'static,
+    ) -> Self {
+        self.on_status_message = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDisplayHandler::on_console_message`] with a closure."]
+    pub fn on_console_message(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                LogSeverity,
+                Option<&CefString>,
+                Option<&CefString>,
+                ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_console_message = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDisplayHandler::on_auto_resize`] with a closure."]
+    pub fn on_auto_resize(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&Size>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.on_auto_resize = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDisplayHandler::on_loading_progress_change`] with a closure."]
+    pub fn on_loading_progress_change(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, f64) + 'static,
+    ) -> Self {
+        self.on_loading_progress_change = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDisplayHandler::on_cursor_change`] with a closure."]
+    pub fn on_cursor_change(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                ::std::os::raw::c_ulong,
+                CursorType,
+                Option<&CursorInfo>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_cursor_change = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDisplayHandler::on_media_access_change`] with a closure."]
+    pub fn on_media_access_change(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, ::std::os::raw::c_int, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_media_access_change = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDisplayHandler::on_contents_bounds_change`] with a closure."]
+    pub fn on_contents_bounds_change(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&Rect>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.on_contents_bounds_change = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDisplayHandler::root_window_screen_rect`] with a closure."]
+    pub fn root_window_screen_rect(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Rect>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.root_window_screen_rect = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`DisplayHandler`] which calls the closures."]
+    pub fn build(self) -> DisplayHandler {
+        BuiltDisplayHandler::new(self)
+    }
+}
+wrap_display_handler! { struct BuiltDisplayHandler { builder : DisplayHandlerBuilder , } impl DisplayHandler { fn on_address_change (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , url : Option < & CefString >) { if let Some (closure) = & self . builder . on_address_change { closure (browser , frame , url) ; } } fn on_title_change (& self , browser : Option < & mut Browser > , title : Option < & CefString >) { if let Some (closure) = & self . builder . on_title_change { closure (browser , title) ; } } fn on_favicon_urlchange (& self , browser : Option < & mut Browser > , icon_urls : Option < & mut CefStringList >) { if let Some (closure) = & self . builder . on_favicon_urlchange { closure (browser , icon_urls) ; } } fn on_fullscreen_mode_change (& self , browser : Option < & mut Browser > , fullscreen : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_fullscreen_mode_change { closure (browser , fullscreen) ; } } fn on_tooltip (& self , browser : Option < & mut Browser > , text : Option < & mut CefString >) -> :: std :: os :: raw :: c_int { match & self . builder . on_tooltip { Some (closure) => closure (browser , text) , None => Default :: default () , } } fn on_status_message (& self , browser : Option < & mut Browser > , value : Option < & CefString >) { if let Some (closure) = & self . builder . on_status_message { closure (browser , value) ; } } fn on_console_message (& self , browser : Option < & mut Browser > , level : LogSeverity , message : Option < & CefString > , source : Option < & CefString > , line : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . on_console_message { Some (closure) => closure (browser , level , message , source , line) , None => Default :: default () , } } fn on_auto_resize (& self , browser : Option < & mut Browser > , new_size : Option < & Size >) -> :: std :: os :: raw :: c_int { match & self . builder . on_auto_resize { Some (closure) => closure (browser , new_size) , None => Default :: default () , } } fn on_loading_progress_change (& self , browser : Option < & mut Browser > , progress : f64) { if let Some (closure) = & self . builder . on_loading_progress_change { closure (browser , progress) ; } } fn on_cursor_change (& self , browser : Option < & mut Browser > , cursor : :: std :: os :: raw :: c_ulong , type_ : CursorType , custom_cursor_info : Option < & CursorInfo >) -> :: std :: os :: raw :: c_int { match & self . builder . on_cursor_change { Some (closure) => closure (browser , cursor , type_ , custom_cursor_info) , None => Default :: default () , } } fn on_media_access_change (& self , browser : Option < & mut Browser > , has_video_access : :: std :: os :: raw :: c_int , has_audio_access : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_media_access_change { closure (browser , has_video_access , has_audio_access) ; } } fn on_contents_bounds_change (& self , browser : Option < & mut Browser > , new_bounds : Option < & Rect >) -> :: std :: os :: raw :: c_int { match & self . builder . on_contents_bounds_change { Some (closure) => closure (browser , new_bounds) , None => Default :: default () , } } fn root_window_screen_rect (& self , browser : Option < & mut Browser > , rect : Option < & mut Rect >) -> :: std :: os :: raw :: c_int { match & self . builder . root_window_screen_rect { Some (closure) => closure (browser , rect) , None => Default :: default () , } } } }
 
 /// See [`_cef_download_item_t`] for more documentation.
 #[derive(Clone)]
@@ -19114,6 +20403,87 @@ impl From<DownloadHandler> for *mut _cef_download_handler_t {
         object
     }
 }
+impl DownloadHandler {
+    #[doc = "Create a [`DownloadHandlerBuilder`] to implement [`DownloadHandler`] with closures."]
+    pub fn builder() -> DownloadHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`DownloadHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplDownloadHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct DownloadHandlerBuilder {
+    can_download: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&CefString>,
+                Option<&CefString>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_before_download: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut DownloadItem>,
+                Option<&CefString>,
+                Option<&mut BeforeDownloadCallback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_download_updated: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut DownloadItem>,
+                Option<&mut DownloadItemCallback>,
+            ),
+        >,
+    >,
+}
+impl DownloadHandlerBuilder {
+    #[doc = "Implement [`ImplDownloadHandler::can_download`] with a closure."]
+    pub fn can_download(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&CefString>,
+                Option<&CefString>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.can_download = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDownloadHandler::on_before_download`] with a closure."]
+    pub fn on_before_download(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut DownloadItem>,
+                Option<&CefString>,
+                Option<&mut BeforeDownloadCallback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_before_download = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDownloadHandler::on_download_updated`] with a closure."]
+    pub fn on_download_updated(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut DownloadItem>, Option<&mut DownloadItemCallback>)
+            + 'static,
+    ) -> Self {
+        self.on_download_updated = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`DownloadHandler`] which calls the closures."]
+    pub fn build(self) -> DownloadHandler {
+        BuiltDownloadHandler::new(self)
+    }
+}
+wrap_download_handler! { struct BuiltDownloadHandler { builder : DownloadHandlerBuilder , } impl DownloadHandler { fn can_download (& self , browser : Option < & mut Browser > , url : Option < & CefString > , request_method : Option < & CefString >) -> :: std :: os :: raw :: c_int { match & self . builder . can_download { Some (closure) => closure (browser , url , request_method) , None => Default :: default () , } } fn on_before_download (& self , browser : Option < & mut Browser > , download_item : Option < & mut DownloadItem > , suggested_name : Option < & CefString > , callback : Option < & mut BeforeDownloadCallback >) -> :: std :: os :: raw :: c_int { match & self . builder . on_before_download { Some (closure) => closure (browser , download_item , suggested_name , callback) , None => Default :: default () , } } fn on_download_updated (& self , browser : Option < & mut Browser > , download_item : Option < & mut DownloadItem > , callback : Option < & mut DownloadItemCallback >) { if let Some (closure) = & self . builder . on_download_updated { closure (browser , download_item , callback) ; } } } }
 
 /// See [`_cef_drag_handler_t`] for more documentation.
 #[derive(Clone)]
@@ -19330,6 +20700,56 @@ impl From<DragHandler> for *mut _cef_drag_handler_t {
         object
     }
 }
+impl DragHandler {
+    #[doc = "Create a [`DragHandlerBuilder`] to implement [`DragHandler`] with closures."]
+    pub fn builder() -> DragHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`DragHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplDragHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct DragHandlerBuilder {
+    on_drag_enter: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut DragData>,
+                DragOperationsMask,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_draggable_regions_changed: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Frame>, Option<&[DraggableRegion]>)>,
+    >,
+}
+impl DragHandlerBuilder {
+    #[doc = "Implement [`ImplDragHandler::on_drag_enter`] with a closure."]
+    pub fn on_drag_enter(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut DragData>,
+                DragOperationsMask,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_drag_enter = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplDragHandler::on_draggable_regions_changed`] with a closure."]
+    pub fn on_draggable_regions_changed(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Frame>, Option<&[DraggableRegion]>) + 'static,
+    ) -> Self {
+        self.on_draggable_regions_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`DragHandler`] which calls the closures."]
+    pub fn build(self) -> DragHandler {
+        BuiltDragHandler::new(self)
+    }
+}
+wrap_drag_handler! { struct BuiltDragHandler { builder : DragHandlerBuilder , } impl DragHandler { fn on_drag_enter (& self , browser : Option < & mut Browser > , drag_data : Option < & mut DragData > , mask : DragOperationsMask) -> :: std :: os :: raw :: c_int { match & self . builder . on_drag_enter { Some (closure) => closure (browser , drag_data , mask) , None => Default :: default () , } } fn on_draggable_regions_changed (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , regions : Option < & [DraggableRegion] >) { if let Some (closure) = & self . builder . on_draggable_regions_changed { closure (browser , frame , regions) ; } } } }
 
 /// See [`_cef_find_handler_t`] for more documentation.
 #[derive(Clone)]
@@ -19515,6 +20935,50 @@ impl From<FindHandler> for *mut _cef_find_handler_t {
         object
     }
 }
+impl FindHandler {
+    #[doc = "Create a [`FindHandlerBuilder`] to implement [`FindHandler`] with closures."]
+    pub fn builder() -> FindHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`FindHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplFindHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct FindHandlerBuilder {
+    on_find_result: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+                Option<&Rect>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+            ),
+        >,
+    >,
+}
+impl FindHandlerBuilder {
+    #[doc = "Implement [`ImplFindHandler::on_find_result`] with a closure."]
+    pub fn on_find_result(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+                Option<&Rect>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+            ) + 'static,
+    ) -> Self {
+        self.on_find_result = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`FindHandler`] which calls the closures."]
+    pub fn build(self) -> FindHandler {
+        BuiltFindHandler::new(self)
+    }
+}
+wrap_find_handler! { struct BuiltFindHandler { builder : FindHandlerBuilder , } impl FindHandler { fn on_find_result (& self , browser : Option < & mut Browser > , identifier : :: std :: os :: raw :: c_int , count : :: std :: os :: raw :: c_int , selection_rect : Option < & Rect > , active_match_ordinal : :: std :: os :: raw :: c_int , final_update : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_find_result { closure (browser , identifier , count , selection_rect , active_match_ordinal , final_update) ; } } } }
 
 /// See [`_cef_focus_handler_t`] for more documentation.
 #[derive(Clone)]
@@ -19694,6 +21158,48 @@ impl From<FocusHandler> for *mut _cef_focus_handler_t {
         object
     }
 }
+impl FocusHandler {
+    #[doc = "Create a [`FocusHandlerBuilder`] to implement [`FocusHandler`] with closures."]
+    pub fn builder() -> FocusHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`FocusHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplFocusHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct FocusHandlerBuilder {
+    on_take_focus: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, ::std::os::raw::c_int)>>,
+    on_set_focus:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, FocusSource) -> ::std::os::raw::c_int>>,
+    on_got_focus: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>)>>,
+}
+impl FocusHandlerBuilder {
+    #[doc = "Implement [`ImplFocusHandler::on_take_focus`] with a closure."]
+    pub fn on_take_focus(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_take_focus = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplFocusHandler::on_set_focus`] with a closure."]
+    pub fn on_set_focus(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, FocusSource) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.on_set_focus = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplFocusHandler::on_got_focus`] with a closure."]
+    pub fn on_got_focus(mut self, closure: impl Fn(Option<&mut Browser>) + 'static) -> Self {
+        self.on_got_focus = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`FocusHandler`] which calls the closures."]
+    pub fn build(self) -> FocusHandler {
+        BuiltFocusHandler::new(self)
+    }
+}
+wrap_focus_handler! { struct BuiltFocusHandler { builder : FocusHandlerBuilder , } impl FocusHandler { fn on_take_focus (& self , browser : Option < & mut Browser > , next : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_take_focus { closure (browser , next) ; } } fn on_set_focus (& self , browser : Option < & mut Browser > , source : FocusSource) -> :: std :: os :: raw :: c_int { match & self . builder . on_set_focus { Some (closure) => closure (browser , source) , None => Default :: default () , } } fn on_got_focus (& self , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_got_focus { closure (browser) ; } } } }
 
 /// See [`_cef_frame_handler_t`] for more documentation.
 #[derive(Clone)]
@@ -20008,6 +21514,71 @@ impl From<FrameHandler> for *mut _cef_frame_handler_t {
         object
     }
 }
+impl FrameHandler {
+    #[doc = "Create a [`FrameHandlerBuilder`] to implement [`FrameHandler`] with closures."]
+    pub fn builder() -> FrameHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`FrameHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplFrameHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct FrameHandlerBuilder {
+    on_frame_created: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Frame>)>>,
+    on_frame_destroyed: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Frame>)>>,
+    on_frame_attached: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Frame>, ::std::os::raw::c_int)>,
+    >,
+    on_frame_detached: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Frame>)>>,
+    on_main_frame_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Frame>, Option<&mut Frame>)>>,
+}
+impl FrameHandlerBuilder {
+    #[doc = "Implement [`ImplFrameHandler::on_frame_created`] with a closure."]
+    pub fn on_frame_created(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Frame>) + 'static,
+    ) -> Self {
+        self.on_frame_created = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplFrameHandler::on_frame_destroyed`] with a closure."]
+    pub fn on_frame_destroyed(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Frame>) + 'static,
+    ) -> Self {
+        self.on_frame_destroyed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplFrameHandler::on_frame_attached`] with a closure."]
+    pub fn on_frame_attached(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Frame>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_frame_attached = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplFrameHandler::on_frame_detached`] with a closure."]
+    pub fn on_frame_detached(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Frame>) + 'static,
+    ) -> Self {
+        self.on_frame_detached = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplFrameHandler::on_main_frame_changed`] with a closure."]
+    pub fn on_main_frame_changed(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Frame>, Option<&mut Frame>) + 'static,
+    ) -> Self {
+        self.on_main_frame_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`FrameHandler`] which calls the closures."]
+    pub fn build(self) -> FrameHandler {
+        BuiltFrameHandler::new(self)
+    }
+}
+wrap_frame_handler! { struct BuiltFrameHandler { builder : FrameHandlerBuilder , } impl FrameHandler { fn on_frame_created (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame >) { if let Some (closure) = & self . builder . on_frame_created { closure (browser , frame) ; } } fn on_frame_destroyed (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame >) { if let Some (closure) = & self . builder . on_frame_destroyed { closure (browser , frame) ; } } fn on_frame_attached (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , reattached : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_frame_attached { closure (browser , frame , reattached) ; } } fn on_frame_detached (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame >) { if let Some (closure) = & self . builder . on_frame_detached { closure (browser , frame) ; } } fn on_main_frame_changed (& self , browser : Option < & mut Browser > , old_frame : Option < & mut Frame > , new_frame : Option < & mut Frame >) { if let Some (closure) = & self . builder . on_main_frame_changed { closure (browser , old_frame , new_frame) ; } } } }
 
 /// See [`_cef_jsdialog_callback_t`] for more documentation.
 #[derive(Clone)]
@@ -20440,6 +22011,92 @@ impl From<JsdialogHandler> for *mut _cef_jsdialog_handler_t {
         object
     }
 }
+impl JsdialogHandler {
+    #[doc = "Create a [`JsdialogHandlerBuilder`] to implement [`JsdialogHandler`] with closures."]
+    pub fn builder() -> JsdialogHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`JsdialogHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplJsdialogHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct JsdialogHandlerBuilder {
+    on_jsdialog: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&CefString>,
+                JsdialogType,
+                Option<&CefString>,
+                Option<&CefString>,
+                Option<&mut JsdialogCallback>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_before_unload_dialog: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&CefString>,
+                ::std::os::raw::c_int,
+                Option<&mut JsdialogCallback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_reset_dialog_state: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>)>>,
+    on_dialog_closed: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>)>>,
+}
+impl JsdialogHandlerBuilder {
+    #[doc = "Implement [`ImplJsdialogHandler::on_jsdialog`] with a closure."]
+    pub fn on_jsdialog(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&CefString>,
+                JsdialogType,
+                Option<&CefString>,
+                Option<&CefString>,
+                Option<&mut JsdialogCallback>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_jsdialog = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplJsdialogHandler::on_before_unload_dialog`] with a closure."]
+    pub fn on_before_unload_dialog(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&CefString>,
+                ::std::os::raw::c_int,
+                Option<&mut JsdialogCallback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_before_unload_dialog = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplJsdialogHandler::on_reset_dialog_state`] with a closure."]
+    pub fn on_reset_dialog_state(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>) + 'static,
+    ) -> Self {
+        self.on_reset_dialog_state = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplJsdialogHandler::on_dialog_closed`] with a closure."]
+    pub fn on_dialog_closed(mut self, closure: impl Fn(Option<&mut Browser>) + 'static) -> Self {
+        self.on_dialog_closed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`JsdialogHandler`] which calls the closures."]
+    pub fn build(self) -> JsdialogHandler {
+        BuiltJsdialogHandler::new(self)
+    }
+}
+wrap_jsdialog_handler! { struct BuiltJsdialogHandler { builder : JsdialogHandlerBuilder , } impl JsdialogHandler { fn on_jsdialog (& self , browser : Option < & mut Browser > , origin_url : Option < & CefString > , dialog_type : JsdialogType , message_text : Option < & CefString > , default_prompt_text : Option < & CefString > , callback : Option < & mut JsdialogCallback > , suppress_message : Option < & mut :: std :: os :: raw :: c_int >) -> :: std :: os :: raw :: c_int { match & self . builder . on_jsdialog { Some (closure) => closure (browser , origin_url , dialog_type , message_text , default_prompt_text , callback , suppress_message) , None => Default :: default () , } } fn on_before_unload_dialog (& self , browser : Option < & mut Browser > , message_text : Option < & CefString > , is_reload : :: std :: os :: raw :: c_int , callback : Option < & mut JsdialogCallback >) -> :: std :: os :: raw :: c_int { match & self . builder . on_before_unload_dialog { Some (closure) => closure (browser , message_text , is_reload , callback) , None => Default :: default () , } } fn on_reset_dialog_state (& self , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_reset_dialog_state { closure (browser) ; } } fn on_dialog_closed (& self , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_dialog_closed { closure (browser) ; } } } }
 
 /// See [`_cef_keyboard_handler_t`] for more documentation.
 #[derive(Clone)]
@@ -20680,6 +22337,69 @@ impl From<KeyboardHandler> for *mut _cef_keyboard_handler_t {
         object
     }
 }
+impl KeyboardHandler {
+    #[doc = "Create a [`KeyboardHandlerBuilder`] to implement [`KeyboardHandler`] with closures."]
+    pub fn builder() -> KeyboardHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`KeyboardHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplKeyboardHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct KeyboardHandlerBuilder {
+    on_pre_key_event: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&KeyEvent>,
+                Option<&mut XEvent>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_key_event: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&KeyEvent>,
+                Option<&mut XEvent>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+}
+impl KeyboardHandlerBuilder {
+    #[doc = "Implement [`ImplKeyboardHandler::on_pre_key_event`] with a closure."]
+    pub fn on_pre_key_event(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&KeyEvent>,
+                Option<&mut XEvent>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_pre_key_event = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplKeyboardHandler::on_key_event`] with a closure."]
+    pub fn on_key_event(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&KeyEvent>,
+                Option<&mut XEvent>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_key_event = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`KeyboardHandler`] which calls the closures."]
+    pub fn build(self) -> KeyboardHandler {
+        BuiltKeyboardHandler::new(self)
+    }
+}
+wrap_keyboard_handler! { struct BuiltKeyboardHandler { builder : KeyboardHandlerBuilder , } impl KeyboardHandler { fn on_pre_key_event (& self , browser : Option < & mut Browser > , event : Option < & KeyEvent > , os_event : Option < & mut XEvent > , is_keyboard_shortcut : Option < & mut :: std :: os :: raw :: c_int >) -> :: std :: os :: raw :: c_int { match & self . builder . on_pre_key_event { Some (closure) => closure (browser , event , os_event , is_keyboard_shortcut) , None => Default :: default () , } } fn on_key_event (& self , browser : Option < & mut Browser > , event : Option < & KeyEvent > , os_event : Option < & mut XEvent >) -> :: std :: os :: raw :: c_int { match & self . builder . on_key_event { Some (closure) => closure (browser , event , os_event) , None => Default :: default () , } } } }
 
 /// See [`_cef_life_span_handler_t`] for more documentation.
 #[derive(Clone)]
@@ -21384,6 +23104,123 @@ impl From<LifeSpanHandler> for *mut _cef_life_span_handler_t {
         object
     }
 }
+impl LifeSpanHandler {
+    #[doc = "Create a [`LifeSpanHandlerBuilder`] to implement [`LifeSpanHandler`] with closures."]
+    pub fn builder() -> LifeSpanHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`LifeSpanHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplLifeSpanHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct LifeSpanHandlerBuilder {
+    on_before_popup: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                Option<&CefString>,
+                WindowOpenDisposition,
+                ::std::os::raw::c_int,
+                Option<&PopupFeatures>,
+                Option<&mut WindowInfo>,
+                Option<&mut Option<Client>>,
+                Option<&mut BrowserSettings>,
+                Option<&mut Option<DictionaryValue>>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_before_popup_aborted:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, ::std::os::raw::c_int)>>,
+    on_before_dev_tools_popup: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut WindowInfo>,
+                Option<&mut Option<Client>>,
+                Option<&mut BrowserSettings>,
+                Option<&mut Option<DictionaryValue>>,
+                Option<&mut ::std::os::raw::c_int>,
+            ),
+        >,
+    >,
+    on_after_created: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>)>>,
+    do_close: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>) -> ::std::os::raw::c_int>>,
+    on_before_close: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>)>>,
+}
+impl LifeSpanHandlerBuilder {
+    #[doc = "Implement [`ImplLifeSpanHandler::on_before_popup`] with a closure."]
+    pub fn on_before_popup(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                Option<&CefString>,
+                WindowOpenDisposition,
+                ::std::os::raw::c_int,
+                Option<&PopupFeatures>,
+                Option<&mut WindowInfo>,
+                Option<&mut Option<Client>>,
+                Option<&mut BrowserSettings>,
+                Option<&mut Option<DictionaryValue>>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_before_popup = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplLifeSpanHandler::on_before_popup_aborted`] with a closure."]
+    pub fn on_before_popup_aborted(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_before_popup_aborted = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplLifeSpanHandler::on_before_dev_tools_popup`] with a closure."]
+    pub fn on_before_dev_tools_popup(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut WindowInfo>,
+                Option<&mut Option<Client>>,
+                Option<&mut BrowserSettings>,
+                Option<&mut Option<DictionaryValue>>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) + 'static,
+    ) -> Self {
+        self.on_before_dev_tools_popup = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplLifeSpanHandler::on_after_created`] with a closure."]
+    pub fn on_after_created(mut self, closure: impl Fn(Option<&mut Browser>) + 'static) -> Self {
+        self.on_after_created = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplLifeSpanHandler::do_close`] with a closure."]
+    pub fn do_close(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.do_close = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplLifeSpanHandler::on_before_close`] with a closure."]
+    pub fn on_before_close(mut self, closure: impl Fn(Option<&mut Browser>) + 'static) -> Self {
+        self.on_before_close = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`LifeSpanHandler`] which calls the closures."]
+    pub fn build(self) -> LifeSpanHandler {
+        BuiltLifeSpanHandler::new(self)
+    }
+}
+wrap_life_span_handler! { struct BuiltLifeSpanHandler { builder : LifeSpanHandlerBuilder , } impl LifeSpanHandler { fn on_before_popup (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , popup_id : :: std :: os :: raw :: c_int , target_url : Option < & CefString > , target_frame_name : Option < & CefString > , target_disposition : WindowOpenDisposition , user_gesture : :: std :: os :: raw :: c_int , popup_features : Option < & PopupFeatures > , window_info : Option < & mut WindowInfo > , client : Option < & mut Option < Client >> , settings : Option < & mut BrowserSettings > , extra_info : Option < & mut Option < DictionaryValue >> , no_javascript_access : Option < & mut :: std :: os :: raw :: c_int >) -> :: std :: os :: raw :: c_int { match & self . builder . on_before_popup { Some (closure) => closure (browser , frame , popup_id , target_url , target_frame_name , target_disposition , user_gesture , popup_features , window_info , client , settings , extra_info , no_javascript_access) , None => Default :: default () , } } fn on_before_popup_aborted (& self , browser : Option < & mut Browser > , popup_id : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_before_popup_aborted { closure (browser , popup_id) ; } } fn on_before_dev_tools_popup (& self , browser : Option < & mut Browser > , window_info : Option < & mut WindowInfo > , client : Option < & mut Option < Client >> , settings : Option < & mut BrowserSettings > , extra_info : Option < & mut Option < DictionaryValue >> , use_default_window : Option < & mut :: std :: os :: raw :: c_int >) { if let Some (closure) = & self . builder . on_before_dev_tools_popup { closure (browser , window_info , client , settings , extra_info , use_default_window) ; } } fn on_after_created (& self , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_after_created { closure (browser) ; } } fn do_close (& self , browser : Option < & mut Browser >) -> :: std :: os :: raw :: c_int { match & self . builder . do_close { Some (closure) => closure (browser) , None => Default :: default () , } } fn on_before_close (& self , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_before_close { closure (browser) ; } } } }
 
 /// See [`_cef_load_handler_t`] for more documentation.
 #[derive(Clone)]
@@ -21731,6 +23568,92 @@ impl From<LoadHandler> for *mut _cef_load_handler_t {
         object
     }
 }
+impl LoadHandler {
+    #[doc = "Create a [`LoadHandlerBuilder`] to implement [`LoadHandler`] with closures."]
+    pub fn builder() -> LoadHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`LoadHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplLoadHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct LoadHandlerBuilder {
+    on_loading_state_change: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+            ),
+        >,
+    >,
+    on_load_start:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Frame>, TransitionType)>>,
+    on_load_end: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Frame>, ::std::os::raw::c_int)>,
+    >,
+    on_load_error: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Errorcode,
+                Option<&CefString>,
+                Option<&CefString>,
+            ),
+        >,
+    >,
+}
+impl LoadHandlerBuilder {
+    #[doc = "Implement [`ImplLoadHandler::on_loading_state_change`] with a closure."]
+    pub fn on_loading_state_change(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+            ) + 'static,
+    ) -> Self {
+        self.on_loading_state_change = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplLoadHandler::on_load_start`] with a closure."]
+    pub fn on_load_start(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Frame>, TransitionType) + 'static,
+    ) -> Self {
+        self.on_load_start = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplLoadHandler::on_load_end`] with a closure."]
+    pub fn on_load_end(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Frame>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_load_end = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplLoadHandler::on_load_error`] with a closure."]
+    pub fn on_load_error(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Errorcode,
+                Option<&CefString>,
+                Option<&CefString>,
+            ) + 'static,
+    ) -> Self {
+        self.on_load_error = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`LoadHandler`] which calls the closures."]
+    pub fn build(self) -> LoadHandler {
+        BuiltLoadHandler::new(self)
+    }
+}
+wrap_load_handler! { struct BuiltLoadHandler { builder : LoadHandlerBuilder , } impl LoadHandler { fn on_loading_state_change (& self , browser : Option < & mut Browser > , is_loading : :: std :: os :: raw :: c_int , can_go_back : :: std :: os :: raw :: c_int , can_go_forward : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_loading_state_change { closure (browser , is_loading , can_go_back , can_go_forward) ; } } fn on_load_start (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , transition_type : TransitionType) { if let Some (closure) = & self . builder . on_load_start { closure (browser , frame , transition_type) ; } } fn on_load_end (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , http_status_code : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_load_end { closure (browser , frame , http_status_code) ; } } fn on_load_error (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , error_code : Errorcode , error_text : Option < & CefString > , failed_url : Option < & CefString >) { if let Some (closure) = & self . builder . on_load_error { closure (browser , frame , error_code , error_text , failed_url) ; } } } }
 
 /// See [`_cef_media_access_callback_t`] for more documentation.
 #[derive(Clone)]
@@ -22211,6 +24134,85 @@ impl From<PermissionHandler> for *mut _cef_permission_handler_t {
         object
     }
 }
+impl PermissionHandler {
+    #[doc = "Create a [`PermissionHandlerBuilder`] to implement [`PermissionHandler`] with closures."]
+    pub fn builder() -> PermissionHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`PermissionHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplPermissionHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct PermissionHandlerBuilder {
+    on_request_media_access_permission: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&CefString>,
+                u32,
+                Option<&mut MediaAccessCallback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_show_permission_prompt: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                u64,
+                Option<&CefString>,
+                u32,
+                Option<&mut PermissionPromptCallback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_dismiss_permission_prompt:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, u64, PermissionRequestResult)>>,
+}
+impl PermissionHandlerBuilder {
+    #[doc = "Implement [`ImplPermissionHandler::on_request_media_access_permission`] with a closure."]
+    pub fn on_request_media_access_permission(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&CefString>,
+                u32,
+                Option<&mut MediaAccessCallback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_request_media_access_permission = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPermissionHandler::on_show_permission_prompt`] with a closure."]
+    pub fn on_show_permission_prompt(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                u64,
+                Option<&CefString>,
+                u32,
+                Option<&mut PermissionPromptCallback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_show_permission_prompt = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPermissionHandler::on_dismiss_permission_prompt`] with a closure."]
+    pub fn on_dismiss_permission_prompt(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, u64, PermissionRequestResult) + 'static,
+    ) -> Self {
+        self.on_dismiss_permission_prompt = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`PermissionHandler`] which calls the closures."]
+    pub fn build(self) -> PermissionHandler {
+        BuiltPermissionHandler::new(self)
+    }
+}
+wrap_permission_handler! { struct BuiltPermissionHandler { builder : PermissionHandlerBuilder , } impl PermissionHandler { fn on_request_media_access_permission (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , requesting_origin : Option < & CefString > , requested_permissions : u32 , callback : Option < & mut MediaAccessCallback >) -> :: std :: os :: raw :: c_int { match & self . builder . on_request_media_access_permission { Some (closure) => closure (browser , frame , requesting_origin , requested_permissions , callback) , None => Default :: default () , } } fn on_show_permission_prompt (& self , browser : Option < & mut Browser > , prompt_id : u64 , requesting_origin : Option < & CefString > , requested_permissions : u32 , callback : Option < & mut PermissionPromptCallback >) -> :: std :: os :: raw :: c_int { match & self . builder . on_show_permission_prompt { Some (closure) => closure (browser , prompt_id , requesting_origin , requested_permissions , callback) , None => Default :: default () , } } fn on_dismiss_permission_prompt (& self , browser : Option < & mut Browser > , prompt_id : u64 , result : PermissionRequestResult) { if let Some (closure) = & self . builder . on_dismiss_permission_prompt { closure (browser , prompt_id , result) ; } } } }
 
 /// See [`_cef_print_settings_t`] for more documentation.
 #[derive(Clone)]
@@ -23123,6 +25125,105 @@ impl From<PrintHandler> for *mut _cef_print_handler_t {
         object
     }
 }
+impl PrintHandler {
+    #[doc = "Create a [`PrintHandlerBuilder`] to implement [`PrintHandler`] with closures."]
+    pub fn builder() -> PrintHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`PrintHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplPrintHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct PrintHandlerBuilder {
+    on_print_start: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>)>>,
+    on_print_settings: Option<
+        std::rc::Rc<
+            dyn Fn(Option<&mut Browser>, Option<&mut PrintSettings>, ::std::os::raw::c_int),
+        >,
+    >,
+    on_print_dialog: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                ::std::os::raw::c_int,
+                Option<&mut PrintDialogCallback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_print_job: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&CefString>,
+                Option<&CefString>,
+                Option<&mut PrintJobCallback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_print_reset: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>)>>,
+    pdf_paper_size:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, ::std::os::raw::c_int) -> Size>>,
+}
+impl PrintHandlerBuilder {
+    #[doc = "Implement [`ImplPrintHandler::on_print_start`] with a closure."]
+    pub fn on_print_start(mut self, closure: impl Fn(Option<&mut Browser>) + 'static) -> Self {
+        self.on_print_start = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPrintHandler::on_print_settings`] with a closure."]
+    pub fn on_print_settings(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut PrintSettings>, ::std::os::raw::c_int)
+            + 'static,
+    ) -> Self {
+        self.on_print_settings = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPrintHandler::on_print_dialog`] with a closure."]
+    pub fn on_print_dialog(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                ::std::os::raw::c_int,
+                Option<&mut PrintDialogCallback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_print_dialog = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPrintHandler::on_print_job`] with a closure."]
+    pub fn on_print_job(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&CefString>,
+                Option<&CefString>,
+                Option<&mut PrintJobCallback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_print_job = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPrintHandler::on_print_reset`] with a closure."]
+    pub fn on_print_reset(mut self, closure: impl Fn(Option<&mut Browser>) + 'static) -> Self {
+        self.on_print_reset = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPrintHandler::pdf_paper_size`] with a closure."]
+    pub fn pdf_paper_size(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, ::std::os::raw::c_int) -> Size + 'static,
+    ) -> Self {
+        self.pdf_paper_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`PrintHandler`] which calls the closures."]
+    pub fn build(self) -> PrintHandler {
+        BuiltPrintHandler::new(self)
+    }
+}
+wrap_print_handler! { struct BuiltPrintHandler { builder : PrintHandlerBuilder , } impl PrintHandler { fn on_print_start (& self , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_print_start { closure (browser) ; } } fn on_print_settings (& self , browser : Option < & mut Browser > , settings : Option < & mut PrintSettings > , get_defaults : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_print_settings { closure (browser , settings , get_defaults) ; } } fn on_print_dialog (& self , browser : Option < & mut Browser > , has_selection : :: std :: os :: raw :: c_int , callback : Option < & mut PrintDialogCallback >) -> :: std :: os :: raw :: c_int { match & self . builder . on_print_dialog { Some (closure) => closure (browser , has_selection , callback) , None => Default :: default () , } } fn on_print_job (& self , browser : Option < & mut Browser > , document_name : Option < & CefString > , pdf_file_path : Option < & CefString > , callback : Option < & mut PrintJobCallback >) -> :: std :: os :: raw :: c_int { match & self . builder . on_print_job { Some (closure) => closure (browser , document_name , pdf_file_path , callback) , None => Default :: default () , } } fn on_print_reset (& self , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_print_reset { closure (browser) ; } } fn pdf_paper_size (& self , browser : Option < & mut Browser > , device_units_per_inch : :: std :: os :: raw :: c_int) -> Size { match & self . builder . pdf_paper_size { Some (closure) => closure (browser , device_units_per_inch) , None => Default :: default () , } } } }
 
 /// See [`_cef_render_handler_t`] for more documentation.
 #[derive(Clone)]
@@ -24238,6 +26339,251 @@ impl From<RenderHandler> for *mut _cef_render_handler_t {
         object
     }
 }
+impl RenderHandler {
+    #[doc = "Create a [`RenderHandlerBuilder`] to implement [`RenderHandler`] with closures."]
+    pub fn builder() -> RenderHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`RenderHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplRenderHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct RenderHandlerBuilder {
+    accessibility_handler: Option<std::rc::Rc<dyn Fn() -> Option<AccessibilityHandler>>>,
+    root_screen_rect: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Rect>) -> ::std::os::raw::c_int>,
+    >,
+    view_rect: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Rect>)>>,
+    screen_point: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+                Option<&mut ::std::os::raw::c_int>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    screen_info: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut ScreenInfo>) -> ::std::os::raw::c_int>,
+    >,
+    on_popup_show: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, ::std::os::raw::c_int)>>,
+    on_popup_size: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&Rect>)>>,
+    on_paint: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                PaintElementType,
+                Option<&[Rect]>,
+                *const u8,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+            ),
+        >,
+    >,
+    on_accelerated_paint: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                PaintElementType,
+                Option<&[Rect]>,
+                Option<&AcceleratedPaintInfo>,
+            ),
+        >,
+    >,
+    touch_handle_size:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, HorizontalAlignment, Option<&mut Size>)>>,
+    on_touch_handle_state_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&TouchHandleState>)>>,
+    start_dragging: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut DragData>,
+                DragOperationsMask,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    update_drag_cursor: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, DragOperationsMask)>>,
+    on_scroll_offset_changed: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, f64, f64)>>,
+    on_ime_composition_range_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&Range>, Option<&[Rect]>)>>,
+    on_text_selection_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&CefString>, Option<&Range>)>>,
+    on_virtual_keyboard_requested: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, TextInputMode)>>,
+}
+impl RenderHandlerBuilder {
+    #[doc = "Implement [`ImplRenderHandler::accessibility_handler`] with a closure."]
+    pub fn accessibility_handler(
+        mut self,
+        closure: impl Fn() -> Option<AccessibilityHandler> + 'static,
+    ) -> Self {
+        self.accessibility_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::root_screen_rect`] with a closure."]
+    pub fn root_screen_rect(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Rect>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.root_screen_rect = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::view_rect`] with a closure."]
+    pub fn view_rect(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Rect>) + 'static,
+    ) -> Self {
+        self.view_rect = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::screen_point`] with a closure."]
+    pub fn screen_point(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+                Option<&mut ::std::os::raw::c_int>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.screen_point = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::screen_info`] with a closure."]
+    pub fn screen_info(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut ScreenInfo>) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.screen_info = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::on_popup_show`] with a closure."]
+    pub fn on_popup_show(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_popup_show = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::on_popup_size`] with a closure."]
+    pub fn on_popup_size(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&Rect>) + 'static,
+    ) -> Self {
+        self.on_popup_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::on_paint`] with a closure."]
+    pub fn on_paint(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                PaintElementType,
+                Option<&[Rect]>,
+                *const u8,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+            ) + 'static,
+    ) -> Self {
+        self.on_paint = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::on_accelerated_paint`] with a closure."]
+    pub fn on_accelerated_paint(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                PaintElementType,
+                Option<&[Rect]>,
+                Option<&AcceleratedPaintInfo>,
+            ) + 'static,
+    ) -> Self {
+        self.on_accelerated_paint = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::touch_handle_size`] with a closure."]
+    pub fn touch_handle_size(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, HorizontalAlignment, Option<&mut Size>) + 'static,
+    ) -> Self {
+        self.touch_handle_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::on_touch_handle_state_changed`] with a closure."]
+    pub fn on_touch_handle_state_changed(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&TouchHandleState>) + 'static,
+    ) -> Self {
+        self.on_touch_handle_state_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::start_dragging`] with a closure."]
+    pub fn start_dragging(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut DragData>,
+                DragOperationsMask,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.start_dragging = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::update_drag_cursor`] with a closure."]
+    pub fn update_drag_cursor(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, DragOperationsMask) + 'static,
+    ) -> Self {
+        self.update_drag_cursor = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::on_scroll_offset_changed`] with a closure."]
+    pub fn on_scroll_offset_changed(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, f64, f64) + 'static,
+    ) -> Self {
+        self.on_scroll_offset_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::on_ime_composition_range_changed`] with a closure."]
+    pub fn on_ime_composition_range_changed(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&Range>, Option<&[Rect]>) + 'static,
+    ) -> Self {
+        self.on_ime_composition_range_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::on_text_selection_changed`] with a closure."]
+    pub fn on_text_selection_changed(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&CefString>, Option<&Range>) + 'static,
+    ) -> Self {
+        self.on_text_selection_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderHandler::on_virtual_keyboard_requested`] with a closure."]
+    pub fn on_virtual_keyboard_requested(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, TextInputMode) + 'static,
+    ) -> Self {
+        self.on_virtual_keyboard_requested = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`RenderHandler`] which calls the closures."]
+    pub fn build(self) -> RenderHandler {
+        BuiltRenderHandler::new(self)
+    }
+}
+wrap_render_handler! { struct BuiltRenderHandler { builder : RenderHandlerBuilder , } impl RenderHandler { fn accessibility_handler (& self) -> Option < AccessibilityHandler > { match & self . builder . accessibility_handler { Some (closure) => closure () , None => Default :: default () , } } fn root_screen_rect (& self , browser : Option < & mut Browser > , rect : Option < & mut Rect >) -> :: std :: os :: raw :: c_int { match & self . builder . root_screen_rect { Some (closure) => closure (browser , rect) , None => Default :: default () , } } fn view_rect (& self , browser : Option < & mut Browser > , rect : Option < & mut Rect >) { if let Some (closure) = & self . builder . view_rect { closure (browser , rect) ; } } fn screen_point (& self , browser : Option < & mut Browser > , view_x : :: std :: os :: raw :: c_int , view_y : :: std :: os :: raw :: c_int , screen_x : Option < & mut :: std :: os :: raw :: c_int > , screen_y : Option < & mut :: std :: os :: raw :: c_int >) -> :: std :: os :: raw :: c_int { match & self . builder . screen_point { Some (closure) => closure (browser , view_x , view_y , screen_x , screen_y) , None => Default :: default () , } } fn screen_info (& self , browser : Option < & mut Browser > , screen_info : Option < & mut ScreenInfo >) -> :: std :: os :: raw :: c_int { match & self . builder . screen_info { Some (closure) => closure (browser , screen_info) , None => Default :: default () , } } fn on_popup_show (& self , browser : Option < & mut Browser > , show : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_popup_show { closure (browser , show) ; } } fn on_popup_size (& self , browser : Option < & mut Browser > , rect : Option < & Rect >) { if let Some (closure) = & self . builder . on_popup_size { closure (browser , rect) ; } } fn on_paint (& self , browser : Option < & mut Browser > , type_ : PaintElementType , dirty_rects : Option < & [Rect] > , buffer : * const u8 , width : :: std :: os :: raw :: c_int , height : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_paint { closure (browser , type_ , dirty_rects , buffer , width , height) ; } } fn on_accelerated_paint (& self , browser : Option < & mut Browser > , type_ : PaintElementType , dirty_rects : Option < & [Rect] > , info : Option < & AcceleratedPaintInfo >) { if let Some (closure) = & self . builder . on_accelerated_paint { closure (browser , type_ , dirty_rects , info) ; } } fn touch_handle_size (& self , browser : Option < & mut Browser > , orientation : HorizontalAlignment , size : Option < & mut Size >) { if let Some (closure) = & self . builder . touch_handle_size { closure (browser , orientation , size) ; } } fn on_touch_handle_state_changed (& self , browser : Option < & mut Browser > , state : Option < & TouchHandleState >) { if let Some (closure) = & self . builder . on_touch_handle_state_changed { closure (browser , state) ; } } fn start_dragging (& self , browser : Option < & mut Browser > , drag_data : Option < & mut DragData > , allowed_ops : DragOperationsMask , x : :: std :: os :: raw :: c_int , y : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . start_dragging { Some (closure) => closure (browser , drag_data , allowed_ops , x , y) , None => Default :: default () , } } fn update_drag_cursor (& self , browser : Option < & mut Browser > , operation : DragOperationsMask) { if let Some (closure) = & self . builder . update_drag_cursor { closure (browser , operation) ; } } fn on_scroll_offset_changed (& self , browser : Option < & mut Browser > , x : f64 , y : f64) { if let Some (closure) = & self . builder . on_scroll_offset_changed { closure (browser , x , y) ; } } fn on_ime_composition_range_changed (& self , browser : Option < & mut Browser > , selected_range : Option < & Range > , character_bounds : Option < & [Rect] >) { if let Some (closure) = & self . builder . on_ime_composition_range_changed { closure (browser , selected_range , character_bounds) ; } } fn on_text_selection_changed (& self , browser : Option < & mut Browser > , selected_text : Option < & CefString > , selected_range : Option < & Range >) { if let Some (closure) = & self . builder . on_text_selection_changed { closure (browser , selected_text , selected_range) ; } } fn on_virtual_keyboard_requested (& self , browser : Option < & mut Browser > , input_mode : TextInputMode) { if let Some (closure) = & self . builder . on_virtual_keyboard_requested { closure (browser , input_mode) ; } } } }
 
 /// See [`_cef_auth_callback_t`] for more documentation.
 #[derive(Clone)]
@@ -25226,6 +27572,139 @@ impl From<ResourceHandler> for *mut _cef_resource_handler_t {
         object
     }
 }
+impl ResourceHandler {
+    #[doc = "Create a [`ResourceHandlerBuilder`] to implement [`ResourceHandler`] with closures."]
+    pub fn builder() -> ResourceHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`ResourceHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplResourceHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct ResourceHandlerBuilder {
+    open: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Request>,
+                Option<&mut ::std::os::raw::c_int>,
+                Option<&mut Callback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    process_request: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Request>, Option<&mut Callback>) -> ::std::os::raw::c_int>,
+    >,
+    response_headers: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Response>, Option<&mut i64>, Option<&mut CefString>)>,
+    >,
+    skip: Option<
+        std::rc::Rc<
+            dyn Fn(
+                i64,
+                Option<&mut i64>,
+                Option<&mut ResourceSkipCallback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    read: Option<
+        std::rc::Rc<
+            dyn Fn(
+                *mut u8,
+                ::std::os::raw::c_int,
+                Option<&mut ::std::os::raw::c_int>,
+                Option<&mut ResourceReadCallback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    read_response: Option<
+        std::rc::Rc<
+            dyn Fn(
+                *mut u8,
+                ::std::os::raw::c_int,
+                Option<&mut ::std::os::raw::c_int>,
+                Option<&mut Callback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    cancel: Option<std::rc::Rc<dyn Fn()>>,
+}
+impl ResourceHandlerBuilder {
+    #[doc = "Implement [`ImplResourceHandler::open`] with a closure."]
+    pub fn open(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Request>,
+                Option<&mut ::std::os::raw::c_int>,
+                Option<&mut Callback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.open = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResourceHandler::process_request`] with a closure."]
+    pub fn process_request(
+        mut self,
+        closure: impl Fn(Option<&mut Request>, Option<&mut Callback>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.process_request = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResourceHandler::response_headers`] with a closure."]
+    pub fn response_headers(
+        mut self,
+        closure: impl Fn(Option<&mut Response>, Option<&mut i64>, Option<&mut CefString>) + 'static,
+    ) -> Self {
+        self.response_headers = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResourceHandler::skip`] with a closure."]
+    pub fn skip(
+        mut self,
+        closure: impl Fn(i64, Option<&mut i64>, Option<&mut ResourceSkipCallback>) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.skip = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResourceHandler::read`] with a closure."]
+    pub fn read(
+        mut self,
+        closure: impl Fn(
+                *mut u8,
+                ::std::os::raw::c_int,
+                Option<&mut ::std::os::raw::c_int>,
+                Option<&mut ResourceReadCallback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.read = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResourceHandler::read_response`] with a closure."]
+    pub fn read_response(
+        mut self,
+        closure: impl Fn(
+                *mut u8,
+                ::std::os::raw::c_int,
+                Option<&mut ::std::os::raw::c_int>,
+                Option<&mut Callback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.read_response = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResourceHandler::cancel`] with a closure."]
+    pub fn cancel(mut self, closure: impl Fn() + 'static) -> Self {
+        self.cancel = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`ResourceHandler`] which calls the closures."]
+    pub fn build(self) -> ResourceHandler {
+        BuiltResourceHandler::new(self)
+    }
+}
+wrap_resource_handler! { struct BuiltResourceHandler { builder : ResourceHandlerBuilder , } impl ResourceHandler { fn open (& self , request : Option < & mut Request > , handle_request : Option < & mut :: std :: os :: raw :: c_int > , callback : Option < & mut Callback >) -> :: std :: os :: raw :: c_int { match & self . builder . open { Some (closure) => closure (request , handle_request , callback) , None => Default :: default () , } } fn process_request (& self , request : Option < & mut Request > , callback : Option < & mut Callback >) -> :: std :: os :: raw :: c_int { match & self . builder . process_request { Some (closure) => closure (request , callback) , None => Default :: default () , } } fn response_headers (& self , response : Option < & mut Response > , response_length : Option < & mut i64 > , redirect_url : Option < & mut CefString >) { if let Some (closure) = & self . builder . response_headers { closure (response , response_length , redirect_url) ; } } fn skip (& self , bytes_to_skip : i64 , bytes_skipped : Option < & mut i64 > , callback : Option < & mut ResourceSkipCallback >) -> :: std :: os :: raw :: c_int { match & self . builder . skip { Some (closure) => closure (bytes_to_skip , bytes_skipped , callback) , None => Default :: default () , } } fn read (& self , data_out : * mut u8 , bytes_to_read : :: std :: os :: raw :: c_int , bytes_read : Option < & mut :: std :: os :: raw :: c_int > , callback : Option < & mut ResourceReadCallback >) -> :: std :: os :: raw :: c_int { match & self . builder . read { Some (closure) => closure (data_out , bytes_to_read , bytes_read , callback) , None => Default :: default () , } } fn read_response (& self , data_out : * mut u8 , bytes_to_read : :: std :: os :: raw :: c_int , bytes_read : Option < & mut :: std :: os :: raw :: c_int > , callback : Option < & mut Callback >) -> :: std :: os :: raw :: c_int { match & self . builder . read_response { Some (closure) => closure (data_out , bytes_to_read , bytes_read , callback) , None => Default :: default () , } } fn cancel (& self) { if let Some (closure) = & self . builder . cancel { closure () ; } } } }
 
 /// See [`_cef_response_filter_t`] for more documentation.
 #[derive(Clone)]
@@ -25464,6 +27943,53 @@ impl From<ResponseFilter> for *mut _cef_response_filter_t {
         object
     }
 }
+impl ResponseFilter {
+    #[doc = "Create a [`ResponseFilterBuilder`] to implement [`ResponseFilter`] with closures."]
+    pub fn builder() -> ResponseFilterBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`ResponseFilter`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplResponseFilter`] trait."]
+#[derive(Clone, Default)]
+pub struct ResponseFilterBuilder {
+    init_filter: Option<std::rc::Rc<dyn Fn() -> ::std::os::raw::c_int>>,
+    filter: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Vec<u8>>,
+                Option<&mut usize>,
+                Option<&mut Vec<u8>>,
+                Option<&mut usize>,
+            ) -> ResponseFilterStatus,
+        >,
+    >,
+}
+impl ResponseFilterBuilder {
+    #[doc = "Implement [`ImplResponseFilter::init_filter`] with a closure."]
+    pub fn init_filter(mut self, closure: impl Fn() -> ::std::os::raw::c_int + 'static) -> Self {
+        self.init_filter = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResponseFilter::filter`] with a closure."]
+    pub fn filter(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Vec<u8>>,
+                Option<&mut usize>,
+                Option<&mut Vec<u8>>,
+                Option<&mut usize>,
+            ) -> ResponseFilterStatus
+            + 'static,
+    ) -> Self {
+        self.filter = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`ResponseFilter`] which calls the closures."]
+    pub fn build(self) -> ResponseFilter {
+        BuiltResponseFilter::new(self)
+    }
+}
+wrap_response_filter! { struct BuiltResponseFilter { builder : ResponseFilterBuilder , } impl ResponseFilter { fn init_filter (& self) -> :: std :: os :: raw :: c_int { match & self . builder . init_filter { Some (closure) => closure () , None => Default :: default () , } } fn filter (& self , data_in : Option < & mut Vec < u8 >> , data_in_read : Option < & mut usize > , data_out : Option < & mut Vec < u8 >> , data_out_written : Option < & mut usize >) -> ResponseFilterStatus { match & self . builder . filter { Some (closure) => closure (data_in , data_in_read , data_out , data_out_written) , None => Default :: default () , } } } }
 
 /// See [`_cef_resource_request_handler_t`] for more documentation.
 #[derive(Clone)]
@@ -26273,6 +28799,214 @@ impl From<ResourceRequestHandler> for *mut _cef_resource_request_handler_t {
         object
     }
 }
+impl ResourceRequestHandler {
+    #[doc = "Create a [`ResourceRequestHandlerBuilder`] to implement [`ResourceRequestHandler`] with closures."]
+    pub fn builder() -> ResourceRequestHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`ResourceRequestHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplResourceRequestHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct ResourceRequestHandlerBuilder {
+    cookie_access_filter: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+            ) -> Option<CookieAccessFilter>,
+        >,
+    >,
+    on_before_resource_load: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&mut Callback>,
+            ) -> ReturnValue,
+        >,
+    >,
+    resource_handler: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+            ) -> Option<ResourceHandler>,
+        >,
+    >,
+    on_resource_redirect: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&mut Response>,
+                Option<&mut CefString>,
+            ),
+        >,
+    >,
+    on_resource_response: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&mut Response>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    resource_response_filter: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&mut Response>,
+            ) -> Option<ResponseFilter>,
+        >,
+    >,
+    on_resource_load_complete: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&mut Response>,
+                UrlrequestStatus,
+                i64,
+            ),
+        >,
+    >,
+    on_protocol_execution: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&mut ::std::os::raw::c_int>,
+            ),
+        >,
+    >,
+}
+impl ResourceRequestHandlerBuilder {
+    #[doc = "Implement [`ImplResourceRequestHandler::cookie_access_filter`] with a closure."]
+    pub fn cookie_access_filter(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+            ) -> Option<CookieAccessFilter>
+            + 'static,
+    ) -> Self {
+        self.cookie_access_filter = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResourceRequestHandler::on_before_resource_load`] with a closure."]
+    pub fn on_before_resource_load(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&mut Callback>,
+            ) -> ReturnValue
+            + 'static,
+    ) -> Self {
+        self.on_before_resource_load = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResourceRequestHandler::resource_handler`] with a closure."]
+    pub fn resource_handler(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+            ) -> Option<ResourceHandler>
+            + 'static,
+    ) -> Self {
+        self.resource_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResourceRequestHandler::on_resource_redirect`] with a closure."]
+    pub fn on_resource_redirect(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&mut Response>,
+                Option<&mut CefString>,
+            ) + 'static,
+    ) -> Self {
+        self.on_resource_redirect = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResourceRequestHandler::on_resource_response`] with a closure."]
+    pub fn on_resource_response(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&mut Response>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_resource_response = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResourceRequestHandler::resource_response_filter`] with a closure."]
+    pub fn resource_response_filter(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&mut Response>,
+            ) -> Option<ResponseFilter>
+            + 'static,
+    ) -> Self {
+        self.resource_response_filter = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResourceRequestHandler::on_resource_load_complete`] with a closure."]
+    pub fn on_resource_load_complete(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&mut Response>,
+                UrlrequestStatus,
+                i64,
+            ) + 'static,
+    ) -> Self {
+        self.on_resource_load_complete = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResourceRequestHandler::on_protocol_execution`] with a closure."]
+    pub fn on_protocol_execution(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) + 'static,
+    ) -> Self {
+        self.on_protocol_execution = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`ResourceRequestHandler`] which calls the closures."]
+    pub fn build(self) -> ResourceRequestHandler {
+        BuiltResourceRequestHandler::new(self)
+    }
+}
+wrap_resource_request_handler! { struct BuiltResourceRequestHandler { builder : ResourceRequestHandlerBuilder , } impl ResourceRequestHandler { fn cookie_access_filter (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , request : Option < & mut Request >) -> Option < CookieAccessFilter > { match & self . builder . cookie_access_filter { Some (closure) => closure (browser , frame , request) , None => Default :: default () , } } fn on_before_resource_load (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , request : Option < & mut Request > , callback : Option < & mut Callback >) -> ReturnValue { match & self . builder . on_before_resource_load { Some (closure) => closure (browser , frame , request , callback) , None => Default :: default () , } } fn resource_handler (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , request : Option < & mut Request >) -> Option < ResourceHandler > { match & self . builder . resource_handler { Some (closure) => closure (browser , frame , request) , None => Default :: default () , } } fn on_resource_redirect (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , request : Option < & mut Request > , response : Option < & mut Response > , new_url : Option < & mut CefString >) { if let Some (closure) = & self . builder . on_resource_redirect { closure (browser , frame , request , response , new_url) ; } } fn on_resource_response (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , request : Option < & mut Request > , response : Option < & mut Response >) -> :: std :: os :: raw :: c_int { match & self . builder . on_resource_response { Some (closure) => closure (browser , frame , request , response) , None => Default :: default () , } } fn resource_response_filter (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , request : Option < & mut Request > , response : Option < & mut Response >) -> Option < ResponseFilter > { match & self . builder . resource_response_filter { Some (closure) => closure (browser , frame , request , response) , None => Default :: default () , } } fn on_resource_load_complete (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , request : Option < & mut Request > , response : Option < & mut Response > , status : UrlrequestStatus , received_content_length : i64) { if let Some (closure) = & self . builder . on_resource_load_complete { closure (browser , frame , request , response , status , received_content_length) ; } } fn on_protocol_execution (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , request : Option < & mut Request > , allow_os_execution : Option < & mut :: std :: os :: raw :: c_int >) { if let Some (closure) = & self . builder . on_protocol_execution { closure (browser , frame , request , allow_os_execution) ; } } } }
 
 /// See [`_cef_cookie_access_filter_t`] for more documentation.
 #[derive(Clone)]
@@ -26542,6 +29276,73 @@ impl From<CookieAccessFilter> for *mut _cef_cookie_access_filter_t {
         object
     }
 }
+impl CookieAccessFilter {
+    #[doc = "Create a [`CookieAccessFilterBuilder`] to implement [`CookieAccessFilter`] with closures."]
+    pub fn builder() -> CookieAccessFilterBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`CookieAccessFilter`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplCookieAccessFilter`] trait."]
+#[derive(Clone, Default)]
+pub struct CookieAccessFilterBuilder {
+    can_send_cookie: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&Cookie>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    can_save_cookie: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&mut Response>,
+                Option<&Cookie>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+}
+impl CookieAccessFilterBuilder {
+    #[doc = "Implement [`ImplCookieAccessFilter::can_send_cookie`] with a closure."]
+    pub fn can_send_cookie(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&Cookie>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.can_send_cookie = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplCookieAccessFilter::can_save_cookie`] with a closure."]
+    pub fn can_save_cookie(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                Option<&mut Response>,
+                Option<&Cookie>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.can_save_cookie = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`CookieAccessFilter`] which calls the closures."]
+    pub fn build(self) -> CookieAccessFilter {
+        BuiltCookieAccessFilter::new(self)
+    }
+}
+wrap_cookie_access_filter! { struct BuiltCookieAccessFilter { builder : CookieAccessFilterBuilder , } impl CookieAccessFilter { fn can_send_cookie (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , request : Option < & mut Request > , cookie : Option < & Cookie >) -> :: std :: os :: raw :: c_int { match & self . builder . can_send_cookie { Some (closure) => closure (browser , frame , request , cookie) , None => Default :: default () , } } fn can_save_cookie (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , request : Option < & mut Request > , response : Option < & mut Response > , cookie : Option < & Cookie >) -> :: std :: os :: raw :: c_int { match & self . builder . can_save_cookie { Some (closure) => closure (browser , frame , request , response , cookie) , None => Default :: default () , } } } }
 
 /// See [`_cef_sslinfo_t`] for more documentation.
 #[derive(Clone)]
@@ -27803,6 +30604,257 @@ impl From<RequestHandler> for *mut _cef_request_handler_t {
         object
     }
 }
+impl RequestHandler {
+    #[doc = "Create a [`RequestHandlerBuilder`] to implement [`RequestHandler`] with closures."]
+    pub fn builder() -> RequestHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`RequestHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplRequestHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct RequestHandlerBuilder {
+    on_before_browse: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_open_urlfrom_tab: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&CefString>,
+                WindowOpenDisposition,
+                ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    resource_request_handler: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> Option<ResourceRequestHandler>,
+        >,
+    >,
+    auth_credentials: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&CefString>,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                Option<&CefString>,
+                Option<&mut AuthCallback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_certificate_error: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Errorcode,
+                Option<&CefString>,
+                Option<&mut Sslinfo>,
+                Option<&mut Callback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_select_client_certificate: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                ::std::os::raw::c_int,
+                Option<&[Option<X509Certificate>]>,
+                Option<&mut SelectClientCertificateCallback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_render_view_ready: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>)>>,
+    on_render_process_unresponsive: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut UnresponsiveProcessCallback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    on_render_process_responsive: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>)>>,
+    on_render_process_terminated: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                TerminationStatus,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+            ),
+        >,
+    >,
+    on_document_available_in_main_frame: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>)>>,
+}
+impl RequestHandlerBuilder {
+    #[doc = "Implement [`ImplRequestHandler::on_before_browse`] with a closure."]
+    pub fn on_before_browse(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_before_browse = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRequestHandler::on_open_urlfrom_tab`] with a closure."]
+    pub fn on_open_urlfrom_tab(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&CefString>,
+                WindowOpenDisposition,
+                ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_open_urlfrom_tab = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRequestHandler::resource_request_handler`] with a closure."]
+    pub fn resource_request_handler(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> Option<ResourceRequestHandler>
+            + 'static,
+    ) -> Self {
+        self.resource_request_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRequestHandler::auth_credentials`] with a closure."]
+    pub fn auth_credentials(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&CefString>,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                Option<&CefString>,
+                Option<&mut AuthCallback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.auth_credentials = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRequestHandler::on_certificate_error`] with a closure."]
+    pub fn on_certificate_error(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Errorcode,
+                Option<&CefString>,
+                Option<&mut Sslinfo>,
+                Option<&mut Callback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_certificate_error = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRequestHandler::on_select_client_certificate`] with a closure."]
+    pub fn on_select_client_certificate(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                ::std::os::raw::c_int,
+                Option<&[Option<X509Certificate>]>,
+                Option<&mut SelectClientCertificateCallback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_select_client_certificate = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRequestHandler::on_render_view_ready`] with a closure."]
+    pub fn on_render_view_ready(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>) + 'static,
+    ) -> Self {
+        self.on_render_view_ready = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRequestHandler::on_render_process_unresponsive`] with a closure."]
+    pub fn on_render_process_unresponsive(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut UnresponsiveProcessCallback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_render_process_unresponsive = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRequestHandler::on_render_process_responsive`] with a closure."]
+    pub fn on_render_process_responsive(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>) + 'static,
+    ) -> Self {
+        self.on_render_process_responsive = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRequestHandler::on_render_process_terminated`] with a closure."]
+    pub fn on_render_process_terminated(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, TerminationStatus, ::std::os::raw::c_int, Option<&CefString>)
+            + 'static,
+    ) -> Self {
+        self.on_render_process_terminated = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRequestHandler::on_document_available_in_main_frame`] with a closure."]
+    pub fn on_document_available_in_main_frame(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>) + 'static,
+    ) -> Self {
+        self.on_document_available_in_main_frame = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`RequestHandler`] which calls the closures."]
+    pub fn build(self) -> RequestHandler {
+        BuiltRequestHandler::new(self)
+    }
+}
+wrap_request_handler! { struct BuiltRequestHandler { builder : RequestHandlerBuilder , } impl RequestHandler { fn on_before_browse (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , request : Option < & mut Request > , user_gesture : :: std :: os :: raw :: c_int , is_redirect : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . on_before_browse { Some (closure) => closure (browser , frame , request , user_gesture , is_redirect) , None => Default :: default () , } } fn on_open_urlfrom_tab (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , target_url : Option < & CefString > , target_disposition : WindowOpenDisposition , user_gesture : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . on_open_urlfrom_tab { Some (closure) => closure (browser , frame , target_url , target_disposition , user_gesture) , None => Default :: default () , } } fn resource_request_handler (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , request : Option < & mut Request > , is_navigation : :: std :: os :: raw :: c_int , is_download : :: std :: os :: raw :: c_int , request_initiator : Option < & CefString > , disable_default_handling : Option < & mut :: std :: os :: raw :: c_int >) -> Option < ResourceRequestHandler > { match & self . builder . resource_request_handler { Some (closure) => closure (browser , frame , request , is_navigation , is_download , request_initiator , disable_default_handling) , None => Default :: default () , } } fn auth_credentials (& self , browser : Option < & mut Browser > , origin_url : Option < & CefString > , is_proxy : :: std :: os :: raw :: c_int , host : Option < & CefString > , port : :: std :: os :: raw :: c_int , realm : Option < & CefString > , scheme : Option < & CefString > , callback : Option < & mut AuthCallback >) -> :: std :: os :: raw :: c_int { match & self . builder . auth_credentials { Some (closure) => closure (browser , origin_url , is_proxy , host , port , realm , scheme , callback) , None => Default :: default () , } } fn on_certificate_error (& self , browser : Option < & mut Browser > , cert_error : Errorcode , request_url : Option < & CefString > , ssl_info : Option < & mut Sslinfo > , callback : Option < & mut Callback >) -> :: std :: os :: raw :: c_int { match & self . builder . on_certificate_error { Some (closure) => closure (browser , cert_error , request_url , ssl_info , callback) , None => Default :: default () , } } fn on_select_client_certificate (& self , browser : Option < & mut Browser > , is_proxy : :: std :: os :: raw :: c_int , host : Option < & CefString > , port : :: std :: os :: raw :: c_int , certificates : Option < & [Option < X509Certificate >] > , callback : Option < & mut SelectClientCertificateCallback >) -> :: std :: os :: raw :: c_int { match & self . builder . on_select_client_certificate { Some (closure) => closure (browser , is_proxy , host , port , certificates , callback) , None => Default :: default () , } } fn on_render_view_ready (& self , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_render_view_ready { closure (browser) ; } } fn on_render_process_unresponsive (& self , browser : Option < & mut Browser > , callback : Option < & mut UnresponsiveProcessCallback >) -> :: std :: os :: raw :: c_int { match & self . builder . on_render_process_unresponsive { Some (closure) => closure (browser , callback) , None => Default :: default () , } } fn on_render_process_responsive (& self , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_render_process_responsive { closure (browser) ; } } fn on_render_process_terminated (& self , browser : Option < & mut Browser > , status : TerminationStatus , error_code : :: std :: os :: raw :: c_int , error_string : Option < & CefString >) { if let Some (closure) = & self . builder . on_render_process_terminated { closure (browser , status , error_code , error_string) ; } } fn on_document_available_in_main_frame (& self , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_document_available_in_main_frame { closure (browser) ; } } } }
 
 /// See [`_cef_client_t`] for more documentation.
 #[derive(Clone)]
@@ -28518,6 +31570,182 @@ impl From<Client> for *mut _cef_client_t {
         object
     }
 }
+impl Client {
+    #[doc = "Create a [`ClientBuilder`] to implement [`Client`] with closures."]
+    pub fn builder() -> ClientBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`Client`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplClient`] trait."]
+#[derive(Clone, Default)]
+pub struct ClientBuilder {
+    audio_handler: Option<std::rc::Rc<dyn Fn() -> Option<AudioHandler>>>,
+    command_handler: Option<std::rc::Rc<dyn Fn() -> Option<CommandHandler>>>,
+    context_menu_handler: Option<std::rc::Rc<dyn Fn() -> Option<ContextMenuHandler>>>,
+    dialog_handler: Option<std::rc::Rc<dyn Fn() -> Option<DialogHandler>>>,
+    display_handler: Option<std::rc::Rc<dyn Fn() -> Option<DisplayHandler>>>,
+    download_handler: Option<std::rc::Rc<dyn Fn() -> Option<DownloadHandler>>>,
+    drag_handler: Option<std::rc::Rc<dyn Fn() -> Option<DragHandler>>>,
+    find_handler: Option<std::rc::Rc<dyn Fn() -> Option<FindHandler>>>,
+    focus_handler: Option<std::rc::Rc<dyn Fn() -> Option<FocusHandler>>>,
+    frame_handler: Option<std::rc::Rc<dyn Fn() -> Option<FrameHandler>>>,
+    permission_handler: Option<std::rc::Rc<dyn Fn() -> Option<PermissionHandler>>>,
+    jsdialog_handler: Option<std::rc::Rc<dyn Fn() -> Option<JsdialogHandler>>>,
+    keyboard_handler: Option<std::rc::Rc<dyn Fn() -> Option<KeyboardHandler>>>,
+    life_span_handler: Option<std::rc::Rc<dyn Fn() -> Option<LifeSpanHandler>>>,
+    load_handler: Option<std::rc::Rc<dyn Fn() -> Option<LoadHandler>>>,
+    print_handler: Option<std::rc::Rc<dyn Fn() -> Option<PrintHandler>>>,
+    render_handler: Option<std::rc::Rc<dyn Fn() -> Option<RenderHandler>>>,
+    request_handler: Option<std::rc::Rc<dyn Fn() -> Option<RequestHandler>>>,
+    on_process_message_received: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                ProcessId,
+                Option<&mut ProcessMessage>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+}
+impl ClientBuilder {
+    #[doc = "Implement [`ImplClient::audio_handler`] with a closure."]
+    pub fn audio_handler(mut self, closure: impl Fn() -> Option<AudioHandler> + 'static) -> Self {
+        self.audio_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::command_handler`] with a closure."]
+    pub fn command_handler(
+        mut self,
+        closure: impl Fn() -> Option<CommandHandler> + 'static,
+    ) -> Self {
+        self.command_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::context_menu_handler`] with a closure."]
+    pub fn context_menu_handler(
+        mut self,
+        closure: impl Fn() -> Option<ContextMenuHandler> + 'static,
+    ) -> Self {
+        self.context_menu_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::dialog_handler`] with a closure."]
+    pub fn dialog_handler(mut self, closure: impl Fn() -> Option<DialogHandler> + 'static) -> Self {
+        self.dialog_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::display_handler`] with a closure."]
+    pub fn display_handler(
+        mut self,
+        closure: impl Fn() -> Option<DisplayHandler> + 'static,
+    ) -> Self {
+        self.display_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::download_handler`] with a closure."]
+    pub fn download_handler(
+        mut self,
+        closure: impl Fn() -> Option<DownloadHandler> + 'static,
+    ) -> Self {
+        self.download_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::drag_handler`] with a closure."]
+    pub fn drag_handler(mut self, closure: impl Fn() -> Option<DragHandler> + 'static) -> Self {
+        self.drag_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::find_handler`] with a closure."]
+    pub fn find_handler(mut self, closure: impl Fn() -> Option<FindHandler> + 'static) -> Self {
+        self.find_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::focus_handler`] with a closure."]
+    pub fn focus_handler(mut self, closure: impl Fn() -> Option<FocusHandler> + 'static) -> Self {
+        self.focus_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::frame_handler`] with a closure."]
+    pub fn frame_handler(mut self, closure: impl Fn() -> Option<FrameHandler> + 'static) -> Self {
+        self.frame_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::permission_handler`] with a closure."]
+    pub fn permission_handler(
+        mut self,
+        closure: impl Fn() -> Option<PermissionHandler> + 'static,
+    ) -> Self {
+        self.permission_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::jsdialog_handler`] with a closure."]
+    pub fn jsdialog_handler(
+        mut self,
+        closure: impl Fn() -> Option<JsdialogHandler> + 'static,
+    ) -> Self {
+        self.jsdialog_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::keyboard_handler`] with a closure."]
+    pub fn keyboard_handler(
+        mut self,
+        closure: impl Fn() -> Option<KeyboardHandler> + 'static,
+    ) -> Self {
+        self.keyboard_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::life_span_handler`] with a closure."]
+    pub fn life_span_handler(
+        mut self,
+        closure: impl Fn() -> Option<LifeSpanHandler> + 'static,
+    ) -> Self {
+        self.life_span_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::load_handler`] with a closure."]
+    pub fn load_handler(mut self, closure: impl Fn() -> Option<LoadHandler> + 'static) -> Self {
+        self.load_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::print_handler`] with a closure."]
+    pub fn print_handler(mut self, closure: impl Fn() -> Option<PrintHandler> + 'static) -> Self {
+        self.print_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::render_handler`] with a closure."]
+    pub fn render_handler(mut self, closure: impl Fn() -> Option<RenderHandler> + 'static) -> Self {
+        self.render_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::request_handler`] with a closure."]
+    pub fn request_handler(
+        mut self,
+        closure: impl Fn() -> Option<RequestHandler> + 'static,
+    ) -> Self {
+        self.request_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplClient::on_process_message_received`] with a closure."]
+    pub fn on_process_message_received(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                ProcessId,
+                Option<&mut ProcessMessage>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_process_message_received = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`Client`] which calls the closures."]
+    pub fn build(self) -> Client {
+        BuiltClient::new(self)
+    }
+}
+wrap_client! { struct BuiltClient { builder : ClientBuilder , } impl Client { fn audio_handler (& self) -> Option < AudioHandler > { match & self . builder . audio_handler { Some (closure) => closure () , None => Default :: default () , } } fn command_handler (& self) -> Option < CommandHandler > { match & self . builder . command_handler { Some (closure) => closure () , None => Default :: default () , } } fn context_menu_handler (& self) -> Option < ContextMenuHandler > { match & self . builder . context_menu_handler { Some (closure) => closure () , None => Default :: default () , } } fn dialog_handler (& self) -> Option < DialogHandler > { match & self . builder . dialog_handler { Some (closure) => closure () , None => Default :: default () , } } fn display_handler (& self) -> Option < DisplayHandler > { match & self . builder . display_handler { Some (closure) => closure () , None => Default :: default () , } } fn download_handler (& self) -> Option < DownloadHandler > { match & self . builder . download_handler { Some (closure) => closure () , None => Default :: default () , } } fn drag_handler (& self) -> Option < DragHandler > { match & self . builder . drag_handler { Some (closure) => closure () , None => Default :: default () , } } fn find_handler (& self) -> Option < FindHandler > { match & self . builder . find_handler { Some (closure) => closure () , None => Default :: default () , } } fn focus_handler (& self) -> Option < FocusHandler > { match & self . builder . focus_handler { Some (closure) => closure () , None => Default :: default () , } } fn frame_handler (& self) -> Option < FrameHandler > { match & self . builder . frame_handler { Some (closure) => closure () , None => Default :: default () , } } fn permission_handler (& self) -> Option < PermissionHandler > { match & self . builder . permission_handler { Some (closure) => closure () , None => Default :: default () , } } fn jsdialog_handler (& self) -> Option < JsdialogHandler > { match & self . builder . jsdialog_handler { Some (closure) => closure () , None => Default :: default () , } } fn keyboard_handler (& self) -> Option < KeyboardHandler > { match & self . builder . keyboard_handler { Some (closure) => closure () , None => Default :: default () , } } fn life_span_handler (& self) -> Option < LifeSpanHandler > { match & self . builder . life_span_handler { Some (closure) => closure () , None => Default :: default () , } } fn load_handler (& self) -> Option < LoadHandler > { match & self . builder . load_handler { Some (closure) => closure () , None => Default :: default () , } } fn print_handler (& self) -> Option < PrintHandler > { match & self . builder . print_handler { Some (closure) => closure () , None => Default :: default () , } } fn render_handler (& self) -> Option < RenderHandler > { match & self . builder . render_handler { Some (closure) => closure () , None => Default :: default () , } } fn request_handler (& self) -> Option < RequestHandler > { match & self . builder . request_handler { Some (closure) => closure () , None => Default :: default () , } } fn on_process_message_received (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , source_process : ProcessId , message : Option < & mut ProcessMessage >) -> :: std :: os :: raw :: c_int { match & self . builder . on_process_message_received { Some (closure) => closure (browser , frame , source_process , message) , None => Default :: default () , } } } }
 
 /// See [`_cef_command_line_t`] for more documentation.
 #[derive(Clone)]
@@ -29142,6 +32370,62 @@ impl From<RequestContextHandler> for *mut _cef_request_context_handler_t {
         object
     }
 }
+impl RequestContextHandler {
+    #[doc = "Create a [`RequestContextHandlerBuilder`] to implement [`RequestContextHandler`] with closures."]
+    pub fn builder() -> RequestContextHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`RequestContextHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplRequestContextHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct RequestContextHandlerBuilder {
+    on_request_context_initialized: Option<std::rc::Rc<dyn Fn(Option<&mut RequestContext>)>>,
+    resource_request_handler: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> Option<ResourceRequestHandler>,
+        >,
+    >,
+}
+impl RequestContextHandlerBuilder {
+    #[doc = "Implement [`ImplRequestContextHandler::on_request_context_initialized`] with a closure."]
+    pub fn on_request_context_initialized(
+        mut self,
+        closure: impl Fn(Option<&mut RequestContext>) + 'static,
+    ) -> Self {
+        self.on_request_context_initialized = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRequestContextHandler::resource_request_handler`] with a closure."]
+    pub fn resource_request_handler(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut Request>,
+                ::std::os::raw::c_int,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> Option<ResourceRequestHandler>
+            + 'static,
+    ) -> Self {
+        self.resource_request_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`RequestContextHandler`] which calls the closures."]
+    pub fn build(self) -> RequestContextHandler {
+        BuiltRequestContextHandler::new(self)
+    }
+}
+wrap_request_context_handler! { struct BuiltRequestContextHandler { builder : RequestContextHandlerBuilder , } impl RequestContextHandler { fn on_request_context_initialized (& self , request_context : Option < & mut RequestContext >) { if let Some (closure) = & self . builder . on_request_context_initialized { closure (request_context) ; } } fn resource_request_handler (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , request : Option < & mut Request > , is_navigation : :: std :: os :: raw :: c_int , is_download : :: std :: os :: raw :: c_int , request_initiator : Option < & CefString > , disable_default_handling : Option < & mut :: std :: os :: raw :: c_int >) -> Option < ResourceRequestHandler > { match & self . builder . resource_request_handler { Some (closure) => closure (browser , frame , request , is_navigation , is_download , request_initiator , disable_default_handling) , None => Default :: default () , } } } }
 
 /// See [`_cef_browser_process_handler_t`] for more documentation.
 #[derive(Clone)]
@@ -29458,6 +32742,81 @@ impl From<BrowserProcessHandler> for *mut _cef_browser_process_handler_t {
         object
     }
 }
+impl BrowserProcessHandler {
+    #[doc = "Create a [`BrowserProcessHandlerBuilder`] to implement [`BrowserProcessHandler`] with closures."]
+    pub fn builder() -> BrowserProcessHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`BrowserProcessHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplBrowserProcessHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct BrowserProcessHandlerBuilder {
+    on_register_custom_preferences:
+        Option<std::rc::Rc<dyn Fn(PreferencesType, Option<&mut PreferenceRegistrar>)>>,
+    on_context_initialized: Option<std::rc::Rc<dyn Fn()>>,
+    on_before_child_process_launch: Option<std::rc::Rc<dyn Fn(Option<&mut CommandLine>)>>,
+    on_already_running_app_relaunch: Option<
+        std::rc::Rc<dyn Fn(Option<&mut CommandLine>, Option<&CefString>) -> ::std::os::raw::c_int>,
+    >,
+    on_schedule_message_pump_work: Option<std::rc::Rc<dyn Fn(i64)>>,
+    default_client: Option<std::rc::Rc<dyn Fn() -> Option<Client>>>,
+    default_request_context_handler: Option<std::rc::Rc<dyn Fn() -> Option<RequestContextHandler>>>,
+}
+impl BrowserProcessHandlerBuilder {
+    #[doc = "Implement [`ImplBrowserProcessHandler::on_register_custom_preferences`] with a closure."]
+    pub fn on_register_custom_preferences(
+        mut self,
+        closure: impl Fn(PreferencesType, Option<&mut PreferenceRegistrar>) + 'static,
+    ) -> Self {
+        self.on_register_custom_preferences = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserProcessHandler::on_context_initialized`] with a closure."]
+    pub fn on_context_initialized(mut self, closure: impl Fn() + 'static) -> Self {
+        self.on_context_initialized = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserProcessHandler::on_before_child_process_launch`] with a closure."]
+    pub fn on_before_child_process_launch(
+        mut self,
+        closure: impl Fn(Option<&mut CommandLine>) + 'static,
+    ) -> Self {
+        self.on_before_child_process_launch = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserProcessHandler::on_already_running_app_relaunch`] with a closure."]
+    pub fn on_already_running_app_relaunch(
+        mut self,
+        closure: impl Fn(Option<&mut CommandLine>, Option<&CefString>) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_already_running_app_relaunch = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserProcessHandler::on_schedule_message_pump_work`] with a closure."]
+    pub fn on_schedule_message_pump_work(mut self, closure: impl Fn(i64) + 'static) -> Self {
+        self.on_schedule_message_pump_work = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserProcessHandler::default_client`] with a closure."]
+    pub fn default_client(mut self, closure: impl Fn() -> Option<Client> + 'static) -> Self {
+        self.default_client = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserProcessHandler::default_request_context_handler`] with a closure."]
+    pub fn default_request_context_handler(
+        mut self,
+        closure: impl Fn() -> Option<RequestContextHandler> + 'static,
+    ) -> Self {
+        self.default_request_context_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`BrowserProcessHandler`] which calls the closures."]
+    pub fn build(self) -> BrowserProcessHandler {
+        BuiltBrowserProcessHandler::new(self)
+    }
+}
+wrap_browser_process_handler! { struct BuiltBrowserProcessHandler { builder : BrowserProcessHandlerBuilder , } impl BrowserProcessHandler { fn on_register_custom_preferences (& self , type_ : PreferencesType , registrar : Option < & mut PreferenceRegistrar >) { if let Some (closure) = & self . builder . on_register_custom_preferences { closure (type_ , registrar) ; } } fn on_context_initialized (& self) { if let Some (closure) = & self . builder . on_context_initialized { closure () ; } } fn on_before_child_process_launch (& self , command_line : Option < & mut CommandLine >) { if let Some (closure) = & self . builder . on_before_child_process_launch { closure (command_line) ; } } fn on_already_running_app_relaunch (& self , command_line : Option < & mut CommandLine > , current_directory : Option < & CefString >) -> :: std :: os :: raw :: c_int { match & self . builder . on_already_running_app_relaunch { Some (closure) => closure (command_line , current_directory) , None => Default :: default () , } } fn on_schedule_message_pump_work (& self , delay_ms : i64) { if let Some (closure) = & self . builder . on_schedule_message_pump_work { closure (delay_ms) ; } } fn default_client (& self) -> Option < Client > { match & self . builder . default_client { Some (closure) => closure () , None => Default :: default () , } } fn default_request_context_handler (& self) -> Option < RequestContextHandler > { match & self . builder . default_request_context_handler { Some (closure) => closure () , None => Default :: default () , } } } }
 
 /// See [`_cef_task_t`] for more documentation.
 #[derive(Clone)]
@@ -29547,6 +32906,29 @@ impl From<Task> for *mut _cef_task_t {
         object
     }
 }
+impl Task {
+    #[doc = "Create a [`TaskBuilder`] to implement [`Task`] with closures."]
+    pub fn builder() -> TaskBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`Task`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplTask`] trait."]
+#[derive(Clone, Default)]
+pub struct TaskBuilder {
+    execute: Option<std::rc::Rc<dyn Fn()>>,
+}
+impl TaskBuilder {
+    #[doc = "Implement [`ImplTask::execute`] with a closure."]
+    pub fn execute(mut self, closure: impl Fn() + 'static) -> Self {
+        self.execute = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`Task`] which calls the closures."]
+    pub fn build(self) -> Task {
+        BuiltTask::new(self)
+    }
+}
+wrap_task! { struct BuiltTask { builder : TaskBuilder , } impl Task { fn execute (& self) { if let Some (closure) = & self . builder . execute { closure () ; } } } }
 
 /// See [`_cef_task_runner_t`] for more documentation.
 #[derive(Clone)]
@@ -30187,6 +33569,49 @@ impl From<V8Handler> for *mut _cef_v8_handler_t {
         object
     }
 }
+impl V8Handler {
+    #[doc = "Create a [`V8HandlerBuilder`] to implement [`V8Handler`] with closures."]
+    pub fn builder() -> V8HandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`V8Handler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplV8Handler`] trait."]
+#[derive(Clone, Default)]
+pub struct V8HandlerBuilder {
+    execute: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&CefString>,
+                Option<&mut V8Value>,
+                Option<&[Option<V8Value>]>,
+                Option<&mut Option<V8Value>>,
+                Option<&mut CefString>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+}
+impl V8HandlerBuilder {
+    #[doc = "Implement [`ImplV8Handler::execute`] with a closure."]
+    pub fn execute(
+        mut self,
+        closure: impl Fn(
+                Option<&CefString>,
+                Option<&mut V8Value>,
+                Option<&[Option<V8Value>]>,
+                Option<&mut Option<V8Value>>,
+                Option<&mut CefString>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.execute = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`V8Handler`] which calls the closures."]
+    pub fn build(self) -> V8Handler {
+        BuiltV8Handler::new(self)
+    }
+}
+wrap_v8_handler! { struct BuiltV8Handler { builder : V8HandlerBuilder , } impl V8Handler { fn execute (& self , name : Option < & CefString > , object : Option < & mut V8Value > , arguments : Option < & [Option < V8Value >] > , retval : Option < & mut Option < V8Value >> , exception : Option < & mut CefString >) -> :: std :: os :: raw :: c_int { match & self . builder . execute { Some (closure) => closure (name , object , arguments , retval , exception) , None => Default :: default () , } } } }
 
 /// See [`_cef_v8_accessor_t`] for more documentation.
 #[derive(Clone)]
@@ -30452,6 +33877,71 @@ impl From<V8Accessor> for *mut _cef_v8_accessor_t {
         object
     }
 }
+impl V8Accessor {
+    #[doc = "Create a [`V8AccessorBuilder`] to implement [`V8Accessor`] with closures."]
+    pub fn builder() -> V8AccessorBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`V8Accessor`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplV8Accessor`] trait."]
+#[derive(Clone, Default)]
+pub struct V8AccessorBuilder {
+    get: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&CefString>,
+                Option<&mut V8Value>,
+                Option<&mut Option<V8Value>>,
+                Option<&mut CefString>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    set: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&CefString>,
+                Option<&mut V8Value>,
+                Option<&mut V8Value>,
+                Option<&mut CefString>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+}
+impl V8AccessorBuilder {
+    #[doc = "Implement [`ImplV8Accessor::get`] with a closure."]
+    pub fn get(
+        mut self,
+        closure: impl Fn(
+                Option<&CefString>,
+                Option<&mut V8Value>,
+                Option<&mut Option<V8Value>>,
+                Option<&mut CefString>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.get = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplV8Accessor::set`] with a closure."]
+    pub fn set(
+        mut self,
+        closure: impl Fn(
+                Option<&CefString>,
+                Option<&mut V8Value>,
+                Option<&mut V8Value>,
+                Option<&mut CefString>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.set = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`V8Accessor`] which calls the closures."]
+    pub fn build(self) -> V8Accessor {
+        BuiltV8Accessor::new(self)
+    }
+}
+wrap_v8_accessor! { struct BuiltV8Accessor { builder : V8AccessorBuilder , } impl V8Accessor { fn get (& self , name : Option < & CefString > , object : Option < & mut V8Value > , retval : Option < & mut Option < V8Value >> , exception : Option < & mut CefString >) -> :: std :: os :: raw :: c_int { match & self . builder . get { Some (closure) => closure (name , object , retval , exception) , None => Default :: default () , } } fn set (& self , name : Option < & CefString > , object : Option < & mut V8Value > , value : Option < & mut V8Value > , exception : Option < & mut CefString >) -> :: std :: os :: raw :: c_int { match & self . builder . set { Some (closure) => closure (name , object , value , exception) , None => Default :: default () , } } } }
 
 /// See [`_cef_v8_interceptor_t`] for more documentation.
 #[derive(Clone)]
@@ -30893,6 +34383,119 @@ impl From<V8Interceptor> for *mut _cef_v8_interceptor_t {
         object
     }
 }
+impl V8Interceptor {
+    #[doc = "Create a [`V8InterceptorBuilder`] to implement [`V8Interceptor`] with closures."]
+    pub fn builder() -> V8InterceptorBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`V8Interceptor`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplV8Interceptor`] trait."]
+#[derive(Clone, Default)]
+pub struct V8InterceptorBuilder {
+    byname: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&CefString>,
+                Option<&mut V8Value>,
+                Option<&mut Option<V8Value>>,
+                Option<&mut CefString>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    byindex: Option<
+        std::rc::Rc<
+            dyn Fn(
+                ::std::os::raw::c_int,
+                Option<&mut V8Value>,
+                Option<&mut Option<V8Value>>,
+                Option<&mut CefString>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    set_byname: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&CefString>,
+                Option<&mut V8Value>,
+                Option<&mut V8Value>,
+                Option<&mut CefString>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    set_byindex: Option<
+        std::rc::Rc<
+            dyn Fn(
+                ::std::os::raw::c_int,
+                Option<&mut V8Value>,
+                Option<&mut V8Value>,
+                Option<&mut CefString>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+}
+impl V8InterceptorBuilder {
+    #[doc = "Implement [`ImplV8Interceptor::byname`] with a closure."]
+    pub fn byname(
+        mut self,
+        closure: impl Fn(
+                Option<&CefString>,
+                Option<&mut V8Value>,
+                Option<&mut Option<V8Value>>,
+                Option<&mut CefString>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.byname = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplV8Interceptor::byindex`] with a closure."]
+    pub fn byindex(
+        mut self,
+        closure: impl Fn(
+                ::std::os::raw::c_int,
+                Option<&mut V8Value>,
+                Option<&mut Option<V8Value>>,
+                Option<&mut CefString>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.byindex = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplV8Interceptor::set_byname`] with a closure."]
+    pub fn set_byname(
+        mut self,
+        closure: impl Fn(
+                Option<&CefString>,
+                Option<&mut V8Value>,
+                Option<&mut V8Value>,
+                Option<&mut CefString>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.set_byname = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplV8Interceptor::set_byindex`] with a closure."]
+    pub fn set_byindex(
+        mut self,
+        closure: impl Fn(
+                ::std::os::raw::c_int,
+                Option<&mut V8Value>,
+                Option<&mut V8Value>,
+                Option<&mut CefString>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.set_byindex = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`V8Interceptor`] which calls the closures."]
+    pub fn build(self) -> V8Interceptor {
+        BuiltV8Interceptor::new(self)
+    }
+}
+wrap_v8_interceptor! { struct BuiltV8Interceptor { builder : V8InterceptorBuilder , } impl V8Interceptor { fn byname (& self , name : Option < & CefString > , object : Option < & mut V8Value > , retval : Option < & mut Option < V8Value >> , exception : Option < & mut CefString >) -> :: std :: os :: raw :: c_int { match & self . builder . byname { Some (closure) => closure (name , object , retval , exception) , None => Default :: default () , } } fn byindex (& self , index : :: std :: os :: raw :: c_int , object : Option < & mut V8Value > , retval : Option < & mut Option < V8Value >> , exception : Option < & mut CefString >) -> :: std :: os :: raw :: c_int { match & self . builder . byindex { Some (closure) => closure (index , object , retval , exception) , None => Default :: default () , } } fn set_byname (& self , name : Option < & CefString > , object : Option < & mut V8Value > , value : Option < & mut V8Value > , exception : Option < & mut CefString >) -> :: std :: os :: raw :: c_int { match & self . builder . set_byname { Some (closure) => closure (name , object , value , exception) , None => Default :: default () , } } fn set_byindex (& self , index : :: std :: os :: raw :: c_int , object : Option < & mut V8Value > , value : Option < & mut V8Value > , exception : Option < & mut CefString >) -> :: std :: os :: raw :: c_int { match & self . builder . set_byindex { Some (closure) => closure (index , object , value , exception) , None => Default :: default () , } } } }
 
 /// See [`_cef_v8_exception_t`] for more documentation.
 #[derive(Clone)]
@@ -31153,6 +34756,29 @@ impl From<V8ArrayBufferReleaseCallback> for *mut _cef_v8_array_buffer_release_ca
         object
     }
 }
+impl V8ArrayBufferReleaseCallback {
+    #[doc = "Create a [`V8ArrayBufferReleaseCallbackBuilder`] to implement [`V8ArrayBufferReleaseCallback`] with closures."]
+    pub fn builder() -> V8ArrayBufferReleaseCallbackBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`V8ArrayBufferReleaseCallback`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplV8ArrayBufferReleaseCallback`] trait."]
+#[derive(Clone, Default)]
+pub struct V8ArrayBufferReleaseCallbackBuilder {
+    release_buffer: Option<std::rc::Rc<dyn Fn(*mut u8)>>,
+}
+impl V8ArrayBufferReleaseCallbackBuilder {
+    #[doc = "Implement [`ImplV8ArrayBufferReleaseCallback::release_buffer`] with a closure."]
+    pub fn release_buffer(mut self, closure: impl Fn(*mut u8) + 'static) -> Self {
+        self.release_buffer = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`V8ArrayBufferReleaseCallback`] which calls the closures."]
+    pub fn build(self) -> V8ArrayBufferReleaseCallback {
+        BuiltV8ArrayBufferReleaseCallback::new(self)
+    }
+}
+wrap_v8_array_buffer_release_callback! { struct BuiltV8ArrayBufferReleaseCallback { builder : V8ArrayBufferReleaseCallbackBuilder , } impl V8ArrayBufferReleaseCallback { fn release_buffer (& self , buffer : * mut u8) { if let Some (closure) = & self . builder . release_buffer { closure (buffer) ; } } } }
 
 /// See [`_cef_v8_value_t`] for more documentation.
 #[derive(Clone)]
@@ -32984,6 +36610,135 @@ impl From<RenderProcessHandler> for *mut _cef_render_process_handler_t {
         object
     }
 }
+impl RenderProcessHandler {
+    #[doc = "Create a [`RenderProcessHandlerBuilder`] to implement [`RenderProcessHandler`] with closures."]
+    pub fn builder() -> RenderProcessHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`RenderProcessHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplRenderProcessHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct RenderProcessHandlerBuilder {
+    on_web_kit_initialized: Option<std::rc::Rc<dyn Fn()>>,
+    on_browser_created:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut DictionaryValue>)>>,
+    on_browser_destroyed: Option<std::rc::Rc<dyn Fn(Option<&mut Browser>)>>,
+    load_handler: Option<std::rc::Rc<dyn Fn() -> Option<LoadHandler>>>,
+    on_context_created: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Frame>, Option<&mut V8Context>)>,
+    >,
+    on_context_released: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Frame>, Option<&mut V8Context>)>,
+    >,
+    on_uncaught_exception: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut V8Context>,
+                Option<&mut V8Exception>,
+                Option<&mut V8StackTrace>,
+            ),
+        >,
+    >,
+    on_focused_node_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Browser>, Option<&mut Frame>, Option<&mut Domnode>)>>,
+    on_process_message_received: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                ProcessId,
+                Option<&mut ProcessMessage>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+}
+impl RenderProcessHandlerBuilder {
+    #[doc = "Implement [`ImplRenderProcessHandler::on_web_kit_initialized`] with a closure."]
+    pub fn on_web_kit_initialized(mut self, closure: impl Fn() + 'static) -> Self {
+        self.on_web_kit_initialized = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderProcessHandler::on_browser_created`] with a closure."]
+    pub fn on_browser_created(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut DictionaryValue>) + 'static,
+    ) -> Self {
+        self.on_browser_created = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderProcessHandler::on_browser_destroyed`] with a closure."]
+    pub fn on_browser_destroyed(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>) + 'static,
+    ) -> Self {
+        self.on_browser_destroyed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderProcessHandler::load_handler`] with a closure."]
+    pub fn load_handler(mut self, closure: impl Fn() -> Option<LoadHandler> + 'static) -> Self {
+        self.load_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderProcessHandler::on_context_created`] with a closure."]
+    pub fn on_context_created(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Frame>, Option<&mut V8Context>) + 'static,
+    ) -> Self {
+        self.on_context_created = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderProcessHandler::on_context_released`] with a closure."]
+    pub fn on_context_released(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Frame>, Option<&mut V8Context>) + 'static,
+    ) -> Self {
+        self.on_context_released = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderProcessHandler::on_uncaught_exception`] with a closure."]
+    pub fn on_uncaught_exception(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&mut V8Context>,
+                Option<&mut V8Exception>,
+                Option<&mut V8StackTrace>,
+            ) + 'static,
+    ) -> Self {
+        self.on_uncaught_exception = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderProcessHandler::on_focused_node_changed`] with a closure."]
+    pub fn on_focused_node_changed(
+        mut self,
+        closure: impl Fn(Option<&mut Browser>, Option<&mut Frame>, Option<&mut Domnode>) + 'static,
+    ) -> Self {
+        self.on_focused_node_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplRenderProcessHandler::on_process_message_received`] with a closure."]
+    pub fn on_process_message_received(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                ProcessId,
+                Option<&mut ProcessMessage>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_process_message_received = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`RenderProcessHandler`] which calls the closures."]
+    pub fn build(self) -> RenderProcessHandler {
+        BuiltRenderProcessHandler::new(self)
+    }
+}
+wrap_render_process_handler! { struct BuiltRenderProcessHandler { builder : RenderProcessHandlerBuilder , } impl RenderProcessHandler { fn on_web_kit_initialized (& self) { if let Some (closure) = & self . builder . on_web_kit_initialized { closure () ; } } fn on_browser_created (& self , browser : Option < & mut Browser > , extra_info : Option < & mut DictionaryValue >) { if let Some (closure) = & self . builder . on_browser_created { closure (browser , extra_info) ; } } fn on_browser_destroyed (& self , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_browser_destroyed { closure (browser) ; } } fn load_handler (& self) -> Option < LoadHandler > { match & self . builder . load_handler { Some (closure) => closure () , None => Default :: default () , } } fn on_context_created (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , context : Option < & mut V8Context >) { if let Some (closure) = & self . builder . on_context_created { closure (browser , frame , context) ; } } fn on_context_released (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , context : Option < & mut V8Context >) { if let Some (closure) = & self . builder . on_context_released { closure (browser , frame , context) ; } } fn on_uncaught_exception (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , context : Option < & mut V8Context > , exception : Option < & mut V8Exception > , stack_trace : Option < & mut V8StackTrace >) { if let Some (closure) = & self . builder . on_uncaught_exception { closure (browser , frame , context , exception , stack_trace) ; } } fn on_focused_node_changed (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , node : Option < & mut Domnode >) { if let Some (closure) = & self . builder . on_focused_node_changed { closure (browser , frame , node) ; } } fn on_process_message_received (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , source_process : ProcessId , message : Option < & mut ProcessMessage >) -> :: std :: os :: raw :: c_int { match & self . builder . on_process_message_received { Some (closure) => closure (browser , frame , source_process , message) , None => Default :: default () , } } } }
 
 /// See [`_cef_resource_bundle_handler_t`] for more documentation.
 #[derive(Clone)]
@@ -33269,6 +37024,64 @@ impl From<ResourceBundleHandler> for *mut _cef_resource_bundle_handler_t {
         object
     }
 }
+impl ResourceBundleHandler {
+    #[doc = "Create a [`ResourceBundleHandlerBuilder`] to implement [`ResourceBundleHandler`] with closures."]
+    pub fn builder() -> ResourceBundleHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`ResourceBundleHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplResourceBundleHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct ResourceBundleHandlerBuilder {
+    localized_string: Option<
+        std::rc::Rc<dyn Fn(::std::os::raw::c_int, Option<&mut CefString>) -> ::std::os::raw::c_int>,
+    >,
+    data_resource: Option<
+        std::rc::Rc<dyn Fn(::std::os::raw::c_int, Option<&mut Vec<u8>>) -> ::std::os::raw::c_int>,
+    >,
+    data_resource_for_scale: Option<
+        std::rc::Rc<
+            dyn Fn(
+                ::std::os::raw::c_int,
+                ScaleFactor,
+                Option<&mut Vec<u8>>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+}
+impl ResourceBundleHandlerBuilder {
+    #[doc = "Implement [`ImplResourceBundleHandler::localized_string`] with a closure."]
+    pub fn localized_string(
+        mut self,
+        closure: impl Fn(::std::os::raw::c_int, Option<&mut CefString>) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.localized_string = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResourceBundleHandler::data_resource`] with a closure."]
+    pub fn data_resource(
+        mut self,
+        closure: impl Fn(::std::os::raw::c_int, Option<&mut Vec<u8>>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.data_resource = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplResourceBundleHandler::data_resource_for_scale`] with a closure."]
+    pub fn data_resource_for_scale(
+        mut self,
+        closure: impl Fn(::std::os::raw::c_int, ScaleFactor, Option<&mut Vec<u8>>) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.data_resource_for_scale = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`ResourceBundleHandler`] which calls the closures."]
+    pub fn build(self) -> ResourceBundleHandler {
+        BuiltResourceBundleHandler::new(self)
+    }
+}
+wrap_resource_bundle_handler! { struct BuiltResourceBundleHandler { builder : ResourceBundleHandlerBuilder , } impl ResourceBundleHandler { fn localized_string (& self , string_id : :: std :: os :: raw :: c_int , string : Option < & mut CefString >) -> :: std :: os :: raw :: c_int { match & self . builder . localized_string { Some (closure) => closure (string_id , string) , None => Default :: default () , } } fn data_resource (& self , resource_id : :: std :: os :: raw :: c_int , data : Option < & mut Vec < u8 >>) -> :: std :: os :: raw :: c_int { match & self . builder . data_resource { Some (closure) => closure (resource_id , data) , None => Default :: default () , } } fn data_resource_for_scale (& self , resource_id : :: std :: os :: raw :: c_int , scale_factor : ScaleFactor , data : Option < & mut Vec < u8 >>) -> :: std :: os :: raw :: c_int { match & self . builder . data_resource_for_scale { Some (closure) => closure (resource_id , scale_factor , data) , None => Default :: default () , } } } }
 
 /// See [`_cef_scheme_registrar_t`] for more documentation.
 #[derive(Clone, Copy)]
@@ -33528,6 +37341,47 @@ impl From<SchemeHandlerFactory> for *mut _cef_scheme_handler_factory_t {
         object
     }
 }
+impl SchemeHandlerFactory {
+    #[doc = "Create a [`SchemeHandlerFactoryBuilder`] to implement [`SchemeHandlerFactory`] with closures."]
+    pub fn builder() -> SchemeHandlerFactoryBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`SchemeHandlerFactory`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplSchemeHandlerFactory`] trait."]
+#[derive(Clone, Default)]
+pub struct SchemeHandlerFactoryBuilder {
+    create: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&CefString>,
+                Option<&mut Request>,
+            ) -> Option<ResourceHandler>,
+        >,
+    >,
+}
+impl SchemeHandlerFactoryBuilder {
+    #[doc = "Implement [`ImplSchemeHandlerFactory::create`] with a closure."]
+    pub fn create(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Browser>,
+                Option<&mut Frame>,
+                Option<&CefString>,
+                Option<&mut Request>,
+            ) -> Option<ResourceHandler>
+            + 'static,
+    ) -> Self {
+        self.create = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`SchemeHandlerFactory`] which calls the closures."]
+    pub fn build(self) -> SchemeHandlerFactory {
+        BuiltSchemeHandlerFactory::new(self)
+    }
+}
+wrap_scheme_handler_factory! { struct BuiltSchemeHandlerFactory { builder : SchemeHandlerFactoryBuilder , } impl SchemeHandlerFactory { fn create (& self , browser : Option < & mut Browser > , frame : Option < & mut Frame > , scheme_name : Option < & CefString > , request : Option < & mut Request >) -> Option < ResourceHandler > { match & self . builder . create { Some (closure) => closure (browser , frame , scheme_name , request) , None => Default :: default () , } } } }
 
 /// See [`_cef_app_t`] for more documentation.
 #[derive(Clone)]
@@ -33775,6 +37629,69 @@ impl From<App> for *mut _cef_app_t {
         object
     }
 }
+impl App {
+    #[doc = "Create a [`AppBuilder`] to implement [`App`] with closures."]
+    pub fn builder() -> AppBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`App`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplApp`] trait."]
+#[derive(Clone, Default)]
+pub struct AppBuilder {
+    on_before_command_line_processing:
+        Option<std::rc::Rc<dyn Fn(Option<&CefString>, Option<&mut CommandLine>)>>,
+    on_register_custom_schemes: Option<std::rc::Rc<dyn Fn(Option<&mut SchemeRegistrar>)>>,
+    resource_bundle_handler: Option<std::rc::Rc<dyn Fn() -> Option<ResourceBundleHandler>>>,
+    browser_process_handler: Option<std::rc::Rc<dyn Fn() -> Option<BrowserProcessHandler>>>,
+    render_process_handler: Option<std::rc::Rc<dyn Fn() -> Option<RenderProcessHandler>>>,
+}
+impl AppBuilder {
+    #[doc = "Implement [`ImplApp::on_before_command_line_processing`] with a closure."]
+    pub fn on_before_command_line_processing(
+        mut self,
+        closure: impl Fn(Option<&CefString>, Option<&mut CommandLine>) + 'static,
+    ) -> Self {
+        self.on_before_command_line_processing = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplApp::on_register_custom_schemes`] with a closure."]
+    pub fn on_register_custom_schemes(
+        mut self,
+        closure: impl Fn(Option<&mut SchemeRegistrar>) + 'static,
+    ) -> Self {
+        self.on_register_custom_schemes = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplApp::resource_bundle_handler`] with a closure."]
+    pub fn resource_bundle_handler(
+        mut self,
+        closure: impl Fn() -> Option<ResourceBundleHandler> + 'static,
+    ) -> Self {
+        self.resource_bundle_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplApp::browser_process_handler`] with a closure."]
+    pub fn browser_process_handler(
+        mut self,
+        closure: impl Fn() -> Option<BrowserProcessHandler> + 'static,
+    ) -> Self {
+        self.browser_process_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplApp::render_process_handler`] with a closure."]
+    pub fn render_process_handler(
+        mut self,
+        closure: impl Fn() -> Option<RenderProcessHandler> + 'static,
+    ) -> Self {
+        self.render_process_handler = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`App`] which calls the closures."]
+    pub fn build(self) -> App {
+        BuiltApp::new(self)
+    }
+}
+wrap_app! { struct BuiltApp { builder : AppBuilder , } impl App { fn on_before_command_line_processing (& self , process_type : Option < & CefString > , command_line : Option < & mut CommandLine >) { if let Some (closure) = & self . builder . on_before_command_line_processing { closure (process_type , command_line) ; } } fn on_register_custom_schemes (& self , registrar : Option < & mut SchemeRegistrar >) { if let Some (closure) = & self . builder . on_register_custom_schemes { closure (registrar) ; } } fn resource_bundle_handler (& self) -> Option < ResourceBundleHandler > { match & self . builder . resource_bundle_handler { Some (closure) => closure () , None => Default :: default () , } } fn browser_process_handler (& self) -> Option < BrowserProcessHandler > { match & self . builder . browser_process_handler { Some (closure) => closure () , None => Default :: default () , } } fn render_process_handler (& self) -> Option < RenderProcessHandler > { match & self . builder . render_process_handler { Some (closure) => closure () , None => Default :: default () , } } } }
 
 /// See [`_cef_resource_bundle_t`] for more documentation.
 #[derive(Clone)]
@@ -34697,6 +38614,117 @@ impl From<ServerHandler> for *mut _cef_server_handler_t {
         object
     }
 }
+impl ServerHandler {
+    #[doc = "Create a [`ServerHandlerBuilder`] to implement [`ServerHandler`] with closures."]
+    pub fn builder() -> ServerHandlerBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`ServerHandler`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplServerHandler`] trait."]
+#[derive(Clone, Default)]
+pub struct ServerHandlerBuilder {
+    on_server_created: Option<std::rc::Rc<dyn Fn(Option<&mut Server>)>>,
+    on_server_destroyed: Option<std::rc::Rc<dyn Fn(Option<&mut Server>)>>,
+    on_client_connected: Option<std::rc::Rc<dyn Fn(Option<&mut Server>, ::std::os::raw::c_int)>>,
+    on_client_disconnected: Option<std::rc::Rc<dyn Fn(Option<&mut Server>, ::std::os::raw::c_int)>>,
+    on_http_request: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Server>,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                Option<&mut Request>,
+            ),
+        >,
+    >,
+    on_web_socket_request: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Server>,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                Option<&mut Request>,
+                Option<&mut Callback>,
+            ),
+        >,
+    >,
+    on_web_socket_connected:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Server>, ::std::os::raw::c_int)>>,
+    on_web_socket_message:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Server>, ::std::os::raw::c_int, Option<&[u8]>)>>,
+}
+impl ServerHandlerBuilder {
+    #[doc = "Implement [`ImplServerHandler::on_server_created`] with a closure."]
+    pub fn on_server_created(mut self, closure: impl Fn(Option<&mut Server>) + 'static) -> Self {
+        self.on_server_created = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplServerHandler::on_server_destroyed`] with a closure."]
+    pub fn on_server_destroyed(mut self, closure: impl Fn(Option<&mut Server>) + 'static) -> Self {
+        self.on_server_destroyed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplServerHandler::on_client_connected`] with a closure."]
+    pub fn on_client_connected(
+        mut self,
+        closure: impl Fn(Option<&mut Server>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_client_connected = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplServerHandler::on_client_disconnected`] with a closure."]
+    pub fn on_client_disconnected(
+        mut self,
+        closure: impl Fn(Option<&mut Server>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_client_disconnected = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplServerHandler::on_http_request`] with a closure."]
+    pub fn on_http_request(
+        mut self,
+        closure: impl Fn(Option<&mut Server>, ::std::os::raw::c_int, Option<&CefString>, Option<&mut Request>)
+            + 'static,
+    ) -> Self {
+        self.on_http_request = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplServerHandler::on_web_socket_request`] with a closure."]
+    pub fn on_web_socket_request(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Server>,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                Option<&mut Request>,
+                Option<&mut Callback>,
+            ) + 'static,
+    ) -> Self {
+        self.on_web_socket_request = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplServerHandler::on_web_socket_connected`] with a closure."]
+    pub fn on_web_socket_connected(
+        mut self,
+        closure: impl Fn(Option<&mut Server>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_web_socket_connected = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplServerHandler::on_web_socket_message`] with a closure."]
+    pub fn on_web_socket_message(
+        mut self,
+        closure: impl Fn(Option<&mut Server>, ::std::os::raw::c_int, Option<&[u8]>) + 'static,
+    ) -> Self {
+        self.on_web_socket_message = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`ServerHandler`] which calls the closures."]
+    pub fn build(self) -> ServerHandler {
+        BuiltServerHandler::new(self)
+    }
+}
+wrap_server_handler! { struct BuiltServerHandler { builder : ServerHandlerBuilder , } impl ServerHandler { fn on_server_created (& self , server : Option < & mut Server >) { if let Some (closure) = & self . builder . on_server_created { closure (server) ; } } fn on_server_destroyed (& self , server : Option < & mut Server >) { if let Some (closure) = & self . builder . on_server_destroyed { closure (server) ; } } fn on_client_connected (& self , server : Option < & mut Server > , connection_id : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_client_connected { closure (server , connection_id) ; } } fn on_client_disconnected (& self , server : Option < & mut Server > , connection_id : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_client_disconnected { closure (server , connection_id) ; } } fn on_http_request (& self , server : Option < & mut Server > , connection_id : :: std :: os :: raw :: c_int , client_address : Option < & CefString > , request : Option < & mut Request >) { if let Some (closure) = & self . builder . on_http_request { closure (server , connection_id , client_address , request) ; } } fn on_web_socket_request (& self , server : Option < & mut Server > , connection_id : :: std :: os :: raw :: c_int , client_address : Option < & CefString > , request : Option < & mut Request > , callback : Option < & mut Callback >) { if let Some (closure) = & self . builder . on_web_socket_request { closure (server , connection_id , client_address , request , callback) ; } } fn on_web_socket_connected (& self , server : Option < & mut Server > , connection_id : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_web_socket_connected { closure (server , connection_id) ; } } fn on_web_socket_message (& self , server : Option < & mut Server > , connection_id : :: std :: os :: raw :: c_int , data : Option < & [u8] >) { if let Some (closure) = & self . builder . on_web_socket_message { closure (server , connection_id , data) ; } } } }
 
 /// See [`_cef_shared_process_message_builder_t`] for more documentation.
 #[derive(Clone)]
@@ -35152,6 +39180,32 @@ impl From<EndTracingCallback> for *mut _cef_end_tracing_callback_t {
         object
     }
 }
+impl EndTracingCallback {
+    #[doc = "Create a [`EndTracingCallbackBuilder`] to implement [`EndTracingCallback`] with closures."]
+    pub fn builder() -> EndTracingCallbackBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`EndTracingCallback`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplEndTracingCallback`] trait."]
+#[derive(Clone, Default)]
+pub struct EndTracingCallbackBuilder {
+    on_end_tracing_complete: Option<std::rc::Rc<dyn Fn(Option<&CefString>)>>,
+}
+impl EndTracingCallbackBuilder {
+    #[doc = "Implement [`ImplEndTracingCallback::on_end_tracing_complete`] with a closure."]
+    pub fn on_end_tracing_complete(
+        mut self,
+        closure: impl Fn(Option<&CefString>) + 'static,
+    ) -> Self {
+        self.on_end_tracing_complete = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`EndTracingCallback`] which calls the closures."]
+    pub fn build(self) -> EndTracingCallback {
+        BuiltEndTracingCallback::new(self)
+    }
+}
+wrap_end_tracing_callback! { struct BuiltEndTracingCallback { builder : EndTracingCallbackBuilder , } impl EndTracingCallback { fn on_end_tracing_complete (& self , tracing_file : Option < & CefString >) { if let Some (closure) = & self . builder . on_end_tracing_complete { closure (tracing_file) ; } } } }
 
 /// See [`_cef_urlrequest_t`] for more documentation.
 #[derive(Clone)]
@@ -35634,6 +39688,87 @@ impl From<UrlrequestClient> for *mut _cef_urlrequest_client_t {
         object
     }
 }
+impl UrlrequestClient {
+    #[doc = "Create a [`UrlrequestClientBuilder`] to implement [`UrlrequestClient`] with closures."]
+    pub fn builder() -> UrlrequestClientBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`UrlrequestClient`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplUrlrequestClient`] trait."]
+#[derive(Clone, Default)]
+pub struct UrlrequestClientBuilder {
+    on_request_complete: Option<std::rc::Rc<dyn Fn(Option<&mut Urlrequest>)>>,
+    on_upload_progress: Option<std::rc::Rc<dyn Fn(Option<&mut Urlrequest>, i64, i64)>>,
+    on_download_progress: Option<std::rc::Rc<dyn Fn(Option<&mut Urlrequest>, i64, i64)>>,
+    on_download_data: Option<std::rc::Rc<dyn Fn(Option<&mut Urlrequest>, *const u8, usize)>>,
+    auth_credentials: Option<
+        std::rc::Rc<
+            dyn Fn(
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                Option<&CefString>,
+                Option<&mut AuthCallback>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+}
+impl UrlrequestClientBuilder {
+    #[doc = "Implement [`ImplUrlrequestClient::on_request_complete`] with a closure."]
+    pub fn on_request_complete(
+        mut self,
+        closure: impl Fn(Option<&mut Urlrequest>) + 'static,
+    ) -> Self {
+        self.on_request_complete = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplUrlrequestClient::on_upload_progress`] with a closure."]
+    pub fn on_upload_progress(
+        mut self,
+        closure: impl Fn(Option<&mut Urlrequest>, i64, i64) + 'static,
+    ) -> Self {
+        self.on_upload_progress = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplUrlrequestClient::on_download_progress`] with a closure."]
+    pub fn on_download_progress(
+        mut self,
+        closure: impl Fn(Option<&mut Urlrequest>, i64, i64) + 'static,
+    ) -> Self {
+        self.on_download_progress = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplUrlrequestClient::on_download_data`] with a closure."]
+    pub fn on_download_data(
+        mut self,
+        closure: impl Fn(Option<&mut Urlrequest>, *const u8, usize) + 'static,
+    ) -> Self {
+        self.on_download_data = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplUrlrequestClient::auth_credentials`] with a closure."]
+    pub fn auth_credentials(
+        mut self,
+        closure: impl Fn(
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                ::std::os::raw::c_int,
+                Option<&CefString>,
+                Option<&CefString>,
+                Option<&mut AuthCallback>,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.auth_credentials = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`UrlrequestClient`] which calls the closures."]
+    pub fn build(self) -> UrlrequestClient {
+        BuiltUrlrequestClient::new(self)
+    }
+}
+wrap_urlrequest_client! { struct BuiltUrlrequestClient { builder : UrlrequestClientBuilder , } impl UrlrequestClient { fn on_request_complete (& self , request : Option < & mut Urlrequest >) { if let Some (closure) = & self . builder . on_request_complete { closure (request) ; } } fn on_upload_progress (& self , request : Option < & mut Urlrequest > , current : i64 , total : i64) { if let Some (closure) = & self . builder . on_upload_progress { closure (request , current , total) ; } } fn on_download_progress (& self , request : Option < & mut Urlrequest > , current : i64 , total : i64) { if let Some (closure) = & self . builder . on_download_progress { closure (request , current , total) ; } } fn on_download_data (& self , request : Option < & mut Urlrequest > , data : * const u8 , data_length : usize) { if let Some (closure) = & self . builder . on_download_data { closure (request , data , data_length) ; } } fn auth_credentials (& self , is_proxy : :: std :: os :: raw :: c_int , host : Option < & CefString > , port : :: std :: os :: raw :: c_int , realm : Option < & CefString > , scheme : Option < & CefString > , callback : Option < & mut AuthCallback >) -> :: std :: os :: raw :: c_int { match & self . builder . auth_credentials { Some (closure) => closure (is_proxy , host , port , realm , scheme , callback) , None => Default :: default () , } } } }
 
 /// See [`_cef_waitable_event_t`] for more documentation.
 #[derive(Clone)]
@@ -37167,6 +41302,108 @@ impl From<ViewDelegate> for *mut _cef_view_delegate_t {
         object
     }
 }
+impl ViewDelegate {
+    #[doc = "Create a [`ViewDelegateBuilder`] to implement [`ViewDelegate`] with closures."]
+    pub fn builder() -> ViewDelegateBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`ViewDelegate`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplViewDelegate`] trait."]
+#[derive(Clone, Default)]
+pub struct ViewDelegateBuilder {
+    preferred_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    minimum_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    maximum_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    height_for_width: Option<
+        std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int) -> ::std::os::raw::c_int>,
+    >,
+    on_parent_view_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>)>>,
+    on_child_view_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>)>>,
+    on_window_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int)>>,
+    on_layout_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>, Option<&Rect>)>>,
+    on_focus: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_blur: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_theme_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+}
+impl ViewDelegateBuilder {
+    #[doc = "Implement [`ImplViewDelegate::preferred_size`] with a closure."]
+    pub fn preferred_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.preferred_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplViewDelegate::minimum_size`] with a closure."]
+    pub fn minimum_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.minimum_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplViewDelegate::maximum_size`] with a closure."]
+    pub fn maximum_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.maximum_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplViewDelegate::height_for_width`] with a closure."]
+    pub fn height_for_width(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.height_for_width = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplViewDelegate::on_parent_view_changed`] with a closure."]
+    pub fn on_parent_view_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>) + 'static,
+    ) -> Self {
+        self.on_parent_view_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplViewDelegate::on_child_view_changed`] with a closure."]
+    pub fn on_child_view_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>) + 'static,
+    ) -> Self {
+        self.on_child_view_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplViewDelegate::on_window_changed`] with a closure."]
+    pub fn on_window_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_window_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplViewDelegate::on_layout_changed`] with a closure."]
+    pub fn on_layout_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, Option<&Rect>) + 'static,
+    ) -> Self {
+        self.on_layout_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplViewDelegate::on_focus`] with a closure."]
+    pub fn on_focus(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_focus = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplViewDelegate::on_blur`] with a closure."]
+    pub fn on_blur(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_blur = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplViewDelegate::on_theme_changed`] with a closure."]
+    pub fn on_theme_changed(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_theme_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`ViewDelegate`] which calls the closures."]
+    pub fn build(self) -> ViewDelegate {
+        BuiltViewDelegate::new(self)
+    }
+}
+wrap_view_delegate! { struct BuiltViewDelegate { builder : ViewDelegateBuilder , } impl ViewDelegate { fn preferred_size (& self , view : Option < & mut View >) -> Size { match & self . builder . preferred_size { Some (closure) => closure (view) , None => Default :: default () , } } fn minimum_size (& self , view : Option < & mut View >) -> Size { match & self . builder . minimum_size { Some (closure) => closure (view) , None => Default :: default () , } } fn maximum_size (& self , view : Option < & mut View >) -> Size { match & self . builder . maximum_size { Some (closure) => closure (view) , None => Default :: default () , } } fn height_for_width (& self , view : Option < & mut View > , width : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . height_for_width { Some (closure) => closure (view , width) , None => Default :: default () , } } fn on_parent_view_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int , parent : Option < & mut View >) { if let Some (closure) = & self . builder . on_parent_view_changed { closure (view , added , parent) ; } } fn on_child_view_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int , child : Option < & mut View >) { if let Some (closure) = & self . builder . on_child_view_changed { closure (view , added , child) ; } } fn on_window_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_window_changed { closure (view , added) ; } } fn on_layout_changed (& self , view : Option < & mut View > , new_bounds : Option < & Rect >) { if let Some (closure) = & self . builder . on_layout_changed { closure (view , new_bounds) ; } } fn on_focus (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_focus { closure (view) ; } } fn on_blur (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_blur { closure (view) ; } } fn on_theme_changed (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_theme_changed { closure (view) ; } } } }
 
 /// See [`_cef_browser_view_delegate_t`] for more documentation.
 #[derive(Clone)]
@@ -37780,6 +42017,219 @@ impl From<BrowserViewDelegate> for *mut _cef_browser_view_delegate_t {
         object
     }
 }
+impl BrowserViewDelegate {
+    #[doc = "Create a [`BrowserViewDelegateBuilder`] to implement [`BrowserViewDelegate`] with closures."]
+    pub fn builder() -> BrowserViewDelegateBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`BrowserViewDelegate`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplBrowserViewDelegate`] trait."]
+#[derive(Clone, Default)]
+pub struct BrowserViewDelegateBuilder {
+    preferred_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    minimum_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    maximum_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    height_for_width: Option<
+        std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int) -> ::std::os::raw::c_int>,
+    >,
+    on_parent_view_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>)>>,
+    on_child_view_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>)>>,
+    on_window_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int)>>,
+    on_layout_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>, Option<&Rect>)>>,
+    on_focus: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_blur: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_theme_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_browser_created: Option<std::rc::Rc<dyn Fn(Option<&mut BrowserView>, Option<&mut Browser>)>>,
+    on_browser_destroyed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut BrowserView>, Option<&mut Browser>)>>,
+    delegate_for_popup_browser_view: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut BrowserView>,
+                Option<&BrowserSettings>,
+                Option<&mut Client>,
+                ::std::os::raw::c_int,
+            ) -> Option<BrowserViewDelegate>,
+        >,
+    >,
+    on_popup_browser_view_created: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut BrowserView>,
+                Option<&mut BrowserView>,
+                ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+    chrome_toolbar_type: Option<std::rc::Rc<dyn Fn(Option<&mut BrowserView>) -> ChromeToolbarType>>,
+    use_frameless_window_for_picture_in_picture:
+        Option<std::rc::Rc<dyn Fn(Option<&mut BrowserView>) -> ::std::os::raw::c_int>>,
+    on_gesture_command: Option<
+        std::rc::Rc<dyn Fn(Option<&mut BrowserView>, GestureCommand) -> ::std::os::raw::c_int>,
+    >,
+    browser_runtime_style: Option<std::rc::Rc<dyn Fn() -> RuntimeStyle>>,
+    allow_move_for_picture_in_picture:
+        Option<std::rc::Rc<dyn Fn(Option<&mut BrowserView>) -> ::std::os::raw::c_int>>,
+}
+impl BrowserViewDelegateBuilder {
+    #[doc = "Implement [`ImplBrowserViewDelegate::preferred_size`] with a closure."]
+    pub fn preferred_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.preferred_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::minimum_size`] with a closure."]
+    pub fn minimum_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.minimum_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::maximum_size`] with a closure."]
+    pub fn maximum_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.maximum_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::height_for_width`] with a closure."]
+    pub fn height_for_width(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.height_for_width = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::on_parent_view_changed`] with a closure."]
+    pub fn on_parent_view_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>) + 'static,
+    ) -> Self {
+        self.on_parent_view_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::on_child_view_changed`] with a closure."]
+    pub fn on_child_view_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>) + 'static,
+    ) -> Self {
+        self.on_child_view_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::on_window_changed`] with a closure."]
+    pub fn on_window_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_window_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::on_layout_changed`] with a closure."]
+    pub fn on_layout_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, Option<&Rect>) + 'static,
+    ) -> Self {
+        self.on_layout_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::on_focus`] with a closure."]
+    pub fn on_focus(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_focus = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::on_blur`] with a closure."]
+    pub fn on_blur(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_blur = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::on_theme_changed`] with a closure."]
+    pub fn on_theme_changed(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_theme_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::on_browser_created`] with a closure."]
+    pub fn on_browser_created(
+        mut self,
+        closure: impl Fn(Option<&mut BrowserView>, Option<&mut Browser>) + 'static,
+    ) -> Self {
+        self.on_browser_created = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::on_browser_destroyed`] with a closure."]
+    pub fn on_browser_destroyed(
+        mut self,
+        closure: impl Fn(Option<&mut BrowserView>, Option<&mut Browser>) + 'static,
+    ) -> Self {
+        self.on_browser_destroyed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::delegate_for_popup_browser_view`] with a closure."]
+    pub fn delegate_for_popup_browser_view(
+        mut self,
+        closure: impl Fn(
+                Option<&mut BrowserView>,
+                Option<&BrowserSettings>,
+                Option<&mut Client>,
+                ::std::os::raw::c_int,
+            ) -> Option<BrowserViewDelegate>
+            + 'static,
+    ) -> Self {
+        self.delegate_for_popup_browser_view = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::on_popup_browser_view_created`] with a closure."]
+    pub fn on_popup_browser_view_created(
+        mut self,
+        closure: impl Fn(
+                Option<&mut BrowserView>,
+                Option<&mut BrowserView>,
+                ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.on_popup_browser_view_created = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::chrome_toolbar_type`] with a closure."]
+    pub fn chrome_toolbar_type(
+        mut self,
+        closure: impl Fn(Option<&mut BrowserView>) -> ChromeToolbarType + 'static,
+    ) -> Self {
+        self.chrome_toolbar_type = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::use_frameless_window_for_picture_in_picture`] with a closure."]
+    pub fn use_frameless_window_for_picture_in_picture(
+        mut self,
+        closure: impl Fn(Option<&mut BrowserView>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.use_frameless_window_for_picture_in_picture = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::on_gesture_command`] with a closure."]
+    pub fn on_gesture_command(
+        mut self,
+        closure: impl Fn(Option<&mut BrowserView>, GestureCommand) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.on_gesture_command = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::browser_runtime_style`] with a closure."]
+    pub fn browser_runtime_style(mut self, closure: impl Fn() -> RuntimeStyle + 'static) -> Self {
+        self.browser_runtime_style = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplBrowserViewDelegate::allow_move_for_picture_in_picture`] with a closure."]
+    pub fn allow_move_for_picture_in_picture(
+        mut self,
+        closure: impl Fn(Option<&mut BrowserView>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.allow_move_for_picture_in_picture = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`BrowserViewDelegate`] which calls the closures."]
+    pub fn build(self) -> BrowserViewDelegate {
+        BuiltBrowserViewDelegate::new(self)
+    }
+}
+wrap_browser_view_delegate! { struct BuiltBrowserViewDelegate { builder : BrowserViewDelegateBuilder , } impl ViewDelegate { fn preferred_size (& self , view : Option < & mut View >) -> Size { match & self . builder . preferred_size { Some (closure) => closure (view) , None => Default :: default () , } } fn minimum_size (& self , view : Option < & mut View >) -> Size { match & self . builder . minimum_size { Some (closure) => closure (view) , None => Default :: default () , } } fn maximum_size (& self , view : Option < & mut View >) -> Size { match & self . builder . maximum_size { Some (closure) => closure (view) , None => Default :: default () , } } fn height_for_width (& self , view : Option < & mut View > , width : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . height_for_width { Some (closure) => closure (view , width) , None => Default :: default () , } } fn on_parent_view_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int , parent : Option < & mut View >) { if let Some (closure) = & self . builder . on_parent_view_changed { closure (view , added , parent) ; } } fn on_child_view_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int , child : Option < & mut View >) { if let Some (closure) = & self . builder . on_child_view_changed { closure (view , added , child) ; } } fn on_window_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_window_changed { closure (view , added) ; } } fn on_layout_changed (& self , view : Option < & mut View > , new_bounds : Option < & Rect >) { if let Some (closure) = & self . builder . on_layout_changed { closure (view , new_bounds) ; } } fn on_focus (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_focus { closure (view) ; } } fn on_blur (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_blur { closure (view) ; } } fn on_theme_changed (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_theme_changed { closure (view) ; } } } impl BrowserViewDelegate { fn on_browser_created (& self , browser_view : Option < & mut BrowserView > , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_browser_created { closure (browser_view , browser) ; } } fn on_browser_destroyed (& self , browser_view : Option < & mut BrowserView > , browser : Option < & mut Browser >) { if let Some (closure) = & self . builder . on_browser_destroyed { closure (browser_view , browser) ; } } fn delegate_for_popup_browser_view (& self , browser_view : Option < & mut BrowserView > , settings : Option < & BrowserSettings > , client : Option < & mut Client > , is_devtools : :: std :: os :: raw :: c_int) -> Option < BrowserViewDelegate > { match & self . builder . delegate_for_popup_browser_view { Some (closure) => closure (browser_view , settings , client , is_devtools) , None => Default :: default () , } } fn on_popup_browser_view_created (& self , browser_view : Option < & mut BrowserView > , popup_browser_view : Option < & mut BrowserView > , is_devtools : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . on_popup_browser_view_created { Some (closure) => closure (browser_view , popup_browser_view , is_devtools) , None => Default :: default () , } } fn chrome_toolbar_type (& self , browser_view : Option < & mut BrowserView >) -> ChromeToolbarType { match & self . builder . chrome_toolbar_type { Some (closure) => closure (browser_view) , None => Default :: default () , } } fn use_frameless_window_for_picture_in_picture (& self , browser_view : Option < & mut BrowserView >) -> :: std :: os :: raw :: c_int { match & self . builder . use_frameless_window_for_picture_in_picture { Some (closure) => closure (browser_view) , None => Default :: default () , } } fn on_gesture_command (& self , browser_view : Option < & mut BrowserView > , gesture_command : GestureCommand) -> :: std :: os :: raw :: c_int { match & self . builder . on_gesture_command { Some (closure) => closure (browser_view , gesture_command) , None => Default :: default () , } } fn browser_runtime_style (& self) -> RuntimeStyle { match & self . builder . browser_runtime_style { Some (closure) => closure () , None => Default :: default () , } } fn allow_move_for_picture_in_picture (& self , browser_view : Option < & mut BrowserView >) -> :: std :: os :: raw :: c_int { match & self . builder . allow_move_for_picture_in_picture { Some (closure) => closure (browser_view) , None => Default :: default () , } } } }
 
 /// See [`_cef_view_t`] for more documentation.
 #[derive(Clone)]
@@ -39422,6 +43872,123 @@ impl From<ButtonDelegate> for *mut _cef_button_delegate_t {
         object
     }
 }
+impl ButtonDelegate {
+    #[doc = "Create a [`ButtonDelegateBuilder`] to implement [`ButtonDelegate`] with closures."]
+    pub fn builder() -> ButtonDelegateBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`ButtonDelegate`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplButtonDelegate`] trait."]
+#[derive(Clone, Default)]
+pub struct ButtonDelegateBuilder {
+    preferred_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    minimum_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    maximum_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    height_for_width: Option<
+        std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int) -> ::std::os::raw::c_int>,
+    >,
+    on_parent_view_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>)>>,
+    on_child_view_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>)>>,
+    on_window_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int)>>,
+    on_layout_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>, Option<&Rect>)>>,
+    on_focus: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_blur: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_theme_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_button_pressed: Option<std::rc::Rc<dyn Fn(Option<&mut Button>)>>,
+    on_button_state_changed: Option<std::rc::Rc<dyn Fn(Option<&mut Button>)>>,
+}
+impl ButtonDelegateBuilder {
+    #[doc = "Implement [`ImplButtonDelegate::preferred_size`] with a closure."]
+    pub fn preferred_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.preferred_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplButtonDelegate::minimum_size`] with a closure."]
+    pub fn minimum_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.minimum_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplButtonDelegate::maximum_size`] with a closure."]
+    pub fn maximum_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.maximum_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplButtonDelegate::height_for_width`] with a closure."]
+    pub fn height_for_width(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.height_for_width = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplButtonDelegate::on_parent_view_changed`] with a closure."]
+    pub fn on_parent_view_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>) + 'static,
+    ) -> Self {
+        self.on_parent_view_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplButtonDelegate::on_child_view_changed`] with a closure."]
+    pub fn on_child_view_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>) + 'static,
+    ) -> Self {
+        self.on_child_view_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplButtonDelegate::on_window_changed`] with a closure."]
+    pub fn on_window_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_window_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplButtonDelegate::on_layout_changed`] with a closure."]
+    pub fn on_layout_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, Option<&Rect>) + 'static,
+    ) -> Self {
+        self.on_layout_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplButtonDelegate::on_focus`] with a closure."]
+    pub fn on_focus(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_focus = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplButtonDelegate::on_blur`] with a closure."]
+    pub fn on_blur(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_blur = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplButtonDelegate::on_theme_changed`] with a closure."]
+    pub fn on_theme_changed(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_theme_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplButtonDelegate::on_button_pressed`] with a closure."]
+    pub fn on_button_pressed(mut self, closure: impl Fn(Option<&mut Button>) + 'static) -> Self {
+        self.on_button_pressed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplButtonDelegate::on_button_state_changed`] with a closure."]
+    pub fn on_button_state_changed(
+        mut self,
+        closure: impl Fn(Option<&mut Button>) + 'static,
+    ) -> Self {
+        self.on_button_state_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`ButtonDelegate`] which calls the closures."]
+    pub fn build(self) -> ButtonDelegate {
+        BuiltButtonDelegate::new(self)
+    }
+}
+wrap_button_delegate! { struct BuiltButtonDelegate { builder : ButtonDelegateBuilder , } impl ViewDelegate { fn preferred_size (& self , view : Option < & mut View >) -> Size { match & self . builder . preferred_size { Some (closure) => closure (view) , None => Default :: default () , } } fn minimum_size (& self , view : Option < & mut View >) -> Size { match & self . builder . minimum_size { Some (closure) => closure (view) , None => Default :: default () , } } fn maximum_size (& self , view : Option < & mut View >) -> Size { match & self . builder . maximum_size { Some (closure) => closure (view) , None => Default :: default () , } } fn height_for_width (& self , view : Option < & mut View > , width : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . height_for_width { Some (closure) => closure (view , width) , None => Default :: default () , } } fn on_parent_view_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int , parent : Option < & mut View >) { if let Some (closure) = & self . builder . on_parent_view_changed { closure (view , added , parent) ; } } fn on_child_view_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int , child : Option < & mut View >) { if let Some (closure) = & self . builder . on_child_view_changed { closure (view , added , child) ; } } fn on_window_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_window_changed { closure (view , added) ; } } fn on_layout_changed (& self , view : Option < & mut View > , new_bounds : Option < & Rect >) { if let Some (closure) = & self . builder . on_layout_changed { closure (view , new_bounds) ; } } fn on_focus (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_focus { closure (view) ; } } fn on_blur (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_blur { closure (view) ; } } fn on_theme_changed (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_theme_changed { closure (view) ; } } } impl ButtonDelegate { fn on_button_pressed (& self , button : Option < & mut Button >) { if let Some (closure) = & self . builder . on_button_pressed { closure (button) ; } } fn on_button_state_changed (& self , button : Option < & mut Button >) { if let Some (closure) = & self . builder . on_button_state_changed { closure (button) ; } } } }
 
 /// See [`_cef_display_t`] for more documentation.
 #[derive(Clone)]
@@ -40323,6 +44890,137 @@ impl From<MenuButtonDelegate> for *mut _cef_menu_button_delegate_t {
         object
     }
 }
+impl MenuButtonDelegate {
+    #[doc = "Create a [`MenuButtonDelegateBuilder`] to implement [`MenuButtonDelegate`] with closures."]
+    pub fn builder() -> MenuButtonDelegateBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`MenuButtonDelegate`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplMenuButtonDelegate`] trait."]
+#[derive(Clone, Default)]
+pub struct MenuButtonDelegateBuilder {
+    preferred_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    minimum_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    maximum_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    height_for_width: Option<
+        std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int) -> ::std::os::raw::c_int>,
+    >,
+    on_parent_view_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>)>>,
+    on_child_view_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>)>>,
+    on_window_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int)>>,
+    on_layout_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>, Option<&Rect>)>>,
+    on_focus: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_blur: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_theme_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_button_pressed: Option<std::rc::Rc<dyn Fn(Option<&mut Button>)>>,
+    on_button_state_changed: Option<std::rc::Rc<dyn Fn(Option<&mut Button>)>>,
+    on_menu_button_pressed: Option<
+        std::rc::Rc<
+            dyn Fn(Option<&mut MenuButton>, Option<&Point>, Option<&mut MenuButtonPressedLock>),
+        >,
+    >,
+}
+impl MenuButtonDelegateBuilder {
+    #[doc = "Implement [`ImplMenuButtonDelegate::preferred_size`] with a closure."]
+    pub fn preferred_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.preferred_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuButtonDelegate::minimum_size`] with a closure."]
+    pub fn minimum_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.minimum_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuButtonDelegate::maximum_size`] with a closure."]
+    pub fn maximum_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.maximum_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuButtonDelegate::height_for_width`] with a closure."]
+    pub fn height_for_width(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.height_for_width = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuButtonDelegate::on_parent_view_changed`] with a closure."]
+    pub fn on_parent_view_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>) + 'static,
+    ) -> Self {
+        self.on_parent_view_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuButtonDelegate::on_child_view_changed`] with a closure."]
+    pub fn on_child_view_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>) + 'static,
+    ) -> Self {
+        self.on_child_view_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuButtonDelegate::on_window_changed`] with a closure."]
+    pub fn on_window_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_window_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuButtonDelegate::on_layout_changed`] with a closure."]
+    pub fn on_layout_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, Option<&Rect>) + 'static,
+    ) -> Self {
+        self.on_layout_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuButtonDelegate::on_focus`] with a closure."]
+    pub fn on_focus(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_focus = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuButtonDelegate::on_blur`] with a closure."]
+    pub fn on_blur(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_blur = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuButtonDelegate::on_theme_changed`] with a closure."]
+    pub fn on_theme_changed(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_theme_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuButtonDelegate::on_button_pressed`] with a closure."]
+    pub fn on_button_pressed(mut self, closure: impl Fn(Option<&mut Button>) + 'static) -> Self {
+        self.on_button_pressed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuButtonDelegate::on_button_state_changed`] with a closure."]
+    pub fn on_button_state_changed(
+        mut self,
+        closure: impl Fn(Option<&mut Button>) + 'static,
+    ) -> Self {
+        self.on_button_state_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplMenuButtonDelegate::on_menu_button_pressed`] with a closure."]
+    pub fn on_menu_button_pressed(
+        mut self,
+        closure: impl Fn(Option<&mut MenuButton>, Option<&Point>, Option<&mut MenuButtonPressedLock>)
+            + 'static,
+    ) -> Self {
+        self.on_menu_button_pressed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`MenuButtonDelegate`] which calls the closures."]
+    pub fn build(self) -> MenuButtonDelegate {
+        BuiltMenuButtonDelegate::new(self)
+    }
+}
+wrap_menu_button_delegate! { struct BuiltMenuButtonDelegate { builder : MenuButtonDelegateBuilder , } impl ViewDelegate { fn preferred_size (& self , view : Option < & mut View >) -> Size { match & self . builder . preferred_size { Some (closure) => closure (view) , None => Default :: default () , } } fn minimum_size (& self , view : Option < & mut View >) -> Size { match & self . builder . minimum_size { Some (closure) => closure (view) , None => Default :: default () , } } fn maximum_size (& self , view : Option < & mut View >) -> Size { match & self . builder . maximum_size { Some (closure) => closure (view) , None => Default :: default () , } } fn height_for_width (& self , view : Option < & mut View > , width : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . height_for_width { Some (closure) => closure (view , width) , None => Default :: default () , } } fn on_parent_view_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int , parent : Option < & mut View >) { if let Some (closure) = & self . builder . on_parent_view_changed { closure (view , added , parent) ; } } fn on_child_view_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int , child : Option < & mut View >) { if let Some (closure) = & self . builder . on_child_view_changed { closure (view , added , child) ; } } fn on_window_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_window_changed { closure (view , added) ; } } fn on_layout_changed (& self , view : Option < & mut View > , new_bounds : Option < & Rect >) { if let Some (closure) = & self . builder . on_layout_changed { closure (view , new_bounds) ; } } fn on_focus (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_focus { closure (view) ; } } fn on_blur (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_blur { closure (view) ; } } fn on_theme_changed (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_theme_changed { closure (view) ; } } } impl ButtonDelegate { fn on_button_pressed (& self , button : Option < & mut Button >) { if let Some (closure) = & self . builder . on_button_pressed { closure (button) ; } } fn on_button_state_changed (& self , button : Option < & mut Button >) { if let Some (closure) = & self . builder . on_button_state_changed { closure (button) ; } } } impl MenuButtonDelegate { fn on_menu_button_pressed (& self , menu_button : Option < & mut MenuButton > , screen_point : Option < & Point > , button_pressed_lock : Option < & mut MenuButtonPressedLock >) { if let Some (closure) = & self . builder . on_menu_button_pressed { closure (menu_button , screen_point , button_pressed_lock) ; } } } }
 
 /// See [`_cef_menu_button_t`] for more documentation.
 #[derive(Clone)]
@@ -41123,6 +45821,108 @@ impl From<PanelDelegate> for *mut _cef_panel_delegate_t {
         object
     }
 }
+impl PanelDelegate {
+    #[doc = "Create a [`PanelDelegateBuilder`] to implement [`PanelDelegate`] with closures."]
+    pub fn builder() -> PanelDelegateBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`PanelDelegate`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplPanelDelegate`] trait."]
+#[derive(Clone, Default)]
+pub struct PanelDelegateBuilder {
+    preferred_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    minimum_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    maximum_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    height_for_width: Option<
+        std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int) -> ::std::os::raw::c_int>,
+    >,
+    on_parent_view_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>)>>,
+    on_child_view_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>)>>,
+    on_window_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int)>>,
+    on_layout_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>, Option<&Rect>)>>,
+    on_focus: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_blur: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_theme_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+}
+impl PanelDelegateBuilder {
+    #[doc = "Implement [`ImplPanelDelegate::preferred_size`] with a closure."]
+    pub fn preferred_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.preferred_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPanelDelegate::minimum_size`] with a closure."]
+    pub fn minimum_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.minimum_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPanelDelegate::maximum_size`] with a closure."]
+    pub fn maximum_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.maximum_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPanelDelegate::height_for_width`] with a closure."]
+    pub fn height_for_width(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.height_for_width = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPanelDelegate::on_parent_view_changed`] with a closure."]
+    pub fn on_parent_view_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>) + 'static,
+    ) -> Self {
+        self.on_parent_view_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPanelDelegate::on_child_view_changed`] with a closure."]
+    pub fn on_child_view_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>) + 'static,
+    ) -> Self {
+        self.on_child_view_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPanelDelegate::on_window_changed`] with a closure."]
+    pub fn on_window_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_window_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPanelDelegate::on_layout_changed`] with a closure."]
+    pub fn on_layout_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, Option<&Rect>) + 'static,
+    ) -> Self {
+        self.on_layout_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPanelDelegate::on_focus`] with a closure."]
+    pub fn on_focus(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_focus = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPanelDelegate::on_blur`] with a closure."]
+    pub fn on_blur(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_blur = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplPanelDelegate::on_theme_changed`] with a closure."]
+    pub fn on_theme_changed(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_theme_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`PanelDelegate`] which calls the closures."]
+    pub fn build(self) -> PanelDelegate {
+        BuiltPanelDelegate::new(self)
+    }
+}
+wrap_panel_delegate! { struct BuiltPanelDelegate { builder : PanelDelegateBuilder , } impl ViewDelegate { fn preferred_size (& self , view : Option < & mut View >) -> Size { match & self . builder . preferred_size { Some (closure) => closure (view) , None => Default :: default () , } } fn minimum_size (& self , view : Option < & mut View >) -> Size { match & self . builder . minimum_size { Some (closure) => closure (view) , None => Default :: default () , } } fn maximum_size (& self , view : Option < & mut View >) -> Size { match & self . builder . maximum_size { Some (closure) => closure (view) , None => Default :: default () , } } fn height_for_width (& self , view : Option < & mut View > , width : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . height_for_width { Some (closure) => closure (view , width) , None => Default :: default () , } } fn on_parent_view_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int , parent : Option < & mut View >) { if let Some (closure) = & self . builder . on_parent_view_changed { closure (view , added , parent) ; } } fn on_child_view_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int , child : Option < & mut View >) { if let Some (closure) = & self . builder . on_child_view_changed { closure (view , added , child) ; } } fn on_window_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_window_changed { closure (view , added) ; } } fn on_layout_changed (& self , view : Option < & mut View > , new_bounds : Option < & Rect >) { if let Some (closure) = & self . builder . on_layout_changed { closure (view , new_bounds) ; } } fn on_focus (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_focus { closure (view) ; } } fn on_blur (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_blur { closure (view) ; } } fn on_theme_changed (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_theme_changed { closure (view) ; } } } impl PanelDelegate { } }
 
 /// See [`_cef_panel_t`] for more documentation.
 #[derive(Clone)]
@@ -42090,6 +46890,128 @@ impl From<TextfieldDelegate> for *mut _cef_textfield_delegate_t {
         object
     }
 }
+impl TextfieldDelegate {
+    #[doc = "Create a [`TextfieldDelegateBuilder`] to implement [`TextfieldDelegate`] with closures."]
+    pub fn builder() -> TextfieldDelegateBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`TextfieldDelegate`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplTextfieldDelegate`] trait."]
+#[derive(Clone, Default)]
+pub struct TextfieldDelegateBuilder {
+    preferred_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    minimum_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    maximum_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    height_for_width: Option<
+        std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int) -> ::std::os::raw::c_int>,
+    >,
+    on_parent_view_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>)>>,
+    on_child_view_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>)>>,
+    on_window_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int)>>,
+    on_layout_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>, Option<&Rect>)>>,
+    on_focus: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_blur: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_theme_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_key_event: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Textfield>, Option<&KeyEvent>) -> ::std::os::raw::c_int>,
+    >,
+    on_after_user_action: Option<std::rc::Rc<dyn Fn(Option<&mut Textfield>)>>,
+}
+impl TextfieldDelegateBuilder {
+    #[doc = "Implement [`ImplTextfieldDelegate::preferred_size`] with a closure."]
+    pub fn preferred_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.preferred_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplTextfieldDelegate::minimum_size`] with a closure."]
+    pub fn minimum_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.minimum_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplTextfieldDelegate::maximum_size`] with a closure."]
+    pub fn maximum_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.maximum_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplTextfieldDelegate::height_for_width`] with a closure."]
+    pub fn height_for_width(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.height_for_width = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplTextfieldDelegate::on_parent_view_changed`] with a closure."]
+    pub fn on_parent_view_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>) + 'static,
+    ) -> Self {
+        self.on_parent_view_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplTextfieldDelegate::on_child_view_changed`] with a closure."]
+    pub fn on_child_view_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>) + 'static,
+    ) -> Self {
+        self.on_child_view_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplTextfieldDelegate::on_window_changed`] with a closure."]
+    pub fn on_window_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_window_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplTextfieldDelegate::on_layout_changed`] with a closure."]
+    pub fn on_layout_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, Option<&Rect>) + 'static,
+    ) -> Self {
+        self.on_layout_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplTextfieldDelegate::on_focus`] with a closure."]
+    pub fn on_focus(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_focus = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplTextfieldDelegate::on_blur`] with a closure."]
+    pub fn on_blur(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_blur = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplTextfieldDelegate::on_theme_changed`] with a closure."]
+    pub fn on_theme_changed(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_theme_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplTextfieldDelegate::on_key_event`] with a closure."]
+    pub fn on_key_event(
+        mut self,
+        closure: impl Fn(Option<&mut Textfield>, Option<&KeyEvent>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.on_key_event = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplTextfieldDelegate::on_after_user_action`] with a closure."]
+    pub fn on_after_user_action(
+        mut self,
+        closure: impl Fn(Option<&mut Textfield>) + 'static,
+    ) -> Self {
+        self.on_after_user_action = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`TextfieldDelegate`] which calls the closures."]
+    pub fn build(self) -> TextfieldDelegate {
+        BuiltTextfieldDelegate::new(self)
+    }
+}
+wrap_textfield_delegate! { struct BuiltTextfieldDelegate { builder : TextfieldDelegateBuilder , } impl ViewDelegate { fn preferred_size (& self , view : Option < & mut View >) -> Size { match & self . builder . preferred_size { Some (closure) => closure (view) , None => Default :: default () , } } fn minimum_size (& self , view : Option < & mut View >) -> Size { match & self . builder . minimum_size { Some (closure) => closure (view) , None => Default :: default () , } } fn maximum_size (& self , view : Option < & mut View >) -> Size { match & self . builder . maximum_size { Some (closure) => closure (view) , None => Default :: default () , } } fn height_for_width (& self , view : Option < & mut View > , width : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . height_for_width { Some (closure) => closure (view , width) , None => Default :: default () , } } fn on_parent_view_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int , parent : Option < & mut View >) { if let Some (closure) = & self . builder . on_parent_view_changed { closure (view , added , parent) ; } } fn on_child_view_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int , child : Option < & mut View >) { if let Some (closure) = & self . builder . on_child_view_changed { closure (view , added , child) ; } } fn on_window_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_window_changed { closure (view , added) ; } } fn on_layout_changed (& self , view : Option < & mut View > , new_bounds : Option < & Rect >) { if let Some (closure) = & self . builder . on_layout_changed { closure (view , new_bounds) ; } } fn on_focus (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_focus { closure (view) ; } } fn on_blur (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_blur { closure (view) ; } } fn on_theme_changed (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_theme_changed { closure (view) ; } } } impl TextfieldDelegate { fn on_key_event (& self , textfield : Option < & mut Textfield > , event : Option < & KeyEvent >) -> :: std :: os :: raw :: c_int { match & self . builder . on_key_event { Some (closure) => closure (textfield , event) , None => Default :: default () , } } fn on_after_user_action (& self , textfield : Option < & mut Textfield >) { if let Some (closure) = & self . builder . on_after_user_action { closure (textfield) ; } } } }
 
 /// See [`_cef_textfield_t`] for more documentation.
 #[derive(Clone)]
@@ -43811,6 +48733,334 @@ impl From<WindowDelegate> for *mut _cef_window_delegate_t {
         object
     }
 }
+impl WindowDelegate {
+    #[doc = "Create a [`WindowDelegateBuilder`] to implement [`WindowDelegate`] with closures."]
+    pub fn builder() -> WindowDelegateBuilder {
+        Default::default()
+    }
+}
+#[doc = "Builds a [`WindowDelegate`] from closures. Methods without a closure keep the default\nimplementation of the [`ImplWindowDelegate`] trait."]
+#[derive(Clone, Default)]
+pub struct WindowDelegateBuilder {
+    preferred_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    minimum_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    maximum_size: Option<std::rc::Rc<dyn Fn(Option<&mut View>) -> Size>>,
+    height_for_width: Option<
+        std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int) -> ::std::os::raw::c_int>,
+    >,
+    on_parent_view_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>)>>,
+    on_child_view_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>)>>,
+    on_window_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>, ::std::os::raw::c_int)>>,
+    on_layout_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>, Option<&Rect>)>>,
+    on_focus: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_blur: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_theme_changed: Option<std::rc::Rc<dyn Fn(Option<&mut View>)>>,
+    on_window_created: Option<std::rc::Rc<dyn Fn(Option<&mut Window>)>>,
+    on_window_closing: Option<std::rc::Rc<dyn Fn(Option<&mut Window>)>>,
+    on_window_destroyed: Option<std::rc::Rc<dyn Fn(Option<&mut Window>)>>,
+    on_window_activation_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Window>, ::std::os::raw::c_int)>>,
+    on_window_bounds_changed: Option<std::rc::Rc<dyn Fn(Option<&mut Window>, Option<&Rect>)>>,
+    on_window_fullscreen_transition:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Window>, ::std::os::raw::c_int)>>,
+    parent_window: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Window>,
+                Option<&mut ::std::os::raw::c_int>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> Option<Window>,
+        >,
+    >,
+    is_window_modal_dialog:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Window>) -> ::std::os::raw::c_int>>,
+    initial_bounds: Option<std::rc::Rc<dyn Fn(Option<&mut Window>) -> Rect>>,
+    initial_show_state: Option<std::rc::Rc<dyn Fn(Option<&mut Window>) -> ShowState>>,
+    is_frameless: Option<std::rc::Rc<dyn Fn(Option<&mut Window>) -> ::std::os::raw::c_int>>,
+    with_standard_window_buttons:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Window>) -> ::std::os::raw::c_int>>,
+    titlebar_height:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Window>, Option<&mut f32>) -> ::std::os::raw::c_int>>,
+    accepts_first_mouse: Option<std::rc::Rc<dyn Fn(Option<&mut Window>) -> State>>,
+    can_resize: Option<std::rc::Rc<dyn Fn(Option<&mut Window>) -> ::std::os::raw::c_int>>,
+    can_maximize: Option<std::rc::Rc<dyn Fn(Option<&mut Window>) -> ::std::os::raw::c_int>>,
+    can_minimize: Option<std::rc::Rc<dyn Fn(Option<&mut Window>) -> ::std::os::raw::c_int>>,
+    can_close: Option<std::rc::Rc<dyn Fn(Option<&mut Window>) -> ::std::os::raw::c_int>>,
+    on_accelerator: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Window>, ::std::os::raw::c_int) -> ::std::os::raw::c_int>,
+    >,
+    on_key_event: Option<
+        std::rc::Rc<dyn Fn(Option<&mut Window>, Option<&KeyEvent>) -> ::std::os::raw::c_int>,
+    >,
+    on_theme_colors_changed:
+        Option<std::rc::Rc<dyn Fn(Option<&mut Window>, ::std::os::raw::c_int)>>,
+    window_runtime_style: Option<std::rc::Rc<dyn Fn() -> RuntimeStyle>>,
+    linux_window_properties: Option<
+        std::rc::Rc<
+            dyn Fn(
+                Option<&mut Window>,
+                Option<&mut LinuxWindowProperties>,
+            ) -> ::std::os::raw::c_int,
+        >,
+    >,
+}
+impl WindowDelegateBuilder {
+    #[doc = "Implement [`ImplWindowDelegate::preferred_size`] with a closure."]
+    pub fn preferred_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.preferred_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::minimum_size`] with a closure."]
+    pub fn minimum_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.minimum_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::maximum_size`] with a closure."]
+    pub fn maximum_size(mut self, closure: impl Fn(Option<&mut View>) -> Size + 'static) -> Self {
+        self.maximum_size = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::height_for_width`] with a closure."]
+    pub fn height_for_width(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.height_for_width = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_parent_view_changed`] with a closure."]
+    pub fn on_parent_view_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>) + 'static,
+    ) -> Self {
+        self.on_parent_view_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_child_view_changed`] with a closure."]
+    pub fn on_child_view_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int, Option<&mut View>) + 'static,
+    ) -> Self {
+        self.on_child_view_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_window_changed`] with a closure."]
+    pub fn on_window_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_window_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_layout_changed`] with a closure."]
+    pub fn on_layout_changed(
+        mut self,
+        closure: impl Fn(Option<&mut View>, Option<&Rect>) + 'static,
+    ) -> Self {
+        self.on_layout_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_focus`] with a closure."]
+    pub fn on_focus(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_focus = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_blur`] with a closure."]
+    pub fn on_blur(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_blur = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_theme_changed`] with a closure."]
+    pub fn on_theme_changed(mut self, closure: impl Fn(Option<&mut View>) + 'static) -> Self {
+        self.on_theme_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_window_created`] with a closure."]
+    pub fn on_window_created(mut self, closure: impl Fn(Option<&mut Window>) + 'static) -> Self {
+        self.on_window_created = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_window_closing`] with a closure."]
+    pub fn on_window_closing(mut self, closure: impl Fn(Option<&mut Window>) + 'static) -> Self {
+        self.on_window_closing = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_window_destroyed`] with a closure."]
+    pub fn on_window_destroyed(mut self, closure: impl Fn(Option<&mut Window>) + 'static) -> Self {
+        self.on_window_destroyed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_window_activation_changed`] with a closure."]
+    pub fn on_window_activation_changed(
+        mut self,
+        closure: impl Fn(Option<&mut Window>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_window_activation_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_window_bounds_changed`] with a closure."]
+    pub fn on_window_bounds_changed(
+        mut self,
+        closure: impl Fn(Option<&mut Window>, Option<&Rect>) + 'static,
+    ) -> Self {
+        self.on_window_bounds_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_window_fullscreen_transition`] with a closure."]
+    pub fn on_window_fullscreen_transition(
+        mut self,
+        closure: impl Fn(Option<&mut Window>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_window_fullscreen_transition = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::parent_window`] with a closure."]
+    pub fn parent_window(
+        mut self,
+        closure: impl Fn(
+                Option<&mut Window>,
+                Option<&mut ::std::os::raw::c_int>,
+                Option<&mut ::std::os::raw::c_int>,
+            ) -> Option<Window>
+            + 'static,
+    ) -> Self {
+        self.parent_window = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::is_window_modal_dialog`] with a closure."]
+    pub fn is_window_modal_dialog(
+        mut self,
+        closure: impl Fn(Option<&mut Window>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.is_window_modal_dialog = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::initial_bounds`] with a closure."]
+    pub fn initial_bounds(
+        mut self,
+        closure: impl Fn(Option<&mut Window>) -> Rect + 'static,
+    ) -> Self {
+        self.initial_bounds = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::initial_show_state`] with a closure."]
+    pub fn initial_show_state(
+        mut self,
+        closure: impl Fn(Option<&mut Window>) -> ShowState + 'static,
+    ) -> Self {
+        self.initial_show_state = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::is_frameless`] with a closure."]
+    pub fn is_frameless(
+        mut self,
+        closure: impl Fn(Option<&mut Window>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.is_frameless = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::with_standard_window_buttons`] with a closure."]
+    pub fn with_standard_window_buttons(
+        mut self,
+        closure: impl Fn(Option<&mut Window>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.with_standard_window_buttons = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::titlebar_height`] with a closure."]
+    pub fn titlebar_height(
+        mut self,
+        closure: impl Fn(Option<&mut Window>, Option<&mut f32>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.titlebar_height = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::accepts_first_mouse`] with a closure."]
+    pub fn accepts_first_mouse(
+        mut self,
+        closure: impl Fn(Option<&mut Window>) -> State + 'static,
+    ) -> Self {
+        self.accepts_first_mouse = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::can_resize`] with a closure."]
+    pub fn can_resize(
+        mut self,
+        closure: impl Fn(Option<&mut Window>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.can_resize = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::can_maximize`] with a closure."]
+    pub fn can_maximize(
+        mut self,
+        closure: impl Fn(Option<&mut Window>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.can_maximize = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::can_minimize`] with a closure."]
+    pub fn can_minimize(
+        mut self,
+        closure: impl Fn(Option<&mut Window>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.can_minimize = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::can_close`] with a closure."]
+    pub fn can_close(
+        mut self,
+        closure: impl Fn(Option<&mut Window>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.can_close = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_accelerator`] with a closure."]
+    pub fn on_accelerator(
+        mut self,
+        closure: impl Fn(Option<&mut Window>, ::std::os::raw::c_int) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.on_accelerator = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_key_event`] with a closure."]
+    pub fn on_key_event(
+        mut self,
+        closure: impl Fn(Option<&mut Window>, Option<&KeyEvent>) -> ::std::os::raw::c_int + 'static,
+    ) -> Self {
+        self.on_key_event = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::on_theme_colors_changed`] with a closure."]
+    pub fn on_theme_colors_changed(
+        mut self,
+        closure: impl Fn(Option<&mut Window>, ::std::os::raw::c_int) + 'static,
+    ) -> Self {
+        self.on_theme_colors_changed = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::window_runtime_style`] with a closure."]
+    pub fn window_runtime_style(mut self, closure: impl Fn() -> RuntimeStyle + 'static) -> Self {
+        self.window_runtime_style = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Implement [`ImplWindowDelegate::linux_window_properties`] with a closure."]
+    pub fn linux_window_properties(
+        mut self,
+        closure: impl Fn(Option<&mut Window>, Option<&mut LinuxWindowProperties>) -> ::std::os::raw::c_int
+            + 'static,
+    ) -> Self {
+        self.linux_window_properties = Some(std::rc::Rc::new(closure));
+        self
+    }
+    #[doc = "Create a [`WindowDelegate`] which calls the closures."]
+    pub fn build(self) -> WindowDelegate {
+        BuiltWindowDelegate::new(self)
+    }
+}
+wrap_window_delegate! { struct BuiltWindowDelegate { builder : WindowDelegateBuilder , } impl ViewDelegate { fn preferred_size (& self , view : Option < & mut View >) -> Size { match & self . builder . preferred_size { Some (closure) => closure (view) , None => Default :: default () , } } fn minimum_size (& self , view : Option < & mut View >) -> Size { match & self . builder . minimum_size { Some (closure) => closure (view) , None => Default :: default () , } } fn maximum_size (& self , view : Option < & mut View >) -> Size { match & self . builder . maximum_size { Some (closure) => closure (view) , None => Default :: default () , } } fn height_for_width (& self , view : Option < & mut View > , width : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . height_for_width { Some (closure) => closure (view , width) , None => Default :: default () , } } fn on_parent_view_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int , parent : Option < & mut View >) { if let Some (closure) = & self . builder . on_parent_view_changed { closure (view , added , parent) ; } } fn on_child_view_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int , child : Option < & mut View >) { if let Some (closure) = & self . builder . on_child_view_changed { closure (view , added , child) ; } } fn on_window_changed (& self , view : Option < & mut View > , added : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_window_changed { closure (view , added) ; } } fn on_layout_changed (& self , view : Option < & mut View > , new_bounds : Option < & Rect >) { if let Some (closure) = & self . builder . on_layout_changed { closure (view , new_bounds) ; } } fn on_focus (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_focus { closure (view) ; } } fn on_blur (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_blur { closure (view) ; } } fn on_theme_changed (& self , view : Option < & mut View >) { if let Some (closure) = & self . builder . on_theme_changed { closure (view) ; } } } impl PanelDelegate { } impl WindowDelegate { fn on_window_created (& self , window : Option < & mut Window >) { if let Some (closure) = & self . builder . on_window_created { closure (window) ; } } fn on_window_closing (& self , window : Option < & mut Window >) { if let Some (closure) = & self . builder . on_window_closing { closure (window) ; } } fn on_window_destroyed (& self , window : Option < & mut Window >) { if let Some (closure) = & self . builder . on_window_destroyed { closure (window) ; } } fn on_window_activation_changed (& self , window : Option < & mut Window > , active : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_window_activation_changed { closure (window , active) ; } } fn on_window_bounds_changed (& self , window : Option < & mut Window > , new_bounds : Option < & Rect >) { if let Some (closure) = & self . builder . on_window_bounds_changed { closure (window , new_bounds) ; } } fn on_window_fullscreen_transition (& self , window : Option < & mut Window > , is_completed : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_window_fullscreen_transition { closure (window , is_completed) ; } } fn parent_window (& self , window : Option < & mut Window > , is_menu : Option < & mut :: std :: os :: raw :: c_int > , can_activate_menu : Option < & mut :: std :: os :: raw :: c_int >) -> Option < Window > { match & self . builder . parent_window { Some (closure) => closure (window , is_menu , can_activate_menu) , None => Default :: default () , } } fn is_window_modal_dialog (& self , window : Option < & mut Window >) -> :: std :: os :: raw :: c_int { match & self . builder . is_window_modal_dialog { Some (closure) => closure (window) , None => Default :: default () , } } fn initial_bounds (& self , window : Option < & mut Window >) -> Rect { match & self . builder . initial_bounds { Some (closure) => closure (window) , None => Default :: default () , } } fn initial_show_state (& self , window : Option < & mut Window >) -> ShowState { match & self . builder . initial_show_state { Some (closure) => closure (window) , None => Default :: default () , } } fn is_frameless (& self , window : Option < & mut Window >) -> :: std :: os :: raw :: c_int { match & self . builder . is_frameless { Some (closure) => closure (window) , None => Default :: default () , } } fn with_standard_window_buttons (& self , window : Option < & mut Window >) -> :: std :: os :: raw :: c_int { match & self . builder . with_standard_window_buttons { Some (closure) => closure (window) , None => Default :: default () , } } fn titlebar_height (& self , window : Option < & mut Window > , titlebar_height : Option < & mut f32 >) -> :: std :: os :: raw :: c_int { match & self . builder . titlebar_height { Some (closure) => closure (window , titlebar_height) , None => Default :: default () , } } fn accepts_first_mouse (& self , window : Option < & mut Window >) -> State { match & self . builder . accepts_first_mouse { Some (closure) => closure (window) , None => Default :: default () , } } fn can_resize (& self , window : Option < & mut Window >) -> :: std :: os :: raw :: c_int { match & self . builder . can_resize { Some (closure) => closure (window) , None => Default :: default () , } } fn can_maximize (& self , window : Option < & mut Window >) -> :: std :: os :: raw :: c_int { match & self . builder . can_maximize { Some (closure) => closure (window) , None => Default :: default () , } } fn can_minimize (& self , window : Option < & mut Window >) -> :: std :: os :: raw :: c_int { match & self . builder . can_minimize { Some (closure) => closure (window) , None => Default :: default () , } } fn can_close (& self , window : Option < & mut Window >) -> :: std :: os :: raw :: c_int { match & self . builder . can_close { Some (closure) => closure (window) , None => Default :: default () , } } fn on_accelerator (& self , window : Option < & mut Window > , command_id : :: std :: os :: raw :: c_int) -> :: std :: os :: raw :: c_int { match & self . builder . on_accelerator { Some (closure) => closure (window , command_id) , None => Default :: default () , } } fn on_key_event (& self , window : Option < & mut Window > , event : Option < & KeyEvent >) -> :: std :: os :: raw :: c_int { match & self . builder . on_key_event { Some (closure) => closure (window , event) , None => Default :: default () , } } fn on_theme_colors_changed (& self , window : Option < & mut Window > , chrome_theme : :: std :: os :: raw :: c_int) { if let Some (closure) = & self . builder . on_theme_colors_changed { closure (window , chrome_theme) ; } } fn window_runtime_style (& self) -> RuntimeStyle { match & self . builder . window_runtime_style { Some (closure) => closure () , None => Default :: default () , } } fn linux_window_properties (& self , window : Option < & mut Window > , properties : Option < & mut LinuxWindowProperties >) -> :: std :: os :: raw :: c_int { match & self . builder . linux_window_properties { Some (closure) => closure (window , properties) , None => Default :: default () , } } } }
 
 /// See [`_cef_window_t`] for more documentation.
 #[derive(Clone)]
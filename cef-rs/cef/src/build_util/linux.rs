@@ -1,5 +1,11 @@
+use super::metadata::BundleMetadata;
+use flate2::{write::GzEncoder, Compression};
+use serde::Serialize;
 use std::{
-    fs, io,
+    env,
+    fs::{self, File},
+    io::{self, Read},
+    os::unix::fs::{symlink, PermissionsExt},
     path::{Path, PathBuf},
     process::Command,
 };
@@ -10,6 +16,10 @@ pub enum Error {
     Io(#[from] io::Error),
     #[error("Metadata error: {0:?}")]
     Metadata(#[from] super::metadata::Error),
+    #[error("JSON error: {0:?}")]
+    Json(#[from] serde_json::Error),
+    #[error("{0} was not found, install it or add it to the PATH")]
+    MissingTool(String),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -19,7 +29,6 @@ pub fn bundle(app_path: &Path, target_path: &Path, executable_name: &str) -> Res
     let cef_path = cef_dll_sys::get_cef_dir().unwrap();
     copy_directory(&cef_path, app_path)?;
 
-    const LOCALES_DIR: &str = "locales";
     copy_directory(&cef_path.join(LOCALES_DIR), &app_path.join(LOCALES_DIR))?;
 
     copy_app(app_path, target_path, executable_name)
@@ -38,6 +47,561 @@ pub fn build_bundle(app_path: &Path, executable_name: &str, release: bool) -> Re
     bundle(app_path, &target_path, executable_name)
 }
 
+/// Application details which are written to the `.desktop` file and package manifests.
+#[derive(Clone, Debug)]
+pub struct PackageInfo {
+    pub executable_name: String,
+    /// Reverse DNS application ID, used to name the `.desktop` file and icons.
+    pub identifier: String,
+    pub display_name: String,
+    pub comment: String,
+    pub categories: Vec<String>,
+    pub version: String,
+    /// Locales to keep from the CEF `locales` directory. `en-US` is always kept, because CEF
+    /// falls back to it.
+    pub locales: Option<Vec<String>>,
+    /// Directory with a `linux/icons` subdirectory containing PNG and SVG icons.
+    pub resources_path: Option<PathBuf>,
+    pub flatpak_runtime_version: String,
+    pub flatpak_finish_args: Vec<String>,
+}
+
+impl PackageInfo {
+    /// Fill in the defaults for anything which isn't set in `[package.metadata.cef.bundle.linux]`.
+    pub fn new(executable_name: &str, bundle_metadata: BundleMetadata) -> Self {
+        let linux = bundle_metadata.linux;
+        Self {
+            executable_name: executable_name.to_owned(),
+            identifier: linux
+                .identifier
+                .unwrap_or_else(|| format!("apps.tauri.cef-rs.{executable_name}")),
+            display_name: linux
+                .display_name
+                .unwrap_or_else(|| executable_name.to_owned()),
+            comment: linux.comment.unwrap_or_default(),
+            categories: linux.categories,
+            version: bundle_metadata.version,
+            locales: linux.locales,
+            resources_path: bundle_metadata.resources_path,
+            flatpak_runtime_version: linux
+                .flatpak_runtime_version
+                .unwrap_or_else(|| "24.08".to_owned()),
+            flatpak_finish_args: linux.flatpak_finish_args.unwrap_or_else(|| {
+                [
+                    "--share=ipc",
+                    "--share=network",
+                    "--socket=x11",
+                    "--socket=wayland",
+                    "--socket=pulseaudio",
+                    "--device=dri",
+                ]
+                .map(str::to_owned)
+                .to_vec()
+            }),
+        }
+    }
+}
+
+/// A relocatable package created by [`package`].
+pub struct Package {
+    /// Directory with the executable, CEF, a `share` directory with the `.desktop` file and icons,
+    /// and a `package-manifest.json` file.
+    pub directory: PathBuf,
+    /// `.tar.gz` archive of [`Package::directory`].
+    pub archive: PathBuf,
+    pub info: PackageInfo,
+}
+
+/// Create a relocatable package directory and `.tar.gz` archive in `app_path`. The executable
+/// must be linked with an `$ORIGIN` rpath so it finds `libcef.so` wherever the package is
+/// extracted, which [`build_package`] takes care of.
+///
+/// The archive stores `chrome-sandbox` as owned by root with mode 4755, which is only preserved
+/// when it is extracted by root. Otherwise `package-manifest.json` lists the permissions which
+/// need to be restored.
+pub fn package(app_path: &Path, target_path: &Path, info: PackageInfo) -> Result<Package> {
+    let package_name = format!(
+        "{}-{}-linux-{}",
+        info.executable_name,
+        info.version,
+        env::consts::ARCH
+    );
+    let directory = app_path.join(&package_name);
+    if directory.exists() {
+        fs::remove_dir_all(&directory)?;
+    }
+    bundle(&directory, target_path, &info.executable_name)?;
+
+    // These are only needed to build against CEF.
+    for file in ["CMakeLists.txt", "archive.json"] {
+        let file = directory.join(file);
+        if file.exists() {
+            fs::remove_file(file)?;
+        }
+    }
+    if let Some(locales) = &info.locales {
+        filter_locales(&directory.join(LOCALES_DIR), locales)?;
+    }
+    install_desktop_entry(&directory, &info)?;
+    install_icons(&directory, &info)?;
+
+    let sandbox = directory.join(CHROME_SANDBOX);
+    if sandbox.exists() {
+        fs::set_permissions(&sandbox, fs::Permissions::from_mode(SANDBOX_MODE))?;
+    }
+    write_package_manifest(&directory, &info)?;
+
+    let archive = app_path.join(format!("{package_name}.tar.gz"));
+    create_archive(&directory, &package_name, &archive)?;
+
+    Ok(Package {
+        directory,
+        archive,
+        info,
+    })
+}
+
+/// Similar to [`package`], but this will read the [`PackageInfo`] from the `Cargo.toml` metadata,
+/// and invoke `cargo rustc` to build the executable target with an `$ORIGIN` rpath.
+pub fn build_package(app_path: &Path, executable_name: &str, release: bool) -> Result<Package> {
+    let cargo_metadata = super::metadata::get_cargo_metadata()?;
+    let target_path =
+        cargo_metadata
+            .target_directory()
+            .join(if release { "release" } else { "debug" });
+    let package_name = cargo_metadata.package_name(executable_name)?;
+    let info = PackageInfo::new(
+        executable_name,
+        cargo_metadata.parse_bundle_metadata(executable_name)?,
+    );
+
+    cargo_build_relocatable(&package_name, executable_name, release)?;
+
+    package(app_path, &target_path, info)
+}
+
+/// Create an [AppDir](https://docs.appimage.org/reference/appdir.html) from `package` in
+/// `app_path`, and build an AppImage from it with `appimagetool`.
+pub fn build_appimage(app_path: &Path, package: &Package) -> Result<PathBuf> {
+    let info = &package.info;
+    let app_dir = create_app_dir(app_path, package)?;
+    let app_image = app_path.join(format!(
+        "{}-{}-{}.AppImage",
+        info.executable_name,
+        info.version,
+        env::consts::ARCH
+    ));
+
+    const APPIMAGETOOL: &str = "appimagetool";
+    let status = Command::new(APPIMAGETOOL)
+        .arg(&app_dir)
+        .arg(&app_image)
+        .env("ARCH", env::consts::ARCH)
+        .status()
+        .map_err(|err| match err.kind() {
+            io::ErrorKind::NotFound => Error::MissingTool(APPIMAGETOOL.to_owned()),
+            _ => err.into(),
+        })?;
+    if status.success() {
+        Ok(app_image)
+    } else {
+        Err(io::Error::from(io::ErrorKind::Interrupted).into())
+    }
+}
+
+/// Create an AppDir for `appimagetool` from `package` in `app_path`. The `chrome-sandbox`
+/// helper is left out, because AppImages are mounted without setuid support.
+pub fn create_app_dir(app_path: &Path, package: &Package) -> Result<PathBuf> {
+    let info = &package.info;
+    let executable_name = info.executable_name.as_str();
+    let app_dir = app_path.join(format!("{executable_name}.AppDir"));
+    if app_dir.exists() {
+        fs::remove_dir_all(&app_dir)?;
+    }
+
+    let lib_dir = app_dir.join("usr").join("lib").join(executable_name);
+    copy_directory_recursive(&package.directory, &lib_dir)?;
+    fs::remove_file(lib_dir.join(CHROME_SANDBOX)).or_else(ignore_not_found)?;
+    fs::remove_file(lib_dir.join(PACKAGE_MANIFEST)).or_else(ignore_not_found)?;
+    fs::rename(lib_dir.join(SHARE_DIR), app_dir.join("usr").join(SHARE_DIR))?;
+
+    let desktop_entry = format!("{}.desktop", info.identifier);
+    fs::copy(
+        app_dir
+            .join("usr")
+            .join(SHARE_DIR)
+            .join("applications")
+            .join(&desktop_entry),
+        app_dir.join(&desktop_entry),
+    )?;
+
+    if let Some(icon) = largest_icon(&app_dir.join("usr"), info)? {
+        let icon_name = format!("{}.png", info.identifier);
+        fs::copy(icon, app_dir.join(&icon_name))?;
+        symlink(&icon_name, app_dir.join(".DirIcon"))?;
+    }
+
+    let app_run = app_dir.join("AppRun");
+    fs::write(
+        &app_run,
+        format!(
+            "#!/bin/sh\nHERE=\"$(dirname \"$(readlink -f \"$0\")\")\"\nexec \"$HERE/usr/lib/{executable_name}/{executable_name}\" \"$@\"\n"
+        ),
+    )?;
+    fs::set_permissions(&app_run, fs::Permissions::from_mode(0o755))?;
+
+    Ok(app_dir)
+}
+
+/// Write a [flatpak-builder](https://docs.flatpak.org/en/latest/flatpak-builder.html) manifest
+/// next to the package archive, which installs the package into `/app`. Flatpak doesn't allow
+/// setuid binaries, so `chrome-sandbox` is removed.
+pub fn write_flatpak_manifest(package: &Package) -> Result<PathBuf> {
+    let info = &package.info;
+    let executable_name = info.executable_name.as_str();
+    let archive_name = package
+        .archive
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let lib_dir = format!("/app/lib/{executable_name}");
+
+    let manifest = serde_json::json!({
+        "app-id": info.identifier,
+        "runtime": "org.freedesktop.Platform",
+        "runtime-version": info.flatpak_runtime_version,
+        "sdk": "org.freedesktop.Sdk",
+        "command": executable_name,
+        "finish-args": info.flatpak_finish_args,
+        "modules": [{
+            "name": executable_name,
+            "buildsystem": "simple",
+            "build-commands": [
+                format!("mkdir -p {lib_dir} /app/bin"),
+                format!("cp -a . {lib_dir}/"),
+                format!("rm -f {lib_dir}/{CHROME_SANDBOX} {lib_dir}/{PACKAGE_MANIFEST}"),
+                format!("cp -a {lib_dir}/{SHARE_DIR} /app/ && rm -rf {lib_dir}/{SHARE_DIR}"),
+                format!("ln -s {lib_dir}/{executable_name} /app/bin/{executable_name}"),
+            ],
+            "sources": [{
+                "type": "archive",
+                "path": archive_name,
+            }],
+        }],
+    });
+
+    let manifest_path = package
+        .archive
+        .with_file_name(format!("{}.json", info.identifier));
+    fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?)?;
+    Ok(manifest_path)
+}
+
+const LOCALES_DIR: &str = "locales";
+const DEFAULT_LOCALE: &str = "en-US";
+const SHARE_DIR: &str = "share";
+const CHROME_SANDBOX: &str = "chrome-sandbox";
+const SANDBOX_MODE: u32 = 0o4755;
+const PACKAGE_MANIFEST: &str = "package-manifest.json";
+
+/// Remove the locale packs which aren't in `locales`. A locale whose region isn't available, e.g.
+/// `de-AT`, keeps the pack for its language instead, which is what CEF falls back to.
+fn filter_locales(locales_path: &Path, locales: &[String]) -> io::Result<()> {
+    let paths = fs::read_dir(locales_path)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    let available: Vec<&str> = paths.iter().filter_map(|path| pak_locale(path)).collect();
+    let keep = locales_to_keep(&available, locales);
+    for path in &paths {
+        if pak_locale(path).is_some_and(|locale| !keep.contains(&locale)) {
+            fs::remove_file(path)?;
+        }
+    }
+    Ok(())
+}
+
+fn pak_locale(path: &Path) -> Option<&str> {
+    if path.extension()? != "pak" {
+        return None;
+    }
+    let stem = path.file_stem()?.to_str()?;
+    // Some locales have variants, e.g. `en-US_FEMININE.pak`.
+    stem.split('_').next()
+}
+
+fn locales_to_keep<'a>(available: &[&'a str], locales: &[String]) -> Vec<&'a str> {
+    let find = |locale: &str| available.iter().copied().find(|&found| found == locale);
+    std::iter::once(DEFAULT_LOCALE)
+        .chain(locales.iter().map(String::as_str))
+        .filter_map(|locale| {
+            find(locale).or_else(|| find(locale.split('-').next().unwrap_or(locale)))
+        })
+        .collect()
+}
+
+fn install_desktop_entry(directory: &Path, info: &PackageInfo) -> io::Result<()> {
+    let applications = directory.join(SHARE_DIR).join("applications");
+    fs::create_dir_all(&applications)?;
+    fs::write(
+        applications.join(format!("{}.desktop", info.identifier)),
+        desktop_entry(info),
+    )
+}
+
+fn desktop_entry(info: &PackageInfo) -> String {
+    let executable_name = escape_desktop_value(&info.executable_name);
+    let mut desktop_entry = format!(
+        "[Desktop Entry]\nType=Application\nName={}\n",
+        escape_desktop_value(&info.display_name)
+    );
+    if !info.comment.is_empty() {
+        desktop_entry.push_str(&format!(
+            "Comment={}\n",
+            escape_desktop_value(&info.comment)
+        ));
+    }
+    desktop_entry.push_str(&format!(
+        "Exec={} %U\nIcon={}\nTerminal=false\nStartupWMClass={executable_name}\n",
+        escape_desktop_value(&quote_exec_argument(&info.executable_name)),
+        escape_desktop_value(&info.identifier)
+    ));
+    if !info.categories.is_empty() {
+        let categories: Vec<String> = info
+            .categories
+            .iter()
+            .map(|category| escape_desktop_value(category).replace(';', "\\;"))
+            .collect();
+        desktop_entry.push_str(&format!("Categories={};\n", categories.join(";")));
+    }
+    desktop_entry
+}
+
+/// Escape a string value in a `.desktop` file.
+/// See https://specifications.freedesktop.org/desktop-entry-spec/latest/value-types.html
+fn escape_desktop_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Quote an argument of the `Exec` key if it contains any reserved characters.
+/// See https://specifications.freedesktop.org/desktop-entry-spec/latest/exec-variables.html
+fn quote_exec_argument(arg: &str) -> String {
+    const RESERVED: &str = " \t\n\"'\\><~|&;$*?#()`";
+    if !arg.chars().any(|c| RESERVED.contains(c)) {
+        return arg.to_owned();
+    }
+    let mut quoted = String::from("\"");
+    for c in arg.chars() {
+        if matches!(c, '"' | '`' | '$' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Install the icons from `<resources_path>/linux/icons` in the hicolor theme, named after the
+/// application ID.
+fn install_icons(directory: &Path, info: &PackageInfo) -> io::Result<()> {
+    let Some(icons_path) = info
+        .resources_path
+        .as_ref()
+        .map(|resources_path| resources_path.join("linux").join("icons"))
+        .filter(|icons_path| icons_path.exists())
+    else {
+        return Ok(());
+    };
+
+    let hicolor = directory.join(SHARE_DIR).join("icons").join("hicolor");
+    for entry in fs::read_dir(icons_path)? {
+        let path = entry?.path();
+        let (size, extension) = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("png") => {
+                let (width, height) = png_size(&path)?;
+                (format!("{width}x{height}"), "png")
+            }
+            Some("svg") => ("scalable".to_owned(), "svg"),
+            _ => continue,
+        };
+        let apps = hicolor.join(size).join("apps");
+        fs::create_dir_all(&apps)?;
+        fs::copy(&path, apps.join(format!("{}.{extension}", info.identifier)))?;
+    }
+    Ok(())
+}
+
+fn png_size(path: &Path) -> io::Result<(u32, u32)> {
+    let mut header = Vec::with_capacity(24);
+    File::open(path)?.take(24).read_to_end(&mut header)?;
+    png_header_size(&header).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("not a PNG file: {}", path.display()),
+        )
+    })
+}
+
+/// Read the width and height from the `IHDR` chunk at the start of a PNG file.
+fn png_header_size(header: &[u8]) -> Option<(u32, u32)> {
+    let header = header.get(..24)?;
+    if &header[..8] != b"\x89PNG\r\n\x1a\n" || &header[12..16] != b"IHDR" {
+        return None;
+    }
+    let width = u32::from_be_bytes(header[16..20].try_into().ok()?);
+    let height = u32::from_be_bytes(header[20..24].try_into().ok()?);
+    Some((width, height))
+}
+
+fn largest_icon(prefix: &Path, info: &PackageInfo) -> io::Result<Option<PathBuf>> {
+    let hicolor = prefix.join(SHARE_DIR).join("icons").join("hicolor");
+    if !hicolor.exists() {
+        return Ok(None);
+    }
+
+    let mut largest = None;
+    for entry in fs::read_dir(hicolor)? {
+        let entry = entry?;
+        let Some(size) = entry
+            .file_name()
+            .to_str()
+            .and_then(|size| size.split('x').next())
+            .and_then(|width| width.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        let icon = entry
+            .path()
+            .join("apps")
+            .join(format!("{}.png", info.identifier));
+        if icon.exists() && largest.as_ref().is_none_or(|(largest, _)| size > *largest) {
+            largest = Some((size, icon));
+        }
+    }
+    Ok(largest.map(|(_, icon)| icon))
+}
+
+#[derive(Serialize)]
+struct PackageManifest<'a> {
+    name: &'a str,
+    identifier: &'a str,
+    version: &'a str,
+    arch: &'static str,
+    executable: &'a str,
+    permissions: Vec<FilePermissions>,
+    files: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct FilePermissions {
+    path: &'static str,
+    owner: &'static str,
+    group: &'static str,
+    mode: String,
+    note: &'static str,
+}
+
+fn write_package_manifest(directory: &Path, info: &PackageInfo) -> Result<()> {
+    let mut files = vec![];
+    list_files(directory, directory, &mut files)?;
+    files.push(PACKAGE_MANIFEST.to_owned());
+    files.sort();
+
+    let permissions = if directory.join(CHROME_SANDBOX).exists() {
+        vec![FilePermissions {
+            path: CHROME_SANDBOX,
+            owner: "root",
+            group: "root",
+            mode: format!("{SANDBOX_MODE:o}"),
+            note: "Required by the setuid sandbox when unprivileged user namespaces are disabled. \
+                   If the package was not extracted as root, run \
+                   `sudo chown root:root chrome-sandbox && sudo chmod 4755 chrome-sandbox`.",
+        }]
+    } else {
+        vec![]
+    };
+
+    let manifest = PackageManifest {
+        name: &info.display_name,
+        identifier: &info.identifier,
+        version: &info.version,
+        arch: env::consts::ARCH,
+        executable: &info.executable_name,
+        permissions,
+        files,
+    };
+    fs::write(
+        directory.join(PACKAGE_MANIFEST),
+        serde_json::to_string_pretty(&manifest)?,
+    )?;
+    Ok(())
+}
+
+fn list_files(root: &Path, directory: &Path, files: &mut Vec<String>) -> io::Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            list_files(root, &entry.path(), files)?;
+        } else if let Ok(path) = entry.path().strip_prefix(root) {
+            files.push(path.display().to_string());
+        }
+    }
+    Ok(())
+}
+
+fn create_archive(directory: &Path, package_name: &str, archive: &Path) -> io::Result<()> {
+    let encoder = GzEncoder::new(File::create(archive)?, Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+    append_directory(&mut builder, directory, Path::new(package_name))?;
+    builder.into_inner()?.finish()?;
+    Ok(())
+}
+
+/// Append the contents of `directory` with deterministic metadata, owned by root.
+fn append_directory<W: io::Write>(
+    builder: &mut tar::Builder<W>,
+    directory: &Path,
+    path: &Path,
+) -> io::Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_metadata_in_mode(&fs::metadata(directory)?, tar::HeaderMode::Deterministic);
+    builder.append_data(&mut header, path, io::empty())?;
+
+    let mut entries = fs::read_dir(directory)?.collect::<io::Result<Vec<_>>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let entry_path = path.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            append_directory(builder, &entry.path(), &entry_path)?;
+        } else {
+            let mut header = tar::Header::new_gnu();
+            header.set_metadata_in_mode(&entry.metadata()?, tar::HeaderMode::Deterministic);
+            // Deterministic mode drops the setuid bit.
+            if entry.file_name() == CHROME_SANDBOX {
+                header.set_mode(SANDBOX_MODE);
+            }
+            builder.append_data(&mut header, &entry_path, File::open(entry.path())?)?;
+        }
+    }
+    Ok(())
+}
+
+fn ignore_not_found(err: io::Error) -> io::Result<()> {
+    match err.kind() {
+        io::ErrorKind::NotFound => Ok(()),
+        _ => Err(err),
+    }
+}
+
 fn copy_app(app_path: &Path, target_path: &Path, executable_name: &str) -> Result<PathBuf> {
     let executable_path = app_path.join(executable_name);
     let target_executable = target_path.join(executable_name);
@@ -57,6 +621,20 @@ fn copy_directory(src: &Path, dst: &Path) -> io::Result<()> {
     Ok(())
 }
 
+fn copy_directory_recursive(src: &Path, dst: &Path) -> io::Result<()> {
+    fs::create_dir_all(dst)?;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let dst_path = dst.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_directory_recursive(&entry.path(), &dst_path)?;
+        } else {
+            fs::copy(entry.path(), &dst_path)?;
+        }
+    }
+    Ok(())
+}
+
 fn cargo_build(name: &str, release: bool) -> Result<()> {
     println!("Building {name}...");
 
@@ -75,3 +653,152 @@ fn cargo_build(name: &str, release: bool) -> Result<()> {
         Err(io::Error::from(io::ErrorKind::Interrupted).into())
     }
 }
+
+/// Like [`cargo_build`], but link the executable with an `$ORIGIN` rpath so it loads `libcef.so`
+/// from its own directory. `cargo rustc` only passes the extra flags to the final target.
+fn cargo_build_relocatable(package_name: &str, name: &str, release: bool) -> Result<()> {
+    println!("Building {name}...");
+
+    let mut args = vec!["rustc", "-p", package_name];
+    if release {
+        args.push("--release");
+    }
+    #[cfg(feature = "linux-x11")]
+    args.extend(["-F", "linux-x11"]);
+    args.extend(["--bin", name, "--", "-C", "link-arg=-Wl,-rpath,$ORIGIN"]);
+
+    let status = Command::new(super::cargo_path()).args(args).status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::from(io::ErrorKind::Interrupted).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = env::temp_dir().join(format!("cef-linux-{name}-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn package_info() -> PackageInfo {
+        PackageInfo {
+            executable_name: "demo".to_owned(),
+            identifier: "org.example.Demo".to_owned(),
+            display_name: "Demo".to_owned(),
+            comment: String::new(),
+            categories: vec![],
+            version: "1.0.0".to_owned(),
+            locales: None,
+            resources_path: None,
+            flatpak_runtime_version: String::new(),
+            flatpak_finish_args: vec![],
+        }
+    }
+
+    #[test]
+    fn filter_locales_falls_back_to_language() {
+        let dir = TempDir::new("locales");
+        for locale in ["de", "en-GB", "en-US", "en-US_FEMININE", "fr", "pt-BR"] {
+            fs::write(dir.0.join(format!("{locale}.pak")), []).unwrap();
+        }
+        fs::write(dir.0.join("README"), []).unwrap();
+
+        let locales = ["de-AT", "pt-BR"].map(String::from);
+        filter_locales(&dir.0, &locales).unwrap();
+
+        let mut kept: Vec<_> = fs::read_dir(&dir.0)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        kept.sort();
+        assert_eq!(
+            kept,
+            [
+                "README",
+                "de.pak",
+                "en-US.pak",
+                "en-US_FEMININE.pak",
+                "pt-BR.pak"
+            ]
+        );
+    }
+
+    #[test]
+    fn locales_to_keep_skips_missing() {
+        let available = ["en-US", "ja"];
+        let locales = ["ja-JP", "ko"].map(String::from);
+        assert_eq!(locales_to_keep(&available, &locales), ["en-US", "ja"]);
+    }
+
+    #[test]
+    fn png_header_size_reads_ihdr() {
+        let mut header = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        header.extend(256_u32.to_be_bytes());
+        header.extend(128_u32.to_be_bytes());
+        assert_eq!(png_header_size(&header), Some((256, 128)));
+
+        assert_eq!(png_header_size(&header[..20]), None);
+
+        let mut not_png = header.clone();
+        not_png[1] = b'J';
+        assert_eq!(png_header_size(&not_png), None);
+    }
+
+    #[test]
+    fn png_size_rejects_truncated_file() {
+        let dir = TempDir::new("png");
+        let path = dir.0.join("icon.png");
+        fs::write(&path, b"\x89PNG\r\n\x1a\n").unwrap();
+        let err = png_size(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn desktop_entry_escapes_values() {
+        let info = PackageInfo {
+            executable_name: "my app".to_owned(),
+            display_name: "Demo\nBrowser".to_owned(),
+            comment: r"A C:\ browser".to_owned(),
+            categories: vec!["Network".to_owned(), "Web;Browser".to_owned()],
+            ..package_info()
+        };
+        assert_eq!(
+            desktop_entry(&info),
+            "[Desktop Entry]\n\
+             Type=Application\n\
+             Name=Demo\\nBrowser\n\
+             Comment=A C:\\\\ browser\n\
+             Exec=\"my app\" %U\n\
+             Icon=org.example.Demo\n\
+             Terminal=false\n\
+             StartupWMClass=my app\n\
+             Categories=Network;Web\\;Browser;\n"
+        );
+    }
+
+    #[test]
+    fn desktop_entry_escapes_exec_quoting() {
+        let info = PackageInfo {
+            executable_name: r#"a"b$"#.to_owned(),
+            ..package_info()
+        };
+        let entry = desktop_entry(&info);
+        assert!(entry.contains("Exec=\"a\\\\\"b\\\\$\" %U\n"), "{entry}");
+    }
+}
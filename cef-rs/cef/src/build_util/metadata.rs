@@ -26,12 +26,40 @@ struct CargoBundleMetadata {
     #[cfg(target_os = "macos")]
     helper_name: String,
     resources_path: Option<String>,
+    #[cfg(target_os = "linux")]
+    #[serde(default)]
+    linux: LinuxBundleMetadata,
+}
+
+/// Packaging options from `[package.metadata.cef.bundle.linux]`.
+#[cfg(target_os = "linux")]
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct LinuxBundleMetadata {
+    /// Reverse DNS application ID, used to name the `.desktop` file, icons and Flatpak app.
+    pub identifier: Option<String>,
+    /// Name shown in application launchers, defaults to the executable name.
+    pub display_name: Option<String>,
+    /// Tooltip shown in application launchers.
+    pub comment: Option<String>,
+    /// [Desktop menu categories](https://specifications.freedesktop.org/menu-spec/latest/category-registry.html).
+    pub categories: Vec<String>,
+    /// Locales to keep from the CEF `locales` directory, e.g. `["en-US", "de"]`. All of them are
+    /// kept if this is not set.
+    pub locales: Option<Vec<String>>,
+    /// Version of the `org.freedesktop.Platform` runtime in the Flatpak manifest.
+    pub flatpak_runtime_version: Option<String>,
+    /// Replaces the default sandbox permissions in the Flatpak manifest.
+    pub flatpak_finish_args: Option<Vec<String>>,
 }
 
 pub struct BundleMetadata {
     #[cfg(target_os = "macos")]
     pub helper_name: String,
     pub resources_path: Option<PathBuf>,
+    pub version: String,
+    #[cfg(target_os = "linux")]
+    pub linux: LinuxBundleMetadata,
 }
 
 impl BundleMetadata {
@@ -59,6 +87,9 @@ impl BundleMetadata {
             #[cfg(target_os = "macos")]
             helper_name: package_metadata.cef.bundle.helper_name,
             resources_path,
+            version: package.version.to_string(),
+            #[cfg(target_os = "linux")]
+            linux: package_metadata.cef.bundle.linux,
         })
     }
 }
@@ -74,6 +105,16 @@ impl CargoMetadata {
         BundleMetadata::parse(executable, &self.0)
             .ok_or_else(|| Error::MissingPackageMetadata(executable.to_owned()))
     }
+
+    /// Name of the package which contains the `executable` target.
+    pub fn package_name(&self, executable: &str) -> Result<String> {
+        self.0
+            .packages
+            .iter()
+            .find(|p| p.targets.iter().any(|t| t.name == executable))
+            .map(|p| p.name.to_string())
+            .ok_or_else(|| Error::MissingPackageMetadata(executable.to_owned()))
+    }
 }
 
 /// Run `cargo metadata` to determine the configuration for the current workspace/package.
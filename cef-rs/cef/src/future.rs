@@ -0,0 +1,839 @@
+//! Adapters that turn the callback based CEF APIs into [Future]s.
+//!
+//! Each adapter wraps the result callback or visitor that CEF expects in a small object which
+//! forwards the result to a [CallbackFuture] or a [CallbackStream]. These only rely on
+//! [std::task::Waker], so they can be awaited from any executor:
+//!
+//! ```no_run
+//! # use cef::{future::*, *};
+//! # async fn example(frame: Frame, cookie_manager: CookieManager) -> Result<(), Error> {
+//! let text = frame.text_async().await?;
+//! let cookies = cookie_manager.visit_all().collect().await?;
+//! let on_ui = post_task_async(ThreadId::UI, || currently_on(ThreadId::UI)).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! If CEF releases a callback without calling it, e.g. because the browser was closed or the
+//! request was rejected, the future resolves to [Error::Canceled].
+
+use crate::*;
+use std::{
+    collections::VecDeque,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicI32, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Waker},
+};
+
+/// The reason a [CallbackFuture] or [CallbackStream] did not produce a result.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// CEF rejected the request, or released the callback without calling it.
+    Canceled,
+    /// The request completed, but CEF reported a failure.
+    Failed(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Canceled => write!(f, "the CEF callback was released without a result"),
+            Self::Failed(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+struct Slot<T> {
+    value: Option<Result<T, Error>>,
+    complete: bool,
+    waker: Option<Waker>,
+}
+
+struct CompleterInner<T>(Arc<Mutex<Slot<T>>>);
+
+impl<T> CompleterInner<T> {
+    fn complete(&self, value: Result<T, Error>) -> bool {
+        let waker = {
+            let Ok(mut slot) = self.0.lock() else {
+                return false;
+            };
+            if slot.complete {
+                return false;
+            }
+            slot.value = Some(value);
+            slot.complete = true;
+            slot.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        true
+    }
+}
+
+impl<T> Drop for CompleterInner<T> {
+    fn drop(&mut self) {
+        self.complete(Err(Error::Canceled));
+    }
+}
+
+/// The sending half of a [CallbackFuture], which is held by the CEF callback object. Clones
+/// share the same result. Once the last clone is dropped without completing the future, it
+/// resolves to [Error::Canceled].
+pub struct Completer<T>(Arc<CompleterInner<T>>);
+
+impl<T> Clone for Completer<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Completer<T> {
+    /// Resolve the future with `value`. Returns false if it had already been completed.
+    pub fn complete(&self, value: Result<T, Error>) -> bool {
+        self.0.complete(value)
+    }
+}
+
+/// A [Future] which resolves to the result passed to its [Completer].
+pub struct CallbackFuture<T> {
+    slot: Arc<Mutex<Slot<T>>>,
+}
+
+impl<T> CallbackFuture<T> {
+    /// Create a future together with the [Completer] which resolves it.
+    pub fn new() -> (Completer<T>, Self) {
+        let slot = Arc::new(Mutex::new(Slot {
+            value: None,
+            complete: false,
+            waker: None,
+        }));
+        let completer = Completer(Arc::new(CompleterInner(slot.clone())));
+        (completer, Self { slot })
+    }
+
+    /// Create a future which has already been resolved.
+    pub fn ready(value: Result<T, Error>) -> Self {
+        let (completer, future) = Self::new();
+        completer.complete(value);
+        future
+    }
+}
+
+impl<T> Future for CallbackFuture<T> {
+    type Output = Result<T, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Ok(mut slot) = self.slot.lock() else {
+            return Poll::Ready(Err(Error::Canceled));
+        };
+        if let Some(value) = slot.value.take() {
+            return Poll::Ready(value);
+        }
+        if slot.complete {
+            // The result was already taken by an earlier poll.
+            return Poll::Ready(Err(Error::Canceled));
+        }
+        slot.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+struct Queue<T> {
+    items: VecDeque<T>,
+    error: Option<Error>,
+    closed: bool,
+    detached: bool,
+    waker: Option<Waker>,
+}
+
+struct StreamSenderInner<T>(Arc<Mutex<Queue<T>>>);
+
+impl<T> StreamSenderInner<T> {
+    /// Apply `update` unless the stream has ended. Returns true if it was applied.
+    fn update(&self, update: impl FnOnce(&mut Queue<T>)) -> bool {
+        let waker = {
+            let Ok(mut queue) = self.0.lock() else {
+                return false;
+            };
+            if queue.closed || queue.detached {
+                return false;
+            }
+            update(&mut queue);
+            queue.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        true
+    }
+}
+
+impl<T> Drop for StreamSenderInner<T> {
+    fn drop(&mut self) {
+        self.update(|queue| queue.closed = true);
+    }
+}
+
+/// The sending half of a [CallbackStream], which is held by the CEF visitor object. Clones
+/// share the same stream, which ends once the last clone is dropped or [StreamSender::close]
+/// is called.
+pub struct StreamSender<T>(Arc<StreamSenderInner<T>>);
+
+impl<T> Clone for StreamSender<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> StreamSender<T> {
+    /// Add an item to the stream. Returns false if the stream has ended or the
+    /// [CallbackStream] has been dropped, in which case the visitor can stop early.
+    pub fn push(&self, item: T) -> bool {
+        self.0.update(|queue| queue.items.push_back(item))
+    }
+
+    /// End the stream after the items that have already been pushed.
+    pub fn close(&self) {
+        self.0.update(|queue| queue.closed = true);
+    }
+
+    /// End the stream with an error, which is reported by [CallbackStream::collect].
+    pub fn fail(&self, error: Error) {
+        self.0.update(|queue| {
+            queue.error = Some(error);
+            queue.closed = true;
+        });
+    }
+}
+
+/// A stream of the items passed to its [StreamSender], e.g. one per visited cookie.
+pub struct CallbackStream<T> {
+    queue: Arc<Mutex<Queue<T>>>,
+}
+
+impl<T> CallbackStream<T> {
+    /// Create a stream together with the [StreamSender] which feeds it.
+    pub fn new() -> (StreamSender<T>, Self) {
+        let queue = Arc::new(Mutex::new(Queue {
+            items: VecDeque::new(),
+            error: None,
+            closed: false,
+            detached: false,
+            waker: None,
+        }));
+        let sender = StreamSender(Arc::new(StreamSenderInner(queue.clone())));
+        (sender, Self { queue })
+    }
+
+    /// Poll for the next item. Returns `Ready(None)` once the stream has ended.
+    pub fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let Ok(mut queue) = self.queue.lock() else {
+            return Poll::Ready(None);
+        };
+        if let Some(item) = queue.items.pop_front() {
+            return Poll::Ready(Some(item));
+        }
+        if queue.closed {
+            return Poll::Ready(None);
+        }
+        queue.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    /// Wait for the next item, or `None` once the stream has ended.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Next<'_, T> {
+        Next { stream: self }
+    }
+
+    /// The error the stream ended with, if any.
+    pub fn error(&self) -> Option<Error> {
+        self.queue.lock().ok().and_then(|queue| queue.error.clone())
+    }
+
+    /// Wait for the stream to end and return all of its items.
+    pub fn collect(self) -> Collect<T> {
+        Collect {
+            stream: self,
+            items: Vec::new(),
+        }
+    }
+}
+
+impl<T> Drop for CallbackStream<T> {
+    fn drop(&mut self) {
+        if let Ok(mut queue) = self.queue.lock() {
+            queue.detached = true;
+            queue.items.clear();
+        }
+    }
+}
+
+/// Future returned by [CallbackStream::next].
+pub struct Next<'a, T> {
+    stream: &'a mut CallbackStream<T>,
+}
+
+impl<T> Future for Next<'_, T> {
+    type Output = Option<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.stream.poll_next(cx)
+    }
+}
+
+/// Future returned by [CallbackStream::collect].
+pub struct Collect<T> {
+    stream: CallbackStream<T>,
+    items: Vec<T>,
+}
+
+impl<T> Unpin for Collect<T> {}
+
+impl<T> Future for Collect<T> {
+    type Output = Result<Vec<T>, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            match self.stream.poll_next(cx) {
+                Poll::Ready(Some(item)) => self.items.push(item),
+                Poll::Ready(None) => {
+                    return Poll::Ready(match self.stream.error() {
+                        Some(error) => Err(error),
+                        None => Ok(std::mem::take(&mut self.items)),
+                    });
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+type TaskClosure = Arc<Mutex<Option<Box<dyn FnOnce() + Send>>>>;
+
+wrap_task! {
+    struct FutureTask {
+        closure: TaskClosure,
+    }
+
+    impl Task {
+        fn execute(&self) {
+            let closure = self.closure.lock().ok().and_then(|mut closure| closure.take());
+            if let Some(closure) = closure {
+                closure();
+            }
+        }
+    }
+}
+
+/// Run `f` on the CEF thread `thread_id` and resolve to its return value.
+pub fn post_task_async<F, T>(thread_id: ThreadId, f: F) -> CallbackFuture<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (completer, future) = CallbackFuture::new();
+    let closure: Box<dyn FnOnce() + Send> = Box::new(move || {
+        completer.complete(Ok(f()));
+    });
+    let mut task = FutureTask::new(Arc::new(Mutex::new(Some(closure))));
+    post_task(thread_id, Some(&mut task));
+    future
+}
+
+wrap_string_visitor! {
+    struct StringVisitorCompleter {
+        completer: Completer<String>,
+    }
+
+    impl CefStringVisitor {
+        fn visit(&self, string: Option<&CefString>) {
+            let string = string.map(ToString::to_string).unwrap_or_default();
+            self.completer.complete(Ok(string));
+        }
+    }
+}
+
+/// Async versions of the [ImplFrame] methods which take a visitor.
+pub trait FrameExt {
+    /// Retrieve the frame's HTML source.
+    fn source_async(&self) -> CallbackFuture<String>;
+    /// Retrieve the frame's display text.
+    fn text_async(&self) -> CallbackFuture<String>;
+}
+
+impl<F: ImplFrame> FrameExt for F {
+    fn source_async(&self) -> CallbackFuture<String> {
+        let (completer, future) = CallbackFuture::new();
+        self.source(Some(&mut StringVisitorCompleter::new(completer)));
+        future
+    }
+
+    fn text_async(&self) -> CallbackFuture<String> {
+        let (completer, future) = CallbackFuture::new();
+        self.text(Some(&mut StringVisitorCompleter::new(completer)));
+        future
+    }
+}
+
+/// An owned copy of a [Cookie], which is only valid for the duration of the visitor callback.
+#[derive(Clone, Debug)]
+pub struct CookieData {
+    pub name: String,
+    pub value: String,
+    pub domain: String,
+    pub path: String,
+    pub secure: bool,
+    pub httponly: bool,
+    pub creation: Basetime,
+    pub last_access: Basetime,
+    pub expires: Option<Basetime>,
+    pub same_site: CookieSameSite,
+    pub priority: CookiePriority,
+}
+
+impl From<&Cookie> for CookieData {
+    fn from(cookie: &Cookie) -> Self {
+        Self {
+            name: cookie.name.to_string(),
+            value: cookie.value.to_string(),
+            domain: cookie.domain.to_string(),
+            path: cookie.path.to_string(),
+            secure: cookie.secure != 0,
+            httponly: cookie.httponly != 0,
+            creation: cookie.creation.clone(),
+            last_access: cookie.last_access.clone(),
+            expires: (cookie.has_expires != 0).then(|| cookie.expires.clone()),
+            same_site: cookie.same_site,
+            priority: cookie.priority,
+        }
+    }
+}
+
+wrap_cookie_visitor! {
+    struct CookieStreamVisitor {
+        sender: StreamSender<CookieData>,
+    }
+
+    impl CookieVisitor {
+        fn visit(
+            &self,
+            cookie: Option<&Cookie>,
+            count: ::std::os::raw::c_int,
+            total: ::std::os::raw::c_int,
+            _delete_cookie: Option<&mut ::std::os::raw::c_int>,
+        ) -> ::std::os::raw::c_int {
+            let Some(cookie) = cookie else {
+                return 1;
+            };
+            if !self.sender.push(CookieData::from(cookie)) {
+                return 0;
+            }
+            if count + 1 >= total {
+                self.sender.close();
+            }
+            1
+        }
+    }
+}
+
+wrap_set_cookie_callback! {
+    struct SetCookieCompleter {
+        completer: Completer<()>,
+    }
+
+    impl SetCookieCallback {
+        fn on_complete(&self, success: ::std::os::raw::c_int) {
+            self.completer.complete(if success != 0 {
+                Ok(())
+            } else {
+                Err(Error::Failed("the cookie was not set".to_string()))
+            });
+        }
+    }
+}
+
+wrap_delete_cookies_callback! {
+    struct DeleteCookiesCompleter {
+        completer: Completer<usize>,
+    }
+
+    impl DeleteCookiesCallback {
+        fn on_complete(&self, num_deleted: ::std::os::raw::c_int) {
+            self.completer.complete(Ok(num_deleted.max(0) as usize));
+        }
+    }
+}
+
+wrap_completion_callback! {
+    struct CompletionCompleter {
+        completer: Completer<()>,
+    }
+
+    impl CompletionCallback {
+        fn on_complete(&self) {
+            self.completer.complete(Ok(()));
+        }
+    }
+}
+
+/// Async versions of the [ImplCookieManager] methods.
+pub trait CookieManagerExt {
+    /// Visit all cookies, in no particular order.
+    fn visit_all(&self) -> CallbackStream<CookieData>;
+    /// Visit the cookies that would be sent with a request to `url`.
+    fn visit_url(&self, url: &str, include_http_only: bool) -> CallbackStream<CookieData>;
+    /// Set a cookie for `url`.
+    fn set_cookie_async(&self, url: &str, cookie: &Cookie) -> CallbackFuture<()>;
+    /// Delete the cookies matching `url` and `cookie_name`, or all cookies if both are `None`.
+    /// Resolves to the number of cookies that were deleted.
+    fn delete_cookies_async(
+        &self,
+        url: Option<&str>,
+        cookie_name: Option<&str>,
+    ) -> CallbackFuture<usize>;
+    /// Flush the backing store to disk.
+    fn flush_store_async(&self) -> CallbackFuture<()>;
+}
+
+impl<M: ImplCookieManager> CookieManagerExt for M {
+    fn visit_all(&self) -> CallbackStream<CookieData> {
+        let (sender, stream) = CallbackStream::new();
+        if self.visit_all_cookies(Some(&mut CookieStreamVisitor::new(sender.clone()))) == 0 {
+            sender.fail(Error::Failed("cookies cannot be accessed".to_string()));
+        }
+        stream
+    }
+
+    fn visit_url(&self, url: &str, include_http_only: bool) -> CallbackStream<CookieData> {
+        let (sender, stream) = CallbackStream::new();
+        let url = CefString::from(url);
+        let mut visitor = CookieStreamVisitor::new(sender.clone());
+        if self.visit_url_cookies(Some(&url), include_http_only.into(), Some(&mut visitor)) == 0 {
+            sender.fail(Error::Failed("cookies cannot be accessed".to_string()));
+        }
+        stream
+    }
+
+    fn set_cookie_async(&self, url: &str, cookie: &Cookie) -> CallbackFuture<()> {
+        let (completer, future) = CallbackFuture::new();
+        let url = CefString::from(url);
+        let mut callback = SetCookieCompleter::new(completer.clone());
+        if self.set_cookie(Some(&url), Some(cookie), Some(&mut callback)) == 0 {
+            completer.complete(Err(Error::Failed(format!("invalid cookie for {url}"))));
+        }
+        future
+    }
+
+    fn delete_cookies_async(
+        &self,
+        url: Option<&str>,
+        cookie_name: Option<&str>,
+    ) -> CallbackFuture<usize> {
+        let (completer, future) = CallbackFuture::new();
+        let url = url.map(CefString::from);
+        let cookie_name = cookie_name.map(CefString::from);
+        let mut callback = DeleteCookiesCompleter::new(completer.clone());
+        if self.delete_cookies(url.as_ref(), cookie_name.as_ref(), Some(&mut callback)) == 0 {
+            completer.complete(Err(Error::Failed("invalid cookie filter".to_string())));
+        }
+        future
+    }
+
+    fn flush_store_async(&self) -> CallbackFuture<()> {
+        let (completer, future) = CallbackFuture::new();
+        self.flush_store(Some(&mut CompletionCompleter::new(completer)));
+        future
+    }
+}
+
+wrap_resolve_callback! {
+    struct ResolveCompleter {
+        completer: Completer<Vec<String>>,
+    }
+
+    impl ResolveCallback {
+        fn on_resolve_completed(&self, result: Errorcode, resolved_ips: Option<&mut CefStringList>) {
+            self.completer.complete(if result == Errorcode::NONE {
+                Ok(resolved_ips
+                    .map(|ips| ips.clone().into_iter().collect())
+                    .unwrap_or_default())
+            } else {
+                Err(Error::Failed(format!("failed to resolve host: {result:?}")))
+            });
+        }
+    }
+}
+
+/// Async versions of the [ImplRequestContext] methods which take a callback.
+pub trait RequestContextExt {
+    /// Resolve the host of `origin` to a list of IP addresses.
+    fn resolve_host_async(&self, origin: &str) -> CallbackFuture<Vec<String>>;
+}
+
+impl<R: ImplRequestContext> RequestContextExt for R {
+    fn resolve_host_async(&self, origin: &str) -> CallbackFuture<Vec<String>> {
+        let (completer, future) = CallbackFuture::new();
+        let origin = CefString::from(origin);
+        self.resolve_host(Some(&origin), Some(&mut ResolveCompleter::new(completer)));
+        future
+    }
+}
+
+wrap_pdf_print_callback! {
+    struct PdfPrintCompleter {
+        completer: Completer<String>,
+    }
+
+    impl PdfPrintCallback {
+        fn on_pdf_print_finished(&self, path: Option<&CefString>, ok: ::std::os::raw::c_int) {
+            let path = path.map(ToString::to_string).unwrap_or_default();
+            self.completer.complete(if ok != 0 {
+                Ok(path)
+            } else {
+                Err(Error::Failed(format!("failed to print to {path}")))
+            });
+        }
+    }
+}
+
+wrap_dev_tools_message_observer! {
+    struct DevToolsMethodObserver {
+        message_id: Arc<AtomicI32>,
+        completer: Completer<Vec<u8>>,
+    }
+
+    impl DevToolsMessageObserver {
+        fn on_dev_tools_method_result(
+            &self,
+            _browser: Option<&mut Browser>,
+            message_id: ::std::os::raw::c_int,
+            success: ::std::os::raw::c_int,
+            result: Option<&[u8]>,
+        ) {
+            if message_id != self.message_id.load(Ordering::Acquire) {
+                return;
+            }
+            let result = result.unwrap_or_default().to_vec();
+            self.completer.complete(if success != 0 {
+                Ok(result)
+            } else {
+                Err(Error::Failed(String::from_utf8_lossy(&result).into_owned()))
+            });
+        }
+    }
+}
+
+/// Future returned by [BrowserHostExt::execute_dev_tools_method_async], which keeps the
+/// observer registered until the result arrives.
+pub struct DevToolsMethodFuture {
+    result: CallbackFuture<Vec<u8>>,
+    registration: Option<Registration>,
+}
+
+impl Future for DevToolsMethodFuture {
+    type Output = Result<Vec<u8>, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let result = Pin::new(&mut self.result).poll(cx);
+        if result.is_ready() {
+            self.registration = None;
+        }
+        result
+    }
+}
+
+/// Async versions of the [ImplBrowserHost] methods which take a callback.
+pub trait BrowserHostExt {
+    /// Print the current page to a PDF file at `path`. Resolves to the path of the file.
+    fn print_to_pdf_async(&self, path: &str, settings: &PdfPrintSettings)
+        -> CallbackFuture<String>;
+    /// Execute a DevTools protocol method and resolve to the JSON encoded result. Must be
+    /// called on the UI thread.
+    fn execute_dev_tools_method_async(
+        &self,
+        method: &str,
+        params: Option<&mut DictionaryValue>,
+    ) -> DevToolsMethodFuture;
+}
+
+impl<H: ImplBrowserHost> BrowserHostExt for H {
+    fn print_to_pdf_async(
+        &self,
+        path: &str,
+        settings: &PdfPrintSettings,
+    ) -> CallbackFuture<String> {
+        let (completer, future) = CallbackFuture::new();
+        let path = CefString::from(path);
+        self.print_to_pdf(
+            Some(&path),
+            Some(settings),
+            Some(&mut PdfPrintCompleter::new(completer)),
+        );
+        future
+    }
+
+    fn execute_dev_tools_method_async(
+        &self,
+        method: &str,
+        params: Option<&mut DictionaryValue>,
+    ) -> DevToolsMethodFuture {
+        let (completer, result) = CallbackFuture::new();
+        let message_id = Arc::new(AtomicI32::new(0));
+        let mut observer = DevToolsMethodObserver::new(message_id.clone(), completer.clone());
+        let Some(registration) = self.add_dev_tools_message_observer(Some(&mut observer)) else {
+            return DevToolsMethodFuture {
+                result: CallbackFuture::ready(Err(Error::Canceled)),
+                registration: None,
+            };
+        };
+
+        // Results are delivered asynchronously on the UI thread, so the observer can't see
+        // one before the assigned id is stored.
+        let method = CefString::from(method);
+        let id = self.execute_dev_tools_method(0, Some(&method), params);
+        if id == 0 {
+            completer.complete(Err(Error::Failed(format!(
+                "failed to execute DevTools method {method}"
+            ))));
+            return DevToolsMethodFuture {
+                result,
+                registration: None,
+            };
+        }
+        message_id.store(id, Ordering::Release);
+
+        DevToolsMethodFuture {
+            result,
+            registration: Some(registration),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{
+        sync::Arc,
+        task::Wake,
+        thread::{self, Thread},
+    };
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn future_completes_from_other_thread() {
+        let (completer, future) = CallbackFuture::new();
+        let handle = thread::spawn(move || {
+            assert!(completer.complete(Ok(42)));
+            assert!(!completer.complete(Ok(0)));
+        });
+        assert_eq!(block_on(future), Ok(42));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn future_canceled_when_released() {
+        let (completer, future) = CallbackFuture::<()>::new();
+        let clone = completer.clone();
+        drop(completer);
+        let handle = thread::spawn(move || drop(clone));
+        assert_eq!(block_on(future), Err(Error::Canceled));
+        handle.join().unwrap();
+
+        let future = CallbackFuture::ready(Err::<(), _>(Error::Failed("nope".to_string())));
+        assert_eq!(block_on(future), Err(Error::Failed("nope".to_string())));
+    }
+
+    #[test]
+    fn stream_collects_items() {
+        let (sender, stream) = CallbackStream::new();
+        let handle = thread::spawn(move || {
+            for i in 0..3 {
+                assert!(sender.push(i));
+            }
+            sender.close();
+            assert!(!sender.push(3));
+        });
+        assert_eq!(block_on(stream.collect()), Ok(vec![0, 1, 2]));
+        handle.join().unwrap();
+
+        let (sender, stream) = CallbackStream::<i32>::new();
+        drop(sender);
+        assert_eq!(block_on(stream.collect()), Ok(vec![]));
+
+        let (sender, stream) = CallbackStream::new();
+        sender.push(1);
+        sender.fail(Error::Canceled);
+        assert_eq!(block_on(stream.collect()), Err(Error::Canceled));
+    }
+
+    #[test]
+    fn stream_next_and_detach() {
+        let (sender, mut stream) = CallbackStream::new();
+        sender.push("a");
+        assert_eq!(block_on(stream.next()), Some("a"));
+        drop(sender);
+        assert_eq!(block_on(stream.next()), None);
+        assert_eq!(stream.error(), None);
+
+        let (sender, stream) = CallbackStream::new();
+        drop(stream);
+        assert!(!sender.push("b"));
+    }
+
+    #[test]
+    fn task_resolves_future() {
+        let (completer, future) = CallbackFuture::new();
+        let closure: Box<dyn FnOnce() + Send> = Box::new(move || {
+            completer.complete(Ok("done"));
+        });
+        let task = FutureTask::new(Arc::new(Mutex::new(Some(closure))));
+        task.execute();
+        task.execute();
+        drop(task);
+        assert_eq!(block_on(future), Ok("done"));
+
+        let (completer, future) = CallbackFuture::<()>::new();
+        let closure: Box<dyn FnOnce() + Send> = Box::new(move || {
+            completer.complete(Ok(()));
+        });
+        drop(FutureTask::new(Arc::new(Mutex::new(Some(closure)))));
+        assert_eq!(block_on(future), Err(Error::Canceled));
+    }
+
+    #[test]
+    fn string_visitor_resolves_future() {
+        let (completer, future) = CallbackFuture::new();
+        let visitor = StringVisitorCompleter::new(completer);
+        visitor.visit(None);
+        drop(visitor);
+        assert_eq!(block_on(future), Ok(String::new()));
+    }
+}
@@ -1,11 +1,15 @@
 #![doc = include_str!("../README.md")]
 
 pub mod args;
+pub mod future;
 pub mod rc;
 pub mod string;
 pub mod window_info;
 pub mod wrapper;
 
+#[cfg(feature = "serde")]
+pub mod value;
+
 #[cfg(target_os = "macos")]
 pub mod application_mac;
 
@@ -25,6 +29,7 @@ pub mod build_util;
 mod bindings;
 pub use bindings::*;
 
+pub use future::post_task_async;
 pub use rc::Rc as _;
 
 pub use cef_dll_sys as sys;
@@ -812,6 +812,16 @@ where
     }
 }
 
+impl<T> CefStringCollection<T> {
+    /// The collection, unless it's a borrowed copy which CEF can't read through.
+    fn as_mut_ptr(&self) -> Option<*mut T> {
+        match self {
+            Self::BorrowedMut(Some(value)) | Self::Free(Some(value)) => Some(value.as_ptr()),
+            _ => None,
+        }
+    }
+}
+
 impl<T> Default for CefStringCollection<T> {
     fn default() -> Self {
         Self::Borrowed(None)
@@ -1074,9 +1084,13 @@ impl IntoIterator for CefStringMap {
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let mut map = self;
-        let map: *mut _cef_string_map_t = (&mut map).into();
-        let map = unsafe { map.as_mut() };
+        self.entries().into_iter()
+    }
+}
+
+impl CefStringMap {
+    fn entries(&self) -> Vec<(String, String)> {
+        let map = self.0.as_mut_ptr().and_then(|map| unsafe { map.as_mut() });
         map.map(|map| {
             let count = unsafe { cef_dll_sys::cef_string_map_size(map) };
             (0..count)
@@ -1096,7 +1110,6 @@ impl IntoIterator for CefStringMap {
                 .collect::<Vec<_>>()
         })
         .unwrap_or_default()
-        .into_iter()
     }
 }
 
@@ -1221,9 +1234,13 @@ impl IntoIterator for CefStringMultimap {
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let mut multimap = self;
-        let multimap: *mut _cef_string_multimap_t = (&mut multimap).into();
-        let multimap = unsafe { multimap.as_mut() };
+        self.entries().into_iter()
+    }
+}
+
+impl CefStringMultimap {
+    fn entries(&self) -> Vec<(String, Vec<String>)> {
+        let multimap = self.0.as_mut_ptr().and_then(|map| unsafe { map.as_mut() });
         let mut entries = vec![];
         multimap
             .map(|multimap| {
@@ -1265,7 +1282,6 @@ impl IntoIterator for CefStringMultimap {
                 entries
             })
             .unwrap_or_default()
-            .into_iter()
     }
 }
 
@@ -1325,12 +1341,136 @@ impl Debug for CefStringMultimap {
     }
 }
 
+#[cfg(feature = "serde")]
+mod serde_support {
+    use super::*;
+    use serde::{
+        de::{MapAccess, SeqAccess, Visitor},
+        ser::SerializeMap,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    /// Serializes as a map of strings.
+    impl Serialize for CefStringMap {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let entries = self.entries();
+            let mut map = serializer.serialize_map(Some(entries.len()))?;
+            for (key, value) in &entries {
+                map.serialize_entry(key, value)?;
+            }
+            map.end()
+        }
+    }
+
+    impl<'de> Deserialize<'de> for CefStringMap {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct MapVisitor;
+
+            impl<'de> Visitor<'de> for MapVisitor {
+                type Value = CefStringMap;
+
+                fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                    write!(f, "a map of strings")
+                }
+
+                fn visit_map<A: MapAccess<'de>>(
+                    self,
+                    mut access: A,
+                ) -> Result<Self::Value, A::Error> {
+                    let mut map = CefStringMap::new();
+                    while let Some((key, value)) = access.next_entry::<String, String>()? {
+                        map.append(&key, &value);
+                    }
+                    Ok(map)
+                }
+            }
+
+            deserializer.deserialize_map(MapVisitor)
+        }
+    }
+
+    /// Serializes as a map from each key to the list of its values.
+    impl Serialize for CefStringMultimap {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let entries = self.entries();
+            let mut map = serializer.serialize_map(Some(entries.len()))?;
+            for (key, values) in &entries {
+                map.serialize_entry(key, values)?;
+            }
+            map.end()
+        }
+    }
+
+    /// Accepts either a single string or a list of strings for each key.
+    impl<'de> Deserialize<'de> for CefStringMultimap {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct Values(Vec<String>);
+
+            impl<'de> Deserialize<'de> for Values {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    struct ValuesVisitor;
+
+                    impl<'de> Visitor<'de> for ValuesVisitor {
+                        type Value = Values;
+
+                        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                            write!(f, "a string or a list of strings")
+                        }
+
+                        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> {
+                            Ok(Values(vec![value.to_string()]))
+                        }
+
+                        fn visit_seq<A: SeqAccess<'de>>(
+                            self,
+                            mut access: A,
+                        ) -> Result<Self::Value, A::Error> {
+                            let mut values = vec![];
+                            while let Some(value) = access.next_element()? {
+                                values.push(value);
+                            }
+                            Ok(Values(values))
+                        }
+                    }
+
+                    deserializer.deserialize_any(ValuesVisitor)
+                }
+            }
+
+            struct MultimapVisitor;
+
+            impl<'de> Visitor<'de> for MultimapVisitor {
+                type Value = CefStringMultimap;
+
+                fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                    write!(f, "a map of strings or lists of strings")
+                }
+
+                fn visit_map<A: MapAccess<'de>>(
+                    self,
+                    mut access: A,
+                ) -> Result<Self::Value, A::Error> {
+                    let mut map = CefStringMultimap::new();
+                    while let Some((key, Values(values))) = access.next_entry::<String, Values>()? {
+                        for value in values {
+                            map.append(&key, &value);
+                        }
+                    }
+                    Ok(map)
+                }
+            }
+
+            deserializer.deserialize_map(MultimapVisitor)
+        }
+    }
+}
+
 #[cfg(test)]
-mod test {
+pub(crate) mod test {
     use crate::*;
 
     #[cfg(target_os = "macos")]
-    fn ensure_dll_loaded() {
+    pub(crate) fn ensure_dll_loaded() {
         use std::sync::Once;
 
         static LOAD_DLL: Once = Once::new();
@@ -1421,4 +1561,46 @@ mod test {
             ]
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_string_map_serde() {
+        #[cfg(target_os = "macos")]
+        ensure_dll_loaded();
+
+        let mut map = CefStringMap::new();
+        map.append("foo", "value1");
+        map.append("bar", "value2");
+
+        let json = serde_json::to_value(&map).expect("failed to serialize");
+        assert_eq!(
+            json,
+            serde_json::json!({ "foo": "value1", "bar": "value2" })
+        );
+
+        let map: CefStringMap = serde_json::from_value(json).expect("failed to deserialize");
+        let mut values: Vec<_> = map.into_iter().collect();
+        values.sort();
+        assert_eq!(
+            values,
+            vec![
+                ("bar".to_string(), "value2".to_string()),
+                ("foo".to_string(), "value1".to_string())
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_string_multimap_serde() {
+        #[cfg(target_os = "macos")]
+        ensure_dll_loaded();
+
+        let json = serde_json::json!({ "accept": ["text/html", "*/*"], "host": "example.com" });
+        let map: CefStringMultimap = serde_json::from_value(json).expect("failed to deserialize");
+        assert_eq!(
+            serde_json::to_value(&map).expect("failed to serialize"),
+            serde_json::json!({ "accept": ["text/html", "*/*"], "host": ["example.com"] })
+        );
+    }
 }
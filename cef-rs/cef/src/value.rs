@@ -0,0 +1,1390 @@
+//! Conversions between Rust types and CEF value trees.
+//!
+//! [to_value] serializes any [Serialize] type into a [Value] and [from_value] deserializes one
+//! back, so structured data can be passed through a [ProcessMessage] argument list without
+//! walking the [ListValue] and [DictionaryValue] by hand:
+//!
+//! ```no_run
+//! # use cef::{value::*, *};
+//! # use std::collections::HashMap;
+//! # fn example(message: ProcessMessage, zoom: &HashMap<String, f64>) -> Result<(), Error> {
+//! let args = message.argument_list().expect("argument list");
+//! args.set_value(0, Some(&mut to_value(zoom)?));
+//! let zoom: HashMap<String, f64> = from_value(&args.value(0).expect("argument"))?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The mapping is the same as the one `serde_json` uses: structs and maps become dictionaries,
+//! sequences and tuples become lists, `None` and `()` become null and enums are externally
+//! tagged. [to_json] and [from_json] convert directly to and from a [serde_json::Value]. A few
+//! types need special handling to fit the CEF value types:
+//!
+//! - CEF integers only have 32 bits, so larger integers are stored as doubles as long as that
+//!   is lossless, i.e. their magnitude is at most 2^53. Anything larger fails with
+//!   [Error::IntegerOutOfRange]. In the other direction, integer types accept doubles that have
+//!   an integral value.
+//! - Byte strings become binary values. CEF can't create an empty binary value, so an empty byte
+//!   string becomes an empty list instead. Binary values deserialize into byte buffers as well as
+//!   `Vec<u8>`.
+//! - Dictionary keys are always strings. Integer map keys are written as decimal strings, and
+//!   parsed again when they are deserialized.
+
+use crate::*;
+use serde::{
+    de::{
+        self, value::SeqDeserializer, DeserializeOwned, DeserializeSeed, EnumAccess,
+        IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor,
+    },
+    forward_to_deserialize_any,
+    ser::{
+        self, Impossible, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+        SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+    },
+    Deserializer, Serialize, Serializer,
+};
+use std::fmt;
+
+/// The largest magnitude of an integer which can be stored in a double without losing precision.
+const MAX_SAFE_INTEGER: u64 = 1 << 53;
+
+/// The reason a conversion to or from a CEF value failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// CEF could not create a value, e.g. because it hasn't been initialized.
+    CreateFailed,
+    /// An integer doesn't fit in a CEF int, and can't be stored as a double without losing
+    /// precision.
+    IntegerOutOfRange,
+    /// A map key doesn't serialize to a string or an integer.
+    KeyMustBeString,
+    /// The value doesn't have the type the caller asked for.
+    UnexpectedType(ValueType),
+    /// An error reported by a [Serialize] or [de::Deserialize] implementation.
+    Message(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::CreateFailed => write!(f, "failed to create a CEF value"),
+            Self::IntegerOutOfRange => {
+                write!(f, "integer can't be represented exactly as a CEF value")
+            }
+            Self::KeyMustBeString => write!(f, "dictionary key must be a string or an integer"),
+            Self::UnexpectedType(value_type) => {
+                write!(f, "unexpected CEF value type {}", value_type.get_raw())
+            }
+            Self::Message(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Message(msg.to_string())
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Message(msg.to_string())
+    }
+}
+
+/// Serialize `value` into a new CEF value.
+pub fn to_value<T>(value: &T) -> Result<Value, Error>
+where
+    T: Serialize + ?Sized,
+{
+    value.serialize(ValueSerializer)
+}
+
+/// Serialize `value` into a new [DictionaryValue]. Fails with [Error::UnexpectedType] if it
+/// isn't a struct or a map.
+pub fn to_dictionary_value<T>(value: &T) -> Result<DictionaryValue, Error>
+where
+    T: Serialize + ?Sized,
+{
+    let value = to_value(value)?;
+    match value.get_type() {
+        ValueType::DICTIONARY => value.dictionary().ok_or(Error::CreateFailed),
+        value_type => Err(Error::UnexpectedType(value_type)),
+    }
+}
+
+/// Serialize `value` into a new [ListValue]. Fails with [Error::UnexpectedType] if it isn't a
+/// sequence or a tuple.
+pub fn to_list_value<T>(value: &T) -> Result<ListValue, Error>
+where
+    T: Serialize + ?Sized,
+{
+    let value = to_value(value)?;
+    match value.get_type() {
+        ValueType::LIST => value.list().ok_or(Error::CreateFailed),
+        value_type => Err(Error::UnexpectedType(value_type)),
+    }
+}
+
+/// Deserialize an instance of `T` from a CEF value.
+pub fn from_value<T>(value: &Value) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(ValueDeserializer(value.clone()))
+}
+
+/// Deserialize an instance of `T` from the entries of a [DictionaryValue].
+pub fn from_dictionary_value<T>(value: &DictionaryValue) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(DictionaryDeserializer(value.clone()))
+}
+
+/// Deserialize an instance of `T` from the elements of a [ListValue].
+pub fn from_list_value<T>(value: &ListValue) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(ListDeserializer(value.clone()))
+}
+
+/// Convert a CEF value to JSON. Binary values become arrays of bytes.
+pub fn to_json(value: &Value) -> serde_json::Value {
+    // Dictionary keys are always strings, which is the only way this can fail.
+    serde_json::to_value(value).unwrap_or_default()
+}
+
+/// Convert JSON to a new CEF value.
+pub fn from_json(value: &serde_json::Value) -> Result<Value, Error> {
+    to_value(value)
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.get_type() {
+            ValueType::BOOL => serializer.serialize_bool(self.bool() != 0),
+            ValueType::INT => serializer.serialize_i32(self.int()),
+            ValueType::DOUBLE => serializer.serialize_f64(self.double()),
+            ValueType::STRING => {
+                serializer.serialize_str(&CefString::from(&self.string()).to_string())
+            }
+            ValueType::BINARY => match self.binary() {
+                Some(binary) => binary.serialize(serializer),
+                None => serializer.serialize_unit(),
+            },
+            ValueType::DICTIONARY => match self.dictionary() {
+                Some(dictionary) => dictionary.serialize(serializer),
+                None => serializer.serialize_unit(),
+            },
+            ValueType::LIST => match self.list() {
+                Some(list) => list.serialize(serializer),
+                None => serializer.serialize_unit(),
+            },
+            _ => serializer.serialize_unit(),
+        }
+    }
+}
+
+impl Serialize for DictionaryValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let keys = dictionary_keys(self);
+        let mut map = serializer.serialize_map(Some(keys.len()))?;
+        for key in keys {
+            let value = self.value(Some(&CefString::from(key.as_str())));
+            map.serialize_entry(&key, &value)?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for ListValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let size = self.size();
+        let mut seq = serializer.serialize_seq(Some(size))?;
+        for index in 0..size {
+            seq.serialize_element(&self.value(index))?;
+        }
+        seq.end()
+    }
+}
+
+impl Serialize for BinaryValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&binary_data(self))
+    }
+}
+
+fn dictionary_keys(dictionary: &DictionaryValue) -> Vec<String> {
+    let mut keys = CefStringList::new();
+    if dictionary.keys(Some(&mut keys)) == 0 {
+        return vec![];
+    }
+    keys.into_iter().collect()
+}
+
+fn binary_data(binary: &BinaryValue) -> Vec<u8> {
+    let mut buffer = vec![0; binary.size()];
+    if !buffer.is_empty() {
+        let size = binary.data(Some(&mut buffer), 0);
+        buffer.truncate(size);
+    }
+    buffer
+}
+
+fn create_value() -> Result<Value, Error> {
+    value_create().ok_or(Error::CreateFailed)
+}
+
+fn checked(result: std::os::raw::c_int) -> Result<(), Error> {
+    if result == 0 {
+        Err(Error::CreateFailed)
+    } else {
+        Ok(())
+    }
+}
+
+fn integer_value(value: i128) -> Result<Value, Error> {
+    let result = create_value()?;
+    if let Ok(value) = i32::try_from(value) {
+        checked(result.set_int(value))?;
+    } else if value.unsigned_abs() <= MAX_SAFE_INTEGER as u128 {
+        checked(result.set_double(value as f64))?;
+    } else {
+        return Err(Error::IntegerOutOfRange);
+    }
+    Ok(result)
+}
+
+fn string_value(value: &str) -> Result<Value, Error> {
+    let result = create_value()?;
+    checked(result.set_string(Some(&CefString::from(value))))?;
+    Ok(result)
+}
+
+fn list_value(mut list: ListValue) -> Result<Value, Error> {
+    let result = create_value()?;
+    checked(result.set_list(Some(&mut list)))?;
+    Ok(result)
+}
+
+fn dictionary_value(mut dictionary: DictionaryValue) -> Result<Value, Error> {
+    let result = create_value()?;
+    checked(result.set_dictionary(Some(&mut dictionary)))?;
+    Ok(result)
+}
+
+/// Wrap `value` in a dictionary with a single entry, which is how enum variants with data are
+/// tagged.
+fn variant_value(variant: &str, mut value: Value) -> Result<Value, Error> {
+    let dictionary = dictionary_value_create().ok_or(Error::CreateFailed)?;
+    checked(dictionary.set_value(Some(&CefString::from(variant)), Some(&mut value)))?;
+    dictionary_value(dictionary)
+}
+
+struct ValueSerializer;
+
+impl Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    type SerializeSeq = ListSerializer;
+    type SerializeTuple = ListSerializer;
+    type SerializeTupleStruct = ListSerializer;
+    type SerializeTupleVariant = ListSerializer;
+    type SerializeMap = DictionarySerializer;
+    type SerializeStruct = DictionarySerializer;
+    type SerializeStructVariant = DictionarySerializer;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, Error> {
+        let result = create_value()?;
+        checked(result.set_bool(v.into()))?;
+        Ok(result)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, Error> {
+        integer_value(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, Error> {
+        integer_value(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, Error> {
+        integer_value(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, Error> {
+        integer_value(v.into())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Value, Error> {
+        integer_value(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, Error> {
+        integer_value(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, Error> {
+        integer_value(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, Error> {
+        integer_value(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, Error> {
+        integer_value(v.into())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Value, Error> {
+        integer_value(i128::try_from(v).map_err(|_| Error::IntegerOutOfRange)?)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value, Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value, Error> {
+        let result = create_value()?;
+        checked(result.set_double(v))?;
+        Ok(result)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, Error> {
+        string_value(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, Error> {
+        string_value(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, Error> {
+        if v.is_empty() {
+            return list_value(list_value_create().ok_or(Error::CreateFailed)?);
+        }
+        let mut binary = binary_value_create(Some(v)).ok_or(Error::CreateFailed)?;
+        let result = create_value()?;
+        checked(result.set_binary(Some(&mut binary)))?;
+        Ok(result)
+    }
+
+    fn serialize_none(self) -> Result<Value, Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Value, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, Error> {
+        let result = create_value()?;
+        checked(result.set_null())?;
+        Ok(result)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Value, Error> {
+        string_value(variant)
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<Value, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        variant_value(variant, to_value(value)?)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<ListSerializer, Error> {
+        ListSerializer::new(None)
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<ListSerializer, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<ListSerializer, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<ListSerializer, Error> {
+        ListSerializer::new(Some(variant))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<DictionarySerializer, Error> {
+        DictionarySerializer::new(None)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<DictionarySerializer, Error> {
+        DictionarySerializer::new(None)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<DictionarySerializer, Error> {
+        DictionarySerializer::new(Some(variant))
+    }
+}
+
+struct ListSerializer {
+    list: ListValue,
+    variant: Option<&'static str>,
+}
+
+impl ListSerializer {
+    fn new(variant: Option<&'static str>) -> Result<Self, Error> {
+        Ok(Self {
+            list: list_value_create().ok_or(Error::CreateFailed)?,
+            variant,
+        })
+    }
+
+    fn push<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let mut value = to_value(value)?;
+        checked(self.list.set_value(self.list.size(), Some(&mut value)))
+    }
+
+    fn finish(self) -> Result<Value, Error> {
+        let value = list_value(self.list)?;
+        match self.variant {
+            Some(variant) => variant_value(variant, value),
+            None => Ok(value),
+        }
+    }
+}
+
+impl SerializeSeq for ListSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl SerializeTuple for ListSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl SerializeTupleStruct for ListSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl SerializeTupleVariant for ListSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+struct DictionarySerializer {
+    dictionary: DictionaryValue,
+    variant: Option<&'static str>,
+    next_key: Option<String>,
+}
+
+impl DictionarySerializer {
+    fn new(variant: Option<&'static str>) -> Result<Self, Error> {
+        Ok(Self {
+            dictionary: dictionary_value_create().ok_or(Error::CreateFailed)?,
+            variant,
+            next_key: None,
+        })
+    }
+
+    fn insert<T>(&mut self, key: &str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let mut value = to_value(value)?;
+        checked(
+            self.dictionary
+                .set_value(Some(&CefString::from(key)), Some(&mut value)),
+        )
+    }
+
+    fn finish(self) -> Result<Value, Error> {
+        let value = dictionary_value(self.dictionary)?;
+        match self.variant {
+            Some(variant) => variant_value(variant, value),
+            None => Ok(value),
+        }
+    }
+}
+
+impl SerializeMap for DictionarySerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.next_key = Some(key.serialize(KeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = self
+            .next_key
+            .take()
+            .ok_or_else(|| Error::Message("serialize_value called before serialize_key".into()))?;
+        self.insert(&key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl SerializeStruct for DictionarySerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl SerializeStructVariant for DictionarySerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+/// Serializes map keys, which must be strings in a [DictionaryValue].
+struct KeySerializer;
+
+impl Serializer for KeySerializer {
+    type Ok = String;
+    type Error = Error;
+
+    type SerializeSeq = Impossible<String, Error>;
+    type SerializeTuple = Impossible<String, Error>;
+    type SerializeTupleStruct = Impossible<String, Error>;
+    type SerializeTupleVariant = Impossible<String, Error>;
+    type SerializeMap = Impossible<String, Error>;
+    type SerializeStruct = Impossible<String, Error>;
+    type SerializeStructVariant = Impossible<String, Error>;
+
+    fn serialize_bool(self, _v: bool) -> Result<String, Error> {
+        Err(Error::KeyMustBeString)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<String, Error> {
+        Err(Error::KeyMustBeString)
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<String, Error> {
+        Err(Error::KeyMustBeString)
+    }
+
+    fn serialize_char(self, v: char) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<String, Error> {
+        Err(Error::KeyMustBeString)
+    }
+
+    fn serialize_none(self) -> Result<String, Error> {
+        Err(Error::KeyMustBeString)
+    }
+
+    fn serialize_some<T>(self, _value: &T) -> Result<String, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(Error::KeyMustBeString)
+    }
+
+    fn serialize_unit(self) -> Result<String, Error> {
+        Err(Error::KeyMustBeString)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<String, Error> {
+        Err(Error::KeyMustBeString)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<String, Error> {
+        Ok(variant.to_string())
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<String, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<String, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(Error::KeyMustBeString)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        Err(Error::KeyMustBeString)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Error> {
+        Err(Error::KeyMustBeString)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        Err(Error::KeyMustBeString)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Err(Error::KeyMustBeString)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Err(Error::KeyMustBeString)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        Err(Error::KeyMustBeString)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Err(Error::KeyMustBeString)
+    }
+}
+
+struct ValueDeserializer(Value);
+
+impl ValueDeserializer {
+    fn is_null(&self) -> bool {
+        matches!(self.0.get_type(), ValueType::NULL | ValueType::INVALID)
+    }
+
+    /// Doubles are how integers which don't fit in a CEF int are stored, so visit the ones with
+    /// an integral value as integers.
+    fn deserialize_integer<'de, V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.0.get_type() == ValueType::DOUBLE {
+            let value = self.0.double();
+            if value.fract() == 0.0 && value.abs() <= MAX_SAFE_INTEGER as f64 {
+                return if value < 0.0 {
+                    visitor.visit_i64(value as i64)
+                } else {
+                    visitor.visit_u64(value as u64)
+                };
+            }
+        }
+        self.deserialize_any(visitor)
+    }
+}
+
+macro_rules! deserialize_integers {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                self.deserialize_integer(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0.get_type() {
+            ValueType::BOOL => visitor.visit_bool(self.0.bool() != 0),
+            ValueType::INT => visitor.visit_i32(self.0.int()),
+            ValueType::DOUBLE => visitor.visit_f64(self.0.double()),
+            ValueType::STRING => {
+                visitor.visit_string(CefString::from(&self.0.string()).to_string())
+            }
+            ValueType::BINARY => match self.0.binary() {
+                Some(binary) => visitor.visit_byte_buf(binary_data(&binary)),
+                None => visitor.visit_unit(),
+            },
+            ValueType::DICTIONARY => match self.0.dictionary() {
+                Some(dictionary) => DictionaryDeserializer(dictionary).deserialize_any(visitor),
+                None => visitor.visit_unit(),
+            },
+            ValueType::LIST => match self.0.list() {
+                Some(list) => ListDeserializer(list).deserialize_any(visitor),
+                None => visitor.visit_unit(),
+            },
+            _ => visitor.visit_unit(),
+        }
+    }
+
+    deserialize_integers! {
+        deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64 deserialize_i128
+        deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64 deserialize_u128
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.is_null() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        // Let `Vec<u8>` and other sequences of bytes read binary values
+        if self.0.get_type() == ValueType::BINARY {
+            if let Some(binary) = self.0.binary() {
+                let mut seq = SeqDeserializer::<_, Error>::new(binary_data(&binary).into_iter());
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                return Ok(value);
+            }
+        }
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.0.get_type() {
+            ValueType::STRING => visitor.visit_enum(
+                CefString::from(&self.0.string())
+                    .to_string()
+                    .into_deserializer(),
+            ),
+            ValueType::DICTIONARY => match self.0.dictionary() {
+                Some(dictionary) => {
+                    DictionaryDeserializer(dictionary).deserialize_enum(name, variants, visitor)
+                }
+                None => Err(Error::CreateFailed),
+            },
+            value_type => Err(Error::UnexpectedType(value_type)),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool f32 f64 char str string bytes byte_buf unit unit_struct tuple tuple_struct map
+        struct identifier
+    }
+}
+
+struct DictionaryDeserializer(DictionaryValue);
+
+impl<'de> Deserializer<'de> for DictionaryDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let keys = dictionary_keys(&self.0);
+        let mut map = DictionaryAccess {
+            dictionary: self.0,
+            keys: keys.into_iter(),
+            key: None,
+        };
+        let value = visitor.visit_map(&mut map)?;
+        match map.keys.len() {
+            0 => Ok(value),
+            remaining => Err(de::Error::invalid_length(
+                remaining,
+                &"fewer entries in the dictionary",
+            )),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let mut keys = dictionary_keys(&self.0);
+        if keys.len() != 1 {
+            return Err(de::Error::invalid_length(
+                keys.len(),
+                &"a dictionary with a single key",
+            ));
+        }
+        let variant = keys.remove(0);
+        let value = self
+            .0
+            .value(Some(&CefString::from(variant.as_str())))
+            .ok_or(Error::CreateFailed)?;
+        visitor.visit_enum(VariantDeserializer {
+            variant,
+            value: ValueDeserializer(value),
+        })
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+struct DictionaryAccess {
+    dictionary: DictionaryValue,
+    keys: std::vec::IntoIter<String>,
+    key: Option<String>,
+}
+
+impl<'de> MapAccess<'de> for DictionaryAccess {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let Some(key) = self.keys.next() else {
+            return Ok(None);
+        };
+        let result = seed.deserialize(KeyDeserializer(key.clone()))?;
+        self.key = Some(key);
+        Ok(Some(result))
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error::Message("next_value_seed called before next_key_seed".into()))?;
+        let value = self
+            .dictionary
+            .value(Some(&CefString::from(key.as_str())))
+            .ok_or(Error::CreateFailed)?;
+        seed.deserialize(ValueDeserializer(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.keys.len())
+    }
+}
+
+struct ListDeserializer(ListValue);
+
+impl<'de> Deserializer<'de> for ListDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let size = self.0.size();
+        let mut seq = ListAccess {
+            list: self.0,
+            index: 0,
+            size,
+        };
+        let value = visitor.visit_seq(&mut seq)?;
+        match size - seq.index {
+            0 => Ok(value),
+            remaining => Err(de::Error::invalid_length(
+                remaining,
+                &"fewer elements in the list",
+            )),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier
+    }
+}
+
+struct ListAccess {
+    list: ListValue,
+    index: usize,
+    size: usize,
+}
+
+impl<'de> SeqAccess<'de> for ListAccess {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if self.index >= self.size {
+            return Ok(None);
+        }
+        let value = self.list.value(self.index).ok_or(Error::CreateFailed)?;
+        self.index += 1;
+        seed.deserialize(ValueDeserializer(value)).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.size - self.index)
+    }
+}
+
+struct VariantDeserializer {
+    variant: String,
+    value: ValueDeserializer,
+}
+
+impl<'de> EnumAccess<'de> for VariantDeserializer {
+    type Error = Error;
+    type Variant = ValueDeserializer;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, ValueDeserializer), Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant =
+            seed.deserialize(IntoDeserializer::<Error>::into_deserializer(self.variant))?;
+        Ok((variant, self.value))
+    }
+}
+
+impl<'de> VariantAccess<'de> for ValueDeserializer {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        if self.is_null() {
+            Ok(())
+        } else {
+            Err(Error::UnexpectedType(self.0.get_type()))
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_map(visitor)
+    }
+}
+
+/// Deserializes dictionary keys, parsing them again if the caller expects an integer.
+struct KeyDeserializer(String);
+
+macro_rules! deserialize_parsed_keys {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                match self.0.parse() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(_) => self.deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for KeyDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_string(self.0)
+    }
+
+    deserialize_parsed_keys! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        bool f32 f64 char str string bytes byte_buf unit unit_struct seq tuple tuple_struct map
+        struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Point(i32, i32),
+        Rect { width: u32, height: u32 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Message {
+        name: String,
+        id: u64,
+        offset: i64,
+        ratio: f32,
+        tag: Option<char>,
+        enabled: bool,
+        shapes: Vec<Shape>,
+        counts: BTreeMap<u16, String>,
+        pair: (u8, String),
+    }
+
+    fn round_trip<T>(value: &T) -> T
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        #[cfg(target_os = "macos")]
+        crate::string::test::ensure_dll_loaded();
+
+        from_value(&to_value(value).expect("failed to serialize")).expect("failed to deserialize")
+    }
+
+    #[test]
+    fn round_trip_struct() {
+        #[cfg(target_os = "macos")]
+        crate::string::test::ensure_dll_loaded();
+
+        let message = Message {
+            name: "tab".to_string(),
+            id: 1 << 40,
+            offset: -(1 << 53),
+            ratio: 0.5,
+            tag: None,
+            enabled: true,
+            shapes: vec![
+                Shape::Empty,
+                Shape::Circle(1.5),
+                Shape::Point(-1, 2),
+                Shape::Rect {
+                    width: 3,
+                    height: 4,
+                },
+            ],
+            counts: [(1, "one".to_string()), (200, "two hundred".to_string())].into(),
+            pair: (7, "seven".to_string()),
+        };
+        assert_eq!(round_trip(&message), message);
+
+        let dictionary = to_dictionary_value(&message).expect("failed to serialize");
+        assert_eq!(
+            CefString::from(&dictionary.string(Some(&CefString::from("name")))).to_string(),
+            "tab"
+        );
+        assert_eq!(
+            dictionary.get_type(Some(&CefString::from("id"))),
+            ValueType::DOUBLE
+        );
+        assert_eq!(
+            from_dictionary_value::<Message>(&dictionary).expect("failed to deserialize"),
+            message
+        );
+    }
+
+    #[test]
+    fn integers() {
+        #[cfg(target_os = "macos")]
+        crate::string::test::ensure_dll_loaded();
+
+        let value = to_value(&i32::MIN).expect("failed to serialize");
+        assert_eq!(value.get_type(), ValueType::INT);
+        assert_eq!(from_value::<i64>(&value), Ok(i32::MIN.into()));
+
+        let value = to_value(&u32::MAX).expect("failed to serialize");
+        assert_eq!(value.get_type(), ValueType::DOUBLE);
+        assert_eq!(from_value::<u32>(&value), Ok(u32::MAX));
+        assert!(from_value::<u16>(&value).is_err());
+
+        assert_eq!(round_trip(&(1u64 << 53)), 1 << 53);
+        assert_eq!(round_trip(&-(1i64 << 53)), -(1 << 53));
+        assert_eq!(
+            to_value(&((1u64 << 53) + 1)).err(),
+            Some(Error::IntegerOutOfRange)
+        );
+        assert_eq!(to_value(&i64::MIN).err(), Some(Error::IntegerOutOfRange));
+
+        let value = to_value(&2.5).expect("failed to serialize");
+        assert!(from_value::<i32>(&value).is_err());
+        assert_eq!(from_value::<f64>(&to_value(&3).unwrap()), Ok(3.0));
+    }
+
+    #[test]
+    fn binary() {
+        #[cfg(target_os = "macos")]
+        crate::string::test::ensure_dll_loaded();
+
+        let bytes = serde_json::from_str::<Vec<u8>>("[0, 1, 254, 255]").unwrap();
+        let value = to_value(&Bytes(&bytes)).expect("failed to serialize");
+        assert_eq!(value.get_type(), ValueType::BINARY);
+        assert_eq!(from_value::<Vec<u8>>(&value), Ok(bytes));
+        assert_eq!(to_json(&value), serde_json::json!([0, 1, 254, 255]));
+
+        let value = to_value(&Bytes(&[])).expect("failed to serialize");
+        assert_eq!(value.get_type(), ValueType::LIST);
+        assert_eq!(from_value::<Vec<u8>>(&value), Ok(vec![]));
+    }
+
+    struct Bytes<'a>(&'a [u8]);
+
+    impl Serialize for Bytes<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(self.0)
+        }
+    }
+
+    #[test]
+    fn json() {
+        #[cfg(target_os = "macos")]
+        crate::string::test::ensure_dll_loaded();
+
+        let json = serde_json::json!({
+            "url": "https://example.com",
+            "frames": [1, 2.5, null, true],
+            "headers": { "accept": ["text/html"] },
+        });
+        let value = from_json(&json).expect("failed to convert");
+        assert_eq!(value.get_type(), ValueType::DICTIONARY);
+        assert_eq!(to_json(&value), json);
+
+        let list = to_list_value(&json["frames"]).expect("failed to convert");
+        assert_eq!(list.size(), 4);
+        assert_eq!(list.get_type(2), ValueType::NULL);
+        assert!(matches!(
+            to_list_value(&json),
+            Err(Error::UnexpectedType(ValueType::DICTIONARY))
+        ));
+        assert_eq!(
+            from_list_value::<(i32, f64, Option<u8>, bool)>(&list),
+            Ok((1, 2.5, None, true))
+        );
+    }
+}
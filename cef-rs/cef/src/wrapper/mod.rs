@@ -2,6 +2,8 @@ pub mod browser_info_map;
 pub mod byte_read_handler;
 pub mod message_router;
 pub mod resource_manager;
+#[cfg(feature = "serde")]
+pub mod rpc;
 pub mod stream_resource_handler;
 pub mod zip_archive;
 
@@ -39,9 +39,9 @@ struct RequestState {
     /// list.
     current_entry: usize,
 
-    /// Position of this request object in the currently associated
-    /// [ProviderEntry]'s `pending_requests` list.
-    current_request: usize,
+    /// This request object in the currently associated [ProviderEntry]'s `pending_requests`
+    /// list.
+    current_request: Weak<Mutex<ResourceManagerRequest>>,
 
     /// Params that will be copied to each request object.
     params: Arc<RequestParams>,
@@ -57,22 +57,11 @@ impl Drop for RequestState {
 }
 
 pub struct ResourceManagerRequest {
-    weak_self: Weak<Mutex<Self>>,
     state: Option<RequestState>,
     params: Arc<RequestParams>,
 }
 
 impl ResourceManagerRequest {
-    fn new(state: Option<RequestState>, params: Arc<RequestParams>) -> Arc<Mutex<Self>> {
-        Arc::new_cyclic(|weak_self| {
-            Mutex::new(Self {
-                weak_self: weak_self.clone(),
-                state,
-                params,
-            })
-        })
-    }
-
     /// Returns the URL associated with this request. The returned value will be fully qualified
     /// but will not contain query or fragment components. It will already have been passed through
     /// the URL filter.
@@ -130,41 +119,6 @@ impl ResourceManagerRequest {
         let mut task = StopRequest::new(Arc::new(Mutex::new(Some(state))));
         post_task(io_thread_id, Some(&mut task));
     }
-
-    /// Detaches and returns `self.state` if the provider indicates that it will not handle the
-    /// request. Note that `self.state` may already be [None] if [ResourceManagerProvider::on_request]
-    /// executes a callback before returning, in which case execution will continue asynchronously
-    /// in any case.
-    fn send_request(&mut self) -> Option<RequestState> {
-        debug_assert_ne!(
-            currently_on(ThreadId::IO),
-            0,
-            "send_request must be called on the IO thread"
-        );
-
-        let state = self.state.as_ref()?;
-        let Some(manager) = state.manager.upgrade() else {
-            return self.state.take();
-        };
-        let Ok(mut manager) = manager.lock() else {
-            return self.state.take();
-        };
-        let Some(provider_entry) = manager.providers.get_mut(state.current_entry) else {
-            return self.state.take();
-        };
-        let Some(provider) = provider_entry.provider.as_mut() else {
-            return self.state.take();
-        };
-
-        let Some(request) = self.weak_self.upgrade() else {
-            return self.state.take();
-        };
-        if !provider.on_request(request) {
-            return self.state.take();
-        }
-
-        None
-    }
 }
 
 wrap_task! {
@@ -930,7 +884,7 @@ impl ResourceManager {
             manager: self.weak_self.clone(),
             callback: Some(callback),
             current_entry: first_entry,
-            current_request: 0,
+            current_request: Weak::new(),
             params: Arc::new(RequestParams {
                 url,
                 browser,
@@ -967,40 +921,60 @@ impl ResourceManager {
 
     /// Send the request to providers in order until one potentially handles it or we run out of
     /// providers. Returns true if the request is potentially handled.
-    fn send_request(&mut self, state: RequestState) -> bool {
-        let mut potentially_handled = false;
-
-        let current_entry = state.current_entry;
+    fn send_request(&mut self, mut state: RequestState) -> bool {
         let params = state.params.clone();
-        let mut state = Some(state);
 
-        while state.is_some() {
+        loop {
             debug_assert!(
-                current_entry < self.providers.len(),
+                state.current_entry < self.providers.len(),
                 "Should not be on the last provider entry."
             );
-            let request = ResourceManagerRequest::new(state.take(), params.clone());
-            let Ok(mut request) = request.lock() else {
-                break;
+            let Some(provider_entry) = self.providers.get_mut(state.current_entry) else {
+                self.stop_request(state);
+                return false;
             };
 
-            // Give the provider an opportunity to handle the request.
-            state = request.send_request();
-
-            let Some(mut next_state) = state.take() else {
-                potentially_handled = true;
-                break;
+            // Associate the request with the provider so that it can be canceled if the provider
+            // is removed while the request is pending.
+            let request = Arc::new_cyclic(|weak_request| {
+                state.current_request = weak_request.clone();
+                Mutex::new(ResourceManagerRequest {
+                    state: Some(state),
+                    params: params.clone(),
+                })
+            });
+            provider_entry.pending_requests.push_back(request.clone());
+
+            // Give the provider an opportunity to handle the request. The request must not be
+            // locked here because the provider may lock it to continue or stop the request.
+            let handled = provider_entry
+                .provider
+                .as_ref()
+                .map(|provider| provider.on_request(request.clone()))
+                .unwrap_or(false);
+
+            // If the provider continued or stopped the request before returning then the state has
+            // already been detached and execution will continue asynchronously.
+            let next_state = if handled {
+                None
+            } else {
+                request
+                    .lock()
+                    .ok()
+                    .and_then(|mut request| request.state.take())
+            };
+            let Some(mut next_state) = next_state else {
+                return true;
             };
 
             // The provider will not handle the request. Move to the next provider if any.
             if self.increment_provider(&mut next_state) {
-                state = Some(next_state);
+                state = next_state;
             } else {
                 self.stop_request(next_state);
+                return false;
             }
         }
-
-        potentially_handled
     }
 
     fn continue_request(&mut self, mut state: RequestState, handler: Option<ResourceHandler>) {
@@ -1053,7 +1027,9 @@ impl ResourceManager {
         let Some(current_entry) = self.providers.get_mut(state.current_entry) else {
             return;
         };
-        current_entry.pending_requests.remove(state.current_request);
+        current_entry
+            .pending_requests
+            .retain(|request| Arc::as_ptr(request) != state.current_request.as_ptr());
         if current_entry.deletion_pending && current_entry.pending_requests.is_empty() {
             // Delete the current provider entry now.
             self.providers.remove(state.current_entry);
@@ -0,0 +1,988 @@
+//! Typed request/response calls between JavaScript and the browser process, built on top of
+//! the [message_router](super::message_router).
+//!
+//! The browser process registers named handlers with an [RpcHandler], which receive requests
+//! and send responses as any [Serialize] and [Deserialize] types. The renderer process uses a
+//! [RendererSideRpc], which adds a `window.<namespace>` object to each V8 context. Every
+//! property of that object is a function that calls the handler with the same name and
+//! returns a Promise:
+//!
+//! ```js
+//! const title = await window.termsurf.setTitle({ title: "Build logs" });
+//!
+//! // Calls can be aborted with an AbortSignal
+//! const controller = new AbortController();
+//! const output = await window.termsurf.runCommand({ command: "make" }, { signal: controller.signal });
+//!
+//! // Streaming handlers are subscribed to, and deliver any number of events
+//! const subscription = window.termsurf.watchOutput.subscribe({ pane: 1 }, (line) => {
+//!   console.log(line);
+//! });
+//! subscription.cancel();
+//! await subscription.done;
+//! ```
+//!
+//! Failed calls reject with an `Error` whose `name` is `"RpcError"`, and which has the `code`
+//! and optional `data` of the [RpcError] returned by the handler.
+//!
+//! Both processes must use the same [RpcConfig]. The browser process adds the [RpcHandler] to
+//! a [BrowserSideRouter] created with [RpcConfig::router], and forwards the CEF callbacks to
+//! the router as usual:
+//!
+//! ```no_run
+//! # use cef::wrapper::{message_router::*, rpc::*};
+//! # #[derive(serde::Deserialize)] struct SetTitle { title: String }
+//! let config = RpcConfig::default();
+//! let rpc = RpcHandler::new();
+//! rpc.register("setTitle", |_context, request: SetTitle| Ok(request.title));
+//! let router = BrowserSideRouter::new(config.router.clone());
+//! router.add_handler(rpc, false);
+//! ```
+
+use super::message_router::*;
+use crate::*;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, Weak,
+    },
+};
+
+/// Used to configure the RPC layer. The same values must be passed to both [RpcHandler]'s
+/// router and [RendererSideRpc].
+#[derive(Clone, Debug)]
+pub struct RpcConfig {
+    /// Name of the object that will be added to the 'window' object with a function for each
+    /// handler. The default value is "termsurf".
+    pub namespace: String,
+    /// Configuration of the underlying router. The query functions should not conflict with any
+    /// other router.
+    pub router: MessageRouterConfig,
+}
+
+impl RpcConfig {
+    /// Create a configuration for `namespace`, with query functions named after it.
+    pub fn new(namespace: &str) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            router: MessageRouterConfig {
+                js_query_function: format!("{namespace}Query"),
+                js_cancel_function: format!("{namespace}QueryCancel"),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// The JavaScript which [RendererSideRpc] evaluates to define the `window.<namespace>`
+    /// object.
+    pub fn script(&self) -> String {
+        let namespace = js_string(&self.namespace);
+        let query = js_string(&self.router.js_query_function);
+        let cancel = js_string(&self.router.js_cancel_function);
+        let stream_end = STREAM_END_CODE;
+        format!(
+            r#"(function() {{
+  const query = window[{query}];
+  const cancel = window[{cancel}];
+  if (typeof query !== "function" || typeof cancel !== "function") {{
+    return;
+  }}
+  function rpcError(code, message) {{
+    let detail = {{ code, message }};
+    try {{
+      const parsed = JSON.parse(message);
+      if (parsed && typeof parsed === "object") {{
+        detail = parsed;
+      }}
+    }} catch (e) {{}}
+    const error = new Error(detail.message);
+    error.name = "RpcError";
+    error.code = detail.code;
+    error.data = detail.data;
+    return error;
+  }}
+  function request(method, params, stream) {{
+    return JSON.stringify({{ method, params: params === undefined ? null : params, stream }});
+  }}
+  function call(method, params, options) {{
+    return new Promise((resolve, reject) => {{
+      const signal = options && options.signal;
+      if (signal && signal.aborted) {{
+        reject(signal.reason);
+        return;
+      }}
+      const onAbort = () => {{
+        cancel(id);
+        reject(signal.reason);
+      }};
+      const id = query({{
+        request: request(method, params, false),
+        persistent: false,
+        onSuccess: (response) => {{
+          if (signal) signal.removeEventListener("abort", onAbort);
+          resolve(JSON.parse(response));
+        }},
+        onFailure: (code, message) => {{
+          if (signal) signal.removeEventListener("abort", onAbort);
+          reject(rpcError(code, message));
+        }},
+      }});
+      if (signal) signal.addEventListener("abort", onAbort, {{ once: true }});
+    }});
+  }}
+  function subscribe(method, params, onEvent) {{
+    let finish;
+    let active = true;
+    const done = new Promise((resolve, reject) => {{
+      finish = (error) => {{
+        if (!active) return;
+        active = false;
+        error ? reject(error) : resolve();
+      }};
+    }});
+    const id = query({{
+      request: request(method, params, true),
+      persistent: true,
+      onSuccess: (response) => {{
+        if (active) onEvent(JSON.parse(response));
+      }},
+      onFailure: (code, message) => {{
+        finish(code === {stream_end} ? undefined : rpcError(code, message));
+      }},
+    }});
+    return {{
+      done,
+      cancel() {{
+        if (active) {{
+          cancel(id);
+          finish();
+        }}
+      }},
+    }};
+  }}
+  const methods = new Map();
+  window[{namespace}] = new Proxy({{}}, {{
+    get(target, name) {{
+      // Don't look like a thenable, or awaiting the namespace would call a handler
+      if (typeof name !== "string" || name === "then") return undefined;
+      if (!methods.has(name)) {{
+        const method = (params, options) => call(name, params, options);
+        method.subscribe = (params, onEvent) => subscribe(name, params, onEvent);
+        methods.set(name, method);
+      }}
+      return methods.get(name);
+    }},
+  }});
+}})();
+"#
+        )
+    }
+}
+
+impl Default for RpcConfig {
+    fn default() -> Self {
+        Self::new("termsurf")
+    }
+}
+
+fn js_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+/// Error code reported to JavaScript when a stream has finished without an error.
+const STREAM_END_CODE: i32 = 0;
+
+/// A structured error which rejects the JavaScript Promise. The codes below -32000 are
+/// reserved, and follow JSON-RPC. The code 0 is used to signal the end of a stream.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RpcError {
+    /// Numeric code, available as `error.code` in JavaScript.
+    pub code: i32,
+    /// Human readable description, available as `error.message` in JavaScript.
+    pub message: String,
+    /// Optional structured details, available as `error.data` in JavaScript.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+impl RpcError {
+    /// The query was canceled by the router, e.g. because the page navigated away.
+    pub const CANCELED: i32 = -1;
+    /// The request could not be parsed.
+    pub const PARSE_ERROR: i32 = -32700;
+    /// The request was not a valid call, e.g. a streaming handler was called without
+    /// subscribing to it.
+    pub const INVALID_REQUEST: i32 = -32600;
+    /// No handler is registered with the requested name.
+    pub const METHOD_NOT_FOUND: i32 = -32601;
+    /// The parameters could not be deserialized into the handler's request type.
+    pub const INVALID_PARAMS: i32 = -32602;
+    /// The handler failed without reporting a more specific error.
+    pub const INTERNAL_ERROR: i32 = -32603;
+
+    pub fn new(code: i32, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    /// Attach structured details to the error. They are dropped if they can't be serialized.
+    pub fn with_data<T: Serialize>(mut self, data: &T) -> Self {
+        self.data = serde_json::to_value(data).ok();
+        self
+    }
+
+    fn internal(message: impl fmt::Display) -> Self {
+        Self::new(Self::INTERNAL_ERROR, message.to_string())
+    }
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (code {})", self.message, self.code)
+    }
+}
+
+impl std::error::Error for RpcError {}
+
+/// The request which the JavaScript side sends for each call.
+#[derive(Deserialize)]
+struct RpcRequest {
+    method: String,
+    #[serde(default)]
+    params: serde_json::Value,
+    #[serde(default)]
+    stream: bool,
+}
+
+/// Tracks whether a query is still pending, so handlers can stop working on canceled queries.
+#[derive(Default)]
+struct QueryState {
+    finished: AtomicBool,
+    canceled: AtomicBool,
+    on_cancel: Mutex<Vec<Box<dyn FnOnce() + Send>>>,
+}
+
+impl QueryState {
+    /// Mark the query as finished, returning false if it already was.
+    fn finish(&self) -> bool {
+        !self.finished.swap(true, Ordering::SeqCst)
+    }
+
+    fn cancel(&self) {
+        self.finished.store(true, Ordering::SeqCst);
+        if self.canceled.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let callbacks = self
+            .on_cancel
+            .lock()
+            .map(|mut callbacks| std::mem::take(&mut *callbacks))
+            .unwrap_or_default();
+        for callback in callbacks {
+            callback();
+        }
+    }
+}
+
+/// Information about the query which a handler is called for.
+#[derive(Clone)]
+pub struct RpcContext {
+    browser: Option<Browser>,
+    frame: Option<Frame>,
+    query_id: i64,
+    state: Arc<QueryState>,
+}
+
+impl RpcContext {
+    /// The browser that sent the query.
+    pub fn browser(&self) -> Option<&Browser> {
+        self.browser.as_ref()
+    }
+
+    /// The frame that sent the query.
+    pub fn frame(&self) -> Option<&Frame> {
+        self.frame.as_ref()
+    }
+
+    /// Uniquely identifies the query for the life span of the router.
+    pub fn query_id(&self) -> i64 {
+        self.query_id
+    }
+
+    /// Returns true once JavaScript or the router has canceled the query.
+    pub fn is_canceled(&self) -> bool {
+        self.state.canceled.load(Ordering::SeqCst)
+    }
+
+    /// Run `callback` when the query is canceled. It runs immediately if the query has
+    /// already been canceled, and never runs if the query completes first.
+    pub fn on_cancel(&self, callback: impl FnOnce() + Send + 'static) {
+        if let Ok(mut callbacks) = self.state.on_cancel.lock() {
+            if !self.is_canceled() {
+                callbacks.push(Box::new(callback));
+                return;
+            }
+        }
+        callback();
+    }
+}
+
+/// Shared by the response handles of a query to complete it.
+struct QueryCompletion {
+    callback: Arc<Mutex<dyn BrowserSideCallback>>,
+    handler: Weak<RpcHandler>,
+    query_id: i64,
+    state: Arc<QueryState>,
+}
+
+impl QueryCompletion {
+    fn is_active(&self) -> bool {
+        !self.state.finished.load(Ordering::SeqCst)
+    }
+
+    fn success(&self, response: &str) -> bool {
+        if !self.is_active() {
+            return false;
+        }
+        let Ok(callback) = self.callback.lock() else {
+            return false;
+        };
+        callback.success_str(response);
+        true
+    }
+
+    fn failure(&self, error: &RpcError) -> bool {
+        if !self.state.finish() {
+            return false;
+        }
+        if let Some(handler) = self.handler.upgrade() {
+            handler.remove_pending(self.query_id);
+        }
+        let message = serde_json::to_string(error).unwrap_or_else(|_| error.message.clone());
+        let Ok(callback) = self.callback.lock() else {
+            return false;
+        };
+        callback.failure(error.code, &message);
+        true
+    }
+}
+
+/// Completes a single request. It can be sent to another thread to respond asynchronously.
+/// Dropping it without responding fails the request with [RpcError::INTERNAL_ERROR].
+pub struct RpcResponder<T> {
+    completion: QueryCompletion,
+    _response: PhantomData<fn(&T)>,
+}
+
+impl<T: Serialize> RpcResponder<T> {
+    /// Resolve or reject the JavaScript Promise. Returns false if the query was canceled.
+    pub fn respond(self, result: Result<T, RpcError>) -> bool {
+        let response =
+            result.and_then(|value| serde_json::to_string(&value).map_err(RpcError::internal));
+        match response {
+            Ok(response) => {
+                let sent = self.completion.success(&response);
+                if self.completion.state.finish() {
+                    if let Some(handler) = self.completion.handler.upgrade() {
+                        handler.remove_pending(self.completion.query_id);
+                    }
+                }
+                sent
+            }
+            Err(error) => self.completion.failure(&error),
+        }
+    }
+
+    /// Returns true once JavaScript or the router has canceled the query.
+    pub fn is_canceled(&self) -> bool {
+        self.completion.state.canceled.load(Ordering::SeqCst)
+    }
+}
+
+impl<T> Drop for RpcResponder<T> {
+    fn drop(&mut self) {
+        self.completion.failure(&RpcError::new(
+            RpcError::INTERNAL_ERROR,
+            "the handler dropped the request without responding",
+        ));
+    }
+}
+
+/// Sends events to a subscription. Dropping it ends the stream, which resolves the
+/// `done` Promise in JavaScript.
+pub struct RpcStream<T> {
+    completion: QueryCompletion,
+    _event: PhantomData<fn(&T)>,
+}
+
+impl<T: Serialize> RpcStream<T> {
+    /// Send an event to the subscriber. Returns false if the subscription has been canceled,
+    /// or the event could not be serialized.
+    pub fn send(&self, event: &T) -> bool {
+        match serde_json::to_string(event) {
+            Ok(event) => self.completion.success(&event),
+            Err(_) => false,
+        }
+    }
+
+    /// End the stream, resolving the `done` Promise.
+    pub fn finish(self) {}
+
+    /// End the stream with an error, rejecting the `done` Promise.
+    pub fn fail(self, error: RpcError) {
+        self.completion.failure(&error);
+    }
+
+    /// Returns true once JavaScript or the router has canceled the subscription.
+    pub fn is_canceled(&self) -> bool {
+        self.completion.state.canceled.load(Ordering::SeqCst)
+    }
+}
+
+impl<T> Drop for RpcStream<T> {
+    fn drop(&mut self) {
+        self.completion
+            .failure(&RpcError::new(STREAM_END_CODE, "the stream has ended"));
+    }
+}
+
+type Method = dyn Fn(RpcContext, serde_json::Value, QueryCompletion) + Send + Sync;
+
+struct Registration {
+    stream: bool,
+    method: Arc<Method>,
+}
+
+/// A [BrowserSideHandler] which dispatches queries sent through `window.<namespace>` to the
+/// handlers registered with it. The methods of this type may be called on any browser process
+/// thread, handlers are executed on the browser process UI thread.
+#[derive(Default)]
+pub struct RpcHandler {
+    weak_self: Weak<Self>,
+    methods: Mutex<HashMap<String, Registration>>,
+    pending: Mutex<HashMap<i64, Arc<QueryState>>>,
+}
+
+impl RpcHandler {
+    pub fn new() -> Arc<Self> {
+        Arc::new_cyclic(|weak_self| Self {
+            weak_self: weak_self.clone(),
+            ..Default::default()
+        })
+    }
+
+    /// Register a handler which responds immediately. Replaces any handler with the same
+    /// `name`.
+    pub fn register<Req, Resp, F>(&self, name: &str, handler: F)
+    where
+        Req: DeserializeOwned,
+        Resp: Serialize,
+        F: Fn(RpcContext, Req) -> Result<Resp, RpcError> + Send + Sync + 'static,
+    {
+        self.register_async(name, move |context, request, responder| {
+            responder.respond(handler(context, request));
+        });
+    }
+
+    /// Register a handler which responds through an [RpcResponder], possibly after it has
+    /// returned. Replaces any handler with the same `name`.
+    pub fn register_async<Req, Resp, F>(&self, name: &str, handler: F)
+    where
+        Req: DeserializeOwned,
+        Resp: Serialize,
+        F: Fn(RpcContext, Req, RpcResponder<Resp>) + Send + Sync + 'static,
+    {
+        self.insert(
+            name,
+            false,
+            Arc::new(move |context, params, completion| {
+                let responder = RpcResponder {
+                    completion,
+                    _response: PhantomData,
+                };
+                match serde_json::from_value(params) {
+                    Ok(request) => handler(context, request, responder),
+                    Err(err) => {
+                        responder.respond(Err(RpcError::new(
+                            RpcError::INVALID_PARAMS,
+                            err.to_string(),
+                        )));
+                    }
+                }
+            }),
+        );
+    }
+
+    /// Register a handler which sends any number of events through an [RpcStream]. It can
+    /// only be called with `subscribe` in JavaScript. Replaces any handler with the same
+    /// `name`.
+    pub fn register_stream<Req, Event, F>(&self, name: &str, handler: F)
+    where
+        Req: DeserializeOwned,
+        Event: Serialize,
+        F: Fn(RpcContext, Req, RpcStream<Event>) + Send + Sync + 'static,
+    {
+        self.insert(
+            name,
+            true,
+            Arc::new(move |context, params, completion| {
+                let stream = RpcStream {
+                    completion,
+                    _event: PhantomData,
+                };
+                match serde_json::from_value(params) {
+                    Ok(request) => handler(context, request, stream),
+                    Err(err) => {
+                        stream.fail(RpcError::new(RpcError::INVALID_PARAMS, err.to_string()))
+                    }
+                }
+            }),
+        );
+    }
+
+    /// Remove the handler registered as `name`. Returns false if there is none. Queries which
+    /// are already pending are not affected.
+    pub fn unregister(&self, name: &str) -> bool {
+        self.methods
+            .lock()
+            .map(|mut methods| methods.remove(name).is_some())
+            .unwrap_or(false)
+    }
+
+    /// Returns the number of queries which have been dispatched to a handler and are still
+    /// pending.
+    pub fn pending_count(&self) -> usize {
+        self.pending
+            .lock()
+            .map(|pending| pending.len())
+            .unwrap_or(0)
+    }
+
+    fn insert(&self, name: &str, stream: bool, method: Arc<Method>) {
+        if let Ok(mut methods) = self.methods.lock() {
+            methods.insert(name.to_string(), Registration { stream, method });
+        }
+    }
+
+    fn remove_pending(&self, query_id: i64) {
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.remove(&query_id);
+        }
+    }
+
+    fn dispatch(
+        &self,
+        browser: Option<Browser>,
+        frame: Option<Frame>,
+        query_id: i64,
+        request: &str,
+        persistent: bool,
+        callback: Arc<Mutex<dyn BrowserSideCallback>>,
+    ) {
+        let state = Arc::new(QueryState::default());
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.insert(query_id, state.clone());
+        }
+        let completion = QueryCompletion {
+            callback,
+            handler: self.weak_self.clone(),
+            query_id,
+            state: state.clone(),
+        };
+
+        let request: RpcRequest = match serde_json::from_str(request) {
+            Ok(request) => request,
+            Err(err) => {
+                completion.failure(&RpcError::new(RpcError::PARSE_ERROR, err.to_string()));
+                return;
+            }
+        };
+        let registration = self.methods.lock().ok().and_then(|methods| {
+            methods
+                .get(&request.method)
+                .map(|registration| (registration.stream, registration.method.clone()))
+        });
+        let Some((stream, method)) = registration else {
+            completion.failure(&RpcError::new(
+                RpcError::METHOD_NOT_FOUND,
+                format!("no handler is registered for {:?}", request.method),
+            ));
+            return;
+        };
+        if stream != request.stream || stream != persistent {
+            let message = if stream {
+                format!("{:?} is a stream, use subscribe to call it", request.method)
+            } else {
+                format!(
+                    "{:?} is not a stream and can't be subscribed to",
+                    request.method
+                )
+            };
+            completion.failure(&RpcError::new(RpcError::INVALID_REQUEST, message));
+            return;
+        }
+
+        let context = RpcContext {
+            browser,
+            frame,
+            query_id,
+            state,
+        };
+        method(context, request.params, completion);
+    }
+}
+
+impl BrowserSideHandler for RpcHandler {
+    fn on_query_str(
+        &self,
+        browser: Option<Browser>,
+        frame: Option<Frame>,
+        query_id: i64,
+        request: &str,
+        persistent: bool,
+        callback: Arc<Mutex<dyn BrowserSideCallback>>,
+    ) -> bool {
+        self.dispatch(browser, frame, query_id, request, persistent, callback);
+        true
+    }
+
+    fn on_query_canceled(&self, _browser: Option<Browser>, _frame: Option<Frame>, query_id: i64) {
+        let state = self
+            .pending
+            .lock()
+            .ok()
+            .and_then(|mut pending| pending.remove(&query_id));
+        if let Some(state) = state {
+            state.cancel();
+        }
+    }
+}
+
+/// Implements the renderer side of the RPC layer. It wraps a [RendererSideRouter], and
+/// defines the `window.<namespace>` object in each new context. The methods of this type must
+/// be called on the render process main thread.
+pub struct RendererSideRpc {
+    router: Arc<RendererSideRouter>,
+    script: String,
+}
+
+impl RendererSideRpc {
+    /// Create a new renderer side with the specified configuration.
+    pub fn new(config: RpcConfig) -> Arc<Self> {
+        let script = config.script();
+        Arc::new(Self {
+            router: RendererSideRouter::new(config.router),
+            script,
+        })
+    }
+
+    /// The underlying router.
+    pub fn router(&self) -> &Arc<RendererSideRouter> {
+        &self.router
+    }
+
+    /// Call from CefRenderProcessHandler::OnContextCreated. Registers the query
+    /// functions and the `window.<namespace>` object with the new context.
+    pub fn on_context_created(
+        &self,
+        browser: Option<Browser>,
+        frame: Option<Frame>,
+        context: Option<V8Context>,
+    ) {
+        self.router
+            .on_context_created(browser, frame, context.clone());
+
+        let Some(context) = context else {
+            return;
+        };
+        let mut retval = None;
+        let mut exception = None;
+        context.eval(
+            Some(&CefString::from(self.script.as_str())),
+            None,
+            0,
+            Some(&mut retval),
+            Some(&mut exception),
+        );
+    }
+
+    /// Call from CefRenderProcessHandler::OnContextReleased. Any pending queries
+    /// associated with the released context will be canceled.
+    pub fn on_context_released(
+        &self,
+        browser: Option<Browser>,
+        frame: Option<Frame>,
+        context: Option<V8Context>,
+    ) {
+        self.router.on_context_released(browser, frame, context);
+    }
+
+    /// Call from CefRenderProcessHandler::OnProcessMessageReceived. Returns true
+    /// if the message is handled by this router or false otherwise.
+    pub fn on_process_message_received(
+        &self,
+        browser: Option<Browser>,
+        frame: Option<Frame>,
+        source_process: Option<ProcessId>,
+        message: Option<ProcessMessage>,
+    ) -> bool {
+        self.router
+            .on_process_message_received(browser, frame, source_process, message)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Call {
+        Success(String),
+        Failure(i32, RpcError),
+    }
+
+    #[derive(Default)]
+    struct TestCallback(Mutex<Vec<Call>>);
+
+    impl BrowserSideCallback for TestCallback {
+        fn success_str(&self, response: &str) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(Call::Success(response.to_string()));
+        }
+
+        fn success_binary(&self, _data: &[u8]) {
+            unreachable!();
+        }
+
+        fn failure(&self, error_code: i32, error_message: &str) {
+            let error = serde_json::from_str(error_message).expect("structured error");
+            self.0
+                .lock()
+                .unwrap()
+                .push(Call::Failure(error_code, error));
+        }
+    }
+
+    struct TestQuery(Arc<Mutex<TestCallback>>);
+
+    impl TestQuery {
+        fn send(handler: &RpcHandler, query_id: i64, request: &str, persistent: bool) -> Self {
+            let callback = Arc::new(Mutex::new(TestCallback::default()));
+            assert!(handler.on_query_str(
+                None,
+                None,
+                query_id,
+                request,
+                persistent,
+                callback.clone()
+            ));
+            Self(callback)
+        }
+
+        fn calls(&self) -> Vec<Call> {
+            std::mem::take(&mut *self.0.lock().unwrap().0.lock().unwrap())
+        }
+    }
+
+    fn error_code(calls: &[Call]) -> Option<i32> {
+        match calls {
+            [Call::Failure(code, error)] => {
+                assert_eq!(*code, error.code);
+                Some(*code)
+            }
+            _ => None,
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct Add {
+        a: i32,
+        b: i32,
+    }
+
+    #[test]
+    fn call_handler() {
+        let handler = RpcHandler::new();
+        handler.register("add", |_context, Add { a, b }| {
+            a.checked_add(b)
+                .ok_or_else(|| RpcError::new(1, "overflow").with_data(&[a, b]))
+        });
+
+        let query = TestQuery::send(
+            &handler,
+            1,
+            r#"{"method":"add","params":{"a":1,"b":2}}"#,
+            false,
+        );
+        assert_eq!(query.calls(), vec![Call::Success("3".to_string())]);
+
+        let query = TestQuery::send(
+            &handler,
+            2,
+            &format!(r#"{{"method":"add","params":{{"a":{},"b":1}}}}"#, i32::MAX),
+            false,
+        );
+        assert_eq!(
+            query.calls(),
+            vec![Call::Failure(
+                1,
+                RpcError {
+                    code: 1,
+                    message: "overflow".to_string(),
+                    data: Some(serde_json::json!([i32::MAX, 1])),
+                }
+            )]
+        );
+        assert_eq!(handler.pending_count(), 0);
+    }
+
+    #[test]
+    fn invalid_requests() {
+        let handler = RpcHandler::new();
+        handler.register("add", |_context, Add { a, b }| Ok(a + b));
+        handler.register_stream("watch", |_context, (), _stream: RpcStream<()>| {});
+
+        for (request, persistent, code) in [
+            ("not json", false, RpcError::PARSE_ERROR),
+            (r#"{"method":"sub"}"#, false, RpcError::METHOD_NOT_FOUND),
+            (
+                r#"{"method":"add","params":[1]}"#,
+                false,
+                RpcError::INVALID_PARAMS,
+            ),
+            (
+                r#"{"method":"add","params":{"a":1,"b":2},"stream":true}"#,
+                true,
+                RpcError::INVALID_REQUEST,
+            ),
+            (r#"{"method":"watch"}"#, false, RpcError::INVALID_REQUEST),
+        ] {
+            let query = TestQuery::send(&handler, 1, request, persistent);
+            assert_eq!(error_code(&query.calls()), Some(code), "{request}");
+        }
+
+        assert!(handler.unregister("add"));
+        assert!(!handler.unregister("add"));
+        let query = TestQuery::send(
+            &handler,
+            1,
+            r#"{"method":"add","params":{"a":1,"b":2}}"#,
+            false,
+        );
+        assert_eq!(error_code(&query.calls()), Some(RpcError::METHOD_NOT_FOUND));
+    }
+
+    #[test]
+    fn async_response_and_cancel() {
+        let handler = RpcHandler::new();
+        let responders = Arc::new(Mutex::new(vec![]));
+        let canceled = Arc::new(AtomicBool::new(false));
+        handler.register_async("later", {
+            let responders = responders.clone();
+            let canceled = canceled.clone();
+            move |context: RpcContext, (): (), responder: RpcResponder<String>| {
+                let canceled = canceled.clone();
+                context.on_cancel(move || canceled.store(true, Ordering::SeqCst));
+                responders.lock().unwrap().push(responder);
+            }
+        });
+
+        let first = TestQuery::send(&handler, 1, r#"{"method":"later"}"#, false);
+        let second = TestQuery::send(&handler, 2, r#"{"method":"later"}"#, false);
+        assert_eq!(handler.pending_count(), 2);
+        assert!(first.calls().is_empty());
+
+        handler.on_query_canceled(None, None, 2);
+        assert!(canceled.load(Ordering::SeqCst));
+        let mut pending: Vec<_> = responders.lock().unwrap().drain(..).collect();
+        let responder = pending.pop().unwrap();
+        assert!(responder.is_canceled());
+        assert!(!responder.respond(Ok("too late".to_string())));
+        assert!(second.calls().is_empty());
+
+        let responder = pending.pop().unwrap();
+        std::thread::spawn(move || responder.respond(Ok("done".to_string())))
+            .join()
+            .unwrap();
+        assert_eq!(first.calls(), vec![Call::Success(r#""done""#.to_string())]);
+        assert_eq!(handler.pending_count(), 0);
+
+        // Dropping a responder still completes the query
+        let third = TestQuery::send(&handler, 3, r#"{"method":"later"}"#, false);
+        responders.lock().unwrap().clear();
+        assert_eq!(error_code(&third.calls()), Some(RpcError::INTERNAL_ERROR));
+    }
+
+    #[test]
+    fn stream_events() {
+        let handler = RpcHandler::new();
+        let streams = Arc::new(Mutex::new(vec![]));
+        handler.register_stream("count", {
+            let streams = streams.clone();
+            move |_context, to: u32, stream: RpcStream<u32>| {
+                for i in 0..to {
+                    stream.send(&i);
+                }
+                streams.lock().unwrap().push(stream);
+            }
+        });
+
+        let query = TestQuery::send(
+            &handler,
+            1,
+            r#"{"method":"count","params":2,"stream":true}"#,
+            true,
+        );
+        assert_eq!(
+            query.calls(),
+            vec![
+                Call::Success("0".to_string()),
+                Call::Success("1".to_string())
+            ]
+        );
+        let stream = streams.lock().unwrap().pop().unwrap();
+        assert!(stream.send(&2));
+        stream.finish();
+        assert_eq!(
+            query.calls(),
+            vec![
+                Call::Success("2".to_string()),
+                Call::Failure(
+                    STREAM_END_CODE,
+                    RpcError::new(STREAM_END_CODE, "the stream has ended")
+                )
+            ]
+        );
+
+        let query = TestQuery::send(
+            &handler,
+            2,
+            r#"{"method":"count","params":0,"stream":true}"#,
+            true,
+        );
+        handler.on_query_canceled(None, None, 2);
+        let stream = streams.lock().unwrap().pop().unwrap();
+        assert!(stream.is_canceled());
+        assert!(!stream.send(&0));
+        drop(stream);
+        assert!(query.calls().is_empty());
+        assert_eq!(handler.pending_count(), 0);
+    }
+
+    #[test]
+    fn script() {
+        let script = RpcConfig::new("my\"App").script();
+        assert!(script.contains(r#"window["my\"App"] = new Proxy"#));
+        assert!(script.contains(r#"window["my\"AppQuery"]"#));
+        assert!(script.contains(r#"window["my\"AppQueryCancel"]"#));
+    }
+}
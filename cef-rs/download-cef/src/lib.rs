@@ -10,8 +10,8 @@ use std::{
     collections::HashMap,
     env,
     fmt::{self, Display},
-    fs::{self, File},
-    io::{self, BufReader, IsTerminal, Write},
+    fs::{self, File, OpenOptions},
+    io::{self, BufReader, IsTerminal, Read, Write},
     path::{Path, PathBuf},
     sync::{Mutex, OnceLock},
     thread,
@@ -57,6 +57,10 @@ pub enum Error {
     },
     #[error("Invalid regex pattern: {0}")]
     InvalidRegexPattern(#[from] regex::Error),
+    #[error("Archive not found: {0}")]
+    ArchiveNotFound(String),
+    #[error("Offline mode: {0}")]
+    Offline(String),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -100,14 +104,8 @@ fn unwrap_cef_version(version: &str) -> Result<String> {
 pub fn check_archive_json(version: &str, location: &str) -> Result<()> {
     let expected = Version::parse(&unwrap_cef_version(version)?)?;
 
-    static PATTERN: OnceLock<core::result::Result<Regex, regex::Error>> = OnceLock::new();
-    let pattern = PATTERN
-        .get_or_init(|| Regex::new(r"^cef_binary_([^+]+)(:?\+.+)?$"))
-        .as_ref()
-        .map_err(Clone::clone)?;
     let archive_json: CefFile = serde_json::from_reader(File::open(archive_json_path(location))?)?;
-    let archive_version = pattern.replace(&archive_json.name, "$1");
-    let archive = Version::parse(&archive_version)?;
+    let archive = archive_json.version()?;
 
     if archive <= expected {
         Ok(())
@@ -133,6 +131,85 @@ pub fn default_download_url() -> String {
     env::var("CEF_DOWNLOAD_URL").unwrap_or(DEFAULT_CDN_URL.to_owned())
 }
 
+/// Content-addressed cache directory shared across builds, set with `CEF_CACHE_DIR`.
+pub fn default_cache_dir() -> Option<PathBuf> {
+    env::var_os("CEF_CACHE_DIR")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Whether downloads are disabled, set with `CEF_OFFLINE=1`.
+pub fn default_offline() -> bool {
+    env::var("CEF_OFFLINE").is_ok_and(|offline| !matches!(offline.as_str(), "" | "0" | "false"))
+}
+
+/// Where to find the CEF index and archives, and how to download them.
+#[derive(Clone, Debug)]
+pub struct DownloadOptions {
+    /// Mirror to download from. It can be an `http(s)://` URL, or a `file://` URL or plain
+    /// directory containing `index.json` and the archives.
+    pub url: String,
+    /// Verified archives are stored in `<cache_dir>/<sha1>/<name>`, and reused by later
+    /// downloads. The latest remote `index.json` is also stored in the cache.
+    pub cache_dir: Option<PathBuf>,
+    /// Fail instead of downloading anything from a remote mirror. Local mirrors and the cache
+    /// are still used.
+    pub offline: bool,
+    pub show_progress: bool,
+    pub retry_delay: Duration,
+    pub max_retries: u32,
+}
+
+impl DownloadOptions {
+    /// Download from `url`, with the cache and offline mode configured by the environment.
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_owned(),
+            cache_dir: default_cache_dir(),
+            offline: default_offline(),
+            show_progress: false,
+            retry_delay: Duration::from_secs(15),
+            max_retries: 0,
+        }
+    }
+
+    fn mirror(&self) -> Mirror<'_> {
+        if self.url.starts_with("http://") || self.url.starts_with("https://") {
+            return Mirror::Remote(&self.url);
+        }
+
+        let path = self.url.strip_prefix("file://").unwrap_or(&self.url);
+        #[cfg(windows)]
+        let path = path
+            .strip_prefix('/')
+            .filter(|path| path.as_bytes().get(1) == Some(&b':'))
+            .unwrap_or(path);
+        Mirror::Local(PathBuf::from(path))
+    }
+
+    fn offline_error(&self, file: &str) -> Error {
+        let url = &self.url;
+        Error::Offline(match &self.cache_dir {
+            Some(cache_dir) => format!(
+                "{file} is not in the cache {} and can't be downloaded from {url}",
+                cache_dir.display()
+            ),
+            None => format!("{file} can't be downloaded from {url} and CEF_CACHE_DIR is not set"),
+        })
+    }
+}
+
+impl Default for DownloadOptions {
+    fn default() -> Self {
+        Self::new(&default_download_url())
+    }
+}
+
+enum Mirror<'a> {
+    Remote(&'a str),
+    Local(PathBuf),
+}
+
 #[derive(Clone, PartialEq, Eq, Deserialize, Serialize, ValueEnum)]
 #[serde(rename_all = "lowercase")]
 pub enum Channel {
@@ -167,10 +244,43 @@ impl CefIndex {
     }
 
     pub fn download_from(url: &str) -> Result<Self> {
-        Ok(ureq::get(&format!("{url}/index.json"))
-            .call()?
-            .into_body()
-            .read_json()?)
+        Self::download_with(&DownloadOptions::new(url))
+    }
+
+    /// Read `index.json` from the mirror. In offline mode, a remote mirror is replaced by the
+    /// index stored in the cache by the last download.
+    pub fn download_with(options: &DownloadOptions) -> Result<Self> {
+        const INDEX_JSON: &str = "index.json";
+        let cached_index = options
+            .cache_dir
+            .as_ref()
+            .map(|cache_dir| cache_dir.join(INDEX_JSON));
+
+        match options.mirror() {
+            Mirror::Local(dir) => Ok(serde_json::from_reader(BufReader::new(File::open(
+                dir.join(INDEX_JSON),
+            )?))?),
+            Mirror::Remote(_) if options.offline => {
+                let cached_index = cached_index
+                    .filter(|cached_index| cached_index.exists())
+                    .ok_or_else(|| options.offline_error(INDEX_JSON))?;
+                Ok(serde_json::from_reader(BufReader::new(File::open(
+                    cached_index,
+                )?))?)
+            }
+            Mirror::Remote(url) => {
+                let index: Self = ureq::get(&format!("{url}/{INDEX_JSON}"))
+                    .call()?
+                    .into_body()
+                    .read_json()?;
+                if let Some(cached_index) = cached_index {
+                    create_atomic(&cached_index, |temp| {
+                        serde_json::to_writer(File::create(temp)?, &index).map_err(io::Error::from)
+                    })?;
+                }
+                Ok(index)
+            }
+        }
     }
 
     pub fn platform(&self, target: &str) -> Result<&CefPlatform> {
@@ -203,17 +313,11 @@ impl CefPlatform {
     }
 
     pub fn latest(&self, channel: Channel) -> Result<&CefVersion> {
-        static PATTERN: OnceLock<core::result::Result<Regex, regex::Error>> = OnceLock::new();
-        let pattern = PATTERN
-            .get_or_init(|| Regex::new(r"^([^+]+)(:?\+.+)?$"))
-            .as_ref()
-            .map_err(Clone::clone)?;
-
         self.versions
             .iter()
             .filter_map(|value| {
                 if value.channel == channel {
-                    let key = Version::parse(&pattern.replace(&value.cef_version, "$1")).ok()?;
+                    let key = cef_version_number(&value.cef_version).ok()?;
                     Some((key, value))
                 } else {
                     None
@@ -249,82 +353,11 @@ impl CefVersion {
     where
         P: AsRef<Path>,
     {
-        let file = self.minimal()?;
-        let (file, sha) = (file.name.as_str(), file.sha1.as_str());
-
-        fs::create_dir_all(&location)?;
-        let download_file = location.as_ref().join(file);
-
-        if download_file.exists() {
-            if calculate_file_sha1(&download_file) == sha {
-                if show_progress {
-                    println!("Verified archive: {}", download_file.display());
-                }
-                return Ok(download_file);
-            }
-
-            if show_progress {
-                println!("Cleaning corrupted archive: {}", download_file.display());
-            }
-            let corrupted_file = location.as_ref().join(format!("corrupted_{file}"));
-            fs::rename(&download_file, &corrupted_file)?;
-            fs::remove_file(&corrupted_file)?;
-        }
-
-        let cef_url = format!("{url}/{file}");
-        if show_progress {
-            println!("Using archive url: {cef_url}");
-        }
-
-        let mut file = File::create(&download_file)?;
-
-        let resp = ureq::get(&cef_url).call()?;
-        let expected = resp
-            .headers()
-            .get("Content-Length")
-            .ok_or(Error::MissingContentLength)?;
-        let expected = expected.to_str()?;
-        let expected = expected
-            .parse::<u64>()
-            .map_err(|_| Error::InvalidContentLength(expected.to_owned()))?;
-
-        let downloaded = if show_progress && io::stdout().is_terminal() {
-            const DOWNLOAD_TEMPLATE: &str = "{msg} {spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {bytes}/{total_bytes} ({eta})";
-
-            let bar = indicatif::ProgressBar::new(expected);
-            bar.set_style(
-                indicatif::ProgressStyle::with_template(DOWNLOAD_TEMPLATE)
-                    .expect("invalid template")
-                    .progress_chars("##-"),
-            );
-            bar.set_message("Downloading");
-            std::io::copy(
-                &mut bar.wrap_read(resp.into_body().into_reader()),
-                &mut file,
-            )
-        } else {
-            let mut reader = resp.into_body().into_reader();
-            std::io::copy(&mut reader, &mut file)
-        }?;
-
-        if downloaded != expected {
-            return Err(Error::UnexpectedFileSize {
-                downloaded,
-                expected,
-            });
-        }
-
-        if show_progress {
-            println!("Verifying SHA1 hash: {sha}...");
-        }
-        if calculate_file_sha1(&download_file) != sha {
-            return Err(Error::CorruptedFile(download_file.display().to_string()));
-        }
-
-        if show_progress {
-            println!("Downloaded archive: {}", download_file.display());
-        }
-        Ok(download_file)
+        let options = DownloadOptions {
+            show_progress,
+            ..DownloadOptions::new(url)
+        };
+        self.download_archive_with(&options, location)
     }
 
     pub fn download_archive_with_retry<P>(
@@ -357,23 +390,171 @@ impl CefVersion {
     where
         P: AsRef<Path>,
     {
-        let mut result = self.download_archive_from(url, &location, show_progress);
+        let options = DownloadOptions {
+            show_progress,
+            retry_delay,
+            max_retries,
+            ..DownloadOptions::new(url)
+        };
+        self.download_archive_with(&options, location)
+    }
+
+    /// Download the minimal archive to `location`, reusing a verified copy which is already in
+    /// `location` or in the cache. Interrupted downloads are resumed when retrying.
+    pub fn download_archive_with<P>(
+        &self,
+        options: &DownloadOptions,
+        location: P,
+    ) -> Result<PathBuf>
+    where
+        P: AsRef<Path>,
+    {
+        let mut result = self.try_download_archive(options, location.as_ref());
 
         let mut retry = 0;
-        while let Err(Error::Io(_)) = &result {
-            if retry >= max_retries {
+        while let Err(Error::Io(_) | Error::Request(ureq::Error::Io(_) | ureq::Error::Timeout(_))) =
+            &result
+        {
+            if retry >= options.max_retries {
                 break;
             }
 
             retry += 1;
-            thread::sleep(retry_delay * retry);
+            thread::sleep(options.retry_delay * retry);
 
-            result = self.download_archive_from(url, &location, show_progress);
+            result = self.try_download_archive(options, location.as_ref());
         }
 
         result
     }
 
+    fn try_download_archive(&self, options: &DownloadOptions, location: &Path) -> Result<PathBuf> {
+        let show_progress = options.show_progress;
+        let file = self.minimal()?;
+        let (name, sha) = (file.name.as_str(), file.sha1.as_str());
+
+        fs::create_dir_all(location)?;
+        let download_file = location.join(name);
+
+        if download_file.exists() {
+            if calculate_file_sha1(&download_file)? == sha {
+                if show_progress {
+                    println!("Verified archive: {}", download_file.display());
+                }
+                return Ok(download_file);
+            }
+
+            if show_progress {
+                println!("Cleaning corrupted archive: {}", download_file.display());
+            }
+            let corrupted_file = location.join(format!("corrupted_{name}"));
+            fs::rename(&download_file, &corrupted_file)?;
+            fs::remove_file(&corrupted_file)?;
+        }
+
+        let cached_file = options
+            .cache_dir
+            .as_ref()
+            .map(|cache_dir| cache_dir.join(sha).join(name));
+        if let Some(cached_file) = cached_file.as_ref().filter(|file| file.exists()) {
+            if calculate_file_sha1(cached_file)? == sha {
+                if show_progress {
+                    println!("Using cached archive: {}", cached_file.display());
+                }
+                link_or_copy(cached_file, &download_file)?;
+                return Ok(download_file);
+            }
+
+            if show_progress {
+                println!(
+                    "Cleaning corrupted cached archive: {}",
+                    cached_file.display()
+                );
+            }
+            fs::remove_file(cached_file)?;
+        }
+
+        let partial_file = location.join(format!("{name}.part"));
+        match options.mirror() {
+            Mirror::Local(dir) => {
+                let source = dir.join(name);
+                if !source.exists() {
+                    return Err(Error::ArchiveNotFound(source.display().to_string()));
+                }
+                if show_progress {
+                    println!("Copying archive: {}", source.display());
+                }
+                fs::copy(&source, &partial_file)?;
+            }
+            Mirror::Remote(_) if options.offline => return Err(options.offline_error(name)),
+            Mirror::Remote(url) => {
+                let cef_url = format!("{url}/{name}");
+                if show_progress {
+                    println!("Using archive url: {cef_url}");
+                }
+                download_file_resumable(&cef_url, &partial_file, show_progress)?;
+            }
+        }
+
+        if show_progress {
+            println!("Verifying SHA1 hash: {sha}...");
+        }
+        if calculate_file_sha1(&partial_file)? != sha {
+            fs::remove_file(&partial_file)?;
+            return Err(Error::CorruptedFile(download_file.display().to_string()));
+        }
+        fs::rename(&partial_file, &download_file)?;
+
+        if let Some(cached_file) = cached_file {
+            if show_progress {
+                println!("Caching archive: {}", cached_file.display());
+            }
+            create_atomic(&cached_file, |temp| link_or_copy(&download_file, temp))?;
+        }
+
+        if show_progress {
+            println!("Downloaded archive: {}", download_file.display());
+        }
+        Ok(download_file)
+    }
+
+    /// Verify that `archive` is the minimal archive of this version, by its SHA1 hash and the
+    /// version which would be written to `archive.json`, before extracting it with
+    /// [extract_target_archive].
+    pub fn extract_archive<P, Q>(
+        &self,
+        target: &str,
+        archive: P,
+        location: Q,
+        show_progress: bool,
+    ) -> Result<PathBuf>
+    where
+        P: AsRef<Path>,
+        Q: AsRef<Path>,
+    {
+        let file = self.minimal()?;
+        let archive = archive.as_ref();
+
+        let expected = cef_version_number(&self.cef_version)?;
+        let version = file.version()?;
+        if version != expected {
+            return Err(Error::VersionMismatch {
+                location: archive.display().to_string(),
+                archive: version.to_string(),
+                expected: expected.to_string(),
+            });
+        }
+
+        if show_progress {
+            println!("Verifying SHA1 hash: {}...", file.sha1);
+        }
+        if calculate_file_sha1(archive)? != file.sha1 {
+            return Err(Error::CorruptedFile(archive.display().to_string()));
+        }
+
+        extract_target_archive(target, archive, location, show_progress)
+    }
+
     pub fn minimal(&self) -> Result<&CefFile> {
         self.files
             .iter()
@@ -398,6 +579,16 @@ pub struct CefFile {
 }
 
 impl CefFile {
+    /// The CEF version of the archive, parsed from its name.
+    pub fn version(&self) -> Result<Version> {
+        static PATTERN: OnceLock<core::result::Result<Regex, regex::Error>> = OnceLock::new();
+        let pattern = PATTERN
+            .get_or_init(|| Regex::new(r"^cef_binary_([^+]+)(:?\+.+)?$"))
+            .as_ref()
+            .map_err(Clone::clone)?;
+        Ok(Version::parse(&pattern.replace(&self.name, "$1"))?)
+    }
+
     pub fn write_archive_json<P>(&self, location: P) -> Result<()>
     where
         P: AsRef<Path>,
@@ -418,7 +609,7 @@ impl TryFrom<&Path> for CefFile {
             .file_name()
             .map(|f| f.display().to_string())
             .ok_or_else(|| Error::InvalidArchiveFile(location.display().to_string()))?;
-        let sha1 = calculate_file_sha1(location);
+        let sha1 = calculate_file_sha1(location)?;
         Ok(Self {
             file_type,
             name,
@@ -455,21 +646,32 @@ pub fn download_target_archive_from<P>(
 where
     P: AsRef<Path>,
 {
-    if show_progress {
+    let options = DownloadOptions {
+        show_progress,
+        max_retries: 3,
+        ..DownloadOptions::new(url)
+    };
+    download_target_archive_with(&options, target, cef_version, location)
+}
+
+pub fn download_target_archive_with<P>(
+    options: &DownloadOptions,
+    target: &str,
+    cef_version: &str,
+    location: P,
+) -> Result<PathBuf>
+where
+    P: AsRef<Path>,
+{
+    if options.show_progress {
         println!("Downloading CEF archive for {target}...");
     }
 
-    let index = CefIndex::download_from(url)?;
+    let index = CefIndex::download_with(options)?;
     let platform = index.platform(target)?;
     let version = platform.version(cef_version)?;
 
-    version.download_archive_with_retry_from(
-        url,
-        location,
-        show_progress,
-        Duration::from_secs(15),
-        3,
-    )
+    version.download_archive_with(options, location)
 }
 
 pub fn extract_target_archive<P, Q>(
@@ -558,21 +760,125 @@ where
     Ok(cef_dir)
 }
 
-fn calculate_file_sha1(path: &Path) -> String {
-    use std::io::Read;
-    let mut file = BufReader::new(File::open(path).unwrap());
+fn cef_version_number(cef_version: &str) -> Result<Version> {
+    static PATTERN: OnceLock<core::result::Result<Regex, regex::Error>> = OnceLock::new();
+    let pattern = PATTERN
+        .get_or_init(|| Regex::new(r"^([^+]+)(:?\+.+)?$"))
+        .as_ref()
+        .map_err(Clone::clone)?;
+    Ok(Version::parse(&pattern.replace(cef_version, "$1"))?)
+}
+
+/// Download `url` to `partial_file`, continuing from the end of an earlier attempt if the
+/// server supports range requests.
+fn download_file_resumable(url: &str, partial_file: &Path, show_progress: bool) -> Result<()> {
+    let offset = fs::metadata(partial_file).map(|m| m.len()).unwrap_or(0);
+    let request = ureq::get(url);
+    let resp = if offset > 0 {
+        match request.header("Range", format!("bytes={offset}-")).call() {
+            Err(ureq::Error::StatusCode(416)) => {
+                // The partial file is no longer valid for this URL, start over.
+                fs::remove_file(partial_file)?;
+                return download_file_resumable(url, partial_file, show_progress);
+            }
+            resp => resp?,
+        }
+    } else {
+        request.call()?
+    };
+
+    let resumed = offset > 0 && resp.status() == ureq::http::StatusCode::PARTIAL_CONTENT;
+    let (mut file, offset) = if resumed {
+        if show_progress {
+            println!("Resuming download at {offset} bytes");
+        }
+        (OpenOptions::new().append(true).open(partial_file)?, offset)
+    } else {
+        (File::create(partial_file)?, 0)
+    };
+
+    let length = resp
+        .headers()
+        .get("Content-Length")
+        .ok_or(Error::MissingContentLength)?;
+    let length = length.to_str()?;
+    let expected = length
+        .parse::<u64>()
+        .map_err(|_| Error::InvalidContentLength(length.to_owned()))?
+        + offset;
+
+    let downloaded = if show_progress && io::stdout().is_terminal() {
+        const DOWNLOAD_TEMPLATE: &str = "{msg} {spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {bytes}/{total_bytes} ({eta})";
+
+        let bar = indicatif::ProgressBar::new(expected);
+        bar.set_style(
+            indicatif::ProgressStyle::with_template(DOWNLOAD_TEMPLATE)
+                .expect("invalid template")
+                .progress_chars("##-"),
+        );
+        bar.set_message("Downloading");
+        bar.set_position(offset);
+        std::io::copy(
+            &mut bar.wrap_read(resp.into_body().into_reader()),
+            &mut file,
+        )
+    } else {
+        let mut reader = resp.into_body().into_reader();
+        std::io::copy(&mut reader, &mut file)
+    }? + offset;
+
+    if downloaded != expected {
+        return Err(Error::UnexpectedFileSize {
+            downloaded,
+            expected,
+        });
+    }
+
+    Ok(())
+}
+
+/// Create `path` by writing a temporary file and renaming it, so concurrent builds sharing a
+/// cache never see a partial file.
+fn create_atomic(path: &Path, write: impl FnOnce(&Path) -> io::Result<()>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(format!(".{}.tmp", std::process::id()));
+    let temp = PathBuf::from(temp);
+
+    match write(&temp).and_then(|_| fs::rename(&temp, path)) {
+        Ok(()) => Ok(()),
+        Err(err) => {
+            let _ = fs::remove_file(&temp);
+            // Another build may have created it first.
+            if path.exists() {
+                Ok(())
+            } else {
+                Err(err.into())
+            }
+        }
+    }
+}
+
+fn link_or_copy(from: &Path, to: &Path) -> io::Result<()> {
+    fs::hard_link(from, to).or_else(|_| fs::copy(from, to).map(|_| ()))
+}
+
+fn calculate_file_sha1(path: &Path) -> Result<String> {
+    let mut file = BufReader::new(File::open(path)?);
     let mut sha1 = Sha1::new();
     let mut buffer = [0; 8192];
 
     loop {
-        let count = file.read(&mut buffer).unwrap();
+        let count = file.read(&mut buffer)?;
         if count == 0 {
             break;
         }
         sha1.update(&buffer[..count]);
     }
 
-    sha1.digest().to_string()
+    Ok(sha1.digest().to_string())
 }
 
 pub struct OsAndArch {
@@ -648,3 +954,131 @@ pub const DEFAULT_TARGET: &str = "aarch64-pc-windows-msvc";
 pub const DEFAULT_TARGET: &str = "x86_64-apple-darwin";
 #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
 pub const DEFAULT_TARGET: &str = "aarch64-apple-darwin";
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = env::temp_dir().join(format!("download-cef-{name}-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    const CEF_VERSION: &str = "143.0.14+g1+chromium-143.0.1";
+    const ARCHIVE: &str = "cef_binary_143.0.14+g1+chromium-143.0.1_linux64_minimal.tar.bz2";
+
+    /// Create a local mirror with an `index.json` listing a single fake archive.
+    fn create_mirror(mirror: &Path) -> CefFile {
+        fs::write(mirror.join(ARCHIVE), b"not really an archive").unwrap();
+        let file = CefFile::try_from(mirror.join(ARCHIVE).as_path()).unwrap();
+        let index = CefIndex {
+            linux64: CefPlatform {
+                versions: vec![CefVersion {
+                    channel: Channel::Stable,
+                    cef_version: CEF_VERSION.to_string(),
+                    files: vec![file.clone()],
+                }],
+            },
+            ..Default::default()
+        };
+        fs::write(
+            mirror.join("index.json"),
+            serde_json::to_string(&index).unwrap(),
+        )
+        .unwrap();
+        file
+    }
+
+    fn version(options: &DownloadOptions) -> CefVersion {
+        let index = CefIndex::download_with(options).unwrap();
+        let platform = index.platform("x86_64-unknown-linux-gnu").unwrap();
+        let version = platform.version("143.0.14").unwrap();
+        serde_json::from_value(serde_json::to_value(version).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn local_mirror_and_cache() {
+        let temp = TempDir::new("cache");
+        let (mirror, cache, output) = (
+            temp.0.join("mirror"),
+            temp.0.join("cache"),
+            temp.0.join("output"),
+        );
+        fs::create_dir_all(&mirror).unwrap();
+        let file = create_mirror(&mirror);
+
+        let options = DownloadOptions {
+            url: format!("file://{}", mirror.display()),
+            cache_dir: Some(cache.clone()),
+            offline: true,
+            ..DownloadOptions::new("")
+        };
+        let version = version(&options);
+        let archive = version.download_archive_with(&options, &output).unwrap();
+        assert_eq!(archive, output.join(ARCHIVE));
+        let cached = cache.join(&file.sha1).join(ARCHIVE);
+        assert_eq!(calculate_file_sha1(&cached).unwrap(), file.sha1);
+
+        // Without the mirror, the archive is only available from the cache.
+        fs::remove_dir_all(&mirror).unwrap();
+        fs::remove_file(&archive).unwrap();
+        let options = DownloadOptions {
+            url: DEFAULT_CDN_URL.to_string(),
+            ..options
+        };
+        assert!(matches!(
+            CefIndex::download_with(&options),
+            Err(Error::Offline(_))
+        ));
+        version.download_archive_with(&options, &output).unwrap();
+        assert_eq!(calculate_file_sha1(&archive).unwrap(), file.sha1);
+
+        // A corrupted cache entry is removed instead of being used.
+        fs::remove_file(&archive).unwrap();
+        fs::remove_file(&cached).unwrap();
+        fs::write(&cached, b"corrupted").unwrap();
+        assert!(matches!(
+            version.download_archive_with(&options, &output),
+            Err(Error::Offline(_))
+        ));
+        assert!(!cached.exists());
+    }
+
+    #[test]
+    fn verify_before_extract() {
+        let temp = TempDir::new("verify");
+        create_mirror(&temp.0);
+        let options = DownloadOptions {
+            url: temp.0.display().to_string(),
+            cache_dir: None,
+            offline: true,
+            ..DownloadOptions::new("")
+        };
+        let mut version = version(&options);
+        let archive = temp.0.join(ARCHIVE);
+
+        fs::write(&archive, b"corrupted").unwrap();
+        assert!(matches!(
+            version.extract_archive("x86_64-unknown-linux-gnu", &archive, &temp.0, false),
+            Err(Error::CorruptedFile(_))
+        ));
+
+        version.cef_version = "144.0.1+g2+chromium-144.0.1".to_string();
+        assert!(matches!(
+            version.extract_archive("x86_64-unknown-linux-gnu", &archive, &temp.0, false),
+            Err(Error::VersionMismatch { .. })
+        ));
+    }
+}
@@ -1,12 +1,11 @@
 #![doc = include_str!("../README.md")]
 
 use clap::Parser;
-use download_cef::{CefFile, CefIndex, OsAndArch, DEFAULT_TARGET};
+use download_cef::{CefFile, CefIndex, DownloadOptions, OsAndArch, DEFAULT_TARGET};
 use std::{
     fs,
     path::{Path, PathBuf},
     sync::OnceLock,
-    time::Duration,
 };
 
 fn default_version() -> &'static str {
@@ -38,13 +37,25 @@ struct Args {
     mirror_url: String,
     #[arg(short, long)]
     archive: Option<String>,
+    /// Content-addressed archive cache shared across builds [env: CEF_CACHE_DIR]
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+    /// Only use a local mirror or the cache, never download [env: CEF_OFFLINE]
+    #[arg(long)]
+    offline: bool,
     output: String,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
     let output = PathBuf::from(args.output);
-    let url = args.mirror_url.as_str();
+    let options = DownloadOptions {
+        cache_dir: args.cache_dir.or_else(download_cef::default_cache_dir),
+        offline: args.offline || download_cef::default_offline(),
+        show_progress: true,
+        max_retries: 3,
+        ..DownloadOptions::new(&args.mirror_url)
+    };
 
     let parent = PathBuf::from(
         output
@@ -95,19 +106,12 @@ fn main() -> anyhow::Result<()> {
         }
         None => {
             let cef_version = args.version.as_str();
-            let index = CefIndex::download_from(url)?;
+            let index = CefIndex::download_with(&options)?;
             let platform = index.platform(target)?;
             let version = platform.version(cef_version)?;
 
-            let archive = version.download_archive_with_retry_from(
-                url,
-                &parent,
-                true,
-                Duration::from_secs(15),
-                3,
-            )?;
-            let extracted_dir =
-                download_cef::extract_target_archive(target, &archive, &parent, true)?;
+            let archive = version.download_archive_with(&options, &parent)?;
+            let extracted_dir = version.extract_archive(target, &archive, &parent, true)?;
 
             if !args.save_archive {
                 println!("Cleaning up: {}", archive.display());
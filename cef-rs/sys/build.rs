@@ -1,6 +1,6 @@
 #[cfg(not(feature = "dox"))]
 fn main() -> anyhow::Result<()> {
-    use download_cef::{CefIndex, OsAndArch};
+    use download_cef::{CefIndex, DownloadOptions, OsAndArch};
     use std::{env, fs, path::PathBuf};
 
     println!("cargo::rerun-if-changed=build.rs");
@@ -10,6 +10,9 @@ fn main() -> anyhow::Result<()> {
 
     println!("cargo::rerun-if-env-changed=FLATPAK");
     println!("cargo::rerun-if-env-changed=CEF_PATH");
+    println!("cargo::rerun-if-env-changed=CEF_DOWNLOAD_URL");
+    println!("cargo::rerun-if-env-changed=CEF_CACHE_DIR");
+    println!("cargo::rerun-if-env-changed=CEF_OFFLINE");
     let cef_path_env = env::var("FLATPAK")
         .map(|_| String::from("/usr/lib"))
         .or_else(|_| env::var("CEF_PATH"));
@@ -26,15 +29,23 @@ fn main() -> anyhow::Result<()> {
             let cef_dir = os_arch.to_string();
             let cef_dir = out_dir.join(&cef_dir);
 
-            if !fs::exists(&cef_dir)? {
+            // Extract again if archive.json is missing or is for an incompatible version.
+            let is_extracted = fs::exists(&cef_dir)?
+                && download_cef::check_archive_json(
+                    &env::var("CARGO_PKG_VERSION")?,
+                    &cef_dir.display().to_string(),
+                )
+                .is_ok();
+
+            if !is_extracted {
                 let cef_version = download_cef::default_version(&env::var("CARGO_PKG_VERSION")?);
-                let index = CefIndex::download()?;
+                let options = DownloadOptions::default();
+                let index = CefIndex::download_with(&options)?;
                 let platform = index.platform(&target)?;
                 let version = platform.version(&cef_version)?;
 
-                let archive = version.download_archive(&out_dir, false)?;
-                let extracted_dir =
-                    download_cef::extract_target_archive(&target, &archive, &out_dir, false)?;
+                let archive = version.download_archive_with(&options, &out_dir)?;
+                let extracted_dir = version.extract_archive(&target, &archive, &out_dir, false)?;
                 if extracted_dir != cef_dir {
                     return Err(anyhow::anyhow!(
                         "extracted dir {extracted_dir:?} does not match cef_dir {cef_dir:?}",
@@ -384,40 +384,42 @@ impl SignatureRef<'_> {
         }
     }
 
-    fn get_rust_args(&self, tree: &ParseTree) -> proc_macro2::TokenStream {
-        let args = self.merge_params(tree).filter_map(|arg| match arg {
-            MergedParam::Receiver => Some(quote! { &self }),
-            MergedParam::Single { name, ty: Some(ty) } => {
+    fn get_rust_arg_types(&self, tree: &ParseTree) -> Vec<(syn::Ident, proc_macro2::TokenStream)> {
+        self.merge_params(tree)
+            .filter_map(|arg| match arg {
+                MergedParam::Single { name, ty: Some(ty) } => Some((name, ty)),
+                MergedParam::Bounded {
+                    slice_name,
+                    slice_ty,
+                    ..
+                }
+                | MergedParam::Buffer {
+                    slice_name,
+                    slice_ty,
+                    ..
+                } => Some((slice_name, slice_ty)),
+                _ => None,
+            })
+            .map(|(name, ty)| {
                 let name = format_ident!("{name}");
                 let ty = ty
                     .get_argument_type(tree)
                     .unwrap_or_else(|| ty.ty.to_token_stream());
-                Some(quote! { #name: #ty })
-            }
-            MergedParam::Bounded {
-                slice_name,
-                slice_ty,
-                ..
-            } => {
-                let slice_name = format_ident!("{slice_name}");
-                let slice_ty = slice_ty
-                    .get_argument_type(tree)
-                    .unwrap_or_else(|| slice_ty.ty.to_token_stream());
-                Some(quote! { #slice_name: #slice_ty })
-            }
-            MergedParam::Buffer {
-                slice_name,
-                slice_ty,
-                ..
-            } => {
-                let slice_name = format_ident!("{slice_name}");
-                let slice_ty = slice_ty
-                    .get_argument_type(tree)
-                    .unwrap_or_else(|| slice_ty.ty.to_token_stream());
-                Some(quote! { #slice_name: #slice_ty })
-            }
-            _ => None,
-        });
+                (name, ty)
+            })
+            .collect()
+    }
+
+    fn get_rust_args(&self, tree: &ParseTree) -> proc_macro2::TokenStream {
+        let receiver = self
+            .merge_params(tree)
+            .any(|arg| matches!(arg, MergedParam::Receiver))
+            .then(|| quote! { &self });
+        let args = self
+            .get_rust_arg_types(tree)
+            .into_iter()
+            .map(|(name, ty)| quote! { #name: #ty });
+        let args = receiver.into_iter().chain(args);
 
         quote! { #(#args),* }
     }
@@ -871,6 +873,20 @@ impl SignatureRef<'_> {
         })
     }
 
+    fn get_default_output(&self) -> Option<proc_macro2::TokenStream> {
+        self.output.map(
+            |ty| match syn::parse2::<ModifiedType>(ty.to_token_stream()) {
+                Ok(ty)
+                    if ty.ty.to_token_stream().to_string()
+                        != quote! { ::std::os::raw::c_void }.to_string() =>
+                {
+                    quote! {  Default::default() }
+                }
+                _ => quote! { unsafe { std::mem::zeroed() } },
+            },
+        )
+    }
+
     fn wrap_cef_args(&self, tree: &ParseTree) -> proc_macro2::TokenStream {
         let capture = self.capture_params();
         let args = self.merge_params(tree).filter_map(|arg| match arg {
@@ -1745,7 +1761,8 @@ impl ParseTree<'_> {
                 unused_variables,
                 clippy::not_unsafe_ptr_arg_deref,
                 clippy::too_many_arguments,
-                clippy::let_unit_value
+                clippy::let_unit_value,
+                clippy::type_complexity
             )]
             use crate::rc::{
                 ConvertParam, ConvertReturnValue, Rc, RcImpl, RefGuard, WrapParamRef,
@@ -2330,18 +2347,7 @@ impl ParseTree<'_> {
             let sig = m.get_signature(self);
             let method_name = &m.name;
             let comment = format!("See [`{name}::{method_name}`] for more documentation.");
-            let impl_default =
-                m.output.map(
-                    |ty| match syn::parse2::<ModifiedType>(ty.to_token_stream()) {
-                        Ok(ty)
-                            if ty.ty.to_token_stream().to_string()
-                                != quote! { ::std::os::raw::c_void }.to_string() =>
-                        {
-                            quote! {  Default::default() }
-                        }
-                        _ => quote! { unsafe { std::mem::zeroed() } },
-                    },
-                );
+            let impl_default = m.get_default_output();
             quote! {
                 #[doc = #comment]
                 #sig {
@@ -2854,7 +2860,135 @@ fn make_my_struct() -> {rust_name} {{
         }
         .to_string();
 
-        writeln!(f, "{wrapper}")
+        writeln!(f, "{wrapper}")?;
+        self.write_closure_builder(f, s, &base_structs, rust_name)
+    }
+
+    fn write_closure_builder(
+        &self,
+        f: &mut Formatter<'_>,
+        s: &StructDeclarationRef<'_>,
+        base_structs: &[&StructDeclarationRef<'_>],
+        rust_name: &syn::Ident,
+    ) -> fmt::Result {
+        let builder_name = format_ident!("{rust_name}Builder");
+        let built_name = format_ident!("Built{rust_name}");
+        let impl_trait = format_ident!("Impl{rust_name}");
+        let wrap_type_macro = make_wrap_type_macro_name(&s.name);
+        let wrap_type_macro_name = format_ident!("{wrap_type_macro}");
+
+        // The macro expects the base interfaces first, starting with the root.
+        let interfaces = base_structs
+            .iter()
+            .rev()
+            .filter_map(|base_struct| {
+                self.cef_name_map
+                    .get(&base_struct.name)
+                    .map(|entry| (*base_struct, format_ident!("{}", entry.name)))
+            })
+            .chain(iter::once((s, rust_name.clone())))
+            .collect::<Vec<_>>();
+        let methods = interfaces
+            .iter()
+            .flat_map(|(s, _)| s.methods.iter())
+            .map(|m| {
+                let name = make_rust_method_name(&m.name);
+                let name = format_ident!("{name}");
+                let (arg_names, arg_types): (Vec<_>, Vec<_>) =
+                    m.get_rust_arg_types(self).into_iter().unzip();
+                let output = m.get_rust_output(self);
+                let closure_ty = quote! { Fn(#(#arg_types),*) #output };
+                (m, name, arg_names, closure_ty)
+            })
+            .collect::<Vec<_>>();
+
+        let fields = methods.iter().map(|(_, name, _, closure_ty)| {
+            quote! { #name: Option<std::rc::Rc<dyn #closure_ty>>, }
+        });
+        let setters = methods.iter().map(|(_, name, _, closure_ty)| {
+            let comment = format!("Implement [`{impl_trait}::{name}`] with a closure.");
+            quote! {
+                #[doc = #comment]
+                pub fn #name(mut self, closure: impl #closure_ty + 'static) -> Self {
+                    self.#name = Some(std::rc::Rc::new(closure));
+                    self
+                }
+            }
+        });
+        let impl_blocks = interfaces.iter().map(|(s, interface)| {
+            let methods = s.methods.iter().map(|m| {
+                let sig = m.get_signature(self);
+                let name = make_rust_method_name(&m.name);
+                let name = format_ident!("{name}");
+                let arg_names = m.get_rust_arg_types(self).into_iter().map(|(name, _)| name);
+                let body = match m.get_default_output() {
+                    Some(default) => quote! {
+                        match &self.builder.#name {
+                            Some(closure) => closure(#(#arg_names),*),
+                            None => #default,
+                        }
+                    },
+                    None => quote! {
+                        if let Some(closure) = &self.builder.#name {
+                            closure(#(#arg_names),*);
+                        }
+                    },
+                };
+                quote! {
+                    #sig {
+                        #body
+                    }
+                }
+            });
+            quote! {
+                impl #interface {
+                    #(#methods)*
+                }
+            }
+        });
+
+        let builder_comment =
+            format!("Create a [`{builder_name}`] to implement [`{rust_name}`] with closures.");
+        let struct_comment = format!(
+            r#"Builds a [`{rust_name}`] from closures. Methods without a closure keep the default
+implementation of the [`{impl_trait}`] trait."#
+        );
+        let build_comment = format!("Create a [`{rust_name}`] which calls the closures.");
+
+        let builder = quote! {
+            impl #rust_name {
+                #[doc = #builder_comment]
+                pub fn builder() -> #builder_name {
+                    Default::default()
+                }
+            }
+
+            #[doc = #struct_comment]
+            #[derive(Clone, Default)]
+            pub struct #builder_name {
+                #(#fields)*
+            }
+
+            impl #builder_name {
+                #(#setters)*
+
+                #[doc = #build_comment]
+                pub fn build(self) -> #rust_name {
+                    #built_name::new(self)
+                }
+            }
+
+            #wrap_type_macro_name! {
+                struct #built_name {
+                    builder: #builder_name,
+                }
+
+                #(#impl_blocks)*
+            }
+        }
+        .to_string();
+
+        writeln!(f, "{builder}")
     }
 
     fn write_scoped_struct(
@@ -34,7 +34,7 @@ use termwiz::image::{ImageData, TextureCoordinate};
 use termwiz::surface::{Line, SequenceNo};
 use thiserror::Error;
 use wezterm_term::color::ColorPalette;
-use wezterm_term::{Alert, ClipboardSelection, StableRowIndex, TerminalSize};
+use wezterm_term::{Alert, ClipboardSelection, CommandRecord, StableRowIndex, TerminalSize};
 
 #[derive(Error, Debug)]
 #[error("Corrupt Response: {0}")]
@@ -441,7 +441,7 @@ macro_rules! pdu {
 /// The overall version of the codec.
 /// This must be bumped when backwards incompatible changes
 /// are made to the types and protocol.
-pub const CODEC_VERSION: usize = 45;
+pub const CODEC_VERSION: usize = 52;
 
 // Defines the Pdu enum.
 // Each struct has an explicit identifying number.
@@ -504,6 +504,18 @@ pdu! {
     AdjustPaneSize: 62,
     WebOpen: 63,
     WebOpenResponse: 64,
+    WebNetworkLog: 65,
+    WebNetworkLogResponse: 66,
+    WebRoutes: 67,
+    WebRoutesResponse: 68,
+    WebContentBlocking: 69,
+    WebContentBlockingResponse: 70,
+    WebSessionSave: 71,
+    WebSessionSaveResponse: 72,
+    WebSessionRestore: 73,
+    WebSessionRestoreResponse: 74,
+    GetCommandRecords: 75,
+    GetCommandRecordsResponse: 76,
 }
 
 impl Pdu {
@@ -882,6 +894,8 @@ pub struct AdjustPaneSize {
 pub struct WebOpen {
     pub pane_id: PaneId,
     pub url: String,
+    /// The browser profile to open the page with
+    pub profile: String,
 }
 
 #[derive(Deserialize, Serialize, PartialEq, Debug)]
@@ -889,6 +903,83 @@ pub struct WebOpenResponse {
     pub message: String,
 }
 
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct WebNetworkLog {
+    pub pane_id: PaneId,
+    /// Only return entries with a sequence number >= since
+    pub since: u64,
+    /// Whether the browser should capture request and response bodies.
+    /// Capture lapses unless the log is polled again within a few seconds.
+    pub capture_bodies: bool,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct WebNetworkLogResponse {
+    pub entries: Vec<mux::web::NetworkEntry>,
+    /// The value to pass as `since` in the next request
+    pub next_seq: u64,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct WebRoutes {
+    pub scope: mux::web::RouteScope,
+    /// If set, replaces the rules for the scope.
+    /// If None, the rules are returned unchanged.
+    pub rules: Option<Vec<mux::web::RouteRule>>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct WebRoutesResponse {
+    /// The rules in effect for the scope
+    pub rules: Vec<mux::web::RouteRule>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct WebContentBlocking {
+    pub pane_id: PaneId,
+    /// If set, enables or disables blocking for the pane
+    pub enable: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct WebContentBlockingResponse {
+    pub enabled: bool,
+    /// Requests blocked on the current page
+    pub blocked: u64,
+    /// Requests blocked since the browser was opened
+    pub total_blocked: u64,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct WebSessionSave {}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct WebSessionSaveResponse {
+    pub session: mux::web_session::Session,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct WebSessionRestore {
+    pub session: mux::web_session::Session,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct WebSessionRestoreResponse {
+    /// The windows that were created
+    pub window_ids: Vec<WindowId>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct GetCommandRecords {
+    pub pane_id: PaneId,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct GetCommandRecordsResponse {
+    /// Oldest first
+    pub records: Vec<CommandRecord>,
+}
+
 #[derive(Deserialize, Serialize, PartialEq, Debug)]
 pub struct GetPaneDirectionResponse {
     pub pane_id: Option<PaneId>,
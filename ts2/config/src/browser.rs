@@ -0,0 +1,120 @@
+use crate::color::SrgbaTuple;
+use crate::default_true;
+use std::path::PathBuf;
+use wezterm_dynamic::{FromDynamic, ToDynamic};
+
+/// Controls the `prefers-color-scheme` media feature reported to
+/// pages displayed in browser panes
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, FromDynamic, ToDynamic)]
+pub enum BrowserColorScheme {
+    /// Report dark or light based on the luminance of the
+    /// terminal background color
+    #[default]
+    Auto,
+    /// Report whatever the operating system appearance is
+    System,
+    Light,
+    Dark,
+}
+
+impl BrowserColorScheme {
+    /// Resolve the scheme against the terminal background color.
+    /// Returns `Some(true)` for dark, `Some(false)` for light and
+    /// `None` when the decision is deferred to the system.
+    pub fn is_dark(self, background: SrgbaTuple) -> Option<bool> {
+        match self {
+            Self::Auto => Some(is_dark_background(background)),
+            Self::System => None,
+            Self::Light => Some(false),
+            Self::Dark => Some(true),
+        }
+    }
+}
+
+/// Returns true if the color is closer to black than to white,
+/// as perceived by a human; a relative luminance of 0.18 corresponds
+/// to a perceptual lightness of 50%.
+pub fn is_dark_background(background: SrgbaTuple) -> bool {
+    background.to_linear().relative_luminance() < 0.18
+}
+
+/// Settings that apply to the browser panes opened with a particular
+/// profile, as selected by `wezterm cli web-open --profile`
+#[derive(Debug, Clone, FromDynamic, ToDynamic)]
+pub struct BrowserProfile {
+    /// Filter lists in the Adblock Plus format, such as EasyList,
+    /// used to block requests and hide elements
+    #[dynamic(default)]
+    pub filter_lists: Vec<PathBuf>,
+
+    /// Whether the filter lists are applied when a browser pane is
+    /// opened.  Blocking can be toggled for an individual pane
+    /// using `wezterm cli web-block`.
+    #[dynamic(default = "default_true")]
+    pub content_blocking: bool,
+
+    /// Origins, such as "https://chat.example.com", whose pages are
+    /// granted permission to show notifications.  "*" grants it to
+    /// every origin.  Pages from other origins are denied permission.
+    #[dynamic(default)]
+    pub notification_origins: Vec<String>,
+}
+
+impl BrowserProfile {
+    /// Returns true if pages from `origin` may show notifications
+    pub fn allows_notifications(&self, origin: &str) -> bool {
+        // Opaque origins, such as those of sandboxed frames, are
+        // serialized as "null" and can't be told apart
+        if origin.is_empty() || origin == "null" {
+            return false;
+        }
+        let origin = origin.trim_end_matches('/');
+        self.notification_origins.iter().any(|allowed| {
+            allowed == "*" || allowed.trim_end_matches('/').eq_ignore_ascii_case(origin)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scheme_luminance() {
+        let black = SrgbaTuple(0., 0., 0., 1.);
+        let white = SrgbaTuple(1., 1., 1., 1.);
+        let solarized_light = SrgbaTuple::from((0xfd, 0xf6, 0xe3));
+        let mid_grey = SrgbaTuple::from((0x60, 0x60, 0x60));
+
+        assert_eq!(BrowserColorScheme::Auto.is_dark(black), Some(true));
+        assert_eq!(BrowserColorScheme::Auto.is_dark(white), Some(false));
+        assert_eq!(
+            BrowserColorScheme::Auto.is_dark(solarized_light),
+            Some(false)
+        );
+        assert_eq!(BrowserColorScheme::Auto.is_dark(mid_grey), Some(true));
+        assert_eq!(BrowserColorScheme::System.is_dark(black), None);
+        assert_eq!(BrowserColorScheme::Light.is_dark(black), Some(false));
+    }
+
+    #[test]
+    fn notification_origins() {
+        let profile = BrowserProfile {
+            filter_lists: vec![],
+            content_blocking: true,
+            notification_origins: vec!["https://chat.example.com/".to_string()],
+        };
+        assert!(profile.allows_notifications("https://chat.example.com"));
+        assert!(profile.allows_notifications("https://Chat.Example.com"));
+        assert!(!profile.allows_notifications("http://chat.example.com"));
+        assert!(!profile.allows_notifications("https://example.com"));
+        assert!(!profile.allows_notifications("null"));
+
+        let profile = BrowserProfile {
+            notification_origins: vec!["*".to_string()],
+            ..profile
+        };
+        assert!(profile.allows_notifications("https://example.com"));
+        assert!(!profile.allows_notifications("null"));
+    }
+}
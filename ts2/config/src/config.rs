@@ -1,5 +1,6 @@
 use crate::background::{BackgroundLayer, Gradient};
 use crate::bell::{AudibleBell, EasingFunction, VisualBell};
+use crate::browser::{BrowserColorScheme, BrowserProfile};
 use crate::color::{
     ColorSchemeFile, HsbTransform, Palette, SrgbaTuple, TabBarStyle, WindowFrameConfig,
 };
@@ -203,6 +204,22 @@ pub struct Config {
     )]
     pub scrollback_lines: usize,
 
+    /// How many of the most recent lines of scrollback are kept
+    /// uncompressed in memory; older lines are compressed in blocks.
+    /// 0 disables compression of the scrollback.
+    #[dynamic(default = "default_scrollback_compression_threshold")]
+    pub scrollback_compression_threshold: usize,
+
+    /// Whether the scrollback of terminal panes is saved with the
+    /// session, so that it can be restored after a restart
+    #[dynamic(default)]
+    pub persist_pane_contents: bool,
+
+    /// How often, in milliseconds, the session is saved while
+    /// `persist_pane_contents` is enabled
+    #[dynamic(default = "default_pane_contents_save_interval")]
+    pub pane_contents_save_interval: u64,
+
     /// If no `prog` is specified on the command line, use this
     /// instead of running the user's shell.
     /// For example, to have `wezterm` always run `top` by default,
@@ -267,6 +284,13 @@ pub struct Config {
     #[dynamic(default = "default_hyperlink_rules")]
     pub hyperlink_rules: Vec<hyperlink::Rule>,
 
+    /// Where clicked links and `OpenUri` are opened, unless the
+    /// `open_in` field of the hyperlink rule that matches the link
+    /// says otherwise.  The browser targets open a browser pane
+    /// next to the terminal.
+    #[dynamic(default)]
+    pub hyperlink_open_in: hyperlink::LinkTarget,
+
     /// What to set the TERM variable to
     #[dynamic(default = "default_term")]
     pub term: String,
@@ -512,6 +536,11 @@ pub struct Config {
     #[dynamic(try_from = "crate::units::PixelUnit", default = "default_half_cell")]
     pub min_scroll_bar_height: Dimension,
 
+    /// If true, mark the commands reported by shell integration in the
+    /// left padding of the pane, colored by their exit status.
+    #[dynamic(default = "default_true")]
+    pub show_command_status_markers: bool,
+
     /// If false, do not try to use a Wayland protocol connection
     /// when starting the gui frontend, and instead use X11.
     /// This option is only considered on X11/Wayland systems and
@@ -712,6 +741,11 @@ pub struct Config {
     #[dynamic(default)]
     pub notification_handling: NotificationHandling,
 
+    /// Whether applications may read the clipboard using the kitty
+    /// clipboard protocol (OSC 5522)
+    #[dynamic(default)]
+    pub clipboard_read_access: ClipboardReadAccess,
+
     #[dynamic(default = "default_true")]
     pub use_dead_keys: bool,
 
@@ -886,6 +920,29 @@ pub struct Config {
 
     #[dynamic(default = "default_ulimit_nproc")]
     pub ulimit_nproc: u64,
+
+    /// Controls the `prefers-color-scheme` reported to pages in
+    /// browser panes.  The default derives it from the luminance
+    /// of the terminal background color.
+    #[dynamic(default)]
+    pub browser_color_scheme: BrowserColorScheme,
+
+    /// When the browser color scheme resolves to dark, ask Chromium
+    /// to automatically darken pages that don't provide a dark theme
+    /// of their own.
+    #[dynamic(default)]
+    pub browser_force_dark: bool,
+
+    /// Use the terminal background color as the page background
+    /// before the first paint, so that opening a browser pane doesn't
+    /// flash white.
+    #[dynamic(default = "default_true")]
+    pub browser_background_from_scheme: bool,
+
+    /// Settings for browser panes, keyed by profile name.
+    /// Panes opened without a profile use the "default" profile.
+    #[dynamic(default)]
+    pub browser_profiles: HashMap<String, BrowserProfile>,
 }
 impl_lua_conversion_dynamic!(Config);
 
@@ -1687,6 +1744,14 @@ fn default_scrollback_lines() -> usize {
     3500
 }
 
+fn default_scrollback_compression_threshold() -> usize {
+    10_000
+}
+
+fn default_pane_contents_save_interval() -> u64 {
+    30_000
+}
+
 const MAX_SCROLLBACK_LINES: usize = 999_999_999;
 fn validate_scrollback_lines(value: &usize) -> Result<(), String> {
     if *value > MAX_SCROLLBACK_LINES {
@@ -2153,6 +2218,17 @@ pub enum NotificationHandling {
     SuppressFromFocusedWindow,
 }
 
+#[derive(Debug, FromDynamic, ToDynamic, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClipboardReadAccess {
+    /// Reading the clipboard is refused
+    Deny,
+    /// The user is asked before each read
+    #[default]
+    Prompt,
+    /// Reading the clipboard is allowed without asking
+    Allow,
+}
+
 fn validate_row_or_col(value: &u16) -> Result<(), String> {
     if *value < 1 {
         Err("initial_cols and initial_rows must be non-zero".to_string())
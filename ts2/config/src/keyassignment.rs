@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::path::PathBuf;
+use termwiz::hyperlink::LinkTarget;
 use wezterm_dynamic::{FromDynamic, FromDynamicOptions, ToDynamic, Value};
 use wezterm_input_types::{KeyCode, Modifiers};
 use wezterm_term::input::MouseButton;
@@ -333,6 +334,20 @@ impl Default for ClipboardPasteSource {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromDynamic, ToDynamic)]
+pub enum LinkFormat {
+    /// Just the URL
+    Url,
+    /// A Markdown link using the link text as its label
+    Markdown,
+}
+
+impl Default for LinkFormat {
+    fn default() -> Self {
+        Self::Url
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, FromDynamic, ToDynamic)]
 pub enum PaneSelectMode {
     Activate,
@@ -540,11 +555,19 @@ pub enum KeyAssignment {
     ToggleAlwaysOnBottom,
     SetWindowLevel(WindowLevel),
     CopyTo(ClipboardCopyDestination),
+    CutTo(ClipboardCopyDestination),
     CopyTextTo {
         text: String,
         destination: ClipboardCopyDestination,
     },
+    CopyLinkTo {
+        #[dynamic(default)]
+        format: LinkFormat,
+        #[dynamic(default)]
+        destination: ClipboardCopyDestination,
+    },
     PasteFrom(ClipboardPasteSource),
+    SelectAll,
     ActivateTabRelative(isize),
     ActivateTabRelativeNoWrap(isize),
     IncreaseFontSize,
@@ -569,6 +592,8 @@ pub enum KeyAssignment {
     ScrollByLine(isize),
     ScrollByCurrentEventWheelDelta,
     ScrollToPrompt(isize),
+    CopyCommandOutput(ClipboardCopyDestination),
+    SelectCommandOutput,
     ScrollToTop,
     ScrollToBottom,
     ShowTabNavigator,
@@ -588,6 +613,7 @@ pub enum KeyAssignment {
     SelectTextAtMouseCursor(SelectionMode),
     ExtendSelectionToMouseCursor(SelectionMode),
     OpenLinkAtMouseCursor,
+    OpenLinkAtMouseCursorIn(LinkTarget),
     ClearSelection,
     CompleteSelection(ClipboardCopyDestination),
     CompleteSelectionOrOpenLinkAtMouseCursor(ClipboardCopyDestination),
@@ -630,6 +656,11 @@ pub enum KeyAssignment {
     ClearKeyTableStack,
     DetachDomain(SpawnTabDomain),
     AttachDomain(String),
+    SwitchToTmuxSession {
+        #[dynamic(default)]
+        domain: SpawnTabDomain,
+        session: String,
+    },
 
     CopyMode(CopyModeAssignment),
     RotatePanes(RotationDirection),
@@ -22,6 +22,7 @@ use wezterm_term::UnicodeVersion;
 
 mod background;
 mod bell;
+mod browser;
 mod cell;
 mod color;
 mod config;
@@ -47,6 +48,7 @@ mod wsl;
 pub use crate::config::*;
 pub use background::*;
 pub use bell::*;
+pub use browser::*;
 pub use cell::*;
 pub use color::*;
 pub use daemon::*;
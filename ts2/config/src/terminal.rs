@@ -52,6 +52,13 @@ impl wezterm_term::TerminalConfiguration for TermConfig {
         self.configuration().scrollback_lines
     }
 
+    fn scrollback_compression_threshold(&self) -> Option<usize> {
+        match self.configuration().scrollback_compression_threshold {
+            0 => None,
+            threshold => Some(threshold),
+        }
+    }
+
     fn enable_csi_u_key_encoding(&self) -> bool {
         self.configuration().enable_csi_u_key_encoding
     }
@@ -320,6 +320,17 @@ impl UserData for MuxPane {
             Ok(zones)
         });
 
+        methods.add_method("get_command_records", |lua, this, ()| {
+            let mux = get_mux()?;
+            let pane = this.resolve(&mux)?;
+
+            let records = pane
+                .get_command_records()
+                .map_err(|e| mlua::Error::external(format!("{:#}", e)))?;
+
+            to_lua(lua, records)
+        });
+
         methods.add_method(
             "get_semantic_zone_at",
             |lua, this, (x, y): (usize, StableRowIndex)| {
@@ -29,7 +29,10 @@ use std::time::{Duration, Instant};
 use termwiz::escape::csi::{DecPrivateMode, DecPrivateModeCode, Device, Mode};
 use termwiz::escape::{Action, CSI};
 use thiserror::*;
-use wezterm_term::{Clipboard, ClipboardSelection, DownloadHandler, TerminalSize};
+use wezterm_term::{
+    Clipboard, ClipboardData, ClipboardReadRequest, ClipboardSelection, DownloadHandler,
+    TerminalSize,
+};
 #[cfg(windows)]
 use winapi::um::winsock2::{SOL_SOCKET, SO_RCVBUF, SO_SNDBUF};
 
@@ -47,6 +50,9 @@ pub mod termwiztermtab;
 pub mod tmux;
 pub mod tmux_commands;
 mod tmux_pty;
+pub mod web;
+pub mod web_filter;
+pub mod web_session;
 pub mod window;
 
 use crate::activity::Activity;
@@ -73,6 +79,17 @@ pub enum MuxNotification {
         selection: ClipboardSelection,
         clipboard: Option<String>,
     },
+    /// Set data of one or more mime types in the clipboard
+    AssignClipboardData {
+        pane_id: PaneId,
+        selection: ClipboardSelection,
+        data: Arc<Vec<ClipboardData>>,
+    },
+    /// The pane asked to read the clipboard
+    ReadClipboard {
+        pane_id: PaneId,
+        request: Arc<ClipboardReadRequest>,
+    },
     SaveToDownloads {
         name: Option<String>,
         data: Arc<Vec<u8>>,
@@ -98,6 +115,7 @@ pub enum MuxNotification {
     WebOpen {
         pane_id: PaneId,
         url: String,
+        profile: String,
     },
     WebClosed {
         pane_id: PaneId,
@@ -118,6 +136,10 @@ pub struct Mux {
     clients: RwLock<HashMap<ClientId, ClientInfo>>,
     identity: RwLock<Option<Arc<ClientId>>>,
     num_panes_by_workspace: RwLock<HashMap<String, usize>>,
+    web_network_logs: RwLock<HashMap<PaneId, web::SharedNetworkLog>>,
+    web_content_blocking: RwLock<HashMap<PaneId, web::SharedContentBlocking>>,
+    web_sessions: RwLock<HashMap<PaneId, web_session::SharedBrowserSession>>,
+    web_routes: Arc<web::RouteRegistry>,
     main_thread_id: std::thread::ThreadId,
     agent: Option<AgentProxy>,
 }
@@ -422,6 +444,21 @@ impl std::ops::Deref for MuxWindowBuilder {
     }
 }
 
+/// Returns the local path for a working directory URL, as reported
+/// by OSC 7
+pub(crate) fn working_dir_path(url: &url::Url) -> Option<String> {
+    let path = percent_decode_str(url.path()).decode_utf8().ok()?;
+    // On Windows the file URI can produce a path like:
+    // `/C:\Users` which is valid in a file URI, but the leading slash
+    // is not liked by the windows file APIs, so we strip it off here.
+    let bytes = path.as_bytes();
+    if bytes.len() > 2 && bytes[0] == b'/' && bytes[2] == b':' {
+        Some(path[1..].to_owned())
+    } else {
+        Some(path.into_owned())
+    }
+}
+
 impl Mux {
     pub fn new(default_domain: Option<Arc<dyn Domain>>) -> Self {
         let mut domains = HashMap::new();
@@ -453,6 +490,10 @@ impl Mux {
             clients: RwLock::new(HashMap::new()),
             identity: RwLock::new(None),
             num_panes_by_workspace: RwLock::new(HashMap::new()),
+            web_network_logs: RwLock::new(HashMap::new()),
+            web_content_blocking: RwLock::new(HashMap::new()),
+            web_sessions: RwLock::new(HashMap::new()),
+            web_routes: Arc::new(web::RouteRegistry::default()),
             main_thread_id: std::thread::current().id(),
             agent,
         }
@@ -778,6 +819,80 @@ impl Mux {
         self.tabs.read().get(&tab_id).map(Arc::clone)
     }
 
+    /// Create the network log for a browser pane, replacing any
+    /// log left over from a previous browser in the same pane
+    pub fn register_web_network_log(&self, pane_id: PaneId) -> web::SharedNetworkLog {
+        let log = web::SharedNetworkLog::default();
+        self.web_network_logs
+            .write()
+            .insert(pane_id, Arc::clone(&log));
+        log
+    }
+
+    pub fn unregister_web_network_log(&self, pane_id: PaneId) {
+        self.web_network_logs.write().remove(&pane_id);
+    }
+
+    /// Returns the network log for a pane, if it has a browser
+    pub fn get_web_network_log(&self, pane_id: PaneId) -> Option<web::SharedNetworkLog> {
+        self.web_network_logs.read().get(&pane_id).map(Arc::clone)
+    }
+
+    /// Create the content blocking state for a browser pane
+    pub fn register_web_content_blocking(
+        &self,
+        pane_id: PaneId,
+        enabled: bool,
+    ) -> web::SharedContentBlocking {
+        let state = Arc::new(web::ContentBlocking::new(enabled));
+        self.web_content_blocking
+            .write()
+            .insert(pane_id, Arc::clone(&state));
+        state
+    }
+
+    pub fn unregister_web_content_blocking(&self, pane_id: PaneId) {
+        self.web_content_blocking.write().remove(&pane_id);
+    }
+
+    /// Returns the content blocking state for a pane, if it has a
+    /// browser with filter lists
+    pub fn get_web_content_blocking(&self, pane_id: PaneId) -> Option<web::SharedContentBlocking> {
+        self.web_content_blocking
+            .read()
+            .get(&pane_id)
+            .map(Arc::clone)
+    }
+
+    /// Record the session state of the browser in a pane.
+    /// A pane with session state but no browser has been restored
+    /// and not yet shown; the GUI creates its browser on demand.
+    pub fn register_web_session(
+        &self,
+        pane_id: PaneId,
+        session: web_session::BrowserSession,
+    ) -> web_session::SharedBrowserSession {
+        let session = Arc::new(Mutex::new(session));
+        self.web_sessions
+            .write()
+            .insert(pane_id, Arc::clone(&session));
+        session
+    }
+
+    pub fn unregister_web_session(&self, pane_id: PaneId) {
+        self.web_sessions.write().remove(&pane_id);
+    }
+
+    /// Returns the session state for a pane, if it has a browser
+    pub fn get_web_session(&self, pane_id: PaneId) -> Option<web_session::SharedBrowserSession> {
+        self.web_sessions.read().get(&pane_id).map(Arc::clone)
+    }
+
+    /// Returns the request routing rules for browser panes
+    pub fn web_routes(&self) -> Arc<web::RouteRegistry> {
+        Arc::clone(&self.web_routes)
+    }
+
     pub fn add_pane(&self, pane: &Arc<dyn Pane>) -> Result<(), Error> {
         if self.panes.read().contains_key(&pane.pane_id()) {
             return Ok(());
@@ -822,6 +937,11 @@ impl Mux {
         if let Some(pane) = self.panes.write().remove(&pane_id).clone() {
             log::debug!("killing pane {}", pane_id);
             pane.kill();
+            self.web_routes
+                .set(web::RouteScope::Pane(pane_id), Vec::new());
+            // A restored browser pane that was never shown has a
+            // session but no browser to unregister it
+            self.unregister_web_session(pane_id);
             self.notify(MuxNotification::PaneRemoved(pane_id));
             changed = true;
         }
@@ -1165,29 +1285,11 @@ impl Mux {
         target_domain: DomainId,
         policy: CachePolicy,
     ) -> Option<String> {
-        command_dir.or_else(|| {
-            match pane {
-                Some(pane) if pane.domain_id() == target_domain => pane
-                    .get_current_working_dir(policy)
-                    .and_then(|url| {
-                        percent_decode_str(url.path())
-                            .decode_utf8()
-                            .ok()
-                            .map(|path| path.into_owned())
-                    })
-                    .map(|path| {
-                        // On Windows the file URI can produce a path like:
-                        // `/C:\Users` which is valid in a file URI, but the leading slash
-                        // is not liked by the windows file APIs, so we strip it off here.
-                        let bytes = path.as_bytes();
-                        if bytes.len() > 2 && bytes[0] == b'/' && bytes[2] == b':' {
-                            path[1..].to_owned()
-                        } else {
-                            path
-                        }
-                    }),
-                _ => None,
-            }
+        command_dir.or_else(|| match pane {
+            Some(pane) if pane.domain_id() == target_domain => pane
+                .get_current_working_dir(policy)
+                .and_then(|url| working_dir_path(&url)),
+            _ => None,
         })
     }
 
@@ -1457,6 +1559,31 @@ impl Clipboard for MuxClipboard {
         });
         Ok(())
     }
+
+    fn set_data(
+        &self,
+        selection: ClipboardSelection,
+        data: Vec<ClipboardData>,
+    ) -> anyhow::Result<()> {
+        let mux =
+            Mux::try_get().ok_or_else(|| anyhow::anyhow!("MuxClipboard::set_data: no Mux?"))?;
+        mux.notify(MuxNotification::AssignClipboardData {
+            pane_id: self.pane_id,
+            selection,
+            data: Arc::new(data),
+        });
+        Ok(())
+    }
+
+    fn request_data(&self, request: ClipboardReadRequest) -> anyhow::Result<()> {
+        let mux =
+            Mux::try_get().ok_or_else(|| anyhow::anyhow!("MuxClipboard::request_data: no Mux?"))?;
+        mux.notify(MuxNotification::ReadClipboard {
+            pane_id: self.pane_id,
+            request: Arc::new(request),
+        });
+        Ok(())
+    }
 }
 
 struct MuxDownloader {}
@@ -31,8 +31,9 @@ use url::Url;
 use wezterm_dynamic::Value;
 use wezterm_term::color::ColorPalette;
 use wezterm_term::{
-    Alert, AlertHandler, Clipboard, DownloadHandler, KeyCode, KeyModifiers, MouseEvent, Progress,
-    SemanticZone, StableRowIndex, Terminal, TerminalConfiguration, TerminalSize,
+    Alert, AlertHandler, Clipboard, CommandRecord, DownloadHandler, KeyCode, KeyModifiers,
+    MouseEvent, Progress, ScreenSnapshot, SemanticZone, StableRowIndex, Terminal,
+    TerminalConfiguration, TerminalSize,
 };
 
 const PROC_INFO_CACHE_TTL: Duration = Duration::from_millis(300);
@@ -643,6 +644,10 @@ impl Pane for LocalPane {
         term.get_semantic_zones()
     }
 
+    fn get_command_records(&self) -> anyhow::Result<Vec<CommandRecord>> {
+        Ok(self.terminal.lock().get_command_records())
+    }
+
     async fn search(
         &self,
         pattern: Pattern,
@@ -1022,6 +1027,32 @@ impl LocalPane {
         }
     }
 
+    /// Captures the primary screen and its scrollback, together with
+    /// the working directory and the foreground process
+    pub fn snapshot(&self) -> ScreenSnapshot {
+        let mut snapshot = self.terminal.lock().snapshot();
+        snapshot.working_dir = self
+            .get_current_working_dir(CachePolicy::AllowStale)
+            .map(|url| url.to_string());
+        snapshot.command = self
+            .get_foreground_process_info(CachePolicy::AllowStale)
+            .map(|info| info.argv);
+        snapshot
+    }
+
+    /// Places the lines of a snapshot taken from a prior pane into
+    /// the scrollback, above the output of the current process
+    pub fn restore_snapshot(&self, snapshot: ScreenSnapshot) {
+        self.terminal.lock().restore_snapshot(snapshot);
+        Mux::notify_from_any_thread(MuxNotification::PaneOutput(self.pane_id));
+    }
+
+    /// Reports to the application that the desktop notification with
+    /// `id` was closed, if it asked for that and hasn't been told yet
+    pub fn notification_closed(&self, id: &str) {
+        self.terminal.lock().notification_closed(id);
+    }
+
     #[cfg(unix)]
     fn get_leader(&self, policy: CachePolicy) -> CachedLeaderInfo {
         let mut leader = self.leader.lock();
@@ -3,10 +3,12 @@ use crate::domain::{alloc_domain_id, Domain, DomainId, DomainState, SplitSource}
 use crate::pane::{Pane, PaneId};
 use crate::tab::{SplitRequest, Tab, TabId};
 use crate::tmux_commands::{
-    ListAllPanes, ListAllWindows, ListCommands, NewWindow, SplitPane, TmuxCommand,
+    quote_argument, DetachClient, ListAllWindows, ListCommands, ListSessions, NewWindow, SplitPane,
+    SwitchClient, TmuxCommand,
 };
 use crate::window::WindowId;
 use crate::{Mux, MuxWindowBuilder};
+use anyhow::anyhow;
 use async_trait::async_trait;
 use filedescriptor::FileDescriptor;
 use parking_lot::{Condvar, Mutex};
@@ -74,8 +76,20 @@ pub(crate) struct TmuxDomainState {
     pub tmux_session: Mutex<Option<TmuxSessionId>>,
     pub support_commands: Mutex<HashMap<String, String>>,
     pub attach_state: Mutex<AttachState>,
-    pending_splits: Mutex<VecDeque<promise::Promise<TmuxPaneId>>>,
+    pub pending_splits: Mutex<VecDeque<promise::Promise<TmuxPaneId>>>,
+    /// Promises for the tabs of windows requested by `spawn`, in the
+    /// order that the new-window commands were sent
+    pub pending_windows: Mutex<VecDeque<promise::Promise<TabId>>>,
+    /// Promises for windows that tmux has created but that we have
+    /// not yet attached
+    pub awaiting_windows: Mutex<HashMap<TmuxWindowId, promise::Promise<TabId>>>,
     pub backlog: Mutex<HashMap<TmuxPaneId, Vec<u8>>>,
+    /// The sessions known to the tmux server
+    pub sessions: Mutex<Vec<(TmuxSessionId, String)>>,
+    /// The tabs and panes of the session that we are switching away
+    /// from; they are removed once the new session is attached
+    pub stale_tabs: Mutex<Vec<TabId>>,
+    pub stale_panes: Mutex<Vec<RefTmuxRemotePane>>,
 }
 
 pub struct TmuxDomain {
@@ -120,15 +134,24 @@ impl TmuxDomainState {
                     *self.state.lock() = State::Exit;
                     let mut pane_map = self.remote_panes.lock();
                     for (_, v) in pane_map.iter_mut() {
-                        let remote_pane = v.lock();
-                        let (lock, condvar) = &*remote_pane.active_lock;
-                        let mut released = lock.lock();
-                        *released = true;
-                        condvar.notify_all();
+                        release_remote_pane(v);
+                    }
+                    for v in self.stale_panes.lock().drain(..) {
+                        release_remote_pane(&v);
                     }
                     let mut cmd_queue = self.cmd_queue.as_ref().lock();
                     cmd_queue.clear();
 
+                    for mut promise in self.pending_splits.lock().drain(..) {
+                        promise.err(anyhow!("tmux exited"));
+                    }
+                    for mut promise in self.pending_windows.lock().drain(..) {
+                        promise.err(anyhow!("tmux exited"));
+                    }
+                    for (_, mut promise) in self.awaiting_windows.lock().drain() {
+                        promise.err(anyhow!("tmux exited"));
+                    }
+
                     // Force to quit the tmux mode
                     let pane_id = self.pane_id;
                     promise::spawn::spawn_into_main_thread_with_low_priority(async move {
@@ -146,16 +169,20 @@ impl TmuxDomainState {
                     visible_layout: _,
                     raw_flags: _,
                 } => {
-                    let mut cmd_queue = self.cmd_queue.as_ref().lock();
-                    cmd_queue.push_back(Box::new(ListAllPanes {
-                        window_id: *window,
-                        prune: true,
-                        layout_csum: if let Some(l) = layout.get(0..4) {
-                            l.to_string()
-                        } else {
-                            "".to_string()
-                        },
-                    }));
+                    let domain_id = self.domain_id;
+                    let window = *window;
+                    let layout = layout.clone();
+                    promise::spawn::spawn_into_main_thread(async move {
+                        let mux = Mux::get();
+                        if let Some(domain) = mux.get_domain(domain_id) {
+                            if let Some(tmux_domain) = domain.downcast_ref::<TmuxDomain>() {
+                                if let Err(err) = tmux_domain.inner.sync_layout(window, &layout) {
+                                    log::error!("Failed to sync tmux layout: {:#}", err);
+                                }
+                            }
+                        }
+                    })
+                    .detach();
                 }
                 Event::Output { pane, text } => {
                     let pane_map = self.remote_panes.lock();
@@ -172,13 +199,34 @@ impl TmuxDomainState {
                     }
                 }
                 Event::SessionChanged { session, name: _ } => {
-                    *self.tmux_session.lock() = Some(*session);
-                    let mut cmd_queue = self.cmd_queue.as_ref().lock();
-                    cmd_queue.push_back(Box::new(ListCommands));
+                    let previous = self.tmux_session.lock().replace(*session);
+                    match previous {
+                        None => {
+                            let mut cmd_queue = self.cmd_queue.as_ref().lock();
+                            cmd_queue.push_back(Box::new(ListCommands));
 
-                    self.subscribe_notification();
+                            self.subscribe_notification();
+                        }
+                        Some(previous) if previous != *session => {
+                            self.detach_session();
+                            let mut cmd_queue = self.cmd_queue.as_ref().lock();
+                            cmd_queue.push_back(Box::new(ListAllWindows {
+                                session_id: *session,
+                                window_id: None,
+                            }));
+                        }
+                        Some(_) => {}
+                    }
+                    self.cmd_queue
+                        .as_ref()
+                        .lock()
+                        .push_back(Box::new(ListSessions));
                     log::info!("tmux session changed:{}", session);
                 }
+                Event::SessionsChanged | Event::SessionRenamed { .. } => {
+                    let mut cmd_queue = self.cmd_queue.as_ref().lock();
+                    cmd_queue.push_back(Box::new(ListSessions));
+                }
                 Event::WindowAdd { window } => {
                     // Only handle the new tab, the first empty window handled by sync_window_state
                     if !self.gui_window.lock().is_none() {
@@ -201,14 +249,6 @@ impl TmuxDomainState {
                     if !self.check_window_attached(*window) {
                         continue;
                     }
-
-                    // Split pane
-                    if !self.check_pane_attached(*window, *pane) {
-                        let mut pending_splits = self.pending_splits.lock();
-                        if let Some(mut promise) = pending_splits.pop_front() {
-                            promise.ok(*pane);
-                        }
-                    }
                     log::info!("tmux window pane changed: {}:{}", window, pane);
                 }
                 Event::WindowRenamed { window, name } => {
@@ -283,10 +323,9 @@ impl TmuxDomainState {
                         notified: false,
                     }
                 } else {
-                    mux.new_empty_window(
-                        None, /* TODO: pass session here */
-                        None, /* position */
-                    )
+                    // The window joins the active workspace; new tmux
+                    // windows are placed in our session by `NewWindow`
+                    mux.new_empty_window(None, None /* position */)
                 };
 
             log::info!("Tmux create window id {}", window_builder.window_id);
@@ -297,11 +336,78 @@ impl TmuxDomainState {
         };
     }
 
-    /// create a tmux window
-    pub fn create_tmux_window(&self) {
+    /// create a tmux window in the current session, returning a future
+    /// that resolves to the local tab once the window is attached
+    pub fn create_tmux_window(
+        &self,
+        command: Option<CommandBuilder>,
+        command_dir: Option<String>,
+    ) -> anyhow::Result<promise::Future<TabId>> {
+        if self.has_exited() {
+            anyhow::bail!("tmux has exited");
+        }
+        let session_id = self
+            .tmux_session
+            .lock()
+            .ok_or_else(|| anyhow!("not attached to a tmux session"))?;
+        let command = tmux_shell_command(command)?;
+        let command_dir = tmux_command_dir(command_dir)?;
+
+        let mut promise = promise::Promise::new();
+        let future = promise
+            .get_future()
+            .ok_or_else(|| anyhow!("failed to create future"))?;
+        self.pending_windows.lock().push_back(promise);
+
         let mut cmd_queue = self.cmd_queue.as_ref().lock();
-        cmd_queue.push_back(Box::new(NewWindow));
+        cmd_queue.push_back(Box::new(NewWindow {
+            session_id,
+            command,
+            command_dir,
+        }));
         TmuxDomainState::schedule_send_next_command(self.domain_id);
+        Ok(future)
+    }
+
+    /// Hand the id of a window made by `NewWindow` to the oldest
+    /// pending spawn.  The spawn completes once the window is attached,
+    /// which its %window-add may already have done.
+    pub fn new_window_created(
+        &self,
+        window_id: anyhow::Result<TmuxWindowId>,
+    ) -> anyhow::Result<()> {
+        let Some(mut promise) = self.pending_windows.lock().pop_front() else {
+            anyhow::bail!(
+                "new-window in domain={} has no pending spawn",
+                self.domain_id
+            );
+        };
+        let window_id = match window_id {
+            Ok(id) => id,
+            Err(err) => {
+                promise.err(anyhow!("{err:#}"));
+                return Err(err);
+            }
+        };
+
+        let tab_id = self.gui_tabs.lock().get(&window_id).map(|t| t.tab_id);
+        match tab_id {
+            Some(tab_id) => {
+                promise.ok(tab_id);
+            }
+            None => {
+                self.awaiting_windows.lock().insert(window_id, promise);
+            }
+        }
+        Ok(())
+    }
+
+    /// Complete the spawn that is waiting for a tmux window, if any,
+    /// now that the window is attached as `tab_id`
+    pub fn window_attached(&self, window_id: TmuxWindowId, tab_id: TabId) {
+        if let Some(mut promise) = self.awaiting_windows.lock().remove(&window_id) {
+            promise.ok(tab_id);
+        }
     }
 
     /// split the tmux pane
@@ -310,6 +416,8 @@ impl TmuxDomainState {
         _tab: TabId,
         pane_id: PaneId,
         split_request: SplitRequest,
+        command: Option<CommandBuilder>,
+        command_dir: Option<String>,
     ) -> anyhow::Result<()> {
         let tmux_pane_id = self
             .remote_panes
@@ -323,6 +431,8 @@ impl TmuxDomainState {
             cmd_queue.push_back(Box::new(SplitPane {
                 pane_id: id,
                 direction: split_request.direction,
+                command: tmux_shell_command(command)?,
+                command_dir: tmux_command_dir(command_dir)?,
             }));
             TmuxDomainState::schedule_send_next_command(self.domain_id);
             return Ok(());
@@ -330,6 +440,75 @@ impl TmuxDomainState {
             anyhow::bail!("Could not find the tmux pane peer for local pane: {pane_id}");
         }
     }
+
+    /// Forget the tabs and panes of the current session; they stay
+    /// on screen until the windows of the new session are attached
+    fn detach_session(&self) {
+        *self.attach_state.lock() = AttachState::Init;
+        self.backlog.lock().clear();
+        let mut stale_tabs = self.stale_tabs.lock();
+        stale_tabs.extend(self.gui_tabs.lock().drain().map(|(_, tab)| tab.tab_id));
+        let mut stale_panes = self.stale_panes.lock();
+        stale_panes.extend(self.remote_panes.lock().drain().map(|(_, pane)| pane));
+    }
+
+    /// Remove the tabs and panes left over from a session switch
+    pub fn remove_stale_session(&self) {
+        let (tab_ids, pane_ids) = self.take_stale_session();
+        let mux = Mux::get();
+        for tab_id in tab_ids {
+            mux.remove_tab(tab_id);
+        }
+        for pane_id in pane_ids {
+            mux.remove_pane(pane_id);
+        }
+    }
+
+    /// Release the panes left over from a session switch, returning
+    /// the local tabs and panes that are to be removed
+    fn take_stale_session(&self) -> (Vec<TabId>, Vec<PaneId>) {
+        let tab_ids = self.stale_tabs.lock().drain(..).collect();
+        let pane_ids = self
+            .stale_panes
+            .lock()
+            .drain(..)
+            .map(|pane| {
+                release_remote_pane(&pane);
+                let local_pane_id = pane.lock().local_pane_id;
+                local_pane_id
+            })
+            .collect();
+        (tab_ids, pane_ids)
+    }
+
+    fn has_exited(&self) -> bool {
+        *self.state.lock() == State::Exit
+    }
+}
+
+/// Wake up the `TmuxChild` of a pane so that the pane can close
+fn release_remote_pane(pane: &RefTmuxRemotePane) {
+    let remote_pane = pane.lock();
+    let (lock, condvar) = &*remote_pane.active_lock;
+    let mut released = lock.lock();
+    *released = true;
+    condvar.notify_all();
+}
+
+/// The quoted command line for tmux to run in a new pane, or None
+/// to run the default shell
+fn tmux_shell_command(command: Option<CommandBuilder>) -> anyhow::Result<Option<String>> {
+    match command {
+        Some(command) if !command.is_default_prog() => {
+            Ok(Some(quote_argument(&command.as_unix_command_line()?)?))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// The quoted working directory for a new tmux pane
+fn tmux_command_dir(command_dir: Option<String>) -> anyhow::Result<Option<String>> {
+    command_dir.as_deref().map(quote_argument).transpose()
 }
 
 impl TmuxDomain {
@@ -349,7 +528,12 @@ impl TmuxDomain {
             support_commands: Mutex::new(HashMap::default()),
             attach_state: Mutex::new(AttachState::Init),
             pending_splits: Mutex::new(VecDeque::default()),
+            pending_windows: Mutex::new(VecDeque::default()),
+            awaiting_windows: Mutex::new(HashMap::default()),
             backlog: Mutex::new(HashMap::default()),
+            sessions: Mutex::new(vec![]),
+            stale_tabs: Mutex::new(vec![]),
+            stale_panes: Mutex::new(vec![]),
         });
 
         Self { inner }
@@ -358,6 +542,51 @@ impl TmuxDomain {
     fn send_next_command(&self) {
         self.inner.send_next_command();
     }
+
+    /// The names of the tmux sessions other than the attached one
+    pub fn other_sessions(&self) -> Vec<String> {
+        let current = *self.inner.tmux_session.lock();
+        self.inner
+            .sessions
+            .lock()
+            .iter()
+            .filter(|(id, _)| Some(*id) != current)
+            .map(|(_, name)| name.clone())
+            .collect()
+    }
+
+    /// Switch this client to the tmux session named `name`.  The tabs
+    /// of the current session are replaced by those of the new one.
+    pub fn switch_session(&self, name: &str) -> anyhow::Result<()> {
+        if self.inner.has_exited() {
+            anyhow::bail!("tmux has exited");
+        }
+        let session_id = self
+            .inner
+            .sessions
+            .lock()
+            .iter()
+            .find(|(_, n)| n == name)
+            .map(|(id, _)| *id)
+            .ok_or_else(|| anyhow!("no tmux session named `{name}`"))?;
+
+        let mut cmd_queue = self.inner.cmd_queue.as_ref().lock();
+        cmd_queue.push_back(Box::new(SwitchClient { session_id }));
+        TmuxDomainState::schedule_send_next_command(self.inner.domain_id);
+        Ok(())
+    }
+
+    /// Returns the local pane for a tmux pane, if we have attached it
+    fn local_pane(&self, pane_id: TmuxPaneId) -> Option<Arc<dyn Pane>> {
+        let local_pane_id = self
+            .inner
+            .remote_panes
+            .lock()
+            .get(&pane_id)?
+            .lock()
+            .local_pane_id;
+        Mux::get().get_pane(local_pane_id)
+    }
 }
 
 #[async_trait(?Send)]
@@ -365,33 +594,70 @@ impl Domain for TmuxDomain {
     async fn spawn(
         &self,
         _size: TerminalSize,
-        _command: Option<CommandBuilder>,
-        _command_dir: Option<String>,
-        _window: WindowId,
+        command: Option<CommandBuilder>,
+        command_dir: Option<String>,
+        window: WindowId,
     ) -> anyhow::Result<Arc<Tab>> {
-        self.inner.create_tmux_window();
-        // This is intention, we would not return a Tab, since we don't have now!
-        // We use create_tmux_window to create back end tmux window, then the
-        // Tmux WindowAdd event will triage us to do the rest things.
-        anyhow::bail!("Intention: we use tmux command to do so");
+        // tmux decides the size of the window.  The tab is attached to
+        // the window that holds the rest of this session, so move it
+        // to the window that it was requested for.
+        let tab_id = self.inner.create_tmux_window(command, command_dir)?.await?;
+        let mux = Mux::get();
+        let tab = mux
+            .get_tab(tab_id)
+            .ok_or_else(|| anyhow!("tab {tab_id} for the new tmux window went away"))?;
+        match mux.window_containing_tab(tab_id) {
+            Some(current) if current == window => {}
+            current => {
+                if mux.get_window(window).is_none() {
+                    anyhow::bail!("no such window_id {window} for the new tmux window");
+                }
+                if let Some(mut current) = current.and_then(|id| mux.get_window_mut(id)) {
+                    current.remove_by_id(tab_id);
+                }
+                mux.add_tab_to_window(&tab, window)?;
+            }
+        }
+        Ok(tab)
     }
 
     async fn split_pane(
         &self,
-        _source: SplitSource,
+        source: SplitSource,
         tab: TabId,
         pane_id: PaneId,
         split_request: SplitRequest,
     ) -> anyhow::Result<Arc<dyn Pane>> {
+        let (command, command_dir) = match source {
+            SplitSource::Spawn {
+                command,
+                command_dir,
+            } => (command, command_dir),
+            SplitSource::MovePane(_) => {
+                anyhow::bail!("Moving panes into a tmux window is not supported")
+            }
+        };
+
         let mut promise = promise::Promise::new();
         if let Some(future) = promise.get_future() {
             {
                 let mut pending_splits = self.inner.pending_splits.lock();
-                let _ = self.inner.split_tmux_pane(tab, pane_id, split_request)?;
+                let _ = self.inner.split_tmux_pane(
+                    tab,
+                    pane_id,
+                    split_request,
+                    command,
+                    command_dir,
+                )?;
                 pending_splits.push_back(promise);
             }
 
             if let Ok(id) = future.await {
+                // The layout change for the split may have already
+                // attached the new pane
+                if let Some(pane) = self.local_pane(id) {
+                    return Ok(pane);
+                }
                 let pane = self.inner.split_pane(tab, pane_id, id, split_request);
                 return pane;
             }
@@ -406,7 +672,10 @@ impl Domain for TmuxDomain {
         _command: Option<CommandBuilder>,
         _command_dir: Option<String>,
     ) -> anyhow::Result<Arc<dyn Pane>> {
-        anyhow::bail!("Spawn_pane not yet implemented for TmuxDomain");
+        // Every tmux pane lives in a tmux window, and is attached to a
+        // tab as soon as tmux reports it, so there is no way to create
+        // a pane that the caller can place for itself
+        anyhow::bail!("spawn_pane is not supported for the tmux domain; use spawn or split_pane")
     }
 
     fn domain_id(&self) -> DomainId {
@@ -421,15 +690,241 @@ impl Domain for TmuxDomain {
         Ok(())
     }
 
+    fn spawnable(&self) -> bool {
+        !self.inner.has_exited()
+    }
+
     fn detachable(&self) -> bool {
-        false
+        !self.inner.has_exited()
     }
 
+    /// Detach from tmux, leaving the session running on the server.
+    /// tmux replies with %exit, which closes our panes.
     fn detach(&self) -> anyhow::Result<()> {
-        anyhow::bail!("detach not implemented for TmuxDomain");
+        if self.inner.has_exited() {
+            anyhow::bail!("tmux has already exited");
+        }
+        let mut cmd_queue = self.inner.cmd_queue.as_ref().lock();
+        cmd_queue.push_back(Box::new(DetachClient));
+        TmuxDomainState::schedule_send_next_command(self.inner.domain_id);
+        Ok(())
     }
 
     fn state(&self) -> DomainState {
-        DomainState::Attached
+        if self.inner.has_exited() {
+            DomainState::Detached
+        } else {
+            DomainState::Attached
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Once;
+
+    fn make_domain() -> TmuxDomain {
+        static SCHEDULER: Once = Once::new();
+        // Commands are sent to tmux from the main thread; these tests
+        // only look at what was queued, so nothing needs to run
+        SCHEDULER.call_once(|| {
+            promise::spawn::set_schedulers(Box::new(|_| {}), Box::new(|_| {}));
+        });
+
+        let domain = TmuxDomain::new(0);
+        *domain.inner.tmux_session.lock() = Some(1);
+        domain
+    }
+
+    fn add_tab(domain: &TmuxDomain, tmux_window_id: TmuxWindowId, tab_id: TabId) {
+        domain.inner.gui_tabs.lock().insert(
+            tmux_window_id,
+            TmuxTab {
+                tab_id,
+                tmux_window_id,
+                layout_csum: String::new(),
+                panes: HashSet::new(),
+            },
+        );
+    }
+
+    fn add_remote_pane(
+        domain: &TmuxDomain,
+        pane_id: TmuxPaneId,
+        local_pane_id: PaneId,
+    ) -> RefTmuxRemotePane {
+        let pipe = filedescriptor::Pipe::new().unwrap();
+        let pane = Arc::new(Mutex::new(TmuxRemotePane {
+            local_pane_id,
+            output_write: pipe.write,
+            active_lock: Arc::new((Mutex::new(false), Condvar::new())),
+            session_id: 1,
+            window_id: 1,
+            pane_id,
+            cursor_x: 0,
+            cursor_y: 0,
+            pane_width: 80,
+            pane_height: 24,
+            pane_left: 0,
+            pane_top: 0,
+        }));
+        domain
+            .inner
+            .remote_panes
+            .lock()
+            .insert(pane_id, Arc::clone(&pane));
+        pane
+    }
+
+    fn is_released(pane: &RefTmuxRemotePane) -> bool {
+        *pane.lock().active_lock.0.lock()
+    }
+
+    fn queued_commands(domain: &TmuxDomain) -> Vec<String> {
+        domain
+            .inner
+            .cmd_queue
+            .lock()
+            .iter()
+            .map(|cmd| cmd.get_command(domain.inner.domain_id))
+            .collect()
+    }
+
+    #[test]
+    fn spawned_window_waits_for_attach() {
+        let domain = make_domain();
+        let future = domain.inner.create_tmux_window(None, None).unwrap();
+        assert_eq!(
+            queued_commands(&domain),
+            vec!["new-window -t $1 -P -F '#{window_id}'\n".to_string()]
+        );
+        assert_eq!(domain.inner.pending_windows.lock().len(), 1);
+
+        // tmux reports the new window before it is attached
+        domain.inner.new_window_created(Ok(5)).unwrap();
+        assert!(domain.inner.pending_windows.lock().is_empty());
+        assert!(domain.inner.awaiting_windows.lock().contains_key(&5));
+
+        domain.inner.window_attached(5, 42);
+        assert!(domain.inner.awaiting_windows.lock().is_empty());
+        assert_eq!(promise::spawn::block_on(future).unwrap(), 42);
+    }
+
+    #[test]
+    fn spawned_window_already_attached() {
+        let domain = make_domain();
+        let future = domain.inner.create_tmux_window(None, None).unwrap();
+
+        // The %window-add for the window won the race with the
+        // response to new-window
+        add_tab(&domain, 6, 43);
+        domain.inner.new_window_created(Ok(6)).unwrap();
+        assert!(domain.inner.awaiting_windows.lock().is_empty());
+        assert_eq!(promise::spawn::block_on(future).unwrap(), 43);
+    }
+
+    #[test]
+    fn spawned_window_failed() {
+        let domain = make_domain();
+        let future = domain.inner.create_tmux_window(None, None).unwrap();
+        assert!(domain
+            .inner
+            .new_window_created(Err(anyhow!("no space for new pane")))
+            .is_err());
+        assert!(domain.inner.pending_windows.lock().is_empty());
+        assert!(promise::spawn::block_on(future).is_err());
+
+        // A response without a pending spawn is an error
+        assert!(domain.inner.new_window_created(Ok(7)).is_err());
+    }
+
+    #[test]
+    fn spawn_requires_session() {
+        let domain = make_domain();
+        *domain.inner.tmux_session.lock() = None;
+        assert!(domain.inner.create_tmux_window(None, None).is_err());
+        assert!(domain.inner.pending_windows.lock().is_empty());
+        assert!(queued_commands(&domain).is_empty());
+    }
+
+    #[test]
+    fn spawn_rejects_line_breaks() {
+        let domain = make_domain();
+        assert!(domain
+            .inner
+            .create_tmux_window(None, Some("/tmp\nkill-server".to_string()))
+            .is_err());
+        assert!(domain.inner.pending_windows.lock().is_empty());
+        assert!(queued_commands(&domain).is_empty());
+
+        assert_eq!(
+            quote_argument("say \"$HOME\" \\o/").unwrap(),
+            "\"say \\\"\\$HOME\\\" \\\\o/\""
+        );
+        assert!(quote_argument("a\rb").is_err());
+    }
+
+    #[test]
+    fn switching_session_keeps_stale_panes_until_attached() {
+        let domain = make_domain();
+        add_tab(&domain, 1, 10);
+        let pane = add_remote_pane(&domain, 2, 20);
+        *domain.inner.attach_state.lock() = AttachState::Done;
+        domain.inner.backlog.lock().insert(2, b"output".to_vec());
+
+        domain.inner.advance(Box::new(vec![Event::SessionChanged {
+            session: 2,
+            name: "other".to_string(),
+        }]));
+
+        assert_eq!(*domain.inner.tmux_session.lock(), Some(2));
+        assert_eq!(*domain.inner.attach_state.lock(), AttachState::Init);
+        assert!(domain.inner.gui_tabs.lock().is_empty());
+        assert!(domain.inner.remote_panes.lock().is_empty());
+        assert!(domain.inner.backlog.lock().is_empty());
+        assert_eq!(*domain.inner.stale_tabs.lock(), vec![10]);
+        assert!(!is_released(&pane));
+        let commands = queued_commands(&domain);
+        assert_eq!(commands.len(), 2);
+        assert!(commands[0].starts_with("list-windows "));
+        assert!(commands[0].ends_with(" -t $2\n"));
+
+        // Once the new session is attached, the old panes are let go
+        assert_eq!(domain.inner.take_stale_session(), (vec![10], vec![20]));
+        assert!(is_released(&pane));
+        assert!(domain.inner.stale_tabs.lock().is_empty());
+        assert!(domain.inner.stale_panes.lock().is_empty());
+        assert_eq!(domain.inner.take_stale_session(), (vec![], vec![]));
+    }
+
+    #[test]
+    fn detach_then_exit() {
+        let domain = make_domain();
+        let pane = add_remote_pane(&domain, 2, 20);
+        let future = domain.inner.create_tmux_window(None, None).unwrap();
+
+        assert!(domain.detachable());
+        assert!(domain.spawnable());
+        domain.detach().unwrap();
+        assert_eq!(
+            queued_commands(&domain).last().map(String::as_str),
+            Some("detach-client\n")
+        );
+        assert_eq!(domain.state(), DomainState::Attached);
+
+        // tmux acknowledges the detach with %exit
+        domain
+            .inner
+            .advance(Box::new(vec![Event::Exit { reason: None }]));
+
+        assert_eq!(domain.state(), DomainState::Detached);
+        assert!(!domain.detachable());
+        assert!(!domain.spawnable());
+        assert!(domain.detach().is_err());
+        assert!(queued_commands(&domain).is_empty());
+        assert!(is_released(&pane));
+        assert!(promise::spawn::block_on(future).is_err());
+        assert!(domain.inner.create_tmux_window(None, None).is_err());
     }
 }
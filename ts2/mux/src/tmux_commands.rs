@@ -1,14 +1,17 @@
 use crate::domain::{DomainId, WriterWrapper};
 use crate::localpane::LocalPane;
 use crate::pane::{alloc_pane_id, PaneId};
-use crate::tab::{SplitDirection, SplitRequest, SplitSize, Tab, TabId};
+use crate::tab::{
+    PaneEntry, PaneNode, SplitDirection, SplitDirectionAndSize, SplitRequest, Tab, TabId,
+};
 use crate::tmux::{AttachState, TmuxDomain, TmuxDomainState, TmuxRemotePane, TmuxTab};
 use crate::tmux_pty::{TmuxChild, TmuxPty};
+use crate::window::WindowId;
 use crate::{Mux, MuxNotification, Pane};
 use anyhow::{anyhow, Context};
 use parking_lot::{Condvar, Mutex};
 use portable_pty::{MasterPty, PtySize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Write};
 use std::io::Write as _;
 use std::sync::Arc;
@@ -45,7 +48,7 @@ struct WindowItem {
     window_height: u64,
     window_active: bool,
     window_name: String,
-    layout: Vec<WindowLayout>,
+    layout: LayoutNode,
     layout_csum: String,
     history_limit: isize,
 }
@@ -375,6 +378,12 @@ impl TmuxDomainState {
                 continue;
             }
 
+            // The %window-add for a window that we spawned can race
+            // with the listing of all windows
+            if self.check_window_attached(window.window_id) {
+                continue;
+            }
+
             let size = TerminalSize {
                 rows: window.window_height as usize,
                 cols: window.window_width as usize,
@@ -388,112 +397,13 @@ impl TmuxDomainState {
             mux.add_tab_no_panes(&tab);
 
             let _ = self.add_attached_window(window, &tab.tab_id())?;
-
-            let mut split_stack;
-            let mut split_direction;
-
-            let mut split_pane_index = 1;
-            for l in &window.layout {
-                match l {
-                    WindowLayout::SinglePane(x) => {
-                        let p = PaneItem {
-                            session_id: window.session_id,
-                            window_id: window.window_id,
-                            _pane_index: 0,
-                            cursor_x: 0,
-                            cursor_y: 0,
-                            pane_active: false,
-                            pane_id: x.pane_id,
-                            pane_width: x.pane_width,
-                            pane_height: x.pane_height,
-                            pane_left: x.pane_left,
-                            pane_top: x.pane_top,
-                        };
-                        let local_pane = self.create_pane(&p).context("failed to create pane")?;
-                        tab.assign_pane(&local_pane);
-                        self.add_attached_pane(p.window_id, p.pane_id)?;
-                        let _ = mux.add_pane(&local_pane);
-                        break;
-                    }
-
-                    WindowLayout::SplitHorizontal(x) => {
-                        split_direction = SplitDirection::Horizontal;
-                        split_stack = x;
-                    }
-
-                    WindowLayout::SplitVertical(x) => {
-                        split_direction = SplitDirection::Vertical;
-                        split_stack = x;
-                    }
-                }
-
-                for x in split_stack {
-                    let p = PaneItem {
-                        session_id: window.session_id,
-                        window_id: window.window_id,
-                        _pane_index: 0,
-                        cursor_x: 0,
-                        cursor_y: 0,
-                        pane_active: false,
-                        pane_id: x.pane_id,
-                        pane_width: x.pane_width,
-                        pane_height: x.pane_height,
-                        pane_left: x.pane_left,
-                        pane_top: x.pane_top,
-                    };
-                    let local_pane;
-                    if !self.check_pane_attached(p.window_id, p.pane_id) {
-                        local_pane = self.create_pane(&p).context("failed to create pane")?;
-                        self.add_attached_pane(p.window_id, p.pane_id)?;
-                        let _ = mux.add_pane(&local_pane);
-                        if let None = tab.get_active_pane() {
-                            tab.assign_pane(&local_pane);
-                            split_pane_index = tab.get_active_idx();
-                            continue;
-                        }
-
-                        split_pane_index = tab.split_and_insert(
-                            split_pane_index,
-                            SplitRequest {
-                                direction: split_direction,
-                                target_is_second: false,
-                                top_level: false,
-                                size: SplitSize::Cells(
-                                    if split_direction == SplitDirection::Horizontal {
-                                        p.pane_width as usize
-                                    } else {
-                                        p.pane_height as usize
-                                    },
-                                ),
-                            },
-                            local_pane.clone(),
-                        )? + 1;
-                    } else {
-                        let pane_map = self.remote_panes.lock();
-                        let local_pane_id = match pane_map.get(&p.pane_id) {
-                            Some(x) => x.lock().local_pane_id,
-                            None => anyhow::bail!("cannot find the local pane for {}", p.pane_id),
-                        };
-
-                        split_pane_index = match tab
-                            .iter_panes_ignoring_zoom()
-                            .iter()
-                            .find(|x| x.pane.pane_id() == local_pane_id)
-                        {
-                            Some(x) => x.index,
-                            None => {
-                                log::info!("invalid pane id {local_pane_id}");
-                                continue;
-                            }
-                        };
-                        continue;
-                    }
-                }
-            }
+            self.apply_layout(&tab, **gui_window_id, window.window_id, &window.layout)?;
 
             mux.add_tab_to_window(&tab, **gui_window_id)?;
             gui_window_id.notify();
 
+            self.window_attached(window.window_id, tab.tab_id());
+
             let gui_tabs = self.gui_tabs.lock();
             let local_tab = match gui_tabs.get(&window.window_id) {
                 Some(x) => x,
@@ -542,11 +452,158 @@ impl TmuxDomainState {
             self.cmd_queue.lock().push_back(Box::new(AttachDone));
         }
 
+        // Now that the windows of a session that we switched to are
+        // attached, the window can safely lose the old ones
+        self.remove_stale_session();
+
         TmuxDomainState::schedule_send_next_command(self.domain_id);
 
         Ok(())
     }
 
+    /// Make the splits of `tab` match a tmux window layout, creating
+    /// panes for tmux panes that we haven't seen before and removing
+    /// the panes that are no longer part of the window
+    fn apply_layout(
+        &self,
+        tab: &Arc<Tab>,
+        window_id: WindowId,
+        tmux_window_id: TmuxWindowId,
+        layout: &LayoutNode,
+    ) -> anyhow::Result<()> {
+        let mux = Mux::get();
+        let mut panes: HashMap<TmuxPaneId, Arc<dyn Pane>> = HashMap::new();
+        let mut pane_set = HashSet::new();
+
+        for item in layout.panes() {
+            let remote_pane = self.remote_panes.lock().get(&item.pane_id).cloned();
+            let local_pane = match remote_pane {
+                Some(remote_pane) => {
+                    // Record the size that tmux gave the pane so that
+                    // resizing the local pane doesn't send it back to tmux
+                    let mut remote_pane = remote_pane.lock();
+                    remote_pane.window_id = tmux_window_id;
+                    remote_pane.pane_width = item.pane_width;
+                    remote_pane.pane_height = item.pane_height;
+                    remote_pane.pane_left = item.pane_left;
+                    remote_pane.pane_top = item.pane_top;
+                    mux.get_pane(remote_pane.local_pane_id).ok_or_else(|| {
+                        anyhow!("the local pane for tmux pane {} is gone", item.pane_id)
+                    })?
+                }
+                None => {
+                    let p = PaneItem {
+                        session_id: 0,
+                        window_id: tmux_window_id,
+                        pane_id: item.pane_id,
+                        _pane_index: 0,
+                        cursor_x: 0,
+                        cursor_y: 0,
+                        pane_width: item.pane_width,
+                        pane_height: item.pane_height,
+                        pane_left: item.pane_left,
+                        pane_top: item.pane_top,
+                        pane_active: false,
+                    };
+                    let local_pane = self.create_pane(&p).context("failed to create pane")?;
+                    let _ = mux.add_pane(&local_pane);
+                    local_pane
+                }
+            };
+            if !self.check_pane_attached(tmux_window_id, item.pane_id) {
+                self.add_attached_pane(tmux_window_id, item.pane_id)?;
+            }
+            pane_set.insert(item.pane_id);
+            panes.insert(item.pane_id, local_pane);
+        }
+
+        // Keep the active pane, if it survived the change
+        let active_pane_id = tab
+            .get_active_pane()
+            .map(|pane| pane.pane_id())
+            .filter(|id| panes.values().any(|pane| pane.pane_id() == *id))
+            .or_else(|| {
+                let first = layout.panes().first()?.pane_id;
+                panes.get(&first).map(|pane| pane.pane_id())
+            });
+
+        let tab_size = tab.get_size();
+        let workspace = mux.active_workspace();
+        let tree = layout_to_pane_node(layout, &tab_size, &mut |item, size| {
+            let pane = &panes[&item.pane_id];
+            let dims = pane.get_dimensions();
+            PaneNode::Leaf(PaneEntry {
+                window_id,
+                tab_id: tab.tab_id(),
+                pane_id: pane.pane_id(),
+                title: pane.get_title(),
+                size,
+                working_dir: None,
+                is_active_pane: Some(pane.pane_id()) == active_pane_id,
+                is_zoomed_pane: false,
+                workspace: workspace.clone(),
+                cursor_pos: pane.get_cursor_position(),
+                physical_top: dims.physical_top,
+                top_row: item.pane_top as usize,
+                left_col: item.pane_left as usize,
+                tty_name: None,
+            })
+        });
+
+        let mut local_panes: HashMap<PaneId, Arc<dyn Pane>> = panes
+            .into_values()
+            .map(|pane| (pane.pane_id(), pane))
+            .collect();
+        tab.sync_with_pane_tree(layout_size(layout.cell(), &tab_size), tree, |entry| {
+            local_panes
+                .remove(&entry.pane_id)
+                .expect("every pane in the tree to come from the layout")
+        });
+
+        self.remove_detached_pane(tmux_window_id, &pane_set)
+    }
+
+    /// Apply the layout from a %layout-change notification
+    pub fn sync_layout(&self, tmux_window_id: TmuxWindowId, layout: &str) -> anyhow::Result<()> {
+        let layout_csum = layout
+            .get(0..4)
+            .ok_or_else(|| anyhow!("missing window_layout"))?;
+        let tab_id = {
+            let gui_tabs = self.gui_tabs.lock();
+            // A window that we haven't attached yet gets its layout
+            // from ListAllWindows
+            let Some(local_tab) = gui_tabs.get(&tmux_window_id) else {
+                return Ok(());
+            };
+            if local_tab.layout_csum == layout_csum {
+                return Ok(());
+            }
+            local_tab.tab_id
+        };
+
+        let layout = parse_layout_tree(
+            layout
+                .get(5..)
+                .ok_or_else(|| anyhow!("missing window_layout"))?,
+        )?;
+
+        let mux = Mux::get();
+        let tab = mux
+            .get_tab(tab_id)
+            .ok_or_else(|| anyhow!("invalid tab id {}", tab_id))?;
+        let window_id = mux
+            .window_containing_tab(tab_id)
+            .ok_or_else(|| anyhow!("no window contains tab {}", tab_id))?;
+        self.apply_layout(&tab, window_id, tmux_window_id, &layout)?;
+
+        // Only remember the layout once it is applied, so that a
+        // failed change is retried when tmux sends it again
+        if let Some(local_tab) = self.gui_tabs.lock().get_mut(&tmux_window_id) {
+            local_tab.layout_csum = layout_csum.to_string();
+        }
+        Ok(())
+    }
+
     pub fn subscribe_notification(&self) {
         let mux = Mux::get();
         let domain_id = self.domain_id;
@@ -622,6 +679,96 @@ impl TmuxDomainState {
     }
 }
 
+/// The size of a tmux layout cell, with pixel dimensions derived
+/// from the cell size of the tab
+fn layout_size(cell: &PaneLayout, tab_size: &TerminalSize) -> TerminalSize {
+    let cell_width = tab_size.pixel_width / tab_size.cols.max(1);
+    let cell_height = tab_size.pixel_height / tab_size.rows.max(1);
+    TerminalSize {
+        rows: cell.pane_height as usize,
+        cols: cell.pane_width as usize,
+        pixel_width: cell.pane_width as usize * cell_width,
+        pixel_height: cell.pane_height as usize * cell_height,
+        dpi: tab_size.dpi,
+    }
+}
+
+/// Converts a tmux layout into the binary tree used by `Tab`.  A tmux
+/// split can have any number of children, so it becomes a split
+/// between its first child and a split of the rest.
+fn layout_to_pane_node<F>(node: &LayoutNode, tab_size: &TerminalSize, make_leaf: &mut F) -> PaneNode
+where
+    F: FnMut(&PaneLayout, TerminalSize) -> PaneNode,
+{
+    match node {
+        LayoutNode::Pane(pane) => make_leaf(pane, layout_size(pane, tab_size)),
+        LayoutNode::SplitHorizontal { children, .. } => {
+            split_to_pane_node(SplitDirection::Horizontal, children, tab_size, make_leaf)
+        }
+        LayoutNode::SplitVertical { children, .. } => {
+            split_to_pane_node(SplitDirection::Vertical, children, tab_size, make_leaf)
+        }
+    }
+}
+
+fn split_to_pane_node<F>(
+    direction: SplitDirection,
+    children: &[LayoutNode],
+    tab_size: &TerminalSize,
+    make_leaf: &mut F,
+) -> PaneNode
+where
+    F: FnMut(&PaneLayout, TerminalSize) -> PaneNode,
+{
+    match children {
+        [] => PaneNode::Empty,
+        [only] => layout_to_pane_node(only, tab_size, make_leaf),
+        [first, rest @ ..] => {
+            // The rest span from the start of the second child to
+            // the end of the last one
+            let start = rest[0].cell();
+            let end = rest[rest.len() - 1].cell();
+            let second = match direction {
+                SplitDirection::Horizontal => PaneLayout {
+                    pane_width: end.pane_left + end.pane_width - start.pane_left,
+                    ..*start
+                },
+                SplitDirection::Vertical => PaneLayout {
+                    pane_height: end.pane_top + end.pane_height - start.pane_top,
+                    ..*start
+                },
+            };
+            PaneNode::Split {
+                left: Box::new(layout_to_pane_node(first, tab_size, make_leaf)),
+                right: Box::new(split_to_pane_node(direction, rest, tab_size, make_leaf)),
+                node: SplitDirectionAndSize {
+                    direction,
+                    first: layout_size(first.cell(), tab_size),
+                    second: layout_size(&second, tab_size),
+                },
+            }
+        }
+    }
+}
+
+/// Quotes `arg` as a single argument to a tmux command.  Control
+/// mode reads one command per line, so an argument that contains a
+/// line break cannot be sent and is rejected.
+pub(crate) fn quote_argument(arg: &str) -> anyhow::Result<String> {
+    if arg.contains(|c| matches!(c, '\n' | '\r')) {
+        anyhow::bail!("tmux arguments cannot contain line breaks: {arg:?}");
+    }
+    let mut quoted = String::from("\"");
+    for c in arg.chars() {
+        if matches!(c, '"' | '\\' | '$') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    Ok(quoted)
+}
+
 fn parse_sigil_number(text: &str) -> anyhow::Result<u64> {
     let num = text
         .get(1..)
@@ -840,7 +987,7 @@ impl TmuxCommand for ListAllWindows {
                 .get(5..)
                 .ok_or_else(|| anyhow!("missing window_layout"))?;
 
-            let layout = parse_layout(window_layout)?;
+            let layout = parse_layout_tree(window_layout)?;
 
             items.push(WindowItem {
                 session_id,
@@ -1038,19 +1185,47 @@ impl TmuxCommand for SendKeys {
 }
 
 #[derive(Debug)]
-pub(crate) struct NewWindow;
+pub(crate) struct NewWindow {
+    pub session_id: TmuxSessionId,
+    /// The command and directory are quoted by `quote_argument`
+    pub command: Option<String>,
+    pub command_dir: Option<String>,
+}
+
 impl TmuxCommand for NewWindow {
     fn get_command(&self, _domain_id: DomainId) -> String {
-        "new-window\n".to_owned()
+        let mut cmd = format!("new-window -t ${} -P -F '#{{window_id}}'", self.session_id);
+        if let Some(dir) = &self.command_dir {
+            write!(&mut cmd, " -c {}", dir).expect("unable to write dir");
+        }
+        if let Some(command) = &self.command {
+            write!(&mut cmd, " {}", command).expect("unable to write command");
+        }
+        cmd.push('\n');
+        cmd
     }
 
     fn process_result(&self, domain_id: DomainId, result: &Guarded) -> anyhow::Result<()> {
-        if result.error {
-            let error = format!("new-window in domain={domain_id} failed: {result:#?}");
-            log::error!("{error}");
-            anyhow::bail!("{error}");
+        let mux = Mux::get();
+        let domain = match mux.get_domain(domain_id) {
+            Some(d) => d,
+            None => anyhow::bail!("Tmux domain lost"),
+        };
+        let tmux_domain = match domain.downcast_ref::<TmuxDomain>() {
+            Some(t) => t,
+            None => anyhow::bail!("Tmux domain lost"),
+        };
+        let window_id = if result.error {
+            Err(anyhow!(
+                "new-window in domain={domain_id} failed: {result:#?}"
+            ))
+        } else {
+            parse_sigil_number(result.output.trim())
+        };
+        if let Err(err) = &window_id {
+            log::error!("{err:#}");
         }
-        Ok(())
+        tmux_domain.inner.new_window_created(window_id)
     }
 }
 
@@ -1104,24 +1279,60 @@ impl TmuxCommand for ListCommands {
 pub(crate) struct SplitPane {
     pub pane_id: TmuxPaneId,
     pub direction: SplitDirection,
+    /// The command and directory are quoted by `quote_argument`
+    pub command: Option<String>,
+    pub command_dir: Option<String>,
 }
 
 impl TmuxCommand for SplitPane {
     fn get_command(&self, _domain_id: DomainId) -> String {
-        if self.direction == SplitDirection::Horizontal {
-            format!("split-window -h -t %{}\n", self.pane_id)
+        let mut cmd = if self.direction == SplitDirection::Horizontal {
+            format!("split-window -h -t %{} -P -F '#{{pane_id}}'", self.pane_id)
         } else {
-            format!("split-window -v -t %{}\n", self.pane_id)
+            format!("split-window -v -t %{} -P -F '#{{pane_id}}'", self.pane_id)
+        };
+        if let Some(dir) = &self.command_dir {
+            write!(&mut cmd, " -c {}", dir).expect("unable to write dir");
+        }
+        if let Some(command) = &self.command {
+            write!(&mut cmd, " {}", command).expect("unable to write command");
         }
+        cmd.push('\n');
+        cmd
     }
 
     fn process_result(&self, domain_id: DomainId, result: &Guarded) -> anyhow::Result<()> {
-        if result.error {
-            let error = format!("split-window in domain={domain_id} failed: {result:#?}");
-            log::error!("{error}");
-            anyhow::bail!("{error}");
+        let mux = Mux::get();
+        let domain = match mux.get_domain(domain_id) {
+            Some(d) => d,
+            None => anyhow::bail!("Tmux domain lost"),
+        };
+        let tmux_domain = match domain.downcast_ref::<TmuxDomain>() {
+            Some(t) => t,
+            None => anyhow::bail!("Tmux domain lost"),
+        };
+        let Some(mut promise) = tmux_domain.inner.pending_splits.lock().pop_front() else {
+            anyhow::bail!("split-window in domain={domain_id} has no pending split");
+        };
+
+        let pane_id = if result.error {
+            Err(anyhow!(
+                "split-window in domain={domain_id} failed: {result:#?}"
+            ))
+        } else {
+            parse_sigil_number(result.output.trim())
+        };
+        match pane_id {
+            Ok(pane_id) => {
+                promise.ok(pane_id);
+                Ok(())
+            }
+            Err(err) => {
+                log::error!("{err:#}");
+                promise.err(anyhow!("{err:#}"));
+                Err(err)
+            }
         }
-        Ok(())
     }
 }
 
@@ -1165,6 +1376,84 @@ impl TmuxCommand for SelectPane {
     }
 }
 
+#[derive(Debug)]
+pub(crate) struct DetachClient;
+impl TmuxCommand for DetachClient {
+    fn get_command(&self, _domain_id: DomainId) -> String {
+        "detach-client\n".to_owned()
+    }
+
+    fn process_result(&self, domain_id: DomainId, result: &Guarded) -> anyhow::Result<()> {
+        if result.error {
+            let error = format!("detach-client in domain={domain_id} failed: {result:#?}");
+            log::error!("{error}");
+            anyhow::bail!("{error}");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ListSessions;
+impl TmuxCommand for ListSessions {
+    fn get_command(&self, _domain_id: DomainId) -> String {
+        "list-sessions -F '#{session_id} #{session_name}'\n".to_owned()
+    }
+
+    fn process_result(&self, domain_id: DomainId, result: &Guarded) -> anyhow::Result<()> {
+        if result.error {
+            let error = format!("list-sessions in domain={domain_id} failed: {result:#?}");
+            log::error!("{error}");
+            anyhow::bail!("{error}");
+        }
+        let mut sessions = vec![];
+        for line in result.output.split('\n') {
+            if line.is_empty() {
+                continue;
+            }
+            let mut fields = line.splitn(2, ' ');
+            let session_id =
+                parse_sigil_number(fields.next().ok_or_else(|| anyhow!("missing session_id"))?)?;
+            let session_name = fields
+                .next()
+                .ok_or_else(|| anyhow!("missing session_name"))?;
+            sessions.push((session_id, session_name.to_string()));
+        }
+
+        let mux = Mux::get();
+        let domain = match mux.get_domain(domain_id) {
+            Some(d) => d,
+            None => anyhow::bail!("Tmux domain lost"),
+        };
+        let tmux_domain = match domain.downcast_ref::<TmuxDomain>() {
+            Some(t) => t,
+            None => anyhow::bail!("Tmux domain lost"),
+        };
+        *tmux_domain.inner.sessions.lock() = sessions;
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SwitchClient {
+    pub session_id: TmuxSessionId,
+}
+
+impl TmuxCommand for SwitchClient {
+    fn get_command(&self, _domain_id: DomainId) -> String {
+        format!("switch-client -t ${}\n", self.session_id)
+    }
+
+    fn process_result(&self, domain_id: DomainId, result: &Guarded) -> anyhow::Result<()> {
+        if result.error {
+            let error = format!("switch-client in domain={domain_id} failed: {result:#?}");
+            log::error!("{error}");
+            anyhow::bail!("{error}");
+        }
+        Ok(())
+    }
+}
+
 // This is a dummy command which indicates the attaching is done, it prevents the tmux output
 // the unexpected and unnecessary content when syncing with back end in attaching stage.
 #[derive(Debug)]
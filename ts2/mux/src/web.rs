@@ -0,0 +1,623 @@
+//! State for browser panes that is shared between the GUI, which owns
+//! the CEF browsers, and the mux server, which answers cli requests
+//! about them.
+use crate::pane::PaneId;
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+/// The number of completed requests retained per browser pane
+const MAX_NETWORK_ENTRIES: usize = 2000;
+
+/// Bodies larger than this are truncated when captured
+pub const MAX_NETWORK_BODY_SIZE: usize = 1024 * 1024;
+
+/// How long body capture stays enabled after the log was last
+/// polled with capture requested.  The cli polls every 500ms, so
+/// this only lapses once it has stopped following the log.
+const CAPTURE_BODIES_LEASE: Duration = Duration::from_secs(5);
+
+/// A NetworkLog that can be updated from the CEF IO thread
+pub type SharedNetworkLog = Arc<Mutex<NetworkLog>>;
+
+/// The captured body of a request or response
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct NetworkBody {
+    /// The body text; if `base64` is true this is the base64
+    /// encoding of the original bytes
+    pub text: String,
+    pub base64: bool,
+    /// True if the body exceeded MAX_NETWORK_BODY_SIZE
+    pub truncated: bool,
+}
+
+impl NetworkBody {
+    pub fn from_bytes(data: &[u8]) -> Self {
+        let truncated = data.len() > MAX_NETWORK_BODY_SIZE;
+        let data = &data[..data.len().min(MAX_NETWORK_BODY_SIZE)];
+        match std::str::from_utf8(data) {
+            Ok(text) => Self {
+                text: text.to_string(),
+                base64: false,
+                truncated,
+            },
+            Err(_) => {
+                use base64::Engine;
+                Self {
+                    text: base64::engine::general_purpose::STANDARD.encode(data),
+                    base64: true,
+                    truncated,
+                }
+            }
+        }
+    }
+}
+
+/// A single request made by a browser pane
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug, Default)]
+pub struct NetworkEntry {
+    /// Monotonically increasing sequence number within the pane
+    pub seq: u64,
+    /// When the request started, in milliseconds since the unix epoch
+    pub started_ms: u64,
+    pub method: String,
+    pub url: String,
+    /// The CEF resource type, eg: "MainFrame", "Script", "Image"
+    pub resource_type: String,
+    /// The HTTP status, or 0 if no response was received
+    pub status: i32,
+    pub status_text: String,
+    pub mime_type: String,
+    /// Total time from the request being issued until it completed
+    pub duration_ms: f64,
+    /// Number of bytes received, or -1 if unknown
+    pub size: i64,
+    /// Set if the request failed or was canceled
+    pub error: Option<String>,
+    pub request_headers: Vec<(String, String)>,
+    pub response_headers: Vec<(String, String)>,
+    pub request_body: Option<NetworkBody>,
+    pub response_body: Option<NetworkBody>,
+}
+
+struct PendingRequest {
+    start: Instant,
+    entry: NetworkEntry,
+    body: Vec<u8>,
+}
+
+/// Records the requests made by a single browser pane.
+/// The browser side populates this from the CEF IO thread; the mux
+/// server drains it in response to `wezterm cli web-network`.
+#[derive(Default)]
+pub struct NetworkLog {
+    entries: VecDeque<NetworkEntry>,
+    pending: HashMap<u64, PendingRequest>,
+    next_seq: u64,
+    /// Bodies are captured until this time
+    capture_bodies_until: Option<Instant>,
+}
+
+impl NetworkLog {
+    /// Returns true if request and response bodies should be recorded
+    pub fn capture_bodies(&self) -> bool {
+        self.capture_bodies_until
+            .map_or(false, |until| Instant::now() < until)
+    }
+
+    /// Enables or disables body capture.  Enabling it only lasts for
+    /// CAPTURE_BODIES_LEASE, so the poller must keep renewing it;
+    /// that way capture stops when nothing is reading the bodies.
+    pub fn set_capture_bodies(&mut self, capture: bool) {
+        self.capture_bodies_until = capture.then(|| Instant::now() + CAPTURE_BODIES_LEASE);
+    }
+
+    /// Record the start of a request; `id` is the identifier
+    /// assigned to the request by the browser
+    pub fn begin_request(&mut self, id: u64, mut entry: NetworkEntry) {
+        entry.started_ms = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        entry.size = -1;
+        if !self.capture_bodies() {
+            entry.request_body.take();
+        }
+        self.pending.insert(
+            id,
+            PendingRequest {
+                start: Instant::now(),
+                entry,
+                body: vec![],
+            },
+        );
+    }
+
+    /// Record the response headers for a request
+    pub fn set_response(
+        &mut self,
+        id: u64,
+        status: i32,
+        status_text: String,
+        mime_type: String,
+        headers: Vec<(String, String)>,
+    ) {
+        if let Some(pending) = self.pending.get_mut(&id) {
+            pending.entry.status = status;
+            pending.entry.status_text = status_text;
+            pending.entry.mime_type = mime_type;
+            pending.entry.response_headers = headers;
+        }
+    }
+
+    /// Accumulate response body data for a request
+    pub fn append_response_body(&mut self, id: u64, data: &[u8]) {
+        if let Some(pending) = self.pending.get_mut(&id) {
+            // Keep one byte more than the limit so that we can tell
+            // that the body was truncated
+            let remain = (MAX_NETWORK_BODY_SIZE + 1).saturating_sub(pending.body.len());
+            pending
+                .body
+                .extend_from_slice(&data[..data.len().min(remain)]);
+        }
+    }
+
+    /// Record the completion of a request, moving it from the
+    /// pending set into the log
+    pub fn complete_request(&mut self, id: u64, size: i64, error: Option<String>) {
+        let Some(mut pending) = self.pending.remove(&id) else {
+            return;
+        };
+        pending.entry.seq = self.next_seq;
+        self.next_seq += 1;
+        pending.entry.duration_ms = pending.start.elapsed().as_secs_f64() * 1000.;
+        pending.entry.size = size;
+        pending.entry.error = error;
+        if self.capture_bodies() && !pending.body.is_empty() {
+            pending.entry.response_body = Some(NetworkBody::from_bytes(&pending.body));
+        }
+
+        if self.entries.len() == MAX_NETWORK_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(pending.entry);
+    }
+
+    /// Returns the completed entries with a sequence number of at
+    /// least `since`, along with the sequence number that should be
+    /// passed to the next call to pick up where this one left off
+    pub fn entries_since(&self, since: u64) -> (Vec<NetworkEntry>, u64) {
+        let entries = self
+            .entries
+            .iter()
+            .filter(|entry| entry.seq >= since)
+            .cloned()
+            .collect();
+        (entries, self.next_seq)
+    }
+}
+
+/// The profile used by browser panes that were opened without
+/// specifying one
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// What to do with a request that matches a RouteRule
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub enum RouteAction {
+    /// Let the request proceed, after adding any headers
+    Continue,
+    /// Cancel the request
+    Block,
+    /// Load the specified URL instead
+    Redirect(String),
+    /// Respond with the contents of a local file without
+    /// touching the network
+    Fulfill(RouteResponse),
+}
+
+/// A canned response used by RouteAction::Fulfill
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct RouteResponse {
+    pub status: i32,
+    /// If empty, the mime type is guessed from the file extension
+    pub mime_type: String,
+    pub headers: Vec<(String, String)>,
+    /// Absolute path to the file holding the response body
+    pub file: PathBuf,
+}
+
+/// Rewrites or stubs requests whose URL matches `pattern`.
+/// The pattern is matched against the full URL; `*` matches any
+/// sequence of characters and all other characters match literally.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct RouteRule {
+    pub pattern: String,
+    pub action: RouteAction,
+    /// Request headers to add or replace before the request is sent
+    pub add_headers: Vec<(String, String)>,
+}
+
+impl RouteRule {
+    pub fn matches(&self, url: &str) -> bool {
+        wildcard_match(&self.pattern, url)
+    }
+}
+
+/// The outcome of matching a URL against the route rules
+#[derive(Clone, PartialEq, Debug)]
+pub struct RouteDecision {
+    pub action: RouteAction,
+    pub add_headers: Vec<(String, String)>,
+}
+
+/// Identifies the browser panes that a set of rules applies to
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum RouteScope {
+    Pane(PaneId),
+    /// All browser panes opened with the named profile
+    Profile(String),
+}
+
+/// Holds the request routing rules for all browser panes.
+/// Rules are consulted from the CEF IO thread for every request,
+/// and replaced by the mux server in response to `wezterm cli web-route`.
+#[derive(Default)]
+pub struct RouteRegistry {
+    rules: RwLock<HashMap<RouteScope, Arc<Vec<RouteRule>>>>,
+}
+
+impl RouteRegistry {
+    /// Returns the rules set for a scope
+    pub fn get(&self, scope: &RouteScope) -> Vec<RouteRule> {
+        self.rules
+            .read()
+            .get(scope)
+            .map(|rules| rules.to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Replace the rules for a scope; an empty set of rules
+    /// removes the scope
+    pub fn set(&self, scope: RouteScope, rules: Vec<RouteRule>) {
+        let mut map = self.rules.write();
+        if rules.is_empty() {
+            map.remove(&scope);
+        } else {
+            map.insert(scope, Arc::new(rules));
+        }
+    }
+
+    /// Match a request URL made by a pane against its rules.
+    /// Rules set for the pane are considered before those set for
+    /// its profile. Headers are accumulated from every matching rule,
+    /// while the action is taken from the first matching rule whose
+    /// action is not Continue.
+    /// Returns None if no rule matched.
+    pub fn resolve(&self, pane_id: PaneId, profile: &str, url: &str) -> Option<RouteDecision> {
+        let (pane_rules, profile_rules) = {
+            let map = self.rules.read();
+            (
+                map.get(&RouteScope::Pane(pane_id)).cloned(),
+                map.get(&RouteScope::Profile(profile.to_string())).cloned(),
+            )
+        };
+
+        let mut decision: Option<RouteDecision> = None;
+        for rule in pane_rules
+            .iter()
+            .chain(profile_rules.iter())
+            .flat_map(|rules| rules.iter())
+            .filter(|rule| rule.matches(url))
+        {
+            let decision = decision.get_or_insert_with(|| RouteDecision {
+                action: RouteAction::Continue,
+                add_headers: vec![],
+            });
+            decision
+                .add_headers
+                .extend(rule.add_headers.iter().cloned());
+            if rule.action != RouteAction::Continue {
+                decision.action = rule.action.clone();
+                break;
+            }
+        }
+        decision
+    }
+}
+
+/// ContentBlocking state that can be updated from the CEF IO thread
+pub type SharedContentBlocking = Arc<ContentBlocking>;
+
+/// The content blocking state of a single browser pane.
+/// Requests are counted from the CEF IO thread; the mux server toggles
+/// blocking and reports the counts for `wezterm cli web-block`.
+#[derive(Debug)]
+pub struct ContentBlocking {
+    enabled: AtomicBool,
+    /// Requests blocked since the current page started loading
+    blocked: AtomicU64,
+    /// Requests blocked since the browser was opened
+    total_blocked: AtomicU64,
+}
+
+impl ContentBlocking {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled: AtomicBool::new(enabled),
+            blocked: AtomicU64::new(0),
+            total_blocked: AtomicU64::new(0),
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Returns the number of requests blocked on the current page
+    pub fn blocked(&self) -> u64 {
+        self.blocked.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of requests blocked by this browser
+    pub fn total_blocked(&self) -> u64 {
+        self.total_blocked.load(Ordering::Relaxed)
+    }
+
+    pub fn record_blocked(&self) {
+        self.blocked.fetch_add(1, Ordering::Relaxed);
+        self.total_blocked.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Called when the main frame navigates to a new page
+    pub fn reset_page(&self) {
+        self.blocked.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Match `text` against `pattern`, where `*` in the pattern matches
+/// any sequence of characters, including none
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let text = text.as_bytes();
+    let (mut p, mut t) = (0, 0);
+    // Position of the most recent `*` in the pattern, and the
+    // position in the text that it is currently assumed to extend to
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == text[t] {
+            p += 1;
+            t += 1;
+        } else if let Some((star, star_t)) = backtrack {
+            // Let the star consume one more character and retry
+            p = star + 1;
+            t = star_t + 1;
+            backtrack = Some((star, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn request(url: &str) -> NetworkEntry {
+        NetworkEntry {
+            method: "GET".to_string(),
+            url: url.to_string(),
+            request_body: Some(NetworkBody::from_bytes(b"hello")),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn network_log_sequence() {
+        let mut log = NetworkLog::default();
+        log.begin_request(10, request("https://a.example/"));
+        log.begin_request(11, request("https://b.example/"));
+        log.set_response(
+            11,
+            404,
+            "Not Found".to_string(),
+            "text/html".to_string(),
+            vec![],
+        );
+        log.append_response_body(11, b"nope");
+        log.complete_request(11, 4, None);
+
+        let (entries, next) = log.entries_since(0);
+        assert_eq!(next, 1);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].url, "https://b.example/");
+        assert_eq!(entries[0].status, 404);
+        assert_eq!(entries[0].size, 4);
+        // Bodies are not captured unless requested
+        assert_eq!(entries[0].request_body, None);
+        assert_eq!(entries[0].response_body, None);
+
+        log.set_capture_bodies(true);
+        log.begin_request(12, request("https://c.example/"));
+        log.append_response_body(12, &[0xff, 0xfe]);
+        log.complete_request(12, 2, None);
+        log.complete_request(10, -1, Some("ERR_ABORTED".to_string()));
+
+        let (entries, next) = log.entries_since(next);
+        assert_eq!(next, 3);
+        assert_eq!(
+            entries.iter().map(|e| e.seq).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            entries[0].response_body,
+            Some(NetworkBody {
+                text: "//4=".to_string(),
+                base64: true,
+                truncated: false,
+            })
+        );
+        assert_eq!(entries[1].error.as_deref(), Some("ERR_ABORTED"));
+    }
+
+    #[test]
+    fn network_capture_lease() {
+        let mut log = NetworkLog::default();
+        assert!(!log.capture_bodies());
+
+        log.set_capture_bodies(true);
+        assert!(log.capture_bodies());
+
+        // Capture lapses if the log is not polled again
+        log.capture_bodies_until = Some(Instant::now() - Duration::from_millis(1));
+        assert!(!log.capture_bodies());
+        log.begin_request(1, request("https://a.example/"));
+        log.append_response_body(1, b"body");
+        log.complete_request(1, 4, None);
+        let (entries, _) = log.entries_since(0);
+        assert_eq!(entries[0].request_body, None);
+        assert_eq!(entries[0].response_body, None);
+
+        log.set_capture_bodies(true);
+        log.set_capture_bodies(false);
+        assert!(!log.capture_bodies());
+    }
+
+    #[test]
+    fn network_body_truncation() {
+        let data = vec![b'a'; MAX_NETWORK_BODY_SIZE + 10];
+        let body = NetworkBody::from_bytes(&data);
+        assert!(body.truncated);
+        assert!(!body.base64);
+        assert_eq!(body.text.len(), MAX_NETWORK_BODY_SIZE);
+    }
+
+    fn rule(pattern: &str, action: RouteAction, headers: &[(&str, &str)]) -> RouteRule {
+        RouteRule {
+            pattern: pattern.to_string(),
+            action,
+            add_headers: headers
+                .iter()
+                .map(|(n, v)| (n.to_string(), v.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn route_wildcards() {
+        assert!(wildcard_match(
+            "https://example.com/",
+            "https://example.com/"
+        ));
+        assert!(!wildcard_match(
+            "https://example.com/",
+            "https://example.com/a"
+        ));
+        assert!(wildcard_match("*", ""));
+        assert!(wildcard_match(
+            "https://*.example.com/*",
+            "https://api.example.com/v1?q=1"
+        ));
+        assert!(!wildcard_match(
+            "https://*.example.com/*",
+            "https://example.com/v1"
+        ));
+        assert!(wildcard_match(
+            "*/api/*.json",
+            "http://localhost/api/a/b.json"
+        ));
+        assert!(!wildcard_match(
+            "*/api/*.json",
+            "http://localhost/api/a/b.json5"
+        ));
+        assert!(wildcard_match("*aab", "aaaab"));
+    }
+
+    #[test]
+    fn route_resolution() {
+        let routes = RouteRegistry::default();
+        routes.set(
+            RouteScope::Profile(DEFAULT_PROFILE.to_string()),
+            vec![
+                rule(
+                    "https://example.com/*",
+                    RouteAction::Continue,
+                    &[("X-Profile", "1")],
+                ),
+                rule("*/ads/*", RouteAction::Block, &[]),
+            ],
+        );
+        routes.set(
+            RouteScope::Pane(2),
+            vec![rule(
+                "https://example.com/ads/*",
+                RouteAction::Redirect("http://localhost/ad".to_string()),
+                &[("X-Pane", "2")],
+            )],
+        );
+
+        assert_eq!(
+            routes.resolve(1, DEFAULT_PROFILE, "https://other.com/ads/x"),
+            Some(RouteDecision {
+                action: RouteAction::Block,
+                add_headers: vec![],
+            })
+        );
+        assert_eq!(
+            routes.resolve(1, DEFAULT_PROFILE, "https://example.com/ads/x"),
+            Some(RouteDecision {
+                action: RouteAction::Block,
+                add_headers: vec![("X-Profile".to_string(), "1".to_string())],
+            })
+        );
+        // Pane rules take precedence over the profile rules
+        assert_eq!(
+            routes.resolve(2, DEFAULT_PROFILE, "https://example.com/ads/x"),
+            Some(RouteDecision {
+                action: RouteAction::Redirect("http://localhost/ad".to_string()),
+                add_headers: vec![("X-Pane".to_string(), "2".to_string())],
+            })
+        );
+        assert_eq!(routes.resolve(1, "work", "https://other.com/ads/x"), None);
+        assert_eq!(
+            routes.resolve(1, DEFAULT_PROFILE, "https://other.com/"),
+            None
+        );
+
+        routes.set(RouteScope::Pane(2), vec![]);
+        assert!(routes.get(&RouteScope::Pane(2)).is_empty());
+        assert_eq!(
+            routes.resolve(2, DEFAULT_PROFILE, "https://example.com/ads/x"),
+            Some(RouteDecision {
+                action: RouteAction::Block,
+                add_headers: vec![("X-Profile".to_string(), "1".to_string())],
+            })
+        );
+    }
+
+    #[test]
+    fn content_blocking_counts() {
+        let state = ContentBlocking::new(true);
+        state.record_blocked();
+        state.record_blocked();
+        assert_eq!((state.blocked(), state.total_blocked()), (2, 2));
+        state.reset_page();
+        state.record_blocked();
+        assert_eq!((state.blocked(), state.total_blocked()), (1, 3));
+        state.set_enabled(false);
+        assert!(!state.enabled());
+    }
+}
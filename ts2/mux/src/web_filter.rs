@@ -0,0 +1,838 @@
+//! Content blocking for browser panes, driven by filter lists written
+//! in the Adblock Plus syntax used by EasyList and most other lists.
+//! <https://help.adblockplus.org/hc/en-us/articles/360062733293>
+//!
+//! Network filters are indexed by a token drawn from their pattern so
+//! that only a handful of them need to be tested against any given URL.
+//! Element hiding filters are turned into a stylesheet for each page.
+//! Filters using syntax that isn't supported here, such as regular
+//! expressions, scriptlets and procedural cosmetic filters, are skipped.
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// The kind of resource being requested.
+/// Filters use these to limit which requests they apply to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestType {
+    /// A top level page
+    Document,
+    /// A page loaded into a frame
+    Subdocument,
+    Stylesheet,
+    Script,
+    Image,
+    Font,
+    Media,
+    Object,
+    XmlHttpRequest,
+    Ping,
+    WebSocket,
+    Other,
+}
+
+impl RequestType {
+    fn bit(self) -> u16 {
+        1 << self as u16
+    }
+
+    fn from_option(name: &str) -> Option<Self> {
+        Some(match name {
+            "document" | "doc" => Self::Document,
+            "subdocument" | "frame" => Self::Subdocument,
+            "stylesheet" | "css" => Self::Stylesheet,
+            "script" => Self::Script,
+            "image" => Self::Image,
+            "font" => Self::Font,
+            "media" => Self::Media,
+            "object" => Self::Object,
+            "xmlhttprequest" | "xhr" => Self::XmlHttpRequest,
+            "ping" => Self::Ping,
+            "websocket" => Self::WebSocket,
+            "other" => Self::Other,
+            _ => return None,
+        })
+    }
+}
+
+/// Filters apply to every type of request other than top level
+/// documents unless their options say otherwise
+const DEFAULT_TYPES: u16 = !(1 << RequestType::Document as u16);
+
+/// A request to be checked against the filters
+#[derive(Debug, Clone, Copy)]
+pub struct FilterRequest<'a> {
+    pub url: &'a str,
+    /// The URL of the page that made the request
+    pub source_url: &'a str,
+    pub request_type: RequestType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Anchor {
+    None,
+    /// `|`: the pattern matches from the start of the URL
+    Start,
+    /// `||`: the pattern matches from the start of the host name
+    /// or of any of its subdomain labels
+    Domain,
+}
+
+#[derive(Debug)]
+struct NetworkFilter {
+    anchor: Anchor,
+    /// The pattern, lower cased unless match_case is set.
+    /// `*` matches any sequence and `^` matches a separator
+    /// character or the end of the URL.
+    pattern: String,
+    /// `|` at the end: the pattern must match the end of the URL
+    end_anchor: bool,
+    types: u16,
+    third_party: Option<bool>,
+    include_domains: Vec<String>,
+    exclude_domains: Vec<String>,
+    match_case: bool,
+    important: bool,
+}
+
+impl NetworkFilter {
+    fn matches(&self, request: &FilterRequest, url_lower: &str, source_host: &str) -> bool {
+        if self.types & request.request_type.bit() == 0 {
+            return false;
+        }
+        if !self.include_domains.is_empty()
+            && !self
+                .include_domains
+                .iter()
+                .any(|d| is_subdomain_of(source_host, d))
+        {
+            return false;
+        }
+        if self
+            .exclude_domains
+            .iter()
+            .any(|d| is_subdomain_of(source_host, d))
+        {
+            return false;
+        }
+        if let Some(third_party) = self.third_party {
+            let host = host_of(request.url).unwrap_or("");
+            if third_party != (base_domain(host) != base_domain(source_host)) {
+                return false;
+            }
+        }
+
+        let url = if self.match_case {
+            request.url
+        } else {
+            url_lower
+        };
+        self.matches_url(url)
+    }
+
+    fn matches_url(&self, url: &str) -> bool {
+        let pattern = self.pattern.as_bytes();
+        match self.anchor {
+            Anchor::Start => pattern_match(pattern, url.as_bytes(), self.end_anchor),
+            Anchor::None => (0..=url.len())
+                .filter(|&i| url.is_char_boundary(i))
+                .any(|i| pattern_match(pattern, &url.as_bytes()[i..], self.end_anchor)),
+            Anchor::Domain => {
+                let Some((start, end)) = host_range(url) else {
+                    return false;
+                };
+                std::iter::once(start)
+                    .chain(
+                        url.as_bytes()[start..end]
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, &c)| c == b'.')
+                            .map(|(i, _)| start + i + 1),
+                    )
+                    .any(|i| pattern_match(pattern, &url.as_bytes()[i..], self.end_anchor))
+            }
+        }
+    }
+
+    /// Returns the token used to index this filter: the longest run
+    /// of alphanumeric characters that must appear as a complete
+    /// token in any URL that the filter matches
+    fn index_token(&self) -> Option<String> {
+        let pattern = self.pattern.as_bytes();
+        let mut best: Option<&[u8]> = None;
+        let mut i = 0;
+        while i < pattern.len() {
+            if !pattern[i].is_ascii_alphanumeric() {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < pattern.len() && pattern[i].is_ascii_alphanumeric() {
+                i += 1;
+            }
+            let left_ok = if start == 0 {
+                self.anchor != Anchor::None
+            } else {
+                pattern[start - 1] != b'*'
+            };
+            let right_ok = if i == pattern.len() {
+                self.end_anchor
+            } else {
+                pattern[i] != b'*'
+            };
+            if left_ok && right_ok && best.map(|b| b.len() < i - start).unwrap_or(true) {
+                best = Some(&pattern[start..i]);
+            }
+        }
+        best.map(|token| String::from_utf8_lossy(token).to_ascii_lowercase())
+    }
+}
+
+/// Network filters, indexed by token
+#[derive(Default)]
+struct NetworkIndex {
+    filters: Vec<NetworkFilter>,
+    by_token: HashMap<String, Vec<usize>>,
+    untokenized: Vec<usize>,
+}
+
+impl NetworkIndex {
+    fn add(&mut self, filter: NetworkFilter) {
+        let idx = self.filters.len();
+        match filter.index_token() {
+            Some(token) => self.by_token.entry(token).or_default().push(idx),
+            None => self.untokenized.push(idx),
+        }
+        self.filters.push(filter);
+    }
+
+    fn find(
+        &self,
+        request: &FilterRequest,
+        url_lower: &str,
+        source_host: &str,
+    ) -> Option<&NetworkFilter> {
+        let mut seen = HashSet::new();
+        url_tokens(url_lower)
+            .filter(|token| seen.insert(*token))
+            .filter_map(|token| self.by_token.get(token))
+            .flatten()
+            .chain(self.untokenized.iter())
+            .map(|&idx| &self.filters[idx])
+            .find(|filter| filter.matches(request, url_lower, source_host))
+    }
+
+    fn len(&self) -> usize {
+        self.filters.len()
+    }
+}
+
+#[derive(Debug)]
+struct CosmeticFilter {
+    include_domains: Vec<String>,
+    exclude_domains: Vec<String>,
+    selector: String,
+}
+
+impl CosmeticFilter {
+    fn applies_to(&self, host: &str) -> bool {
+        (self.include_domains.is_empty()
+            || self
+                .include_domains
+                .iter()
+                .any(|d| is_subdomain_of(host, d)))
+            && !self
+                .exclude_domains
+                .iter()
+                .any(|d| is_subdomain_of(host, d))
+    }
+}
+
+/// A compiled set of filter lists
+#[derive(Default)]
+pub struct FilterSet {
+    block: NetworkIndex,
+    allow: NetworkIndex,
+    /// Element hiding filters without any domain restrictions
+    generic_hide: Vec<String>,
+    /// Element hiding filters restricted by domain
+    specific_hide: Vec<CosmeticFilter>,
+    /// `#@#` exceptions to element hiding filters
+    hide_exceptions: Vec<CosmeticFilter>,
+    /// The number of filters that were skipped because they
+    /// use unsupported syntax
+    skipped: usize,
+}
+
+impl FilterSet {
+    /// Load and compile the filter lists from the specified files
+    pub fn load<P: AsRef<Path>>(paths: &[P]) -> anyhow::Result<Self> {
+        let mut set = Self::default();
+        for path in paths {
+            let path = path.as_ref();
+            let text = std::fs::read_to_string(path).map_err(|err| {
+                anyhow::anyhow!("reading filter list {}: {err:#}", path.display())
+            })?;
+            set.add_list(&text);
+        }
+        Ok(set)
+    }
+
+    /// Parse a filter list and add its filters to the set
+    pub fn add_list(&mut self, text: &str) {
+        for line in text.lines() {
+            self.add_filter(line.trim());
+        }
+    }
+
+    fn add_filter(&mut self, line: &str) {
+        if line.is_empty() || line.starts_with('!') || line.starts_with('[') {
+            return;
+        }
+        let parsed = if let Some(idx) = line.find("#@#") {
+            parse_cosmetic(&line[..idx], &line[idx + 3..]).map(|f| self.hide_exceptions.push(f))
+        } else if let Some(idx) = line.find("##") {
+            parse_cosmetic(&line[..idx], &line[idx + 2..]).map(|f| {
+                if f.include_domains.is_empty() && f.exclude_domains.is_empty() {
+                    self.generic_hide.push(f.selector)
+                } else {
+                    self.specific_hide.push(f)
+                }
+            })
+        } else if line.contains("#?#") || line.contains("#$#") || line.contains("#%#") {
+            None
+        } else if let Some(rule) = line.strip_prefix("@@") {
+            parse_network(rule).map(|f| self.allow.add(f))
+        } else {
+            parse_network(line).map(|f| self.block.add(f))
+        };
+        if parsed.is_none() {
+            self.skipped += 1;
+        }
+    }
+
+    /// Returns the number of network and element hiding filters
+    pub fn len(&self) -> usize {
+        self.block.len()
+            + self.allow.len()
+            + self.generic_hide.len()
+            + self.specific_hide.len()
+            + self.hide_exceptions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of filters that were skipped
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// Returns true if the request should be blocked
+    pub fn should_block(&self, request: &FilterRequest) -> bool {
+        let url_lower = request.url.to_ascii_lowercase();
+        let source_lower = request.source_url.to_ascii_lowercase();
+        let source_host = host_of(&source_lower)
+            .or_else(|| host_of(&url_lower))
+            .unwrap_or("");
+
+        match self.block.find(request, &url_lower, source_host) {
+            None => false,
+            Some(filter) if filter.important => true,
+            Some(_) => self.allow.find(request, &url_lower, source_host).is_none(),
+        }
+    }
+
+    /// Returns a stylesheet that hides the elements matched by the
+    /// element hiding filters that apply to the page at `url`,
+    /// or None if there are none
+    pub fn hiding_stylesheet(&self, url: &str) -> Option<String> {
+        let url = url.to_ascii_lowercase();
+        let host = host_of(&url).unwrap_or("");
+
+        let exceptions: HashSet<&str> = self
+            .hide_exceptions
+            .iter()
+            .filter(|f| f.applies_to(host))
+            .map(|f| f.selector.as_str())
+            .collect();
+
+        let mut css = String::new();
+        let selectors = self.generic_hide.iter().map(String::as_str).chain(
+            self.specific_hide
+                .iter()
+                .filter(|f| f.applies_to(host))
+                .map(|f| f.selector.as_str()),
+        );
+        for selector in selectors.filter(|s| !exceptions.contains(s)) {
+            // One rule per selector, as a single invalid selector
+            // would otherwise invalidate the whole group
+            css.push_str(selector);
+            css.push_str(" { display: none !important; }\n");
+        }
+
+        if css.is_empty() {
+            None
+        } else {
+            Some(css)
+        }
+    }
+}
+
+fn parse_network(rule: &str) -> Option<NetworkFilter> {
+    let (pattern, options) = match rule.rfind('$') {
+        // A `$` is only an option separator if what follows it
+        // looks like options
+        Some(idx) if !rule.starts_with('/') || rule[..idx].ends_with('/') => {
+            (&rule[..idx], Some(&rule[idx + 1..]))
+        }
+        _ => (rule, None),
+    };
+
+    // Regular expression filters aren't supported
+    if pattern.len() > 1 && pattern.starts_with('/') && pattern.ends_with('/') {
+        return None;
+    }
+
+    let mut filter = NetworkFilter {
+        anchor: Anchor::None,
+        pattern: String::new(),
+        end_anchor: false,
+        types: DEFAULT_TYPES,
+        third_party: None,
+        include_domains: vec![],
+        exclude_domains: vec![],
+        match_case: false,
+        important: false,
+    };
+
+    if let Some(options) = options {
+        let mut positive_types = 0;
+        let mut negative_types = 0;
+        for option in options.split(',') {
+            let (negated, name) = match option.strip_prefix('~') {
+                Some(name) => (true, name),
+                None => (false, option),
+            };
+            if let Some(t) = RequestType::from_option(name) {
+                if negated {
+                    negative_types |= t.bit();
+                } else {
+                    positive_types |= t.bit();
+                }
+                continue;
+            }
+            match (negated, name) {
+                (_, "third-party" | "3p") => filter.third_party = Some(!negated),
+                (false, "first-party" | "1p") => filter.third_party = Some(false),
+                (false, "match-case") => filter.match_case = true,
+                (false, "important") => filter.important = true,
+                (false, _) if name.starts_with("domain=") => {
+                    for domain in name["domain=".len()..].split('|') {
+                        match domain.strip_prefix('~') {
+                            Some(d) => filter.exclude_domains.push(d.to_ascii_lowercase()),
+                            None => filter.include_domains.push(domain.to_ascii_lowercase()),
+                        }
+                    }
+                }
+                // Anything else, such as redirect=, csp= or popup,
+                // changes the meaning of the filter in ways that we
+                // can't honor, so skip the filter entirely
+                _ => return None,
+            }
+        }
+        if positive_types != 0 {
+            filter.types = positive_types;
+        }
+        filter.types &= !negative_types;
+        if filter.types == 0 {
+            return None;
+        }
+    }
+
+    let mut pattern = pattern;
+    if let Some(p) = pattern.strip_prefix("||") {
+        filter.anchor = Anchor::Domain;
+        pattern = p;
+    } else if let Some(p) = pattern.strip_prefix('|') {
+        filter.anchor = Anchor::Start;
+        pattern = p;
+    }
+    if let Some(p) = pattern.strip_suffix('|') {
+        filter.end_anchor = true;
+        pattern = p;
+    }
+    // Leading and trailing wildcards are implied
+    if filter.anchor == Anchor::None {
+        pattern = pattern.trim_start_matches('*');
+    }
+    if !filter.end_anchor {
+        pattern = pattern.trim_end_matches('*');
+    }
+    if pattern.is_empty() && filter.include_domains.is_empty() {
+        // This would match every request
+        return None;
+    }
+
+    filter.pattern = if filter.match_case {
+        pattern.to_string()
+    } else {
+        pattern.to_ascii_lowercase()
+    };
+    Some(filter)
+}
+
+fn parse_cosmetic(domains: &str, selector: &str) -> Option<CosmeticFilter> {
+    // Snippets (`##+js(...)`), HTML filters (`##^`) and procedural
+    // selectors need more than a stylesheet to implement
+    const PROCEDURAL: &[&str] = &[
+        ":-abp-",
+        ":has-text(",
+        ":matches-css",
+        ":matches-path(",
+        ":min-text-length(",
+        ":remove(",
+        ":style(",
+        ":upward(",
+        ":watch-attr(",
+        ":xpath(",
+    ];
+    if selector.is_empty()
+        || selector.starts_with('+')
+        || selector.starts_with('^')
+        || PROCEDURAL.iter().any(|p| selector.contains(p))
+        || selector.contains('{')
+    {
+        return None;
+    }
+
+    let mut filter = CosmeticFilter {
+        include_domains: vec![],
+        exclude_domains: vec![],
+        selector: selector.to_string(),
+    };
+    for domain in domains.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+        match domain.strip_prefix('~') {
+            Some(d) => filter.exclude_domains.push(d.to_ascii_lowercase()),
+            None => filter.include_domains.push(domain.to_ascii_lowercase()),
+        }
+    }
+    Some(filter)
+}
+
+/// Returns true if `c` is a separator for the purposes of `^`
+fn is_separator(c: u8) -> bool {
+    !(c.is_ascii_alphanumeric() || matches!(c, b'_' | b'-' | b'.' | b'%') || c >= 0x80)
+}
+
+/// Match `pattern` against the start of `text`; if `whole` is true
+/// the pattern must match all of `text`.
+/// `*` matches any sequence of characters and `^` matches a single
+/// separator character, or the end of the text.
+fn pattern_match(pattern: &[u8], text: &[u8], whole: bool) -> bool {
+    let (mut p, mut t) = (0, 0);
+    // Position of the most recent `*` in the pattern, and the
+    // position in the text that it is currently assumed to extend to
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    loop {
+        if p == pattern.len() && (!whole || t == text.len()) {
+            return true;
+        }
+        if t == text.len() {
+            // Any remaining `^` can match the end of the text
+            return pattern[p..].iter().all(|&c| c == b'*' || c == b'^');
+        }
+        match pattern.get(p) {
+            Some(b'*') => {
+                backtrack = Some((p, t));
+                p += 1;
+                continue;
+            }
+            Some(b'^') if is_separator(text[t]) => {
+                p += 1;
+                t += 1;
+                continue;
+            }
+            Some(&c) if c != b'^' && c == text[t] => {
+                p += 1;
+                t += 1;
+                continue;
+            }
+            _ => {}
+        }
+        match backtrack {
+            Some((star, star_t)) => {
+                // Let the star consume one more character and retry
+                p = star + 1;
+                t = star_t + 1;
+                backtrack = Some((star, star_t + 1));
+            }
+            None => return false,
+        }
+    }
+}
+
+/// Iterate over the alphanumeric tokens of a URL
+fn url_tokens(url: &str) -> impl Iterator<Item = &str> {
+    url.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|token| !token.is_empty())
+}
+
+/// Returns the byte range of the host name within a URL
+fn host_range(url: &str) -> Option<(usize, usize)> {
+    let start = url.find("://")? + 3;
+    let rest = &url[start..];
+    let authority = &rest[..rest.find(['/', '?', '#']).unwrap_or(rest.len())];
+    let host_start = authority.rfind('@').map(|i| i + 1).unwrap_or(0);
+    let host = &authority[host_start..];
+    let host_len = if host.starts_with('[') {
+        host.find(']').map(|i| i + 1).unwrap_or(host.len())
+    } else {
+        host.find(':').unwrap_or(host.len())
+    };
+    Some((start + host_start, start + host_start + host_len))
+}
+
+fn host_of(url: &str) -> Option<&str> {
+    let (start, end) = host_range(url)?;
+    Some(&url[start..end])
+}
+
+fn is_subdomain_of(host: &str, domain: &str) -> bool {
+    host == domain
+        || (host.len() > domain.len()
+            && host.ends_with(domain)
+            && host.as_bytes()[host.len() - domain.len() - 1] == b'.')
+}
+
+/// Returns an approximation of the registrable domain of a host,
+/// used to decide whether a request is third-party.
+/// Without the public suffix list, assume that the registrable domain
+/// is the last two labels, or three when it looks like the host is
+/// under a second level domain such as `co.uk` or `com.au`.
+fn base_domain(host: &str) -> &str {
+    if host.starts_with('[') || host.parse::<std::net::Ipv4Addr>().is_ok() {
+        return host;
+    }
+    let labels: Vec<&str> = host.rsplitn(4, '.').collect();
+    let keep = match labels.as_slice() {
+        [tld, sld, _, ..] if tld.len() == 2 && sld.len() <= 3 => 3,
+        _ => 2,
+    };
+    if labels.len() <= keep {
+        return host;
+    }
+    let suffix_len: usize = labels[..keep].iter().map(|l| l.len() + 1).sum::<usize>() - 1;
+    &host[host.len() - suffix_len..]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const LIST: &str = r#"
+[Adblock Plus 2.0]
+! Title: test list
+||ads.example.com^
+||tracker.net^$third-party
+/banner/*/img^
+|https://exact.example.org/ad.js|
+&adtype=
+||cdn.example.com/ads/$script,domain=news.com|~sports.news.com
+@@||ads.example.com/allowed/
+||important.example.com^$important
+@@||important.example.com^
+/ads?[0-9]+/
+||popup.example.com^$popup
+##.ad-banner
+##div[id^="sponsor"]
+news.com##.promo
+~shop.news.com,news.com##.sidebar-ad
+news.com#@#.ad-banner
+example.com##+js(nowebrtc)
+example.com#?#div:-abp-has(.ad)
+"#;
+
+    fn request<'a>(
+        url: &'a str,
+        source_url: &'a str,
+        request_type: RequestType,
+    ) -> FilterRequest<'a> {
+        FilterRequest {
+            url,
+            source_url,
+            request_type,
+        }
+    }
+
+    fn blocks(set: &FilterSet, url: &str, source_url: &str, request_type: RequestType) -> bool {
+        set.should_block(&request(url, source_url, request_type))
+    }
+
+    #[test]
+    fn filter_patterns() {
+        assert!(pattern_match(b"example.com^", b"example.com/", false));
+        assert!(pattern_match(b"example.com^", b"example.com", false));
+        assert!(pattern_match(b"example.com^", b"example.com:8080", false));
+        assert!(!pattern_match(b"example.com^", b"example.com.evil", false));
+        assert!(pattern_match(
+            b"/banner/*/img^",
+            b"/banner/1/2/img?x",
+            false
+        ));
+        assert!(!pattern_match(b"a.js", b"a.js?x", true));
+        assert!(pattern_match(b"a*c", b"abbbc", true));
+
+        assert_eq!(
+            host_of("https://user@sub.Example.com:443/x"),
+            Some("sub.Example.com")
+        );
+        assert_eq!(host_of("http://[::1]:80/"), Some("[::1]"));
+        assert_eq!(base_domain("a.b.example.com"), "example.com");
+        assert_eq!(base_domain("www.bbc.co.uk"), "bbc.co.uk");
+        assert_eq!(base_domain("example.com"), "example.com");
+        assert_eq!(base_domain("10.0.0.1"), "10.0.0.1");
+    }
+
+    #[test]
+    fn filter_network() {
+        let mut set = FilterSet::default();
+        set.add_list(LIST);
+        // The regex, popup, scriptlet and procedural filters
+        assert_eq!(set.skipped(), 4);
+
+        let page = "https://news.com/article";
+        assert!(blocks(
+            &set,
+            "https://ads.example.com/x.js",
+            page,
+            RequestType::Script
+        ));
+        assert!(blocks(
+            &set,
+            "https://sub.ads.example.com/x.js",
+            page,
+            RequestType::Image
+        ));
+        assert!(!blocks(
+            &set,
+            "https://notads.example.com/x.js",
+            page,
+            RequestType::Script
+        ));
+        // Top level documents are only blocked with $document
+        assert!(!blocks(
+            &set,
+            "https://ads.example.com/",
+            page,
+            RequestType::Document
+        ));
+        // Exceptions
+        assert!(!blocks(
+            &set,
+            "https://ads.example.com/allowed/x",
+            page,
+            RequestType::Script
+        ));
+        assert!(blocks(
+            &set,
+            "https://important.example.com/x",
+            page,
+            RequestType::Script
+        ));
+
+        // Third party
+        assert!(blocks(
+            &set,
+            "https://tracker.net/p",
+            page,
+            RequestType::Image
+        ));
+        assert!(!blocks(
+            &set,
+            "https://tracker.net/p",
+            "https://www.tracker.net/",
+            RequestType::Image
+        ));
+
+        // Anchors and wildcards
+        assert!(blocks(
+            &set,
+            "http://x.com/banner/a/b/img?w=300",
+            page,
+            RequestType::Image
+        ));
+        assert!(blocks(
+            &set,
+            "https://exact.example.org/ad.js",
+            page,
+            RequestType::Script
+        ));
+        assert!(!blocks(
+            &set,
+            "https://exact.example.org/ad.js?v=1",
+            page,
+            RequestType::Script
+        ));
+        assert!(blocks(
+            &set,
+            "https://x.com/q?a=1&adtype=banner",
+            page,
+            RequestType::XmlHttpRequest
+        ));
+        assert!(blocks(
+            &set,
+            "https://x.com/q?a=1&ADTYPE=banner",
+            page,
+            RequestType::XmlHttpRequest
+        ));
+
+        // Types and domains
+        let ads = "https://cdn.example.com/ads/x.js";
+        assert!(blocks(&set, ads, page, RequestType::Script));
+        assert!(!blocks(&set, ads, page, RequestType::Image));
+        assert!(!blocks(
+            &set,
+            ads,
+            "https://other.com/",
+            RequestType::Script
+        ));
+        assert!(!blocks(
+            &set,
+            ads,
+            "https://sports.news.com/",
+            RequestType::Script
+        ));
+        assert!(blocks(
+            &set,
+            ads,
+            "https://www.news.com/",
+            RequestType::Script
+        ));
+    }
+
+    #[test]
+    fn filter_cosmetic() {
+        let mut set = FilterSet::default();
+        set.add_list(LIST);
+
+        let css = set.hiding_stylesheet("https://example.com/").unwrap();
+        assert!(css.contains(".ad-banner {"));
+        assert!(css.contains("div[id^=\"sponsor\"] {"));
+        assert!(!css.contains(".promo"));
+
+        let css = set.hiding_stylesheet("https://www.news.com/").unwrap();
+        assert!(!css.contains(".ad-banner"));
+        assert!(css.contains(".promo {"));
+        assert!(css.contains(".sidebar-ad {"));
+
+        let css = set.hiding_stylesheet("https://shop.news.com/").unwrap();
+        assert!(!css.contains(".sidebar-ad"));
+
+        assert_eq!(
+            FilterSet::default().hiding_stylesheet("https://x.com/"),
+            None
+        );
+    }
+}
@@ -0,0 +1,657 @@
+//! Saving and restoring the windows, tabs and splits of the mux
+//! together with the browsers hosted in its panes, so that a TermSurf
+//! session can be recreated after a restart.
+//!
+//! Terminal panes are restored by spawning a new shell in the saved
+//! working directory.  When `persist_pane_contents` is enabled, the
+//! scrollback of each terminal pane is saved too, and is placed above
+//! the output of the new shell.  Browser panes are restored with a fresh host
+//! pane whose browser session state is registered with the mux; the
+//! GUI creates the browser when the pane is first shown, so pages in
+//! background tabs are not loaded until they are needed.
+use crate::localpane::LocalPane;
+use crate::pane::{Pane, PaneId};
+use crate::tab::{
+    PaneNode, SerdeUrl, SplitDirection, SplitDirectionAndSize, SplitRequest, SplitSize, Tab,
+};
+use crate::window::WindowId;
+use crate::Mux;
+use anyhow::{anyhow, Context};
+use base64::Engine;
+use config::keyassignment::SpawnTabDomain;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use termwiz::surface::SequenceNo;
+use wezterm_term::{ScreenSnapshot, TerminalSize};
+
+/// The version of the session file format written by this build
+pub const SESSION_VERSION: u32 = 1;
+
+/// A BrowserSession that can be updated from CEF callbacks
+pub type SharedBrowserSession = Arc<Mutex<BrowserSession>>;
+
+/// The session file used when no other path is specified
+pub fn default_session_path() -> PathBuf {
+    config::DATA_DIR.join("session.json")
+}
+
+/// The session file written and restored automatically when
+/// `persist_pane_contents` is enabled.  It is kept apart from the
+/// default session file so that it doesn't replace a session that
+/// was saved explicitly.
+pub fn autosave_session_path() -> PathBuf {
+    config::DATA_DIR.join("autosave-session.json")
+}
+
+/// How a browser pane is used
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum BrowserMode {
+    #[default]
+    Normal,
+    /// The pane is reused to preview hyperlinks opened with
+    /// `BrowserPreview`
+    Preview,
+}
+
+/// The state of a browser that is saved with the session
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct BrowserSession {
+    /// The URL of the current page
+    pub url: String,
+    /// The URLs in the navigation history, oldest first
+    pub history: Vec<String>,
+    /// The index of the current page in `history`
+    pub history_index: usize,
+    pub profile: String,
+    /// The CEF zoom level; 0.0 is the default of 100%
+    pub zoom_level: f64,
+    pub mode: BrowserMode,
+    /// The number of entries at the start of `history` that were
+    /// restored from a session file rather than reported by the browser
+    #[serde(skip)]
+    restored: usize,
+}
+
+impl BrowserSession {
+    pub fn new(url: &str, profile: &str, mode: BrowserMode) -> Self {
+        Self {
+            url: url.to_string(),
+            history: vec![url.to_string()],
+            history_index: 0,
+            profile: profile.to_string(),
+            zoom_level: 0.0,
+            mode,
+            restored: 0,
+        }
+    }
+
+    /// Prepare a saved session to be loaded into a new browser.
+    /// CEF has no way to populate the history of a browser, so the
+    /// new browser starts with only the current page.  The entries
+    /// before it are retained so that they survive the next save;
+    /// entries after it are discarded, as they would be when
+    /// navigating away from the page.
+    pub fn into_restored(mut self) -> Self {
+        if self.history_index >= self.history.len() {
+            self.history = vec![self.url.clone()];
+            self.history_index = 0;
+        }
+        self.history.truncate(self.history_index + 1);
+        self.restored = self.history_index;
+        self
+    }
+
+    /// Update the history from the entries reported by the browser,
+    /// where `index` is that of the current page
+    pub fn update_history(&mut self, entries: Vec<String>, index: usize) {
+        let Some(url) = entries.get(index) else {
+            return;
+        };
+        self.url = url.clone();
+        self.history.truncate(self.restored);
+        self.history.extend(entries);
+        self.history_index = self.restored + index;
+    }
+}
+
+/// A saved set of windows
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct Session {
+    pub version: u32,
+    pub windows: Vec<SessionWindow>,
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct SessionWindow {
+    pub workspace: String,
+    pub title: String,
+    pub tabs: Vec<SessionTab>,
+    /// The index of the active tab in `tabs`
+    pub active_tab: usize,
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct SessionTab {
+    pub title: String,
+    pub size: TerminalSize,
+    pub root: SessionNode,
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub enum SessionNode {
+    Split {
+        left: Box<SessionNode>,
+        right: Box<SessionNode>,
+        node: SplitDirectionAndSize,
+    },
+    Pane(SessionPane),
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct SessionPane {
+    pub working_dir: Option<SerdeUrl>,
+    pub is_active: bool,
+    pub is_zoomed: bool,
+    /// Set if the pane hosts a browser
+    pub browser: Option<BrowserSession>,
+    /// The base64 encoded `ScreenSnapshot` of a terminal pane
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contents: Option<String>,
+    /// The local pane that this was captured from
+    #[serde(skip)]
+    pub pane_id: Option<PaneId>,
+}
+
+impl Session {
+    /// Capture the layout of every window in the mux, together with
+    /// the contents of its terminal panes if `persist_pane_contents`
+    /// is enabled
+    pub fn capture(mux: &Mux) -> Self {
+        let mut session = Self::capture_layout(mux);
+        session.for_each_pane_mut(|pane| {
+            if pane.browser.is_none() {
+                pane.contents = pane.pane_id.and_then(|id| capture_contents(mux, id));
+            }
+        });
+        session
+    }
+
+    /// Capture the layout of every window in the mux, without the
+    /// contents of the panes
+    fn capture_layout(mux: &Mux) -> Self {
+        let mut windows = vec![];
+        for window_id in mux.iter_windows() {
+            let Some(window) = mux.get_window(window_id) else {
+                continue;
+            };
+            let active_idx = window.get_active_idx();
+            let mut tabs = vec![];
+            let mut active_tab = 0;
+            for (idx, tab) in window.iter().enumerate() {
+                let Some(root) = SessionNode::capture(mux, tab.codec_pane_tree()) else {
+                    continue;
+                };
+                if idx == active_idx {
+                    active_tab = tabs.len();
+                }
+                tabs.push(SessionTab {
+                    title: tab.get_title(),
+                    size: tab.get_size(),
+                    root,
+                });
+            }
+            if !tabs.is_empty() {
+                windows.push(SessionWindow {
+                    workspace: window.get_workspace().to_string(),
+                    title: window.get_title().to_string(),
+                    tabs,
+                    active_tab,
+                });
+            }
+        }
+        Self {
+            version: SESSION_VERSION,
+            windows,
+        }
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let data = std::fs::read(path)
+            .with_context(|| format!("reading session file {}", path.display()))?;
+        let session: Self = serde_json::from_slice(&data)
+            .with_context(|| format!("parsing session file {}", path.display()))?;
+        if session.version > SESSION_VERSION {
+            anyhow::bail!(
+                "session file {} has version {}, but only version {} is supported",
+                path.display(),
+                session.version,
+                SESSION_VERSION
+            );
+        }
+        Ok(session)
+    }
+
+    /// Write the session to `path`.  The file is replaced in a single
+    /// step, so that it is never left partially written.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            config::create_user_owned_dirs(parent)?;
+        }
+        let data = serde_json::to_vec_pretty(self)?;
+        let temp_path = path.with_extension("json.tmp");
+        std::fs::write(&temp_path, data)
+            .with_context(|| format!("writing session file {}", temp_path.display()))?;
+        std::fs::rename(&temp_path, path)
+            .with_context(|| format!("replacing session file {}", path.display()))
+    }
+
+    fn for_each_pane_mut<F: FnMut(&mut SessionPane)>(&mut self, mut func: F) {
+        for window in &mut self.windows {
+            for tab in &mut window.tabs {
+                tab.root.for_each_pane_mut(&mut func);
+            }
+        }
+    }
+
+    /// Recreate the saved windows in the default domain.
+    /// Returns the ids of the new windows.
+    pub async fn restore(self) -> anyhow::Result<Vec<WindowId>> {
+        let mut window_ids = vec![];
+        for window in self.windows {
+            window_ids.push(window.restore().await?);
+        }
+        Ok(window_ids)
+    }
+}
+
+impl SessionWindow {
+    async fn restore(self) -> anyhow::Result<WindowId> {
+        let mux = Mux::get();
+        let mut window_id = None;
+        for tab in self.tabs {
+            let root_pane = tab.root.first_pane();
+            let (new_tab, pane, new_window_id) = mux
+                .spawn_tab_or_window(
+                    window_id,
+                    SpawnTabDomain::DefaultDomain,
+                    None,
+                    root_pane.working_dir_path(),
+                    tab.size,
+                    None,
+                    self.workspace.clone(),
+                    None,
+                )
+                .await?;
+            window_id = Some(new_window_id);
+            if !tab.title.is_empty() {
+                new_tab.set_title(&tab.title);
+            }
+            tab.root.restore(&new_tab, pane).await?;
+        }
+
+        let window_id = window_id.ok_or_else(|| anyhow!("session window has no tabs"))?;
+        if let Some(mut window) = mux.get_window_mut(window_id) {
+            if !self.title.is_empty() {
+                window.set_title(&self.title);
+            }
+            if self.active_tab < window.len() {
+                window.set_active_without_saving(self.active_tab);
+            }
+        }
+        Ok(window_id)
+    }
+}
+
+impl SessionNode {
+    fn capture(mux: &Mux, node: PaneNode) -> Option<Self> {
+        match node {
+            PaneNode::Empty => None,
+            PaneNode::Split { left, right, node } => Some(Self::Split {
+                left: Box::new(Self::capture(mux, *left)?),
+                right: Box::new(Self::capture(mux, *right)?),
+                node,
+            }),
+            PaneNode::Leaf(entry) => Some(Self::Pane(SessionPane {
+                working_dir: entry.working_dir,
+                is_active: entry.is_active_pane,
+                is_zoomed: entry.is_zoomed_pane,
+                browser: mux
+                    .get_web_session(entry.pane_id)
+                    .map(|session| session.lock().clone()),
+                contents: None,
+                pane_id: Some(entry.pane_id),
+            })),
+        }
+    }
+
+    fn for_each_pane_mut<F: FnMut(&mut SessionPane)>(&mut self, func: &mut F) {
+        match self {
+            Self::Split { left, right, .. } => {
+                left.for_each_pane_mut(func);
+                right.for_each_pane_mut(func);
+            }
+            Self::Pane(pane) => func(pane),
+        }
+    }
+
+    /// Returns the top-left pane of the node
+    fn first_pane(&self) -> &SessionPane {
+        match self {
+            Self::Split { left, .. } => left.first_pane(),
+            Self::Pane(pane) => pane,
+        }
+    }
+
+    /// Recreate the node in place of `pane`, which was spawned for
+    /// `self.first_pane()`.  Each split is made by splitting the pane
+    /// that occupies the whole of the node, so that the panes in its
+    /// left subtree are then made by splitting that same pane.
+    async fn restore(self, tab: &Arc<Tab>, pane: Arc<dyn Pane>) -> anyhow::Result<()> {
+        let mux = Mux::get();
+        let mut pending = vec![(self, pane)];
+        let mut active = None;
+        let mut zoomed = false;
+
+        while let Some((node, pane)) = pending.pop() {
+            match node {
+                Self::Split { left, right, node } => {
+                    let (second, _size) = mux
+                        .split_pane(
+                            pane.pane_id(),
+                            SplitRequest {
+                                direction: node.direction,
+                                target_is_second: true,
+                                top_level: false,
+                                size: SplitSize::Percent(split_percent(&node)),
+                            },
+                            crate::domain::SplitSource::Spawn {
+                                command: None,
+                                command_dir: right.first_pane().working_dir_path(),
+                            },
+                            SpawnTabDomain::CurrentPaneDomain,
+                        )
+                        .await?;
+                    pending.push((*right, second));
+                    pending.push((*left, pane));
+                }
+                Self::Pane(entry) => {
+                    if entry.is_active {
+                        active = Some(Arc::clone(&pane));
+                        zoomed = entry.is_zoomed;
+                    }
+                    if let Some(browser) = entry.browser {
+                        mux.register_web_session(pane.pane_id(), browser.into_restored());
+                    }
+                    if let Some(contents) = &entry.contents {
+                        if let Err(err) = restore_contents(&pane, contents) {
+                            log::error!(
+                                "while restoring the contents of pane {}: {:#}",
+                                pane.pane_id(),
+                                err
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(pane) = active {
+            tab.set_active_pane(&pane);
+            if zoomed {
+                tab.set_zoomed(true);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SessionPane {
+    fn working_dir_path(&self) -> Option<String> {
+        let url: url::Url = self.working_dir.clone()?.into();
+        crate::working_dir_path(&url)
+    }
+}
+
+/// Returns the encoded snapshot of a local terminal pane, if
+/// `persist_pane_contents` is enabled
+fn capture_contents(mux: &Mux, pane_id: PaneId) -> Option<String> {
+    if !config::configuration().persist_pane_contents {
+        return None;
+    }
+    let pane = mux.get_pane(pane_id)?;
+    let pane = pane.downcast_ref::<LocalPane>()?;
+    encode_contents(pane_id, &pane.snapshot())
+}
+
+fn encode_contents(pane_id: PaneId, snapshot: &ScreenSnapshot) -> Option<String> {
+    match snapshot.encode() {
+        Ok(data) => Some(base64::engine::general_purpose::STANDARD.encode(data)),
+        Err(err) => {
+            log::error!(
+                "while capturing the contents of pane {}: {:#}",
+                pane_id,
+                err
+            );
+            None
+        }
+    }
+}
+
+/// Saves the session, with the contents of its terminal panes, from
+/// time to time.  Snapshots are taken only of the panes that changed
+/// since the previous save, and are encoded by `PendingSave::write`,
+/// which can run away from the main thread.
+#[derive(Default)]
+pub struct SessionSaver {
+    state: Arc<Mutex<SaverState>>,
+}
+
+#[derive(Default)]
+struct SaverState {
+    /// The seqno and encoded contents of each terminal pane, as of
+    /// the previous save
+    contents: HashMap<PaneId, (SequenceNo, Option<String>)>,
+    /// The layout that was last written
+    saved: Option<Session>,
+}
+
+/// A session captured by `SessionSaver::capture`, waiting to be written
+pub struct PendingSave {
+    session: Session,
+    snapshots: Vec<(PaneId, SequenceNo, ScreenSnapshot)>,
+    state: Arc<Mutex<SaverState>>,
+}
+
+impl SessionSaver {
+    /// Capture the layout of the mux, and snapshot the terminal panes
+    /// whose contents changed since the previous save.  If a previous
+    /// save is still being written, this waits for it to finish.
+    pub fn capture(&self, mux: &Mux) -> PendingSave {
+        let mut session = Session::capture_layout(mux);
+        let mut state = self.state.lock();
+        let mut snapshots = vec![];
+        let mut live = HashSet::new();
+        session.for_each_pane_mut(|entry| {
+            let Some(pane_id) = entry.pane_id else {
+                return;
+            };
+            let Some(pane) = mux.get_pane(pane_id) else {
+                return;
+            };
+            let Some(pane) = pane.downcast_ref::<LocalPane>() else {
+                return;
+            };
+            if entry.browser.is_some() {
+                return;
+            }
+            live.insert(pane_id);
+            let seqno = pane.get_current_seqno();
+            if state.contents.get(&pane_id).map(|(saved, _)| *saved) != Some(seqno) {
+                snapshots.push((pane_id, seqno, pane.snapshot()));
+            }
+        });
+        state.contents.retain(|pane_id, _| live.contains(pane_id));
+
+        PendingSave {
+            session,
+            snapshots,
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+impl PendingSave {
+    /// Returns true if there are no windows to save
+    pub fn is_empty(&self) -> bool {
+        self.session.windows.is_empty()
+    }
+
+    /// Encode the snapshots and write the session to `path`.  Nothing
+    /// is written if neither the layout nor any of the panes changed
+    /// since the previous save.
+    pub fn write(self, path: &Path) -> anyhow::Result<()> {
+        let mut state = self.state.lock();
+        if self.snapshots.is_empty() && state.saved.as_ref() == Some(&self.session) {
+            return Ok(());
+        }
+        for (pane_id, seqno, snapshot) in self.snapshots {
+            let contents = encode_contents(pane_id, &snapshot);
+            state.contents.insert(pane_id, (seqno, contents));
+        }
+
+        let mut session = self.session.clone();
+        session.for_each_pane_mut(|pane| {
+            pane.contents = pane
+                .pane_id
+                .and_then(|id| state.contents.get(&id))
+                .and_then(|(_, contents)| contents.clone());
+        });
+        session.save(path)?;
+        state.saved = Some(self.session);
+        Ok(())
+    }
+}
+
+fn restore_contents(pane: &Arc<dyn Pane>, contents: &str) -> anyhow::Result<()> {
+    let data = base64::engine::general_purpose::STANDARD.decode(contents)?;
+    let snapshot = ScreenSnapshot::decode(&data)?;
+    if let Some(pane) = pane.downcast_ref::<LocalPane>() {
+        pane.restore_snapshot(snapshot);
+    }
+    Ok(())
+}
+
+/// The size of the second half of a split, as a percentage of the
+/// space available to the split
+fn split_percent(node: &SplitDirectionAndSize) -> u8 {
+    let (first, second) = match node.direction {
+        SplitDirection::Horizontal => (node.first.cols, node.second.cols),
+        SplitDirection::Vertical => (node.first.rows, node.second.rows),
+    };
+    let total = first + second + 1;
+    ((second * 100 + total / 2) / total).clamp(1, 99) as u8
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn size(cols: usize, rows: usize) -> TerminalSize {
+        TerminalSize {
+            cols,
+            rows,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn split_percentages() {
+        let node = SplitDirectionAndSize {
+            direction: SplitDirection::Horizontal,
+            first: size(40, 24),
+            second: size(39, 24),
+        };
+        assert_eq!(split_percent(&node), 49);
+
+        let node = SplitDirectionAndSize {
+            direction: SplitDirection::Vertical,
+            first: size(80, 5),
+            second: size(80, 18),
+        };
+        assert_eq!(split_percent(&node), 75);
+
+        let node = SplitDirectionAndSize {
+            direction: SplitDirection::Vertical,
+            first: size(80, 200),
+            second: size(80, 0),
+        };
+        assert_eq!(split_percent(&node), 1);
+    }
+
+    #[test]
+    fn restored_history() {
+        let mut session = BrowserSession::new("https://a.example/", "default", BrowserMode::Normal);
+        session.update_history(
+            vec![
+                "https://a.example/".to_string(),
+                "https://b.example/".to_string(),
+                "https://c.example/".to_string(),
+            ],
+            1,
+        );
+        assert_eq!(session.url, "https://b.example/");
+        assert_eq!(session.history_index, 1);
+
+        // The forward entry is dropped and the browser reports
+        // only the page that was loaded
+        let mut session = session.into_restored();
+        assert_eq!(session.history.len(), 2);
+        session.update_history(vec!["https://b.example/".to_string()], 0);
+        assert_eq!(
+            session.history,
+            vec!["https://a.example/", "https://b.example/"]
+        );
+        assert_eq!(session.history_index, 1);
+
+        session.update_history(
+            vec![
+                "https://b.example/".to_string(),
+                "https://d.example/".to_string(),
+            ],
+            1,
+        );
+        assert_eq!(
+            session.history,
+            vec![
+                "https://a.example/",
+                "https://b.example/",
+                "https://d.example/"
+            ]
+        );
+        assert_eq!(session.history_index, 2);
+        assert_eq!(session.url, "https://d.example/");
+    }
+
+    #[test]
+    fn pane_without_contents() {
+        let pane: SessionPane = serde_json::from_str(
+            r#"{"working_dir": null, "is_active": true, "is_zoomed": false, "browser": null}"#,
+        )
+        .unwrap();
+        assert_eq!(pane.contents, None);
+        assert!(!serde_json::to_string(&pane).unwrap().contains("contents"));
+    }
+
+    #[test]
+    fn invalid_history_index() {
+        let mut session =
+            BrowserSession::new("https://a.example/", "default", BrowserMode::Preview);
+        session.history_index = 5;
+        let session = session.into_restored();
+        assert_eq!(session.history, vec!["https://a.example/"]);
+        assert_eq!(session.history_index, 0);
+    }
+}
@@ -0,0 +1,168 @@
+//! Compares the memory used by a large scrollback with and without
+//! compression, along with the cost of producing output and of reading
+//! back lines from the scrollback.
+//!
+//! Run with `cargo bench -p wezterm-term --features use_serde --bench scrollback`
+use criterion::{black_box, Criterion};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use wezterm_term::color::ColorPalette;
+use wezterm_term::{Terminal, TerminalConfiguration, TerminalSize};
+
+/// Tracks the number of bytes currently allocated
+struct CountingAlloc;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATED.fetch_add(new_size, Ordering::Relaxed);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const MILLION: usize = 1_000_000;
+
+#[derive(Debug)]
+struct BenchConfig {
+    scrollback: usize,
+    compression_threshold: Option<usize>,
+}
+
+impl TerminalConfiguration for BenchConfig {
+    fn scrollback_size(&self) -> usize {
+        self.scrollback
+    }
+
+    fn scrollback_compression_threshold(&self) -> Option<usize> {
+        self.compression_threshold
+    }
+
+    fn color_palette(&self) -> ColorPalette {
+        ColorPalette::default()
+    }
+}
+
+fn new_terminal(scrollback: usize, compression_threshold: Option<usize>) -> Terminal {
+    Terminal::new(
+        TerminalSize {
+            rows: 50,
+            cols: 120,
+            pixel_width: 120 * 8,
+            pixel_height: 50 * 16,
+            dpi: 0,
+        },
+        Arc::new(BenchConfig {
+            scrollback,
+            compression_threshold,
+        }),
+        "WezTerm",
+        "bench",
+        Box::new(std::io::sink()),
+    )
+}
+
+/// Produces build-log-like output, with a little color
+fn output(first: usize, count: usize) -> Vec<u8> {
+    let mut data = vec![];
+    for n in first..first + count {
+        data.extend_from_slice(
+            format!(
+                "\x1b[1;32m   Compiling\x1b[0m crate-{} v0.{}.{} (/home/user/src/project/crates/crate-{}) [{}]\r\n",
+                n % 97,
+                n % 13,
+                n % 7,
+                n % 97,
+                n
+            )
+            .as_bytes(),
+        );
+    }
+    data
+}
+
+fn fill(term: &mut Terminal, lines: usize) {
+    const CHUNK: usize = 10_000;
+    for first in (0..lines).step_by(CHUNK) {
+        term.advance_bytes(output(first, CHUNK.min(lines - first)));
+    }
+}
+
+fn report_memory() {
+    for (label, threshold) in [("uncompressed", None), ("compressed", Some(10_000))] {
+        let before = ALLOCATED.load(Ordering::Relaxed);
+        let mut term = new_terminal(MILLION, threshold);
+        fill(&mut term, MILLION);
+        let used = ALLOCATED.load(Ordering::Relaxed).saturating_sub(before);
+        println!(
+            "scrollback memory per million lines, {}: {:.1} MiB",
+            label,
+            used as f64 / (1024. * 1024.)
+        );
+        black_box(&term);
+    }
+}
+
+fn bench_scrollback(c: &mut Criterion) {
+    const LINES: usize = 100_000;
+    let data = output(0, 1_000);
+
+    let mut group = c.benchmark_group("scrollback");
+    for (label, threshold) in [("uncompressed", None), ("compressed", Some(10_000))] {
+        let mut term = new_terminal(LINES, threshold);
+        fill(&mut term, LINES);
+
+        group.bench_function(format!("output 1000 lines/{}", label), |b| {
+            b.iter(|| term.advance_bytes(&data))
+        });
+
+        // Reading through a shared reference, as get-text and search
+        // do, decompresses the lines each time
+        let rows = term.screen().scrollback_rows();
+        let mut start = 0;
+        group.bench_function(format!("read 100 lines/{}", label), |b| {
+            b.iter(|| {
+                start = (start + 7_919) % (rows - 100);
+                black_box(term.screen().lines_in_phys_range(start..start + 100))
+            })
+        });
+
+        // Rendering accesses the lines mutably, which keeps the most
+        // recently used blocks decompressed; scrolling back through
+        // the history mostly revisits the same blocks
+        let mut start = 0;
+        group.bench_function(format!("render 100 lines/{}", label), |b| {
+            b.iter(|| {
+                start = (start + 10) % (rows - 100);
+                term.screen_mut()
+                    .with_phys_lines_mut(start..start + 100, |lines| {
+                        black_box(lines.len());
+                    })
+            })
+        });
+    }
+    group.finish();
+}
+
+fn main() {
+    report_memory();
+
+    let mut criterion = Criterion::default().configure_from_args();
+    bench_scrollback(&mut criterion);
+    criterion.final_summary();
+}
@@ -147,6 +147,15 @@ pub trait TerminalConfiguration: Downcast + std::fmt::Debug + Send + Sync {
         3500
     }
 
+    /// Returns the number of rows of scrollback that are kept
+    /// uncompressed, or None if the scrollback is never compressed.
+    /// Rows older than these are compressed in blocks and are
+    /// decompressed on demand.  Compression requires the `use_serde`
+    /// feature; without it, this has no effect.
+    fn scrollback_compression_threshold(&self) -> Option<usize> {
+        None
+    }
+
     /// Return true if the embedding application wants to use CSI-u encoding
     /// for keys that would otherwise be ambiguous.
     /// <http://www.leonerd.org.uk/hacks/fixterms/>
@@ -122,6 +122,50 @@ pub struct SemanticZone {
     pub semantic_type: SemanticType,
 }
 
+/// A command that was run at a shell prompt, as reported by the
+/// OSC 133 sequences of shell integration
+#[cfg_attr(feature = "use_serde", derive(Deserialize, Serialize))]
+#[derive(Debug, Clone, Eq, PartialEq, FromDynamic, ToDynamic)]
+pub struct CommandRecord {
+    /// The command line, as entered at the prompt
+    pub command: String,
+    /// The first row of the prompt
+    pub prompt_y: StableRowIndex,
+    /// The first row of the output
+    pub output_start_y: StableRowIndex,
+    /// The row after the last row of the output, or None while the
+    /// command is running
+    pub output_end_y: Option<StableRowIndex>,
+    /// When the command started, in milliseconds since the unix epoch
+    pub start_time: u64,
+    /// When the command finished, in milliseconds since the unix epoch
+    pub end_time: Option<u64>,
+    /// The exit status, if the shell reported one
+    pub exit_code: Option<i32>,
+}
+
+impl CommandRecord {
+    /// Returns true if the command has finished
+    pub fn is_finished(&self) -> bool {
+        self.output_end_y.is_some()
+    }
+
+    /// Returns how long the command ran for
+    pub fn duration(&self) -> Option<std::time::Duration> {
+        let end_time = self.end_time?;
+        Some(std::time::Duration::from_millis(
+            end_time.saturating_sub(self.start_time),
+        ))
+    }
+
+    /// Returns the rows of the output.  The output of a command that is
+    /// still running extends up to, but not including, `cursor_y`.
+    pub fn output_rows(&self, cursor_y: StableRowIndex) -> Range<StableRowIndex> {
+        let end_y = self.output_end_y.unwrap_or(cursor_y);
+        self.output_start_y..end_y.max(self.output_start_y)
+    }
+}
+
 pub mod color;
 
 #[cfg(test)]
@@ -1,12 +1,14 @@
 #![allow(clippy::range_plus_one)]
 use super::*;
 use crate::config::BidiMode;
+use linestore::{LineStore, BLOCK_LINES};
 use log::debug;
-use std::collections::VecDeque;
 use std::sync::Arc;
 use termwiz::input::KeyboardEncoding;
 use wezterm_surface::SequenceNo;
 
+mod linestore;
+
 /// Holds the model of a screen.  This can either be the primary screen
 /// which includes lines of scrollback text, or the alternate screen
 /// which holds no scrollback.  The intent is to have one instance of
@@ -14,14 +16,15 @@ use wezterm_surface::SequenceNo;
 #[derive(Debug, Clone)]
 pub struct Screen {
     /// Holds the line data that comprises the screen contents.
-    /// This is allocated with capacity for the entire scrollback.
+    /// This is allocated with capacity for the entire scrollback,
+    /// or for its uncompressed portion if it is compressed.
     /// The last N lines are the visible lines, with those prior being
     /// the lines that have scrolled off the top of the screen.
     /// Index 0 is the topmost line of the screen/scrollback (depending
     /// on the current window size) and will be the first line to be
     /// popped off the front of the screen when a new line is added that
     /// would otherwise have exceeded the line capacity
-    lines: VecDeque<Line>,
+    lines: LineStore,
 
     /// Whenever we scroll a line off the top of the scrollback, we
     /// increment this.  We use this offset to translate between
@@ -54,6 +57,36 @@ fn scrollback_size(config: &Arc<dyn TerminalConfiguration>, allow_scrollback: bo
     }
 }
 
+/// The number of lines at the bottom of the screen that are kept
+/// uncompressed, or None if the scrollback is not to be compressed
+fn uncompressed_lines(
+    config: &Arc<dyn TerminalConfiguration>,
+    allow_scrollback: bool,
+    physical_rows: usize,
+) -> Option<usize> {
+    if allow_scrollback && cfg!(feature = "use_serde") {
+        config
+            .scrollback_compression_threshold()
+            .map(|threshold| physical_rows + threshold)
+    } else {
+        None
+    }
+}
+
+/// The number of lines to allocate space for up front; when the
+/// scrollback is compressed, only its uncompressed portion needs it
+fn line_capacity(
+    config: &Arc<dyn TerminalConfiguration>,
+    allow_scrollback: bool,
+    physical_rows: usize,
+) -> usize {
+    let capacity = physical_rows + scrollback_size(config, allow_scrollback);
+    match uncompressed_lines(config, allow_scrollback, physical_rows) {
+        Some(uncompressed) => capacity.min(uncompressed + BLOCK_LINES),
+        None => capacity,
+    }
+}
+
 impl Screen {
     /// Create a new Screen with the specified dimensions.
     /// The Cells in the viewable portion of the screen are set to the
@@ -69,7 +102,7 @@ impl Screen {
         let physical_cols = size.cols.max(1);
 
         let mut lines =
-            VecDeque::with_capacity(physical_rows + scrollback_size(config, allow_scrollback));
+            LineStore::with_capacity(line_capacity(config, allow_scrollback, physical_rows));
         for _ in 0..physical_rows {
             let mut line = Line::new(seqno);
             bidi_mode.apply_to_line(&mut line, seqno);
@@ -97,6 +130,13 @@ impl Screen {
         scrollback_size(&self.config, self.allow_scrollback)
     }
 
+    /// Compresses the older part of the scrollback, if enabled
+    fn compress_scrollback(&mut self) {
+        let keep = uncompressed_lines(&self.config, self.allow_scrollback, self.physical_rows)
+            .unwrap_or(usize::MAX);
+        self.lines.compress(keep);
+    }
+
     fn rewrap_lines(
         &mut self,
         physical_cols: usize,
@@ -105,12 +145,14 @@ impl Screen {
         cursor_y: PhysRowIndex,
         seqno: SequenceNo,
     ) -> (usize, PhysRowIndex) {
-        let mut rewrapped = VecDeque::new();
+        let keep = uncompressed_lines(&self.config, self.allow_scrollback, physical_rows)
+            .unwrap_or(usize::MAX);
+        let mut rewrapped = LineStore::with_capacity(self.lines.capacity().min(keep));
         let mut logical_line: Option<Line> = None;
         let mut logical_cursor_x: Option<usize> = None;
         let mut adjusted_cursor = (cursor_x, cursor_y);
 
-        for (phys_idx, mut line) in self.lines.drain(..).enumerate() {
+        for (phys_idx, mut line) in self.lines.take_all().enumerate() {
             line.update_last_change_seqno(seqno);
             let was_wrapped = line.last_cell_was_wrapped();
 
@@ -171,6 +213,7 @@ impl Screen {
                     rewrapped.push_back(line);
                 }
             }
+            rewrapped.compress(keep);
         }
         self.lines = rewrapped;
 
@@ -233,22 +276,23 @@ impl Screen {
             if self.allow_scrollback {
                 self.rewrap_lines(physical_cols, physical_rows, cursor.x, cursor_phys, seqno)
             } else {
-                for line in &mut self.lines {
-                    if physical_cols < self.physical_cols {
+                let narrower = physical_cols < self.physical_cols;
+                self.lines.for_each_mut(|_, line| {
+                    if narrower {
                         // Do a simple prune of the lines instead
                         line.resize(physical_cols, seqno);
                     } else {
                         // otherwise: invalidate them
                         line.update_last_change_seqno(seqno);
                     }
-                }
+                });
                 (cursor.x, cursor_phys)
             }
         } else {
             (cursor.x, cursor_phys)
         };
 
-        let capacity = physical_rows + self.scrollback_size();
+        let capacity = line_capacity(&self.config, self.allow_scrollback, physical_rows);
         let current_capacity = self.lines.capacity();
         if capacity > current_capacity {
             self.lines.reserve(capacity - current_capacity);
@@ -316,6 +360,7 @@ impl Screen {
 
         self.physical_rows = physical_rows;
         self.physical_cols = physical_cols;
+        self.compress_scrollback();
         CursorPosition {
             x: cursor_x,
             y: new_cursor_y,
@@ -328,7 +373,7 @@ impl Screen {
     /// Get mutable reference to a line, relative to start of scrollback.
     #[inline]
     pub fn line_mut(&mut self, idx: PhysRowIndex) -> &mut Line {
-        &mut self.lines[idx]
+        self.lines.get_mut(idx).expect("line index out of bounds")
     }
 
     /// Returns the number of occupied rows of scrollback
@@ -340,8 +385,8 @@ impl Screen {
     #[inline]
     pub fn dirty_line(&mut self, idx: VisibleRowIndex, seqno: SequenceNo) {
         let line_idx = self.phys_row(idx);
-        if line_idx < self.lines.len() {
-            self.lines[line_idx].update_last_change_seqno(seqno);
+        if let Some(line) = self.lines.get_mut(line_idx) {
+            line.update_last_change_seqno(seqno);
         }
     }
 
@@ -349,20 +394,26 @@ impl Screen {
     #[cfg(test)]
     pub fn visible_lines(&self) -> Vec<Line> {
         let line_idx = self.lines.len() - self.physical_rows;
-        let mut lines = Vec::new();
-        for line in self.lines.iter().skip(line_idx) {
-            if lines.len() >= self.physical_rows {
-                break;
-            }
-            lines.push(line.clone());
-        }
-        lines
+        self.lines_in_phys_range(line_idx..line_idx + self.physical_rows)
     }
 
     /// Returns a copy of the lines in the screen (including scrollback)
     #[cfg(test)]
     pub fn all_lines(&self) -> Vec<Line> {
-        self.lines.iter().map(|l| l.clone()).collect()
+        self.lines_in_phys_range(0..self.lines.len())
+    }
+
+    /// Returns the number of lines that are held compressed
+    #[cfg(test)]
+    pub fn compressed_rows(&self) -> usize {
+        self.lines.compressed_len()
+    }
+
+    /// Returns the number of compressed blocks of lines that are
+    /// being held decompressed
+    #[cfg(test)]
+    pub fn thawed_blocks(&self) -> usize {
+        self.lines.thawed_blocks()
     }
 
     pub fn insert_cell(
@@ -450,6 +501,62 @@ impl Screen {
         line.fill_range(cols, &Cell::blank_with_attrs(attr.clone()), seqno);
     }
 
+    /// Returns a copy of the cells in the rectangle bounded by `rows`
+    /// and `cols`.  Cells beyond the end of a line are returned as blanks.
+    pub fn copy_rectangle(
+        &mut self,
+        rows: Range<VisibleRowIndex>,
+        cols: Range<usize>,
+    ) -> Vec<Vec<Cell>> {
+        rows.map(|y| {
+            let line_idx = self.phys_row(y);
+            let cells = self.line_mut(line_idx).cells_mut();
+            cols.clone()
+                .map(|x| cells.get(x).cloned().unwrap_or_else(Cell::blank))
+                .collect()
+        })
+        .collect()
+    }
+
+    /// Places a block of cells, such as that returned from copy_rectangle,
+    /// so that its top left cell is at `x`, `y`.  The caller is responsible
+    /// for clipping the block to the screen.
+    pub fn paste_rectangle(
+        &mut self,
+        x: usize,
+        y: VisibleRowIndex,
+        cells: Vec<Vec<Cell>>,
+        seqno: SequenceNo,
+    ) {
+        for (row, line_cells) in cells.into_iter().enumerate() {
+            let line_idx = self.phys_row(y + row as VisibleRowIndex);
+            let line = self.line_mut(line_idx);
+            for (col, cell) in line_cells.into_iter().enumerate() {
+                line.set_cell(x + col, cell, seqno);
+            }
+        }
+    }
+
+    /// Calls `func` to modify the attributes of the cells in `cols` on
+    /// row `y`, extending the line with blanks if it is shorter than `cols`.
+    pub fn change_cell_attributes<F: FnMut(&mut CellAttributes)>(
+        &mut self,
+        y: VisibleRowIndex,
+        cols: Range<usize>,
+        seqno: SequenceNo,
+        mut func: F,
+    ) {
+        let line_idx = self.phys_row(y);
+        let line = self.line_mut(line_idx);
+        if line.len() < cols.end {
+            line.resize(cols.end, seqno);
+        }
+        for cell in &mut line.cells_mut_for_attr_changes_only()[cols] {
+            func(cell.attrs_mut());
+        }
+        line.update_last_change_seqno(seqno);
+    }
+
     /// Ensure that row is within the range of the physical portion of
     /// the screen; 0 .. physical_rows by clamping it to the nearest
     /// boundary.
@@ -579,7 +686,7 @@ impl Screen {
 
                 // Copy the source cells first
                 let cells = {
-                    self.lines[src_row]
+                    self.line_mut(src_row)
                         .cells_mut()
                         .iter()
                         .skip(left_and_right_margins.start)
@@ -704,10 +811,13 @@ impl Screen {
         let to_move = lines_removed.min(num_rows);
         let (to_remove, to_add) = {
             for _ in 0..to_move {
-                let mut line = self.lines.remove(remove_idx).unwrap();
                 let line = if default_blank == blank_attr {
+                    // Discarding rather than removing the line avoids
+                    // decompressing it when it is the oldest line
+                    self.lines.discard(remove_idx);
                     Line::new(seqno)
                 } else {
+                    let mut line = self.lines.remove(remove_idx).unwrap();
                     // Make the line like a new one of the appropriate width
                     line.resize_and_clear(self.physical_cols, seqno, blank_attr.clone());
                     line.update_last_change_seqno(seqno);
@@ -726,7 +836,7 @@ impl Screen {
 
         // Perform the removal
         for _ in 0..to_remove {
-            self.lines.remove(remove_idx);
+            self.lines.discard(remove_idx);
         }
 
         if remove_idx == 0 && scrollback_ok {
@@ -757,6 +867,10 @@ impl Screen {
                 self.line_mut(y).update_last_change_seqno(seqno);
             }
         }
+
+        if scrollback_ok {
+            self.compress_scrollback();
+        }
     }
 
     pub fn erase_scrollback(&mut self) {
@@ -849,7 +963,7 @@ impl Screen {
 
                 // Copy the source cells first
                 let cells = {
-                    self.lines[src_row]
+                    self.line_mut(src_row)
                         .cells_mut()
                         .iter()
                         .skip(left_and_right_margins.start)
@@ -897,13 +1011,72 @@ impl Screen {
         }
     }
 
+    /// Inserts `lines` into the scrollback above the existing lines,
+    /// rewrapping them to the current width.  The oldest of them are
+    /// discarded if there is insufficient room in the scrollback.
+    /// This is a no-op for the alternate screen.
+    /// Returns the number of rows that were inserted; the StableRowIndex
+    /// of each existing row increases by that amount.
+    pub fn prepend_lines(&mut self, lines: Vec<Line>, seqno: SequenceNo) -> usize {
+        if !self.allow_scrollback {
+            return 0;
+        }
+
+        let physical_cols = self.physical_cols;
+        let mut rewrapped = vec![];
+        let mut push = |line: Line| {
+            if line.len() <= physical_cols {
+                rewrapped.push(line);
+            } else {
+                rewrapped.extend(line.wrap(physical_cols, seqno));
+            }
+        };
+
+        let mut logical_line: Option<Line> = None;
+        for mut line in lines {
+            let was_wrapped = line.last_cell_was_wrapped();
+            if was_wrapped {
+                line.set_last_cell_was_wrapped(false, seqno);
+            }
+
+            let line = match logical_line.take() {
+                None => line,
+                Some(mut prior) => {
+                    prior.append_line(line, seqno);
+                    prior
+                }
+            };
+
+            if was_wrapped {
+                logical_line.replace(line);
+            } else {
+                push(line);
+            }
+        }
+        if let Some(line) = logical_line.take() {
+            push(line);
+        }
+
+        let capacity = self.physical_rows + self.scrollback_size();
+        let room = capacity.saturating_sub(self.lines.len());
+        let skip = rewrapped.len().saturating_sub(room);
+        let inserted = rewrapped.len() - skip;
+        for line in rewrapped.into_iter().skip(skip).rev() {
+            self.lines.push_front(line);
+        }
+
+        // Every existing line now has a different StableRowIndex
+        self.lines.for_each_mut(|_, line| {
+            line.update_last_change_seqno(seqno);
+        });
+        self.compress_scrollback();
+        inserted
+    }
+
     pub fn lines_in_phys_range(&self, phys_range: Range<PhysRowIndex>) -> Vec<Line> {
-        self.lines
-            .iter()
-            .skip(phys_range.start)
-            .take(phys_range.end - phys_range.start)
-            .cloned()
-            .collect()
+        self.lines.with_lines(phys_range, |lines| {
+            lines.iter().map(|&line| line.clone()).collect()
+        })
     }
 
     pub fn get_changed_stable_rows(
@@ -913,17 +1086,13 @@ impl Screen {
     ) -> Vec<StableRowIndex> {
         let phys = self.stable_range(&stable_lines);
         let mut set = vec![];
-        for (idx, line) in self
-            .lines
-            .iter()
-            .enumerate()
-            .skip(phys.start)
-            .take(phys.end - phys.start)
-        {
-            if line.changed_since(seqno) {
-                set.push(self.phys_to_stable_row_index(idx))
+        self.lines.with_lines(phys.clone(), |lines| {
+            for (idx, line) in lines.iter().enumerate() {
+                if line.changed_since(seqno) {
+                    set.push(self.phys_to_stable_row_index(phys.start + idx))
+                }
             }
-        }
+        });
         set
     }
 
@@ -931,61 +1100,41 @@ impl Screen {
     where
         F: FnMut(&[&Line]),
     {
-        let (first, second) = self.lines.as_slices();
-        let first_range = 0..first.len();
-        let second_range = first.len()..first.len() + second.len();
-        let first_range = phys_intersection(&first_range, &phys_range);
-        let second_range = phys_intersection(&second_range, &phys_range);
-
-        let mut lines: Vec<&Line> = Vec::with_capacity(phys_range.end - phys_range.start);
-        for line in &first[first_range] {
-            lines.push(line);
-        }
-        for line in &second[second_range] {
-            lines.push(line);
-        }
-        func(&lines)
+        self.lines.with_lines(phys_range, |lines| func(lines))
     }
 
     pub fn with_phys_lines_mut<F>(&mut self, phys_range: Range<PhysRowIndex>, mut func: F)
     where
         F: FnMut(&mut [&mut Line]),
     {
-        let (first, second) = self.lines.as_mut_slices();
-        let first_len = first.len();
-        let first_range = 0..first.len();
-        let second_range = first.len()..first.len() + second.len();
-        let first_range = phys_intersection(&first_range, &phys_range);
-        let second_range = phys_intersection(&second_range, &phys_range);
-
-        let mut lines: Vec<&mut Line> = Vec::with_capacity(phys_range.end - phys_range.start);
-        for line in &mut first[first_range] {
-            lines.push(line);
-        }
-        for line in &mut second[second_range.start.saturating_sub(first_len)
-            ..second_range.end.saturating_sub(first_len)]
-        {
-            lines.push(line);
-        }
-        func(&mut lines)
+        let mut lines = self.lines.range_mut(phys_range);
+        func(&mut lines);
+        self.compress_scrollback();
     }
 
-    pub fn for_each_phys_line<F>(&self, mut f: F)
+    pub fn for_each_phys_line<F>(&self, f: F)
     where
         F: FnMut(usize, &Line),
     {
-        for (idx, line) in self.lines.iter().enumerate() {
-            f(idx, line);
-        }
+        self.lines.for_each(f);
     }
 
-    pub fn for_each_phys_line_mut<F>(&mut self, mut f: F)
+    pub fn for_each_phys_line_mut<F>(&mut self, f: F)
     where
         F: FnMut(usize, &mut Line),
     {
-        for (idx, line) in self.lines.iter_mut().enumerate() {
-            f(idx, line);
-        }
+        self.lines.for_each_mut(f);
+    }
+
+    /// Like for_each_phys_line_mut, but skips lines that are compressed.
+    /// Lines holding images or image placeholders are never compressed,
+    /// so this can be used to update those without decompressing the
+    /// whole of the scrollback.
+    pub fn for_each_uncompressed_phys_line_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(usize, &mut Line),
+    {
+        self.lines.for_each_uncompressed_mut(f);
     }
 
     pub fn for_each_logical_line_in_stable_range_mut<F>(
@@ -1006,7 +1155,10 @@ impl Screen {
         // Look backwards to find the start of the first logical line
         let mut back_len = 0;
         while phys_range.start > 0 {
-            let prior = &mut self.lines[phys_range.start - 1];
+            let prior = self
+                .lines
+                .get(phys_range.start - 1)
+                .expect("line index out of bounds");
             if !prior.last_cell_was_wrapped() {
                 break;
             }
@@ -1082,74 +1234,88 @@ impl Screen {
         // This is an imperfect length constraint to partially manage the cost.
         const MAX_LOGICAL_LINE_LEN: usize = 1024;
 
+        // Compressed lines are only decompressed for the duration of a
+        // call to with_lines, so we visit the lines a chunk at a time
+        // rather than a logical line at a time.  A chunk is always large
+        // enough to hold a logical line limited by MAX_LOGICAL_LINE_LEN.
+        const CHUNK_LINES: usize = 4 * MAX_LOGICAL_LINE_LEN;
+
         // Look backwards to find the start of the first logical line
-        let mut back_len = 0;
-        while phys_range.start > 0 {
-            let prior = &self.lines[phys_range.start - 1];
-            if !prior.last_cell_was_wrapped() {
-                break;
-            }
-            if prior.len() + back_len > MAX_LOGICAL_LINE_LEN {
-                break;
+        let back_range = phys_range.start.saturating_sub(MAX_LOGICAL_LINE_LEN)..phys_range.start;
+        self.lines.with_lines(back_range, |lines| {
+            let mut back_len = 0;
+            for prior in lines.iter().rev() {
+                if !prior.last_cell_was_wrapped() {
+                    break;
+                }
+                if prior.len() + back_len > MAX_LOGICAL_LINE_LEN {
+                    break;
+                }
+                back_len += prior.len();
+                phys_range.start -= 1
             }
-            back_len += prior.len();
-            phys_range.start -= 1
-        }
+        });
 
         let mut phys_row = phys_range.start;
-        let mut line_vec: Vec<&Line> = vec![];
-        while phys_row < phys_range.end {
-            // Look forwards until we find the end of this logical line
-            let mut total_len = 0;
-            let mut end_inclusive = phys_row;
-            line_vec.clear();
-
-            for idx in phys_row.. {
-                if let Some(line) = self.lines.get(idx) {
-                    if total_len > 0 && total_len + line.len() > MAX_LOGICAL_LINE_LEN {
-                        break;
-                    }
-                    end_inclusive = idx;
-                    total_len += line.len();
-                    line_vec.push(line);
-                    if !line.last_cell_was_wrapped() {
-                        break;
+        let mut done = false;
+        while phys_row < phys_range.end && !done {
+            let chunk = phys_row..phys_row + CHUNK_LINES;
+            phys_row = self.lines.with_lines(chunk.clone(), |lines| {
+                let mut phys_row = chunk.start;
+                let mut line_vec: Vec<&Line> = vec![];
+                while phys_row < phys_range.end {
+                    // Look forwards until we find the end of this logical line
+                    let mut total_len = 0;
+                    let mut end_inclusive = phys_row;
+                    line_vec.clear();
+
+                    for idx in phys_row.. {
+                        if let Some(line) = lines.get(idx - chunk.start) {
+                            if total_len > 0 && total_len + line.len() > MAX_LOGICAL_LINE_LEN {
+                                break;
+                            }
+                            end_inclusive = idx;
+                            total_len += line.len();
+                            line_vec.push(line);
+                            if !line.last_cell_was_wrapped() {
+                                break;
+                            }
+                        } else if idx == phys_row {
+                            // Either no more rows exist, or we have
+                            // reached the end of the chunk
+                            done = lines.len() < CHUNK_LINES;
+                            return phys_row;
+                        } else if lines.len() == CHUNK_LINES && phys_row > chunk.start {
+                            // The logical line continues beyond the chunk;
+                            // start the next chunk from its first row
+                            return phys_row;
+                        } else {
+                            break;
+                        }
                     }
-                } else if idx == phys_row {
-                    // No more rows exist
-                    return;
-                } else {
-                    break;
-                }
-            }
 
-            let logical_stable_range = self.phys_to_stable_row_index(phys_row)
-                ..self.phys_to_stable_row_index(end_inclusive + 1);
+                    let logical_stable_range = self.phys_to_stable_row_index(phys_row)
+                        ..self.phys_to_stable_row_index(end_inclusive + 1);
 
-            phys_row = end_inclusive + 1;
+                    phys_row = end_inclusive + 1;
 
-            if logical_stable_range.end < stable_range.start {
-                continue;
-            }
-            if logical_stable_range.start > stable_range.end {
-                break;
-            }
+                    if logical_stable_range.end < stable_range.start {
+                        continue;
+                    }
+                    if logical_stable_range.start > stable_range.end {
+                        done = true;
+                        break;
+                    }
 
-            let continue_iteration = f(logical_stable_range, &line_vec);
+                    let continue_iteration = f(logical_stable_range, &line_vec);
 
-            if !continue_iteration {
-                break;
-            }
+                    if !continue_iteration {
+                        done = true;
+                        break;
+                    }
+                }
+                phys_row
+            });
         }
     }
 }
-
-fn phys_intersection(r1: &Range<PhysRowIndex>, r2: &Range<PhysRowIndex>) -> Range<PhysRowIndex> {
-    let start = r1.start.max(r2.start);
-    let end = r1.end.min(r2.end);
-    if end > start {
-        start..end
-    } else {
-        0..0
-    }
-}
@@ -0,0 +1,658 @@
+//! Storage for the lines of a screen and its scrollback.
+//!
+//! The oldest lines can be moved into blocks of `BLOCK_LINES` lines
+//! that are serialized and compressed, so that very long histories
+//! occupy a fraction of the memory that they would otherwise need.
+//! Reading lines through a shared reference decompresses them into
+//! a temporary buffer.  Accessing them through a mutable reference
+//! keeps the block decompressed, up to `MAX_THAWED_BLOCKS` blocks,
+//! beyond which the least recently used blocks are compressed again.
+//! Lines are addressed by their PhysRowIndex regardless of whether
+//! they are compressed.
+use std::collections::VecDeque;
+use std::ops::Range;
+use wezterm_surface::Line;
+
+/// The number of lines in each compressed block
+pub const BLOCK_LINES: usize = 256;
+
+/// The number of compressed blocks that may be held decompressed
+/// after they have been accessed
+const MAX_THAWED_BLOCKS: usize = 16;
+
+#[derive(Clone)]
+struct Block {
+    /// The compressed lines, or None if the lines could not be
+    /// compressed, in which case `lines` is always populated
+    data: Option<Box<[u8]>>,
+    /// The decompressed lines, including removed lines
+    lines: Option<Vec<Line>>,
+    /// The number of lines that have been removed from the start
+    /// of the block
+    start: usize,
+    /// The number of lines in the block, including removed lines
+    total: usize,
+    /// Set when `lines` may have been changed since `data` was
+    /// produced
+    modified: bool,
+    /// The value of the store's clock when the block was last accessed
+    last_used: u64,
+}
+
+impl Block {
+    fn new(lines: Vec<Line>) -> Self {
+        let total = lines.len();
+        let (data, lines) = match compress(&lines) {
+            Some(data) => (Some(data), None),
+            None => (None, Some(lines)),
+        };
+        Self {
+            data,
+            lines,
+            start: 0,
+            total,
+            modified: false,
+            last_used: 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.total - self.start
+    }
+
+    fn is_compressed(&self) -> bool {
+        self.data.is_some()
+    }
+
+    fn is_thawed(&self) -> bool {
+        self.is_compressed() && self.lines.is_some()
+    }
+
+    /// Decompresses all of the lines of the block, including removed
+    /// lines.  Should that fail, the lines are replaced by a notice
+    /// that they were lost and the block is marked as modified so
+    /// that the notice is what gets compressed next time.
+    fn decompress(&self) -> (Vec<Line>, bool) {
+        match decompress(self.data.as_deref().unwrap_or_default(), self.total) {
+            Ok(lines) => (lines, false),
+            Err(err) => {
+                log::error!(
+                    "failed to decompress {} lines of scrollback: {:#}",
+                    self.total,
+                    err
+                );
+                (lost_lines(self.total), true)
+            }
+        }
+    }
+
+    /// Decompresses the lines, returning true if they were not
+    /// already decompressed
+    fn thaw(&mut self) -> bool {
+        if self.lines.is_some() {
+            return false;
+        }
+        let (lines, lost) = self.decompress();
+        self.lines = Some(lines);
+        self.modified |= lost;
+        true
+    }
+
+    /// The lines of a thawed or uncompressed block
+    fn lines(&self) -> &[Line] {
+        &self.lines.as_ref().expect("block is thawed")[self.start..]
+    }
+
+    fn lines_mut(&mut self) -> &mut [Line] {
+        self.modified = true;
+        let start = self.start;
+        &mut self.lines.as_mut().expect("block is thawed")[start..]
+    }
+
+    /// Discards the decompressed lines, compressing them again if
+    /// they may have been changed.  Returns true if the block was
+    /// thawed.
+    fn freeze(&mut self) -> bool {
+        if !self.is_thawed() {
+            return false;
+        }
+        let lines = self.lines.take().expect("block is thawed");
+        if self.modified {
+            let last_used = self.last_used;
+            *self = Self::new(lines.into_iter().skip(self.start).collect());
+            self.last_used = last_used;
+        }
+        true
+    }
+
+    fn into_lines(self) -> Vec<Line> {
+        let start = self.start;
+        let lines = match self.lines {
+            Some(lines) => lines,
+            None => self.decompress().0,
+        };
+        lines.into_iter().skip(start).collect()
+    }
+}
+
+impl std::fmt::Debug for Block {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fmt.debug_struct("Block")
+            .field("len", &self.len())
+            .field(
+                "compressed_bytes",
+                &self.data.as_ref().map(|data| data.len()),
+            )
+            .field("thawed", &self.is_thawed())
+            .finish()
+    }
+}
+
+/// Holds the lines of a Screen, oldest first.  Every block other
+/// than the first holds exactly BLOCK_LINES lines, which allows
+/// a line to be located in constant time.
+#[derive(Debug, Clone)]
+pub struct LineStore {
+    blocks: VecDeque<Block>,
+    /// The number of lines held in `blocks`
+    blocks_len: usize,
+    /// The most recent lines, which are never compressed
+    recent: VecDeque<Line>,
+    /// Advanced each time that compressed blocks are accessed
+    clock: u64,
+    /// The number of compressed blocks that are decompressed
+    thawed: usize,
+}
+
+impl LineStore {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            blocks: VecDeque::new(),
+            blocks_len: 0,
+            recent: VecDeque::with_capacity(capacity),
+            clock: 0,
+            thawed: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.blocks_len + self.recent.len()
+    }
+
+    /// The number of lines that are held in compressed blocks
+    #[cfg(test)]
+    pub fn compressed_len(&self) -> usize {
+        self.blocks
+            .iter()
+            .filter(|block| block.is_compressed())
+            .map(Block::len)
+            .sum()
+    }
+
+    /// The number of compressed blocks that are held decompressed
+    #[cfg(test)]
+    pub fn thawed_blocks(&self) -> usize {
+        self.blocks.iter().filter(|block| block.is_thawed()).count()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.blocks_len + self.recent.capacity()
+    }
+
+    /// Reserves space for `additional` more uncompressed lines
+    pub fn reserve(&mut self, additional: usize) {
+        self.recent.reserve(additional);
+    }
+
+    /// Returns the index of the block holding line `idx`, along with
+    /// the index of the line within the block
+    fn locate(&self, idx: usize) -> (usize, usize) {
+        let front_len = self.blocks[0].len();
+        if idx < front_len {
+            (0, idx)
+        } else {
+            let idx = idx - front_len;
+            (1 + idx / BLOCK_LINES, idx % BLOCK_LINES)
+        }
+    }
+
+    /// The index of the first line held by the block at `block_idx`
+    fn block_start(&self, block_idx: usize) -> usize {
+        if block_idx == 0 {
+            0
+        } else {
+            self.blocks[0].len() + (block_idx - 1) * BLOCK_LINES
+        }
+    }
+
+    /// The range of blocks holding the lines in `range`, which
+    /// must be non-empty and lie within the blocks
+    fn block_range(&self, range: &Range<usize>) -> Range<usize> {
+        self.locate(range.start).0..self.locate(range.end - 1).0 + 1
+    }
+
+    /// Decompresses the blocks in `block_range`, first compressing the
+    /// least recently used of the other blocks again so that no more
+    /// than MAX_THAWED_BLOCKS remain decompressed.  When the range
+    /// itself spans more blocks than that, they are all decompressed
+    /// and the excess is compressed again by the next access.
+    fn thaw_blocks(&mut self, block_range: Range<usize>) {
+        self.clock += 1;
+        let now = self.clock;
+        let mut needed = 0;
+        for block in self.blocks.range_mut(block_range.clone()) {
+            block.last_used = now;
+            if block.lines.is_none() {
+                needed += 1;
+            }
+        }
+        self.evict(MAX_THAWED_BLOCKS.saturating_sub(needed), now);
+        for block in self.blocks.range_mut(block_range) {
+            if block.thaw() {
+                self.thawed += 1;
+            }
+        }
+    }
+
+    /// Compresses the least recently used thawed blocks, other than
+    /// those used at or after `now`, until no more than `limit` remain
+    fn evict(&mut self, limit: usize, now: u64) {
+        if self.thawed <= limit {
+            return;
+        }
+        let mut thawed: Vec<(u64, usize)> = self
+            .blocks
+            .iter()
+            .enumerate()
+            .filter(|(_, block)| block.is_thawed() && block.last_used < now)
+            .map(|(idx, block)| (block.last_used, idx))
+            .collect();
+        thawed.sort_unstable();
+        let excess = self.thawed - limit;
+        for (_, idx) in thawed.into_iter().take(excess) {
+            if self.blocks[idx].freeze() {
+                self.thawed -= 1;
+            }
+        }
+    }
+
+    /// Returns the line at `idx`, keeping its block decompressed
+    pub fn get(&mut self, idx: usize) -> Option<&Line> {
+        if idx < self.blocks_len {
+            let (block_idx, line_idx) = self.locate(idx);
+            self.thaw_blocks(block_idx..block_idx + 1);
+            self.blocks[block_idx].lines().get(line_idx)
+        } else {
+            self.recent.get(idx - self.blocks_len)
+        }
+    }
+
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut Line> {
+        if idx < self.blocks_len {
+            let (block_idx, line_idx) = self.locate(idx);
+            self.thaw_blocks(block_idx..block_idx + 1);
+            self.blocks[block_idx].lines_mut().get_mut(line_idx)
+        } else {
+            self.recent.get_mut(idx - self.blocks_len)
+        }
+    }
+
+    pub fn back(&mut self) -> Option<&Line> {
+        if self.recent.is_empty() {
+            let idx = self.len().checked_sub(1)?;
+            self.get(idx)
+        } else {
+            self.recent.back()
+        }
+    }
+
+    pub fn push_back(&mut self, line: Line) {
+        self.recent.push_back(line);
+    }
+
+    pub fn pop_back(&mut self) -> Option<Line> {
+        if self.recent.is_empty() && !self.blocks.is_empty() {
+            self.thaw_from(self.blocks.len() - 1);
+        }
+        self.recent.pop_back()
+    }
+
+    pub fn push_front(&mut self, line: Line) {
+        if !self.blocks.is_empty() {
+            self.thaw_from(0);
+        }
+        self.recent.push_front(line);
+    }
+
+    /// Removes the oldest line without decompressing it
+    pub fn pop_front(&mut self) {
+        match self.blocks.front_mut() {
+            Some(front) => {
+                front.start += 1;
+                self.blocks_len -= 1;
+                if front.len() == 0 {
+                    if front.is_thawed() {
+                        self.thawed -= 1;
+                    }
+                    self.blocks.pop_front();
+                }
+            }
+            None => {
+                self.recent.pop_front();
+            }
+        }
+    }
+
+    pub fn remove(&mut self, idx: usize) -> Option<Line> {
+        if idx >= self.blocks_len {
+            return self.recent.remove(idx - self.blocks_len);
+        }
+        if idx == 0 {
+            let line = self.get(0).cloned();
+            self.pop_front();
+            return line;
+        }
+        let (block_idx, _) = self.locate(idx);
+        self.thaw_from(block_idx);
+        self.recent.remove(idx - self.blocks_len)
+    }
+
+    /// Removes the line at `idx`, avoiding decompressing it if
+    /// it is the oldest line
+    pub fn discard(&mut self, idx: usize) {
+        if idx == 0 {
+            self.pop_front();
+        } else {
+            self.remove(idx);
+        }
+    }
+
+    pub fn insert(&mut self, idx: usize, line: Line) {
+        if idx < self.blocks_len {
+            let (block_idx, _) = self.locate(idx);
+            self.thaw_from(block_idx);
+        }
+        self.recent.insert(idx - self.blocks_len, line);
+    }
+
+    /// Moves the blocks from `block_idx` onwards back into the
+    /// uncompressed lines, so that lines can be inserted or
+    /// removed amongst them
+    fn thaw_from(&mut self, block_idx: usize) {
+        let blocks: Vec<Block> = self.blocks.drain(block_idx..).collect();
+        for block in blocks.into_iter().rev() {
+            if block.is_thawed() {
+                self.thawed -= 1;
+            }
+            self.blocks_len -= block.len();
+            for line in block.into_lines().into_iter().rev() {
+                self.recent.push_front(line);
+            }
+        }
+    }
+
+    /// Compresses the lines that are more than `keep` lines from the
+    /// end, and compresses again those blocks that were decompressed
+    /// but have not been accessed recently.  Any of the last `keep`
+    /// lines that are held in blocks, which can happen when the
+    /// screen is resized taller, are moved back out of them.
+    pub fn compress(&mut self, keep: usize) {
+        if self.blocks_len > self.len().saturating_sub(keep) {
+            let (block_idx, _) = self.locate(self.len().saturating_sub(keep));
+            self.thaw_from(block_idx);
+        }
+
+        while self.recent.len() >= keep.saturating_add(BLOCK_LINES) {
+            let lines: Vec<Line> = self.recent.drain(..BLOCK_LINES).collect();
+            self.blocks.push_back(Block::new(lines));
+            self.blocks_len += BLOCK_LINES;
+        }
+
+        self.evict(MAX_THAWED_BLOCKS, u64::MAX);
+    }
+
+    /// Removes all of the lines, returning an iterator that
+    /// decompresses them one block at a time
+    pub fn take_all(&mut self) -> impl Iterator<Item = Line> {
+        let blocks = std::mem::take(&mut self.blocks);
+        let recent = std::mem::take(&mut self.recent);
+        self.blocks_len = 0;
+        self.thawed = 0;
+        blocks.into_iter().flat_map(Block::into_lines).chain(recent)
+    }
+
+    /// Calls `f` with the lines in `range`, which is clamped to the
+    /// available lines.  Compressed lines are decompressed into a
+    /// temporary buffer for the duration of the call.
+    pub fn with_lines<F, R>(&self, range: Range<usize>, f: F) -> R
+    where
+        F: FnOnce(&[&Line]) -> R,
+    {
+        let range = range.start.min(self.len())..range.end.min(self.len());
+        if range.is_empty() {
+            return f(&[]);
+        }
+
+        let mut decompressed = vec![];
+        if range.start < self.blocks_len {
+            let block_range = self.block_range(&(range.start..range.end.min(self.blocks_len)));
+            for block in self.blocks.range(block_range) {
+                if block.lines.is_none() {
+                    decompressed.push(block.decompress().0);
+                }
+            }
+        }
+
+        let mut lines = Vec::with_capacity(range.end - range.start);
+        if range.start < self.blocks_len {
+            let block_range = self.block_range(&(range.start..range.end.min(self.blocks_len)));
+            let mut idx = self.block_start(block_range.start);
+            let mut decompressed = decompressed.iter();
+            for block in self.blocks.range(block_range) {
+                let block_lines = match &block.lines {
+                    Some(_) => block.lines(),
+                    None => &decompressed.next().expect("block was decompressed")[block.start..],
+                };
+                for line in block_lines {
+                    if range.contains(&idx) {
+                        lines.push(line);
+                    }
+                    idx += 1;
+                }
+            }
+        }
+
+        let recent_start = range.start.max(self.blocks_len) - self.blocks_len;
+        let recent_end = range.end.max(self.blocks_len) - self.blocks_len;
+        lines.extend(
+            self.recent
+                .iter()
+                .skip(recent_start)
+                .take(recent_end - recent_start),
+        );
+        f(&lines)
+    }
+
+    /// Returns the lines in `range`, which is clamped to the
+    /// available lines, keeping their blocks decompressed
+    pub fn range_mut(&mut self, range: Range<usize>) -> Vec<&mut Line> {
+        let range = range.start.min(self.len())..range.end.min(self.len());
+        let mut lines = Vec::with_capacity(range.end.saturating_sub(range.start));
+        if range.is_empty() {
+            return lines;
+        }
+
+        let recent_start = range.start.max(self.blocks_len) - self.blocks_len;
+        let recent_end = range.end.max(self.blocks_len) - self.blocks_len;
+
+        if range.start < self.blocks_len {
+            let block_range = self.block_range(&(range.start..range.end.min(self.blocks_len)));
+            self.thaw_blocks(block_range.clone());
+            let mut idx = self.block_start(block_range.start);
+            for block in self.blocks.range_mut(block_range) {
+                for line in block.lines_mut() {
+                    if range.contains(&idx) {
+                        lines.push(line);
+                    }
+                    idx += 1;
+                }
+            }
+        }
+
+        lines.extend(
+            self.recent
+                .iter_mut()
+                .skip(recent_start)
+                .take(recent_end - recent_start),
+        );
+        lines
+    }
+
+    /// Calls `f` with each line and its index.  Compressed blocks
+    /// are decompressed one at a time into a temporary buffer, so
+    /// that the whole of the scrollback is never decompressed at once.
+    pub fn for_each<F>(&self, mut f: F)
+    where
+        F: FnMut(usize, &Line),
+    {
+        let mut idx = 0;
+        for block in &self.blocks {
+            let decompressed;
+            let lines = match &block.lines {
+                Some(_) => block.lines(),
+                None => {
+                    decompressed = block.decompress().0;
+                    &decompressed[block.start..]
+                }
+            };
+            for line in lines {
+                f(idx, line);
+                idx += 1;
+            }
+        }
+        for line in &self.recent {
+            f(idx, line);
+            idx += 1;
+        }
+    }
+
+    /// Calls `f` with each line and its index.  Compressed blocks
+    /// are decompressed one at a time and compressed again after `f`
+    /// has been called for their lines.
+    pub fn for_each_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut Line),
+    {
+        let mut idx = 0;
+        for block in self.blocks.iter_mut() {
+            let thawed_here = block.thaw();
+            for line in block.lines_mut() {
+                f(idx, line);
+                idx += 1;
+            }
+            if thawed_here {
+                block.freeze();
+            }
+        }
+        for line in self.recent.iter_mut() {
+            f(idx, line);
+            idx += 1;
+        }
+    }
+
+    /// Calls `f` with each line that is not compressed, and its index.
+    /// Lines that hold images or image placeholders are never compressed.
+    pub fn for_each_uncompressed_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut Line),
+    {
+        let mut idx = 0;
+        for block in self.blocks.iter_mut() {
+            if block.is_compressed() {
+                idx += block.len();
+                continue;
+            }
+            for line in block.lines_mut() {
+                f(idx, line);
+                idx += 1;
+            }
+        }
+        for line in self.recent.iter_mut() {
+            f(idx, line);
+            idx += 1;
+        }
+    }
+}
+
+/// Lines that hold images are not compressed, as the image data is
+/// shared with other lines and with the image cache.  Lines with image
+/// placeholders are left alone too, as their images are resolved in place.
+#[cfg(feature = "use_serde")]
+fn can_compress(line: &Line) -> bool {
+    line.visible_cells()
+        .all(|cell| cell.attrs().images().is_none())
+        && line.image_placeholders().is_empty()
+}
+
+#[cfg(feature = "use_serde")]
+fn compress(lines: &[Line]) -> Option<Box<[u8]>> {
+    use serde::Serialize;
+
+    if !lines.iter().all(can_compress) {
+        return None;
+    }
+    let mut data = vec![];
+    let mut encode = varbincode::Serializer::new(&mut data);
+    if let Err(err) = lines.serialize(&mut encode) {
+        log::error!("failed to compress scrollback: {:#}", err);
+        return None;
+    }
+    Some(miniz_oxide::deflate::compress_to_vec(&data, 1).into_boxed_slice())
+}
+
+#[cfg(not(feature = "use_serde"))]
+fn compress(_lines: &[Line]) -> Option<Box<[u8]>> {
+    None
+}
+
+#[cfg(feature = "use_serde")]
+fn decompress(data: &[u8], num_lines: usize) -> anyhow::Result<Vec<Line>> {
+    use serde::Deserialize;
+
+    let data = miniz_oxide::inflate::decompress_to_vec(data)
+        .map_err(|e| anyhow::anyhow!("inflating: {:?}", e))?;
+    let mut reader = data.as_slice();
+    let mut decode = varbincode::Deserializer::new(&mut reader);
+    let lines = Vec::<Line>::deserialize(&mut decode)?;
+    anyhow::ensure!(
+        lines.len() == num_lines,
+        "found {} lines, expected {}",
+        lines.len(),
+        num_lines
+    );
+    Ok(lines)
+}
+
+#[cfg(not(feature = "use_serde"))]
+fn decompress(_data: &[u8], _num_lines: usize) -> anyhow::Result<Vec<Line>> {
+    anyhow::bail!("compressed scrollback requires the use_serde feature")
+}
+
+/// Stands in for lines that could not be decompressed, with a
+/// notice in the first of them so that the loss is visible
+fn lost_lines(num_lines: usize) -> Vec<Line> {
+    (0..num_lines)
+        .map(|idx| {
+            if idx == 0 {
+                Line::from_text(
+                    "[scrollback lost: failed to decompress]",
+                    &Default::default(),
+                    0,
+                    None,
+                )
+            } else {
+                Line::new(0)
+            }
+        })
+        .collect()
+}
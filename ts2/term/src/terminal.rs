@@ -1,7 +1,12 @@
 use super::*;
 use crate::terminalstate::performer::Performer;
 use std::sync::Arc;
+use wezterm_escape_parser::osc::{
+    KittyClipboard, KittyClipboardStatus, KittyClipboardType, KittyNotification,
+    KittyNotificationPayload,
+};
 use wezterm_escape_parser::parser::Parser;
+use wezterm_escape_parser::OperatingSystemCommand;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "use_serde", derive(Serialize, Deserialize))]
@@ -10,12 +15,135 @@ pub enum ClipboardSelection {
     PrimarySelection,
 }
 
+/// The mime type that is used to list the types that are available
+/// in the clipboard, in the kitty clipboard protocol
+pub const CLIPBOARD_MIME_TYPES_QUERY: &str = ".";
+
+/// Clipboard contents in a particular format, as transferred by the
+/// kitty clipboard protocol (OSC 5522)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "use_serde", derive(Serialize, Deserialize))]
+pub struct ClipboardData {
+    pub mime_type: String,
+    pub data: Vec<u8>,
+}
+
+impl ClipboardData {
+    pub fn is_text(&self) -> bool {
+        is_text_mime_type(&self.mime_type)
+    }
+}
+
+/// Returns true if the mime type refers to plain text
+pub fn is_text_mime_type(mime_type: &str) -> bool {
+    mime_type == "text/plain" || mime_type.starts_with("text/plain;")
+}
+
+/// Why a request to read the clipboard failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipboardReadError {
+    /// The user denied access to the clipboard
+    PermissionDenied,
+    /// Reading the clipboard is not possible
+    NotSupported,
+}
+
+/// A request by the application to read the clipboard, using the
+/// kitty clipboard protocol (OSC 5522).
+/// The response is sent to the application asynchronously, once the
+/// data has been read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "use_serde", derive(Serialize, Deserialize))]
+pub struct ClipboardReadRequest {
+    pub selection: ClipboardSelection,
+    /// The mime types to read. CLIPBOARD_MIME_TYPES_QUERY asks for
+    /// the list of types that are available.
+    pub mime_types: Vec<String>,
+    /// Echoed back to the application in the response
+    pub id: Option<String>,
+    /// Identifies the application to the user
+    pub name: Option<String>,
+}
+
+impl ClipboardReadRequest {
+    /// The largest amount of data sent in a single response chunk
+    const CHUNK_SIZE: usize = 3072;
+
+    fn response_chunk(
+        &self,
+        status: KittyClipboardStatus,
+        mime: Option<&str>,
+        payload: &[u8],
+    ) -> String {
+        OperatingSystemCommand::KittyClipboard(KittyClipboard {
+            kind: KittyClipboardType::Read,
+            id: self.id.clone(),
+            mime: mime.map(|m| m.to_string()),
+            primary: self.selection == ClipboardSelection::PrimarySelection,
+            status: Some(status),
+            payload: payload.to_vec(),
+            ..Default::default()
+        })
+        .to_string()
+    }
+
+    /// Returns the escape sequences that send `data` to the application.
+    /// The list of available types is sent as an entry whose mime type
+    /// is CLIPBOARD_MIME_TYPES_QUERY and whose data is the space
+    /// separated list of types.
+    pub fn response(&self, data: &[ClipboardData]) -> String {
+        let mut response = self.response_chunk(KittyClipboardStatus::Ok, None, b"");
+        for item in data {
+            for chunk in item.data.chunks(Self::CHUNK_SIZE) {
+                response.push_str(&self.response_chunk(
+                    KittyClipboardStatus::Data,
+                    Some(&item.mime_type),
+                    chunk,
+                ));
+            }
+        }
+        response.push_str(&self.response_chunk(KittyClipboardStatus::Done, None, b""));
+        response
+    }
+
+    /// Returns the escape sequence that reports to the application
+    /// that its request failed
+    pub fn error_response(&self, error: ClipboardReadError) -> String {
+        let status = match error {
+            ClipboardReadError::PermissionDenied => KittyClipboardStatus::PermissionDenied,
+            ClipboardReadError::NotSupported => KittyClipboardStatus::NotSupported,
+        };
+        self.response_chunk(status, None, b"")
+    }
+}
+
 pub trait Clipboard: Send + Sync {
     fn set_contents(
         &self,
         selection: ClipboardSelection,
         data: Option<String>,
     ) -> anyhow::Result<()>;
+
+    /// Sets data in one or more formats.
+    /// Implementations that only support text set the textual entry.
+    fn set_data(
+        &self,
+        selection: ClipboardSelection,
+        data: Vec<ClipboardData>,
+    ) -> anyhow::Result<()> {
+        let text = data
+            .into_iter()
+            .find(|item| item.is_text())
+            .map(|item| String::from_utf8_lossy(&item.data).into_owned());
+        self.set_contents(selection, text)
+    }
+
+    /// Starts reading the clipboard on behalf of the application.
+    /// The implementation is responsible for sending the response to
+    /// the application once the data is available.
+    fn request_data(&self, _request: ClipboardReadRequest) -> anyhow::Result<()> {
+        anyhow::bail!("reading the clipboard is not supported")
+    }
 }
 
 impl Clipboard for Box<dyn Clipboard> {
@@ -26,6 +154,18 @@ impl Clipboard for Box<dyn Clipboard> {
     ) -> anyhow::Result<()> {
         self.as_ref().set_contents(selection, data)
     }
+
+    fn set_data(
+        &self,
+        selection: ClipboardSelection,
+        data: Vec<ClipboardData>,
+    ) -> anyhow::Result<()> {
+        self.as_ref().set_data(selection, data)
+    }
+
+    fn request_data(&self, request: ClipboardReadRequest) -> anyhow::Result<()> {
+        self.as_ref().request_data(request)
+    }
 }
 
 pub trait DeviceControlHandler: Send + Sync {
@@ -42,6 +182,84 @@ pub enum Progress {
     Indeterminate,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "use_serde", derive(Serialize, Deserialize))]
+pub enum NotificationUrgency {
+    Low,
+    #[default]
+    Normal,
+    Critical,
+}
+
+/// When a desktop notification should be shown
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "use_serde", derive(Serialize, Deserialize))]
+pub enum NotificationOccasion {
+    #[default]
+    Always,
+    /// Only if the pane is not focused
+    Unfocused,
+    /// Only if the pane is not visible
+    Invisible,
+}
+
+/// A notification requested by the kitty desktop notification
+/// protocol (OSC 99)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "use_serde", derive(Serialize, Deserialize))]
+pub struct DesktopNotification {
+    /// Identifies the notification to the application
+    pub id: Option<String>,
+    pub title: Option<String>,
+    pub body: String,
+    pub urgency: NotificationUrgency,
+    /// How long the notification should be shown for, in milliseconds.
+    /// None leaves it up to the system.
+    pub timeout: Option<u64>,
+    pub occasion: NotificationOccasion,
+    /// Whether clicking on the notification should focus the pane
+    pub focus: bool,
+    /// Whether clicking on the notification should be reported to
+    /// the application
+    pub report_activation: bool,
+    /// Whether the notification closing should be reported to the
+    /// application
+    pub report_close: bool,
+}
+
+impl DesktopNotification {
+    /// Returns the escape sequence that reports to the application that
+    /// the notification was activated, if it asked for that
+    pub fn activation_report(&self) -> Option<String> {
+        if !self.report_activation {
+            return None;
+        }
+        Some(
+            OperatingSystemCommand::KittyNotification(KittyNotification {
+                id: self.id.clone(),
+                ..Default::default()
+            })
+            .to_string(),
+        )
+    }
+
+    /// Returns the escape sequence that reports to the application that
+    /// the notification was closed, if it asked for that
+    pub fn close_report(&self) -> Option<String> {
+        if !self.report_close {
+            return None;
+        }
+        Some(
+            OperatingSystemCommand::KittyNotification(KittyNotification {
+                id: self.id.clone(),
+                payload_type: KittyNotificationPayload::Close,
+                ..Default::default()
+            })
+            .to_string(),
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "use_serde", derive(Serialize, Deserialize))]
 pub enum Alert {
@@ -71,6 +289,13 @@ pub enum Alert {
     OutputSinceFocusLost,
     /// A change to the progress bar state
     Progress(Progress),
+    /// A notification from the kitty desktop notification protocol
+    DesktopNotification(DesktopNotification),
+    /// The application asked to close the desktop notification with
+    /// this identifier
+    CloseDesktopNotification {
+        id: String,
+    },
 }
 
 pub trait AlertHandler: Send + Sync {
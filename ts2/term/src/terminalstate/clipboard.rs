@@ -0,0 +1,190 @@
+//! Clipboard access with mime types, from the kitty clipboard
+//! protocol (OSC 5522).
+//! <https://sw.kovidgoyal.net/kitty/clipboard/>
+
+use crate::{ClipboardData, ClipboardReadRequest, ClipboardSelection, TerminalState};
+use std::io::Write;
+use wezterm_escape_parser::osc::{KittyClipboard, KittyClipboardStatus, KittyClipboardType};
+use wezterm_escape_parser::OperatingSystemCommand;
+
+/// The largest amount of data, across all mime types, that a single
+/// write may place in the clipboard
+const MAX_CLIPBOARD_WRITE: usize = 64 * 1024 * 1024;
+
+/// A write whose data is still arriving
+#[derive(Debug)]
+pub(crate) struct ClipboardWrite {
+    selection: ClipboardSelection,
+    id: Option<String>,
+    data: Vec<ClipboardData>,
+    /// (alias, mime type) pairs; the alias is offered with the
+    /// data of the mime type
+    aliases: Vec<(String, String)>,
+    size: usize,
+}
+
+impl ClipboardWrite {
+    fn append(&mut self, mime_type: String, payload: &[u8]) -> bool {
+        self.size += payload.len();
+        if self.size > MAX_CLIPBOARD_WRITE {
+            return false;
+        }
+        match self
+            .data
+            .iter_mut()
+            .find(|item| item.mime_type == mime_type)
+        {
+            Some(item) => item.data.extend_from_slice(payload),
+            None => self.data.push(ClipboardData {
+                mime_type,
+                data: payload.to_vec(),
+            }),
+        }
+        true
+    }
+
+    fn into_data(mut self) -> Vec<ClipboardData> {
+        for (alias, mime_type) in &self.aliases {
+            if self.data.iter().any(|item| item.mime_type == *alias) {
+                continue;
+            }
+            let aliased = self
+                .data
+                .iter()
+                .find(|item| item.mime_type == *mime_type)
+                .map(|item| item.data.clone());
+            if let Some(data) = aliased {
+                self.data.push(ClipboardData {
+                    mime_type: alias.clone(),
+                    data,
+                });
+            }
+        }
+        self.data
+    }
+}
+
+impl TerminalState {
+    pub(crate) fn kitty_clipboard(&mut self, clip: KittyClipboard) {
+        let selection = if clip.primary {
+            ClipboardSelection::PrimarySelection
+        } else {
+            ClipboardSelection::Clipboard
+        };
+
+        match clip.kind {
+            KittyClipboardType::Read => {
+                let request = ClipboardReadRequest {
+                    selection,
+                    mime_types: clip.payload_mime_types(),
+                    id: clip.id,
+                    name: clip.name,
+                };
+                if request.mime_types.is_empty() {
+                    self.kitty_clipboard_reply(
+                        KittyClipboardType::Read,
+                        request.id,
+                        clip.primary,
+                        KittyClipboardStatus::Invalid,
+                    );
+                    return;
+                }
+                let result = match self.clipboard.as_ref() {
+                    Some(clipboard) => clipboard.request_data(request.clone()),
+                    None => Err(anyhow::anyhow!("no clipboard")),
+                };
+                if let Err(err) = result {
+                    log::debug!("kitty clipboard read {:?}: {:#}", request, err);
+                    self.kitty_clipboard_reply(
+                        KittyClipboardType::Read,
+                        request.id,
+                        clip.primary,
+                        KittyClipboardStatus::NotSupported,
+                    );
+                }
+            }
+            KittyClipboardType::Write => {
+                self.kitty_clipboard_write.replace(ClipboardWrite {
+                    selection,
+                    id: clip.id,
+                    data: vec![],
+                    aliases: vec![],
+                    size: 0,
+                });
+            }
+            KittyClipboardType::WriteData => {
+                let Some(write) = self.kitty_clipboard_write.as_mut() else {
+                    if self.config.log_unknown_escape_sequences() {
+                        log::warn!("kitty clipboard wdata without a write {:?}", clip.mime);
+                    }
+                    return;
+                };
+                match clip.mime {
+                    Some(mime_type) => {
+                        if !write.append(mime_type, &clip.payload) {
+                            let write = self.kitty_clipboard_write.take().unwrap();
+                            log::warn!("kitty clipboard write is too large; discarding it");
+                            self.kitty_clipboard_reply(
+                                KittyClipboardType::Write,
+                                write.id,
+                                write.selection == ClipboardSelection::PrimarySelection,
+                                KittyClipboardStatus::Io,
+                            );
+                        }
+                    }
+                    // The end of the data
+                    None => self.kitty_clipboard_commit(),
+                }
+            }
+            KittyClipboardType::WriteAlias => {
+                let Some(write) = self.kitty_clipboard_write.as_mut() else {
+                    return;
+                };
+                if let Some(mime_type) = clip.mime {
+                    for alias in clip.payload_mime_types() {
+                        write.aliases.push((alias, mime_type.clone()));
+                    }
+                }
+            }
+        }
+    }
+
+    fn kitty_clipboard_commit(&mut self) {
+        let Some(write) = self.kitty_clipboard_write.take() else {
+            return;
+        };
+        let id = write.id.clone();
+        let primary = write.selection == ClipboardSelection::PrimarySelection;
+        let selection = write.selection;
+
+        let status = match self.clipboard.as_ref() {
+            Some(clipboard) => match clipboard.set_data(selection, write.into_data()) {
+                Ok(()) => KittyClipboardStatus::Done,
+                Err(err) => {
+                    log::error!("failed to set clipboard in response to OSC 5522: {:#}", err);
+                    KittyClipboardStatus::Io
+                }
+            },
+            None => KittyClipboardStatus::NotSupported,
+        };
+        self.kitty_clipboard_reply(KittyClipboardType::Write, id, primary, status);
+    }
+
+    fn kitty_clipboard_reply(
+        &mut self,
+        kind: KittyClipboardType,
+        id: Option<String>,
+        primary: bool,
+        status: KittyClipboardStatus,
+    ) {
+        let response = OperatingSystemCommand::KittyClipboard(KittyClipboard {
+            kind,
+            id,
+            primary,
+            status: Some(status),
+            ..Default::default()
+        });
+        write!(self.writer, "{}", response).ok();
+        self.writer.flush().ok();
+    }
+}
@@ -0,0 +1,141 @@
+//! Command records, built from the OSC 133 semantic prompt sequences
+//! that shell integration emits around each command
+
+use crate::{CommandRecord, StableRowIndex, TerminalState};
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::time::{SystemTime, UNIX_EPOCH};
+use wezterm_cell::SemanticType;
+
+/// The number of command records that are kept for each terminal
+const MAX_COMMAND_RECORDS: usize = 1000;
+
+#[derive(Debug, Default)]
+pub(crate) struct CommandState {
+    /// Oldest first; the last record may still be running
+    records: VecDeque<CommandRecord>,
+    /// The first row of the prompt that is being shown, if any
+    prompt_y: Option<StableRowIndex>,
+    /// Whether the last record is still running
+    running: bool,
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+impl TerminalState {
+    fn cursor_stable_row(&self) -> StableRowIndex {
+        self.screen().visible_row_to_stable_row(self.cursor.y)
+    }
+
+    /// Called when a prompt starts.  `new_prompt` is true for a fresh
+    /// prompt (OSC 133 A or N), and false for other prompt text (OSC 133
+    /// P), which only starts a prompt if none is being shown.  A command
+    /// that is still running is assumed to have finished.
+    pub(crate) fn command_prompt_started(&mut self, new_prompt: bool) {
+        if self.commands.running {
+            self.command_finished(None);
+        }
+        if new_prompt || self.commands.prompt_y.is_none() {
+            self.commands.prompt_y = Some(self.cursor_stable_row());
+        }
+    }
+
+    /// Called when the output of a command starts (OSC 133 C).  The
+    /// command line is taken from the input that follows the prompt.
+    pub(crate) fn command_output_started(&mut self) {
+        if self.commands.running {
+            self.command_finished(None);
+        }
+        let output_start_y = self.cursor_stable_row();
+        let prompt_y = self.commands.prompt_y.take().unwrap_or(output_start_y);
+        let command = self.command_text(prompt_y..output_start_y + 1);
+
+        if self.commands.records.len() == MAX_COMMAND_RECORDS {
+            self.commands.records.pop_front();
+        }
+        self.commands.records.push_back(CommandRecord {
+            command,
+            prompt_y,
+            output_start_y,
+            output_end_y: None,
+            start_time: now_millis(),
+            end_time: None,
+            exit_code: None,
+        });
+        self.commands.running = true;
+    }
+
+    /// Called when the running command finishes, with its exit status
+    /// if it was reported (OSC 133 D), or without when the end of the
+    /// command was only implied (OSC 133 N, or a new prompt).
+    pub(crate) fn command_finished(&mut self, exit_code: Option<i32>) {
+        if !self.commands.running {
+            return;
+        }
+        self.commands.running = false;
+
+        // Output that ends with a newline leaves the cursor at the start
+        // of the following line, which isn't part of the output
+        let cursor_y = self.cursor_stable_row();
+        let end_y = if self.cursor.x == 0 {
+            cursor_y
+        } else {
+            cursor_y + 1
+        };
+        if let Some(record) = self.commands.records.back_mut() {
+            record.output_end_y = Some(end_y.max(record.output_start_y));
+            record.end_time = Some(now_millis());
+            record.exit_code = exit_code;
+        }
+    }
+
+    /// Returns the text of the cells in `rows` that are marked as input
+    fn command_text(&self, rows: Range<StableRowIndex>) -> String {
+        let screen = self.screen();
+        let lines = screen.lines_in_phys_range(screen.stable_range(&rows));
+        let mut command = String::new();
+        for line in &lines {
+            let start_len = command.len();
+            for cell in line.visible_cells() {
+                if cell.attrs().semantic_type() == SemanticType::Input {
+                    command.push_str(cell.str());
+                }
+            }
+            if !line.last_cell_was_wrapped() {
+                let text_len = command.trim_end_matches(' ').len().max(start_len);
+                command.truncate(text_len);
+                if command.len() > start_len {
+                    command.push('\n');
+                }
+            }
+        }
+        command.trim().to_string()
+    }
+
+    /// Moves the recorded rows down by `rows`, after that many rows
+    /// were inserted above them
+    pub(crate) fn shift_command_rows(&mut self, rows: StableRowIndex) {
+        for record in self.commands.records.iter_mut() {
+            record.prompt_y += rows;
+            record.output_start_y += rows;
+            if let Some(end_y) = record.output_end_y.as_mut() {
+                *end_y += rows;
+            }
+        }
+        if let Some(prompt_y) = self.commands.prompt_y.as_mut() {
+            *prompt_y += rows;
+        }
+    }
+
+    /// Returns the commands that have been run in this terminal, oldest
+    /// first.  The rows of the oldest records may have been scrolled
+    /// out of the scrollback.
+    pub fn get_command_records(&self) -> Vec<CommandRecord> {
+        self.commands.records.iter().cloned().collect()
+    }
+}
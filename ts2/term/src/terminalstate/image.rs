@@ -12,8 +12,24 @@ use wezterm_surface::TextureCoordinate;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PlacementInfo {
     pub first_row: StableRowIndex,
+    pub first_col: usize,
     pub rows: usize,
     pub cols: usize,
+    pub z_index: i32,
+}
+
+impl PlacementInfo {
+    pub fn covers_row(&self, row: StableRowIndex) -> bool {
+        row >= self.first_row && row < self.first_row + self.rows as StableRowIndex
+    }
+
+    pub fn covers_column(&self, col: usize) -> bool {
+        col >= self.first_col && col < self.first_col + self.cols
+    }
+
+    pub fn covers_cell(&self, col: usize, row: StableRowIndex) -> bool {
+        self.covers_column(col) && self.covers_row(row)
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -248,8 +264,10 @@ impl TerminalState {
 
         Ok(PlacementInfo {
             first_row,
+            first_col: cursor_x,
             rows: height_in_cells,
             cols: width_in_cells,
+            z_index: params.z_index,
         })
     }
 
@@ -16,6 +16,7 @@ use wezterm_escape_parser::apc::{
     KittyImageTransmit, KittyImageVerbosity,
 };
 use wezterm_surface::change::ImageData;
+use wezterm_surface::line::VirtualPlacement;
 
 #[derive(Debug, Default)]
 pub struct KittyImageState {
@@ -24,10 +25,19 @@ pub struct KittyImageState {
     number_to_id: HashMap<u32, u32>,
     id_to_data: HashMap<u32, Arc<ImageData>>,
     placements: HashMap<(u32, Option<u32>), PlacementInfo>,
+    virtual_placements: HashMap<(u32, Option<u32>), VirtualPlacement>,
     used_memory: usize,
 }
 
 impl KittyImageState {
+    /// Moves the placements down by `rows`, after that many rows were
+    /// inserted above them
+    pub(crate) fn shift_placements(&mut self, rows: StableRowIndex) {
+        for info in self.placements.values_mut() {
+            info.first_row += rows;
+        }
+    }
+
     fn remove_data_for_id(&mut self, image_id: u32) {
         if let Some(data) = self.id_to_data.remove(&image_id) {
             self.used_memory = self.used_memory.saturating_sub(data.len());
@@ -46,7 +56,12 @@ impl KittyImageState {
     fn prune_unreferenced(&mut self) {
         let budget = 320 * 1024 * 1024; // FIXME: make this configurable
         if self.used_memory > budget {
-            let referenced: HashSet<u32> = self.placements.keys().map(|(k, _)| *k).collect();
+            let referenced: HashSet<u32> = self
+                .placements
+                .keys()
+                .chain(self.virtual_placements.keys())
+                .map(|(k, _)| *k)
+                .collect();
             let target = self.used_memory - budget;
             let mut freed = 0;
             self.id_to_data.retain(|id, data| {
@@ -66,6 +81,36 @@ impl KittyImageState {
             self.used_memory = self.used_memory.saturating_sub(freed);
         }
     }
+
+    /// Returns true if the image has any placements, including
+    /// virtual placements
+    fn is_placed(&self, image_id: u32) -> bool {
+        self.placements
+            .keys()
+            .chain(self.virtual_placements.keys())
+            .any(|(id, _)| *id == image_id)
+    }
+
+    /// Returns the virtual placement that is shown by placeholders for
+    /// the image and placement id.  Placeholders without a placement id
+    /// show any virtual placement of the image.
+    fn virtual_placement(
+        &self,
+        image_id: u32,
+        placement_id: Option<u32>,
+    ) -> Option<&VirtualPlacement> {
+        if let Some(placement) = self.virtual_placements.get(&(image_id, placement_id)) {
+            return Some(placement);
+        }
+        if placement_id.is_some() {
+            return None;
+        }
+        self.virtual_placements
+            .iter()
+            .filter(|((id, _), _)| *id == image_id)
+            .min_by_key(|((_, placement_id), _)| *placement_id)
+            .map(|(_, placement)| placement)
+    }
 }
 
 impl TerminalState {
@@ -113,6 +158,16 @@ impl TerminalState {
 
         let (image_width, image_height) = img.data().dimensions()?;
 
+        if placement.virtual_placement {
+            return self.kitty_img_place_virtual(
+                image_id,
+                img,
+                image_width,
+                image_height,
+                placement,
+            );
+        }
+
         let info = self.assign_image_to_cells(ImageAttachParams {
             image_width,
             image_height,
@@ -145,6 +200,127 @@ impl TerminalState {
         Ok(())
     }
 
+    /// Record a virtual placement, which is displayed wherever the
+    /// application prints placeholder cells for it
+    fn kitty_img_place_virtual(
+        &mut self,
+        image_id: u32,
+        data: Arc<ImageData>,
+        image_width: u32,
+        image_height: u32,
+        placement: KittyImagePlacement,
+    ) -> anyhow::Result<()> {
+        let (cell_width, cell_height) = self.kitty_cell_pixel_dims();
+        let source_x = placement.x.unwrap_or(0).min(image_width);
+        let source_y = placement.y.unwrap_or(0).min(image_height);
+        let source_width = placement
+            .w
+            .unwrap_or(image_width)
+            .min(image_width - source_x);
+        let source_height = placement
+            .h
+            .unwrap_or(image_height)
+            .min(image_height - source_y);
+        if source_width == 0 || source_height == 0 {
+            anyhow::bail!("virtual placement of image {} is empty", image_id);
+        }
+
+        // Without an explicit size, the placement covers as many cells
+        // as the image does at its natural size
+        let cells = |pixels: usize, cell: usize| pixels.div_ceil(cell).max(1) as u32;
+        let (source_width_px, source_height_px) = (source_width as usize, source_height as usize);
+        let (columns, rows) = match (placement.columns, placement.rows) {
+            (Some(columns), Some(rows)) => (columns, rows),
+            (Some(columns), None) => (
+                columns,
+                cells(
+                    columns as usize * cell_width * source_height_px / source_width_px,
+                    cell_height,
+                ),
+            ),
+            (None, Some(rows)) => (
+                cells(
+                    rows as usize * cell_height * source_width_px / source_height_px,
+                    cell_width,
+                ),
+                rows,
+            ),
+            (None, None) => (
+                cells(source_width_px, cell_width),
+                cells(source_height_px, cell_height),
+            ),
+        };
+
+        self.kitty_img.virtual_placements.insert(
+            (image_id, placement.placement_id),
+            VirtualPlacement {
+                data,
+                image_width,
+                image_height,
+                source: (source_x, source_y, source_width, source_height),
+                columns,
+                rows,
+                z_index: placement.z_index.unwrap_or(0),
+            },
+        );
+        log::trace!(
+            "record virtual placement for {} {:?}: {}x{} cells",
+            image_id,
+            placement.placement_id,
+            columns,
+            rows
+        );
+        self.kitty_resolve_all_placeholders();
+
+        Ok(())
+    }
+
+    /// Returns the size of a cell in pixels
+    fn kitty_cell_pixel_dims(&self) -> (usize, usize) {
+        let screen = self.screen();
+        (
+            (self.pixel_width / screen.physical_cols.max(1)).max(1),
+            (self.pixel_height / screen.physical_rows.max(1)).max(1),
+        )
+    }
+
+    /// Update the images shown by the placeholder cells in `rows`,
+    /// which have just been printed
+    pub(crate) fn kitty_resolve_placeholders(&mut self, rows: &[StableRowIndex]) {
+        if self.kitty_img.virtual_placements.is_empty() {
+            return;
+        }
+        let (cell_width, cell_height) = self.kitty_cell_pixel_dims();
+        let seqno = self.seqno;
+        let kitty_img = &self.kitty_img;
+        for row in rows {
+            if let Some(phys) = self.screen.stable_row_to_phys(*row) {
+                self.screen.line_mut(phys).resolve_image_placeholders(
+                    cell_width,
+                    cell_height,
+                    |image_id, placement_id| kitty_img.virtual_placement(image_id, placement_id),
+                    seqno,
+                );
+            }
+        }
+    }
+
+    /// Update the images shown by all placeholder cells, after the
+    /// virtual placements have changed
+    fn kitty_resolve_all_placeholders(&mut self) {
+        let (cell_width, cell_height) = self.kitty_cell_pixel_dims();
+        let seqno = self.seqno;
+        let kitty_img = &self.kitty_img;
+        self.screen.for_each_uncompressed_phys_line_mut(|_, line| {
+            line.resolve_image_placeholders(
+                cell_width,
+                cell_height,
+                |image_id, placement_id| kitty_img.virtual_placement(image_id, placement_id),
+                seqno,
+            );
+        });
+    }
+
     fn kitty_img_inner(&mut self, img: KittyImage) -> anyhow::Result<()> {
         match self
             .coalesce_kitty_accumulation(img)
@@ -238,37 +414,9 @@ impl TerminalState {
             } => {
                 self.kitty_img_place(image_id, image_number, placement, verbosity)?;
             }
-            KittyImage::Delete {
-                what:
-                    KittyImageDelete::ByImageId {
-                        image_id,
-                        placement_id,
-                        delete,
-                    },
-                verbosity,
-            } => {
-                log::trace!(
-                    "remove a placement: image_id {} placement_id {:?} delete {} verb {:?}",
-                    image_id,
-                    placement_id,
-                    delete,
-                    verbosity
-                );
-
-                self.kitty_remove_placement(image_id, placement_id);
-
-                if delete {
-                    self.kitty_img.remove_data_for_id(image_id);
-                }
-            }
-            KittyImage::Delete {
-                what: KittyImageDelete::All { delete },
-                verbosity: _,
-            } => {
-                self.kitty_remove_all_placements(delete);
-            }
             KittyImage::Delete { what, verbosity } => {
-                log::warn!("unhandled KittyImage::Delete {:?} {:?}", what, verbosity);
+                log::trace!("delete {:?} verb {:?}", what, verbosity);
+                self.kitty_img_delete(what);
             }
             KittyImage::TransmitFrame {
                 transmit,
@@ -289,6 +437,124 @@ impl TerminalState {
         Ok(())
     }
 
+    fn kitty_img_delete(&mut self, what: KittyImageDelete) {
+        match what {
+            KittyImageDelete::All { delete } => {
+                self.kitty_remove_all_placements(delete);
+            }
+            KittyImageDelete::ByImageId {
+                image_id,
+                placement_id,
+                delete,
+            } => {
+                self.kitty_remove_placement(image_id, placement_id);
+                if delete {
+                    self.kitty_img.remove_data_for_id(image_id);
+                }
+            }
+            KittyImageDelete::ByImageNumber {
+                image_number,
+                placement_id,
+                delete,
+            } => {
+                if let Some(&image_id) = self.kitty_img.number_to_id.get(&image_number) {
+                    self.kitty_remove_placement(image_id, placement_id);
+                    if delete {
+                        self.kitty_img.remove_data_for_id(image_id);
+                        self.kitty_img.number_to_id.remove(&image_number);
+                    }
+                }
+            }
+            KittyImageDelete::AtCursorPosition { delete } => {
+                let col = self.cursor.x;
+                let row = self.screen().visible_row_to_stable_row(self.cursor.y);
+                self.kitty_remove_placements_where(delete, |info| info.covers_cell(col, row));
+            }
+            KittyImageDelete::DeleteAt { x, y, delete } => {
+                let col = kitty_cell_index(x);
+                let row = self.kitty_stable_row(y);
+                self.kitty_remove_placements_where(delete, |info| info.covers_cell(col, row));
+            }
+            KittyImageDelete::DeleteAtZ { x, y, z, delete } => {
+                let col = kitty_cell_index(x);
+                let row = self.kitty_stable_row(y);
+                self.kitty_remove_placements_where(delete, |info| {
+                    info.covers_cell(col, row) && info.z_index == z
+                });
+            }
+            KittyImageDelete::DeleteColumn { x, delete } => {
+                let col = kitty_cell_index(x);
+                self.kitty_remove_placements_where(delete, |info| info.covers_column(col));
+            }
+            KittyImageDelete::DeleteRow { y, delete } => {
+                let row = self.kitty_stable_row(y);
+                self.kitty_remove_placements_where(delete, |info| info.covers_row(row));
+            }
+            KittyImageDelete::DeleteZ { z, delete } => {
+                self.kitty_remove_placements_where(delete, |info| info.z_index == z);
+            }
+            KittyImageDelete::DeleteRange {
+                first,
+                last,
+                delete,
+            } => {
+                let kitty_img = &self.kitty_img;
+                let mut image_ids: Vec<u32> = kitty_img
+                    .placements
+                    .keys()
+                    .chain(kitty_img.virtual_placements.keys())
+                    .map(|(id, _)| *id)
+                    .chain(kitty_img.id_to_data.keys().copied().filter(|_| delete))
+                    .filter(|id| (first..=last).contains(id))
+                    .collect();
+                image_ids.sort_unstable();
+                image_ids.dedup();
+                for image_id in image_ids {
+                    self.kitty_remove_placement(image_id, None);
+                    if delete {
+                        self.kitty_img.remove_data_for_id(image_id);
+                    }
+                }
+            }
+            what @ KittyImageDelete::AnimationFrames { .. } => {
+                log::warn!("unhandled KittyImage::Delete {:?}", what);
+            }
+        }
+    }
+
+    /// Convert a 1-based row number on the visible screen, as used
+    /// by delete commands, to a stable row index
+    fn kitty_stable_row(&self, y: u32) -> StableRowIndex {
+        self.screen()
+            .visible_row_to_stable_row(kitty_cell_index(y) as i64)
+    }
+
+    /// Remove the placements for which `pred` returns true.  Virtual
+    /// placements have no position on the screen and are not matched.
+    /// When `delete` is true, the data of images that are left without
+    /// any placement is freed as well.
+    fn kitty_remove_placements_where(
+        &mut self,
+        delete: bool,
+        pred: impl Fn(&PlacementInfo) -> bool,
+    ) {
+        let matched: Vec<(u32, Option<u32>)> = self
+            .kitty_img
+            .placements
+            .iter()
+            .filter(|(_, info)| pred(info))
+            .map(|(key, _)| *key)
+            .collect();
+        for (image_id, placement_id) in matched {
+            if let Some(info) = self.kitty_img.placements.remove(&(image_id, placement_id)) {
+                self.kitty_remove_placement_from_model(image_id, placement_id, info);
+            }
+            if delete && !self.kitty_img.is_placed(image_id) {
+                self.kitty_img.remove_data_for_id(image_id);
+            }
+        }
+    }
+
     fn kitty_remove_placement_from_model(
         &mut self,
         image_id: u32,
@@ -310,6 +576,14 @@ impl TerminalState {
     }
 
     fn kitty_remove_placement(&mut self, image_id: u32, placement_id: Option<u32>) {
+        let virtual_placements = self.kitty_img.virtual_placements.len();
+        self.kitty_img
+            .virtual_placements
+            .retain(|(id, p), _| *id != image_id || (placement_id.is_some() && *p != placement_id));
+        if self.kitty_img.virtual_placements.len() != virtual_placements {
+            self.kitty_resolve_all_placeholders();
+        }
+
         if placement_id.is_some() {
             if let Some(info) = self.kitty_img.placements.remove(&(image_id, placement_id)) {
                 log::trace!("removed placement {} {:?}", image_id, placement_id);
@@ -341,6 +615,10 @@ impl TerminalState {
         for ((image_id, p), info) in std::mem::take(&mut self.kitty_img.placements).into_iter() {
             self.kitty_remove_placement_from_model(image_id, p, info);
         }
+        if !self.kitty_img.virtual_placements.is_empty() {
+            self.kitty_img.virtual_placements.clear();
+            self.kitty_resolve_all_placeholders();
+        }
         if delete {
             self.kitty_img.id_to_data.clear();
             self.kitty_img.used_memory = 0;
@@ -924,6 +1202,11 @@ impl TerminalState {
     }
 }
 
+/// Convert a 1-based cell coordinate to a 0-based index
+fn kitty_cell_index(value: u32) -> usize {
+    (value as usize).saturating_sub(1)
+}
+
 /// Make a copy of the source region.
 /// Ideally we wouldn't need this, but Rust's mutability rules
 /// make it very awkward to mutably reference a frame while
@@ -2,7 +2,7 @@
 // and inclusive range
 #![allow(clippy::range_plus_one)]
 use super::*;
-use crate::color::{ColorPalette, RgbColor};
+use crate::color::{ColorPalette, RgbColor, SrgbaTuple};
 use crate::config::{BidiMode, NewlineCanon};
 use log::debug;
 use num_traits::ToPrimitive;
@@ -18,22 +18,35 @@ use wezterm_bidi::ParagraphDirectionHint;
 use wezterm_cell::image::ImageData;
 use wezterm_cell::UnicodeVersion;
 use wezterm_escape_parser::csi::{
-    Cursor, CursorStyle, DecPrivateMode, DecPrivateModeCode, Device, Edit, EraseInDisplay,
-    EraseInLine, Mode, Sgr, TabulationClear, TerminalMode, TerminalModeCode, Window, XtSmGraphics,
-    XtSmGraphicsAction, XtSmGraphicsItem, XtSmGraphicsStatus, XtermKeyModifierResource,
+    AttributeChangeExtent, ColorScheme, Cursor, CursorStyle, DecPrivateMode, DecPrivateModeCode,
+    Device, Edit, EraseInDisplay, EraseInLine, Mode, Sgr, TabulationClear, TerminalMode,
+    TerminalModeCode, Window, XtSmGraphics, XtSmGraphicsAction, XtSmGraphicsItem,
+    XtSmGraphicsStatus, XtermKeyModifierResource,
 };
 use wezterm_escape_parser::{OneBased, OperatingSystemCommand, CSI};
 use wezterm_surface::{CursorShape, CursorVisibility, SequenceNo};
 
+mod clipboard;
+mod commands;
 mod image;
 mod iterm;
 mod keyboard;
 mod kitty;
 mod mouse;
+mod notify;
 pub(crate) mod performer;
+mod rectangle;
 mod sixel;
+#[cfg(feature = "use_serde")]
+mod snapshot;
+mod text_size;
+use crate::terminalstate::clipboard::ClipboardWrite;
+use crate::terminalstate::commands::CommandState;
 use crate::terminalstate::image::*;
 use crate::terminalstate::kitty::*;
+use crate::terminalstate::notify::NotificationState;
+#[cfg(feature = "use_serde")]
+pub use crate::terminalstate::snapshot::{ScreenSnapshot, SNAPSHOT_VERSION};
 
 lazy_static::lazy_static! {
     static ref DB: Database = {
@@ -311,6 +324,12 @@ pub struct TerminalState {
     /// Movement events enabled
     any_event_mouse: bool,
     focus_tracking: bool,
+    /// Report resizes to the application (mode 2048)
+    in_band_resize: bool,
+    /// Report changes to the color scheme to the application (mode 2031)
+    color_scheme_updates: bool,
+    /// Whether the palette is dark or light, as of the last change
+    color_scheme: ColorScheme,
     /// X10 (legacy), SGR, and SGR-Pixels style mouse tracking and
     /// reporting is enabled
     mouse_encoding: MouseEncoding,
@@ -361,6 +380,12 @@ pub struct TerminalState {
     user_vars: HashMap<String, String>,
 
     kitty_img: KittyImageState,
+    commands: CommandState,
+    notifications: NotificationState,
+    /// A kitty clipboard protocol write whose data is still arriving
+    kitty_clipboard_write: Option<ClipboardWrite>,
+    /// DECSACE; how DECCARA and DECRARA interpret their area
+    attribute_change_extent: AttributeChangeExtent,
     seqno: SequenceNo,
 
     /// The unicode version that is in effect
@@ -400,6 +425,18 @@ struct UnicodeVersionStackEntry {
     label: Option<String>,
 }
 
+/// A palette is considered to be dark if its background contrasts
+/// more with white than it does with black
+fn palette_color_scheme(palette: &ColorPalette) -> ColorScheme {
+    let white = SrgbaTuple(1., 1., 1., 1.);
+    let black = SrgbaTuple(0., 0., 0., 1.);
+    if palette.background.contrast_ratio(&white) > palette.background.contrast_ratio(&black) {
+        ColorScheme::Dark
+    } else {
+        ColorScheme::Light
+    }
+}
+
 fn default_color_map() -> HashMap<u16, RgbColor> {
     let mut color_map = HashMap::new();
     // Match colors to the VT340 color table:
@@ -511,6 +548,7 @@ impl TerminalState {
         let color_map = default_color_map();
 
         let unicode_version = config.unicode_version();
+        let color_scheme = palette_color_scheme(&config.color_palette());
 
         TerminalState {
             config,
@@ -538,6 +576,9 @@ impl TerminalState {
             application_keypad: false,
             bracketed_paste: false,
             focus_tracking: false,
+            in_band_resize: false,
+            color_scheme_updates: false,
+            color_scheme,
             mouse_encoding: MouseEncoding::X10,
             keyboard_encoding: KeyboardEncoding::Xterm,
             sixel_scrolls_right: false,
@@ -569,6 +610,10 @@ impl TerminalState {
             image_cache: lru::LruCache::new(NonZeroUsize::new(16).unwrap()),
             user_vars: HashMap::new(),
             kitty_img: Default::default(),
+            commands: Default::default(),
+            notifications: Default::default(),
+            kitty_clipboard_write: None,
+            attribute_change_extent: AttributeChangeExtent::default(),
             seqno,
             unicode_version,
             unicode_version_stack: vec![],
@@ -599,6 +644,7 @@ impl TerminalState {
 
     pub fn set_config(&mut self, config: Arc<dyn TerminalConfiguration>) {
         self.config = config;
+        self.check_color_scheme_change();
     }
 
     pub fn get_config(&self) -> Arc<dyn TerminalConfiguration> {
@@ -911,6 +957,10 @@ impl TerminalState {
                 saved.wrap_next = false;
             }
         }
+
+        if self.in_band_resize {
+            self.report_in_band_resize();
+        }
     }
 
     pub fn get_size(&self) -> TerminalSize {
@@ -929,6 +979,37 @@ impl TerminalState {
         if let Some(handler) = self.alert_handler.as_mut() {
             handler.alert(Alert::PaletteChanged);
         }
+        self.check_color_scheme_change();
+    }
+
+    /// Reports the color scheme to the application if it has switched
+    /// between dark and light, and it has asked to be told about that
+    fn check_color_scheme_change(&mut self) {
+        let color_scheme = palette_color_scheme(&self.palette());
+        if color_scheme != self.color_scheme {
+            self.color_scheme = color_scheme;
+            if self.color_scheme_updates {
+                self.report_color_scheme();
+            }
+        }
+    }
+
+    fn report_color_scheme(&mut self) {
+        let report = Device::ColorSchemeReport(self.color_scheme);
+        write!(self.writer, "{}{}", CSI, report).ok();
+        self.writer.flush().ok();
+    }
+
+    fn report_in_band_resize(&mut self) {
+        let screen = self.screen();
+        let report = Window::InBandResize {
+            rows: screen.physical_rows as i64,
+            cols: screen.physical_cols as i64,
+            pixel_height: self.pixel_height as i64,
+            pixel_width: self.pixel_width as i64,
+        };
+        write!(self.writer, "{}{}", CSI, report).ok();
+        self.writer.flush().ok();
     }
 
     /// When dealing with selection, mark a range of lines as dirty
@@ -1336,6 +1417,10 @@ impl TerminalState {
                 self.writer.write(b"\x1b[0n").ok();
                 self.writer.flush().ok();
             }
+            Device::RequestColorScheme => {
+                self.report_color_scheme();
+            }
+            Device::ColorSchemeReport(_) => {}
             Device::XtSmGraphics(g) => {
                 let response = if matches!(g.item, XtSmGraphicsItem::Unspecified(_)) {
                     XtSmGraphics {
@@ -1815,6 +1900,34 @@ impl TerminalState {
                 self.decqrm_response(mode, true, self.focus_tracking);
             }
 
+            Mode::SetDecPrivateMode(DecPrivateMode::Code(DecPrivateModeCode::InBandResize)) => {
+                self.in_band_resize = true;
+                // The application learns the current size straight away
+                self.report_in_band_resize();
+            }
+            Mode::ResetDecPrivateMode(DecPrivateMode::Code(DecPrivateModeCode::InBandResize)) => {
+                self.in_band_resize = false;
+            }
+            Mode::QueryDecPrivateMode(DecPrivateMode::Code(DecPrivateModeCode::InBandResize)) => {
+                self.decqrm_response(mode, true, self.in_band_resize);
+            }
+
+            Mode::SetDecPrivateMode(DecPrivateMode::Code(
+                DecPrivateModeCode::ColorSchemeUpdates,
+            )) => {
+                self.color_scheme_updates = true;
+            }
+            Mode::ResetDecPrivateMode(DecPrivateMode::Code(
+                DecPrivateModeCode::ColorSchemeUpdates,
+            )) => {
+                self.color_scheme_updates = false;
+            }
+            Mode::QueryDecPrivateMode(DecPrivateMode::Code(
+                DecPrivateModeCode::ColorSchemeUpdates,
+            )) => {
+                self.decqrm_response(mode, true, self.color_scheme_updates);
+            }
+
             Mode::SetDecPrivateMode(DecPrivateMode::Code(DecPrivateModeCode::SGRMouse)) => {
                 self.mouse_encoding = MouseEncoding::SGR;
                 self.last_mouse_move.take();
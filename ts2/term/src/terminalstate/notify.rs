@@ -0,0 +1,211 @@
+//! Desktop notifications, from the kitty notification protocol (OSC 99).
+//! <https://sw.kovidgoyal.net/kitty/desktop-notifications/>
+
+use crate::terminal::Alert;
+use crate::{DesktopNotification, NotificationOccasion, NotificationUrgency, TerminalState};
+use std::collections::{HashMap, VecDeque};
+use std::io::Write;
+use wezterm_escape_parser::osc::{
+    KittyNotification, KittyNotificationOccasion, KittyNotificationPayload,
+    KittyNotificationUrgency,
+};
+use wezterm_escape_parser::OperatingSystemCommand;
+
+/// The number of notifications that can be in the process of being
+/// sent in chunks at the same time
+const MAX_PENDING_NOTIFICATIONS: usize = 16;
+/// The largest title or body that is accepted, in bytes
+const MAX_NOTIFICATION_TEXT: usize = 64 * 1024;
+/// The number of notifications whose identifiers are remembered so
+/// that a close requested by the application can be reported to it
+const MAX_CLOSE_REPORTS: usize = 64;
+
+/// What the terminal reports that it supports in response to a query
+const QUERY_RESPONSE: &str =
+    "a=focus,report:c=1:o=always,unfocused,invisible:p=title,body,?,close:u=0,1,2:w=1";
+
+#[derive(Debug, Default)]
+pub(crate) struct NotificationState {
+    /// Notifications whose chunks are still arriving, keyed by identifier
+    pending: HashMap<Option<String>, PendingNotification>,
+    /// Identifiers of notifications that asked for close reports
+    close_reports: VecDeque<String>,
+}
+
+#[derive(Debug, Default)]
+struct PendingNotification {
+    metadata: KittyNotification,
+    title: Vec<u8>,
+    body: Vec<u8>,
+}
+
+impl PendingNotification {
+    /// Keys that are repeated in later chunks take precedence
+    fn merge_metadata(&mut self, chunk: &KittyNotification) {
+        let meta = &mut self.metadata;
+        macro_rules! merge {
+            ($($field:ident),*) => {
+                $(
+                    if chunk.$field.is_some() {
+                        meta.$field = chunk.$field.clone();
+                    }
+                )*
+            };
+        }
+        merge!(
+            focus,
+            report,
+            close_event,
+            app_name,
+            icon_cache_id,
+            occasion,
+            sound,
+            urgency,
+            timeout
+        );
+        meta.icon_names.extend(chunk.icon_names.iter().cloned());
+        meta.types.extend(chunk.types.iter().cloned());
+    }
+
+    fn append(text: &mut Vec<u8>, payload: &[u8]) {
+        let len = payload.len().min(MAX_NOTIFICATION_TEXT - text.len());
+        text.extend_from_slice(&payload[..len]);
+    }
+
+    fn into_notification(self, id: Option<String>) -> Option<DesktopNotification> {
+        let title = String::from_utf8_lossy(&self.title).to_string();
+        let body = String::from_utf8_lossy(&self.body).to_string();
+        if title.is_empty() && body.is_empty() {
+            return None;
+        }
+        let meta = self.metadata;
+
+        Some(DesktopNotification {
+            title: if title.is_empty() { None } else { Some(title) },
+            body,
+            urgency: match meta.urgency {
+                Some(KittyNotificationUrgency::Low) => NotificationUrgency::Low,
+                Some(KittyNotificationUrgency::Normal) | None => NotificationUrgency::Normal,
+                Some(KittyNotificationUrgency::Critical) => NotificationUrgency::Critical,
+            },
+            timeout: meta.timeout.and_then(|t| u64::try_from(t).ok()),
+            occasion: match meta.occasion {
+                Some(KittyNotificationOccasion::Always) | None => NotificationOccasion::Always,
+                Some(KittyNotificationOccasion::Unfocused) => NotificationOccasion::Unfocused,
+                Some(KittyNotificationOccasion::Invisible) => NotificationOccasion::Invisible,
+            },
+            focus: meta.focus_on_activation(),
+            report_activation: meta.report_on_activation() && id.is_some(),
+            report_close: meta.close_event.unwrap_or(false) && id.is_some(),
+            id,
+        })
+    }
+}
+
+impl TerminalState {
+    /// Reports to the application that the notification with `id` was
+    /// closed, if it asked for that.  Each close is reported only once.
+    pub fn notification_closed(&mut self, id: &str) {
+        let Some(idx) = self
+            .notifications
+            .close_reports
+            .iter()
+            .position(|i| i == id)
+        else {
+            return;
+        };
+        self.notifications.close_reports.remove(idx);
+        let report = OperatingSystemCommand::KittyNotification(KittyNotification {
+            id: Some(id.to_string()),
+            payload_type: KittyNotificationPayload::Close,
+            ..Default::default()
+        });
+        write!(self.writer, "{}", report).ok();
+        self.writer.flush().ok();
+    }
+
+    pub(crate) fn kitty_notification(&mut self, notif: KittyNotification) {
+        match notif.payload_type {
+            KittyNotificationPayload::Title | KittyNotificationPayload::Body => {
+                self.kitty_notification_chunk(notif)
+            }
+            KittyNotificationPayload::Close => {
+                let Some(id) = notif.id else {
+                    return;
+                };
+                // Forget any part of it that hasn't been shown yet
+                self.notifications.pending.remove(&Some(id.clone()));
+                self.notification_closed(&id);
+
+                if let Some(handler) = self.alert_handler.as_mut() {
+                    handler.alert(Alert::CloseDesktopNotification { id });
+                }
+            }
+            KittyNotificationPayload::Query => {
+                let response = OperatingSystemCommand::KittyNotification(KittyNotification {
+                    id: notif.id,
+                    payload_type: KittyNotificationPayload::Query,
+                    payload: QUERY_RESPONSE.as_bytes().to_vec(),
+                    ..Default::default()
+                });
+                write!(self.writer, "{}", response).ok();
+                self.writer.flush().ok();
+            }
+            KittyNotificationPayload::Icon
+            | KittyNotificationPayload::Alive
+            | KittyNotificationPayload::Buttons => {
+                if self.config.log_unknown_escape_sequences() {
+                    log::warn!("unhandled kitty notification {:?}", notif);
+                }
+            }
+        }
+    }
+
+    fn kitty_notification_chunk(&mut self, notif: KittyNotification) {
+        let key = notif.id.clone();
+        if !self.notifications.pending.contains_key(&key)
+            && self.notifications.pending.len() >= MAX_PENDING_NOTIFICATIONS
+        {
+            log::warn!(
+                "too many incomplete kitty notifications; ignoring {:?}",
+                key
+            );
+            return;
+        }
+
+        let pending = self.notifications.pending.entry(key.clone()).or_default();
+        pending.merge_metadata(&notif);
+        match notif.payload_type {
+            KittyNotificationPayload::Body => {
+                PendingNotification::append(&mut pending.body, &notif.payload)
+            }
+            _ => PendingNotification::append(&mut pending.title, &notif.payload),
+        }
+
+        if !notif.is_done() {
+            return;
+        }
+
+        let pending = match self.notifications.pending.remove(&key) {
+            Some(pending) => pending,
+            None => return,
+        };
+        let notification = match pending.into_notification(key) {
+            Some(notification) => notification,
+            None => return,
+        };
+
+        if notification.report_close {
+            if let Some(id) = &notification.id {
+                if self.notifications.close_reports.len() == MAX_CLOSE_REPORTS {
+                    self.notifications.close_reports.pop_front();
+                }
+                self.notifications.close_reports.push_back(id.clone());
+            }
+        }
+
+        if let Some(handler) = self.alert_handler.as_mut() {
+            handler.alert(Alert::DesktopNotification(notification));
+        }
+    }
+}
@@ -27,6 +27,7 @@ use wezterm_escape_parser::osc::{
 use wezterm_escape_parser::{
     Action, ControlCode, DeviceControlMode, Esc, EscCode, OperatingSystemCommand, CSI,
 };
+use wezterm_surface::line::is_image_placeholder;
 
 /// A helper struct for implementing `vtparse::VTActor` while compartmentalizing
 /// the terminal state and the embedding/host terminal interface
@@ -130,6 +131,8 @@ impl<'a> Performer<'a> {
         } else {
             p.as_str()
         };
+        // Rows with image placeholders, which show kitty images
+        let mut placeholder_rows = vec![];
 
         for g in Graphemes::new(text) {
             let g = self.remap_grapheme(g);
@@ -223,6 +226,13 @@ impl<'a> Performer<'a> {
             self.screen_mut()
                 .set_cell_grapheme(x, y, g, print_width, pen, seqno);
 
+            if is_image_placeholder(g) {
+                let row = self.screen().visible_row_to_stable_row(y);
+                if placeholder_rows.last() != Some(&row) {
+                    placeholder_rows.push(row);
+                }
+            }
+
             if !wrappable {
                 self.cursor.x += print_width;
                 self.wrap_next = false;
@@ -233,6 +243,10 @@ impl<'a> Performer<'a> {
 
         std::mem::swap(&mut self.print, &mut p);
         self.print.clear();
+
+        if !placeholder_rows.is_empty() && self.config.enable_kitty_graphics() {
+            self.kitty_resolve_placeholders(&placeholder_rows);
+        }
     }
 
     /// ConPTY, at the time of writing, does something horrible to rewrite
@@ -507,6 +521,7 @@ impl<'a> Performer<'a> {
             CSI::Device(dev) => self.state.perform_device(*dev),
             CSI::Mouse(mouse) => error!("mouse report sent by app? {:?}", mouse),
             CSI::Window(window) => self.state.perform_csi_window(*window),
+            CSI::RectangularArea(area) => self.state.perform_csi_rectangular_area(*area),
             CSI::SelectCharacterPath(CharacterPath::ImplementationDefault, _) => {
                 self.state.bidi_hint.take();
             }
@@ -695,6 +710,9 @@ impl<'a> Performer<'a> {
                 self.application_keypad = false;
                 self.bracketed_paste = false;
                 self.focus_tracking = false;
+                self.in_band_resize = false;
+                self.color_scheme_updates = false;
+                self.attribute_change_extent = Default::default();
                 self.mouse_tracking = false;
                 self.mouse_encoding = MouseEncoding::X10;
                 self.keyboard_encoding = KeyboardEncoding::Xterm;
@@ -867,17 +885,21 @@ impl<'a> Performer<'a> {
                 FinalTermSemanticPrompt::FreshLineAndStartPrompt { .. },
             ) => {
                 self.fresh_line();
+                self.command_prompt_started(true);
                 self.pen.set_semantic_type(SemanticType::Prompt);
             }
             OperatingSystemCommand::FinalTermSemanticPrompt(
                 FinalTermSemanticPrompt::StartPrompt(_),
             ) => {
+                self.command_prompt_started(false);
                 self.pen.set_semantic_type(SemanticType::Prompt);
             }
             OperatingSystemCommand::FinalTermSemanticPrompt(
                 FinalTermSemanticPrompt::MarkEndOfCommandWithFreshLine { .. },
             ) => {
+                self.command_finished(None);
                 self.fresh_line();
+                self.command_prompt_started(true);
                 self.pen.set_semantic_type(SemanticType::Prompt);
             }
             OperatingSystemCommand::FinalTermSemanticPrompt(
@@ -894,12 +916,15 @@ impl<'a> Performer<'a> {
             OperatingSystemCommand::FinalTermSemanticPrompt(
                 FinalTermSemanticPrompt::MarkEndOfInputAndStartOfOutput { .. },
             ) => {
+                self.command_output_started();
                 self.pen.set_semantic_type(SemanticType::Output);
             }
 
             OperatingSystemCommand::FinalTermSemanticPrompt(
-                FinalTermSemanticPrompt::CommandStatus { .. },
-            ) => {}
+                FinalTermSemanticPrompt::CommandStatus { status, .. },
+            ) => {
+                self.command_finished(Some(status));
+            }
 
             OperatingSystemCommand::SystemNotification(message) => {
                 if let Some(handler) = self.alert_handler.as_mut() {
@@ -1091,6 +1116,9 @@ impl<'a> Performer<'a> {
                     }
                 }
             }
+            OperatingSystemCommand::KittyNotification(notif) => self.kitty_notification(notif),
+            OperatingSystemCommand::TextSize(size) => self.print_scaled_text(size),
+            OperatingSystemCommand::KittyClipboard(clip) => self.kitty_clipboard(clip),
         }
     }
 }
@@ -0,0 +1,330 @@
+//! The VT420 rectangular area operations: DECCRA, DECFRA, DECERA,
+//! DECSERA, DECCARA, DECRARA and DECSACE.
+//! <https://vt100.net/docs/vt510-rm/chapter5.html#S5.3>
+
+use crate::{TerminalState, VisibleRowIndex};
+use std::ops::Range;
+use wezterm_cell::{Blink, Cell, CellAttributes, Intensity, Underline};
+use wezterm_escape_parser::csi::{
+    AttributeChangeExtent, Rectangle, RectangleAttribute, RectangularArea,
+};
+use wezterm_escape_parser::OneBased;
+
+/// The screen coordinates of a rectangle
+struct Bounds {
+    rows: Range<VisibleRowIndex>,
+    cols: Range<usize>,
+}
+
+impl TerminalState {
+    pub(crate) fn perform_csi_rectangular_area(&mut self, area: RectangularArea) {
+        let seqno = self.seqno;
+        match area {
+            RectangularArea::Copy {
+                area, top, left, ..
+            } => {
+                // We have no notion of pages, so we copy within
+                // the current screen
+                let Some(source) = self.rectangle_bounds(&area) else {
+                    return;
+                };
+                let origin = self.rectangle_origin();
+                let limits = self.rectangle_limits();
+                let y = origin.rows.start + top.as_zero_based() as VisibleRowIndex;
+                let x = origin.cols.start + left.as_zero_based() as usize;
+                if y >= limits.rows.end || x >= limits.cols.end {
+                    return;
+                }
+                let height = source.rows.end - source.rows.start;
+                let width = source.cols.end - source.cols.start;
+                let rows = source.rows.start..source.rows.start + height.min(limits.rows.end - y);
+                let cols = source.cols.start..source.cols.start + width.min(limits.cols.end - x);
+
+                // Take a copy of the source first, so that overlapping
+                // areas are copied correctly
+                let screen = self.screen_mut();
+                let cells = screen.copy_rectangle(rows, cols);
+                screen.paste_rectangle(x, y, cells, seqno);
+            }
+            RectangularArea::Fill { ch, area } => {
+                let cell = Cell::new(ch, self.pen.clone_sgr_only());
+                if ch.is_control() || cell.width() != 1 {
+                    if self.config.log_unknown_escape_sequences() {
+                        log::warn!("DECFRA: cannot fill with {:?}", ch);
+                    }
+                    return;
+                }
+                self.fill_rectangle(&area, &cell);
+            }
+            RectangularArea::Erase(area) => {
+                let cell = Cell::blank_with_attrs(self.pen.clone_sgr_only());
+                self.fill_rectangle(&area, &cell);
+            }
+            RectangularArea::SelectiveErase(area) => {
+                // We don't support the protected attribute (DECSCA), so
+                // every cell is erasable.  Unlike DECERA, the attributes
+                // of the cells are left alone.
+                let Some(bounds) = self.rectangle_bounds(&area) else {
+                    return;
+                };
+                let screen = self.screen_mut();
+                for y in bounds.rows {
+                    let line_idx = screen.phys_row(y);
+                    let line = screen.line_mut(line_idx);
+                    let end = bounds.cols.end.min(line.len());
+                    for x in bounds.cols.start..end {
+                        let attrs = line.cells_mut()[x].attrs().clone();
+                        line.set_cell(x, Cell::blank_with_attrs(attrs), seqno);
+                    }
+                }
+            }
+            RectangularArea::ChangeAttributes { area, attributes } => {
+                self.change_rectangle_attributes(&area, |attrs| {
+                    for attribute in &attributes {
+                        change_attribute(attrs, *attribute);
+                    }
+                });
+            }
+            RectangularArea::ReverseAttributes { area, attributes } => {
+                self.change_rectangle_attributes(&area, |attrs| {
+                    for attribute in &attributes {
+                        reverse_attribute(attrs, *attribute);
+                    }
+                });
+            }
+            RectangularArea::SelectAttributeChangeExtent(extent) => {
+                self.attribute_change_extent = extent;
+            }
+        }
+    }
+
+    /// The region that rectangle coordinates are relative to;
+    /// the margins in origin mode, otherwise the whole screen
+    fn rectangle_origin(&self) -> Bounds {
+        if self.dec_origin_mode {
+            Bounds {
+                rows: self.top_and_bottom_margins.clone(),
+                cols: self.left_and_right_margins.clone(),
+            }
+        } else {
+            Bounds {
+                rows: 0..self.screen().physical_rows as VisibleRowIndex,
+                cols: 0..self.screen().physical_cols,
+            }
+        }
+    }
+
+    /// The region that rectangles are clipped to
+    fn rectangle_limits(&self) -> Bounds {
+        let origin = self.rectangle_origin();
+        Bounds {
+            rows: origin.rows.start.max(0)
+                ..origin
+                    .rows
+                    .end
+                    .min(self.screen().physical_rows as VisibleRowIndex),
+            cols: origin.cols.start..origin.cols.end.min(self.screen().physical_cols),
+        }
+    }
+
+    /// Translates `area` into screen coordinates, clipped to the
+    /// limits.  Returns None if the area is empty.
+    fn rectangle_bounds(&self, area: &Rectangle) -> Option<Bounds> {
+        let bounds = self.rectangle_edges(area);
+        if bounds.rows.is_empty() || bounds.cols.is_empty() {
+            None
+        } else {
+            Some(bounds)
+        }
+    }
+
+    /// Translates `area` into screen coordinates, clipped to the
+    /// limits, without checking that the result is a valid rectangle
+    fn rectangle_edges(&self, area: &Rectangle) -> Bounds {
+        let origin = self.rectangle_origin();
+        let limits = self.rectangle_limits();
+        let edge = |start: usize, limit: usize, value: Option<OneBased>| -> usize {
+            value
+                .map(|v| start + v.as_one_based() as usize)
+                .unwrap_or(limit)
+                .min(limit)
+        };
+
+        let top = origin.rows.start + area.top.as_zero_based() as VisibleRowIndex;
+        let bottom = edge(
+            origin.rows.start as usize,
+            limits.rows.end as usize,
+            area.bottom,
+        ) as VisibleRowIndex;
+        let left = origin.cols.start + area.left.as_zero_based() as usize;
+        let right = edge(origin.cols.start, limits.cols.end, area.right);
+
+        Bounds {
+            rows: top..bottom,
+            cols: left..right,
+        }
+    }
+
+    fn fill_rectangle(&mut self, area: &Rectangle, cell: &Cell) {
+        let Some(bounds) = self.rectangle_bounds(area) else {
+            return;
+        };
+        let seqno = self.seqno;
+        let screen = self.screen_mut();
+        for y in bounds.rows {
+            let line_idx = screen.phys_row(y);
+            screen
+                .line_mut(line_idx)
+                .fill_range(bounds.cols.clone(), cell, seqno);
+        }
+    }
+
+    /// Applies `func` to the attributes of the cells in `area`, which is
+    /// either exactly the rectangle or, in stream mode, the characters
+    /// from its top left position through its bottom right position.
+    fn change_rectangle_attributes<F: FnMut(&mut CellAttributes)>(
+        &mut self,
+        area: &Rectangle,
+        mut func: F,
+    ) {
+        let extent = self.attribute_change_extent;
+        let bounds = match extent {
+            AttributeChangeExtent::Rectangle => match self.rectangle_bounds(area) {
+                Some(bounds) => bounds,
+                None => return,
+            },
+            // The left edge may be to the right of the right edge
+            // when the stream spans several lines
+            AttributeChangeExtent::Stream => {
+                let bounds = self.rectangle_edges(area);
+                if bounds.rows.is_empty() {
+                    return;
+                }
+                bounds
+            }
+        };
+        let limits = self.rectangle_limits();
+        let seqno = self.seqno;
+        let last_row = bounds.rows.end - 1;
+        let screen = self.screen_mut();
+        for y in bounds.rows.clone() {
+            let cols = match extent {
+                AttributeChangeExtent::Rectangle => bounds.cols.clone(),
+                AttributeChangeExtent::Stream => {
+                    let start = if y == bounds.rows.start {
+                        bounds.cols.start
+                    } else {
+                        limits.cols.start
+                    };
+                    let end = if y == last_row {
+                        bounds.cols.end
+                    } else {
+                        limits.cols.end
+                    };
+                    start..end
+                }
+            };
+            if !cols.is_empty() {
+                screen.change_cell_attributes(y, cols, seqno, &mut func);
+            }
+        }
+    }
+}
+
+fn change_attribute(attrs: &mut CellAttributes, attribute: RectangleAttribute) {
+    match attribute {
+        RectangleAttribute::AllAttributes => {
+            attrs
+                .set_intensity(Intensity::Normal)
+                .set_underline(Underline::None)
+                .set_blink(Blink::None)
+                .set_reverse(false)
+                .set_invisible(false);
+        }
+        RectangleAttribute::Bold => {
+            attrs.set_intensity(Intensity::Bold);
+        }
+        RectangleAttribute::Underline => {
+            attrs.set_underline(Underline::Single);
+        }
+        RectangleAttribute::Blink => {
+            attrs.set_blink(Blink::Slow);
+        }
+        RectangleAttribute::Inverse => {
+            attrs.set_reverse(true);
+        }
+        RectangleAttribute::Invisible => {
+            attrs.set_invisible(true);
+        }
+        RectangleAttribute::NoBold => {
+            attrs.set_intensity(Intensity::Normal);
+        }
+        RectangleAttribute::NoUnderline => {
+            attrs.set_underline(Underline::None);
+        }
+        RectangleAttribute::NoBlink => {
+            attrs.set_blink(Blink::None);
+        }
+        RectangleAttribute::NoInverse => {
+            attrs.set_reverse(false);
+        }
+        RectangleAttribute::NoInvisible => {
+            attrs.set_invisible(false);
+        }
+    }
+}
+
+/// DECRARA toggles attributes; the "No" forms have no meaning here
+/// and are ignored
+fn reverse_attribute(attrs: &mut CellAttributes, attribute: RectangleAttribute) {
+    match attribute {
+        RectangleAttribute::AllAttributes => {
+            for attribute in [
+                RectangleAttribute::Bold,
+                RectangleAttribute::Underline,
+                RectangleAttribute::Blink,
+                RectangleAttribute::Inverse,
+                RectangleAttribute::Invisible,
+            ] {
+                reverse_attribute(attrs, attribute);
+            }
+        }
+        RectangleAttribute::Bold => {
+            let intensity = if attrs.intensity() == Intensity::Bold {
+                Intensity::Normal
+            } else {
+                Intensity::Bold
+            };
+            attrs.set_intensity(intensity);
+        }
+        RectangleAttribute::Underline => {
+            let underline = if attrs.underline() == Underline::None {
+                Underline::Single
+            } else {
+                Underline::None
+            };
+            attrs.set_underline(underline);
+        }
+        RectangleAttribute::Blink => {
+            let blink = if attrs.blink() == Blink::None {
+                Blink::Slow
+            } else {
+                Blink::None
+            };
+            attrs.set_blink(blink);
+        }
+        RectangleAttribute::Inverse => {
+            let reverse = !attrs.reverse();
+            attrs.set_reverse(reverse);
+        }
+        RectangleAttribute::Invisible => {
+            let invisible = !attrs.invisible();
+            attrs.set_invisible(invisible);
+        }
+        RectangleAttribute::NoBold
+        | RectangleAttribute::NoUnderline
+        | RectangleAttribute::NoBlink
+        | RectangleAttribute::NoInverse
+        | RectangleAttribute::NoInvisible => {}
+    }
+}
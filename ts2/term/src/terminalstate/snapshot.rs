@@ -0,0 +1,113 @@
+//! Snapshots of the primary screen and its scrollback, used to
+//! persist the contents of a pane across restarts.
+
+use crate::{CursorPosition, Line, StableRowIndex, TerminalState};
+use anyhow::{anyhow, bail};
+use serde::{Deserialize, Serialize};
+
+/// Bumped whenever the encoding of a `ScreenSnapshot` changes in
+/// an incompatible way; older snapshots are rejected rather than
+/// being misinterpreted.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+const SNAPSHOT_MAGIC: &[u8; 4] = b"WZSS";
+
+/// The contents of the primary screen, from the top of the
+/// scrollback through to the line holding the cursor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScreenSnapshot {
+    pub physical_rows: usize,
+    pub physical_cols: usize,
+    pub lines: Vec<Line>,
+    pub cursor: CursorPosition,
+    pub title: String,
+    pub working_dir: Option<String>,
+    /// The argv of the process that was running in the pane,
+    /// which the terminal itself doesn't know; filled in by the
+    /// embedding application
+    pub command: Option<Vec<String>>,
+}
+
+impl ScreenSnapshot {
+    /// Encodes the snapshot as a magic number and version, followed
+    /// by the deflate-compressed payload
+    pub fn encode(&self) -> anyhow::Result<Vec<u8>> {
+        let mut payload = vec![];
+        let mut encode = varbincode::Serializer::new(&mut payload);
+        self.serialize(&mut encode)?;
+
+        let mut data = SNAPSHOT_MAGIC.to_vec();
+        data.extend_from_slice(&SNAPSHOT_VERSION.to_le_bytes());
+        data.extend_from_slice(&miniz_oxide::deflate::compress_to_vec(&payload, 6));
+        Ok(data)
+    }
+
+    pub fn decode(data: &[u8]) -> anyhow::Result<Self> {
+        let header_len = SNAPSHOT_MAGIC.len() + 4;
+        if data.len() < header_len || &data[..SNAPSHOT_MAGIC.len()] != SNAPSHOT_MAGIC {
+            bail!("data is not a screen snapshot");
+        }
+        let mut version = [0u8; 4];
+        version.copy_from_slice(&data[SNAPSHOT_MAGIC.len()..header_len]);
+        let version = u32::from_le_bytes(version);
+        if version != SNAPSHOT_VERSION {
+            bail!(
+                "screen snapshot version {} is not supported (expected {})",
+                version,
+                SNAPSHOT_VERSION
+            );
+        }
+
+        let payload = miniz_oxide::inflate::decompress_to_vec(&data[header_len..])
+            .map_err(|e| anyhow!("decompressing screen snapshot: {:?}", e))?;
+        let mut reader = payload.as_slice();
+        let mut decode = varbincode::Deserializer::new(&mut reader);
+        Ok(Self::deserialize(&mut decode)?)
+    }
+}
+
+impl TerminalState {
+    /// Captures the primary screen, even while the alternate screen
+    /// is active.  Lines below the cursor are not included, as they
+    /// hold nothing of interest when restored as history.
+    pub fn snapshot(&self) -> ScreenSnapshot {
+        let screen = &self.screen.screen;
+        let cursor = if self.screen.alt_screen_is_active {
+            screen
+                .saved_cursor
+                .as_ref()
+                .map(|saved| saved.position)
+                .unwrap_or_default()
+        } else {
+            self.cursor
+        };
+        let end = screen.phys_row(cursor.y) + 1;
+
+        ScreenSnapshot {
+            physical_rows: screen.physical_rows,
+            physical_cols: screen.physical_cols,
+            lines: screen.lines_in_phys_range(0..end),
+            cursor,
+            title: self.title.clone(),
+            working_dir: self.current_dir.as_ref().map(|url| url.to_string()),
+            command: None,
+        }
+    }
+
+    /// Places the lines of `snapshot` into the scrollback above
+    /// the current contents of the primary screen, so that they
+    /// appear as history above whatever is subsequently output
+    pub fn restore_snapshot(&mut self, snapshot: ScreenSnapshot) {
+        self.increment_seqno();
+        let seqno = self.seqno;
+        let inserted = self.screen.screen.prepend_lines(snapshot.lines, seqno);
+
+        // Anything that refers to the rows of the primary screen by
+        // StableRowIndex has to follow them down
+        let rows = inserted as StableRowIndex;
+        self.shift_command_rows(rows);
+        if !self.screen.alt_screen_is_active {
+            self.kitty_img.shift_placements(rows);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Scaled text, from the kitty text sizing protocol (OSC 66).
+//! <https://sw.kovidgoyal.net/kitty/text-sizing-protocol/>
+
+use crate::{TerminalState, VisibleRowIndex};
+use finl_unicode::grapheme_clusters::Graphemes;
+use wezterm_cell::{grapheme_column_width, ScaledText};
+use wezterm_escape_parser::osc::TextSize;
+
+impl TerminalState {
+    pub(crate) fn print_scaled_text(&mut self, size: TextSize) {
+        let scaled = ScaledText {
+            scale: size.scale.max(1),
+            numerator: size.numerator,
+            denominator: size.denominator,
+            vertical_align: size.vertical_align,
+            horizontal_align: size.horizontal_align,
+            row: 0,
+        };
+        let scale = scaled.scale as usize;
+
+        // Control characters have no place in the text; they would
+        // be rendered as spaces anyway
+        let text: String = size.text.chars().filter(|c| !c.is_control()).collect();
+        if text.is_empty() {
+            return;
+        }
+
+        if size.width > 0 {
+            // All of the text shares a single block of the requested width
+            self.print_scaled_block(&text, size.width as usize * scale, scaled);
+        } else {
+            // Each grapheme is placed in a block of its own
+            for g in Graphemes::new(&text) {
+                let width = grapheme_column_width(g, Some(&self.unicode_version));
+                if width == 0 {
+                    continue;
+                }
+                self.print_scaled_block(g, width * scale, scaled);
+            }
+        }
+    }
+
+    /// Places `text` in a block that is `width` columns wide and
+    /// `scaled.scale` rows high, with its top left at the cursor.
+    /// The cursor moves along the top row of the block, the same way
+    /// that it does when printing a wide character.
+    fn print_scaled_block(&mut self, text: &str, width: usize, scaled: ScaledText) {
+        let seqno = self.seqno;
+        let margins = self.left_and_right_margins.clone();
+        let rows = scaled.scale as VisibleRowIndex;
+        let region = if self.top_and_bottom_margins.contains(&self.cursor.y) {
+            self.top_and_bottom_margins.clone()
+        } else {
+            0..self.screen().physical_rows as VisibleRowIndex
+        };
+
+        if width > margins.end - margins.start || rows > region.end - region.start {
+            log::warn!(
+                "scaled text {:?} in a {}x{} block does not fit on the screen",
+                text,
+                width,
+                rows
+            );
+            return;
+        }
+
+        if self.wrap_next || self.cursor.x + width > margins.end {
+            if self.dec_auto_wrap {
+                let y = self.cursor.y;
+                let screen = self.screen_mut();
+                let y = screen.phys_row(y);
+                screen.line_mut(y).set_last_cell_was_wrapped(true, seqno);
+                self.new_line(true);
+            } else {
+                self.set_cursor_position_absolute(margins.end - width, self.cursor.y);
+            }
+        }
+
+        // Make room for the rows of the block that are below the cursor
+        let overflow = self.cursor.y + rows - region.end;
+        if overflow > 0 {
+            if region == self.top_and_bottom_margins {
+                self.scroll_up(overflow as usize);
+            }
+            self.set_cursor_position_absolute(self.cursor.x, self.cursor.y - overflow);
+        }
+
+        let x = self.cursor.x;
+        let y = self.cursor.y;
+        for row in 0..scaled.scale {
+            let mut attr = self.pen.clone();
+            attr.set_scaled_text(Some(ScaledText { row, ..scaled }));
+            // Only the top row holds the text; the rest of the block
+            // is made up of placeholders
+            let text = if row == 0 { text } else { " " };
+            self.screen_mut().set_cell_grapheme(
+                x,
+                y + row as VisibleRowIndex,
+                text,
+                width,
+                attr,
+                seqno,
+            );
+        }
+
+        if x + width < margins.end {
+            self.cursor.x += width;
+            self.wrap_next = false;
+        } else {
+            self.wrap_next = self.dec_auto_wrap;
+        }
+    }
+}
@@ -401,3 +401,195 @@ fn test_ed_erase_scrollback() {
     term.print("b");
     assert_all_contents(&term, file!(), line!(), &["111", "222", "ab"]);
 }
+
+fn visible_text(term: &TestTerm) -> Vec<String> {
+    term.screen()
+        .visible_lines()
+        .iter()
+        .map(|line| line.as_str().trim_end().to_string())
+        .collect()
+}
+
+fn visible_attrs<F: Fn(&CellAttributes) -> bool>(
+    term: &TestTerm,
+    row: usize,
+    func: F,
+) -> Vec<bool> {
+    term.screen().visible_lines()[row]
+        .visible_cells()
+        .map(|cell| func(cell.attrs()))
+        .collect()
+}
+
+#[test]
+fn test_deccra() {
+    let mut term = TestTerm::new(4, 6, 0);
+    term.print("abcdef\r\nghijkl\r\nmnopqr\r\nstuvwx");
+
+    term.print("\x1b[1;1;2;2;1;3;4;1$v");
+    assert_eq!(
+        visible_text(&term),
+        vec!["abcdef", "ghijkl", "mnoabr", "stghwx"]
+    );
+
+    // Overlapping areas copy the original contents of the source
+    term.print("\x1b[1;1;2;3;1;2;2;1$v");
+    assert_eq!(
+        visible_text(&term),
+        vec!["abcdef", "gabckl", "mghibr", "stghwx"]
+    );
+
+    // The destination is clipped to the screen
+    term.print("\x1b[1;1;4;6;1;4;5;1$v");
+    assert_eq!(
+        visible_text(&term),
+        vec!["abcdef", "gabckl", "mghibr", "stghab"]
+    );
+}
+
+#[test]
+fn test_decfra_decera() {
+    let mut term = TestTerm::new(3, 5, 0);
+    term.print("hello\r\nworld\r\nthere");
+
+    term.print("\x1b[88;2;2;3;4$x");
+    assert_eq!(visible_text(&term), vec!["hello", "wXXXd", "tXXXe"]);
+
+    // Zero for the bottom and right means the edge of the screen
+    term.print("\x1b[1;4;0;0$z");
+    assert_eq!(visible_text(&term), vec!["hel", "wXX", "tXX"]);
+
+    // In origin mode, the coordinates are relative to the margins
+    // and the area is clipped to them
+    term.print("\x1b[2;3r\x1b[?6h\x1b[1;1;5;1$z");
+    assert_eq!(visible_text(&term), vec!["hel", " XX", " XX"]);
+
+    // The fill uses the current graphic rendition
+    term.print("\x1b[?6l\x1b[7m\x1b[42;1;1;1;2$x");
+    assert_eq!(visible_text(&term), vec!["**l", " XX", " XX"]);
+    assert_eq!(
+        visible_attrs(&term, 0, |attrs| attrs.reverse()),
+        vec![true, true, false]
+    );
+}
+
+#[test]
+fn test_decsera() {
+    let mut term = TestTerm::new(1, 5, 0);
+    term.print("\x1b[7mab\x1b[0mcd");
+    term.print("\x1b[1;1;1;3${");
+    assert_eq!(visible_text(&term), vec!["   d"]);
+    assert_eq!(
+        visible_attrs(&term, 0, |attrs| attrs.reverse()),
+        vec![true, true, false, false]
+    );
+}
+
+#[test]
+fn test_deccara_decrara() {
+    let mut term = TestTerm::new(3, 4, 0);
+    term.print("abcd\r\nefgh\r\nijkl");
+    let bold = |attrs: &CellAttributes| attrs.intensity() == Intensity::Bold;
+
+    // The default extent is a stream of characters, wrapping at the
+    // end of each line
+    term.print("\x1b[1;3;2;2;1$r");
+    assert_eq!(
+        visible_attrs(&term, 0, bold),
+        vec![false, false, true, true]
+    );
+    assert_eq!(
+        visible_attrs(&term, 1, bold),
+        vec![true, true, false, false]
+    );
+    assert_eq!(visible_attrs(&term, 2, bold), vec![false; 4]);
+
+    // With DECSACE selecting a rectangle, only the columns in the
+    // area are changed
+    term.print("\x1b[2*x\x1b[1;1;3;1;7$t");
+    for row in 0..3 {
+        assert_eq!(
+            visible_attrs(&term, row, |attrs| attrs.reverse()),
+            vec![true, false, false, false]
+        );
+    }
+
+    term.print("\x1b[1;1;1;2;7$t");
+    assert_eq!(
+        visible_attrs(&term, 0, |attrs| attrs.reverse()),
+        vec![false, true, false, false]
+    );
+
+    // No attributes resets all of them
+    term.print("\x1b[1;1;3;4$r");
+    for row in 0..3 {
+        assert_eq!(visible_attrs(&term, row, bold), vec![false; 4]);
+        assert_eq!(
+            visible_attrs(&term, row, |attrs| attrs.reverse()),
+            vec![false; 4]
+        );
+    }
+}
+
+#[test]
+fn test_in_band_resize() {
+    let mut term = TestTerm::new(4, 10, 0);
+    // Without mode 2048, resizes are not reported
+    term.resize(TerminalSize {
+        rows: 5,
+        cols: 12,
+        pixel_width: 96,
+        pixel_height: 80,
+        dpi: 0,
+    });
+    assert_eq!(term.take_output(), "");
+
+    // Enabling the mode reports the current size straight away
+    term.set_mode("?2048", true);
+    assert_eq!(term.take_output(), "\x1b[48;5;12;80;96t");
+
+    term.resize(TerminalSize {
+        rows: 6,
+        cols: 20,
+        pixel_width: 160,
+        pixel_height: 96,
+        dpi: 0,
+    });
+    assert_eq!(term.take_output(), "\x1b[48;6;20;96;160t");
+
+    term.set_mode("?2048", false);
+    term.resize(TerminalSize {
+        rows: 4,
+        cols: 10,
+        pixel_width: 80,
+        pixel_height: 64,
+        dpi: 0,
+    });
+    assert_eq!(term.take_output(), "");
+}
+
+#[test]
+fn test_color_scheme_updates() {
+    let mut term = TestTerm::new(4, 10, 0);
+    term.print("\x1b[?996n");
+    assert_eq!(term.take_output(), "\x1b[?997;1n");
+
+    // Without mode 2031, changes are not reported
+    term.print("\x1b]11;rgb:ff/ff/ff\x1b\\");
+    assert_eq!(term.take_output(), "");
+    term.print("\x1b]111\x1b\\");
+
+    term.set_mode("?2031", true);
+    assert_eq!(term.take_output(), "");
+
+    // Switching from a dark to a light background is reported
+    term.print("\x1b]11;rgb:ff/ff/ff\x1b\\");
+    assert_eq!(term.take_output(), "\x1b[?997;2n");
+
+    // Changing the color without changing the scheme is not
+    term.print("\x1b]11;rgb:ee/ee/ee\x1b\\");
+    assert_eq!(term.take_output(), "");
+
+    term.print("\x1b]111\x1b\\");
+    assert_eq!(term.take_output(), "\x1b[?997;1n");
+}
@@ -0,0 +1,204 @@
+//! Testing the kitty graphics protocol
+
+use super::*;
+use wezterm_cell::image::ImageCell;
+use wezterm_surface::line::ImagePlaceholder;
+use wezterm_surface::TextureCoordinate;
+
+/// Transmit an image of `width` x `height` cells, each 8x16 pixels
+fn transmit(term: &mut TestTerm, image_id: u32, width: usize, height: usize) {
+    let pixels = width * 8 * height * 16;
+    term.print(format!(
+        "\x1b_Ga=t,f=24,s={},v={},i={},q=2;{}\x1b\\",
+        width * 8,
+        height * 16,
+        image_id,
+        // Each 3 bytes of black pixels encode as AAAA
+        "AAAA".repeat(pixels)
+    ));
+}
+
+/// Place an image at the given cell without moving the cursor
+fn place(term: &mut TestTerm, image_id: u32, col: isize, row: isize, z: i32) {
+    term.cup(col, row);
+    term.print(format!("\x1b_Ga=p,i={},z={},C=1,q=2\x1b\\", image_id, z));
+}
+
+fn delete(term: &mut TestTerm, keys: &str) {
+    term.print(format!("\x1b_Ga=d,q=2,{}\x1b\\", keys));
+}
+
+fn cell_images(term: &TestTerm, col: usize, row: usize) -> Vec<ImageCell> {
+    term.screen().visible_lines()[row]
+        .get_cell(col)
+        .and_then(|cell| cell.attrs().images())
+        .unwrap_or_default()
+}
+
+/// Returns the ids of the images that are shown on the screen
+fn shown_images(term: &TestTerm) -> Vec<u32> {
+    let mut ids: Vec<u32> = term
+        .screen()
+        .visible_lines()
+        .iter()
+        .flat_map(|line| {
+            line.visible_cells()
+                .filter_map(|cell| cell.attrs().images())
+                .flatten()
+                .filter_map(|image| image.image_id())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
+
+/// Four single cell images: 1 and 3 in the first column with z-index
+/// 0, 2 and 4 in the fourth column with z-index 5, in rows 1 and 3
+fn four_images() -> TestTerm {
+    let mut term = TestTerm::new(5, 10, 0);
+    for image_id in 1..=4 {
+        transmit(&mut term, image_id, 1, 1);
+    }
+    place(&mut term, 1, 0, 0, 0);
+    place(&mut term, 2, 3, 0, 5);
+    place(&mut term, 3, 0, 2, 0);
+    place(&mut term, 4, 3, 2, 5);
+    assert_eq!(shown_images(&term), vec![1, 2, 3, 4]);
+    term
+}
+
+#[test]
+fn test_delete_at_cell() {
+    let mut term = four_images();
+    delete(&mut term, "d=p,x=1,y=1");
+    assert_eq!(shown_images(&term), vec![2, 3, 4]);
+
+    delete(&mut term, "d=q,x=4,y=1,z=0");
+    assert_eq!(
+        shown_images(&term),
+        vec![2, 3, 4],
+        "z-index must match as well"
+    );
+    delete(&mut term, "d=q,x=4,y=1,z=5");
+    assert_eq!(shown_images(&term), vec![3, 4]);
+}
+
+#[test]
+fn test_delete_at_cursor() {
+    let mut term = four_images();
+    term.cup(3, 2);
+    delete(&mut term, "d=c");
+    assert_eq!(shown_images(&term), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_delete_column_and_row() {
+    let mut term = four_images();
+    delete(&mut term, "d=x,x=4");
+    assert_eq!(shown_images(&term), vec![1, 3]);
+    delete(&mut term, "d=y,y=3");
+    assert_eq!(shown_images(&term), vec![1]);
+}
+
+#[test]
+fn test_delete_z_index() {
+    let mut term = four_images();
+    delete(&mut term, "d=z,z=5");
+    assert_eq!(shown_images(&term), vec![1, 3]);
+}
+
+#[test]
+fn test_delete_range() {
+    let mut term = four_images();
+    delete(&mut term, "d=r,x=2,y=3");
+    assert_eq!(shown_images(&term), vec![1, 4]);
+}
+
+#[test]
+fn test_delete_frees_data() {
+    let mut term = four_images();
+    delete(&mut term, "d=p,x=1,y=1");
+    place(&mut term, 1, 0, 0, 0);
+    assert_eq!(
+        shown_images(&term),
+        vec![1, 2, 3, 4],
+        "lowercase keeps the data"
+    );
+
+    delete(&mut term, "d=P,x=1,y=1");
+    place(&mut term, 1, 0, 0, 0);
+    assert_eq!(
+        shown_images(&term),
+        vec![2, 3, 4],
+        "uppercase frees the data"
+    );
+}
+
+#[test]
+fn test_placeholders() {
+    let mut term = TestTerm::new(5, 10, 0);
+    transmit(&mut term, 42, 2, 2);
+    term.print("\x1b_Ga=p,U=1,i=42,c=2,r=2,q=2\x1b\\");
+    term.assert_cursor_pos(0, 0, Some("virtual placements don't move the cursor"), None);
+
+    // The image id is in the foreground color.  The first row has
+    // both diacritics, then the column is inferred; the second row
+    // has only the row diacritic.
+    term.print("\x1b[38;2;0;0;42m");
+    term.print("\u{10EEEE}\u{0305}\u{0305}\u{10EEEE}\r\n");
+    term.print("\u{10EEEE}\u{030D}\u{10EEEE}\u{030D}");
+
+    for (col, row) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+        let images = cell_images(&term, col, row);
+        assert_eq!(images.len(), 1, "cell {},{}", col, row);
+        let (left, top) = (col as f32 / 2., row as f32 / 2.);
+        assert_eq!(images[0].image_id(), Some(42));
+        assert_eq!(images[0].top_left(), TextureCoordinate::new_f32(left, top));
+        assert_eq!(
+            images[0].bottom_right(),
+            TextureCoordinate::new_f32(left + 0.5, top + 0.5)
+        );
+    }
+
+    delete(&mut term, "d=i,i=42");
+    assert_eq!(shown_images(&term), vec![]);
+}
+
+#[test]
+fn test_placeholder_printed_before_placement() {
+    let mut term = TestTerm::new(5, 10, 0);
+    transmit(&mut term, 7, 1, 1);
+    term.print("\x1b[38;5;7m\u{10EEEE}");
+    assert_eq!(shown_images(&term), vec![]);
+
+    term.print("\x1b_Ga=p,U=1,i=7,q=2\x1b\\");
+    assert_eq!(shown_images(&term), vec![7]);
+}
+
+#[test]
+fn test_placeholder_decoding() {
+    let mut term = TestTerm::new(5, 10, 0);
+    // Palette index 7 with a most significant byte of 1, and
+    // placement id 9 in the underline color
+    term.print("\x1b[38;5;7;58;5;9m");
+    term.print("\u{10EEEE}\u{0305}\u{0305}\u{030D}\u{10EEEE}\u{10EEEE}\u{030E}");
+
+    let placeholder = |image_id, row, column| ImagePlaceholder {
+        image_id,
+        placement_id: Some(9),
+        row,
+        column,
+    };
+    assert_eq!(
+        term.screen().visible_lines()[0].image_placeholders(),
+        vec![
+            (0, placeholder(0x0100_0007, 0, 0)),
+            // Continues the cell to the left
+            (1, placeholder(0x0100_0007, 0, 1)),
+            // Starts a new row, so nothing is inferred
+            (2, placeholder(7, 2, 0)),
+        ]
+    );
+}
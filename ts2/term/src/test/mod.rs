@@ -6,6 +6,11 @@ mod c0;
 use bitflags::bitflags;
 mod c1;
 mod csi;
+mod kitty;
+#[cfg(feature = "use_serde")]
+mod scrollback;
+#[cfg(feature = "use_serde")]
+mod snapshot;
 // mod selection; FIXME: port to render layer
 use crate::color::ColorPalette;
 use k9::assert_equal as assert_eq;
@@ -38,31 +43,70 @@ impl Clipboard for LocalClip {
     }
 }
 
+/// Collects the data that the terminal sends back to the application
+#[derive(Clone, Default)]
+struct SharedWriter {
+    buf: Arc<Mutex<Vec<u8>>>,
+}
+
+impl std::io::Write for SharedWriter {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        self.buf.lock().unwrap().extend_from_slice(data);
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 struct TestTerm {
     term: Terminal,
+    output: SharedWriter,
 }
 
 #[derive(Debug)]
 struct TestTermConfig {
     scrollback: usize,
+    compression_threshold: Option<usize>,
 }
 impl TerminalConfiguration for TestTermConfig {
     fn scrollback_size(&self) -> usize {
         self.scrollback
     }
 
+    fn scrollback_compression_threshold(&self) -> Option<usize> {
+        self.compression_threshold
+    }
+
     fn color_palette(&self) -> ColorPalette {
         ColorPalette::default()
     }
+
+    fn enable_kitty_graphics(&self) -> bool {
+        true
+    }
 }
 
 impl TestTerm {
     fn new(height: usize, width: usize, scrollback: usize) -> Self {
+        Self::with_config(
+            height,
+            width,
+            TestTermConfig {
+                scrollback,
+                compression_threshold: None,
+            },
+        )
+    }
+
+    fn with_config(height: usize, width: usize, config: TestTermConfig) -> Self {
         let _ = env_logger::Builder::new()
             .is_test(true)
             .filter_level(log::LevelFilter::Trace)
             .try_init();
 
+        let output = SharedWriter::default();
         let mut term = Terminal::new(
             TerminalSize {
                 rows: height,
@@ -71,15 +115,15 @@ impl TestTerm {
                 pixel_height: height * 16,
                 dpi: 0,
             },
-            Arc::new(TestTermConfig { scrollback }),
+            Arc::new(config),
             "WezTerm",
             "O_o",
-            Box::new(Vec::new()),
+            Box::new(output.clone()),
         );
         let clip: Arc<dyn Clipboard> = Arc::new(LocalClip::new());
         term.set_clipboard(&clip);
 
-        let mut term = Self { term };
+        let mut term = Self { term, output };
 
         term.set_auto_wrap(true);
 
@@ -90,6 +134,12 @@ impl TestTerm {
         self.term.advance_bytes(bytes);
     }
 
+    /// Returns the data sent to the application since the last call
+    fn take_output(&mut self) -> String {
+        let data = std::mem::take(&mut *self.output.buf.lock().unwrap());
+        String::from_utf8(data).unwrap()
+    }
+
     fn set_mode(&mut self, mode: &str, enable: bool) {
         self.print(CSI);
         self.print(mode);
@@ -479,6 +529,216 @@ fn test_semantic() {
     );
 }
 
+#[test]
+fn test_command_records() {
+    use wezterm_escape_parser::osc::FinalTermSemanticPrompt;
+    let mut term = TestTerm::new(10, 20, 0);
+    let run = |term: &mut TestTerm, command: &str, output: &str, status: i32| {
+        term.print(format!(
+            "{}$ {}{}\r\n{}{}{}",
+            OperatingSystemCommand::FinalTermSemanticPrompt(
+                FinalTermSemanticPrompt::FreshLineAndStartPrompt {
+                    aid: None,
+                    cl: None
+                }
+            ),
+            OperatingSystemCommand::FinalTermSemanticPrompt(
+                FinalTermSemanticPrompt::MarkEndOfPromptAndStartOfInputUntilNextMarker
+            ),
+            command,
+            OperatingSystemCommand::FinalTermSemanticPrompt(
+                FinalTermSemanticPrompt::MarkEndOfInputAndStartOfOutput { aid: None }
+            ),
+            output,
+            OperatingSystemCommand::FinalTermSemanticPrompt(
+                FinalTermSemanticPrompt::CommandStatus { status, aid: None }
+            ),
+        ));
+    };
+
+    run(&mut term, "ls", "a\r\nb\r\n", 0);
+    run(&mut term, "false", "", 1);
+    run(&mut term, "echo", "partial", 0);
+
+    let records = term.get_command_records();
+    let summary: Vec<_> = records
+        .iter()
+        .map(|r| {
+            (
+                r.command.as_str(),
+                r.prompt_y,
+                r.output_start_y,
+                r.output_end_y,
+                r.exit_code,
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("ls", 0, 1, Some(3), Some(0)),
+            ("false", 3, 4, Some(4), Some(1)),
+            ("echo", 4, 5, Some(6), Some(0)),
+        ]
+    );
+    assert!(records.iter().all(|r| r.duration().is_some()));
+
+    // A command that is left running ends when the next prompt starts
+    term.print(format!(
+        "{}$ {}sleep\r\n{}",
+        OperatingSystemCommand::FinalTermSemanticPrompt(
+            FinalTermSemanticPrompt::FreshLineAndStartPrompt {
+                aid: None,
+                cl: None
+            }
+        ),
+        OperatingSystemCommand::FinalTermSemanticPrompt(
+            FinalTermSemanticPrompt::MarkEndOfPromptAndStartOfInputUntilNextMarker
+        ),
+        OperatingSystemCommand::FinalTermSemanticPrompt(
+            FinalTermSemanticPrompt::MarkEndOfInputAndStartOfOutput { aid: None }
+        ),
+    ));
+    let running = term.get_command_records().pop().unwrap();
+    assert_eq!(running.command, "sleep");
+    assert!(!running.is_finished());
+    assert_eq!(running.output_rows(9), 7..9);
+
+    term.print(format!(
+        "{}",
+        OperatingSystemCommand::FinalTermSemanticPrompt(
+            FinalTermSemanticPrompt::FreshLineAndStartPrompt {
+                aid: None,
+                cl: None
+            }
+        )
+    ));
+    let interrupted = term.get_command_records().pop().unwrap();
+    assert_eq!(interrupted.output_end_y, Some(7));
+    assert_eq!(interrupted.exit_code, None);
+}
+
+#[derive(Clone, Default)]
+struct AlertCollector {
+    alerts: Arc<Mutex<Vec<Alert>>>,
+}
+
+impl AlertHandler for AlertCollector {
+    fn alert(&mut self, alert: Alert) {
+        self.alerts.lock().unwrap().push(alert);
+    }
+}
+
+#[test]
+fn test_kitty_notification() {
+    let mut term = TestTerm::new(10, 20, 0);
+    let collector = AlertCollector::default();
+    term.set_notification_handler(Box::new(collector.clone()));
+
+    // The title and body may arrive in chunks
+    term.print("\x1b]99;i=1:d=0:u=2;Hello \x1b\\");
+    term.print("\x1b]99;i=1:d=0;world\x1b\\");
+    term.print("\x1b]99;i=1:p=body:a=report:c=1;The body\x1b\\");
+    term.print("\x1b]99;;Another\x1b\\");
+    term.print("\x1b]99;i=1:p=close;\x1b\\");
+
+    assert_eq!(
+        collector.alerts.lock().unwrap().clone(),
+        vec![
+            Alert::DesktopNotification(DesktopNotification {
+                id: Some("1".to_string()),
+                title: Some("Hello world".to_string()),
+                body: "The body".to_string(),
+                urgency: NotificationUrgency::Critical,
+                focus: true,
+                report_activation: true,
+                report_close: true,
+                ..Default::default()
+            }),
+            Alert::DesktopNotification(DesktopNotification {
+                title: Some("Another".to_string()),
+                focus: true,
+                ..Default::default()
+            }),
+            Alert::CloseDesktopNotification {
+                id: "1".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_kitty_notification_close_report() {
+    let mut term = TestTerm::new(10, 20, 0);
+    term.print("\x1b]99;i=1:c=1;One\x1b\\");
+    term.print("\x1b]99;i=2:c=1;Two\x1b\\");
+    term.print("\x1b]99;i=3;Three\x1b\\");
+
+    // A close requested by the application is reported once
+    term.print("\x1b]99;i=1:p=close;\x1b\\");
+    assert_eq!(term.take_output(), "\x1b]99;i=1:p=close;\x1b\\");
+    term.print("\x1b]99;i=1:p=close;\x1b\\");
+    assert_eq!(term.take_output(), "");
+
+    // As is one by the user, even if the application closes it later
+    term.notification_closed("2");
+    assert_eq!(term.take_output(), "\x1b]99;i=2:p=close;\x1b\\");
+    term.print("\x1b]99;i=2:p=close;\x1b\\");
+    assert_eq!(term.take_output(), "");
+
+    // Closes are only reported if they were asked for
+    term.notification_closed("3");
+    assert_eq!(term.take_output(), "");
+}
+
+#[test]
+fn test_text_size() {
+    let mut term = TestTerm::new(4, 10, 0);
+
+    fn cell(term: &TestTerm, col: usize, row: usize) -> (String, usize, Option<ScaledText>) {
+        let line = &term.screen().visible_lines()[row];
+        let cell = line.get_cell(col).unwrap();
+        (
+            cell.str().to_string(),
+            cell.width(),
+            cell.attrs().scaled_text(),
+        )
+    }
+
+    let scaled = |row| {
+        Some(ScaledText {
+            scale: 2,
+            numerator: 0,
+            denominator: 0,
+            vertical_align: TextSizeVerticalAlign::Top,
+            horizontal_align: TextSizeHorizontalAlign::Left,
+            row,
+        })
+    };
+
+    // Each grapheme gets a block of its own, and the cursor moves
+    // along the top row
+    term.print("a\x1b]66;s=2;Hi\x1b\\b");
+    assert_eq!(cell(&term, 1, 0), ("H".to_string(), 2, scaled(0)));
+    assert_eq!(cell(&term, 1, 1), (" ".to_string(), 2, scaled(1)));
+    assert_eq!(cell(&term, 3, 0), ("i".to_string(), 2, scaled(0)));
+    assert_eq!(cell(&term, 5, 0), ("b".to_string(), 1, None));
+    assert_eq!((term.cursor_pos().x, term.cursor_pos().y), (6, 0));
+
+    // A block that doesn't fit wraps to the next line
+    term.print("\x1b]66;s=2:w=3;abc\x1b\\");
+    assert_eq!(cell(&term, 0, 1), ("abc".to_string(), 6, scaled(0)));
+    assert_eq!(cell(&term, 0, 2), (" ".to_string(), 6, scaled(1)));
+    assert_eq!((term.cursor_pos().x, term.cursor_pos().y), (6, 1));
+
+    // The screen scrolls to make room for the rows below the cursor
+    term.print("\r\n\r\n\x1b]66;s=2;X\x1b\\");
+    assert_eq!(cell(&term, 0, 0), ("abc".to_string(), 6, scaled(0)));
+    assert_eq!(cell(&term, 0, 2), ("X".to_string(), 2, scaled(0)));
+    assert_eq!(cell(&term, 0, 3), (" ".to_string(), 2, scaled(1)));
+    assert_eq!((term.cursor_pos().x, term.cursor_pos().y), (2, 2));
+}
+
 #[test]
 fn issue_1161() {
     let mut term = TestTerm::new(1, 5, 0);
@@ -1373,3 +1633,79 @@ fn test_hyperlinks() {
         Compare::TEXT | Compare::ATTRS,
     );
 }
+
+#[derive(Clone, Default)]
+struct DataClip {
+    data: Arc<Mutex<Vec<ClipboardData>>>,
+}
+
+impl Clipboard for DataClip {
+    fn set_contents(
+        &self,
+        _selection: ClipboardSelection,
+        _clip: Option<String>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn set_data(
+        &self,
+        _selection: ClipboardSelection,
+        data: Vec<ClipboardData>,
+    ) -> anyhow::Result<()> {
+        *self.data.lock().unwrap() = data;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_kitty_clipboard_write() {
+    let mut term = TestTerm::new(4, 10, 0);
+    let clip = DataClip::default();
+    let handle: Arc<dyn Clipboard> = Arc::new(clip.clone());
+    term.set_clipboard(&handle);
+
+    term.print("\x1b]5522;type=write\x1b\\");
+    term.print("\x1b]5522;type=wdata:mime=dGV4dC9wbGFpbg==;aGk=\x1b\\");
+    term.print("\x1b]5522;type=wdata:mime=aW1hZ2UvcG5n;UE5H\x1b\\");
+    term.print("\x1b]5522;type=walias:mime=aW1hZ2UvcG5n;aW1hZ2UveC1wbmc=\x1b\\");
+    // Nothing is set until the data is complete
+    assert_eq!(clip.data.lock().unwrap().clone(), vec![]);
+    term.print("\x1b]5522;type=wdata\x1b\\");
+
+    let entry = |mime_type: &str, data: &[u8]| ClipboardData {
+        mime_type: mime_type.to_string(),
+        data: data.to_vec(),
+    };
+    assert_eq!(
+        clip.data.lock().unwrap().clone(),
+        vec![
+            entry("text/plain", b"hi"),
+            entry("image/png", b"PNG"),
+            entry("image/x-png", b"PNG"),
+        ]
+    );
+}
+
+#[test]
+fn test_kitty_clipboard_read_response() {
+    let request = ClipboardReadRequest {
+        selection: ClipboardSelection::Clipboard,
+        mime_types: vec!["image/png".to_string()],
+        id: Some("x".to_string()),
+        name: None,
+    };
+    assert_eq!(
+        request.response(&[ClipboardData {
+            mime_type: "image/png".to_string(),
+            data: b"PNG".to_vec(),
+        }]),
+        "\x1b]5522;type=read:id=x:status=OK\x1b\\\
+         \x1b]5522;type=read:id=x:mime=aW1hZ2UvcG5n:status=DATA;UE5H\x1b\\\
+         \x1b]5522;type=read:id=x:status=DONE\x1b\\"
+    );
+    assert_eq!(
+        request.error_response(ClipboardReadError::PermissionDenied),
+        "\x1b]5522;type=read:id=x:status=EPERM\x1b\\"
+    );
+}
@@ -0,0 +1,232 @@
+//! Testing that compressing the scrollback is transparent
+
+use super::*;
+use crate::color::ColorAttribute;
+
+const ROWS: usize = 5;
+
+fn compressed_term(cols: usize, scrollback: usize) -> TestTerm {
+    TestTerm::with_config(
+        ROWS,
+        cols,
+        TestTermConfig {
+            scrollback,
+            compression_threshold: Some(10),
+        },
+    )
+}
+
+/// Prints `count` numbered lines, every seventh of which is red
+fn print_lines(term: &mut TestTerm, count: usize) {
+    for n in 0..count {
+        if n > 0 {
+            term.print("\r\n");
+        }
+        if n % 7 == 0 {
+            term.print(format!("\x1b[31mline {}\x1b[0m", n));
+        } else {
+            term.print(format!("line {}", n));
+        }
+    }
+}
+
+fn all_text(term: &TestTerm) -> Vec<String> {
+    term.screen()
+        .all_lines()
+        .iter()
+        .map(|line| line.as_str().into_owned())
+        .collect()
+}
+
+fn resize(term: &mut TestTerm, cols: usize) {
+    term.resize(TerminalSize {
+        rows: ROWS,
+        cols,
+        pixel_width: cols * 8,
+        pixel_height: ROWS * 16,
+        dpi: 0,
+    });
+}
+
+#[test]
+fn test_compressed_scrollback_contents() {
+    let mut term = compressed_term(20, 3000);
+    let mut plain = TestTerm::new(ROWS, 20, 3000);
+    print_lines(&mut term, 2000);
+    print_lines(&mut plain, 2000);
+
+    let screen = term.screen();
+    assert!(screen.compressed_rows() > 0);
+    assert_eq!(screen.scrollback_rows(), plain.screen().scrollback_rows());
+    assert_eq!(all_text(&term), all_text(&plain));
+
+    // A range spanning the boundary between two blocks
+    let text: Vec<String> = screen
+        .lines_in_phys_range(250..260)
+        .iter()
+        .map(|line| line.as_str().into_owned())
+        .collect();
+    let expect: Vec<String> = (250..260).map(|n| format!("line {}", n)).collect();
+    assert_eq!(text, expect);
+
+    let line = &screen.lines_in_phys_range(7..8)[0];
+    assert_eq!(
+        line.get_cell(0).unwrap().attrs().foreground(),
+        ColorAttribute::PaletteIndex(1)
+    );
+    let line = &screen.lines_in_phys_range(8..9)[0];
+    assert_eq!(
+        line.get_cell(0).unwrap().attrs().foreground(),
+        ColorAttribute::Default
+    );
+
+    let stable = screen.phys_to_stable_row_index(300);
+    assert_eq!(screen.stable_row_to_phys(stable), Some(300));
+    screen.with_phys_lines(screen.stable_range(&(stable..stable + 2)), |lines| {
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].as_str(), "line 300");
+        assert_eq!(lines[1].as_str(), "line 301");
+    });
+}
+
+#[test]
+fn test_compressed_scrollback_limit() {
+    let mut term = compressed_term(20, 600);
+    let mut plain = TestTerm::new(ROWS, 20, 600);
+    print_lines(&mut term, 3000);
+    print_lines(&mut plain, 3000);
+
+    let screen = term.screen();
+    assert!(screen.compressed_rows() > 0);
+    assert_eq!(screen.scrollback_rows(), 600 + ROWS);
+    assert_eq!(all_text(&term), all_text(&plain));
+    assert_eq!(
+        screen.phys_to_stable_row_index(0),
+        plain.screen().phys_to_stable_row_index(0)
+    );
+}
+
+#[test]
+fn test_compressed_scrollback_search() {
+    let mut term = compressed_term(20, 3000);
+    print_lines(&mut term, 2000);
+
+    let screen = term.screen();
+    let stable_range = screen.phys_to_stable_row_index(0)
+        ..screen.phys_to_stable_row_index(screen.scrollback_rows());
+    let mut found = vec![];
+    screen.for_each_logical_line_in_stable_range(stable_range, |range, lines| {
+        if lines[0].as_str() == "line 1234" {
+            found.push(range.start);
+        }
+        true
+    });
+    assert_eq!(found, vec![screen.phys_to_stable_row_index(1234)]);
+}
+
+#[test]
+fn test_compressed_scrollback_mutation() {
+    let mut term = compressed_term(20, 6000);
+    print_lines(&mut term, 5000);
+
+    let seqno = term.current_seqno();
+    let stable = term.screen().phys_to_stable_row_index(3);
+    term.screen_mut()
+        .for_each_logical_line_in_stable_range_mut(stable..stable + 1, |_, lines| {
+            lines[0].set_cell(0, Cell::new('X', CellAttributes::default()), seqno);
+            false
+        });
+
+    assert_eq!(all_text(&term)[3], "Xine 3");
+
+    // Access enough other blocks that the changed block is
+    // compressed again
+    for start in (256..5000).step_by(256) {
+        term.screen_mut()
+            .with_phys_lines_mut(start..start + 1, |_| {});
+    }
+    term.print("\r\nmore");
+
+    let text = all_text(&term);
+    assert_eq!(text[3], "Xine 3");
+    assert_eq!(text[4], "line 4");
+    assert_eq!(text[4000], "line 4000");
+}
+
+#[test]
+fn test_compressed_scrollback_resize() {
+    let mut term = compressed_term(20, 3000);
+    let mut plain = TestTerm::new(ROWS, 20, 3000);
+    for t in [&mut term, &mut plain] {
+        for n in 0..1000 {
+            t.print(format!("{:04} abcdefghijkl\r\n", n));
+        }
+    }
+
+    resize(&mut term, 8);
+    resize(&mut plain, 8);
+    assert!(term.screen().compressed_rows() > 0);
+    assert_eq!(all_text(&term), all_text(&plain));
+    assert_eq!(
+        all_text(&term)[0..3].to_vec(),
+        vec!["0000 abc", "defghijk", "l"]
+    );
+
+    resize(&mut term, 20);
+    resize(&mut plain, 20);
+    assert_eq!(all_text(&term), all_text(&plain));
+    assert_eq!(all_text(&term)[0], "0000 abcdefghijkl");
+}
+
+#[test]
+fn test_compressed_scrollback_erase() {
+    let mut term = compressed_term(20, 3000);
+    print_lines(&mut term, 2000);
+    assert!(term.screen().compressed_rows() > 0);
+
+    term.erase_in_display(EraseInDisplay::EraseScrollback);
+    assert_eq!(term.screen().scrollback_rows(), ROWS);
+    assert_eq!(term.screen().compressed_rows(), 0);
+    assert_visible_contents(
+        &term,
+        file!(),
+        line!(),
+        &[
+            "line 1995",
+            "line 1996",
+            "line 1997",
+            "line 1998",
+            "line 1999",
+        ],
+    );
+}
+
+#[test]
+fn test_compressed_scrollback_thaw_limit() {
+    let mut term = compressed_term(20, 10000);
+    print_lines(&mut term, 9000);
+
+    // Reading through a shared reference leaves nothing decompressed
+    let rows = term.screen().scrollback_rows();
+    assert_eq!(all_text(&term).len(), rows);
+    let screen = term.screen();
+    let mut logical_lines = 0;
+    screen.for_each_logical_line_in_stable_range(
+        screen.phys_to_stable_row_index(0)..screen.phys_to_stable_row_index(rows),
+        |_, _| {
+            logical_lines += 1;
+            true
+        },
+    );
+    assert_eq!(logical_lines, rows);
+    assert_eq!(screen.thawed_blocks(), 0);
+
+    // Mutable access keeps a bounded number of blocks decompressed
+    for start in (0..rows - 100).step_by(200) {
+        term.screen_mut()
+            .with_phys_lines_mut(start..start + 100, |lines| assert_eq!(lines.len(), 100));
+        assert!(term.screen().thawed_blocks() <= 16);
+    }
+    assert_eq!(term.screen().thawed_blocks(), 16);
+    assert_eq!(all_text(&term)[1234], "line 1234");
+}
@@ -0,0 +1,169 @@
+//! Testing the persistence of the screen contents
+
+use super::*;
+use crate::color::ColorAttribute;
+
+fn print_lines(term: &mut TestTerm, count: usize) {
+    for n in 0..count {
+        if n > 0 {
+            term.print("\r\n");
+        }
+        term.print(format!("line {}", n));
+    }
+}
+
+#[test]
+fn test_snapshot_round_trip() {
+    let mut term = TestTerm::new(3, 20, 10);
+    term.print("\x1b]133;A\x1b\\$ \x1b]133;B\x1b\\ls\r\n\x1b]133;C\x1b\\");
+    term.print("\x1b[31mred\x1b[0m\r\n");
+    let link = Arc::new(Hyperlink::new("https://example.com"));
+    term.hyperlink(&link);
+    term.print("link");
+    term.hyperlink_off();
+    term.print("\r\nlast");
+
+    let snapshot = term.snapshot();
+    assert_eq!(snapshot.physical_rows, 3);
+    assert_eq!(snapshot.physical_cols, 20);
+    assert_eq!(snapshot.cursor.y, 2);
+    let text: Vec<String> = snapshot
+        .lines
+        .iter()
+        .map(|line| line.as_str().into_owned())
+        .collect();
+    assert_eq!(text, vec!["$ ls", "red", "link", "last"]);
+
+    let decoded = ScreenSnapshot::decode(&snapshot.encode().unwrap()).unwrap();
+    assert_eq!(decoded, snapshot);
+
+    let lines = &decoded.lines;
+    assert_eq!(
+        lines[0].get_cell(0).unwrap().attrs().semantic_type(),
+        SemanticType::Prompt
+    );
+    assert_eq!(
+        lines[0].get_cell(2).unwrap().attrs().semantic_type(),
+        SemanticType::Input
+    );
+    assert_eq!(
+        lines[1].get_cell(0).unwrap().attrs().semantic_type(),
+        SemanticType::Output
+    );
+    assert_eq!(
+        lines[1].get_cell(0).unwrap().attrs().foreground(),
+        ColorAttribute::PaletteIndex(1)
+    );
+    assert_eq!(
+        lines[2].get_cell(0).unwrap().attrs().hyperlink(),
+        Some(&link)
+    );
+    assert_eq!(lines[3].get_cell(0).unwrap().attrs().hyperlink(), None);
+}
+
+#[test]
+fn test_snapshot_alt_screen() {
+    let mut term = TestTerm::new(3, 20, 10);
+    print_lines(&mut term, 2);
+    term.set_mode("?1049", true);
+    term.print("full screen app");
+
+    let snapshot = term.snapshot();
+    assert_eq!(snapshot.cursor.y, 1);
+    let text: Vec<String> = snapshot
+        .lines
+        .iter()
+        .map(|line| line.as_str().into_owned())
+        .collect();
+    assert_eq!(text, vec!["line 0", "line 1"]);
+}
+
+#[test]
+fn test_snapshot_decode_errors() {
+    let term = TestTerm::new(3, 20, 10);
+    let data = term.snapshot().encode().unwrap();
+
+    assert!(ScreenSnapshot::decode(b"").is_err());
+    assert!(ScreenSnapshot::decode(&data[1..]).is_err());
+
+    let mut future = data.clone();
+    future[4..8].copy_from_slice(&(SNAPSHOT_VERSION + 1).to_le_bytes());
+    assert!(ScreenSnapshot::decode(&future).is_err());
+
+    let mut truncated = data.clone();
+    truncated.truncate(data.len() / 2);
+    assert!(ScreenSnapshot::decode(&truncated).is_err());
+}
+
+#[test]
+fn test_restore_snapshot() {
+    let mut term = TestTerm::new(3, 10, 10);
+    print_lines(&mut term, 5);
+    let snapshot = ScreenSnapshot::decode(&term.snapshot().encode().unwrap()).unwrap();
+
+    let mut term = TestTerm::new(3, 10, 10);
+    term.restore_snapshot(snapshot);
+    term.print("$ ");
+    assert_all_contents(
+        &term,
+        file!(),
+        line!(),
+        &[
+            "line 0", "line 1", "line 2", "line 3", "line 4", "$ ", "", "",
+        ],
+    );
+    assert_visible_contents(&term, file!(), line!(), &["$ ", "", ""]);
+}
+
+#[test]
+fn test_restore_snapshot_command_rows() {
+    let mut term = TestTerm::new(3, 10, 10);
+    print_lines(&mut term, 2);
+    let snapshot = term.snapshot();
+
+    let mut term = TestTerm::new(3, 10, 10);
+    term.print("\x1b]133;A\x1b\\$ \x1b]133;B\x1b\\ls\r\n\x1b]133;C\x1b\\out\r\n");
+    let record = term.get_command_records().pop().unwrap();
+    assert_eq!(record.prompt_y, 0);
+    assert_eq!(record.output_start_y, 1);
+
+    // The rows that were already present move below the history
+    term.restore_snapshot(snapshot);
+    let record = term.get_command_records().pop().unwrap();
+    assert_eq!(record.prompt_y, 2);
+    assert_eq!(record.output_start_y, 3);
+    let screen = term.screen();
+    let lines = screen.lines_in_phys_range(screen.stable_range(&(2..4)));
+    let text: Vec<String> = lines
+        .iter()
+        .map(|line| line.as_str().into_owned())
+        .collect();
+    assert_eq!(text, vec!["$ ls", "out"]);
+}
+
+#[test]
+fn test_restore_snapshot_rewrap() {
+    let mut term = TestTerm::new(3, 4, 10);
+    term.print("aaaabb\r\ncc");
+    let snapshot = term.snapshot();
+    assert_eq!(snapshot.lines.len(), 3);
+
+    let mut term = TestTerm::new(3, 8, 10);
+    term.restore_snapshot(snapshot.clone());
+    assert_all_contents(&term, file!(), line!(), &["aaaabb", "cc", "", "", ""]);
+
+    let mut term = TestTerm::new(3, 3, 10);
+    term.restore_snapshot(snapshot);
+    assert_all_contents(&term, file!(), line!(), &["aaa", "abb", "cc", "", "", ""]);
+}
+
+#[test]
+fn test_restore_snapshot_scrollback_limit() {
+    let mut term = TestTerm::new(3, 10, 10);
+    print_lines(&mut term, 5);
+    let snapshot = term.snapshot();
+
+    let mut term = TestTerm::new(3, 10, 2);
+    term.restore_snapshot(snapshot);
+    assert_all_contents(&term, file!(), line!(), &["line 3", "line 4", "", "", ""]);
+}
@@ -99,6 +99,8 @@ struct FatAttributes {
     underline_color: ColorAttribute,
     foreground: ColorAttribute,
     background: ColorAttribute,
+    /// Set when the cell is part of a block of scaled text
+    scaled_text: Option<ScaledText>,
 }
 
 impl FatAttributes {
@@ -113,6 +115,48 @@ impl FatAttributes {
         self.underline_color.hash(hasher);
         self.foreground.hash(hasher);
         self.background.hash(hasher);
+        self.scaled_text.hash(hasher);
+    }
+}
+
+pub use wezterm_escape_parser::osc::{TextSizeHorizontalAlign, TextSizeVerticalAlign};
+
+/// Describes a cell that is part of a block of scaled text, as
+/// produced by the kitty text sizing protocol (OSC 66).
+/// The block is `scale` rows high and as wide as the cell in its top
+/// row, which holds the text; the cells in the rows below it are
+/// placeholders that hold no text of their own.
+#[cfg_attr(feature = "use_serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScaledText {
+    /// The number of rows spanned by the block, 1-7
+    pub scale: u8,
+    /// The numerator of the fractional scale of the text
+    pub numerator: u8,
+    /// The denominator of the fractional scale of the text.
+    /// The fractional scale applies only when it is greater than
+    /// the numerator.
+    pub denominator: u8,
+    pub vertical_align: TextSizeVerticalAlign,
+    pub horizontal_align: TextSizeHorizontalAlign,
+    /// Which row of the block this cell is in; 0 is the top row
+    pub row: u8,
+}
+
+impl ScaledText {
+    /// Returns the factor by which the font size is to be multiplied
+    pub fn font_scale(&self) -> f32 {
+        let fraction = if self.numerator > 0 && self.denominator > self.numerator {
+            self.numerator as f32 / self.denominator as f32
+        } else {
+            1.0
+        };
+        self.scale as f32 * fraction
+    }
+
+    /// Returns true if this cell is in the top row of the block
+    pub fn is_top_row(&self) -> bool {
+        self.row == 0
     }
 }
 
@@ -323,6 +367,7 @@ impl CellAttributes {
                 underline_color: ColorAttribute::Default,
                 foreground: ColorAttribute::Default,
                 background: ColorAttribute::Default,
+                scaled_text: None,
             }));
         }
     }
@@ -342,6 +387,7 @@ impl CellAttributes {
                     && fat.underline_color == ColorAttribute::Default
                     && fat.foreground == ColorAttribute::Default
                     && fat.background == ColorAttribute::Default
+                    && fat.scaled_text.is_none()
             })
             .unwrap_or(false);
         if deallocate {
@@ -359,6 +405,18 @@ impl CellAttributes {
             self
         }
     }
+
+    /// Mark the cell as being part of a block of scaled text
+    pub fn set_scaled_text(&mut self, scaled_text: Option<ScaledText>) -> &mut Self {
+        if scaled_text.is_none() && self.fat.is_none() {
+            self
+        } else {
+            self.allocate_fat_attributes();
+            self.fat.as_mut().unwrap().scaled_text = scaled_text;
+            self.deallocate_fat_attributes_if_none();
+            self
+        }
+    }
 }
 
 #[cfg(feature = "use_image")]
@@ -477,6 +535,11 @@ impl CellAttributes {
         Some(fat.image.iter().map(|im| im.as_ref().clone()).collect())
     }
 
+    /// Returns the scaled text block that the cell is part of, if any
+    pub fn scaled_text(&self) -> Option<ScaledText> {
+        self.fat.as_ref().and_then(|fat| fat.scaled_text)
+    }
+
     pub fn underline_color(&self) -> ColorAttribute {
         self.fat
             .as_ref()
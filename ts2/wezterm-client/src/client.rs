@@ -1390,4 +1390,22 @@ impl Client {
     );
     rpc!(adjust_pane_size, AdjustPaneSize, UnitResponse);
     rpc!(web_open, WebOpen, WebOpenResponse);
+    rpc!(web_network_log, WebNetworkLog, WebNetworkLogResponse);
+    rpc!(web_routes, WebRoutes, WebRoutesResponse);
+    rpc!(
+        web_content_blocking,
+        WebContentBlocking,
+        WebContentBlockingResponse
+    );
+    rpc!(web_session_save, WebSessionSave, WebSessionSaveResponse);
+    rpc!(
+        web_session_restore,
+        WebSessionRestore,
+        WebSessionRestoreResponse
+    );
+    rpc!(
+        get_command_records,
+        GetCommandRecords,
+        GetCommandRecordsResponse
+    );
 }
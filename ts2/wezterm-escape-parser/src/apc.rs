@@ -609,6 +609,10 @@ pub struct KittyImagePlacement {
     pub placement_id: Option<u32>,
     /// z=...
     pub z_index: Option<i32>,
+    /// Create a virtual placement, which is displayed by printing
+    /// Unicode placeholder characters rather than at the cursor.
+    /// U=0, U=1
+    pub virtual_placement: bool,
 }
 
 impl KittyImagePlacement {
@@ -629,6 +633,11 @@ impl KittyImagePlacement {
                 _ => return None,
             },
             z_index: geti(keys, "z"),
+            virtual_placement: match get(keys, "U") {
+                None | Some("0") => false,
+                Some("1") => true,
+                _ => return None,
+            },
         })
     }
 
@@ -648,6 +657,10 @@ impl KittyImagePlacement {
         }
 
         set(keys, "z", &self.z_index);
+
+        if self.virtual_placement {
+            keys.insert("U", "1".to_string());
+        }
     }
 }
 
@@ -713,6 +726,11 @@ pub enum KittyImageDelete {
     /// d='z' or d='Z'
     /// Delete all placements that have the specified z-index.
     DeleteZ { z: i32, delete: bool },
+
+    /// d='r' or d='R'
+    /// Delete all images whose image_id is in the range
+    /// first..=last
+    DeleteRange { first: u32, last: u32, delete: bool },
 }
 
 impl KittyImageDelete {
@@ -760,6 +778,11 @@ impl KittyImageDelete {
                 z: geti(keys, "z")?,
                 delete,
             }),
+            'r' | 'R' => Some(Self::DeleteRange {
+                first: geti(keys, "x")?,
+                last: geti(keys, "y")?,
+                delete,
+            }),
             _ => None,
         }
     }
@@ -807,7 +830,7 @@ impl KittyImageDelete {
                 keys.insert("y", y.to_string());
             }
             Self::DeleteAtZ { x, y, z, delete } => {
-                keys.insert("d", d('p', delete));
+                keys.insert("d", d('q', delete));
                 keys.insert("x", x.to_string());
                 keys.insert("y", y.to_string());
                 keys.insert("z", z.to_string());
@@ -824,6 +847,15 @@ impl KittyImageDelete {
                 keys.insert("d", d('z', delete));
                 keys.insert("z", z.to_string());
             }
+            Self::DeleteRange {
+                first,
+                last,
+                delete,
+            } => {
+                keys.insert("d", d('r', delete));
+                keys.insert("x", first.to_string());
+                keys.insert("y", last.to_string());
+            }
         }
     }
 }
@@ -1266,4 +1298,56 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn kitty_virtual_placement() {
+        assert_eq!(
+            KittyImage::parse_apc("Ga=p,U=1,i=42,p=3,c=4,r=2,q=2".as_bytes()).unwrap(),
+            KittyImage::Display {
+                image_id: Some(42),
+                image_number: None,
+                placement: KittyImagePlacement {
+                    x: None,
+                    y: None,
+                    w: None,
+                    h: None,
+                    x_offset: None,
+                    y_offset: None,
+                    columns: Some(4),
+                    rows: Some(2),
+                    do_not_move_cursor: false,
+                    placement_id: Some(3),
+                    z_index: None,
+                    virtual_placement: true,
+                },
+                verbosity: KittyImageVerbosity::Quiet,
+            }
+        );
+    }
+
+    #[test]
+    fn kitty_delete() {
+        assert_eq!(
+            KittyImage::parse_apc("Ga=d,d=R,x=2,y=5".as_bytes()).unwrap(),
+            KittyImage::Delete {
+                what: KittyImageDelete::DeleteRange {
+                    first: 2,
+                    last: 5,
+                    delete: true
+                },
+                verbosity: KittyImageVerbosity::Verbose
+            }
+        );
+
+        let at_z = KittyImage::Delete {
+            what: KittyImageDelete::DeleteAtZ {
+                x: 3,
+                y: 4,
+                z: -1,
+                delete: false,
+            },
+            verbosity: KittyImageVerbosity::Quiet,
+        };
+        assert_eq!(at_z.to_string(), "\x1b_Ga=d,d=q,q=2,x=3,y=4,z=-1");
+    }
 }
@@ -130,6 +130,9 @@ pub enum CSI {
 
     Window(Box<Window>),
 
+    /// Operations on rectangular areas of the screen
+    RectangularArea(Box<RectangularArea>),
+
     Keyboard(Keyboard),
 
     /// ECMA-48 SCP
@@ -225,6 +228,7 @@ impl Display for CSI {
             CSI::Mouse(mouse) => mouse.fmt(f)?,
             CSI::Device(dev) => dev.fmt(f)?,
             CSI::Window(window) => window.fmt(f)?,
+            CSI::RectangularArea(area) => area.fmt(f)?,
             CSI::Keyboard(Keyboard::SetKittyState { flags, mode }) => {
                 write!(f, "={};{}u", flags.bits(), *mode as u16)?
             }
@@ -462,6 +466,18 @@ pub enum Device {
     RequestTerminalNameAndVersion,
     RequestTerminalParameters(i64),
     XtSmGraphics(XtSmGraphics),
+    /// Asks whether the terminal is using a dark or a light color scheme
+    /// <https://contour-terminal.org/vt-extensions/color-palette-update-notifications/>
+    RequestColorScheme,
+    /// The response to RequestColorScheme, which is also sent when
+    /// the scheme changes while ColorSchemeUpdates mode is enabled
+    ColorSchemeReport(ColorScheme),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorScheme {
+    Dark,
+    Light,
 }
 
 impl Display for Device {
@@ -482,6 +498,9 @@ impl Display for Device {
             Device::RequestTerminalNameAndVersion => write!(f, ">q")?,
             Device::RequestTerminalParameters(n) => write!(f, "{};1;1;128;128;1;0x", n + 2)?,
             Device::StatusReport => write!(f, "5n")?,
+            Device::RequestColorScheme => write!(f, "?996n")?,
+            Device::ColorSchemeReport(ColorScheme::Dark) => write!(f, "?997;1n")?,
+            Device::ColorSchemeReport(ColorScheme::Light) => write!(f, "?997;2n")?,
             Device::XtSmGraphics(g) => {
                 write!(f, "?{};{}", g.item, g.action_or_status)?;
                 for v in &g.value {
@@ -578,6 +597,15 @@ pub enum Window {
     PopIconAndWindowTitle,
     PopIconTitle,
     PopWindowTitle,
+    /// Reports the size of the text area when it changes, while
+    /// InBandResize mode is enabled
+    /// <https://gist.github.com/rockorager/e695fb2924d36b2bcf1fff4a3704bd83>
+    InBandResize {
+        rows: i64,
+        cols: i64,
+        pixel_height: i64,
+        pixel_width: i64,
+    },
     /// DECRQCRA; used by esctest
     ChecksumRectangularArea {
         request_id: i64,
@@ -647,6 +675,12 @@ impl Display for Window {
             Window::PopIconAndWindowTitle => write!(f, "23;0t"),
             Window::PopIconTitle => write!(f, "23;1t"),
             Window::PopWindowTitle => write!(f, "23;2t"),
+            Window::InBandResize {
+                rows,
+                cols,
+                pixel_height,
+                pixel_width,
+            } => write!(f, "48;{};{};{};{}t", rows, cols, pixel_height, pixel_width),
             Window::ChecksumRectangularArea {
                 request_id,
                 page_number,
@@ -663,6 +697,146 @@ impl Display for Window {
     }
 }
 
+/// The area that a VT420 rectangular area operation applies to.
+/// The coordinates are relative to the origin that is set by DECOM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rectangle {
+    pub top: OneBased,
+    pub left: OneBased,
+    /// None is the last row of the page
+    pub bottom: Option<OneBased>,
+    /// None is the last column of the page
+    pub right: Option<OneBased>,
+}
+
+impl Rectangle {
+    fn parse(params: &Cracked, first: usize) -> Result<Self, ()> {
+        let edge = |idx: usize| match params.get(idx) {
+            None | Some(CsiParam::Integer(0)) => Ok(None),
+            Some(p) => OneBased::from_esc_param(p).map(Some),
+        };
+        Ok(Self {
+            top: OneBased::from_optional_esc_param(params.get(first))?,
+            left: OneBased::from_optional_esc_param(params.get(first + 1))?,
+            bottom: edge(first + 2)?,
+            right: edge(first + 3)?,
+        })
+    }
+}
+
+impl Display for Rectangle {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        write!(
+            f,
+            "{};{};{};{}",
+            self.top,
+            self.left,
+            self.bottom.map(|b| b.as_one_based()).unwrap_or(0),
+            self.right.map(|r| r.as_one_based()).unwrap_or(0)
+        )
+    }
+}
+
+/// The attributes that DECCARA and DECRARA can change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromPrimitive)]
+pub enum RectangleAttribute {
+    /// All of the attributes; DECRARA reverses each of them
+    AllAttributes = 0,
+    Bold = 1,
+    Underline = 4,
+    Blink = 5,
+    Inverse = 7,
+    Invisible = 8,
+    NoBold = 22,
+    NoUnderline = 24,
+    NoBlink = 25,
+    NoInverse = 27,
+    NoInvisible = 28,
+}
+
+/// How DECCARA and DECRARA interpret their area
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AttributeChangeExtent {
+    /// From the top left position to the bottom right position,
+    /// wrapping at the end of each line in between
+    #[default]
+    Stream,
+    /// Exactly the rectangle
+    Rectangle,
+}
+
+/// Rectangular area operations from the VT420 and later
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RectangularArea {
+    /// DECCRA - Copy Rectangular Area.
+    /// Copies `area` so that its top left is at `top`, `left`.
+    Copy {
+        area: Rectangle,
+        source_page: u32,
+        top: OneBased,
+        left: OneBased,
+        dest_page: u32,
+    },
+    /// DECFRA - Fill Rectangular Area with a character, using the
+    /// current graphic rendition
+    Fill { ch: char, area: Rectangle },
+    /// DECERA - Erase Rectangular Area
+    Erase(Rectangle),
+    /// DECSERA - Selective Erase Rectangular Area.
+    /// Erases the characters that are not protected, leaving the
+    /// attributes alone.
+    SelectiveErase(Rectangle),
+    /// DECCARA - Change Attributes in Rectangular Area
+    ChangeAttributes {
+        area: Rectangle,
+        attributes: Vec<RectangleAttribute>,
+    },
+    /// DECRARA - Reverse Attributes in Rectangular Area
+    ReverseAttributes {
+        area: Rectangle,
+        attributes: Vec<RectangleAttribute>,
+    },
+    /// DECSACE - Select Attribute Change Extent
+    SelectAttributeChangeExtent(AttributeChangeExtent),
+}
+
+impl Display for RectangularArea {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        let attrs = |attributes: &[RectangleAttribute]| -> String {
+            attributes
+                .iter()
+                .map(|a| format!(";{}", *a as u8))
+                .collect()
+        };
+        match self {
+            Self::Copy {
+                area,
+                source_page,
+                top,
+                left,
+                dest_page,
+            } => write!(
+                f,
+                "{};{};{};{};{}$v",
+                area, source_page, top, left, dest_page
+            ),
+            Self::Fill { ch, area } => write!(f, "{};{}$x", *ch as u32, area),
+            Self::Erase(area) => write!(f, "{}$z", area),
+            Self::SelectiveErase(area) => write!(f, "{}${{", area),
+            Self::ChangeAttributes { area, attributes } => {
+                write!(f, "{}{}$r", area, attrs(attributes))
+            }
+            Self::ReverseAttributes { area, attributes } => {
+                write!(f, "{}{}$t", area, attrs(attributes))
+            }
+            Self::SelectAttributeChangeExtent(AttributeChangeExtent::Stream) => write!(f, "1*x"),
+            Self::SelectAttributeChangeExtent(AttributeChangeExtent::Rectangle) => {
+                write!(f, "2*x")
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MouseReport {
     SGR1006 {
@@ -948,6 +1122,14 @@ pub enum DecPrivateModeCode {
     /// Grapheme clustering mode
     GraphemeClustering = 2027,
 
+    /// Report changes between dark and light color schemes
+    /// <https://contour-terminal.org/vt-extensions/color-palette-update-notifications/>
+    ColorSchemeUpdates = 2031,
+
+    /// Report resizes in-band rather than relying on SIGWINCH
+    /// <https://gist.github.com/rockorager/e695fb2924d36b2bcf1fff4a3704bd83>
+    InBandResize = 2048,
+
     /// Applies to sixel and regis modes
     UsePrivateColorRegistersForEachGraphic = 1070,
 
@@ -1832,6 +2014,13 @@ impl<'a> CSIParser<'a> {
             ('k', [.., CsiParam::P(b' ')]) => self.select_character_path(params),
             ('q', [.., CsiParam::P(b' ')]) => self.cursor_style(params),
             ('y', [.., CsiParam::P(b'*')]) => self.checksum_area(params),
+            ('x', [.., CsiParam::P(b'*')]) => self.attribute_change_extent(params),
+            ('v', [.., CsiParam::P(b'$')])
+            | ('x', [.., CsiParam::P(b'$')])
+            | ('z', [.., CsiParam::P(b'$')])
+            | ('{', [.., CsiParam::P(b'$')])
+            | ('r', [.., CsiParam::P(b'$')])
+            | ('t', [.., CsiParam::P(b'$')]) => self.rectangular_area(params),
 
             ('c', [CsiParam::P(b'='), ..]) => self
                 .req_tertiary_device_attributes(params)
@@ -1869,6 +2058,24 @@ impl<'a> CSIParser<'a> {
                 .dec(self.focus(params, 1, 0))
                 .map(|mode| CSI::Mode(Mode::SaveDecPrivateMode(mode))),
             ('m', [CsiParam::P(b'>'), ..]) => self.xterm_key_modifier(params),
+            ('n', [CsiParam::P(b'?'), CsiParam::Integer(996)]) => {
+                Ok(CSI::Device(Box::new(Device::RequestColorScheme)))
+            }
+            (
+                'n',
+                [
+                    CsiParam::P(b'?'),
+                    CsiParam::Integer(997),
+                    CsiParam::P(b';'),
+                    CsiParam::Integer(scheme),
+                ],
+            ) => Ok(CSI::Device(Box::new(Device::ColorSchemeReport(
+                match *scheme {
+                    1 => ColorScheme::Dark,
+                    2 => ColorScheme::Light,
+                    _ => return Err(()),
+                },
+            )))),
 
             ('p', [CsiParam::P(b'!')]) => Ok(CSI::Device(Box::new(Device::SoftReset))),
             ('u', [CsiParam::P(b'='), CsiParam::Integer(flags)]) => {
@@ -2082,6 +2289,75 @@ impl<'a> CSIParser<'a> {
         })))
     }
 
+    fn attribute_change_extent(&mut self, params: &'a [CsiParam]) -> Result<CSI, ()> {
+        let params = Cracked::parse(&params[..params.len() - 1])?;
+        if params.len() > 1 {
+            return Err(());
+        }
+        let extent = match params.opt_int(0).unwrap_or(0) {
+            0 | 1 => AttributeChangeExtent::Stream,
+            2 => AttributeChangeExtent::Rectangle,
+            _ => return Err(()),
+        };
+        Ok(CSI::RectangularArea(Box::new(
+            RectangularArea::SelectAttributeChangeExtent(extent),
+        )))
+    }
+
+    fn rectangular_area(&mut self, params: &'a [CsiParam]) -> Result<CSI, ()> {
+        let params = Cracked::parse(&params[..params.len() - 1])?;
+        let page = |idx: usize| -> Result<u32, ()> {
+            params
+                .opt_int(idx)
+                .unwrap_or(1)
+                .max(1)
+                .try_into()
+                .map_err(|_| ())
+        };
+        // Unrecognized attributes are ignored, and no attributes
+        // is the same as all attributes
+        let attributes = || -> Vec<RectangleAttribute> {
+            let attributes: Vec<RectangleAttribute> = (4..params.len())
+                .filter_map(|idx| FromPrimitive::from_i64(params.opt_int(idx).unwrap_or(0)))
+                .collect();
+            if attributes.is_empty() {
+                vec![RectangleAttribute::AllAttributes]
+            } else {
+                attributes
+            }
+        };
+
+        let area = match self.control {
+            'v' => RectangularArea::Copy {
+                area: Rectangle::parse(&params, 0)?,
+                source_page: page(4)?,
+                top: OneBased::from_optional_esc_param(params.get(5))?,
+                left: OneBased::from_optional_esc_param(params.get(6))?,
+                dest_page: page(7)?,
+            },
+            'x' => RectangularArea::Fill {
+                ch: params
+                    .opt_int(0)
+                    .and_then(|c| u32::try_from(c).ok())
+                    .and_then(char::from_u32)
+                    .ok_or(())?,
+                area: Rectangle::parse(&params, 1)?,
+            },
+            'z' => RectangularArea::Erase(Rectangle::parse(&params, 0)?),
+            '{' => RectangularArea::SelectiveErase(Rectangle::parse(&params, 0)?),
+            'r' => RectangularArea::ChangeAttributes {
+                area: Rectangle::parse(&params, 0)?,
+                attributes: attributes(),
+            },
+            't' => RectangularArea::ReverseAttributes {
+                area: Rectangle::parse(&params, 0)?,
+                attributes: attributes(),
+            },
+            _ => return Err(()),
+        };
+        Ok(CSI::RectangularArea(Box::new(area)))
+    }
+
     fn dsr(&mut self, params: &'a [CsiParam]) -> Result<CSI, ()> {
         match params {
             [CsiParam::Integer(5)] => {
@@ -2642,6 +2918,12 @@ impl<'a> CSIParser<'a> {
                 Some(2) => Ok(Window::PopWindowTitle),
                 _ => Err(()),
             },
+            48 => Ok(Window::InBandResize {
+                rows: params.int(1)?,
+                cols: params.int(2)?,
+                pixel_height: params.opt_int(3).unwrap_or(0),
+                pixel_width: params.opt_int(4).unwrap_or(0),
+            }),
             _ => Err(()),
         }
     }
@@ -3392,4 +3674,130 @@ mod test {
         );
         assert_eq!(encode(&res), "\x1b[?63;1;2;4;6;9;15;22c");
     }
+
+    #[test]
+    fn color_scheme() {
+        let res: Vec<_> =
+            CSI::parse(&[CsiParam::P(b'?'), CsiParam::Integer(996)], false, 'n').collect();
+        assert_eq!(encode(&res), "\x1b[?996n");
+        assert_eq!(res, vec![CSI::Device(Box::new(Device::RequestColorScheme))]);
+
+        let res: Vec<_> = CSI::parse(
+            &[
+                CsiParam::P(b'?'),
+                CsiParam::Integer(997),
+                CsiParam::P(b';'),
+                CsiParam::Integer(2),
+            ],
+            false,
+            'n',
+        )
+        .collect();
+        assert_eq!(encode(&res), "\x1b[?997;2n");
+        assert_eq!(
+            res,
+            vec![CSI::Device(Box::new(Device::ColorSchemeReport(
+                ColorScheme::Light
+            )))]
+        );
+    }
+
+    #[test]
+    fn in_band_resize() {
+        assert_eq!(
+            parse('t', &[48, 24, 80, 384, 640], "\x1b[48;24;80;384;640t"),
+            vec![CSI::Window(Box::new(Window::InBandResize {
+                rows: 24,
+                cols: 80,
+                pixel_height: 384,
+                pixel_width: 640,
+            }))]
+        );
+    }
+
+    fn parse_with_intermediate(
+        control: char,
+        params: &[i64],
+        intermediate: u8,
+        expected: &str,
+    ) -> Vec<CSI> {
+        let mut cparams = vec![];
+        for &p in params {
+            if !cparams.is_empty() {
+                cparams.push(CsiParam::P(b';'));
+            }
+            cparams.push(CsiParam::Integer(p));
+        }
+        cparams.push(CsiParam::P(intermediate));
+        let res = CSI::parse(&cparams, false, control).collect();
+        assert_eq!(encode(&res), expected, "parsed -> {res:?}");
+        res
+    }
+
+    #[test]
+    fn rectangular_area() {
+        let area = Rectangle {
+            top: OneBased::new(2),
+            left: OneBased::new(3),
+            bottom: Some(OneBased::new(4)),
+            right: None,
+        };
+        assert_eq!(
+            parse_with_intermediate('v', &[2, 3, 4, 0, 1, 5, 6], b'$', "\x1b[2;3;4;0;1;5;6;1$v"),
+            vec![CSI::RectangularArea(Box::new(RectangularArea::Copy {
+                area,
+                source_page: 1,
+                top: OneBased::new(5),
+                left: OneBased::new(6),
+                dest_page: 1,
+            }))]
+        );
+        assert_eq!(
+            parse_with_intermediate('x', &[88, 2, 3, 4], b'$', "\x1b[88;2;3;4;0$x"),
+            vec![CSI::RectangularArea(Box::new(RectangularArea::Fill {
+                ch: 'X',
+                area,
+            }))]
+        );
+        assert_eq!(
+            parse_with_intermediate('z', &[2, 3, 4], b'$', "\x1b[2;3;4;0$z"),
+            vec![CSI::RectangularArea(Box::new(RectangularArea::Erase(area)))]
+        );
+        assert_eq!(
+            parse_with_intermediate('{', &[0, 0, 0, 0], b'$', "\x1b[1;1;0;0${"),
+            vec![CSI::RectangularArea(Box::new(
+                RectangularArea::SelectiveErase(Rectangle {
+                    top: OneBased::new(1),
+                    left: OneBased::new(1),
+                    bottom: None,
+                    right: None,
+                })
+            ))]
+        );
+        // Unknown attributes are ignored
+        assert_eq!(
+            parse_with_intermediate('r', &[2, 3, 4, 0, 1, 3, 27], b'$', "\x1b[2;3;4;0;1;27$r"),
+            vec![CSI::RectangularArea(Box::new(
+                RectangularArea::ChangeAttributes {
+                    area,
+                    attributes: vec![RectangleAttribute::Bold, RectangleAttribute::NoInverse],
+                }
+            ))]
+        );
+        assert_eq!(
+            parse_with_intermediate('t', &[2, 3, 4, 0], b'$', "\x1b[2;3;4;0;0$t"),
+            vec![CSI::RectangularArea(Box::new(
+                RectangularArea::ReverseAttributes {
+                    area,
+                    attributes: vec![RectangleAttribute::AllAttributes],
+                }
+            ))]
+        );
+        assert_eq!(
+            parse_with_intermediate('x', &[2], b'*', "\x1b[2*x"),
+            vec![CSI::RectangularArea(Box::new(
+                RectangularArea::SelectAttributeChangeExtent(AttributeChangeExtent::Rectangle)
+            ))]
+        );
+    }
 }
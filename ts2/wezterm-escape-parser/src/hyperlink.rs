@@ -55,6 +55,17 @@ impl Hyperlink {
         }
     }
 
+    pub fn new_implicit_with_params<S: Into<String>, I: IntoIterator<Item = (String, String)>>(
+        uri: S,
+        params: I,
+    ) -> Self {
+        Self {
+            uri: uri.into(),
+            params: params.into_iter().collect(),
+            implicit: true,
+        }
+    }
+
     pub fn new_with_id<S: Into<String>, S2: Into<String>>(uri: S, id: S2) -> Self {
         let mut params = HashMap::new();
         params.insert("id".into(), id.into());
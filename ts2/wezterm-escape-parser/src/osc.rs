@@ -9,6 +9,8 @@ use core::str::FromStr;
 use num_derive::*;
 use num_traits::FromPrimitive;
 use ordered_float::NotNan;
+#[cfg(feature = "use_serde")]
+use serde::{Deserialize, Serialize};
 #[cfg(feature = "std")]
 use std::sync::LazyLock;
 
@@ -50,6 +52,9 @@ pub enum OperatingSystemCommand {
     ResetColors(Vec<u8>),
     RxvtExtension(Vec<String>),
     ConEmuProgress(Progress),
+    KittyNotification(KittyNotification),
+    TextSize(TextSize),
+    KittyClipboard(KittyClipboard),
 
     Unspecified(Vec<Vec<u8>>),
 }
@@ -361,6 +366,13 @@ impl OperatingSystemCommand {
             }
             FinalTermSemanticPrompt => self::FinalTermSemanticPrompt::parse(osc)
                 .map(OperatingSystemCommand::FinalTermSemanticPrompt),
+            KittyNotification => {
+                self::KittyNotification::parse(osc).map(OperatingSystemCommand::KittyNotification)
+            }
+            TextSize => self::TextSize::parse(osc).map(OperatingSystemCommand::TextSize),
+            KittyClipboard => {
+                self::KittyClipboard::parse(osc).map(OperatingSystemCommand::KittyClipboard)
+            }
             ChangeColorNumber => Self::parse_change_color_number(osc),
             ResetColors => Self::parse_reset_colors(osc),
 
@@ -479,6 +491,12 @@ osc_entries!(
     SetTektronixCursorColor = "18",
     SetHighlightForegroundColor = "19",
     SetLogFileName = "46",
+    /// kitty text sizing protocol
+    TextSize = "66",
+    /// kitty desktop notifications
+    KittyNotification = "99",
+    /// kitty clipboard protocol
+    KittyClipboard = "5522",
     SetFont = "50",
     EmacsShell = "51",
     ManipulateSelectionData = "52",
@@ -583,6 +601,9 @@ impl Display for OperatingSystemCommand {
             SystemNotification(s) => write!(f, "9;{}", s)?,
             ITermProprietary(i) => i.fmt(f)?,
             FinalTermSemanticPrompt(i) => i.fmt(f)?,
+            KittyNotification(n) => n.fmt(f)?,
+            TextSize(t) => t.fmt(f)?,
+            KittyClipboard(c) => c.fmt(f)?,
             ResetColors(colors) => {
                 write!(f, "104")?;
                 for c in colors {
@@ -878,6 +899,617 @@ impl Display for FinalTermSemanticPrompt {
     }
 }
 
+/// What the payload of a kitty notification is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KittyNotificationPayload {
+    /// The title of the notification
+    #[default]
+    Title,
+    /// The body of the notification
+    Body,
+    /// Close the notification with the given identifier, or when sent by
+    /// the terminal, reports that it was closed
+    Close,
+    /// Icon image data
+    Icon,
+    /// Asks which notifications are still shown
+    Alive,
+    /// Labels of buttons, separated by U+2028
+    Buttons,
+    /// Asks which features the terminal supports
+    Query,
+}
+
+impl KittyNotificationPayload {
+    fn parse(s: &str) -> Result<Self> {
+        Ok(match s {
+            "title" => Self::Title,
+            "body" => Self::Body,
+            "close" => Self::Close,
+            "icon" => Self::Icon,
+            "alive" => Self::Alive,
+            "buttons" => Self::Buttons,
+            "?" => Self::Query,
+            _ => bail!("invalid kitty notification payload type {}", s),
+        })
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Title => "title",
+            Self::Body => "body",
+            Self::Close => "close",
+            Self::Icon => "icon",
+            Self::Alive => "alive",
+            Self::Buttons => "buttons",
+            Self::Query => "?",
+        }
+    }
+}
+
+/// When a kitty notification should be shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KittyNotificationOccasion {
+    Always,
+    /// Only when the window is not focused
+    Unfocused,
+    /// Only when the window is not focused, or the pane is not visible
+    Invisible,
+}
+
+impl KittyNotificationOccasion {
+    fn parse(s: &str) -> Result<Self> {
+        Ok(match s {
+            "always" => Self::Always,
+            "unfocused" => Self::Unfocused,
+            "invisible" => Self::Invisible,
+            _ => bail!("invalid kitty notification occasion {}", s),
+        })
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Always => "always",
+            Self::Unfocused => "unfocused",
+            Self::Invisible => "invisible",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromPrimitive)]
+pub enum KittyNotificationUrgency {
+    Low = 0,
+    Normal = 1,
+    Critical = 2,
+}
+
+/// The kitty desktop notification protocol, OSC 99.
+/// Keys that are not present are None, so that chunks of a notification
+/// can be merged and so that the sequence encodes as it was received.
+/// <https://sw.kovidgoyal.net/kitty/desktop-notifications/>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct KittyNotification {
+    /// `i`: identifies the notification in reports and updates
+    pub id: Option<String>,
+    /// `d`: false when more chunks of the notification follow
+    pub done: Option<bool>,
+    /// `p`: what the payload is
+    pub payload_type: KittyNotificationPayload,
+    /// `a=focus`: focus the window when the notification is activated
+    pub focus: Option<bool>,
+    /// `a=report`: report activation to the application
+    pub report: Option<bool>,
+    /// `c`: report to the application when the notification is closed
+    pub close_event: Option<bool>,
+    /// `f`: the name of the application
+    pub app_name: Option<String>,
+    /// `g`: identifies a cached icon
+    pub icon_cache_id: Option<String>,
+    /// `n`: names of icons to use
+    pub icon_names: Vec<String>,
+    /// `o`: when the notification should be shown
+    pub occasion: Option<KittyNotificationOccasion>,
+    /// `s`: the name of a sound to play
+    pub sound: Option<String>,
+    /// `t`: the types of the notification
+    pub types: Vec<String>,
+    /// `u`: the urgency of the notification
+    pub urgency: Option<KittyNotificationUrgency>,
+    /// `w`: close the notification after this many milliseconds;
+    /// -1 lets the system decide
+    pub timeout: Option<i64>,
+    pub payload: Vec<u8>,
+}
+
+impl KittyNotification {
+    /// Returns true if this is the last chunk of the notification
+    pub fn is_done(&self) -> bool {
+        self.done.unwrap_or(true)
+    }
+
+    /// Returns true if the window should be focused on activation
+    pub fn focus_on_activation(&self) -> bool {
+        self.focus.unwrap_or(true)
+    }
+
+    /// Returns true if activation should be reported to the application
+    pub fn report_on_activation(&self) -> bool {
+        self.report.unwrap_or(false)
+    }
+
+    fn parse(osc: &[&[u8]]) -> Result<Self> {
+        ensure!(osc.len() >= 2, "wrong param count");
+        let mut notif = Self::default();
+        let mut encoded = false;
+
+        fn decode_string(value: &str) -> Result<String> {
+            Ok(String::from_utf8(base64_decode(value)?)?)
+        }
+
+        let metadata = str::from_utf8(osc[1])?;
+        for item in metadata.split(':').filter(|item| !item.is_empty()) {
+            let (key, value) = item
+                .split_once('=')
+                .ok_or_else(|| format_err!("invalid kitty notification metadata {}", item))?;
+            match key {
+                "a" => {
+                    for action in value.split(',') {
+                        let (enable, action) = match action.strip_prefix('-') {
+                            Some(action) => (false, action),
+                            None => (true, action),
+                        };
+                        match action {
+                            "focus" => notif.focus = Some(enable),
+                            "report" => notif.report = Some(enable),
+                            _ => {}
+                        }
+                    }
+                }
+                "c" => notif.close_event = Some(value == "1"),
+                "d" => notif.done = Some(value != "0"),
+                "e" => encoded = value == "1",
+                "f" => notif.app_name = Some(decode_string(value)?),
+                "g" => notif.icon_cache_id = Some(value.to_string()),
+                "i" => notif.id = Some(value.to_string()),
+                "n" => notif.icon_names.push(decode_string(value)?),
+                "o" => notif.occasion = Some(KittyNotificationOccasion::parse(value)?),
+                "p" => notif.payload_type = KittyNotificationPayload::parse(value)?,
+                "s" => notif.sound = Some(decode_string(value)?),
+                "t" => notif.types.push(decode_string(value)?),
+                "u" => {
+                    notif.urgency = Some(
+                        FromPrimitive::from_u8(value.parse()?)
+                            .ok_or_else(|| format_err!("invalid kitty urgency {}", value))?,
+                    )
+                }
+                "w" => notif.timeout = Some(value.parse()?),
+                // Unknown keys are to be ignored
+                _ => {}
+            }
+        }
+
+        // The payload may have been split at semicolons
+        let mut payload = vec![];
+        for (idx, chunk) in osc[2..].iter().enumerate() {
+            if idx > 0 {
+                payload.push(b';');
+            }
+            payload.extend_from_slice(chunk);
+        }
+        notif.payload = if encoded {
+            base64_decode(&payload)?
+        } else {
+            payload
+        };
+
+        Ok(notif)
+    }
+}
+
+impl Display for KittyNotification {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let mut keys = vec![];
+        if let Some(id) = &self.id {
+            keys.push(format!("i={}", id));
+        }
+        if let Some(done) = self.done {
+            keys.push(format!("d={}", if done { 1 } else { 0 }));
+        }
+        if self.payload_type != KittyNotificationPayload::Title {
+            keys.push(format!("p={}", self.payload_type.as_str()));
+        }
+        let mut actions = vec![];
+        for (enable, action) in [(self.focus, "focus"), (self.report, "report")] {
+            match enable {
+                Some(true) => actions.push(action.to_string()),
+                Some(false) => actions.push(format!("-{}", action)),
+                None => {}
+            }
+        }
+        if !actions.is_empty() {
+            keys.push(format!("a={}", actions.join(",")));
+        }
+        if let Some(close_event) = self.close_event {
+            keys.push(format!("c={}", if close_event { 1 } else { 0 }));
+        }
+        if let Some(app_name) = &self.app_name {
+            keys.push(format!("f={}", base64_encode(app_name)));
+        }
+        if let Some(icon_cache_id) = &self.icon_cache_id {
+            keys.push(format!("g={}", icon_cache_id));
+        }
+        for name in &self.icon_names {
+            keys.push(format!("n={}", base64_encode(name)));
+        }
+        if let Some(occasion) = self.occasion {
+            keys.push(format!("o={}", occasion.as_str()));
+        }
+        if let Some(sound) = &self.sound {
+            keys.push(format!("s={}", base64_encode(sound)));
+        }
+        for t in &self.types {
+            keys.push(format!("t={}", base64_encode(t)));
+        }
+        if let Some(urgency) = self.urgency {
+            keys.push(format!("u={}", urgency as u8));
+        }
+        if let Some(timeout) = self.timeout {
+            keys.push(format!("w={}", timeout));
+        }
+
+        // The payload is sent as-is only if it is text without any
+        // control characters
+        let plain = str::from_utf8(&self.payload)
+            .ok()
+            .filter(|text| !text.chars().any(char::is_control));
+        match plain {
+            Some(text) => write!(f, "99;{};{}", keys.join(":"), text),
+            None => {
+                keys.push("e=1".to_string());
+                write!(f, "99;{};{}", keys.join(":"), base64_encode(&self.payload))
+            }
+        }
+    }
+}
+
+/// How text is placed vertically within a block from the kitty text
+/// sizing protocol, when it is scaled to less than the block height
+#[cfg_attr(feature = "use_serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, FromPrimitive)]
+pub enum TextSizeVerticalAlign {
+    #[default]
+    Top = 0,
+    Bottom = 1,
+    Center = 2,
+}
+
+/// How text is placed horizontally within a block from the kitty text
+/// sizing protocol, when it is narrower than the block
+#[cfg_attr(feature = "use_serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, FromPrimitive)]
+pub enum TextSizeHorizontalAlign {
+    #[default]
+    Left = 0,
+    Right = 1,
+    Center = 2,
+}
+
+/// The largest amount of text that may be sent in a single
+/// text sizing sequence, in bytes
+const MAX_TEXT_SIZE_TEXT: usize = 4096;
+
+/// The kitty text sizing protocol, OSC 66.
+/// The text is rendered in a block of cells that is `scale` rows high.
+/// <https://sw.kovidgoyal.net/kitty/text-sizing-protocol/>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextSize {
+    /// `s`: the block is this many times taller and wider than the
+    /// text would normally be, 1-7
+    pub scale: u8,
+    /// `w`: the width of the block in units of `scale` cells, 0-7.
+    /// 0 means that each grapheme is placed in a block of its own,
+    /// as wide as the grapheme would normally be.
+    pub width: u8,
+    /// `n`: the numerator of the fractional scale, 0-15
+    pub numerator: u8,
+    /// `d`: the denominator of the fractional scale, 0-15.
+    /// The fractional scale applies only when it is greater than
+    /// the numerator.
+    pub denominator: u8,
+    /// `v`: the vertical alignment of fractionally scaled text
+    pub vertical_align: TextSizeVerticalAlign,
+    /// `h`: the horizontal alignment of text within the block
+    pub horizontal_align: TextSizeHorizontalAlign,
+    pub text: String,
+}
+
+impl Default for TextSize {
+    fn default() -> Self {
+        Self {
+            scale: 1,
+            width: 0,
+            numerator: 0,
+            denominator: 0,
+            vertical_align: TextSizeVerticalAlign::default(),
+            horizontal_align: TextSizeHorizontalAlign::default(),
+            text: String::new(),
+        }
+    }
+}
+
+impl TextSize {
+    fn parse(osc: &[&[u8]]) -> Result<Self> {
+        ensure!(osc.len() >= 2, "wrong param count");
+        let mut size = Self::default();
+
+        fn number(value: &str, max: u8) -> Result<u8> {
+            let n: u8 = value.parse()?;
+            ensure!(n <= max, "text sizing value {} is out of range", n);
+            Ok(n)
+        }
+
+        let metadata = str::from_utf8(osc[1])?;
+        for item in metadata.split(':').filter(|item| !item.is_empty()) {
+            let (key, value) = item
+                .split_once('=')
+                .ok_or_else(|| format_err!("invalid text sizing metadata {}", item))?;
+            match key {
+                "s" => {
+                    size.scale = number(value, 7)?;
+                    ensure!(size.scale > 0, "text sizing scale must be at least 1");
+                }
+                "w" => size.width = number(value, 7)?,
+                "n" => size.numerator = number(value, 15)?,
+                "d" => size.denominator = number(value, 15)?,
+                "v" => {
+                    size.vertical_align = FromPrimitive::from_u8(value.parse()?)
+                        .ok_or_else(|| format_err!("invalid vertical alignment {}", value))?
+                }
+                "h" => {
+                    size.horizontal_align = FromPrimitive::from_u8(value.parse()?)
+                        .ok_or_else(|| format_err!("invalid horizontal alignment {}", value))?
+                }
+                // Unknown keys are to be ignored
+                _ => {}
+            }
+        }
+
+        // The text may have been split at semicolons
+        let mut text = vec![];
+        for (idx, chunk) in osc[2..].iter().enumerate() {
+            if idx > 0 {
+                text.push(b';');
+            }
+            text.extend_from_slice(chunk);
+        }
+        ensure!(
+            text.len() <= MAX_TEXT_SIZE_TEXT,
+            "text sizing text is too long"
+        );
+        size.text = String::from_utf8(text)?;
+
+        Ok(size)
+    }
+
+    /// Returns the fraction of the block height that the text is
+    /// scaled to occupy
+    pub fn fractional_scale(&self) -> f32 {
+        if self.numerator > 0 && self.denominator > self.numerator {
+            self.numerator as f32 / self.denominator as f32
+        } else {
+            1.0
+        }
+    }
+}
+
+impl Display for TextSize {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let mut keys = vec![];
+        if self.scale != 1 {
+            keys.push(format!("s={}", self.scale));
+        }
+        if self.width != 0 {
+            keys.push(format!("w={}", self.width));
+        }
+        if self.numerator != 0 {
+            keys.push(format!("n={}", self.numerator));
+        }
+        if self.denominator != 0 {
+            keys.push(format!("d={}", self.denominator));
+        }
+        if self.vertical_align != TextSizeVerticalAlign::default() {
+            keys.push(format!("v={}", self.vertical_align as u8));
+        }
+        if self.horizontal_align != TextSizeHorizontalAlign::default() {
+            keys.push(format!("h={}", self.horizontal_align as u8));
+        }
+        write!(f, "66;{};{}", keys.join(":"), self.text)
+    }
+}
+
+/// The kind of a kitty clipboard protocol request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KittyClipboardType {
+    /// Read from the clipboard, or when sent by the terminal,
+    /// the response to a read
+    #[default]
+    Read,
+    /// Start writing to the clipboard, or when sent by the terminal,
+    /// the response to a write
+    Write,
+    /// Data for a write. An empty `wdata` ends the write.
+    WriteData,
+    /// Declares the mime types in the payload to be aliases of `mime`
+    WriteAlias,
+}
+
+impl KittyClipboardType {
+    fn parse(s: &str) -> Result<Self> {
+        Ok(match s {
+            "read" => Self::Read,
+            "write" => Self::Write,
+            "wdata" => Self::WriteData,
+            "walias" => Self::WriteAlias,
+            _ => bail!("invalid kitty clipboard type {}", s),
+        })
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::WriteData => "wdata",
+            Self::WriteAlias => "walias",
+        }
+    }
+}
+
+/// The status reported by the terminal in a kitty clipboard response
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KittyClipboardStatus {
+    /// The read was accepted and data follows
+    Ok,
+    /// A chunk of data
+    Data,
+    /// The request completed
+    Done,
+    /// `EPERM`: the user denied access
+    PermissionDenied,
+    /// `ENOSYS`: the clipboard is not available
+    NotSupported,
+    /// `EINVAL`: the request was malformed
+    Invalid,
+    /// `EIO`: an error occurred while transferring the data
+    Io,
+    /// `EBUSY`: another request is in progress
+    Busy,
+}
+
+impl KittyClipboardStatus {
+    fn parse(s: &str) -> Result<Self> {
+        Ok(match s {
+            "OK" => Self::Ok,
+            "DATA" => Self::Data,
+            "DONE" => Self::Done,
+            "EPERM" => Self::PermissionDenied,
+            "ENOSYS" => Self::NotSupported,
+            "EINVAL" => Self::Invalid,
+            "EIO" => Self::Io,
+            "EBUSY" => Self::Busy,
+            _ => bail!("invalid kitty clipboard status {}", s),
+        })
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "OK",
+            Self::Data => "DATA",
+            Self::Done => "DONE",
+            Self::PermissionDenied => "EPERM",
+            Self::NotSupported => "ENOSYS",
+            Self::Invalid => "EINVAL",
+            Self::Io => "EIO",
+            Self::Busy => "EBUSY",
+        }
+    }
+}
+
+/// The kitty clipboard protocol, OSC 5522, which transfers clipboard
+/// data of arbitrary mime types.
+/// <https://sw.kovidgoyal.net/kitty/clipboard/>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct KittyClipboard {
+    /// `type`: what is being requested or reported
+    pub kind: KittyClipboardType,
+    /// `id`: chosen by the application and echoed in responses
+    pub id: Option<String>,
+    /// `mime`: the mime type of the payload
+    pub mime: Option<String>,
+    /// `loc=primary`: use the primary selection rather than
+    /// the clipboard
+    pub primary: bool,
+    /// `status`: the outcome of a request, in responses
+    pub status: Option<KittyClipboardStatus>,
+    /// `name`: a name that identifies the application to the user
+    pub name: Option<String>,
+    /// `pw`: a password that the user can remember access by
+    pub password: Option<String>,
+    /// The decoded payload
+    pub payload: Vec<u8>,
+}
+
+impl KittyClipboard {
+    /// Returns the space separated list of mime types in the payload
+    /// of a read or walias request
+    pub fn payload_mime_types(&self) -> Vec<String> {
+        String::from_utf8_lossy(&self.payload)
+            .split_whitespace()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    fn parse(osc: &[&[u8]]) -> Result<Self> {
+        ensure!(osc.len() >= 2, "wrong param count");
+        let mut clip = Self::default();
+
+        fn decode_string(value: &str) -> Result<String> {
+            Ok(String::from_utf8(base64_decode(value)?)?)
+        }
+
+        let metadata = str::from_utf8(osc[1])?;
+        for item in metadata.split(':').filter(|item| !item.is_empty()) {
+            let (key, value) = item
+                .split_once('=')
+                .ok_or_else(|| format_err!("invalid kitty clipboard metadata {}", item))?;
+            match key {
+                "type" => clip.kind = KittyClipboardType::parse(value)?,
+                "id" => clip.id = Some(value.to_string()),
+                "mime" => clip.mime = Some(decode_string(value)?),
+                "loc" => clip.primary = value == "primary",
+                "status" => clip.status = Some(KittyClipboardStatus::parse(value)?),
+                "name" => clip.name = Some(decode_string(value)?),
+                "pw" => clip.password = Some(decode_string(value)?),
+                // Unknown keys are to be ignored
+                _ => {}
+            }
+        }
+
+        let payload = osc[2..].concat();
+        clip.payload = base64_decode(&payload)?;
+
+        Ok(clip)
+    }
+}
+
+impl Display for KittyClipboard {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let mut keys = vec![format!("type={}", self.kind.as_str())];
+        if let Some(id) = &self.id {
+            keys.push(format!("id={}", id));
+        }
+        if self.primary {
+            keys.push("loc=primary".to_string());
+        }
+        if let Some(mime) = &self.mime {
+            keys.push(format!("mime={}", base64_encode(mime)));
+        }
+        if let Some(name) = &self.name {
+            keys.push(format!("name={}", base64_encode(name)));
+        }
+        if let Some(password) = &self.password {
+            keys.push(format!("pw={}", base64_encode(password)));
+        }
+        if let Some(status) = self.status {
+            keys.push(format!("status={}", status.as_str()));
+        }
+        write!(f, "5522;{}", keys.join(":"))?;
+        if !self.payload.is_empty() {
+            write!(f, ";{}", base64_encode(&self.payload))?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Progress {
     None,
@@ -1997,4 +2629,157 @@ mod test {
             )))
         );
     }
+
+    #[test]
+    fn text_size() {
+        assert_eq!(
+            parse(&["66", "s=2", "Hello"], "\x1b]66;s=2;Hello\x1b\\"),
+            OperatingSystemCommand::TextSize(TextSize {
+                scale: 2,
+                text: "Hello".into(),
+                ..Default::default()
+            })
+        );
+
+        let size = parse(
+            &["66", "w=3:n=1:d=2:v=2:h=1", "a", "b"],
+            "\x1b]66;w=3:n=1:d=2:v=2:h=1;a;b\x1b\\",
+        );
+        assert_eq!(
+            size,
+            OperatingSystemCommand::TextSize(TextSize {
+                width: 3,
+                numerator: 1,
+                denominator: 2,
+                vertical_align: TextSizeVerticalAlign::Center,
+                horizontal_align: TextSizeHorizontalAlign::Right,
+                text: "a;b".into(),
+                ..Default::default()
+            })
+        );
+        match size {
+            OperatingSystemCommand::TextSize(size) => assert_eq!(size.fractional_scale(), 0.5),
+            _ => unreachable!(),
+        }
+
+        // Out of range values are rejected
+        assert_eq!(
+            parse(&["66", "s=8", "x"], "\x1b]66;s=8;x\x1b\\"),
+            OperatingSystemCommand::Unspecified(vec![
+                b"66".to_vec(),
+                b"s=8".to_vec(),
+                b"x".to_vec()
+            ])
+        );
+    }
+
+    #[test]
+    fn kitty_notification() {
+        assert_eq!(
+            parse(&["99", "", "Hello world"], "\x1b]99;;Hello world\x1b\\"),
+            OperatingSystemCommand::KittyNotification(KittyNotification {
+                payload: b"Hello world".to_vec(),
+                ..Default::default()
+            })
+        );
+
+        // The payload may contain semicolons
+        assert_eq!(
+            parse(
+                &["99", "i=1:d=0:a=report,-focus:u=2", "a", "b"],
+                "\x1b]99;i=1:d=0:a=-focus,report:u=2;a;b\x1b\\"
+            ),
+            OperatingSystemCommand::KittyNotification(KittyNotification {
+                id: Some("1".into()),
+                done: Some(false),
+                focus: Some(false),
+                report: Some(true),
+                urgency: Some(KittyNotificationUrgency::Critical),
+                payload: b"a;b".to_vec(),
+                ..Default::default()
+            })
+        );
+
+        // Unknown keys are ignored, and the payload may be base64 encoded
+        let notif = parse(
+            &[
+                "99",
+                "i=1:p=body:e=1:z=what:o=unfocused:w=500",
+                "bGluZQpicmVhaw==",
+            ],
+            "\x1b]99;i=1:p=body:o=unfocused:w=500:e=1;bGluZQpicmVhaw==\x1b\\",
+        );
+        assert_eq!(
+            notif,
+            OperatingSystemCommand::KittyNotification(KittyNotification {
+                id: Some("1".into()),
+                payload_type: KittyNotificationPayload::Body,
+                occasion: Some(KittyNotificationOccasion::Unfocused),
+                timeout: Some(500),
+                payload: b"line\nbreak".to_vec(),
+                ..Default::default()
+            })
+        );
+
+        assert_eq!(
+            parse(
+                &["99", "i=x:p=?:f=YXBw:t=aW0=:t=ZW1haWw="],
+                "\x1b]99;i=x:p=?:f=YXBw:t=aW0=:t=ZW1haWw=;\x1b\\"
+            ),
+            OperatingSystemCommand::KittyNotification(KittyNotification {
+                id: Some("x".into()),
+                payload_type: KittyNotificationPayload::Query,
+                app_name: Some("app".into()),
+                types: vec!["im".into(), "email".into()],
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn kitty_clipboard() {
+        let read = parse(
+            &[
+                "5522",
+                "type=read:id=a1:loc=primary",
+                "dGV4dC9wbGFpbiBpbWFnZS9wbmc=",
+            ],
+            "\x1b]5522;type=read:id=a1:loc=primary;dGV4dC9wbGFpbiBpbWFnZS9wbmc=\x1b\\",
+        );
+        match &read {
+            OperatingSystemCommand::KittyClipboard(clip) => {
+                assert_eq!(clip.kind, KittyClipboardType::Read);
+                assert_eq!(clip.id.as_deref(), Some("a1"));
+                assert!(clip.primary);
+                assert_eq!(clip.payload_mime_types(), vec!["text/plain", "image/png"]);
+            }
+            _ => unreachable!(),
+        }
+
+        // Unknown keys are ignored
+        assert_eq!(
+            parse(
+                &["5522", "type=wdata:mime=aW1hZ2UvcG5n:z=1", "iVBORw=="],
+                "\x1b]5522;type=wdata:mime=aW1hZ2UvcG5n;iVBORw==\x1b\\"
+            ),
+            OperatingSystemCommand::KittyClipboard(KittyClipboard {
+                kind: KittyClipboardType::WriteData,
+                mime: Some("image/png".into()),
+                payload: vec![0x89, b'P', b'N', b'G'],
+                ..Default::default()
+            })
+        );
+
+        assert_eq!(
+            parse(
+                &["5522", "type=write:status=EPERM"],
+                "\x1b]5522;type=write:status=EPERM\x1b\\"
+            ),
+            OperatingSystemCommand::KittyClipboard(KittyClipboard {
+                kind: KittyClipboardType::Write,
+                status: Some(KittyClipboardStatus::PermissionDenied),
+                ..Default::default()
+            })
+        );
+    }
 }
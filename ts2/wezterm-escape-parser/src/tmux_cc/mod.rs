@@ -138,11 +138,42 @@ pub struct PaneLayout {
     pub pane_top: u64,
 }
 
-#[derive(Debug)]
-pub enum WindowLayout {
-    SplitVertical(Vec<PaneLayout>),
-    SplitHorizontal(Vec<PaneLayout>),
-    SinglePane(PaneLayout),
+/// A tmux window layout as a tree.  The geometry of a split is held
+/// in a `PaneLayout` whose `pane_id` is 0.
+#[derive(Debug, Clone)]
+pub enum LayoutNode {
+    Pane(PaneLayout),
+    /// The children are side by side; `{...}` in the layout string
+    SplitHorizontal {
+        cell: PaneLayout,
+        children: Vec<LayoutNode>,
+    },
+    /// The children are one above the other; `[...]` in the layout string
+    SplitVertical {
+        cell: PaneLayout,
+        children: Vec<LayoutNode>,
+    },
+}
+
+impl LayoutNode {
+    /// The size and position of this node
+    pub fn cell(&self) -> &PaneLayout {
+        match self {
+            Self::Pane(cell)
+            | Self::SplitHorizontal { cell, .. }
+            | Self::SplitVertical { cell, .. } => cell,
+        }
+    }
+
+    /// The panes in this node, from top left to bottom right
+    pub fn panes(&self) -> Vec<PaneLayout> {
+        match self {
+            Self::Pane(pane) => vec![*pane],
+            Self::SplitHorizontal { children, .. } | Self::SplitVertical { children, .. } => {
+                children.iter().flat_map(|child| child.panes()).collect()
+            }
+        }
+    }
 }
 
 fn parse_pane_id(pair: Pair<Rule>) -> Result<TmuxPaneId> {
@@ -727,113 +758,34 @@ fn parse_layout_pane(pair: Pair<Rule>) -> Result<PaneLayout> {
     });
 }
 
-fn parse_layout_inner(
-    mut pairs: Pairs<Rule>,
-    result: &mut Vec<WindowLayout>,
-) -> Result<Vec<PaneLayout>> {
-    let mut stack = Vec::new();
-
-    while let Some(pair) = pairs.next() {
-        let rule = pair.as_rule();
-        match rule {
-            Rule::layout_split_horizontal | Rule::layout_split_vertical => {
-                let mut pairs_inner = pair.into_inner();
-                let pair = pairs_inner
+fn parse_layout_node(pair: Pair<Rule>) -> Result<LayoutNode> {
+    let rule = pair.as_rule();
+    match rule {
+        Rule::layout_pane => Ok(LayoutNode::Pane(parse_layout_pane(pair)?)),
+        Rule::layout_split_horizontal | Rule::layout_split_vertical => {
+            let mut pairs = pair.into_inner();
+            let cell = parse_layout_pane(
+                pairs
                     .next()
-                    .ok_or_else(|| format_err!("no pairs!?"))?;
-                let mut pane = parse_layout_pane(pair)?;
-
-                if result.is_empty() {
-                    // Fake one, to flag it is not a TmuxLayout::SinglePane will pop
-                    result.push(WindowLayout::SplitHorizontal(vec![]));
-                }
-
-                let mut layout_inner = parse_layout_inner(pairs_inner, result)?;
-
-                let last_item = layout_inner
-                    .pop()
-                    .ok_or_else(|| format_err!("wrong layout format"))?;
-
-                pane.pane_id = last_item.pane_id;
-
-                layout_inner.insert(0, pane.clone());
-
-                if let Rule::layout_split_horizontal = rule {
-                    result.insert(0, WindowLayout::SplitHorizontal(layout_inner));
-                } else {
-                    result.insert(0, WindowLayout::SplitVertical(layout_inner));
-                }
-
-                stack.push(pane);
-            }
-            Rule::layout_pane => {
-                let pane = parse_layout_pane(pair)?;
-
-                // SinglePane
-                if result.is_empty() {
-                    result.insert(0, WindowLayout::SinglePane(pane));
-                    return Ok(stack);
-                }
-
-                stack.push(pane);
+                    .ok_or_else(|| format_err!("wrong layout format"))?,
+            )?;
+            let children = pairs.map(parse_layout_node).collect::<Result<Vec<_>>>()?;
+            if rule == Rule::layout_split_horizontal {
+                Ok(LayoutNode::SplitHorizontal { cell, children })
+            } else {
+                Ok(LayoutNode::SplitVertical { cell, children })
             }
-            Rule::EOI
-            | Rule::any_text
-            | Rule::begin
-            | Rule::client_detached
-            | Rule::client_name
-            | Rule::client_session_changed
-            | Rule::config_error
-            | Rule::r#continue
-            | Rule::end
-            | Rule::error
-            | Rule::exit
-            | Rule::extended_output
-            | Rule::layout_change
-            | Rule::layout_split_pane
-            | Rule::layout_window
-            | Rule::line
-            | Rule::line_entire
-            | Rule::message
-            | Rule::number
-            | Rule::output
-            | Rule::pane_id
-            | Rule::pane_mode_changed
-            | Rule::paste_buffer_changed
-            | Rule::paste_buffer_deleted
-            | Rule::pause
-            | Rule::session_changed
-            | Rule::session_id
-            | Rule::session_renamed
-            | Rule::session_window_changed
-            | Rule::sessions_changed
-            | Rule::subscription_changed
-            | Rule::unlinked_window_add
-            | Rule::unlinked_window_close
-            | Rule::unlinked_window_renamed
-            | Rule::window_add
-            | Rule::window_close
-            | Rule::window_id
-            | Rule::window_layout
-            | Rule::window_pane_changed
-            | Rule::window_renamed
-            | Rule::word => bail!("Should not reach here"),
         }
+        _ => bail!("unexpected {:?} in layout", rule),
     }
-
-    Ok(stack)
 }
 
-pub fn parse_layout(layout: &str) -> Result<Vec<WindowLayout>> {
-    let mut result = Vec::new();
-    let pairs = parser::TmuxParser::parse(Rule::layout_window, layout)?;
-
-    let _ = parse_layout_inner(pairs, &mut result)?;
-    if result.len() > 1 {
-        let _ = result.pop();
-    }
-
-    Ok(result)
+/// Parses a window layout, without its leading checksum, into a tree
+pub fn parse_layout_tree(layout: &str) -> Result<LayoutNode> {
+    let pair = parser::TmuxParser::parse(Rule::layout_window, layout)?
+        .next()
+        .ok_or_else(|| format_err!("empty layout"))?;
+    parse_layout_node(pair)
 }
 
 pub struct Parser {
@@ -1146,31 +1098,49 @@ here
     }
 
     #[test]
-    fn test_parse_layout() {
-        let layout_case1 = "158x40,0,0,72".to_string();
-        let layout_case2 = "158x40,0,0[158x20,0,0,69,158x19,0,21{79x19,0,21,70,78x19,80,21[78x9,80,21,71,78x9,80,31,73]}]".to_string();
-        let layout_case3 = "158x40,0,0{79x40,0,0[79x20,0,0,74,79x19,0,21{39x19,0,21,76,39x19,40,21,77}],78x40,80,0,75}".to_string();
-
-        let mut layout = parse_layout(&layout_case1).unwrap();
-        let l = layout.pop().unwrap();
-        assert!(if let WindowLayout::SinglePane(p) = l {
-            assert_eq!(p.pane_width, 158);
-            assert_eq!(p.pane_height, 40);
-            assert_eq!(p.pane_left, 0);
-            assert_eq!(p.pane_top, 0);
-            assert_eq!(p.pane_id, 72);
-            true
-        } else {
-            false
-        });
+    fn test_parse_layout_tree() {
+        let layout = parse_layout_tree("158x40,0,0,72").unwrap();
+        let LayoutNode::Pane(pane) = layout else {
+            panic!("expected a single pane, got {layout:?}");
+        };
+        assert_eq!(pane.pane_id, 72);
+        assert_eq!((pane.pane_width, pane.pane_height), (158, 40));
+        assert_eq!((pane.pane_left, pane.pane_top), (0, 0));
+
+        let layout = parse_layout_tree(
+            "158x40,0,0[158x20,0,0,69,158x19,0,21{79x19,0,21,70,78x19,80,21[78x9,80,21,71,78x9,80,31,73]}]",
+        )
+        .unwrap();
+        assert!(
+            matches!(&layout, LayoutNode::SplitVertical { children, .. } if children.len() == 2)
+        );
+        let panes: Vec<_> = layout.panes().iter().map(|p| p.pane_id).collect();
+        assert_eq!(panes, vec![69, 70, 71, 73]);
+
+        let layout = parse_layout_tree(
+            "158x40,0,0{79x40,0,0[79x20,0,0,74,79x19,0,21{39x19,0,21,76,39x19,40,21,77}],78x40,80,0,75}",
+        )
+        .unwrap();
+        let LayoutNode::SplitHorizontal { cell, children } = &layout else {
+            panic!("expected a horizontal split, got {layout:?}");
+        };
+        assert_eq!((cell.pane_width, cell.pane_height), (158, 40));
+        assert_eq!(children.len(), 2);
+        let LayoutNode::SplitVertical {
+            cell,
+            children: inner,
+        } = &children[0]
+        else {
+            panic!("expected a vertical split, got {:?}", children[0]);
+        };
+        assert_eq!((cell.pane_width, cell.pane_height), (79, 40));
+        assert_eq!(inner.len(), 2);
+        assert!(
+            matches!(&inner[1], LayoutNode::SplitHorizontal { children, .. } if children.len() == 2)
+        );
+        assert_eq!(children[1].cell().pane_left, 80);
 
-        layout = parse_layout(&layout_case2).unwrap();
-        assert!(matches!(&layout[0], WindowLayout::SplitVertical(_x)));
-        assert!(matches!(&layout[1], WindowLayout::SplitHorizontal(_x)));
-        assert!(matches!(&layout[2], WindowLayout::SplitVertical(_x)));
-        layout = parse_layout(&layout_case3).unwrap();
-        assert!(matches!(&layout[0], WindowLayout::SplitHorizontal(_x)));
-        assert!(matches!(&layout[1], WindowLayout::SplitVertical(_x)));
-        assert!(matches!(&layout[2], WindowLayout::SplitHorizontal(_x)));
+        let panes: Vec<_> = layout.panes().iter().map(|p| p.pane_id).collect();
+        assert_eq!(panes, vec![74, 76, 77, 75]);
     }
 }
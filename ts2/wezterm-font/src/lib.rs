@@ -440,6 +440,11 @@ impl FallbackResolveInfo {
                     ),
                     url: Some(url.to_string()),
                     timeout: Some(Duration::from_secs(15)),
+                    icon: None,
+                    on_click: None,
+                    on_close: None,
+                    tag: None,
+                    urgency: None,
                 }
                 .show();
             } else {
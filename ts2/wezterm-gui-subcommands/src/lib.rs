@@ -101,6 +101,20 @@ pub struct StartCommand {
     #[arg(long, requires = "domain")]
     pub attach: bool,
 
+    /// Recreate the windows, tabs, splits and browser panes saved
+    /// by `wezterm cli web-session save`, instead of spawning PROG.
+    /// If FILE is omitted, session.json in the wezterm data
+    /// directory is used.
+    /// This always starts a new instance of wezterm.
+    #[arg(
+        long,
+        value_name = "FILE",
+        num_args = 0..=1,
+        value_hint = ValueHint::FilePath,
+        conflicts_with_all = &["attach", "prog"]
+    )]
+    pub restore_session: Option<Option<PathBuf>>,
+
     /// Instead of executing your shell, run PROG.
     /// For example: `wezterm start -- bash -l` will spawn bash
     /// as if it were a login shell. [aliases: -e]
@@ -0,0 +1,149 @@
+//! Clipboard and selection support for browser panes
+//!
+//! Cut, copy, paste and select-all are performed by the focused frame,
+//! so that they behave exactly like the browser's own edit commands.
+//! Reading the selected text, inserting text and finding the current
+//! link have no direct CEF API and are done via the DevTools protocol.
+
+use super::BrowserState;
+use anyhow::{anyhow, Context};
+use cef::future::BrowserHostExt;
+use cef::{Browser, ImplBrowser, ImplDictionaryValue, ImplFrame};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::future::Future;
+
+/// Returns the selected text, including the selection inside of a
+/// focused text field, which `getSelection` doesn't report
+const SELECTION_SCRIPT: &str = r#"(function() {
+  const element = document.activeElement;
+  if (element && typeof element.value === "string"
+      && typeof element.selectionStart === "number") {
+    return element.value.substring(element.selectionStart, element.selectionEnd);
+  }
+  const selection = window.getSelection();
+  return selection ? selection.toString() : "";
+})()"#;
+
+/// Returns the link containing the selection or the focused element,
+/// falling back to the page itself
+const LINK_SCRIPT: &str = r#"(function() {
+  function closestLink(node) {
+    const element = node && node.nodeType !== Node.ELEMENT_NODE ? node.parentElement : node;
+    return element && element.closest ? element.closest("a[href]") : null;
+  }
+  const selection = window.getSelection();
+  const link = closestLink(selection && selection.anchorNode)
+    || closestLink(document.activeElement);
+  if (link) {
+    return { url: link.href, title: link.textContent.trim() };
+  }
+  return { url: location.href, title: document.title };
+})()"#;
+
+/// An editing command that is run in the focused frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditCommand {
+    Cut,
+    Copy,
+    Paste,
+    SelectAll,
+    Delete,
+}
+
+impl EditCommand {
+    /// Run the command in the focused frame of `browser`, or in its
+    /// main frame if nothing has focus
+    pub fn run(self, browser: &Browser) {
+        let Some(frame) = browser.focused_frame().or_else(|| browser.main_frame()) else {
+            return;
+        };
+        match self {
+            Self::Cut => frame.cut(),
+            Self::Copy => frame.copy(),
+            Self::Paste => frame.paste(),
+            Self::SelectAll => frame.select_all(),
+            Self::Delete => frame.del(),
+        }
+    }
+}
+
+/// A link and the text that it is shown with
+#[derive(Debug, Clone, Deserialize)]
+pub struct Link {
+    pub url: String,
+    #[serde(default)]
+    pub title: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EvaluateResponse {
+    result: RemoteObject,
+    exception_details: Option<ExceptionDetails>,
+}
+
+#[derive(Deserialize)]
+struct RemoteObject {
+    #[serde(default)]
+    value: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct ExceptionDetails {
+    text: String,
+}
+
+impl BrowserState {
+    /// Run an editing command in the focused frame
+    pub fn edit(&self, command: EditCommand) {
+        command.run(&self.browser);
+    }
+
+    /// Resolves to the text that is currently selected in the page
+    pub fn selection_text(&self) -> impl Future<Output = anyhow::Result<String>> {
+        evaluate(&self.browser, SELECTION_SCRIPT)
+    }
+
+    /// Resolves to the selected link, or to the page itself when no
+    /// link is selected
+    pub fn current_link(&self) -> impl Future<Output = anyhow::Result<Link>> {
+        evaluate(&self.browser, LINK_SCRIPT)
+    }
+
+    /// Insert text at the cursor as though it had been typed
+    pub fn insert_text(&self, text: &str) {
+        match serde_json::to_string(&serde_json::json!({ "text": text })) {
+            Ok(params) => self.send_devtools_method("Input.insertText", &params),
+            Err(err) => log::error!("[CEF] Failed to encode text to insert: {:#}", err),
+        }
+    }
+}
+
+/// Evaluate `expression` in the main frame and deserialize its result.
+/// The DevTools method is called right away, as it must be called on
+/// the UI thread, while the returned future can be polled anywhere.
+fn evaluate<T: DeserializeOwned>(
+    browser: &Browser,
+    expression: &str,
+) -> impl Future<Output = anyhow::Result<T>> {
+    let response = browser.host().and_then(|host| {
+        let mut params = cef::dictionary_value_create()?;
+        params.set_string(Some(&"expression".into()), Some(&expression.into()));
+        params.set_bool(Some(&"returnByValue".into()), 1);
+        Some(host.execute_dev_tools_method_async("Runtime.evaluate", Some(&mut params)))
+    });
+
+    async move {
+        let response = response
+            .ok_or_else(|| anyhow!("browser is closed"))?
+            .await
+            .context("Runtime.evaluate")?;
+        let response: EvaluateResponse =
+            serde_json::from_slice(&response).context("parsing Runtime.evaluate result")?;
+        if let Some(exception) = response.exception_details {
+            anyhow::bail!("script failed: {}", exception.text);
+        }
+        serde_json::from_value(response.result.value).context("unexpected script result")
+    }
+}
@@ -0,0 +1,177 @@
+//! Filter list based content blocking for browser panes
+//!
+//! The filter lists configured for a profile are compiled into a
+//! `mux::web_filter::FilterSet`, which is shared by every browser that
+//! uses the same lists.  Requests that match are canceled from the
+//! ResourceRequestHandler in `network`, and element hiding rules are
+//! injected into each frame as a stylesheet when it starts loading.
+//! The per-pane state lives in the mux so that `wezterm cli web-block`
+//! can toggle it and report the counts.
+
+use cef::{CefString, Frame, ImplFrame, ImplRequest, Request, ResourceType};
+use mux::web::SharedContentBlocking;
+use mux::web_filter::{FilterRequest, FilterSet, RequestType};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+struct CachedFilters {
+    mtimes: Vec<Option<SystemTime>>,
+    filters: Arc<FilterSet>,
+}
+
+lazy_static::lazy_static! {
+    /// Compiled filter lists, keyed by the list of files they came from
+    static ref FILTER_CACHE: Mutex<HashMap<Vec<PathBuf>, CachedFilters>> =
+        Mutex::new(HashMap::new());
+}
+
+/// Load and compile the specified filter lists, reusing a previously
+/// compiled set if none of the files have changed since.
+/// Returns None if there are no lists or they can't be loaded.
+pub fn load_filter_lists(paths: &[PathBuf]) -> Option<Arc<FilterSet>> {
+    if paths.is_empty() {
+        return None;
+    }
+
+    let mtimes: Vec<_> = paths
+        .iter()
+        .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect();
+
+    let mut cache = FILTER_CACHE.lock();
+    if let Some(cached) = cache.get(paths) {
+        if cached.mtimes == mtimes {
+            return Some(Arc::clone(&cached.filters));
+        }
+    }
+
+    match FilterSet::load(paths) {
+        Ok(filters) => {
+            log::info!(
+                "[CEF] loaded {} filters ({} unsupported filters skipped)",
+                filters.len(),
+                filters.skipped()
+            );
+            let filters = Arc::new(filters);
+            cache.insert(
+                paths.to_vec(),
+                CachedFilters {
+                    mtimes,
+                    filters: Arc::clone(&filters),
+                },
+            );
+            Some(filters)
+        }
+        Err(err) => {
+            log::error!("[CEF] content blocking disabled: {:#}", err);
+            None
+        }
+    }
+}
+
+/// Applies a set of filters to the requests and pages of a browser
+#[derive(Clone)]
+pub struct ContentBlocker {
+    filters: Arc<FilterSet>,
+    state: SharedContentBlocking,
+}
+
+impl ContentBlocker {
+    pub fn new(filters: Arc<FilterSet>, state: SharedContentBlocking) -> Self {
+        Self { filters, state }
+    }
+
+    pub fn state(&self) -> SharedContentBlocking {
+        Arc::clone(&self.state)
+    }
+
+    /// Returns true if the request should be canceled.
+    /// `source_url` is the URL of the frame making the request.
+    pub(crate) fn should_block(&self, request: &Request, source_url: Option<&str>) -> bool {
+        let resource_type = request.resource_type();
+        if resource_type == ResourceType::MAIN_FRAME {
+            self.state.reset_page();
+        }
+        if !self.state.enabled() {
+            return false;
+        }
+
+        let url = CefString::from(&request.url()).to_string();
+        let request_type = request_type(resource_type);
+        // A navigation is initiated by the page it replaces, but the
+        // filters treat a top level document as its own source
+        let source_url = match source_url {
+            Some(source_url) if request_type != RequestType::Document && !source_url.is_empty() => {
+                source_url
+            }
+            _ => url.as_str(),
+        };
+
+        let blocked = self.filters.should_block(&FilterRequest {
+            url: &url,
+            source_url,
+            request_type,
+        });
+        if blocked {
+            log::trace!("[CEF] content blocker canceled {}", url);
+            self.state.record_blocked();
+        }
+        blocked
+    }
+
+    /// Add the element hiding rules that apply to the frame's page
+    pub(crate) fn inject_stylesheet(&self, frame: &Frame) {
+        if !self.state.enabled() {
+            return;
+        }
+        let url = CefString::from(&frame.url()).to_string();
+        let Some(css) = self.filters.hiding_stylesheet(&url) else {
+            return;
+        };
+        let Ok(css) = serde_json::to_string(&css) else {
+            return;
+        };
+
+        // The document may not have an element to attach to yet when
+        // the load starts
+        let script = format!(
+            r#"(function() {{
+  const css = {css};
+  function inject() {{
+    const style = document.createElement("style");
+    style.textContent = css;
+    (document.head || document.documentElement).appendChild(style);
+  }}
+  if (document.documentElement) {{
+    inject();
+  }} else {{
+    document.addEventListener("DOMContentLoaded", inject, {{ once: true }});
+  }}
+}})();"#
+        );
+        frame.execute_java_script(
+            Some(&CefString::from(script.as_str())),
+            Some(&CefString::from(url.as_str())),
+            0,
+        );
+    }
+}
+
+fn request_type(resource_type: ResourceType) -> RequestType {
+    match resource_type {
+        ResourceType::MAIN_FRAME => RequestType::Document,
+        ResourceType::SUB_FRAME => RequestType::Subdocument,
+        ResourceType::STYLESHEET => RequestType::Stylesheet,
+        ResourceType::SCRIPT => RequestType::Script,
+        ResourceType::IMAGE | ResourceType::FAVICON => RequestType::Image,
+        ResourceType::FONT_RESOURCE => RequestType::Font,
+        ResourceType::MEDIA => RequestType::Media,
+        ResourceType::OBJECT | ResourceType::PLUGIN_RESOURCE => RequestType::Object,
+        ResourceType::XHR => RequestType::XmlHttpRequest,
+        ResourceType::PING | ResourceType::CSP_REPORT => RequestType::Ping,
+        _ => RequestType::Other,
+    }
+}
@@ -0,0 +1,210 @@
+//! Input method support for browser panes
+//!
+//! The window reports text that is being composed, either by an input
+//! method or after a dead key, as a `DeadKeyStatus` and then delivers
+//! the committed text as a key event.  Both are passed on to the IME
+//! methods of the browser host.  CEF reports where the composition is
+//! drawn, which is used to place the input method's candidate window.
+
+use super::BrowserState;
+use cef::{CompositionUnderline, ImplBrowserHost, Range, Rect};
+use mux::pane::PaneId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// The bounds of the composition in view coordinates, as last reported
+/// by the render handler
+pub(crate) type ImeBounds = Rc<RefCell<Option<Rect>>>;
+
+/// Passed where CEF expects a range to replace, meaning that the
+/// composition or the current selection is replaced
+fn invalid_range() -> Range {
+    Range {
+        from: u32::MAX,
+        to: u32::MAX,
+    }
+}
+
+/// Returns the smallest rectangle containing all of `bounds`
+pub(crate) fn union_bounds(bounds: &[Rect]) -> Option<Rect> {
+    let first = bounds.first()?;
+    let (mut left, mut top) = (first.x, first.y);
+    let (mut right, mut bottom) = (first.x + first.width, first.y + first.height);
+    for rect in &bounds[1..] {
+        left = left.min(rect.x);
+        top = top.min(rect.y);
+        right = right.max(rect.x + rect.width);
+        bottom = bottom.max(rect.y + rect.height);
+    }
+    Some(Rect {
+        x: left,
+        y: top,
+        width: right - left,
+        height: bottom - top,
+    })
+}
+
+/// A pane that can hold an uncommitted composition
+pub trait Composition {
+    fn is_composing(&self) -> bool;
+    fn finish_composing(&self);
+}
+
+impl Composition for BrowserState {
+    fn is_composing(&self) -> bool {
+        self.composing.get()
+    }
+
+    fn finish_composing(&self) {
+        self.ime_finish_composing_text();
+    }
+}
+
+/// Commit the compositions of all of `browsers` other than the one in
+/// the `active` pane.  Only the active pane receives input, so any
+/// other composition would otherwise be left showing indefinitely.
+/// `active` is None when the window doesn't have the focus.
+pub fn finish_inactive_compositions<C: Composition>(
+    browsers: &HashMap<PaneId, C>,
+    active: Option<PaneId>,
+) {
+    for (pane_id, browser) in browsers {
+        if Some(*pane_id) != active && browser.is_composing() {
+            browser.finish_composing();
+        }
+    }
+}
+
+impl BrowserState {
+    /// Show `text` as the uncommitted composition, replacing any
+    /// previous composition.  Empty text ends the composition.
+    pub fn ime_set_composition(&self, text: &str) {
+        let Some(host) = self.host() else {
+            return;
+        };
+        if text.is_empty() {
+            self.ime_cancel_composition();
+            return;
+        }
+
+        // Ranges are in UTF-16 code units
+        let len = text.encode_utf16().count() as u32;
+        let underline = CompositionUnderline {
+            range: Range { from: 0, to: len },
+            color: 0xff00_0000,
+            ..Default::default()
+        };
+        host.ime_set_composition(
+            Some(&text.into()),
+            Some(std::slice::from_ref(&underline)),
+            Some(&invalid_range()),
+            Some(&Range { from: len, to: len }),
+        );
+        self.composing.set(true);
+    }
+
+    /// Insert `text`, replacing the composition if there is one
+    pub fn ime_commit_text(&self, text: &str) {
+        if let Some(host) = self.host() {
+            host.ime_commit_text(Some(&text.into()), Some(&invalid_range()), 0);
+        }
+        self.composing.set(false);
+        self.ime_bounds.borrow_mut().take();
+    }
+
+    /// Discard the composition, if there is one
+    pub fn ime_cancel_composition(&self) {
+        if !self.composing.replace(false) {
+            return;
+        }
+        if let Some(host) = self.host() {
+            host.ime_cancel_composition();
+        }
+        self.ime_bounds.borrow_mut().take();
+    }
+
+    /// Commit the composition as it stands, if there is one
+    pub fn ime_finish_composing_text(&self) {
+        if !self.composing.replace(false) {
+            return;
+        }
+        if let Some(host) = self.host() {
+            host.ime_finish_composing_text(0);
+        }
+        self.ime_bounds.borrow_mut().take();
+    }
+
+    /// Returns true while there is an uncommitted composition
+    pub fn is_composing(&self) -> bool {
+        self.composing.get()
+    }
+
+    /// Returns the bounds of the composition in window pixels, for
+    /// positioning the candidate window
+    pub fn ime_rect(&self) -> Option<::window::Rect> {
+        if !self.composing.get() {
+            return None;
+        }
+        let bounds = self.ime_bounds.borrow();
+        let bounds = bounds.as_ref()?;
+        let pane = self.get_pane_rect();
+        let scale = self.device_scale_factor;
+        Some(::window::Rect::new(
+            ::window::Point::new(
+                (pane.x + bounds.x as f32 * scale) as isize,
+                (pane.y + bounds.y as f32 * scale) as isize,
+            ),
+            ::window::Size::new(
+                (bounds.width as f32 * scale) as isize,
+                (bounds.height as f32 * scale) as isize,
+            ),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+
+    #[derive(Default)]
+    struct TestComposition {
+        composing: Cell<bool>,
+        finished: Cell<usize>,
+    }
+
+    impl Composition for TestComposition {
+        fn is_composing(&self) -> bool {
+            self.composing.get()
+        }
+
+        fn finish_composing(&self) {
+            self.composing.set(false);
+            self.finished.set(self.finished.get() + 1);
+        }
+    }
+
+    #[test]
+    fn switching_panes_finishes_composition() {
+        let mut browsers = HashMap::new();
+        browsers.insert(1, TestComposition::default());
+        browsers.insert(2, TestComposition::default());
+        browsers[&1].composing.set(true);
+
+        finish_inactive_compositions(&browsers, Some(1));
+        assert!(browsers[&1].is_composing());
+
+        // Another pane becomes active
+        finish_inactive_compositions(&browsers, Some(2));
+        assert!(!browsers[&1].is_composing());
+        assert_eq!(browsers[&1].finished.get(), 1);
+        assert_eq!(browsers[&2].finished.get(), 0);
+
+        // The window loses the focus
+        browsers[&2].composing.set(true);
+        finish_inactive_compositions(&browsers, None);
+        assert!(!browsers[&2].is_composing());
+        assert_eq!(browsers[&1].finished.get(), 1);
+    }
+}
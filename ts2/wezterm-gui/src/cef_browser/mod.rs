@@ -4,18 +4,36 @@
 //! that overlay terminal panes.
 
 use cef::{
-    self, rc::Rc, wrap_client, wrap_context_menu_handler, wrap_render_handler,
-    wrap_request_context_handler, Browser, BrowserHost, BrowserSettings, Client,
-    ContextMenuHandler, ImplBrowser, ImplBrowserHost, ImplClient, ImplContextMenuHandler,
-    ImplMenuModel, ImplRenderHandler, ImplRequestContextHandler, KeyEvent, KeyEventType,
-    MouseButtonType, MouseEvent, PaintElementType, Rect, RenderHandler, RequestContextHandler,
-    RequestContextSettings, ScreenInfo, WindowInfo, WrapClient, WrapContextMenuHandler,
-    WrapRenderHandler, WrapRequestContextHandler,
+    self, rc::Rc, wrap_client, wrap_context_menu_handler, wrap_load_handler, wrap_render_handler,
+    wrap_request_context_handler, wrap_request_handler, Browser, BrowserHost, BrowserSettings,
+    Client, ContextMenuHandler, ImplBrowser, ImplBrowserHost, ImplClient, ImplContextMenuHandler,
+    ImplFrame, ImplLoadHandler, ImplMenuModel, ImplRenderHandler, ImplRequestContextHandler,
+    ImplRequestHandler, KeyEvent, KeyEventType, LoadHandler, MouseButtonType, MouseEvent,
+    PaintElementType, Rect, Registration, RenderHandler, RequestContextHandler,
+    RequestContextSettings, RequestHandler, ResourceRequestHandler, ScreenInfo, TransitionType,
+    WindowInfo, WrapClient, WrapContextMenuHandler, WrapLoadHandler, WrapRenderHandler,
+    WrapRequestContextHandler, WrapRequestHandler,
 };
+use config::ConfigHandle;
 use mux::pane::PaneId;
-use std::cell::RefCell;
+use mux::web::{RouteRegistry, SharedContentBlocking, SharedNetworkLog};
+use mux::web_session::SharedBrowserSession;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::sync::Arc;
+use wezterm_term::color::{ColorPalette, SrgbaTuple};
+
+mod clipboard;
+mod content_blocker;
+mod ime;
+mod network;
+mod notifications;
+mod routes;
+mod session;
+
+pub use clipboard::EditCommand;
+pub use content_blocker::{load_filter_lists, ContentBlocker};
+pub use ime::finish_inactive_compositions;
 
 /// Texture holder type for storing the CEF render texture bind group
 pub type TextureHolder = std::rc::Rc<RefCell<Option<wgpu::BindGroup>>>;
@@ -25,11 +43,22 @@ pub struct BrowserState {
     pub browser: Browser,
     pub pane_id: PaneId,
     pub url: String,
+    /// The profile the browser was opened with
+    pub profile: String,
     pub texture_holder: TextureHolder,
+    /// Set if the profile has filter lists
+    content_blocking: Option<SharedContentBlocking>,
     size: std::rc::Rc<RefCell<(u32, u32)>>,
     device_scale_factor: f32,
     /// Stored pane rectangle for overlay rendering (in pixels)
     pub pane_rect: RefCell<PaneRect>,
+    appearance: Cell<BrowserAppearance>,
+    next_devtools_message_id: Cell<i32>,
+    /// Set while an input method composition is in progress
+    composing: Cell<bool>,
+    ime_bounds: ime::ImeBounds,
+    /// Keeps the DevTools observer for web notifications registered
+    notifications: Option<Registration>,
 }
 
 /// Rectangle describing the pane position and size in pixels
@@ -41,6 +70,45 @@ pub struct PaneRect {
     pub height: f32,
 }
 
+/// Appearance of the page, derived from the terminal color scheme
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BrowserAppearance {
+    /// `Some(true)` reports `prefers-color-scheme: dark` to the page,
+    /// `Some(false)` reports light and `None` follows the system
+    pub dark: Option<bool>,
+    /// Use Chromium's automatic dark mode for pages without a dark theme
+    pub force_dark: bool,
+    /// Color shown behind the page before it has painted;
+    /// `None` uses the CEF default of opaque white
+    pub background: Option<SrgbaTuple>,
+}
+
+impl BrowserAppearance {
+    /// Build the appearance from the config and the palette of the pane
+    /// that hosts the browser
+    pub fn new(config: &ConfigHandle, palette: &ColorPalette) -> Self {
+        let dark = config.browser_color_scheme.is_dark(palette.background);
+        Self {
+            dark,
+            force_dark: config.browser_force_dark && dark == Some(true),
+            background: config
+                .browser_background_from_scheme
+                .then_some(palette.background),
+        }
+    }
+
+    /// The background as an opaque ARGB value for `BrowserSettings`
+    fn background_argb(&self) -> u32 {
+        match self.background {
+            Some(color) => {
+                let (r, g, b, _) = color.to_srgb_u8();
+                0xff00_0000 | (r as u32) << 16 | (g as u32) << 8 | b as u32
+            }
+            None => 0,
+        }
+    }
+}
+
 impl BrowserState {
     /// Create a new browser for the given pane
     pub fn new(
@@ -52,6 +120,12 @@ impl BrowserState {
         queue: &wgpu::Queue,
         bind_group_layout: &wgpu::BindGroupLayout,
         invalidate_callback: Arc<dyn Fn() + Send + Sync>,
+        appearance: BrowserAppearance,
+        profile: &str,
+        network_log: SharedNetworkLog,
+        route_registry: Arc<RouteRegistry>,
+        content_blocker: Option<ContentBlocker>,
+        session: SharedBrowserSession,
     ) -> anyhow::Result<Self> {
         log::info!(
             "[CEF] Creating browser for pane {} with URL: {} ({}x{})",
@@ -66,6 +140,7 @@ impl BrowserState {
         // Create render handler parts
         let size = std::rc::Rc::new(RefCell::new((width, height)));
         let texture_holder: TextureHolder = std::rc::Rc::new(RefCell::new(None));
+        let ime_bounds: ime::ImeBounds = std::rc::Rc::new(RefCell::new(None));
 
         let render_handler = CefRenderHandler {
             size: size.clone(),
@@ -75,6 +150,7 @@ impl BrowserState {
             bind_group_layout: bind_group_layout.clone(),
             device_scale_factor,
             invalidate_callback,
+            ime_bounds: ime_bounds.clone(),
         };
 
         // Window info for OSR mode
@@ -91,6 +167,7 @@ impl BrowserState {
 
         let browser_settings = BrowserSettings {
             windowless_frame_rate: 60,
+            background_color: appearance.background_argb(),
             ..Default::default()
         };
 
@@ -100,10 +177,18 @@ impl BrowserState {
             Some(&mut CefRequestContextHandlerBuilder::build()),
         );
 
+        let content_blocking = content_blocker.as_ref().map(ContentBlocker::state);
+
         // Create the browser synchronously
         let browser = cef::browser_host_create_browser_sync(
             Some(&window_info),
-            Some(&mut CefClientBuilder::build(render_handler)),
+            Some(&mut CefClientBuilder::build(
+                render_handler,
+                network_log,
+                routes::BrowserRoutes::new(route_registry, pane_id, profile.to_string()),
+                content_blocker,
+                session::SessionRecorder::new(session),
+            )),
             Some(&url.into()),
             Some(&browser_settings),
             None,
@@ -114,11 +199,13 @@ impl BrowserState {
 
         log::info!("[CEF] Browser created successfully for pane {}", pane_id);
 
-        Ok(Self {
+        let state = Self {
             browser,
             pane_id,
             url: url.to_string(),
+            profile: profile.to_string(),
             texture_holder,
+            content_blocking,
             size,
             device_scale_factor,
             pane_rect: RefCell::new(PaneRect {
@@ -127,6 +214,87 @@ impl BrowserState {
                 width: width as f32,
                 height: height as f32,
             }),
+            appearance: Cell::new(BrowserAppearance::default()),
+            next_devtools_message_id: Cell::new(1),
+            composing: Cell::new(false),
+            ime_bounds,
+            notifications: None,
+        };
+        state.set_appearance(appearance);
+
+        Ok(state)
+    }
+
+    /// Apply the color scheme and dark mode settings to the page.
+    /// This uses DevTools emulation so that it takes effect per-browser
+    /// and without reloading the page.
+    pub fn set_appearance(&self, appearance: BrowserAppearance) {
+        self.appearance.set(appearance);
+
+        let scheme = match appearance.dark {
+            Some(true) => "dark",
+            Some(false) => "light",
+            None => "",
+        };
+        self.send_devtools_method(
+            "Emulation.setEmulatedMedia",
+            &format!(
+                r#"{{"features":[{{"name":"prefers-color-scheme","value":"{}"}}]}}"#,
+                scheme
+            ),
+        );
+        self.send_devtools_method(
+            "Emulation.setAutoDarkModeOverride",
+            &format!(r#"{{"enabled":{}}}"#, appearance.force_dark),
+        );
+    }
+
+    /// Returns the appearance currently applied to the page
+    pub fn appearance(&self) -> BrowserAppearance {
+        self.appearance.get()
+    }
+
+    /// Send a DevTools protocol method call to the browser.
+    /// `params` must be a serialized JSON object.
+    fn send_devtools_method(&self, method: &str, params: &str) {
+        let Some(host) = self.host() else {
+            return;
+        };
+        let id = self.next_devtools_message_id.get();
+        self.next_devtools_message_id.set(id + 1);
+        let message = format!(
+            r#"{{"id":{},"method":"{}","params":{}}}"#,
+            id, method, params
+        );
+        if host.send_dev_tools_message(Some(message.as_bytes())) == 0 {
+            log::warn!(
+                "[CEF] Failed to send DevTools method {} for pane {}",
+                method,
+                self.pane_id
+            );
+        }
+    }
+
+    /// Navigate the browser to a different URL
+    pub fn load_url(&mut self, url: &str) {
+        if let Some(frame) = self.browser.main_frame() {
+            frame.load_url(Some(&url.into()));
+        }
+        self.url = url.to_string();
+    }
+
+    /// Returns the text shown in the row reserved at the bottom of
+    /// the pane, if the browser has one
+    pub fn status_text(&self) -> Option<String> {
+        let state = self.content_blocking.as_ref()?;
+        Some(if state.enabled() {
+            format!(
+                "Blocked {} on this page, {} total",
+                state.blocked(),
+                state.total_blocked()
+            )
+        } else {
+            "Content blocking off".to_string()
         })
     }
 
@@ -256,6 +424,7 @@ struct CefRenderHandler {
     bind_group_layout: wgpu::BindGroupLayout,
     device_scale_factor: f32,
     invalidate_callback: Arc<dyn Fn() + Send + Sync>,
+    ime_bounds: ime::ImeBounds,
 }
 
 wrap_render_handler! {
@@ -286,6 +455,18 @@ wrap_render_handler! {
             0
         }
 
+        fn on_ime_composition_range_changed(
+            &self,
+            _browser: Option<&mut Browser>,
+            _selected_range: Option<&cef::Range>,
+            character_bounds: Option<&[Rect]>,
+        ) {
+            let bounds = character_bounds.and_then(ime::union_bounds);
+            *self.handler.ime_bounds.borrow_mut() = bounds;
+            // Repaint so that the candidate window is moved
+            (self.handler.invalidate_callback)();
+        }
+
         fn screen_point(
             &self,
             _browser: Option<&mut Browser>,
@@ -518,6 +699,8 @@ wrap_client! {
     struct CefClientBuilder {
         render_handler: RenderHandler,
         context_menu_handler: ContextMenuHandler,
+        request_handler: RequestHandler,
+        load_handler: LoadHandler,
     }
 
     impl Client {
@@ -528,18 +711,119 @@ wrap_client! {
         fn context_menu_handler(&self) -> Option<cef::ContextMenuHandler> {
             Some(self.context_menu_handler.clone())
         }
+
+        fn request_handler(&self) -> Option<cef::RequestHandler> {
+            Some(self.request_handler.clone())
+        }
+
+        fn load_handler(&self) -> Option<cef::LoadHandler> {
+            Some(self.load_handler.clone())
+        }
     }
 }
 
 impl CefClientBuilder {
-    fn build(render_handler: CefRenderHandler) -> Client {
+    fn build(
+        render_handler: CefRenderHandler,
+        network_log: SharedNetworkLog,
+        routes: routes::BrowserRoutes,
+        content_blocker: Option<ContentBlocker>,
+        session: session::SessionRecorder,
+    ) -> Client {
         Self::new(
             CefRenderHandlerBuilder::build(render_handler),
             CefContextMenuHandlerBuilder::build(),
+            CefRequestHandlerBuilder::build(network_log, routes, content_blocker.clone()),
+            CefLoadHandlerBuilder::build(content_blocker, session),
         )
     }
 }
 
+// ============================================================================
+// CEF Load Handler
+// ============================================================================
+
+wrap_load_handler! {
+    struct CefLoadHandlerBuilder {
+        content_blocker: Option<ContentBlocker>,
+        session: session::SessionRecorder,
+    }
+
+    impl LoadHandler {
+        fn on_load_start(
+            &self,
+            _browser: Option<&mut Browser>,
+            frame: Option<&mut cef::Frame>,
+            _transition_type: TransitionType,
+        ) {
+            if let (Some(blocker), Some(frame)) = (&self.content_blocker, frame) {
+                blocker.inject_stylesheet(frame);
+            }
+        }
+
+        fn on_load_end(
+            &self,
+            browser: Option<&mut Browser>,
+            frame: Option<&mut cef::Frame>,
+            _http_status_code: ::std::os::raw::c_int,
+        ) {
+            if let (Some(browser), Some(frame)) = (browser, frame) {
+                if frame.is_main() != 0 {
+                    self.session.page_loaded(browser);
+                }
+            }
+        }
+    }
+}
+
+impl CefLoadHandlerBuilder {
+    fn build(
+        content_blocker: Option<ContentBlocker>,
+        session: session::SessionRecorder,
+    ) -> LoadHandler {
+        Self::new(content_blocker, session)
+    }
+}
+
+// ============================================================================
+// CEF Request Handler
+// ============================================================================
+
+wrap_request_handler! {
+    struct CefRequestHandlerBuilder {
+        resource_request_handler: ResourceRequestHandler,
+    }
+
+    impl RequestHandler {
+        fn resource_request_handler(
+            &self,
+            _browser: Option<&mut Browser>,
+            _frame: Option<&mut cef::Frame>,
+            _request: Option<&mut cef::Request>,
+            _is_navigation: ::std::os::raw::c_int,
+            _is_download: ::std::os::raw::c_int,
+            _request_initiator: Option<&cef::CefString>,
+            _disable_default_handling: Option<&mut ::std::os::raw::c_int>,
+        ) -> Option<ResourceRequestHandler> {
+            Some(self.resource_request_handler.clone())
+        }
+    }
+}
+
+impl CefRequestHandlerBuilder {
+    fn build(
+        network_log: SharedNetworkLog,
+        routes: routes::BrowserRoutes,
+        content_blocker: Option<ContentBlocker>,
+    ) -> RequestHandler {
+        Self::new(network::NetworkResourceRequestHandler::build(
+            network_log,
+            routes,
+            content_blocker,
+        ))
+    }
+}
+
 // ============================================================================
 // CEF Request Context Handler
 // ============================================================================
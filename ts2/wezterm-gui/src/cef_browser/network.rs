@@ -0,0 +1,253 @@
+//! Network activity logging for browser panes
+//!
+//! Requests are observed on CEF's IO thread by a ResourceRequestHandler
+//! and recorded in the pane's `mux::web::NetworkLog`, where the mux
+//! server can pick them up for `wezterm cli web-network`.
+//! The same handler applies the pane's routing rules and content
+//! blocking; see `routes` and `content_blocker`.
+
+use super::content_blocker::ContentBlocker;
+use super::routes::BrowserRoutes;
+use cef::{
+    rc::Rc, wrap_resource_request_handler, wrap_response_filter, Browser, Callback, CefString,
+    CefStringMultimap, Frame, ImplFrame, ImplPostData, ImplPostDataElement, ImplRequest,
+    ImplResourceRequestHandler, ImplResponse, ImplResponseFilter, Request, ResourceHandler,
+    ResourceRequestHandler, ResourceType, Response, ResponseFilter, ResponseFilterStatus,
+    ReturnValue, UrlrequestStatus, WrapResourceRequestHandler, WrapResponseFilter,
+};
+use mux::web::{NetworkBody, NetworkEntry, SharedNetworkLog};
+
+wrap_resource_request_handler! {
+    pub(crate) struct NetworkResourceRequestHandler {
+        log: SharedNetworkLog,
+        routes: BrowserRoutes,
+        blocker: Option<ContentBlocker>,
+    }
+
+    impl ResourceRequestHandler {
+        fn on_before_resource_load(
+            &self,
+            browser: Option<&mut Browser>,
+            frame: Option<&mut Frame>,
+            request: Option<&mut Request>,
+            callback: Option<&mut Callback>,
+        ) -> ReturnValue {
+            let Some(request) = request else {
+                return ReturnValue::CONTINUE;
+            };
+
+            let source_url = match (&self.blocker, &frame) {
+                (Some(_), Some(frame)) => Some(CefString::from(&frame.url()).to_string()),
+                _ => None,
+            };
+
+            // Apply the routing rules first so that the log reflects
+            // any headers that they add.  Explicit rules take precedence
+            // over the filter lists.
+            let result = self
+                .routes
+                .on_before_resource_load(browser, frame, request, callback)
+                .unwrap_or_else(|| match &self.blocker {
+                    Some(blocker) if blocker.should_block(request, source_url.as_deref()) => {
+                        ReturnValue::CANCEL
+                    }
+                    _ => ReturnValue::CONTINUE,
+                });
+
+            let mut headers = CefStringMultimap::new();
+            request.header_map(Some(&mut headers));
+            let entry = NetworkEntry {
+                method: CefString::from(&request.method()).to_string(),
+                url: CefString::from(&request.url()).to_string(),
+                resource_type: resource_type_name(request.resource_type()).to_string(),
+                request_headers: flatten_headers(headers),
+                request_body: request_body(request),
+                ..Default::default()
+            };
+            self.log.lock().begin_request(request.identifier(), entry);
+            result
+        }
+
+        fn resource_handler(
+            &self,
+            browser: Option<&mut Browser>,
+            frame: Option<&mut Frame>,
+            request: Option<&mut Request>,
+        ) -> Option<ResourceHandler> {
+            self.routes.resource_handler(browser, frame, request?)
+        }
+
+        fn on_resource_response(
+            &self,
+            _browser: Option<&mut Browser>,
+            _frame: Option<&mut Frame>,
+            request: Option<&mut Request>,
+            response: Option<&mut Response>,
+        ) -> ::std::os::raw::c_int {
+            if let (Some(request), Some(response)) = (request, response) {
+                let mut headers = CefStringMultimap::new();
+                response.header_map(Some(&mut headers));
+                self.log.lock().set_response(
+                    request.identifier(),
+                    response.status(),
+                    CefString::from(&response.status_text()).to_string(),
+                    CefString::from(&response.mime_type()).to_string(),
+                    flatten_headers(headers),
+                );
+            }
+            // Don't modify or redirect the request
+            0
+        }
+
+        fn resource_response_filter(
+            &self,
+            _browser: Option<&mut Browser>,
+            _frame: Option<&mut Frame>,
+            request: Option<&mut Request>,
+            _response: Option<&mut Response>,
+        ) -> Option<ResponseFilter> {
+            let request = request?;
+            if !self.log.lock().capture_bodies() {
+                return None;
+            }
+            Some(BodyCaptureFilter::new(
+                self.log.clone(),
+                request.identifier(),
+            ))
+        }
+
+        fn on_resource_load_complete(
+            &self,
+            _browser: Option<&mut Browser>,
+            _frame: Option<&mut Frame>,
+            request: Option<&mut Request>,
+            response: Option<&mut Response>,
+            status: UrlrequestStatus,
+            received_content_length: i64,
+        ) {
+            let Some(request) = request else {
+                return;
+            };
+            let error = if status == UrlrequestStatus::SUCCESS {
+                None
+            } else {
+                let code = response.map(|r| r.error().get_raw()).unwrap_or(0);
+                Some(if status == UrlrequestStatus::CANCELED {
+                    format!("canceled (net error {})", code)
+                } else {
+                    format!("failed (net error {})", code)
+                })
+            };
+            self.log
+                .lock()
+                .complete_request(request.identifier(), received_content_length, error);
+        }
+    }
+}
+
+impl NetworkResourceRequestHandler {
+    pub(crate) fn build(
+        log: SharedNetworkLog,
+        routes: BrowserRoutes,
+        blocker: Option<ContentBlocker>,
+    ) -> ResourceRequestHandler {
+        Self::new(log, routes, blocker)
+    }
+}
+
+wrap_response_filter! {
+    struct BodyCaptureFilter {
+        log: SharedNetworkLog,
+        request_id: u64,
+    }
+
+    impl ResponseFilter {
+        fn init_filter(&self) -> ::std::os::raw::c_int {
+            1
+        }
+
+        fn filter(
+            &self,
+            data_in: Option<&mut Vec<u8>>,
+            data_in_read: Option<&mut usize>,
+            data_out: Option<&mut Vec<u8>>,
+            data_out_written: Option<&mut usize>,
+        ) -> ResponseFilterStatus {
+            // Pass the data through unchanged, keeping a copy of it.
+            // When the output buffer is smaller than the input, CEF
+            // calls us again with the unread remainder.
+            let Some(data_in) = data_in else {
+                return ResponseFilterStatus::DONE;
+            };
+            let (Some(data_out), Some(data_in_read), Some(data_out_written)) =
+                (data_out, data_in_read, data_out_written)
+            else {
+                return ResponseFilterStatus::ERROR;
+            };
+
+            let len = data_in.len().min(data_out.len());
+            data_out[..len].copy_from_slice(&data_in[..len]);
+            *data_in_read = len;
+            *data_out_written = len;
+            self.log
+                .lock()
+                .append_response_body(self.request_id, &data_in[..len]);
+
+            ResponseFilterStatus::NEED_MORE_DATA
+        }
+    }
+}
+
+/// Returns the name of a resource type, matching the names used by
+/// the Chrome DevTools network panel where one exists
+fn resource_type_name(resource_type: ResourceType) -> &'static str {
+    match resource_type {
+        ResourceType::MAIN_FRAME | ResourceType::SUB_FRAME => "Document",
+        ResourceType::STYLESHEET => "Stylesheet",
+        ResourceType::SCRIPT => "Script",
+        ResourceType::IMAGE | ResourceType::FAVICON => "Image",
+        ResourceType::FONT_RESOURCE => "Font",
+        ResourceType::MEDIA => "Media",
+        ResourceType::XHR => "XHR",
+        ResourceType::PING => "Ping",
+        ResourceType::PREFETCH => "Prefetch",
+        ResourceType::CSP_REPORT => "CSPViolationReport",
+        ResourceType::WORKER | ResourceType::SHARED_WORKER | ResourceType::SERVICE_WORKER => {
+            "Worker"
+        }
+        _ => "Other",
+    }
+}
+
+fn flatten_headers(headers: CefStringMultimap) -> Vec<(String, String)> {
+    headers
+        .into_iter()
+        .flat_map(|(name, values)| values.into_iter().map(move |v| (name.clone(), v)))
+        .collect()
+}
+
+/// Extract the in-memory portion of the request body.
+/// Elements that reference files are skipped.
+fn request_body(request: &Request) -> Option<NetworkBody> {
+    let post_data = request.post_data()?;
+    let mut elements = vec![None; post_data.element_count()];
+    post_data.elements(Some(&mut elements));
+
+    let mut body = vec![];
+    for element in elements.into_iter().flatten() {
+        let count = element.bytes_count();
+        if count == 0 {
+            continue;
+        }
+        let start = body.len();
+        body.resize(start + count, 0);
+        let read = element.bytes(count, body[start..].as_mut_ptr());
+        body.truncate(start + read);
+    }
+
+    if body.is_empty() {
+        None
+    } else {
+        Some(NetworkBody::from_bytes(&body))
+    }
+}
@@ -0,0 +1,407 @@
+//! Web notifications for browser panes
+//!
+//! Windowless CEF browsers don't display notifications, so pages are
+//! given a replacement `Notification` class that passes them to a
+//! DevTools binding.  They are shown as toast notifications, subject
+//! to the same `notification_handling` as those from terminal panes.
+//! Permission is granted to the origins listed in the profile's
+//! `notification_origins`; the origin of the calling page is taken
+//! from its execution context rather than trusted from the page.
+
+use super::BrowserState;
+use crate::termwindow::TermWindowNotif;
+use anyhow::Context;
+use base64::Engine;
+use cef::{DevToolsMessageObserver, ImplBrowserHost};
+use config::BrowserProfile;
+use mux::pane::PaneId;
+use mux::Mux;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use wezterm_toast_notification::{ClickHandler, ToastNotification};
+
+/// The name of the binding that the page calls to show a notification
+const BINDING: &str = "__weztermNotify";
+
+/// Icons are written to files for the notification service to read,
+/// and are removed once they are this old
+const ICON_LIFETIME: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Replaces `Notification` in every document.  `ORIGINS` is replaced
+/// by the origins that are granted permission.
+const NOTIFICATION_SCRIPT: &str = r#"(function() {
+  const notify = window.__weztermNotify;
+  if (typeof notify !== "function") {
+    return;
+  }
+  delete window.__weztermNotify;
+
+  const origins = ORIGINS.map(origin => origin.replace(/\/+$/, "").toLowerCase());
+  const granted = location.origin !== "null"
+    && (origins.includes("*") || origins.includes(location.origin.toLowerCase()));
+  const permission = granted ? "granted" : "denied";
+  const shown = new Map();
+  let nextId = 1;
+
+  // The notification service can't load the icon, so send its data
+  function iconData(url) {
+    if (!url) {
+      return Promise.resolve("");
+    }
+    return fetch(url)
+      .then(response => response.blob())
+      .then(blob => new Promise(resolve => {
+        if (!blob.type.startsWith("image/") || blob.size > 1048576) {
+          resolve("");
+          return;
+        }
+        const reader = new FileReader();
+        reader.onload = () => resolve(reader.result);
+        reader.onerror = () => resolve("");
+        reader.readAsDataURL(blob);
+      }))
+      .catch(() => "");
+  }
+
+  class Notification extends EventTarget {
+    constructor(title, options = {}) {
+      super();
+      this.title = String(title);
+      this.body = options.body ? String(options.body) : "";
+      this.icon = options.icon ? new URL(options.icon, location.href).href : "";
+      this.tag = options.tag ? String(options.tag) : "";
+      this.data = options.data === undefined ? null : options.data;
+      this.onclick = null;
+      this.onshow = null;
+      this.onclose = null;
+      this.onerror = null;
+      if (!granted) {
+        setTimeout(() => this._fire("error"), 0);
+        return;
+      }
+      const id = nextId++;
+      shown.set(id, this);
+      iconData(this.icon).then(icon => {
+        notify(JSON.stringify({ id, title: this.title, body: this.body, icon }));
+        this._fire("show");
+      });
+    }
+
+    static get permission() {
+      return permission;
+    }
+
+    static requestPermission(callback) {
+      if (typeof callback === "function") {
+        setTimeout(() => callback(permission), 0);
+      }
+      return Promise.resolve(permission);
+    }
+
+    close() {
+      for (const [id, notification] of shown) {
+        if (notification === this) {
+          shown.delete(id);
+          this._fire("close");
+        }
+      }
+    }
+
+    _fire(type) {
+      const event = new Event(type);
+      const handler = this["on" + type];
+      if (typeof handler === "function") {
+        handler.call(this, event);
+      }
+      this.dispatchEvent(event);
+    }
+  }
+
+  Object.defineProperty(window, "__weztermNotificationClicked", {
+    value: id => {
+      const notification = shown.get(id);
+      if (notification) {
+        notification._fire("click");
+      }
+    },
+  });
+  window.Notification = Notification;
+
+  if (window.ServiceWorkerRegistration) {
+    ServiceWorkerRegistration.prototype.showNotification = function(title, options) {
+      new Notification(title, options);
+      return Promise.resolve();
+    };
+  }
+
+  if (navigator.permissions && navigator.permissions.query) {
+    const query = navigator.permissions.query.bind(navigator.permissions);
+    navigator.permissions.query = descriptor => {
+      if (descriptor && descriptor.name === "notifications") {
+        return Promise.resolve({ name: "notifications", state: permission, onchange: null });
+      }
+      return query(descriptor);
+    };
+  }
+})()"#;
+
+#[derive(Deserialize)]
+struct PageNotification {
+    id: u64,
+    title: String,
+    #[serde(default)]
+    body: String,
+    /// A data URL, or empty
+    #[serde(default)]
+    icon: String,
+}
+
+#[derive(Deserialize)]
+struct ExecutionContextCreated {
+    context: ExecutionContextDescription,
+}
+
+#[derive(Deserialize)]
+struct ExecutionContextDescription {
+    id: i64,
+    #[serde(default)]
+    origin: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExecutionContextDestroyed {
+    execution_context_id: i64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BindingCalled {
+    name: String,
+    payload: String,
+    execution_context_id: i64,
+}
+
+impl BrowserState {
+    /// Show notifications from pages whose origin is listed in the
+    /// profile's `notification_origins`
+    pub fn enable_notifications(&mut self, profile: &BrowserProfile) {
+        if profile.notification_origins.is_empty() {
+            return;
+        }
+        let Some(host) = self.host() else {
+            return;
+        };
+
+        let pane_id = self.pane_id;
+        let profile = profile.clone();
+        let contexts = RefCell::new(HashMap::new());
+        let mut observer = DevToolsMessageObserver::builder()
+            .on_dev_tools_event(move |_browser, method, params| {
+                let (Some(method), Some(params)) = (method, params) else {
+                    return;
+                };
+                match method.to_string().as_str() {
+                    "Runtime.executionContextCreated" => {
+                        if let Ok(event) = serde_json::from_slice::<ExecutionContextCreated>(params)
+                        {
+                            contexts
+                                .borrow_mut()
+                                .insert(event.context.id, event.context.origin);
+                        }
+                    }
+                    "Runtime.executionContextDestroyed" => {
+                        if let Ok(event) =
+                            serde_json::from_slice::<ExecutionContextDestroyed>(params)
+                        {
+                            contexts.borrow_mut().remove(&event.execution_context_id);
+                        }
+                    }
+                    "Runtime.executionContextsCleared" => contexts.borrow_mut().clear(),
+                    "Runtime.bindingCalled" => {
+                        let Ok(event) = serde_json::from_slice::<BindingCalled>(params) else {
+                            return;
+                        };
+                        if event.name != BINDING {
+                            return;
+                        }
+                        let origin = contexts
+                            .borrow()
+                            .get(&event.execution_context_id)
+                            .cloned()
+                            .unwrap_or_default();
+                        if !profile.allows_notifications(&origin) {
+                            log::warn!(
+                                "[CEF] Ignoring notification from {:?} in pane {}",
+                                origin,
+                                pane_id
+                            );
+                            return;
+                        }
+                        match serde_json::from_str(&event.payload) {
+                            Ok(notification) => show_notification(
+                                pane_id,
+                                event.execution_context_id,
+                                &origin,
+                                notification,
+                            ),
+                            Err(err) => log::error!("[CEF] Invalid notification: {:#}", err),
+                        }
+                    }
+                    _ => {}
+                }
+            })
+            .build();
+        self.notifications = host.add_dev_tools_message_observer(Some(&mut observer));
+
+        let script = match serde_json::to_string(&profile.notification_origins) {
+            Ok(origins) => NOTIFICATION_SCRIPT.replace("ORIGINS", &origins),
+            Err(err) => {
+                log::error!("[CEF] Failed to encode notification origins: {:#}", err);
+                return;
+            }
+        };
+        self.send_devtools_method("Runtime.enable", "{}");
+        self.send_devtools_method(
+            "Runtime.addBinding",
+            &serde_json::json!({ "name": BINDING }).to_string(),
+        );
+        self.send_devtools_method(
+            "Page.addScriptToEvaluateOnNewDocument",
+            &serde_json::json!({ "source": script }).to_string(),
+        );
+        // The first document may have been created before the script
+        // was added
+        self.send_devtools_method(
+            "Runtime.evaluate",
+            &serde_json::json!({ "expression": script }).to_string(),
+        );
+    }
+
+    /// Fire the click event of a notification in the page that showed it
+    pub fn notification_clicked(&self, context_id: i64, id: u64) {
+        let expression = format!(
+            "window.__weztermNotificationClicked && window.__weztermNotificationClicked({})",
+            id
+        );
+        self.send_devtools_method(
+            "Runtime.evaluate",
+            &serde_json::json!({ "expression": expression, "contextId": context_id }).to_string(),
+        );
+    }
+}
+
+fn show_notification(
+    pane_id: PaneId,
+    context_id: i64,
+    origin: &str,
+    notification: PageNotification,
+) {
+    let icon = match save_icon(&notification.icon) {
+        Ok(icon) => icon,
+        Err(err) => {
+            log::warn!("[CEF] Notification icon from {}: {:#}", origin, err);
+            None
+        }
+    };
+
+    let id = notification.id;
+    let on_click = ClickHandler::new(move || {
+        promise::spawn::spawn_into_main_thread(async move {
+            let mux = Mux::get();
+            let Some((_domain, window_id, _tab_id)) = mux.resolve_pane_id(pane_id) else {
+                return;
+            };
+            if let Some(gui_window) =
+                crate::frontend::front_end().gui_window_for_mux_window(window_id)
+            {
+                gui_window
+                    .window
+                    .notify(TermWindowNotif::Apply(Box::new(move |term_window| {
+                        term_window.web_notification_clicked(pane_id, context_id, id);
+                    })));
+            }
+        })
+        .detach();
+    });
+
+    let title = if notification.title.is_empty() {
+        origin.to_string()
+    } else {
+        notification.title
+    };
+
+    crate::frontend::front_end().show_pane_notification(
+        pane_id,
+        ToastNotification {
+            title,
+            message: notification.body,
+            url: None,
+            timeout: None,
+            icon,
+            on_click: Some(on_click),
+            on_close: None,
+            tag: None,
+            urgency: None,
+        },
+        true,
+    );
+}
+
+/// Write the icon of a notification, given as a data URL, to a file
+fn save_icon(data_url: &str) -> anyhow::Result<Option<PathBuf>> {
+    static NEXT_ICON: AtomicUsize = AtomicUsize::new(0);
+
+    if data_url.is_empty() {
+        return Ok(None);
+    }
+    let (header, data) = data_url
+        .strip_prefix("data:")
+        .and_then(|url| url.split_once(','))
+        .context("not a data URL")?;
+    let extension = match header {
+        "image/png;base64" => "png",
+        "image/jpeg;base64" => "jpg",
+        "image/gif;base64" => "gif",
+        "image/webp;base64" => "webp",
+        _ => anyhow::bail!("unsupported icon type {}", header),
+    };
+    let data = base64::engine::general_purpose::STANDARD
+        .decode(data)
+        .context("decoding icon")?;
+
+    let dir = config::CACHE_DIR.join("web-notifications");
+    std::fs::create_dir_all(&dir).with_context(|| format!("creating {}", dir.display()))?;
+    remove_old_icons(&dir);
+
+    let path = dir.join(format!(
+        "{}-{}.{}",
+        std::process::id(),
+        NEXT_ICON.fetch_add(1, Ordering::Relaxed),
+        extension
+    ));
+    std::fs::write(&path, data).with_context(|| format!("writing {}", path.display()))?;
+    Ok(Some(path))
+}
+
+/// Remove the icons of notifications that have long been shown
+fn remove_old_icons(dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let expired = entry
+            .metadata()
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .map_or(false, |age| age > ICON_LIFETIME);
+        if expired {
+            std::fs::remove_file(entry.path()).ok();
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Request interception for browser panes
+//!
+//! The rules set by `wezterm cli web-route` live in the mux's
+//! `RouteRegistry`. Blocking, redirecting and adding headers are applied
+//! by modifying the request before it is sent, while canned responses
+//! are served from disk by a provider attached to a cef-rs
+//! `ResourceManager`.
+
+use cef::wrapper::resource_manager::{
+    ResourceManager, ResourceManagerProvider, ResourceManagerRequest,
+};
+use cef::wrapper::stream_resource_handler::StreamResourceHandler;
+use cef::{
+    Browser, Callback, CefString, CefStringMultimap, Frame, ImplRequest, Request, ResourceHandler,
+    ReturnValue,
+};
+use mux::pane::PaneId;
+use mux::web::{RouteAction, RouteDecision, RouteRegistry};
+use std::sync::{Arc, Mutex};
+
+/// Identifies the rules that apply to a particular browser
+#[derive(Clone)]
+struct RouteRules {
+    registry: Arc<RouteRegistry>,
+    pane_id: PaneId,
+    profile: String,
+}
+
+impl RouteRules {
+    fn resolve(&self, url: &str) -> Option<RouteDecision> {
+        self.registry.resolve(self.pane_id, &self.profile, url)
+    }
+}
+
+/// Applies the route rules to the requests made by a browser.
+/// The methods are called on the CEF IO thread from the
+/// corresponding ResourceRequestHandler methods.
+#[derive(Clone)]
+pub(crate) struct BrowserRoutes {
+    rules: RouteRules,
+    manager: Arc<Mutex<ResourceManager>>,
+}
+
+impl BrowserRoutes {
+    pub(crate) fn new(registry: Arc<RouteRegistry>, pane_id: PaneId, profile: String) -> Self {
+        let rules = RouteRules {
+            registry,
+            pane_id,
+            profile,
+        };
+        let manager = ResourceManager::new();
+        if let Ok(mut manager) = manager.lock() {
+            manager.add_provider(
+                Box::new(FulfillProvider {
+                    rules: rules.clone(),
+                }),
+                0,
+                "routes",
+            );
+        }
+        Self { rules, manager }
+    }
+
+    /// Returns None if no rule blocked, redirected or fulfilled the
+    /// request, in which case it is subject to the normal handling
+    pub(crate) fn on_before_resource_load(
+        &self,
+        browser: Option<&mut Browser>,
+        frame: Option<&mut Frame>,
+        request: &mut Request,
+        callback: Option<&mut Callback>,
+    ) -> Option<ReturnValue> {
+        let url = CefString::from(&request.url()).to_string();
+        let decision = self.rules.resolve(&url)?;
+
+        for (name, value) in &decision.add_headers {
+            request.set_header_by_name(
+                Some(&CefString::from(name.as_str())),
+                Some(&CefString::from(value.as_str())),
+                1,
+            );
+        }
+
+        match decision.action {
+            RouteAction::Continue => None,
+            RouteAction::Block => {
+                log::debug!("[CEF] pane {} blocked {}", self.rules.pane_id, url);
+                Some(ReturnValue::CANCEL)
+            }
+            RouteAction::Redirect(target) => {
+                // Changing the URL is treated as a redirect, which comes
+                // back through here; don't loop if the target also matches
+                if target != url {
+                    request.set_url(Some(&CefString::from(target.as_str())));
+                }
+                Some(ReturnValue::CONTINUE)
+            }
+            RouteAction::Fulfill(_) => {
+                let (Some(browser), Some(frame), Some(callback)) = (browser, frame, callback)
+                else {
+                    // Requests from service workers have no browser or
+                    // frame and can't go through the resource manager
+                    return None;
+                };
+                let mut manager = self.manager.lock().ok()?;
+                Some(manager.on_before_resource_load(
+                    browser.clone(),
+                    frame.clone(),
+                    request.clone(),
+                    callback.clone(),
+                ))
+            }
+        }
+    }
+
+    /// Returns the handler for a request that is being fulfilled
+    pub(crate) fn resource_handler(
+        &self,
+        browser: Option<&mut Browser>,
+        frame: Option<&mut Frame>,
+        request: &mut Request,
+    ) -> Option<ResourceHandler> {
+        let (browser, frame) = (browser?, frame?);
+        self.manager
+            .lock()
+            .ok()?
+            .resource_handler(browser.clone(), frame.clone(), request.clone())
+    }
+}
+
+/// Serves the responses for RouteAction::Fulfill rules
+struct FulfillProvider {
+    rules: RouteRules,
+}
+
+impl ResourceManagerProvider for FulfillProvider {
+    fn on_request(&self, request: Arc<Mutex<ResourceManagerRequest>>) -> bool {
+        let Ok(mut request) = request.lock() else {
+            return false;
+        };
+
+        // The rules are matched against the full URL, whereas
+        // request.url() has the query and fragment removed
+        let url = CefString::from(&request.request().url()).to_string();
+        let Some(RouteDecision {
+            action: RouteAction::Fulfill(response),
+            ..
+        }) = self.rules.resolve(&url)
+        else {
+            return false;
+        };
+
+        let path = response.file.to_string_lossy();
+        let Some(stream) =
+            cef::stream_reader_create_for_file(Some(&CefString::from(path.as_ref())))
+        else {
+            log::warn!("[CEF] unable to read {} to respond to {}", path, url);
+            return false;
+        };
+
+        let mime_type = if response.mime_type.is_empty() {
+            (request.mime_type_resolver())(&path)
+        } else {
+            response.mime_type.clone()
+        };
+
+        let mut headers = CefStringMultimap::new();
+        for (name, value) in &response.headers {
+            headers.append(name, value);
+        }
+
+        request.continue_request(Some(StreamResourceHandler::new(
+            response.status,
+            status_text(response.status).to_string(),
+            mime_type,
+            Some(headers),
+            Some(stream),
+        )));
+        true
+    }
+}
+
+fn status_text(status: i32) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        204 => "No Content",
+        301 => "Moved Permanently",
+        302 => "Found",
+        304 => "Not Modified",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
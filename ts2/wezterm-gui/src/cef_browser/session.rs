@@ -0,0 +1,72 @@
+//! Session state for browser panes
+//!
+//! The URL, navigation history and zoom level of each browser are kept
+//! in a `mux::web_session::BrowserSession` so that `wezterm cli
+//! web-session save` can write them out without asking the GUI.  They
+//! are recorded whenever a page finishes loading in the main frame.
+
+use cef::{
+    Browser, CefString, ImplBrowser, ImplBrowserHost, ImplNavigationEntry, NavigationEntry,
+    NavigationEntryVisitor,
+};
+use mux::web_session::SharedBrowserSession;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+#[derive(Clone)]
+pub(crate) struct SessionRecorder {
+    session: SharedBrowserSession,
+    /// The zoom level of a restored browser, which is applied once
+    /// its first page has loaded
+    pending_zoom: Rc<Cell<Option<f64>>>,
+}
+
+impl SessionRecorder {
+    pub(crate) fn new(session: SharedBrowserSession) -> Self {
+        let zoom_level = session.lock().zoom_level;
+        Self {
+            session,
+            pending_zoom: Rc::new(Cell::new((zoom_level != 0.0).then_some(zoom_level))),
+        }
+    }
+
+    /// Record the state of the browser after its main frame has loaded
+    pub(crate) fn page_loaded(&self, browser: &Browser) {
+        let Some(host) = browser.host() else {
+            return;
+        };
+
+        let zoom_level = match self.pending_zoom.take() {
+            Some(zoom_level) => {
+                host.set_zoom_level(zoom_level);
+                zoom_level
+            }
+            None => host.zoom_level(),
+        };
+
+        let entries = Rc::new(RefCell::new(vec![]));
+        let current = Rc::new(Cell::new(0));
+        let mut visitor = NavigationEntryVisitor::builder()
+            .visit({
+                let entries = Rc::clone(&entries);
+                let current = Rc::clone(&current);
+                move |entry: Option<&mut NavigationEntry>, is_current, index, _total| {
+                    if let Some(entry) = entry {
+                        entries
+                            .borrow_mut()
+                            .push(CefString::from(&entry.url()).to_string());
+                        if is_current != 0 {
+                            current.set(index.max(0) as usize);
+                        }
+                    }
+                    1
+                }
+            })
+            .build();
+        host.navigation_entries(Some(&mut visitor), 0);
+
+        let mut session = self.session.lock();
+        session.update_history(entries.take(), current.get());
+        session.zoom_level = zoom_level;
+    }
+}
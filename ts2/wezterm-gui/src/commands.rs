@@ -673,6 +673,70 @@ pub fn derive_command_from_key_assignment(action: &KeyAssignment) -> Option<Comm
             menubar: &["Edit"],
             icon: Some("md_content_paste"),
         },
+        CutTo(ClipboardCopyDestination::Clipboard) => CommandDef {
+            brief: "Cut to clipboard".into(),
+            doc: "Cuts the selected text in a browser pane to the clipboard. \
+                In a terminal pane this copies the selection."
+                .into(),
+            keys: vec![(Modifiers::SUPER, "x".into())],
+            args: &[ArgType::ActivePane],
+            menubar: &["Edit"],
+            icon: Some("md_content_cut"),
+        },
+        CutTo(destination) => CommandDef {
+            brief: format!("Cut to {destination:?}").into(),
+            doc: format!(
+                "Cuts the selected text in a browser pane to {destination:?}. \
+                In a terminal pane this copies the selection."
+            )
+            .into(),
+            keys: vec![],
+            args: &[ArgType::ActivePane],
+            menubar: &[],
+            icon: Some("md_content_cut"),
+        },
+        SelectAll => CommandDef {
+            brief: "Select all".into(),
+            doc: "Selects the whole page in a browser pane, or all of the \
+                scrollback in a terminal pane"
+                .into(),
+            keys: vec![(Modifiers::SUPER, "a".into())],
+            args: &[ArgType::ActivePane],
+            menubar: &["Edit"],
+            icon: Some("md_select_all"),
+        },
+        CopyLinkTo {
+            format: LinkFormat::Url,
+            destination,
+        } => CommandDef {
+            brief: "Copy link".into(),
+            doc: format!(
+                "Copies the URL of the selected link, or of the current page, \
+                in a browser pane to {destination:?}. In a terminal pane this \
+                copies the link under the mouse cursor."
+            )
+            .into(),
+            keys: vec![],
+            args: &[ArgType::ActivePane],
+            menubar: &["Edit"],
+            icon: Some("md_link"),
+        },
+        CopyLinkTo {
+            format: LinkFormat::Markdown,
+            destination,
+        } => CommandDef {
+            brief: "Copy link as Markdown".into(),
+            doc: format!(
+                "Copies the selected link, or the current page, in a browser \
+                pane to {destination:?} as a Markdown link. In a terminal pane \
+                this copies the link under the mouse cursor."
+            )
+            .into(),
+            keys: vec![],
+            args: &[ArgType::ActivePane],
+            menubar: &["Edit"],
+            icon: Some("md_link"),
+        },
         ToggleFullScreen => CommandDef {
             brief: "Toggle full screen mode".into(),
             doc: "Switch between normal and full screen mode".into(),
@@ -1410,6 +1474,35 @@ pub fn derive_command_from_key_assignment(action: &KeyAssignment) -> Option<Comm
                 icon: Some("oct_terminal"),
             }
         }
+        CopyCommandOutput(destination) => CommandDef {
+            brief: match destination {
+                ClipboardCopyDestination::Clipboard => "Copy command output to clipboard",
+                ClipboardCopyDestination::PrimarySelection => {
+                    "Copy command output to primary selection"
+                }
+                ClipboardCopyDestination::ClipboardAndPrimarySelection => {
+                    "Copy command output to clipboard and primary selection"
+                }
+            }
+            .into(),
+            doc: "Copies the output of the command under the mouse cursor, \
+                or else of the most recent command, as recorded by shell integration"
+                .into(),
+            keys: vec![],
+            args: &[ArgType::ActivePane],
+            menubar: &["Edit"],
+            icon: Some("md_content_copy"),
+        },
+        SelectCommandOutput => CommandDef {
+            brief: "Select command output".into(),
+            doc: "Selects the output of the command under the mouse cursor, \
+                or else of the most recent command, as recorded by shell integration"
+                .into(),
+            keys: vec![],
+            args: &[ArgType::ActivePane],
+            menubar: &["Edit"],
+            icon: Some("md_select_all"),
+        },
         ScrollByCurrentEventWheelDelta => CommandDef {
             brief: "Scrolls based on the mouse wheel position \
                 in the current mouse event"
@@ -1622,6 +1715,14 @@ pub fn derive_command_from_key_assignment(action: &KeyAssignment) -> Option<Comm
             menubar: &["Shell"],
             icon: None,
         },
+        OpenLinkAtMouseCursorIn(target) => CommandDef {
+            brief: format!("Open link at mouse cursor in {target:?}").into(),
+            doc: "If there is no link under the mouse cursor, has no effect.".into(),
+            keys: vec![],
+            args: &[ArgType::ActivePane],
+            menubar: &[],
+            icon: None,
+        },
         ShowLauncherArgs(_) | ShowLauncher => CommandDef {
             brief: "Show the launcher".into(),
             doc: "Shows the launcher menu".into(),
@@ -1961,6 +2062,14 @@ pub fn derive_command_from_key_assignment(action: &KeyAssignment) -> Option<Comm
             menubar: &["Shell", "Attach"],
             icon: Some("md_pipe"),
         },
+        SwitchToTmuxSession { session, .. } => CommandDef {
+            brief: format!("Switch to tmux session `{session}`").into(),
+            doc: format!("Switches the tmux client to the session named `{session}`").into(),
+            keys: vec![],
+            args: &[ArgType::ActivePane],
+            menubar: &[],
+            icon: Some("md_pipe"),
+        },
         CopyMode(copy_mode) => CommandDef {
             brief: format!("{copy_mode:?}").into(),
             doc: "".into(),
@@ -2046,7 +2155,19 @@ fn compute_default_actions() -> Vec<KeyAssignment> {
         #[cfg(not(target_os = "macos"))]
         CopyTo(ClipboardCopyDestination::PrimarySelection),
         CopyTo(ClipboardCopyDestination::Clipboard),
+        CutTo(ClipboardCopyDestination::Clipboard),
         PasteFrom(ClipboardPasteSource::Clipboard),
+        SelectAll,
+        CopyCommandOutput(ClipboardCopyDestination::Clipboard),
+        SelectCommandOutput,
+        CopyLinkTo {
+            format: LinkFormat::Url,
+            destination: ClipboardCopyDestination::Clipboard,
+        },
+        CopyLinkTo {
+            format: LinkFormat::Markdown,
+            destination: ClipboardCopyDestination::Clipboard,
+        },
         ClearScrollback(ScrollbackEraseMode::ScrollbackOnly),
         ClearScrollback(ScrollbackEraseMode::ScrollbackAndViewport),
         QuickSelect,
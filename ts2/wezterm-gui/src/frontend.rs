@@ -7,14 +7,20 @@ use anyhow::{Context, Error};
 use config::keyassignment::{KeyAssignment, SpawnCommand};
 use config::{ConfigSubscription, NotificationHandling};
 use mux::client::ClientId;
+use mux::localpane::LocalPane;
+use mux::pane::PaneId;
 use mux::window::WindowId as MuxWindowId;
 use mux::{Mux, MuxNotification};
 use promise::{Future, Promise};
 use std::cell::RefCell;
 use std::collections::{BTreeMap, HashSet};
+use std::io::Write;
 use std::rc::Rc;
 use std::sync::Arc;
-use wezterm_term::{Alert, ClipboardSelection};
+use wezterm_term::{
+    Alert, ClipboardReadError, ClipboardSelection, DesktopNotification, NotificationOccasion,
+    NotificationUrgency,
+};
 use wezterm_toast_notification::*;
 
 pub struct GuiFrontEnd {
@@ -96,41 +102,42 @@ impl GuiFrontEnd {
                 MuxNotification::PaneAdded(_) => {}
                 MuxNotification::Alert {
                     pane_id,
-                    alert:
-                        Alert::ToastNotification {
+                    alert: Alert::ToastNotification { title, body, focus },
+                } => {
+                    let message = if title.is_none() {
+                        String::new()
+                    } else {
+                        body.clone()
+                    };
+                    let title = title.unwrap_or(body);
+                    show_pane_notification(
+                        &client_id,
+                        pane_id,
+                        ToastNotification {
                             title,
-                            body,
-                            focus: _,
+                            message,
+                            url: None,
+                            timeout: None,
+                            icon: None,
+                            on_click: None,
+                            on_close: None,
+                            tag: None,
+                            urgency: None,
                         },
+                        focus,
+                    );
+                }
+                MuxNotification::Alert {
+                    pane_id,
+                    alert: Alert::DesktopNotification(notif),
                 } => {
-                    let mux = Mux::get();
-
-                    if let Some((_domain, window_id, tab_id)) = mux.resolve_pane_id(pane_id) {
-                        let config = config::configuration();
-
-                        if let Some((_fdomain, f_window, f_tab, f_pane)) =
-                            mux.resolve_focused_pane(&client_id)
-                        {
-                            let show = match config.notification_handling {
-                                NotificationHandling::NeverShow => false,
-                                NotificationHandling::AlwaysShow => true,
-                                NotificationHandling::SuppressFromFocusedPane => f_pane != pane_id,
-                                NotificationHandling::SuppressFromFocusedTab => f_tab != tab_id,
-                                NotificationHandling::SuppressFromFocusedWindow => {
-                                    f_window != window_id
-                                }
-                            };
-
-                            if show {
-                                let message = if title.is_none() { "" } else { &body };
-                                let title = title.as_ref().unwrap_or(&body);
-                                // FIXME: if notification.focus is true, we should do
-                                // something here to arrange to focus pane_id when the
-                                // notification is clicked
-                                persistent_toast_notification(title, message);
-                            }
-                        }
-                    }
+                    show_desktop_notification(&client_id, pane_id, notif);
+                }
+                MuxNotification::Alert {
+                    pane_id,
+                    alert: Alert::CloseDesktopNotification { id },
+                } => {
+                    close_notification(&desktop_notification_tag(pane_id, &id));
                 }
                 MuxNotification::Alert {
                     pane_id: _,
@@ -200,6 +207,61 @@ impl GuiFrontEnd {
                     })
                     .detach();
                 }
+                MuxNotification::AssignClipboardData {
+                    pane_id,
+                    selection,
+                    data,
+                } => {
+                    promise::spawn::spawn_into_main_thread(async move {
+                        let fe = crate::frontend::front_end();
+                        log::trace!(
+                            "set clipboard data in pane {} {:?} {:?}",
+                            pane_id,
+                            selection,
+                            data.iter().map(|item| &item.mime_type).collect::<Vec<_>>()
+                        );
+                        if let Some(window) = fe.known_windows.borrow().keys().next() {
+                            window.set_clipboard_data(
+                                match selection {
+                                    ClipboardSelection::Clipboard => Clipboard::Clipboard,
+                                    ClipboardSelection::PrimarySelection => {
+                                        Clipboard::PrimarySelection
+                                    }
+                                },
+                                data.iter()
+                                    .map(|item| ClipboardData {
+                                        mime_type: item.mime_type.clone(),
+                                        data: item.data.clone(),
+                                    })
+                                    .collect(),
+                            );
+                        } else {
+                            log::error!("Cannot assign clipboard as there are no windows");
+                        };
+                    })
+                    .detach();
+                }
+                // ReadClipboard is handled by the TermWindow that contains
+                // the pane; if there is no such window, nothing will answer
+                // the request, so report that it is not supported
+                MuxNotification::ReadClipboard { pane_id, request } => {
+                    promise::spawn::spawn_into_main_thread(async move {
+                        let fe = crate::frontend::front_end();
+                        let mux = Mux::get();
+                        let in_window = match mux.resolve_pane_id(pane_id) {
+                            Some((_domain, window_id, _tab_id)) => fe.has_mux_window(window_id),
+                            None => false,
+                        };
+                        if !in_window {
+                            if let Some(pane) = mux.get_pane(pane_id) {
+                                let response =
+                                    request.error_response(ClipboardReadError::NotSupported);
+                                pane.writer().write_all(response.as_bytes()).ok();
+                            }
+                        }
+                    })
+                    .detach();
+                }
                 // WebOpen/WebClosed are handled by TermWindow
                 MuxNotification::WebOpen { .. } | MuxNotification::WebClosed { .. } => {}
             }
@@ -474,6 +536,12 @@ impl GuiFrontEnd {
         *self.switching_workspaces.borrow()
     }
 
+    /// Show a notification on behalf of a pane, subject to the
+    /// `notification_handling` configuration
+    pub fn show_pane_notification(&self, pane_id: PaneId, toast: ToastNotification, focus: bool) {
+        show_pane_notification(&self.client_id, pane_id, toast, focus);
+    }
+
     pub fn gui_window_for_mux_window(&self, mux_window_id: MuxWindowId) -> Option<GuiWin> {
         let windows = self.known_windows.borrow();
         for (window, v) in windows.iter() {
@@ -488,6 +556,166 @@ impl GuiFrontEnd {
     }
 }
 
+/// Show `toast` unless `notification_handling` suppresses notifications
+/// from `pane_id` while it has focus.  When `focus` is true, clicking
+/// the notification also activates the pane.
+fn show_pane_notification(
+    client_id: &ClientId,
+    pane_id: PaneId,
+    mut toast: ToastNotification,
+    focus: bool,
+) {
+    let mux = Mux::get();
+
+    if let Some((_domain, window_id, tab_id)) = mux.resolve_pane_id(pane_id) {
+        let config = config::configuration();
+
+        if let Some((_fdomain, f_window, f_tab, f_pane)) = mux.resolve_focused_pane(client_id) {
+            let show = match config.notification_handling {
+                NotificationHandling::NeverShow => false,
+                NotificationHandling::AlwaysShow => true,
+                NotificationHandling::SuppressFromFocusedPane => f_pane != pane_id,
+                NotificationHandling::SuppressFromFocusedTab => f_tab != tab_id,
+                NotificationHandling::SuppressFromFocusedWindow => f_window != window_id,
+            };
+
+            if show {
+                if focus {
+                    let on_click = toast.on_click.take();
+                    toast.on_click.replace(ClickHandler::new(move || {
+                        if let Some(on_click) = &on_click {
+                            on_click.call();
+                        }
+                        promise::spawn::spawn_into_main_thread(async move {
+                            activate_pane(pane_id);
+                        })
+                        .detach();
+                    }));
+                }
+                toast.show();
+            }
+        }
+    }
+}
+
+/// Show a notification from the kitty desktop notification protocol.
+/// Activating or closing it is reported back to the application in
+/// `pane_id` if it asked for that.
+fn show_desktop_notification(client_id: &ClientId, pane_id: PaneId, notif: DesktopNotification) {
+    let mux = Mux::get();
+    let Some((_domain, _window_id, tab_id)) = mux.resolve_pane_id(pane_id) else {
+        return;
+    };
+    if let Some((_fdomain, _f_window, f_tab, f_pane)) = mux.resolve_focused_pane(client_id) {
+        let suppress = match notif.occasion {
+            NotificationOccasion::Always => false,
+            NotificationOccasion::Unfocused => f_pane == pane_id,
+            NotificationOccasion::Invisible => f_tab == tab_id,
+        };
+        if suppress {
+            return;
+        }
+    }
+
+    let report_handler = |report: String| {
+        ClickHandler::new(move || {
+            let report = report.clone();
+            promise::spawn::spawn_into_main_thread(async move {
+                if let Some(pane) = Mux::get().get_pane(pane_id) {
+                    let mut writer = pane.writer();
+                    writer.write_all(report.as_bytes()).ok();
+                    writer.flush().ok();
+                }
+            })
+            .detach();
+        })
+    };
+
+    // A local terminal reports the close itself, so that it isn't
+    // reported again if the application later closes it too
+    let on_close = match (&notif.id, notif.close_report()) {
+        (Some(id), Some(report)) => {
+            let id = id.clone();
+            Some(ClickHandler::new(move || {
+                let id = id.clone();
+                let report = report.clone();
+                promise::spawn::spawn_into_main_thread(async move {
+                    let Some(pane) = Mux::get().get_pane(pane_id) else {
+                        return;
+                    };
+                    if let Some(pane) = pane.downcast_ref::<LocalPane>() {
+                        pane.notification_closed(&id);
+                    } else {
+                        let mut writer = pane.writer();
+                        writer.write_all(report.as_bytes()).ok();
+                        writer.flush().ok();
+                    }
+                })
+                .detach();
+            }))
+        }
+        _ => None,
+    };
+
+    let (title, message) = match notif.title {
+        Some(title) => (title, notif.body),
+        None => (notif.body, String::new()),
+    };
+
+    show_pane_notification(
+        client_id,
+        pane_id,
+        ToastNotification {
+            title,
+            message,
+            url: None,
+            timeout: notif.timeout.map(std::time::Duration::from_millis),
+            icon: None,
+            on_click: notif.activation_report().map(&report_handler),
+            on_close,
+            tag: notif
+                .id
+                .as_ref()
+                .map(|id| desktop_notification_tag(pane_id, id)),
+            urgency: Some(match notif.urgency {
+                NotificationUrgency::Low => Urgency::Low,
+                NotificationUrgency::Normal => Urgency::Normal,
+                NotificationUrgency::Critical => Urgency::Critical,
+            }),
+        },
+        notif.focus,
+    );
+}
+
+/// Notification identifiers are chosen by the application, so they
+/// are only unique within a pane
+fn desktop_notification_tag(pane_id: PaneId, id: &str) -> String {
+    format!("{}:{}", pane_id, id)
+}
+
+/// Make `pane_id` the active pane in its window and bring that
+/// window to the front
+fn activate_pane(pane_id: PaneId) {
+    let mux = Mux::get();
+    let Some(pane) = mux.get_pane(pane_id) else {
+        return;
+    };
+    let Some((_domain, window_id, tab_id)) = mux.resolve_pane_id(pane_id) else {
+        return;
+    };
+    if let Some(mut window) = mux.get_window_mut(window_id) {
+        if let Some(tab_idx) = window.idx_by_id(tab_id) {
+            window.save_and_then_set_active(tab_idx);
+        }
+    }
+    if let Some(tab) = mux.get_tab(tab_id) {
+        tab.set_active_pane(&pane);
+    }
+    if let Some(gui_window) = front_end().gui_window_for_mux_window(window_id) {
+        gui_window.window.focus();
+    }
+}
+
 thread_local! {
     static FRONT_END: RefCell<Option<Rc<GuiFrontEnd>>> = RefCell::new(None);
 }
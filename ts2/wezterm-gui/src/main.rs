@@ -22,6 +22,7 @@ use std::env::current_dir;
 use std::ffi::OsString;
 use std::path::PathBuf;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use termwiz::cell::CellAttributes;
 use termwiz::surface::{Line, SEQ_ZERO};
@@ -475,6 +476,112 @@ async fn trigger_and_log_gui_attached(domain: MuxDomain) {
     }
 }
 
+/// Restore a saved session.  Failures are reported rather than
+/// fatal; if nothing was restored, the usual initial tab is spawned.
+async fn restore_session(path: Option<PathBuf>) {
+    let path = path.unwrap_or_else(mux::web_session::default_session_path);
+    let result = match mux::web_session::Session::load(&path) {
+        Ok(session) => session.restore().await,
+        Err(err) => Err(err),
+    };
+    if let Err(err) = result {
+        let message = format!("while restoring session {}: {:#}", path.display(), err);
+        log::error!("{}", message);
+        persistent_toast_notification("Error", &message);
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref SESSION_SAVER: mux::web_session::SessionSaver = Default::default();
+}
+
+/// Set when the session was saved as the last window was closed,
+/// so that exiting without any windows keeps that session
+static SAVED_LAST_WINDOW: AtomicBool = AtomicBool::new(false);
+
+/// Capture the session for saving to the autosave session file, so
+/// that the panes and their contents are restored when the gui is
+/// next started.  Returns None unless `persist_pane_contents` is
+/// enabled and there are windows to save.
+fn capture_pane_contents() -> Option<mux::web_session::PendingSave> {
+    if !config::configuration().persist_pane_contents {
+        return None;
+    }
+    let mux = Mux::try_get()?;
+    let pending = SESSION_SAVER.capture(&mux);
+    if pending.is_empty() {
+        return None;
+    }
+    SAVED_LAST_WINDOW.store(false, Ordering::Relaxed);
+    Some(pending)
+}
+
+fn write_pane_contents(pending: mux::web_session::PendingSave) {
+    let path = mux::web_session::autosave_session_path();
+    if let Err(err) = pending.write(&path) {
+        log::error!("while saving session {}: {:#}", path.display(), err);
+    }
+}
+
+/// Save the session before exiting.  If there are no windows left
+/// because their programs exited, rather than because the last one
+/// was closed, there is nothing to restore, so the session file is
+/// removed instead.
+fn save_pane_contents() {
+    if !config::configuration().persist_pane_contents {
+        return;
+    }
+    if let Some(pending) = capture_pane_contents() {
+        write_pane_contents(pending);
+        return;
+    }
+    if SAVED_LAST_WINDOW.load(Ordering::Relaxed) {
+        return;
+    }
+    let path = mux::web_session::autosave_session_path();
+    if let Err(err) = std::fs::remove_file(&path) {
+        if err.kind() != std::io::ErrorKind::NotFound {
+            log::error!("while removing session {}: {:#}", path.display(), err);
+        }
+    }
+}
+
+/// Save the session if `mux_window_id` is the last window and is
+/// about to be closed, as by the time the gui exits, there will be
+/// no windows left to save
+pub fn save_pane_contents_before_closing(mux_window_id: mux::window::WindowId) {
+    let Some(mux) = Mux::try_get() else {
+        return;
+    };
+    if mux.iter_windows().into_iter().any(|id| id != mux_window_id) {
+        return;
+    }
+    if let Some(pending) = capture_pane_contents() {
+        write_pane_contents(pending);
+        SAVED_LAST_WINDOW.store(true, Ordering::Relaxed);
+    }
+}
+
+async fn save_pane_contents_periodically() {
+    loop {
+        let interval = config::configuration()
+            .pane_contents_save_interval
+            .max(1_000);
+        smol::Timer::after(std::time::Duration::from_millis(interval)).await;
+        // Snapshots are taken here, but encoding and writing them
+        // can take a while for large scrollbacks, so that is done
+        // on another thread
+        if let Some(pending) = capture_pane_contents() {
+            promise::spawn::spawn_into_new_thread(move || {
+                write_pane_contents(pending);
+                Ok(())
+            })
+            .await
+            .ok();
+        }
+    }
+}
+
 fn cell_pixel_dims(config: &ConfigHandle, dpi: f64) -> anyhow::Result<(usize, usize)> {
     let fontconfig = Rc::new(FontConfiguration::new(Some(config.clone()), dpi as usize)?);
     let render_metrics = RenderMetrics::new(&fontconfig)?;
@@ -535,6 +642,18 @@ async fn async_run_terminal_gui(
         trigger_and_log_gui_startup(spawn_command).await;
     }
 
+    if let Some(path) = &opts.restore_session {
+        restore_session(path.clone()).await;
+    } else if cmd.is_none()
+        && domain.is_none()
+        && !opts.attach
+        && config::configuration().persist_pane_contents
+        && mux::web_session::autosave_session_path().exists()
+    {
+        restore_session(Some(mux::web_session::autosave_session_path())).await;
+    }
+    promise::spawn::spawn(save_pane_contents_periodically()).detach();
+
     let is_connecting = opts.attach;
 
     if let Some(domain) = &domain {
@@ -838,7 +957,7 @@ fn run_terminal_gui(opts: StartCommand, default_domain_name: Option<String>) ->
     let mut publish = Publish::resolve(
         &mux,
         &config,
-        opts.always_new_process || opts.position.is_some(),
+        opts.always_new_process || opts.position.is_some() || opts.restore_session.is_some(),
     );
     log::trace!("{:?}", publish);
     if publish.try_spawn(
@@ -866,7 +985,9 @@ fn run_terminal_gui(opts: StartCommand, default_domain_name: Option<String>) ->
     .detach();
 
     maybe_show_configuration_error_window();
-    gui.run_forever()
+    let result = gui.run_forever();
+    save_pane_contents();
+    result
 }
 
 fn fatal_toast_notification(title: &str, message: &str) {
@@ -1361,6 +1482,7 @@ fn run() -> anyhow::Result<()> {
                 _cmd: false,
                 no_auto_connect: false,
                 cwd: None,
+                restore_session: None,
             },
             Some(connect.domain_name),
         ),
@@ -60,6 +60,7 @@ pub fn confirm_close_window(
         &mut term,
     )? {
         promise::spawn::spawn_into_main_thread(async move {
+            crate::save_pane_contents_before_closing(mux_window_id);
             let mux = Mux::get();
             mux.kill_window(mux_window_id);
         })
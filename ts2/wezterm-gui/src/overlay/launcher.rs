@@ -15,6 +15,7 @@ use config::keyassignment::{KeyAssignment, SpawnCommand, SpawnTabDomain};
 use mux::domain::{DomainId, DomainState};
 use mux::pane::PaneId;
 use mux::termwiztermtab::TermWizTerminal;
+use mux::tmux::TmuxDomain;
 use mux::window::WindowId;
 use mux::Mux;
 use rayon::prelude::*;
@@ -47,6 +48,8 @@ pub struct LauncherDomainEntry {
     pub name: String,
     pub state: DomainState,
     pub label: String,
+    /// The other sessions on the server of a tmux domain
+    pub tmux_sessions: Vec<String>,
 }
 
 pub struct LauncherArgs {
@@ -141,11 +144,16 @@ impl LauncherArgs {
                 } else {
                     format!("domain `{}` - {}", name, label)
                 };
+                let tmux_sessions = dom
+                    .downcast_ref::<TmuxDomain>()
+                    .map(|tmux| tmux.other_sessions())
+                    .unwrap_or_default();
                 d.push(LauncherDomainEntry {
                     domain_id: dom.domain_id(),
                     name: name.to_string(),
                     state: dom.state(),
                     label,
+                    tmux_sessions,
                 });
             }
             d
@@ -268,6 +276,16 @@ impl LauncherState {
                 self.active_idx = self.entries.len();
             }
             self.entries.push(entry);
+
+            for session in &domain.tmux_sessions {
+                self.entries.push(Entry {
+                    label: format!("Switch to tmux session `{}` ({})", session, domain.label),
+                    action: KeyAssignment::SwitchToTmuxSession {
+                        domain: SpawnTabDomain::DomainId(domain.domain_id),
+                        session: session.clone(),
+                    },
+                });
+            }
         }
 
         if args.flags.contains(LauncherFlags::WORKSPACES) {
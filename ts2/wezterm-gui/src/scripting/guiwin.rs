@@ -88,7 +88,12 @@ impl UserData for GuiWin {
                     title,
                     message,
                     url,
-                    timeout: timeout.map(std::time::Duration::from_millis)
+                    timeout: timeout.map(std::time::Duration::from_millis),
+                    icon: None,
+                    on_click: None,
+                    on_close: None,
+                    tag: None,
+                    urgency: None,
                 });
                 Ok(())
             },
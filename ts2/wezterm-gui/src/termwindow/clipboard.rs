@@ -1,10 +1,17 @@
+use crate::overlay::{confirm, start_overlay_pane};
 use crate::termwindow::TermWindowNotif;
 use crate::TermWindow;
-use config::keyassignment::{ClipboardCopyDestination, ClipboardPasteSource};
-use mux::pane::Pane;
+use config::keyassignment::{ClipboardCopyDestination, ClipboardPasteSource, LinkFormat};
+use config::ClipboardReadAccess;
+use mux::pane::{Pane, PaneId};
 use mux::Mux;
+use std::io::Write;
 use std::sync::Arc;
-use window::{Clipboard, WindowOps};
+use wezterm_term::{
+    is_text_mime_type, ClipboardData, ClipboardReadError, ClipboardReadRequest, ClipboardSelection,
+    CLIPBOARD_MIME_TYPES_QUERY,
+};
+use window::{Clipboard, Window, WindowOps};
 
 impl TermWindow {
     pub fn copy_to_clipboard(&self, clipboard: ClipboardCopyDestination, text: String) {
@@ -57,4 +64,267 @@ impl TermWindow {
         .detach();
         self.maybe_scroll_to_bottom_for_input(&pane);
     }
+
+    /// Answers a request by the application in a pane to read the
+    /// clipboard, asking the user first if clipboard_read_access
+    /// calls for it
+    pub fn read_clipboard_for_pane(&mut self, pane_id: PaneId, request: Arc<ClipboardReadRequest>) {
+        let mux = Mux::get();
+        let pane = match mux.get_pane(pane_id) {
+            Some(pane) => pane,
+            None => return,
+        };
+
+        match self.config.clipboard_read_access {
+            ClipboardReadAccess::Allow => self.send_clipboard_to_pane(pane_id, request),
+            ClipboardReadAccess::Deny => {
+                let response = request.error_response(ClipboardReadError::PermissionDenied);
+                pane.writer().write_all(response.as_bytes()).ok();
+            }
+            ClipboardReadAccess::Prompt => {
+                let window = self.window.clone().unwrap();
+                let message = match &request.name {
+                    Some(name) => format!("📋 Allow {} to read the clipboard?", name),
+                    None => "📋 Allow this pane to read the clipboard?".to_string(),
+                };
+                let (overlay, future) =
+                    start_overlay_pane(self, &pane, move |pane_id, mut term| {
+                        let allowed =
+                            confirm::run_confirmation(&message, &mut term).unwrap_or(false);
+                        window.notify(TermWindowNotif::Apply(Box::new(move |myself| {
+                            if allowed {
+                                myself.send_clipboard_to_pane(pane_id, request);
+                            } else if let Some(pane) = Mux::get().get_pane(pane_id) {
+                                let response =
+                                    request.error_response(ClipboardReadError::PermissionDenied);
+                                pane.writer().write_all(response.as_bytes()).ok();
+                            }
+                        })));
+                        Ok(())
+                    });
+                self.assign_overlay_for_pane(pane_id, overlay);
+                promise::spawn::spawn(future).detach();
+            }
+        }
+    }
+
+    fn send_clipboard_to_pane(&self, pane_id: PaneId, request: Arc<ClipboardReadRequest>) {
+        let window = self.window.as_ref().unwrap().clone();
+        promise::spawn::spawn(async move {
+            let data = read_clipboard_data(&window, &request).await;
+            if let Some(pane) = Mux::get().get_pane(pane_id) {
+                let response = request.response(&data);
+                pane.writer().write_all(response.as_bytes()).ok();
+            }
+        })
+        .detach();
+    }
+
+    /// Copy the hyperlink under the mouse cursor
+    pub fn copy_link_at_mouse_cursor(
+        &self,
+        format: LinkFormat,
+        destination: ClipboardCopyDestination,
+    ) {
+        if let Some(link) = self.current_highlight.as_ref() {
+            self.copy_to_clipboard(destination, format_link(format, link.uri(), ""));
+        }
+    }
+
+    /// Perform a clipboard related key assignment using the browser
+    /// of the pane.  Returns false if the pane has no browser, or if
+    /// the assignment should be performed as for a terminal pane.
+    #[cfg(all(target_os = "macos", feature = "cef"))]
+    pub fn perform_browser_clipboard_assignment(
+        &self,
+        pane: &Arc<dyn Pane>,
+        assignment: &config::keyassignment::KeyAssignment,
+    ) -> bool {
+        use crate::cef_browser::EditCommand;
+        use config::keyassignment::KeyAssignment;
+
+        let pane_id = pane.pane_id();
+        let browsers = self.browser_states.borrow();
+        let Some(browser) = browsers.get(&pane_id) else {
+            return false;
+        };
+        let window = self.window.as_ref().unwrap().clone();
+
+        match assignment {
+            KeyAssignment::CopyTo(destination) | KeyAssignment::CutTo(destination) => {
+                let cut = matches!(assignment, KeyAssignment::CutTo(_));
+                let command = match (destination, cut) {
+                    (ClipboardCopyDestination::PrimarySelection, true) => Some(EditCommand::Delete),
+                    (ClipboardCopyDestination::PrimarySelection, false) => None,
+                    (_, true) => Some(EditCommand::Cut),
+                    (_, false) => Some(EditCommand::Copy),
+                };
+                if *destination == ClipboardCopyDestination::Clipboard {
+                    browser.edit(command.unwrap());
+                    return true;
+                }
+
+                // The frame can only write to the clipboard itself, so
+                // the primary selection is given the selected text, which
+                // has to be read before a cut removes it
+                let text = browser.selection_text();
+                let cef_browser = browser.browser.clone();
+                promise::spawn::spawn(async move {
+                    match text.await {
+                        Ok(text) => window.set_clipboard(Clipboard::PrimarySelection, text),
+                        Err(err) => log::error!("Failed to read browser selection: {:#}", err),
+                    }
+                    if let Some(command) = command {
+                        command.run(&cef_browser);
+                    }
+                })
+                .detach();
+            }
+            KeyAssignment::PasteFrom(ClipboardPasteSource::Clipboard) => {
+                browser.edit(EditCommand::Paste);
+            }
+            KeyAssignment::PasteFrom(ClipboardPasteSource::PrimarySelection) => {
+                let future = window.get_clipboard(Clipboard::PrimarySelection);
+                promise::spawn::spawn(async move {
+                    if let Ok(clip) = future.await {
+                        window.notify(TermWindowNotif::Apply(Box::new(move |myself| {
+                            if let Some(browser) = myself.browser_states.borrow().get(&pane_id) {
+                                browser.insert_text(&clip);
+                            }
+                        })));
+                    }
+                })
+                .detach();
+            }
+            KeyAssignment::SelectAll => {
+                browser.edit(EditCommand::SelectAll);
+            }
+            KeyAssignment::CopyLinkTo {
+                format,
+                destination,
+            } => {
+                let link = browser.current_link();
+                let format = *format;
+                let destination = *destination;
+                promise::spawn::spawn(async move {
+                    match link.await {
+                        Ok(link) => {
+                            let text = format_link(format, &link.url, &link.title);
+                            window.notify(TermWindowNotif::Apply(Box::new(move |myself| {
+                                myself.copy_to_clipboard(destination, text);
+                            })));
+                        }
+                        Err(err) => log::error!("Failed to find the browser link: {:#}", err),
+                    }
+                })
+                .detach();
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Format a link for the clipboard.  A Markdown link with no title
+/// is written as an autolink.
+pub fn format_link(format: LinkFormat, url: &str, title: &str) -> String {
+    match format {
+        LinkFormat::Url => url.to_string(),
+        LinkFormat::Markdown => {
+            let mut destination = String::with_capacity(url.len());
+            for c in url.chars() {
+                match c {
+                    ' ' => destination.push_str("%20"),
+                    '(' => destination.push_str("%28"),
+                    ')' => destination.push_str("%29"),
+                    '<' => destination.push_str("%3C"),
+                    '>' => destination.push_str("%3E"),
+                    c => destination.push(c),
+                }
+            }
+
+            let mut label = String::with_capacity(title.len());
+            for word in title.split_whitespace() {
+                if !label.is_empty() {
+                    label.push(' ');
+                }
+                for c in word.chars() {
+                    if matches!(c, '\\' | '[' | ']') {
+                        label.push('\\');
+                    }
+                    label.push(c);
+                }
+            }
+
+            if label.is_empty() {
+                format!("<{destination}>")
+            } else {
+                format!("[{label}]({destination})")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn markdown_links() {
+        assert_eq!(
+            format_link(LinkFormat::Url, "https://example.com/a b", "Example"),
+            "https://example.com/a b"
+        );
+        assert_eq!(
+            format_link(
+                LinkFormat::Markdown,
+                "https://en.wikipedia.org/wiki/Rust_(programming_language)",
+                "  Rust\n [programming]  language "
+            ),
+            "[Rust \\[programming\\] language](https://en.wikipedia.org/wiki/Rust_%28programming_language%29)"
+        );
+        assert_eq!(
+            format_link(LinkFormat::Markdown, "https://example.com/", " "),
+            "<https://example.com/>"
+        );
+    }
+}
+
+/// Reads each of the mime types in the request from the clipboard.
+/// Types that are not available are left out of the result.
+async fn read_clipboard_data(
+    window: &Window,
+    request: &ClipboardReadRequest,
+) -> Vec<ClipboardData> {
+    let clipboard = match request.selection {
+        ClipboardSelection::Clipboard => Clipboard::Clipboard,
+        ClipboardSelection::PrimarySelection => Clipboard::PrimarySelection,
+    };
+
+    let mut result = vec![];
+    for mime_type in &request.mime_types {
+        let data = if mime_type == CLIPBOARD_MIME_TYPES_QUERY {
+            window
+                .get_clipboard_mime_types(clipboard)
+                .await
+                .map(|mime_types| mime_types.join(" ").into_bytes())
+        } else if is_text_mime_type(mime_type) {
+            window
+                .get_clipboard(clipboard)
+                .await
+                .map(|text| text.into_bytes())
+        } else {
+            window
+                .get_clipboard_data(clipboard, mime_type.clone())
+                .await
+        };
+        match data {
+            Ok(data) => result.push(ClipboardData {
+                mime_type: mime_type.clone(),
+                data,
+            }),
+            Err(err) => log::debug!("reading {} from the clipboard: {:#}", mime_type, err),
+        }
+    }
+    result
 }
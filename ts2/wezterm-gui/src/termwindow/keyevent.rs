@@ -596,38 +596,77 @@ impl super::TermWindow {
         }
     }
 
+    /// Pass the composition status of the input method to the browser in
+    /// the active pane, if it has one
+    #[cfg(all(target_os = "macos", feature = "cef"))]
+    pub fn advise_browser_composition(&self, status: &DeadKeyStatus) {
+        use crate::termwindow::TermWindowNotif;
+
+        let Some(pane) = self.get_active_pane_or_overlay() else {
+            return;
+        };
+        let pane_id = pane.pane_id();
+        let browsers = self.browser_states.borrow();
+        let Some(browser) = browsers.get(&pane_id) else {
+            return;
+        };
+        match status {
+            DeadKeyStatus::Composing(text) => browser.ime_set_composition(text),
+            DeadKeyStatus::None if browser.is_composing() => {
+                // A committed composition is followed by a key event with
+                // the text, which replaces the composition.  Wait for it
+                // before deciding that the composition was abandoned.
+                if let Some(window) = self.window.as_ref() {
+                    window.notify(TermWindowNotif::Apply(Box::new(move |myself| {
+                        if let Some(browser) = myself.browser_states.borrow().get(&pane_id) {
+                            browser.ime_cancel_composition();
+                        }
+                    })));
+                }
+            }
+            DeadKeyStatus::None => {}
+        }
+    }
+
+    /// Commit the compositions of browsers that no longer receive key
+    /// events, because another pane became active or the window lost
+    /// the focus
+    #[cfg(all(target_os = "macos", feature = "cef"))]
+    pub fn finish_inactive_browser_compositions(&self) {
+        let active = match self.focused {
+            Some(_) => self.get_active_pane_or_overlay().map(|pane| pane.pane_id()),
+            None => None,
+        };
+        crate::cef_browser::finish_inactive_compositions(&self.browser_states.borrow(), active);
+    }
+
+    /// Insert text produced by an input method, a dead key or a compose
+    /// sequence into the browser of the pane.  Returns false if the key
+    /// event doesn't carry such text.
+    #[cfg(all(target_os = "macos", feature = "cef"))]
+    fn commit_browser_text(&self, pane_id: mux::pane::PaneId, window_key: &KeyEvent) -> bool {
+        if !window_key.key_is_down {
+            return false;
+        }
+        let browsers = self.browser_states.borrow();
+        let Some(browser) = browsers.get(&pane_id) else {
+            return false;
+        };
+        let text = match &window_key.key {
+            KeyCode::Composed(text) => text.clone(),
+            KeyCode::Char(c) if browser.is_composing() => c.to_string(),
+            _ => return false,
+        };
+        browser.ime_commit_text(&text);
+        true
+    }
+
     pub fn key_event_impl(&mut self, window_key: KeyEvent, context: &dyn WindowOps) {
         let pane = match self.get_active_pane_or_overlay() {
             Some(pane) => pane,
             None => return,
         };
 
-        // CEF browser key handling - intercept Ctrl+C to close browser
-        #[cfg(all(target_os = "macos", feature = "cef"))]
-        {
-            let pane_id = pane.pane_id();
-            if self.has_browser_for_pane(pane_id) {
-                // Check for Ctrl+C to close the browser
-                let is_ctrl_c = window_key.key_is_down
-                    && window_key.modifiers.contains(::window::Modifiers::CTRL)
-                    && matches!(
-                        &window_key.key,
-                        ::window::KeyCode::Char('c') | ::window::KeyCode::Char('C')
-                    );
-
-                if is_ctrl_c {
-                    log::info!("[CEF] Ctrl+C pressed, closing browser for pane {}", pane_id);
-                    self.close_browser_for_pane(pane_id);
-                    return;
-                }
-
-                // Forward other key events to the browser
-                // TODO: Implement full key event forwarding to CEF browser
-                // For now, consume all keys when browser is active
-                return;
-            }
-        }
-
         // The leader key is a kind of modal modifier key.
         // It is allowed to be active for up to the leader timeout duration,
         // after which it auto-deactivates.
@@ -652,6 +691,49 @@ impl super::TermWindow {
             );
         }
 
+        // CEF browser key handling - intercept Ctrl+C to close browser
+        #[cfg(all(target_os = "macos", feature = "cef"))]
+        {
+            let pane_id = pane.pane_id();
+            if self.has_browser_for_pane(pane_id) {
+                // Check for Ctrl+C to close the browser.  Ctrl+Shift+C is
+                // left alone as it is bound to CopyTo by default.
+                let is_ctrl_c = window_key.key_is_down
+                    && window_key.modifiers.contains(::window::Modifiers::CTRL)
+                    && !window_key.modifiers.contains(::window::Modifiers::SHIFT)
+                    && matches!(
+                        &window_key.key,
+                        ::window::KeyCode::Char('c') | ::window::KeyCode::Char('C')
+                    );
+
+                if is_ctrl_c {
+                    log::info!("[CEF] Ctrl+C pressed, closing browser for pane {}", pane_id);
+                    self.close_browser_for_pane(pane_id);
+                    return;
+                }
+
+                if self.commit_browser_text(pane_id, &window_key) {
+                    return;
+                }
+
+                // Key assignments such as CopyTo and PasteFrom apply to
+                // the browser too; any other key is consumed.
+                // TODO: Implement full key event forwarding to CEF browser
+                self.process_key(
+                    &pane,
+                    context,
+                    &window_key.key,
+                    window_key.modifiers,
+                    leader_active,
+                    leader_mod,
+                    OnlyKeyBindings::Yes,
+                    window_key.key_is_down,
+                    Some(&window_key),
+                );
+                return;
+            }
+        }
+
         let modifiers = window_key.modifiers;
 
         if self.process_key(
@@ -48,6 +48,7 @@ use mux::tab::{
     PositionedPane, PositionedSplit, SplitDirection, SplitRequest, SplitSize as MuxSplitSize, Tab,
     TabId,
 };
+use mux::tmux::TmuxDomain;
 use mux::window::WindowId as MuxWindowId;
 use mux::{Mux, MuxNotification};
 use mux_lua::MuxPane;
@@ -60,13 +61,15 @@ use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use termwiz::hyperlink::Hyperlink;
+use termwiz::hyperlink::{Hyperlink, LinkTarget};
 use termwiz::surface::SequenceNo;
 use wezterm_dynamic::Value;
 use wezterm_font::FontConfiguration;
 use wezterm_term::color::ColorPalette;
 use wezterm_term::input::LastMouseClick;
-use wezterm_term::{Alert, Progress, StableRowIndex, TerminalConfiguration, TerminalSize};
+use wezterm_term::{
+    Alert, CommandRecord, Progress, StableRowIndex, TerminalConfiguration, TerminalSize,
+};
 
 pub mod background;
 pub mod box_model;
@@ -185,6 +188,12 @@ pub struct SemanticZoneCache {
     zones: Vec<StableRowIndex>,
 }
 
+#[derive(Clone, Default)]
+pub struct CommandRecordCache {
+    seqno: SequenceNo,
+    records: Vec<CommandRecord>,
+}
+
 pub struct OverlayState {
     pub pane: Arc<dyn Pane>,
     pub key_table_state: KeyTableState,
@@ -406,6 +415,7 @@ pub struct TermWindow {
     tab_state: RefCell<HashMap<TabId, TabState>>,
     pane_state: RefCell<HashMap<PaneId, PaneState>>,
     semantic_zones: HashMap<PaneId, SemanticZoneCache>,
+    command_records: HashMap<PaneId, CommandRecordCache>,
 
     window_background: Vec<LoadedBackgroundLayer>,
 
@@ -467,6 +477,9 @@ pub struct TermWindow {
     /// CEF browser states for web overlay panes
     #[cfg(all(target_os = "macos", feature = "cef"))]
     browser_states: RefCell<std::collections::HashMap<PaneId, crate::cef_browser::BrowserState>>,
+    /// Browser pane that links opened with LinkTarget::BrowserPreview reuse
+    #[cfg(all(target_os = "macos", feature = "cef"))]
+    preview_browser_pane: std::cell::Cell<Option<PaneId>>,
 }
 
 impl TermWindow {
@@ -487,6 +500,7 @@ impl TermWindow {
         match self.config.window_close_confirmation {
             WindowCloseConfirmation::NeverPrompt => {
                 // Immediately kill the tabs and allow the window to close
+                crate::save_pane_contents_before_closing(self.mux_window_id);
                 mux.kill_window(self.mux_window_id);
                 window.close();
                 front_end().forget_known_window(window);
@@ -495,6 +509,7 @@ impl TermWindow {
                 let tab = match mux.get_active_tab_for_window(self.mux_window_id) {
                     Some(tab) => tab,
                     None => {
+                        crate::save_pane_contents_before_closing(self.mux_window_id);
                         mux.kill_window(self.mux_window_id);
                         window.close();
                         front_end().forget_known_window(window);
@@ -508,6 +523,7 @@ impl TermWindow {
                     .get_window(mux_window_id)
                     .map_or(false, |w| w.can_close_without_prompting());
                 if can_close {
+                    crate::save_pane_contents_before_closing(self.mux_window_id);
                     mux.kill_window(self.mux_window_id);
                     window.close();
                     front_end().forget_known_window(window);
@@ -552,6 +568,8 @@ impl TermWindow {
         if let Some(pane) = self.get_active_pane_or_overlay() {
             pane.focus_changed(focused);
         }
+        #[cfg(all(target_os = "macos", feature = "cef"))]
+        self.finish_inactive_browser_compositions();
 
         self.update_title();
         self.emit_window_event("window-focus-changed", None);
@@ -785,6 +803,7 @@ impl TermWindow {
             scheduled_animation: RefCell::new(None),
             allow_images: AllowImage::Yes,
             semantic_zones: HashMap::new(),
+            command_records: HashMap::new(),
             ui_items: vec![],
             dragging: None,
             last_ui_item: None,
@@ -794,6 +813,8 @@ impl TermWindow {
             opengl_info: None,
             #[cfg(all(target_os = "macos", feature = "cef"))]
             browser_states: RefCell::new(std::collections::HashMap::new()),
+            #[cfg(all(target_os = "macos", feature = "cef"))]
+            preview_browser_pane: std::cell::Cell::new(None),
         };
 
         let tw = Rc::new(RefCell::new(myself));
@@ -997,6 +1018,8 @@ impl TermWindow {
                 } else {
                     log::trace!("DeadKeyStatus now: {:?}", status);
                 }
+                #[cfg(all(target_os = "macos", feature = "cef"))]
+                self.advise_browser_composition(&status);
                 self.dead_key_status = status;
                 self.update_title();
                 // Ensure that we repaint so that any composing
@@ -1229,6 +1252,8 @@ impl TermWindow {
                     // this overall invalidation for the palette
                     self.dispatch_notif(TermWindowNotif::InvalidateShapeCache, window)?;
                     self.mux_pane_output_event(pane_id);
+                    #[cfg(all(target_os = "macos", feature = "cef"))]
+                    self.update_browser_appearance();
                 }
                 MuxNotification::Alert {
                     alert: Alert::Bell,
@@ -1253,7 +1278,10 @@ impl TermWindow {
                     window.invalidate();
                 }
                 MuxNotification::Alert {
-                    alert: Alert::ToastNotification { .. },
+                    alert:
+                        Alert::ToastNotification { .. }
+                        | Alert::DesktopNotification(_)
+                        | Alert::CloseDesktopNotification { .. },
                     ..
                 } => {}
                 MuxNotification::TabAddedToWindow {
@@ -1297,9 +1325,15 @@ impl TermWindow {
                 MuxNotification::WindowRemoved(_window_id) => {
                     // Handled by frontend
                 }
-                MuxNotification::AssignClipboard { .. } => {
+                MuxNotification::AssignClipboard { .. }
+                | MuxNotification::AssignClipboardData { .. } => {
                     // Handled by frontend
                 }
+                MuxNotification::ReadClipboard { pane_id, request } => {
+                    if self.window_contains_pane(pane_id) {
+                        self.read_clipboard_for_pane(pane_id, request);
+                    }
+                }
                 MuxNotification::SaveToDownloads { .. } => {
                     // Handled by frontend
                 }
@@ -1322,12 +1356,16 @@ impl TermWindow {
                 | MuxNotification::Empty
                 | MuxNotification::WindowCreated(_)
                 | MuxNotification::WebClosed { .. } => {}
-                MuxNotification::WebOpen { pane_id, url } => {
+                MuxNotification::WebOpen {
+                    pane_id,
+                    url,
+                    profile,
+                } => {
                     #[cfg(all(target_os = "macos", feature = "cef"))]
-                    self.handle_web_open(pane_id, url);
+                    self.handle_web_open(pane_id, url, profile);
                     #[cfg(not(all(target_os = "macos", feature = "cef")))]
                     {
-                        let _ = (pane_id, url);
+                        let _ = (pane_id, url, profile);
                         log::warn!("WebOpen notification received but CEF is not enabled");
                     }
                 }
@@ -1532,10 +1570,14 @@ impl TermWindow {
                 }
             }
             MuxNotification::Alert {
-                alert: Alert::ToastNotification { .. },
+                alert:
+                    Alert::ToastNotification { .. }
+                    | Alert::DesktopNotification(_)
+                    | Alert::CloseDesktopNotification { .. },
                 ..
             }
             | MuxNotification::AssignClipboard { .. }
+            | MuxNotification::AssignClipboardData { .. }
             | MuxNotification::SaveToDownloads { .. }
             | MuxNotification::WindowCreated(_)
             | MuxNotification::ActiveWorkspaceChanged(_)
@@ -1543,8 +1585,9 @@ impl TermWindow {
             | MuxNotification::Empty
             | MuxNotification::WindowWorkspaceChanged(_)
             | MuxNotification::WebClosed { .. } => return true,
-            // WebOpen should be forwarded to the window for handling
-            MuxNotification::WebOpen { .. } => {
+            // WebOpen and ReadClipboard should be forwarded to the window
+            // for handling
+            MuxNotification::WebOpen { .. } | MuxNotification::ReadClipboard { .. } => {
                 // fall through to notify window
             }
             MuxNotification::Alert {
@@ -1849,6 +1892,9 @@ impl TermWindow {
             window.invalidate();
         }
 
+        #[cfg(all(target_os = "macos", feature = "cef"))]
+        self.update_browser_appearance();
+
         // Do this after we've potentially adjusted scaling based on config/padding
         // and window size
         self.window_background = reload_background_image(
@@ -1990,6 +2036,10 @@ impl TermWindow {
         let active_tab = tabs.iter().find(|t| t.is_active).cloned();
         let active_pane = panes.iter().find(|p| p.is_active).cloned();
 
+        // This runs whenever the active tab or pane changes
+        #[cfg(all(target_os = "macos", feature = "cef"))]
+        self.finish_inactive_browser_compositions();
+
         let border = self.get_os_border();
         let tab_bar_height = self.tab_bar_pixel_height().unwrap_or(0.);
         let tab_bar_y = if self.config.tab_bar_at_bottom {
@@ -2131,6 +2181,21 @@ impl TermWindow {
     }
 
     fn update_text_cursor(&mut self, pos: &PositionedPane) {
+        // Place the candidate window next to the text that is being
+        // composed in a browser pane
+        #[cfg(all(target_os = "macos", feature = "cef"))]
+        if let Some(r) = self
+            .browser_states
+            .borrow()
+            .get(&pos.pane.pane_id())
+            .and_then(|browser| browser.ime_rect())
+        {
+            if let Some(win) = self.window.as_ref() {
+                win.set_text_cursor_position(r);
+            }
+            return;
+        }
+
         if let Some(win) = self.window.as_ref() {
             let cursor = pos.pane.get_cursor_position();
             let top = pos.pane.get_dimensions().physical_top;
@@ -2518,6 +2583,21 @@ impl TermWindow {
         &cache.zones
     }
 
+    /// Returns the commands that shell integration has recorded
+    fn get_command_records(&mut self, pane: &Arc<dyn Pane>) -> &[CommandRecord] {
+        let cache = self
+            .command_records
+            .entry(pane.pane_id())
+            .or_insert_with(CommandRecordCache::default);
+
+        let seqno = pane.get_current_seqno();
+        if cache.seqno != seqno {
+            cache.records = pane.get_command_records().unwrap_or_else(|_| vec![]);
+            cache.seqno = seqno;
+        }
+        &cache.records
+    }
+
     fn scroll_to_prompt(&mut self, amount: isize, pane: &Arc<dyn Pane>) -> anyhow::Result<()> {
         let dims = pane.get_dimensions();
         let position = self
@@ -2619,6 +2699,11 @@ impl TermWindow {
             result => return Ok(result),
         }
 
+        #[cfg(all(target_os = "macos", feature = "cef"))]
+        if self.perform_browser_clipboard_assignment(pane, assignment) {
+            return Ok(PerformAssignmentResult::Handled);
+        }
+
         let window = self.window.as_ref().map(|w| w.clone());
 
         match assignment {
@@ -2727,16 +2812,23 @@ impl TermWindow {
                 let window = self.window.clone().unwrap();
                 window.set_window_level(level.clone());
             }
-            CopyTo(dest) => {
+            CopyTo(dest) | CutTo(dest) => {
                 let text = self.selection_text(pane);
                 self.copy_to_clipboard(*dest, text);
             }
             CopyTextTo { text, destination } => {
                 self.copy_to_clipboard(*destination, text.clone());
             }
+            CopyLinkTo {
+                format,
+                destination,
+            } => {
+                self.copy_link_at_mouse_cursor(*format, *destination);
+            }
             PasteFrom(source) => {
                 self.paste_from_clipboard(pane, *source);
             }
+            SelectAll => self.select_all(pane),
             ActivateTabRelative(n) => {
                 self.activate_tab_relative(*n, true)?;
             }
@@ -2794,6 +2886,8 @@ impl TermWindow {
             ScrollByLine(n) => self.scroll_by_line(*n, pane)?,
             ScrollByCurrentEventWheelDelta => self.scroll_by_current_event_wheel_delta(pane)?,
             ScrollToPrompt(n) => self.scroll_to_prompt(*n, pane)?,
+            CopyCommandOutput(dest) => self.copy_command_output(pane, *dest),
+            SelectCommandOutput => self.select_command_output(pane),
             ScrollToTop => self.scroll_to_top(pane),
             ScrollToBottom => self.scroll_to_bottom(pane),
             ShowTabNavigator => self.show_tab_navigator(),
@@ -2850,7 +2944,10 @@ impl TermWindow {
                 self.window_drag_position = self.current_mouse_event.clone();
             }
             OpenLinkAtMouseCursor => {
-                self.do_open_link_at_mouse_cursor(pane);
+                self.do_open_link_at_mouse_cursor(pane, None);
+            }
+            OpenLinkAtMouseCursorIn(target) => {
+                self.do_open_link_at_mouse_cursor(pane, Some(*target));
             }
             EmitEvent(name) => {
                 self.emit_window_event(name, None);
@@ -2862,7 +2959,7 @@ impl TermWindow {
                     let window = self.window.as_ref().unwrap();
                     window.invalidate();
                 } else {
-                    self.do_open_link_at_mouse_cursor(pane);
+                    self.do_open_link_at_mouse_cursor(pane, None);
                 }
             }
             CompleteSelection(dest) => {
@@ -3079,6 +3176,13 @@ impl TermWindow {
                 let domain = Mux::get().resolve_spawn_tab_domain(Some(pane.pane_id()), domain)?;
                 domain.detach()?;
             }
+            SwitchToTmuxSession { domain, session } => {
+                let domain = Mux::get().resolve_spawn_tab_domain(Some(pane.pane_id()), domain)?;
+                let tmux_domain = domain
+                    .downcast_ref::<TmuxDomain>()
+                    .ok_or_else(|| anyhow!("{} is not a tmux domain", domain.domain_name()))?;
+                tmux_domain.switch_session(session)?;
+            }
             AttachDomain(domain) => {
                 let window = self.mux_window_id;
                 let domain = domain.to_string();
@@ -3174,7 +3278,8 @@ impl TermWindow {
                 )]);
             }
             OpenUri(link) => {
-                wezterm_open_url::open_url(link);
+                let target = self.link_target(&Hyperlink::new(link.as_str()));
+                self.open_link(pane.pane_id(), link.clone(), target);
             }
             ActivateCommandPalette => {
                 let modal = crate::termwindow::palette::CommandPalette::new(self);
@@ -3187,7 +3292,36 @@ impl TermWindow {
         Ok(PerformAssignmentResult::Handled)
     }
 
-    fn do_open_link_at_mouse_cursor(&self, pane: &Arc<dyn Pane>) {
+    /// Decide where a link should be opened.  The rule that produced an
+    /// implicit link, or a rule that matches the whole of an explicit
+    /// link, takes precedence over `hyperlink_open_in`.
+    fn link_target(&self, link: &Hyperlink) -> LinkTarget {
+        LinkTarget::for_link(link)
+            .or_else(|| {
+                let uri = link.uri();
+                termwiz::hyperlink::Rule::match_hyperlinks(uri, &self.config.hyperlink_rules)
+                    .into_iter()
+                    .filter(|m| m.range == (0..uri.len()))
+                    .find_map(|m| LinkTarget::for_link(&m.link))
+            })
+            .unwrap_or(self.config.hyperlink_open_in)
+    }
+
+    fn open_link(&mut self, pane_id: PaneId, url: String, target: LinkTarget) {
+        match target {
+            LinkTarget::External => wezterm_open_url::open_url(&url),
+            #[cfg(all(target_os = "macos", feature = "cef"))]
+            _ => self.open_link_in_browser(pane_id, url, target),
+            #[cfg(not(all(target_os = "macos", feature = "cef")))]
+            _ => {
+                let _ = pane_id;
+                log::warn!("{target:?} requires CEF; opening {url} externally");
+                wezterm_open_url::open_url(&url);
+            }
+        }
+    }
+
+    fn do_open_link_at_mouse_cursor(&self, pane: &Arc<dyn Pane>, target: Option<LinkTarget>) {
         // They clicked on a link, so let's open it!
         // We need to ensure that we spawn the `open` call outside of the context
         // of our window loop; on Windows it can cause a panic due to
@@ -3198,13 +3332,17 @@ impl TermWindow {
         if let Some(link) = self.current_highlight.as_ref().cloned() {
             let window = GuiWin::new(self);
             let pane = MuxPane(pane.pane_id());
+            let target = target.unwrap_or_else(|| self.link_target(&link));
 
             async fn open_uri(
                 lua: Option<Rc<mlua::Lua>>,
                 window: GuiWin,
                 pane: MuxPane,
                 link: String,
+                target: LinkTarget,
             ) -> anyhow::Result<()> {
+                let term_window = window.window.clone();
+                let pane_id = pane.0;
                 let default_click = match lua {
                     Some(lua) => {
                         let args = lua.pack_multi((window, pane, link.clone()))?;
@@ -3219,13 +3357,19 @@ impl TermWindow {
                 };
                 if default_click {
                     log::info!("clicking {}", link);
-                    wezterm_open_url::open_url(&link);
+                    if target == LinkTarget::External {
+                        wezterm_open_url::open_url(&link);
+                    } else {
+                        term_window.notify(TermWindowNotif::Apply(Box::new(move |tw| {
+                            tw.open_link(pane_id, link, target)
+                        })));
+                    }
                 }
                 Ok(())
             }
 
             promise::spawn::spawn(config::with_lua_config_on_main_thread(move |lua| {
-                open_uri(lua, window, pane, link.uri().to_string())
+                open_uri(lua, window, pane, link.uri().to_string(), target)
             }))
             .detach();
         }
@@ -3642,11 +3786,12 @@ impl TermWindow {
 #[cfg(all(target_os = "macos", feature = "cef"))]
 impl TermWindow {
     /// Handle WebOpen notification - create a browser overlay for the pane
-    pub fn handle_web_open(&self, pane_id: PaneId, url: String) {
+    pub fn handle_web_open(&self, pane_id: PaneId, url: String, profile: String) {
         log::info!(
-            "[CEF] handle_web_open called for pane {} with URL: {}",
+            "[CEF] handle_web_open called for pane {} with URL: {} (profile {})",
             pane_id,
-            url
+            url,
+            profile
         );
 
         // Check if we already have a browser for this pane
@@ -3658,6 +3803,38 @@ impl TermWindow {
             self.close_browser_for_pane(pane_id);
         }
 
+        let mode = if self.preview_browser_pane.get() == Some(pane_id) {
+            mux::web_session::BrowserMode::Preview
+        } else {
+            mux::web_session::BrowserMode::Normal
+        };
+        let session = Mux::get().register_web_session(
+            pane_id,
+            mux::web_session::BrowserSession::new(&url, &profile, mode),
+        );
+        self.create_browser(pane_id, session);
+    }
+
+    /// Create the browser for a pane that was restored from a session
+    /// and has not been shown since
+    pub fn restore_browser_for_pane(&self, pane_id: PaneId) {
+        let Some(session) = Mux::get().get_web_session(pane_id) else {
+            return;
+        };
+        log::info!("[CEF] Restoring browser for pane {}", pane_id);
+        if session.lock().mode == mux::web_session::BrowserMode::Preview {
+            self.preview_browser_pane.set(Some(pane_id));
+        }
+        self.create_browser(pane_id, session);
+    }
+
+    /// Create the browser for a pane from its session state
+    fn create_browser(&self, pane_id: PaneId, session: mux::web_session::SharedBrowserSession) {
+        let (url, profile) = {
+            let session = session.lock();
+            (session.url.clone(), session.profile.clone())
+        };
+
         // Get wgpu device, queue, and CEF bind group layout from render state
         let (device, queue, cef_bind_group_layout) = match &self.webgpu {
             Some(webgpu) => (
@@ -3667,21 +3844,39 @@ impl TermWindow {
             ),
             None => {
                 log::error!("[CEF] WebGPU not available, cannot create browser");
+                Mux::get().unregister_web_session(pane_id);
                 return;
             }
         };
 
-        // Get pane dimensions
-        let (width, height) = {
+        let content_blocker = self
+            .config
+            .browser_profiles
+            .get(&profile)
+            .and_then(|settings| {
+                let filters = crate::cef_browser::load_filter_lists(&settings.filter_lists)?;
+                let state =
+                    Mux::get().register_web_content_blocking(pane_id, settings.content_blocking);
+                Some(crate::cef_browser::ContentBlocker::new(filters, state))
+            });
+
+        // Get pane dimensions and the appearance derived from its palette.
+        // With content blocking, the bottom row shows its status.
+        let status_rows = content_blocker.is_some() as usize;
+        let (width, height, appearance) = {
             let mux = Mux::get();
             if let Some(pane) = mux.get_pane(pane_id) {
                 let dims = pane.get_dimensions();
                 (
                     (dims.cols as f32 * self.render_metrics.cell_size.width as f32) as u32,
-                    (dims.viewport_rows as f32 * self.render_metrics.cell_size.height as f32) as u32,
+                    (dims.viewport_rows.saturating_sub(status_rows) as f32
+                        * self.render_metrics.cell_size.height as f32) as u32,
+                    crate::cef_browser::BrowserAppearance::new(&self.config, &pane.palette()),
                 )
             } else {
                 log::error!("[CEF] Pane {} not found", pane_id);
+                mux.unregister_web_content_blocking(pane_id);
+                mux.unregister_web_session(pane_id);
                 return;
             }
         };
@@ -3704,8 +3899,17 @@ impl TermWindow {
             &queue,
             &cef_bind_group_layout,
             invalidate_callback,
+            appearance,
+            &profile,
+            Mux::get().register_web_network_log(pane_id),
+            Mux::get().web_routes(),
+            content_blocker,
+            session,
         ) {
-            Ok(state) => {
+            Ok(mut state) => {
+                if let Some(settings) = self.config.browser_profiles.get(&profile) {
+                    state.enable_notifications(settings);
+                }
                 self.browser_states.borrow_mut().insert(pane_id, state);
                 log::info!("[CEF] Browser created successfully for pane {}", pane_id);
 
@@ -3716,10 +3920,96 @@ impl TermWindow {
             }
             Err(e) => {
                 log::error!("[CEF] Failed to create browser for pane {}: {}", pane_id, e);
+                Mux::get().unregister_web_network_log(pane_id);
+                Mux::get().unregister_web_content_blocking(pane_id);
+                Mux::get().unregister_web_session(pane_id);
             }
         }
     }
 
+    /// Open a link in a browser pane next to `pane_id`.  A preview
+    /// browser is created once and then navigated by subsequent links.
+    fn open_link_in_browser(&mut self, pane_id: PaneId, url: String, target: LinkTarget) {
+        let profile = mux::web::DEFAULT_PROFILE.to_string();
+
+        if target == LinkTarget::BrowserPreview {
+            let preview = self
+                .preview_browser_pane
+                .get()
+                .filter(|id| Mux::get().get_pane(*id).is_some());
+            if let Some(preview) = preview {
+                if let Some(browser) = self.browser_states.borrow_mut().get_mut(&preview) {
+                    browser.load_url(&url);
+                    return;
+                }
+                // The browser was closed but its pane is still around
+                self.handle_web_open(preview, url, profile);
+                return;
+            }
+        }
+
+        let window = match self.window.clone() {
+            Some(window) => window,
+            None => return,
+        };
+        let size = self.terminal_size;
+        let term_config = Arc::new(TermConfig::with_config(self.config.clone()));
+        let mux_window_id = self.mux_window_id;
+
+        promise::spawn::spawn(async move {
+            let mux = Mux::get();
+            let pane = if target == LinkTarget::BrowserTab {
+                mux.spawn_tab_or_window(
+                    Some(mux_window_id),
+                    config::keyassignment::SpawnTabDomain::CurrentPaneDomain,
+                    None,
+                    None,
+                    size,
+                    Some(pane_id),
+                    mux.active_workspace(),
+                    None,
+                )
+                .await
+                .map(|(_tab, pane, _window_id)| pane)
+            } else {
+                mux.split_pane(
+                    pane_id,
+                    SplitRequest {
+                        direction: SplitDirection::Horizontal,
+                        target_is_second: true,
+                        top_level: false,
+                        size: MuxSplitSize::Percent(50),
+                    },
+                    mux::domain::SplitSource::Spawn {
+                        command: None,
+                        command_dir: None,
+                    },
+                    config::keyassignment::SpawnTabDomain::CurrentPaneDomain,
+                )
+                .await
+                .map(|(pane, _size)| pane)
+            };
+
+            let pane = match pane {
+                Ok(pane) => pane,
+                Err(err) => {
+                    log::error!("[CEF] Failed to spawn pane for {}: {:#}", url, err);
+                    return;
+                }
+            };
+            pane.set_config(term_config);
+
+            let new_pane_id = pane.pane_id();
+            window.notify(TermWindowNotif::Apply(Box::new(move |tw| {
+                if target == LinkTarget::BrowserPreview {
+                    tw.preview_browser_pane.set(Some(new_pane_id));
+                }
+                tw.handle_web_open(new_pane_id, url, profile);
+            })));
+        })
+        .detach();
+    }
+
     /// Close and remove the browser for a pane
     pub fn close_browser_for_pane(&self, pane_id: PaneId) {
         log::info!("[CEF] Closing browser for pane {}", pane_id);
@@ -3729,6 +4019,9 @@ impl TermWindow {
 
         // Notify mux that browser closed
         let mux = Mux::get();
+        mux.unregister_web_network_log(pane_id);
+        mux.unregister_web_content_blocking(pane_id);
+        mux.unregister_web_session(pane_id);
         mux.notify(MuxNotification::WebClosed { pane_id });
 
         // Trigger redraw
@@ -3737,10 +4030,34 @@ impl TermWindow {
         }
     }
 
+    /// Fire the click event of a web notification in the page of the
+    /// browser pane that showed it
+    pub fn web_notification_clicked(&self, pane_id: PaneId, context_id: i64, id: u64) {
+        if let Some(browser) = self.browser_states.borrow().get(&pane_id) {
+            browser.notification_clicked(context_id, id);
+        }
+    }
+
     /// Check if a pane has an active browser overlay
     pub fn has_browser_for_pane(&self, pane_id: PaneId) -> bool {
         self.browser_states.borrow().contains_key(&pane_id)
     }
+
+    /// Re-derive the page appearance after the config or the
+    /// pane palette has changed
+    fn update_browser_appearance(&self) {
+        let mux = Mux::get();
+        for (pane_id, browser) in self.browser_states.borrow().iter() {
+            let Some(pane) = mux.get_pane(*pane_id) else {
+                continue;
+            };
+            let appearance =
+                crate::cef_browser::BrowserAppearance::new(&self.config, &pane.palette());
+            if browser.appearance() != appearance {
+                browser.set_appearance(appearance);
+            }
+        }
+    }
 }
 
 impl Drop for TermWindow {
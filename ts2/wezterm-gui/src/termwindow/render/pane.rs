@@ -14,6 +14,7 @@ use mux::pane::{PaneId, WithPaneLines};
 use mux::renderable::{RenderableDimensions, StableCursorPosition};
 use mux::tab::PositionedPane;
 use ordered_float::NotNan;
+use std::ops::Range;
 use std::time::Instant;
 use wezterm_dynamic::Value;
 use wezterm_term::color::{ColorAttribute, ColorPalette};
@@ -38,6 +39,11 @@ impl crate::TermWindow {
         #[cfg(all(target_os = "macos", feature = "cef"))]
         {
             let pane_id = pos.pane.pane_id();
+            if !self.has_browser_for_pane(pane_id) {
+                // Browsers restored from a session are created when
+                // their pane is first shown
+                self.restore_browser_for_pane(pane_id);
+            }
             if self.has_browser_for_pane(pane_id) {
                 // Browser overlay is active - render a placeholder background
                 // TODO: Actually render the CEF texture here
@@ -300,6 +306,13 @@ impl crate::TermWindow {
             .context("filled_rectangle")?;
         }
 
+        if config.show_command_status_markers && !pos.pane.is_alt_screen_active() {
+            let stable_top = current_viewport.unwrap_or(dims.physical_top);
+            let stable_range = stable_top..stable_top + dims.viewport_rows as StableRowIndex;
+            self.paint_command_markers(pos, &palette, stable_range, top_pixel_y, layers)
+                .context("paint_command_markers")?;
+        }
+
         let (selrange, rectangular) = {
             let sel = self.selection(pos.pane.pane_id());
             (sel.range.clone(), sel.rectangular)
@@ -593,6 +606,71 @@ impl crate::TermWindow {
         Ok(())
     }
 
+    /// Mark the commands that shell integration has recorded with a
+    /// bar to the left of their rows, colored by their exit status
+    fn paint_command_markers(
+        &mut self,
+        pos: &PositionedPane,
+        palette: &ColorPalette,
+        stable_range: Range<StableRowIndex>,
+        top_pixel_y: f32,
+        layers: &mut TripleLayerQuadAllocator,
+    ) -> anyhow::Result<()> {
+        let cursor_y = pos.pane.get_cursor_position().y;
+        let markers: Vec<(Range<StableRowIndex>, LinearRgba)> = self
+            .get_command_records(&pos.pane)
+            .iter()
+            .filter_map(|record| {
+                let end_y = record
+                    .output_rows(cursor_y)
+                    .end
+                    .max(record.prompt_y + 1)
+                    .min(stable_range.end);
+                let start_y = record.prompt_y.max(stable_range.start);
+                if start_y >= end_y {
+                    return None;
+                }
+                let color = match record.exit_code {
+                    Some(0) => palette.colors.0[2].to_linear(),
+                    Some(_) => palette.colors.0[1].to_linear(),
+                    None => palette.foreground.to_linear().mul_alpha(0.5),
+                };
+                Some((start_y..end_y, color))
+            })
+            .collect();
+        if markers.is_empty() {
+            return Ok(());
+        }
+
+        let (padding_left, _) = self.padding_left_top();
+        let border = self.get_os_border();
+        let cell_width = self.render_metrics.cell_size.width as f32;
+        let cell_height = self.render_metrics.cell_size.height as f32;
+        let left_pixel_x = padding_left + border.left.get() as f32 + (pos.left as f32 * cell_width);
+        let width = (cell_width / 4.).max(1.);
+        // The marker occupies the padding, or the gap between splits
+        if pos.left == 0 && padding_left < width {
+            return Ok(());
+        }
+
+        for (rows, color) in markers {
+            let y = top_pixel_y
+                + (pos.top as f32 + (rows.start - stable_range.start) as f32) * cell_height;
+            self.filled_rectangle(
+                layers,
+                2,
+                euclid::rect(
+                    left_pixel_x - width,
+                    y,
+                    width,
+                    (rows.end - rows.start) as f32 * cell_height,
+                ),
+                color,
+            )?;
+        }
+        Ok(())
+    }
+
     pub fn build_pane(&mut self, pos: &PositionedPane) -> anyhow::Result<ComputedElement> {
         // First compute the bounds for the pane background
 
@@ -705,7 +783,7 @@ impl crate::TermWindow {
     fn paint_browser_overlay(
         &mut self,
         pos: &PositionedPane,
-        _layers: &mut TripleLayerQuadAllocator,
+        layers: &mut TripleLayerQuadAllocator,
     ) -> anyhow::Result<()> {
         let (padding_left, padding_top) = self.padding_left_top();
 
@@ -765,10 +843,21 @@ impl crate::TermWindow {
             (pos.height as f32 * cell_height) + height_delta as f32
         };
 
-        // Update the browser's pane rectangle for the CEF overlay render pass
+        // Update the browser's pane rectangle for the CEF overlay render pass,
+        // leaving the bottom row for the status line if the browser has one
         let pane_id = pos.pane.pane_id();
+        let mut placeholder = None;
+        let mut status = None;
+        let mut height = height;
         if let Some(browser) = self.browser_states.borrow().get(&pane_id) {
+            if let Some(text) = browser.status_text() {
+                height = (height - cell_height).max(0.);
+                status = Some(text);
+            }
             browser.set_pane_rect(x, y, width, height);
+            if !browser.has_texture() {
+                placeholder = browser.appearance().background;
+            }
             log::trace!(
                 "[CEF] Updated pane rect for browser {}: x={}, y={}, w={}, h={}",
                 pane_id,
@@ -779,6 +868,74 @@ impl crate::TermWindow {
             );
         }
 
+        // Until CEF has painted, fill the pane with the scheme background
+        // rather than leaving it empty
+        if let Some(background) = placeholder {
+            self.filled_rectangle(
+                layers,
+                0,
+                euclid::rect(x, y, width, height),
+                background.to_linear(),
+            )
+            .context("filled_rectangle for browser placeholder")?;
+        }
+
+        if let Some(text) = status {
+            self.paint_browser_status(pos, euclid::rect(x, y + height, width, cell_height), text)
+                .context("paint_browser_status")?;
+        }
+
         Ok(())
     }
+
+    /// Draw the status line in the row below a browser
+    #[cfg(all(target_os = "macos", feature = "cef"))]
+    fn paint_browser_status(
+        &mut self,
+        pos: &PositionedPane,
+        rect: ::window::RectF,
+        text: String,
+    ) -> anyhow::Result<()> {
+        let palette = pos.pane.palette();
+        let font = self.fonts.default_font()?;
+        let gl_state = self.render_state.as_ref().unwrap();
+
+        let element = Element::new(&font, ElementContent::Text(text))
+            .colors(ElementColors {
+                border: BorderColor::default(),
+                bg: palette.foreground.to_linear().into(),
+                text: palette.background.to_linear().into(),
+            })
+            .padding(BoxDimension {
+                left: Dimension::Cells(0.5),
+                right: Dimension::Cells(0.5),
+                top: Dimension::Cells(0.),
+                bottom: Dimension::Cells(0.),
+            })
+            .min_width(Some(Dimension::Pixels(rect.width())))
+            .display(DisplayType::Block);
+
+        let mut computed = self.compute_element(
+            &LayoutContext {
+                height: DimensionContext {
+                    dpi: self.dimensions.dpi as f32,
+                    pixel_max: rect.height(),
+                    pixel_cell: self.render_metrics.cell_size.height as f32,
+                },
+                width: DimensionContext {
+                    dpi: self.dimensions.dpi as f32,
+                    pixel_max: rect.width(),
+                    pixel_cell: self.render_metrics.cell_size.width as f32,
+                },
+                bounds: euclid::rect(0., 0., rect.width(), rect.height()),
+                metrics: &self.render_metrics,
+                gl_state,
+                zindex: 1,
+            },
+            &element,
+        )?;
+        computed.translate(euclid::vec2(rect.min_x(), rect.min_y()));
+
+        self.render_element(&computed, gl_state, None)
+    }
 }
@@ -16,7 +16,7 @@ use termwiz::color::LinearRgba;
 use termwiz::surface::CursorShape;
 use wezterm_bidi::Direction;
 use wezterm_term::color::ColorAttribute;
-use wezterm_term::CellAttributes;
+use wezterm_term::{CellAttributes, Line, TextSizeHorizontalAlign, TextSizeVerticalAlign};
 
 impl crate::TermWindow {
     /// "Render" a line of the terminal screen into the vertex buffer.
@@ -458,6 +458,16 @@ impl crate::TermWindow {
                 };
             }
 
+            if cluster.attrs.scaled_text().is_some() {
+                // Scaled text spans several rows; it is rendered
+                // separately by render_scaled_text below
+                if direction == Direction::LeftToRight {
+                    cluster_x_pos += cluster.width as f32 * cell_width;
+                }
+                visual_cell_idx += cluster.width;
+                continue;
+            }
+
             for info in glyph_info.iter() {
                 let glyph = &info.glyph;
 
@@ -696,6 +706,9 @@ impl crate::TermWindow {
             }
         }
 
+        self.render_scaled_text(&params, layers, hsv, cell_width)
+            .context("render_scaled_text")?;
+
         for (cell_idx, img, glyph_color) in overlay_images {
             self.populate_image_quad(
                 &img,
@@ -717,6 +730,108 @@ impl crate::TermWindow {
         })
     }
 
+    /// Render the text of the blocks of scaled text, from the kitty
+    /// text sizing protocol, whose top row is this line.
+    /// The glyphs are stretched over all of the rows of the block, in
+    /// the same way that double height lines are rendered, so the
+    /// placeholder cells in the rows below have nothing to render.
+    fn render_scaled_text(
+        &self,
+        params: &RenderScreenLineParams,
+        layers: &mut TripleLayerQuadAllocator,
+        hsv: Option<HsbTransform>,
+        cell_width: f32,
+    ) -> anyhow::Result<()> {
+        let gl_state = self.render_state.as_ref().unwrap();
+        let cell_height = params.render_metrics.cell_size.height as f32;
+        let pos_y = (self.dimensions.pixel_height as f32 / -2.) + params.top_pixel_y;
+        let gl_x = self.dimensions.pixel_width as f32 / -2.;
+
+        for cell in params.line.visible_cells() {
+            let scaled = match cell.attrs().scaled_text() {
+                Some(scaled) if scaled.is_top_row() => scaled,
+                _ => continue,
+            };
+            if cell.attrs().invisible() {
+                continue;
+            }
+
+            let mut attrs = cell.attrs().clone();
+            attrs.set_scaled_text(None);
+            let style = self.fonts.match_style(params.config, &attrs);
+            let fg_color = if attrs.reverse() == !params.dims.reverse_video {
+                params.palette.resolve_bg(attrs.background()).to_linear()
+            } else {
+                resolve_fg_color_attr(
+                    &attrs,
+                    attrs.foreground(),
+                    &params.palette,
+                    &params.config,
+                    style,
+                )
+            };
+
+            let line = Line::from_text(cell.str(), &attrs, 0, None);
+            let mut glyphs = vec![];
+            for cluster in line.cluster(None) {
+                let glyph_info = self.cached_cluster_shape(
+                    style,
+                    &cluster,
+                    &gl_state,
+                    None,
+                    &params.render_metrics,
+                )?;
+                glyphs.extend(glyph_info.iter().map(|info| Rc::clone(&info.glyph)));
+            }
+
+            let font_scale = scaled.font_scale();
+            let text_width: f32 = glyphs
+                .iter()
+                .map(|glyph| glyph.x_advance.get() as f32 * font_scale)
+                .sum();
+            let text_height = cell_height * font_scale;
+            let block_width = cell.width() as f32 * cell_width;
+            let block_height = scaled.scale as f32 * cell_height;
+
+            let mut x = params.left_pixel_x
+                + cell.cell_index() as f32 * cell_width
+                + match scaled.horizontal_align {
+                    TextSizeHorizontalAlign::Left => 0.,
+                    TextSizeHorizontalAlign::Right => (block_width - text_width).max(0.),
+                    TextSizeHorizontalAlign::Center => ((block_width - text_width) / 2.).max(0.),
+                };
+            let y = match scaled.vertical_align {
+                TextSizeVerticalAlign::Top => 0.,
+                TextSizeVerticalAlign::Bottom => block_height - text_height,
+                TextSizeVerticalAlign::Center => (block_height - text_height) / 2.,
+            };
+
+            for glyph in &glyphs {
+                if let Some(texture) = glyph.texture.as_ref() {
+                    let left = x + (glyph.x_offset + glyph.bearing_x).get() as f32 * font_scale;
+                    let top = y
+                        + (cell_height + params.render_metrics.descender.get() as f32
+                            - (glyph.y_offset + glyph.bearing_y).get() as f32)
+                            * font_scale;
+
+                    let mut quad = layers.allocate(1).context("layers.allocate(1)")?;
+                    quad.set_position(
+                        gl_x + left,
+                        pos_y + top,
+                        gl_x + left + texture.coords.size.width as f32 * font_scale,
+                        pos_y + top + texture.coords.size.height as f32 * font_scale,
+                    );
+                    quad.set_fg_color(fg_color);
+                    quad.set_texture(texture.texture_coords());
+                    quad.set_hsv(hsv);
+                    quad.set_has_color(glyph.has_color);
+                }
+                x += glyph.x_advance.get() as f32 * font_scale;
+            }
+        }
+        Ok(())
+    }
+
     fn build_line_element_shape(
         &self,
         params: LineToElementParams,
@@ -1,5 +1,6 @@
 use crate::selection::{Selection, SelectionCoordinate, SelectionMode, SelectionRange, SelectionX};
 use ::window::WindowOps;
+use config::keyassignment::ClipboardCopyDestination;
 use mux::pane::{Pane, PaneId};
 use std::cell::RefMut;
 use std::sync::Arc;
@@ -64,49 +65,12 @@ impl super::TermWindow {
 
     /// Returns the selection text only
     pub fn selection_text(&self, pane: &Arc<dyn Pane>) -> String {
-        let mut s = String::new();
         let rectangular = self.selection(pane.pane_id()).rectangular;
-        if let Some(sel) = self
-            .selection(pane.pane_id())
-            .range
-            .as_ref()
-            .map(|r| r.normalize())
-        {
-            let mut last_was_wrapped = false;
-            let first_row = sel.rows().start;
-            let last_row = sel.rows().end;
-
-            for line in pane.get_logical_lines(sel.rows()) {
-                if !s.is_empty() && !last_was_wrapped {
-                    s.push('\n');
-                }
-                let last_idx = line.physical_lines.len().saturating_sub(1);
-                for (idx, phys) in line.physical_lines.iter().enumerate() {
-                    let this_row = line.first_row + idx as StableRowIndex;
-                    if this_row >= first_row && this_row < last_row {
-                        let last_phys_idx = phys.len().saturating_sub(1);
-                        let cols = sel.cols_for_row(this_row, rectangular);
-                        let last_col_idx = cols.end.saturating_sub(1).min(last_phys_idx);
-                        let col_span = phys.columns_as_str(cols);
-                        // Only trim trailing whitespace if we are the last line
-                        // in a wrapped sequence
-                        if idx == last_idx {
-                            s.push_str(col_span.trim_end());
-                        } else {
-                            s.push_str(&col_span);
-                        }
-
-                        last_was_wrapped = last_col_idx == last_phys_idx
-                            && phys
-                                .get_cell(last_col_idx)
-                                .map(|c| c.attrs().wrapped())
-                                .unwrap_or(false);
-                    }
-                }
-            }
+        let range = self.selection(pane.pane_id()).range;
+        match range {
+            Some(sel) => range_text(pane, sel.normalize(), rectangular),
+            None => String::new(),
         }
-
-        s
     }
 
     pub fn clear_selection(&mut self, pane: &Arc<dyn Pane>) {
@@ -116,6 +80,72 @@ impl super::TermWindow {
         self.window.as_ref().unwrap().invalidate();
     }
 
+    /// Select all of the scrollback and the viewport
+    pub fn select_all(&mut self, pane: &Arc<dyn Pane>) {
+        let dims = pane.get_dimensions();
+        let start = SelectionCoordinate::x_y(0, dims.scrollback_top);
+        let end = SelectionCoordinate::x_y(
+            usize::max_value(),
+            dims.scrollback_top + dims.scrollback_rows.saturating_sub(1) as StableRowIndex,
+        );
+
+        let mut selection = self.selection(pane.pane_id());
+        selection.origin = Some(start);
+        selection.range = Some(SelectionRange { start, end });
+        selection.rectangular = false;
+        selection.seqno = pane.get_current_seqno();
+        drop(selection);
+        self.window.as_ref().unwrap().invalidate();
+    }
+
+    /// Returns the output of the command under the mouse cursor, or
+    /// else of the most recently finished command
+    fn command_output_range(&mut self, pane: &Arc<dyn Pane>) -> Option<SelectionRange> {
+        let mouse_y = self
+            .pane_state(pane.pane_id())
+            .mouse_terminal_coords
+            .map(|coords| coords.1);
+        let cursor_y = pane.get_cursor_position().y;
+        let records = self.get_command_records(pane);
+        let record = mouse_y
+            .and_then(|y| {
+                records
+                    .iter()
+                    .rev()
+                    .find(|record| record.prompt_y <= y && y < record.output_rows(cursor_y).end)
+            })
+            .or_else(|| records.iter().rev().find(|record| record.is_finished()))?;
+
+        let rows = record.output_rows(cursor_y);
+        if rows.is_empty() {
+            return None;
+        }
+        Some(SelectionRange {
+            start: SelectionCoordinate::x_y(0, rows.start),
+            end: SelectionCoordinate::x_y(usize::max_value(), rows.end - 1),
+        })
+    }
+
+    pub fn select_command_output(&mut self, pane: &Arc<dyn Pane>) {
+        let Some(range) = self.command_output_range(pane) else {
+            return;
+        };
+        let mut selection = self.selection(pane.pane_id());
+        selection.origin = Some(range.start);
+        selection.range = Some(range);
+        selection.rectangular = false;
+        selection.seqno = pane.get_current_seqno();
+        drop(selection);
+        self.window.as_ref().unwrap().invalidate();
+    }
+
+    pub fn copy_command_output(&mut self, pane: &Arc<dyn Pane>, dest: ClipboardCopyDestination) {
+        if let Some(range) = self.command_output_range(pane) {
+            let text = range_text(pane, range, false);
+            self.copy_to_clipboard(dest, text);
+        }
+    }
+
     pub fn extend_selection_at_mouse_cursor(&mut self, mode: SelectionMode, pane: &Arc<dyn Pane>) {
         self.selection(pane.pane_id()).seqno = pane.get_current_seqno();
         let (position, y) = match self.pane_state(pane.pane_id()).mouse_terminal_coords {
@@ -281,3 +311,42 @@ impl super::TermWindow {
         self.window.as_ref().unwrap().invalidate();
     }
 }
+
+/// Returns the text in `sel`, joining wrapped lines
+fn range_text(pane: &Arc<dyn Pane>, sel: SelectionRange, rectangular: bool) -> String {
+    let mut s = String::new();
+    let mut last_was_wrapped = false;
+    let first_row = sel.rows().start;
+    let last_row = sel.rows().end;
+
+    for line in pane.get_logical_lines(sel.rows()) {
+        if !s.is_empty() && !last_was_wrapped {
+            s.push('\n');
+        }
+        let last_idx = line.physical_lines.len().saturating_sub(1);
+        for (idx, phys) in line.physical_lines.iter().enumerate() {
+            let this_row = line.first_row + idx as StableRowIndex;
+            if this_row >= first_row && this_row < last_row {
+                let last_phys_idx = phys.len().saturating_sub(1);
+                let cols = sel.cols_for_row(this_row, rectangular);
+                let last_col_idx = cols.end.saturating_sub(1).min(last_phys_idx);
+                let col_span = phys.columns_as_str(cols);
+                // Only trim trailing whitespace if we are the last line
+                // in a wrapped sequence
+                if idx == last_idx {
+                    s.push_str(col_span.trim_end());
+                } else {
+                    s.push_str(&col_span);
+                }
+
+                last_was_wrapped = last_col_idx == last_phys_idx
+                    && phys
+                        .get_cell(last_col_idx)
+                        .map(|c| c.attrs().wrapped())
+                        .unwrap_or(false);
+            }
+        }
+    }
+
+    s
+}
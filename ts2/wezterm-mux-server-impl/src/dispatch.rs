@@ -196,6 +196,27 @@ where
                     })
                 );
             }
+            Ok(Item::Notif(MuxNotification::AssignClipboardData {
+                pane_id,
+                selection,
+                data,
+            })) => {
+                // Only text can be sent to the client
+                if let Some(item) = data.iter().find(|item| item.is_text()) {
+                    send_notification!(
+                        &mut stream,
+                        Pdu::SetClipboard(codec::SetClipboard {
+                            pane_id,
+                            clipboard: Some(String::from_utf8_lossy(&item.data).into_owned()),
+                            selection,
+                        })
+                    );
+                }
+            }
+            // There is no way to ask the client for the clipboard; the
+            // headless server answers the request itself, and the gui
+            // answers for the panes in its windows
+            Ok(Item::Notif(MuxNotification::ReadClipboard { .. })) => {}
             Ok(Item::Notif(MuxNotification::TabAddedToWindow { tab_id, window_id })) => {
                 send_notification!(
                     &mut stream,
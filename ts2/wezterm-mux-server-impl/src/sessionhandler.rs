@@ -7,6 +7,7 @@ use mux::domain::SplitSource;
 use mux::pane::{CachePolicy, Pane, PaneId};
 use mux::renderable::{RenderableDimensions, StableCursorPosition};
 use mux::tab::TabId;
+use mux::web::RouteScope;
 use mux::{Mux, MuxNotification};
 use promise::spawn::spawn_into_main_thread;
 use std::collections::HashMap;
@@ -811,6 +812,25 @@ impl SessionHandler {
                 .detach();
             }
 
+            Pdu::GetCommandRecords(GetCommandRecords { pane_id }) => {
+                spawn_into_main_thread(async move {
+                    catch(
+                        move || {
+                            let mux = Mux::get();
+                            let pane = mux
+                                .get_pane(pane_id)
+                                .ok_or_else(|| anyhow!("no such pane {}", pane_id))?;
+                            let records = pane.get_command_records()?;
+                            Ok(Pdu::GetCommandRecordsResponse(GetCommandRecordsResponse {
+                                records,
+                            }))
+                        },
+                        send_response,
+                    )
+                })
+                .detach();
+            }
+
             Pdu::GetImageCell(GetImageCell {
                 pane_id,
                 line_idx,
@@ -987,7 +1007,11 @@ impl SessionHandler {
                 .detach();
             }
 
-            Pdu::WebOpen(WebOpen { pane_id, url }) => {
+            Pdu::WebOpen(WebOpen {
+                pane_id,
+                url,
+                profile,
+            }) => {
                 spawn_into_main_thread(async move {
                     catch(
                         move || {
@@ -1000,6 +1024,7 @@ impl SessionHandler {
                             mux.notify(MuxNotification::WebOpen {
                                 pane_id,
                                 url: url.clone(),
+                                profile,
                             });
 
                             let message = format!("Opening {}", url);
@@ -1011,6 +1036,103 @@ impl SessionHandler {
                 .detach();
             }
 
+            Pdu::WebNetworkLog(WebNetworkLog {
+                pane_id,
+                since,
+                capture_bodies,
+            }) => {
+                spawn_into_main_thread(async move {
+                    catch(
+                        move || {
+                            let mux = Mux::get();
+                            let log = mux.get_web_network_log(pane_id).ok_or_else(|| {
+                                anyhow!("pane_id {} does not have a browser", pane_id)
+                            })?;
+                            let mut log = log.lock();
+                            log.set_capture_bodies(capture_bodies);
+                            let (entries, next_seq) = log.entries_since(since);
+                            Ok(Pdu::WebNetworkLogResponse(WebNetworkLogResponse {
+                                entries,
+                                next_seq,
+                            }))
+                        },
+                        send_response,
+                    )
+                })
+                .detach();
+            }
+
+            Pdu::WebRoutes(WebRoutes { scope, rules }) => {
+                spawn_into_main_thread(async move {
+                    catch(
+                        move || {
+                            let mux = Mux::get();
+                            if let RouteScope::Pane(pane_id) = &scope {
+                                mux.get_pane(*pane_id)
+                                    .ok_or_else(|| anyhow!("pane_id {} invalid", pane_id))?;
+                            }
+                            let routes = mux.web_routes();
+                            if let Some(rules) = rules {
+                                routes.set(scope.clone(), rules);
+                            }
+                            Ok(Pdu::WebRoutesResponse(WebRoutesResponse {
+                                rules: routes.get(&scope),
+                            }))
+                        },
+                        send_response,
+                    )
+                })
+                .detach();
+            }
+
+            Pdu::WebContentBlocking(WebContentBlocking { pane_id, enable }) => {
+                spawn_into_main_thread(async move {
+                    catch(
+                        move || {
+                            let mux = Mux::get();
+                            let state = mux.get_web_content_blocking(pane_id).ok_or_else(|| {
+                                anyhow!("pane_id {} does not have content blocking", pane_id)
+                            })?;
+                            if let Some(enable) = enable {
+                                state.set_enabled(enable);
+                            }
+                            Ok(Pdu::WebContentBlockingResponse(
+                                WebContentBlockingResponse {
+                                    enabled: state.enabled(),
+                                    blocked: state.blocked(),
+                                    total_blocked: state.total_blocked(),
+                                },
+                            ))
+                        },
+                        send_response,
+                    )
+                })
+                .detach();
+            }
+
+            Pdu::WebSessionSave(WebSessionSave {}) => {
+                spawn_into_main_thread(async move {
+                    catch(
+                        move || {
+                            let session = mux::web_session::Session::capture(&Mux::get());
+                            Ok(Pdu::WebSessionSaveResponse(WebSessionSaveResponse {
+                                session,
+                            }))
+                        },
+                        send_response,
+                    )
+                })
+                .detach();
+            }
+
+            Pdu::WebSessionRestore(WebSessionRestore { session }) => {
+                let client_id = self.client_id.clone();
+                spawn_into_main_thread(async move {
+                    schedule_web_session_restore(session, send_response, client_id);
+                })
+                .detach();
+            }
+
             Pdu::Invalid { .. } => send_response(Err(anyhow!("invalid PDU {:?}", decoded.pdu))),
             Pdu::Pong { .. }
             | Pdu::ListPanesResponse { .. }
@@ -1035,7 +1157,13 @@ impl SessionHandler {
             | Pdu::TabAddedToWindow { .. }
             | Pdu::GetPaneRenderableDimensionsResponse { .. }
             | Pdu::ErrorResponse { .. }
-            | Pdu::WebOpenResponse { .. } => {
+            | Pdu::WebOpenResponse { .. }
+            | Pdu::WebNetworkLogResponse { .. }
+            | Pdu::WebRoutesResponse { .. }
+            | Pdu::WebContentBlockingResponse { .. }
+            | Pdu::WebSessionSaveResponse { .. }
+            | Pdu::WebSessionRestoreResponse { .. }
+            | Pdu::GetCommandRecordsResponse { .. } => {
                 send_response(Err(anyhow!("expected a request, got {:?}", decoded.pdu)))
             }
         }
@@ -1130,6 +1258,33 @@ fn schedule_move_pane<SND>(
         .detach();
 }
 
+fn schedule_web_session_restore<SND>(
+    session: mux::web_session::Session,
+    send_response: SND,
+    client_id: Option<Arc<ClientId>>,
+) where
+    SND: Fn(anyhow::Result<Pdu>) + 'static,
+{
+    promise::spawn::spawn(
+        async move { send_response(web_session_restore(session, client_id).await) },
+    )
+    .detach();
+}
+
+async fn web_session_restore(
+    session: mux::web_session::Session,
+    client_id: Option<Arc<ClientId>>,
+) -> anyhow::Result<Pdu> {
+    let mux = Mux::get();
+    let _identity = mux.with_identity(client_id);
+
+    let window_ids = session.restore().await?;
+
+    Ok::<Pdu, anyhow::Error>(Pdu::WebSessionRestoreResponse(WebSessionRestoreResponse {
+        window_ids,
+    }))
+}
+
 async fn move_pane(
     request: MovePaneToNewTab,
     client_id: Option<Arc<ClientId>>,
@@ -2,15 +2,17 @@ use clap::*;
 use config::configuration;
 use mux::activity::Activity;
 use mux::domain::{Domain, LocalDomain};
-use mux::Mux;
+use mux::{Mux, MuxNotification};
 use portable_pty::cmdbuilder::CommandBuilder;
 use std::ffi::OsString;
+use std::io::Write;
 use std::process::Command;
 use std::rc::Rc;
 use std::sync::Arc;
 use std::thread;
 use wezterm_gui_subcommands::*;
 use wezterm_mux_server_impl::update_mux_domains_for_server;
+use wezterm_term::ClipboardReadError;
 
 mod daemonize;
 
@@ -227,6 +229,7 @@ fn run() -> anyhow::Result<()> {
     let domain: Arc<dyn Domain> = Arc::new(LocalDomain::new("local")?);
     let mux = Arc::new(mux::Mux::new(Some(domain.clone())));
     Mux::set_mux(&mux);
+    answer_clipboard_reads(&mux);
 
     let executor = promise::spawn::SimpleExecutor::new();
 
@@ -300,6 +303,25 @@ async fn async_run(cmd: Option<CommandBuilder>) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// There is no clipboard that the server could read, and clients
+/// have no way to provide theirs, so tell applications that ask
+/// for it that reading the clipboard is not supported rather than
+/// leaving them waiting for a response
+fn answer_clipboard_reads(mux: &Mux) {
+    mux.subscribe(|n| {
+        if let MuxNotification::ReadClipboard { pane_id, request } = n {
+            promise::spawn::spawn_into_main_thread(async move {
+                if let Some(pane) = Mux::get().get_pane(pane_id) {
+                    let response = request.error_response(ClipboardReadError::NotSupported);
+                    pane.writer().write_all(response.as_bytes()).ok();
+                }
+            })
+            .detach();
+        }
+        true
+    });
+}
+
 fn terminate_with_error(err: anyhow::Error) -> ! {
     log::error!("{:#}; terminating", err);
     std::process::exit(1);
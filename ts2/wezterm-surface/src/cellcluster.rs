@@ -1,4 +1,4 @@
-use crate::line::CellRef;
+use crate::line::{is_image_placeholder, CellRef};
 use alloc::borrow::Cow;
 use wezterm_bidi::{BidiContext, Direction, ParagraphDirectionHint};
 use wezterm_cell::CellAttributes;
@@ -60,7 +60,12 @@ impl CellCluster {
         for c in iter {
             let cell_idx = c.cell_index();
             let presentation = c.presentation();
-            let cell_str = c.str();
+            // Image placeholders show their image rather than text
+            let cell_str = if is_image_placeholder(c.str()) {
+                " "
+            } else {
+                c.str()
+            };
             let normalized_attr = if c.attrs().wrapped() {
                 let mut attr_storage = c.attrs().clone();
                 attr_storage.set_wrapped(false);
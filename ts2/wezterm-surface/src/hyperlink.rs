@@ -20,6 +20,58 @@ use alloc::vec::Vec;
 
 pub use wezterm_escape_parser::hyperlink::Hyperlink;
 
+/// Where a hyperlink is opened when it is clicked
+#[cfg_attr(feature = "use_serde", derive(Deserialize, Serialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, FromDynamic, ToDynamic)]
+pub enum LinkTarget {
+    /// Hand the link to the system's default handler for the URL
+    #[default]
+    External,
+    /// Open a browser pane in a new split next to the pane
+    /// containing the link
+    BrowserSplit,
+    /// Open a browser pane in a new tab
+    BrowserTab,
+    /// Open the link in a browser pane next to the pane containing
+    /// the link, reusing the same browser pane for subsequent links
+    BrowserPreview,
+}
+
+impl LinkTarget {
+    /// The name of the hyperlink parameter that records the target
+    /// of links created by a Rule
+    pub const PARAM: &'static str = "link_target";
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::External => "External",
+            Self::BrowserSplit => "BrowserSplit",
+            Self::BrowserTab => "BrowserTab",
+            Self::BrowserPreview => "BrowserPreview",
+        }
+    }
+
+    pub fn from_name(s: &str) -> Option<Self> {
+        match s {
+            "External" => Some(Self::External),
+            "BrowserSplit" => Some(Self::BrowserSplit),
+            "BrowserTab" => Some(Self::BrowserTab),
+            "BrowserPreview" => Some(Self::BrowserPreview),
+            _ => None,
+        }
+    }
+
+    /// Returns the target recorded on a link created by a Rule
+    pub fn for_link(link: &Hyperlink) -> Option<Self> {
+        if !link.is_implicit() {
+            return None;
+        }
+        link.params()
+            .get(Self::PARAM)
+            .and_then(|target| Self::from_name(target))
+    }
+}
+
 /// In addition to handling explicit escape sequences to enable
 /// hyperlinks, we also support defining rules that match text
 /// from screen lines and generate implicit hyperlinks.  This
@@ -59,6 +111,12 @@ pub struct Rule {
     /// Which capture to highlight
     #[dynamic(default)]
     pub highlight: usize,
+
+    /// Where links created by this rule are opened.
+    /// If not set, the `hyperlink_open_in` configuration is used.
+    #[cfg_attr(feature = "use_serde", serde(default))]
+    #[dynamic(default)]
+    pub open_in: Option<LinkTarget>,
 }
 
 struct RegexWrap(Regex);
@@ -179,6 +237,7 @@ impl Rule {
             regex: Regex::new(regex)?,
             format: format.to_owned(),
             highlight,
+            open_in: None,
         })
     }
 
@@ -205,7 +264,13 @@ impl Rule {
             .into_iter()
             .map(|m| {
                 let url = m.expand();
-                let link = Arc::new(Hyperlink::new_implicit(url));
+                let link = Arc::new(match m.rule.open_in {
+                    Some(target) => Hyperlink::new_implicit_with_params(
+                        url,
+                        [(LinkTarget::PARAM.to_string(), target.as_str().to_string())],
+                    ),
+                    None => Hyperlink::new_implicit(url),
+                });
                 RuleMatch {
                     link,
                     range: m.range(),
@@ -250,6 +315,33 @@ mod test {
         );
     }
 
+    #[test]
+    fn rule_link_target() {
+        let mut rule = Rule::new(r"\bhttps://docs\.rs/\S+", "$0").unwrap();
+        rule.open_in = Some(LinkTarget::BrowserPreview);
+        let rules = vec![
+            rule,
+            Rule::new(r"\b\w+@[\w-]+(\.[\w-]+)+\b", "mailto:$0").unwrap(),
+        ];
+
+        let matches =
+            Rule::match_hyperlinks("see https://docs.rs/regex or foo@example.com", &rules);
+        assert_eq!(
+            LinkTarget::for_link(&matches[0].link),
+            Some(LinkTarget::BrowserPreview)
+        );
+        assert_eq!(LinkTarget::for_link(&matches[1].link), None);
+
+        // Explicit links can't choose where they are opened
+        let explicit = Hyperlink::new_with_params(
+            "https://example.com",
+            vec![(LinkTarget::PARAM.to_string(), "BrowserTab".to_string())]
+                .into_iter()
+                .collect(),
+        );
+        assert_eq!(LinkTarget::for_link(&explicit), None);
+    }
+
     #[test]
     fn parse_with_parentheses() {
         fn assert_helper(test_uri: &str, expected_uri: &str, msg: &str) {
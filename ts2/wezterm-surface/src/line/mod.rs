@@ -2,9 +2,13 @@ mod cellref;
 mod clusterline;
 mod line;
 mod linebits;
+mod placeholder;
 mod storage;
 mod test;
 mod vecstorage;
 
 pub use cellref::CellRef;
 pub use line::{DoubleClickRange, Line};
+#[cfg(feature = "use_image")]
+pub use placeholder::VirtualPlacement;
+pub use placeholder::{is_image_placeholder, ImagePlaceholder, IMAGE_PLACEHOLDER};
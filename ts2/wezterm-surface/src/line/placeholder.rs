@@ -0,0 +1,286 @@
+//! Unicode placeholders for kitty images.
+//!
+//! An application displays a virtual placement of a kitty image by
+//! printing the placeholder character U+10EEEE.  The image id is
+//! encoded in the foreground color of the placeholder and the
+//! placement id, if any, in its underline color.  Up to three
+//! combining diacritics select the row and the column of the image
+//! that the cell shows, and the most significant byte of the image id.
+//! Diacritics that are omitted are inferred from the cell to the left.
+//! <https://sw.kovidgoyal.net/kitty/graphics-protocol/#unicode-placeholders>
+
+#[cfg(feature = "use_image")]
+use crate::change::{ImageData, TextureCoordinate};
+use crate::line::Line;
+#[cfg(feature = "use_image")]
+use crate::SequenceNo;
+#[cfg(feature = "use_image")]
+use alloc::boxed::Box;
+#[cfg(feature = "use_image")]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use wezterm_cell::color::ColorAttribute;
+#[cfg(feature = "use_image")]
+use wezterm_cell::image::ImageCell;
+
+/// The character that is printed in place of an image
+pub const IMAGE_PLACEHOLDER: char = '\u{10EEEE}';
+
+/// The diacritics that encode numbers in placeholder cells.
+/// The value of each diacritic is its position in this string.
+const DIACRITICS: &str = "\
+    \u{0305}\u{030d}\u{030e}\u{0310}\u{0312}\u{033d}\u{033e}\u{033f}\u{0346}\u{034a}\u{034b}\
+    \u{034c}\u{0350}\u{0351}\u{0352}\u{0357}\u{035b}\u{0363}\u{0364}\u{0365}\u{0366}\u{0367}\
+    \u{0368}\u{0369}\u{036a}\u{036b}\u{036c}\u{036d}\u{036e}\u{036f}\u{0483}\u{0484}\u{0485}\
+    \u{0486}\u{0487}\u{0592}\u{0593}\u{0594}\u{0595}\u{0597}\u{0598}\u{0599}\u{059c}\u{059d}\
+    \u{059e}\u{059f}\u{05a0}\u{05a1}\u{05a8}\u{05a9}\u{05ab}\u{05ac}\u{05af}\u{05c4}\u{0610}\
+    \u{0611}\u{0612}\u{0613}\u{0614}\u{0615}\u{0616}\u{0617}\u{0657}\u{0658}\u{0659}\u{065a}\
+    \u{065b}\u{065d}\u{065e}\u{06d6}\u{06d7}\u{06d8}\u{06d9}\u{06da}\u{06db}\u{06dc}\u{06df}\
+    \u{06e0}\u{06e1}\u{06e2}\u{06e4}\u{06e7}\u{06e8}\u{06eb}\u{06ec}\u{0730}\u{0732}\u{0733}\
+    \u{0735}\u{0736}\u{073a}\u{073d}\u{073f}\u{0740}\u{0741}\u{0743}\u{0745}\u{0747}\u{0749}\
+    \u{074a}\u{07eb}\u{07ec}\u{07ed}\u{07ee}\u{07ef}\u{07f0}\u{07f1}\u{07f3}\u{0816}\u{0817}\
+    \u{0818}\u{0819}\u{081b}\u{081c}\u{081d}\u{081e}\u{081f}\u{0820}\u{0821}\u{0822}\u{0823}\
+    \u{0825}\u{0826}\u{0827}\u{0829}\u{082a}\u{082b}\u{082c}\u{082d}\u{0951}\u{0953}\u{0954}\
+    \u{0f82}\u{0f83}\u{0f86}\u{0f87}\u{135d}\u{135e}\u{135f}\u{17dd}\u{193a}\u{1a17}\u{1a75}\
+    \u{1a76}\u{1a77}\u{1a78}\u{1a79}\u{1a7a}\u{1a7b}\u{1a7c}\u{1b6b}\u{1b6d}\u{1b6e}\u{1b6f}\
+    \u{1b70}\u{1b71}\u{1b72}\u{1b73}\u{1cd0}\u{1cd1}\u{1cd2}\u{1cda}\u{1cdb}\u{1ce0}\u{1dc0}\
+    \u{1dc1}\u{1dc3}\u{1dc4}\u{1dc5}\u{1dc6}\u{1dc7}\u{1dc8}\u{1dc9}\u{1dcb}\u{1dcc}\u{1dd1}\
+    \u{1dd2}\u{1dd3}\u{1dd4}\u{1dd5}\u{1dd6}\u{1dd7}\u{1dd8}\u{1dd9}\u{1dda}\u{1ddb}\u{1ddc}\
+    \u{1ddd}\u{1dde}\u{1ddf}\u{1de0}\u{1de1}\u{1de2}\u{1de3}\u{1de4}\u{1de5}\u{1de6}\u{1dfe}\
+    \u{20d0}\u{20d1}\u{20d4}\u{20d5}\u{20d6}\u{20d7}\u{20db}\u{20dc}\u{20e1}\u{20e7}\u{20e9}\
+    \u{20f0}\u{2cef}\u{2cf0}\u{2cf1}\u{2de0}\u{2de1}\u{2de2}\u{2de3}\u{2de4}\u{2de5}\u{2de6}\
+    \u{2de7}\u{2de8}\u{2de9}\u{2dea}\u{2deb}\u{2dec}\u{2ded}\u{2dee}\u{2def}\u{2df0}\u{2df1}\
+    \u{2df2}\u{2df3}\u{2df4}\u{2df5}\u{2df6}\u{2df7}\u{2df8}\u{2df9}\u{2dfa}\u{2dfb}\u{2dfc}\
+    \u{2dfd}\u{2dfe}\u{2dff}\u{a66f}\u{a67c}\u{a67d}\u{a6f0}\u{a6f1}\u{a8e0}\u{a8e1}\u{a8e2}\
+    \u{a8e3}\u{a8e4}\u{a8e5}\u{a8e6}\u{a8e7}\u{a8e8}\u{a8e9}\u{a8ea}\u{a8eb}\u{a8ec}\u{a8ed}\
+    \u{a8ee}\u{a8ef}\u{a8f0}\u{a8f1}\u{aab0}\u{aab2}\u{aab3}\u{aab7}\u{aab8}\u{aabe}\u{aabf}\
+    \u{aac1}\u{fe20}\u{fe21}\u{fe22}\u{fe23}\u{fe24}\u{fe25}\u{fe26}\u{10a0f}\u{10a38}\
+    \u{1d185}\u{1d186}\u{1d187}\u{1d188}\u{1d189}\u{1d1aa}\u{1d1ab}\u{1d1ac}\u{1d1ad}\
+    \u{1d242}\u{1d243}\u{1d244}";
+
+/// Returns true if `text`, the text of a cell, is an image placeholder
+pub fn is_image_placeholder(text: &str) -> bool {
+    text.starts_with(IMAGE_PLACEHOLDER)
+}
+
+fn diacritic_value(c: char) -> Option<u32> {
+    DIACRITICS
+        .chars()
+        .position(|d| d == c)
+        .map(|idx| idx as u32)
+}
+
+/// Returns the number encoded in a color; 24 bits for a true color
+/// and 8 bits for a palette index
+fn color_value(color: ColorAttribute) -> Option<u32> {
+    fn channel(value: f32) -> u32 {
+        (value * 255.0 + 0.5) as u32
+    }
+
+    match color {
+        ColorAttribute::TrueColorWithPaletteFallback(rgb, _)
+        | ColorAttribute::TrueColorWithDefaultFallback(rgb) => {
+            Some((channel(rgb.0) << 16) | (channel(rgb.1) << 8) | channel(rgb.2))
+        }
+        ColorAttribute::PaletteIndex(idx) => Some(idx as u32),
+        ColorAttribute::Default => None,
+    }
+}
+
+/// A placeholder cell, decoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImagePlaceholder {
+    pub image_id: u32,
+    /// The placement to display; if None, any virtual placement
+    /// of the image may be used
+    pub placement_id: Option<u32>,
+    /// The row and column of the image, in cells, that is shown
+    pub row: u32,
+    pub column: u32,
+}
+
+impl Line {
+    /// Decode the image placeholders in this line, returning the cell
+    /// index of each along with the part of the image that it shows
+    pub fn image_placeholders(&self) -> Vec<(usize, ImagePlaceholder)> {
+        let mut placeholders = Vec::new();
+        // The cell to the left, along with the low bits and the most
+        // significant byte of its image id
+        let mut left: Option<(usize, ImagePlaceholder, u32, u32)> = None;
+
+        for cell in self.visible_cells() {
+            let mut chars = cell.str().chars();
+            if chars.next() != Some(IMAGE_PLACEHOLDER) {
+                left = None;
+                continue;
+            }
+            let attrs = cell.attrs();
+            let Some(id_low) = color_value(attrs.foreground()) else {
+                left = None;
+                continue;
+            };
+            let placement_id = color_value(attrs.underline_color()).filter(|&id| id != 0);
+            let row = chars.next().and_then(diacritic_value);
+            let column = chars.next().and_then(diacritic_value);
+            let msb = chars.next().and_then(diacritic_value);
+
+            // The cell to the left continues into this one if it shows
+            // the same image and placement in the same row
+            let left_cell = left.filter(|(idx, prior, prior_low, _)| {
+                idx + 1 == cell.cell_index()
+                    && *prior_low == id_low
+                    && prior.placement_id == placement_id
+                    && row.map_or(true, |row| row == prior.row)
+            });
+
+            let (row, column, msb) = match (row, column, left_cell) {
+                (Some(row), Some(column), Some((_, prior, _, prior_msb)))
+                    if msb.is_none() && prior.column + 1 == column =>
+                {
+                    (row, column, prior_msb)
+                }
+                (Some(row), Some(column), _) => (row, column, msb.unwrap_or(0)),
+                (row, None, Some((_, prior, _, prior_msb))) => {
+                    (row.unwrap_or(prior.row), prior.column + 1, prior_msb)
+                }
+                (row, _, _) => (row.unwrap_or(0), 0, 0),
+            };
+
+            let image_id = ((msb & 0xff) << 24) | id_low;
+            if image_id == 0 {
+                left = None;
+                continue;
+            }
+            let placeholder = ImagePlaceholder {
+                image_id,
+                placement_id,
+                row,
+                column,
+            };
+            placeholders.push((cell.cell_index(), placeholder));
+            left = Some((cell.cell_index(), placeholder, id_low, msb));
+        }
+
+        placeholders
+    }
+}
+
+/// A virtual placement of an image, which is displayed by placeholder
+/// cells rather than being attached to cells when it is placed
+#[cfg(feature = "use_image")]
+#[derive(Debug, Clone)]
+pub struct VirtualPlacement {
+    pub data: Arc<ImageData>,
+    /// Dimensions of the image data, in pixels
+    pub image_width: u32,
+    pub image_height: u32,
+    /// The area of the image that is displayed, in pixels: x, y,
+    /// width and height
+    pub source: (u32, u32, u32, u32),
+    /// The size of the placement in cells.  The image is scaled to
+    /// fit, preserving its aspect ratio, and centered.
+    pub columns: u32,
+    pub rows: u32,
+    pub z_index: i32,
+}
+
+#[cfg(feature = "use_image")]
+impl VirtualPlacement {
+    /// Returns the part of the image that is shown by the cell in
+    /// `placeholder.row` and `placeholder.column` of the placement,
+    /// or None if that cell is outside of the image
+    pub fn image_cell(
+        &self,
+        placeholder: &ImagePlaceholder,
+        cell_width: usize,
+        cell_height: usize,
+    ) -> Option<ImageCell> {
+        let (source_x, source_y, source_width, source_height) = self.source;
+        if placeholder.row >= self.rows
+            || placeholder.column >= self.columns
+            || source_width == 0
+            || source_height == 0
+            || self.image_width == 0
+            || self.image_height == 0
+        {
+            return None;
+        }
+
+        // Fit the source area into the placement, in pixels
+        let (cell_width, cell_height) = (cell_width as f32, cell_height as f32);
+        let box_width = self.columns as f32 * cell_width;
+        let box_height = self.rows as f32 * cell_height;
+        let scale = (box_width / source_width as f32).min(box_height / source_height as f32);
+        let draw_width = source_width as f32 * scale;
+        let draw_height = source_height as f32 * scale;
+        let origin_x = (box_width - draw_width) / 2.;
+        let origin_y = (box_height - draw_height) / 2.;
+
+        // Intersect the cell with the image
+        let cell_left = placeholder.column as f32 * cell_width;
+        let cell_top = placeholder.row as f32 * cell_height;
+        let left = cell_left.max(origin_x);
+        let top = cell_top.max(origin_y);
+        let right = (cell_left + cell_width).min(origin_x + draw_width);
+        let bottom = (cell_top + cell_height).min(origin_y + draw_height);
+        if right <= left || bottom <= top {
+            return None;
+        }
+
+        let padding = |value: f32| (value + 0.5) as u16;
+        let texture_x =
+            |x: f32| (source_x as f32 + (x - origin_x) / scale) / self.image_width as f32;
+        let texture_y =
+            |y: f32| (source_y as f32 + (y - origin_y) / scale) / self.image_height as f32;
+
+        Some(ImageCell::with_z_index(
+            TextureCoordinate::new_f32(texture_x(left), texture_y(top)),
+            TextureCoordinate::new_f32(texture_x(right), texture_y(bottom)),
+            Arc::clone(&self.data),
+            self.z_index,
+            padding(left - cell_left),
+            padding(top - cell_top),
+            padding(cell_left + cell_width - right),
+            padding(cell_top + cell_height - bottom),
+            Some(placeholder.image_id),
+            placeholder.placement_id,
+        ))
+    }
+}
+
+#[cfg(feature = "use_image")]
+impl Line {
+    /// Attach to each placeholder cell in this line the part of the
+    /// image that it shows, replacing the image that it showed before.
+    /// `lookup` returns the virtual placement for an image id and an
+    /// optional placement id.
+    pub fn resolve_image_placeholders<'a, F>(
+        &mut self,
+        cell_width: usize,
+        cell_height: usize,
+        lookup: F,
+        seqno: SequenceNo,
+    ) where
+        F: Fn(u32, Option<u32>) -> Option<&'a VirtualPlacement>,
+    {
+        let placeholders = self.image_placeholders();
+        if placeholders.is_empty() {
+            return;
+        }
+
+        let cells = self.cells_mut_for_attr_changes_only();
+        for (idx, placeholder) in placeholders {
+            let Some(cell) = cells.get_mut(idx) else {
+                continue;
+            };
+            let attrs = cell.attrs_mut();
+            attrs.detach_image_with_placement(placeholder.image_id, placeholder.placement_id);
+            if let Some(image) = lookup(placeholder.image_id, placeholder.placement_id)
+                .and_then(|placement| placement.image_cell(&placeholder, cell_width, cell_height))
+            {
+                attrs.attach_image(Box::new(image));
+            }
+        }
+        self.update_last_change_seqno(seqno);
+    }
+}
@@ -1,10 +1,12 @@
 #![cfg(all(not(target_os = "macos"), not(windows)))]
 //! See <https://developer.gnome.org/notification-spec/>
 
-use crate::ToastNotification;
+use crate::{ToastNotification, Urgency};
 use futures_util::stream::{abortable, StreamExt};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex};
 use zbus::proxy;
 use zvariant::{Type, Value};
 
@@ -87,6 +89,22 @@ impl Reason {
     }
 }
 
+/// The notifications that were shown with a tag, as (nid, serial).
+/// The serial tells a notification apart from the one that replaced it,
+/// which the server is likely to give the same nid.
+static TAGGED: LazyLock<Mutex<HashMap<String, (u32, u64)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+static SERIAL: AtomicU64 = AtomicU64::new(0);
+
+/// Returns true if the notification with `tag` has been replaced by
+/// another, or closed by close_notification
+fn is_superseded(tag: Option<&String>, serial: u64) -> bool {
+    match tag {
+        Some(tag) => TAGGED.lock().unwrap().get(tag).map(|&(_, s)| s) != Some(serial),
+        None => false,
+    }
+}
+
 async fn show_notif_impl(notif: ToastNotification) -> Result<(), Box<dyn std::error::Error>> {
     let connection = zbus::ConnectionBuilder::session()?.build().await?;
 
@@ -100,25 +118,54 @@ async fn show_notif_impl(notif: ToastNotification) -> Result<(), Box<dyn std::er
         return Ok(());
     }
 
+    let urgency = match notif.urgency {
+        Some(Urgency::Low) => 0,
+        Some(Urgency::Normal) => 1,
+        Some(Urgency::Critical) | None => 2,
+    };
     let mut hints = HashMap::new();
-    hints.insert("urgency", Value::U8(2 /* Critical */));
+    hints.insert("urgency", Value::U8(urgency));
+
+    // The "default" action is invoked by clicking the notification itself
+    let mut actions = vec![];
+    if notif.on_click.is_some() {
+        actions.extend_from_slice(&["default", "Show"]);
+    } else if notif.url.is_some() {
+        actions.extend_from_slice(&["show", "Show"]);
+    }
+
+    let icon = match &notif.icon {
+        Some(icon) => icon.to_string_lossy().into_owned(),
+        None => "org.wezfurlong.wezterm".to_string(),
+    };
+
+    let serial = SERIAL.fetch_add(1, Ordering::Relaxed);
+    let replaces_id = notif
+        .tag
+        .as_ref()
+        .and_then(|tag| TAGGED.lock().unwrap().get(tag).map(|&(nid, _)| nid))
+        .unwrap_or(0);
+
     let notification = proxy
         .notify(
             "wezterm",
-            0,
-            "org.wezfurlong.wezterm",
+            replaces_id,
+            &icon,
             &notif.title,
             &notif.message,
-            if notif.url.is_some() {
-                &["show", "Show"]
-            } else {
-                &[]
-            },
+            &actions,
             &hints,
             notif.timeout.map(|d| d.as_millis() as _).unwrap_or(0),
         )
         .await?;
 
+    if let Some(tag) = &notif.tag {
+        TAGGED
+            .lock()
+            .unwrap()
+            .insert(tag.clone(), (notification, serial));
+    }
+
     let (mut invoked_stream, abort_invoked) = abortable(proxy.receive_action_invoked().await?);
     let (mut closed_stream, abort_closed) = abortable(proxy.receive_notification_closed().await?);
 
@@ -127,6 +174,15 @@ async fn show_notif_impl(notif: ToastNotification) -> Result<(), Box<dyn std::er
             while let Some(signal) = invoked_stream.next().await {
                 let args = signal.args()?;
                 if args.nid == notification {
+                    if is_superseded(notif.tag.as_ref(), serial) {
+                        abort_closed.abort();
+                        break;
+                    }
+                    if let Some(on_click) = notif.on_click.as_ref() {
+                        on_click.call();
+                        abort_closed.abort();
+                        break;
+                    }
                     if let Some(url) = notif.url.as_ref() {
                         wezterm_open_url::open_url(url);
                         abort_closed.abort();
@@ -139,8 +195,18 @@ async fn show_notif_impl(notif: ToastNotification) -> Result<(), Box<dyn std::er
         async {
             while let Some(signal) = closed_stream.next().await {
                 let args = signal.args()?;
-                let _reason = Reason::new(args.reason);
+                let reason = Reason::new(args.reason);
                 if args.nid == notification {
+                    if !is_superseded(notif.tag.as_ref(), serial) {
+                        if let Some(tag) = &notif.tag {
+                            TAGGED.lock().unwrap().remove(tag);
+                        }
+                        if matches!(reason, Reason::Expired | Reason::Dismissed) {
+                            if let Some(on_close) = notif.on_close.as_ref() {
+                                on_close.call();
+                            }
+                        }
+                    }
                     abort_invoked.abort();
                     break;
                 }
@@ -163,3 +229,21 @@ pub fn show_notif(notif: ToastNotification) -> Result<(), Box<dyn std::error::Er
     });
     Ok(())
 }
+
+pub fn close_notif(tag: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let nid = match TAGGED.lock().unwrap().remove(tag) {
+        Some((nid, _)) => nid,
+        None => return Ok(()),
+    };
+    std::thread::spawn(move || {
+        let res = async_io::block_on(async move {
+            let connection = zbus::ConnectionBuilder::session()?.build().await?;
+            let proxy = NotificationsProxy::new(&connection).await?;
+            proxy.close_notification(nid).await
+        });
+        if let Err(err) = res {
+            log::error!("while closing notification: {:#}", err);
+        }
+    });
+    Ok(())
+}
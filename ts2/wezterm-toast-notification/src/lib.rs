@@ -1,3 +1,6 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
 mod dbus;
 mod macos;
 mod windows;
@@ -8,6 +11,47 @@ pub struct ToastNotification {
     pub message: String,
     pub url: Option<String>,
     pub timeout: Option<std::time::Duration>,
+    /// An image file to show alongside the message
+    pub icon: Option<PathBuf>,
+    /// Called when the notification is clicked, rather than opening `url`
+    pub on_click: Option<ClickHandler>,
+    /// Called when the notification expires or is dismissed by the user,
+    /// but not when it is closed by `close_notification`
+    pub on_close: Option<ClickHandler>,
+    /// Identifies the notification, so that it can be closed by
+    /// `close_notification`.  Showing another notification with the
+    /// same tag replaces it.
+    pub tag: Option<String>,
+    /// The default is `Urgency::Critical`
+    pub urgency: Option<Urgency>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Urgency {
+    Low,
+    Normal,
+    Critical,
+}
+
+/// A callback for when a notification is clicked.  It may be called
+/// from any thread.
+#[derive(Clone)]
+pub struct ClickHandler(Arc<dyn Fn() + Send + Sync>);
+
+impl ClickHandler {
+    pub fn new<F: Fn() + Send + Sync + 'static>(func: F) -> Self {
+        Self(Arc::new(func))
+    }
+
+    pub fn call(&self) {
+        (self.0)()
+    }
+}
+
+impl std::fmt::Debug for ClickHandler {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fmt.write_str("ClickHandler")
+    }
 }
 
 impl ToastNotification {
@@ -30,6 +74,11 @@ mod nop {
     pub fn show_notif(_: ToastNotification) -> Result<(), Box<dyn std::error::Error>> {
         Ok(())
     }
+
+    #[allow(dead_code)]
+    pub fn close_notif(_: &str) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
 }
 
 pub fn show(notif: ToastNotification) {
@@ -38,12 +87,25 @@ pub fn show(notif: ToastNotification) {
     }
 }
 
+/// Closes the notification that was shown with `tag`, if it is
+/// still showing
+pub fn close_notification(tag: &str) {
+    if let Err(err) = backend::close_notif(tag) {
+        log::error!("Failed to close notification: {}", err);
+    }
+}
+
 pub fn persistent_toast_notification_with_click_to_open_url(title: &str, message: &str, url: &str) {
     show(ToastNotification {
         title: title.to_string(),
         message: message.to_string(),
         url: Some(url.to_string()),
         timeout: None,
+        icon: None,
+        on_click: None,
+        on_close: None,
+        tag: None,
+        urgency: None,
     });
 }
 
@@ -53,6 +115,11 @@ pub fn persistent_toast_notification(title: &str, message: &str) {
         message: message.to_string(),
         url: None,
         timeout: None,
+        icon: None,
+        on_click: None,
+        on_close: None,
+        tag: None,
+        urgency: None,
     });
 }
 
@@ -1,17 +1,30 @@
 #![cfg(target_os = "macos")]
-use crate::ToastNotification;
+use crate::{ClickHandler, ToastNotification};
 use block2::{Block, RcBlock};
 use objc2::rc::Retained;
 use objc2::runtime::{Bool, NSObject, NSObjectProtocol, ProtocolObject};
 use objc2::{define_class, msg_send, AllocAnyThread};
-use objc2_foundation::{ns_string, NSArray, NSDictionary, NSError, NSSet, NSString};
+use objc2_foundation::{ns_string, NSArray, NSDictionary, NSError, NSSet, NSString, NSURL};
 use objc2_user_notifications::{
     UNAuthorizationOptions, UNMutableNotificationContent, UNNotification, UNNotificationAction,
-    UNNotificationActionOptions, UNNotificationCategory, UNNotificationCategoryOptions,
-    UNNotificationPresentationOptions, UNNotificationRequest, UNNotificationResponse,
-    UNUserNotificationCenter, UNUserNotificationCenterDelegate,
+    UNNotificationActionOptions, UNNotificationAttachment, UNNotificationCategory,
+    UNNotificationCategoryOptions, UNNotificationPresentationOptions, UNNotificationRequest,
+    UNNotificationResponse, UNUserNotificationCenter, UNUserNotificationCenterDelegate,
 };
-use std::sync::{LazyLock, Once};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex, Once};
+
+/// Click handlers for delivered notifications, keyed by request identifier
+static CLICK_HANDLERS: LazyLock<Mutex<HashMap<String, ClickHandler>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// The serial number of each notification that is still delivered,
+/// keyed by request identifier.  Notifications that are shown with the
+/// same identifier replace each other.
+static DELIVERED: LazyLock<Mutex<HashMap<String, u64>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+static SERIAL: AtomicU64 = AtomicU64::new(0);
 
 const NEEDS_SIGN: &str = "Note that the application must be code-signed \
                           for UNUserNotificationCenter to work";
@@ -60,12 +73,18 @@ define_class!(
             completion_handler: &Block<dyn Fn()>,
         ) {
             let action = response.actionIdentifier();
-            let user_info = response.notification().request().content().userInfo();
+            let request = response.notification().request();
+            let user_info = request.content().userInfo();
             let url = user_info.valueForKey(ns_string!("url"));
 
             log::debug!("did_receive_notification -> action={action:?} url={url:?}");
 
-            if let Some(url) = url {
+            let identifier = request.identifier().to_string();
+            DELIVERED.lock().unwrap().remove(&identifier);
+            let on_click = CLICK_HANDLERS.lock().unwrap().remove(&identifier);
+            if let Some(on_click) = on_click {
+                on_click.call();
+            } else if let Some(url) = url {
                 if let Ok(url_str) = url.downcast::<NSString>() {
                     wezterm_open_url::open_url(&url_str.to_string());
                 }
@@ -164,7 +183,46 @@ pub fn show_notif(toast: ToastNotification) -> Result<(), Box<dyn std::error::Er
             notif.setCategoryIdentifier(ns_string!("SHOW_URL_ACTION"));
         }
 
-        let identifier = uuid::Uuid::new_v4().to_string();
+        // A notification request replaces any with the same identifier
+        let identifier = toast
+            .tag
+            .clone()
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+        if let Some(icon) = &toast.icon {
+            // The file is moved into the notification's data store
+            let url = NSURL::fileURLWithPath(&NSString::from_str(&icon.to_string_lossy()));
+            match UNNotificationAttachment::attachmentWithIdentifier_URL_options_error(
+                &NSString::from_str(&identifier),
+                &url,
+                None,
+            ) {
+                Ok(attachment) => {
+                    notif.setAttachments(&NSArray::from_retained_slice(&[attachment]))
+                }
+                Err(err) => log::error!(
+                    "notification icon {}: {}",
+                    icon.display(),
+                    ns_error_to_string(Retained::as_ptr(&err) as *mut NSError)
+                ),
+            }
+        }
+
+        let serial = SERIAL.fetch_add(1, Ordering::Relaxed);
+        DELIVERED.lock().unwrap().insert(identifier.clone(), serial);
+
+        match &toast.on_click {
+            Some(on_click) => {
+                CLICK_HANDLERS
+                    .lock()
+                    .unwrap()
+                    .insert(identifier.clone(), on_click.clone());
+            }
+            None => {
+                CLICK_HANDLERS.lock().unwrap().remove(&identifier);
+            }
+        }
+
         let request = UNNotificationRequest::requestWithIdentifier_content_trigger(
             &NSString::from_str(&identifier),
             &*notif,
@@ -182,12 +240,26 @@ pub fn show_notif(toast: ToastNotification) -> Result<(), Box<dyn std::error::Er
                         // thread.  We also don't have access to the executor machinery
                         // from the window crate here, so we just do this basic take.
                         let identifier = identifier.clone();
+                        let on_close = toast.on_close.clone();
                         std::thread::spawn(move || {
                             std::thread::sleep(timeout);
+                            // Leave it alone if it has been clicked, closed
+                            // or replaced in the meantime
+                            {
+                                let mut delivered = DELIVERED.lock().unwrap();
+                                if delivered.get(&identifier) != Some(&serial) {
+                                    return;
+                                }
+                                delivered.remove(&identifier);
+                            }
+                            CLICK_HANDLERS.lock().unwrap().remove(&identifier);
                             // Remove this notification
                             let ident_array =
                                 NSArray::from_retained_slice(&[NSString::from_str(&identifier)]);
                             CENTER.removeDeliveredNotificationsWithIdentifiers(&ident_array);
+                            if let Some(on_close) = on_close {
+                                on_close.call();
+                            }
                         });
                     }
                 } else {
@@ -199,3 +271,14 @@ pub fn show_notif(toast: ToastNotification) -> Result<(), Box<dyn std::error::Er
 
     Ok(())
 }
+
+pub fn close_notif(tag: &str) -> Result<(), Box<dyn std::error::Error>> {
+    DELIVERED.lock().unwrap().remove(tag);
+    CLICK_HANDLERS.lock().unwrap().remove(tag);
+    let ident_array = NSArray::from_retained_slice(&[NSString::from_str(tag)]);
+    unsafe {
+        CENTER.removePendingNotificationRequestsWithIdentifiers(&ident_array);
+        CENTER.removeDeliveredNotificationsWithIdentifiers(&ident_array);
+    }
+    Ok(())
+}
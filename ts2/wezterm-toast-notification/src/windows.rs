@@ -1,16 +1,21 @@
 #![cfg(windows)]
 
 use crate::ToastNotification as TN;
-use xml::escape::escape_str_pcdata;
+use xml::escape::{escape_str_attribute, escape_str_pcdata};
 
 use windows::core::{Error as WinError, IInspectable, Interface, HSTRING};
 use windows::Data::Xml::Dom::XmlDocument;
 use windows::Foundation::TypedEventHandler;
 use windows::Win32::Foundation::E_POINTER;
 use windows::UI::Notifications::{
-    ToastActivatedEventArgs, ToastNotification, ToastNotificationManager,
+    ToastActivatedEventArgs, ToastDismissalReason, ToastDismissedEventArgs, ToastNotification,
+    ToastNotificationManager,
 };
 
+const APP_ID: &str = "org.wezfurlong.wezterm";
+/// Tagged notifications are put in this group, so that they can be closed
+const GROUP: &str = "wezterm";
+
 fn unwrap_arg<T>(a: &Option<T>) -> Result<&T, WinError> {
     match a {
         Some(t) => Ok(t),
@@ -31,23 +36,61 @@ fn show_notif_impl(toast: TN) -> Result<(), Box<dyn std::error::Error>> {
         ""
     };
 
+    let icon = match &toast.icon {
+        Some(icon) => format!(
+            r#"<image placement="appLogoOverride" src="{}" />"#,
+            escape_str_attribute(&icon.to_string_lossy())
+        ),
+        None => String::new(),
+    };
+
+    // Clicking the body of the toast activates it with the launch argument
+    let launch = if toast.on_click.is_some() {
+        r#" launch="click""#
+    } else {
+        ""
+    };
+
     xml.LoadXml(HSTRING::from(format!(
-        r#"<toast duration="long">
+        r#"<toast duration="long"{}>
         <visual>
             <binding template="ToastGeneric">
                 <text>{}</text>
                 <text>{}</text>
+                {}
             </binding>
         </visual>
         {}
     </toast>"#,
+        launch,
         escape_str_pcdata(&toast.title),
         escape_str_pcdata(&toast.message),
+        icon,
         url_actions
     )))?;
 
     let notif = ToastNotification::CreateToastNotification(xml)?;
 
+    // A notification replaces any in the same group with the same tag
+    if let Some(tag) = &toast.tag {
+        notif.SetTag(&HSTRING::from(tag.as_str()))?;
+        notif.SetGroup(&HSTRING::from(GROUP))?;
+    }
+
+    if let Some(on_close) = toast.on_close.clone() {
+        notif.Dismissed(TypedEventHandler::new(
+            move |_: &Option<ToastNotification>, result: &Option<ToastDismissedEventArgs>| {
+                let reason = unwrap_arg(result)?.Reason()?;
+                if reason == ToastDismissalReason::UserCanceled
+                    || reason == ToastDismissalReason::TimedOut
+                {
+                    on_close.call();
+                }
+                Ok(())
+            },
+        ))?;
+    }
+
     notif.Activated(TypedEventHandler::new(
         move |_: &Option<ToastNotification>, result: &Option<IInspectable>| {
             // let myself = unwrap_arg(myself)?;
@@ -55,7 +98,11 @@ fn show_notif_impl(toast: TN) -> Result<(), Box<dyn std::error::Error>> {
 
             let args = result.Arguments()?;
 
-            if args == "show" {
+            if args == "click" {
+                if let Some(on_click) = toast.on_click.as_ref() {
+                    on_click.call();
+                }
+            } else if args == "show" {
                 if let Some(url) = toast.url.as_ref() {
                     wezterm_open_url::open_url(url);
                 }
@@ -77,9 +124,7 @@ fn show_notif_impl(toast: TN) -> Result<(), Box<dyn std::error::Error>> {
     }))?;
     */
 
-    let notifier = ToastNotificationManager::CreateToastNotifierWithId(HSTRING::from(
-        "org.wezfurlong.wezterm",
-    ))?;
+    let notifier = ToastNotificationManager::CreateToastNotifierWithId(HSTRING::from(APP_ID))?;
 
     notifier.Show(&notif)?;
 
@@ -98,3 +143,13 @@ pub fn show_notif(notif: TN) -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+pub fn close_notif(tag: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let history = ToastNotificationManager::History()?;
+    history.RemoveGroupedTagWithId(
+        &HSTRING::from(tag),
+        &HSTRING::from(GROUP),
+        &HSTRING::from(APP_ID),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use anyhow::anyhow;
+use clap::Parser;
+use mux::pane::PaneId;
+use termwiz_funcs::lines_to_escapes;
+use wezterm_client::client::Client;
+
+#[derive(Debug, Parser, Clone)]
+pub struct GetCommandOutput {
+    /// Specify the target pane.
+    /// The default is to use the current pane based on the
+    /// environment variable WEZTERM_PANE.
+    #[arg(long)]
+    pane_id: Option<PaneId>,
+
+    /// Output the most recently finished command.
+    /// This is the default.
+    #[arg(long, conflicts_with = "index")]
+    last: bool,
+
+    /// Output the finished command with this index.
+    /// 0 is the oldest command that is still recorded.
+    /// Negative numbers count backwards from the most recent
+    /// command, which is -1.
+    #[arg(long, allow_hyphen_values = true)]
+    index: Option<isize>,
+
+    /// Print the command line before its output
+    #[arg(long)]
+    with_command: bool,
+
+    /// Include escape sequences that color and style the text.
+    /// If omitted, unattributed text will be returned.
+    #[arg(long)]
+    escapes: bool,
+}
+
+impl GetCommandOutput {
+    pub async fn run(self, client: Client) -> anyhow::Result<()> {
+        let pane_id = client.resolve_pane_id(self.pane_id).await?;
+
+        // A command that is still running may well be this one, so
+        // only finished commands are considered
+        let records: Vec<_> = client
+            .get_command_records(codec::GetCommandRecords { pane_id })
+            .await?
+            .records
+            .into_iter()
+            .filter(|record| record.is_finished())
+            .collect();
+        if records.is_empty() {
+            anyhow::bail!(
+                "no commands have been recorded in pane {}. \
+                 Shell integration must be enabled for commands to be recorded",
+                pane_id
+            );
+        }
+
+        let index = match (self.last, self.index) {
+            (true, _) | (false, None) => records.len() - 1,
+            (false, Some(n)) if n >= 0 => n as usize,
+            (false, Some(n)) => records
+                .len()
+                .checked_sub(n.unsigned_abs())
+                .ok_or_else(|| anyhow!("there are only {} commands", records.len()))?,
+        };
+        let record = records
+            .get(index)
+            .ok_or_else(|| anyhow!("there are only {} commands", records.len()))?;
+
+        if self.with_command {
+            println!("{}", record.command);
+        }
+
+        let info = client
+            .get_dimensions(codec::GetPaneRenderableDimensions { pane_id })
+            .await?;
+        let rows = record.output_rows(info.dimensions.physical_top);
+        let start_line = rows.start.max(info.dimensions.scrollback_top);
+        if start_line >= rows.end {
+            return Ok(());
+        }
+
+        let lines = client
+            .get_lines(codec::GetLines {
+                pane_id,
+                lines: vec![start_line..rows.end],
+            })
+            .await?;
+
+        let lines = lines
+            .lines
+            .extract_data()
+            .0
+            .into_iter()
+            .map(|(_idx, line)| line)
+            .collect();
+
+        if self.escapes {
+            println!("{}", lines_to_escapes(lines)?);
+        } else {
+            lines.iter().for_each(|line| println!("{}", line.as_str()));
+        }
+        Ok(())
+    }
+}
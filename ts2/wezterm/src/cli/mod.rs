@@ -7,6 +7,7 @@ mod activate_pane;
 mod activate_pane_direction;
 mod activate_tab;
 mod adjust_pane_size;
+mod get_command_output;
 mod get_pane_direction;
 mod get_text;
 mod kill_pane;
@@ -21,7 +22,11 @@ mod set_window_title;
 mod spawn_command;
 mod split_pane;
 mod tls_creds;
+mod web_block;
+mod web_network;
 mod web_open;
+mod web_route;
+mod web_session;
 mod zoom_pane;
 
 #[derive(Debug, Parser, Clone, Copy)]
@@ -122,6 +127,11 @@ Outputs the pane-id for the newly created pane on success"
     #[command(name = "get-text", rename_all = "kebab")]
     GetText(get_text::GetText),
 
+    /// Retrieves the output of a command that was run in a pane
+    /// and output it to stdout.  This requires shell integration.
+    #[command(name = "get-command-output", rename_all = "kebab")]
+    GetCommandOutput(get_command_output::GetCommandOutput),
+
     /// Activate an adjacent pane in the specified direction.
     #[command(name = "activate-pane-direction", rename_all = "kebab")]
     ActivatePaneDirection(activate_pane_direction::ActivatePaneDirection),
@@ -168,6 +178,26 @@ Outputs the pane-id for the newly created pane on success"
     /// Open a URL in a web browser pane
     #[command(name = "web-open", rename_all = "kebab")]
     WebOpen(web_open::WebOpen),
+
+    /// Print the network requests made by a web browser pane as
+    /// JSON lines, optionally recording them as an HTTP Archive
+    #[command(name = "web-network", rename_all = "kebab")]
+    WebNetwork(web_network::WebNetwork),
+
+    /// Set or show the rules used to block, redirect, stub or
+    /// add headers to the requests made by web browser panes
+    #[command(name = "web-route", rename_all = "kebab")]
+    WebRoute(web_route::WebRoute),
+
+    /// Enable, disable or show the state of filter list based
+    /// content blocking for a web browser pane
+    #[command(name = "web-block", rename_all = "kebab")]
+    WebBlock(web_block::WebBlock),
+
+    /// Save the windows, tabs and splits, including the state of
+    /// web browser panes, to a session file, or restore them from one
+    #[command(name = "web-session", rename_all = "kebab")]
+    WebSession(web_session::WebSession),
 }
 
 async fn run_cli_async(opts: &crate::Opt, cli: CliCommand) -> anyhow::Result<()> {
@@ -191,6 +221,7 @@ async fn run_cli_async(opts: &crate::Opt, cli: CliCommand) -> anyhow::Result<()>
         CliSubCommand::SplitPane(cmd) => cmd.run(client).await,
         CliSubCommand::SendText(cmd) => cmd.run(client).await,
         CliSubCommand::GetText(cmd) => cmd.run(client).await,
+        CliSubCommand::GetCommandOutput(cmd) => cmd.run(client).await,
         CliSubCommand::SpawnCommand(cmd) => cmd.run(client, &crate::init_config(opts)?).await,
         CliSubCommand::Proxy(cmd) => cmd.run(client, &crate::init_config(opts)?).await,
         CliSubCommand::TlsCreds(cmd) => cmd.run(client).await,
@@ -205,6 +236,10 @@ async fn run_cli_async(opts: &crate::Opt, cli: CliCommand) -> anyhow::Result<()>
         CliSubCommand::RenameWorkspace(cmd) => cmd.run(client).await,
         CliSubCommand::ZoomPane(cmd) => cmd.run(client).await,
         CliSubCommand::WebOpen(cmd) => cmd.run(client).await,
+        CliSubCommand::WebNetwork(cmd) => cmd.run(client).await,
+        CliSubCommand::WebRoute(cmd) => cmd.run(client).await,
+        CliSubCommand::WebBlock(cmd) => cmd.run(client).await,
+        CliSubCommand::WebSession(cmd) => cmd.run(client).await,
     }
 }
 
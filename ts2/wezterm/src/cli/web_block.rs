@@ -0,0 +1,60 @@
+use clap::Parser;
+use mux::pane::PaneId;
+use serde_json::json;
+use wezterm_client::client::Client;
+
+#[derive(Debug, Parser, Clone)]
+pub struct WebBlock {
+    /// Specify the target pane.
+    /// The default is to use the current pane based on the
+    /// environment variable WEZTERM_PANE.
+    #[arg(long)]
+    pane_id: Option<PaneId>,
+
+    /// Enable content blocking for the pane
+    #[arg(long, conflicts_with_all=&["off", "toggle"])]
+    on: bool,
+
+    /// Disable content blocking for the pane
+    #[arg(long, conflicts_with_all=&["on", "toggle"])]
+    off: bool,
+
+    /// Toggle content blocking for the pane
+    #[arg(long, conflicts_with_all=&["on", "off"])]
+    toggle: bool,
+}
+
+impl WebBlock {
+    pub async fn run(&self, client: Client) -> anyhow::Result<()> {
+        let pane_id = client.resolve_pane_id(self.pane_id).await?;
+
+        let enable = if self.toggle {
+            let current = client
+                .web_content_blocking(codec::WebContentBlocking {
+                    pane_id,
+                    enable: None,
+                })
+                .await?;
+            Some(!current.enabled)
+        } else if self.on {
+            Some(true)
+        } else if self.off {
+            Some(false)
+        } else {
+            None
+        };
+
+        let response = client
+            .web_content_blocking(codec::WebContentBlocking { pane_id, enable })
+            .await?;
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&json!({
+                "enabled": response.enabled,
+                "blocked": response.blocked,
+                "total_blocked": response.total_blocked,
+            }))?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+use anyhow::Context;
+use chrono::TimeZone;
+use clap::Parser;
+use mux::pane::PaneId;
+use mux::web::{NetworkBody, NetworkEntry};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use wezterm_client::client::Client;
+
+#[derive(Debug, Parser, Clone)]
+pub struct WebNetwork {
+    /// Specify the target pane.
+    /// The default is to use the current pane based on the
+    /// environment variable WEZTERM_PANE.
+    #[arg(long)]
+    pane_id: Option<PaneId>,
+
+    /// Record the session as an HTTP Archive (HAR) at the specified
+    /// path.  The file is rewritten as each request completes.
+    #[arg(long)]
+    har: Option<PathBuf>,
+
+    /// Capture request and response bodies and include them
+    /// in the HAR
+    #[arg(long)]
+    bodies: bool,
+
+    /// Print the requests recorded so far and exit, rather than
+    /// continuing to print requests as they complete
+    #[arg(long)]
+    no_follow: bool,
+}
+
+impl WebNetwork {
+    pub async fn run(&self, client: Client) -> anyhow::Result<()> {
+        let pane_id = client.resolve_pane_id(self.pane_id).await?;
+        let mut since = 0;
+        let mut har_entries = vec![];
+        let mut first = true;
+
+        loop {
+            let response = client
+                .web_network_log(codec::WebNetworkLog {
+                    pane_id,
+                    since,
+                    capture_bodies: self.bodies,
+                })
+                .await?;
+            since = response.next_seq;
+
+            for entry in &response.entries {
+                println!("{}", serde_json::to_string(&summary(entry))?);
+            }
+
+            if let Some(path) = &self.har {
+                if first || !response.entries.is_empty() {
+                    har_entries.extend(response.entries.iter().map(har_entry));
+                    write_har(path, &har_entries)?;
+                }
+            }
+            first = false;
+
+            if self.no_follow {
+                return Ok(());
+            }
+            smol::Timer::after(Duration::from_millis(500)).await;
+        }
+    }
+}
+
+/// The one-line summary printed for each request
+fn summary(entry: &NetworkEntry) -> Value {
+    json!({
+        "method": entry.method,
+        "url": entry.url,
+        "status": entry.status,
+        "type": entry.resource_type,
+        "mime_type": entry.mime_type,
+        "time_ms": entry.duration_ms,
+        "size": entry.size,
+        "error": entry.error,
+    })
+}
+
+fn write_har(path: &Path, entries: &[Value]) -> anyhow::Result<()> {
+    let har = json!({
+        "log": {
+            "version": "1.2",
+            "creator": {
+                "name": "wezterm",
+                "version": config::wezterm_version(),
+            },
+            "pages": [],
+            "entries": entries,
+        }
+    });
+    let data = serde_json::to_vec_pretty(&har)?;
+    std::fs::write(path, data).with_context(|| format!("writing {}", path.display()))
+}
+
+fn har_headers(headers: &[(String, String)]) -> Value {
+    headers
+        .iter()
+        .map(|(name, value)| json!({"name": name, "value": value}))
+        .collect()
+}
+
+fn header_value<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_str())
+}
+
+fn har_body(body: &NetworkBody, mime_type: &str) -> Value {
+    let mut value = json!({
+        "mimeType": mime_type,
+        "text": body.text,
+    });
+    if body.base64 {
+        value["encoding"] = "base64".into();
+    }
+    if body.truncated {
+        value["comment"] = "truncated".into();
+    }
+    value
+}
+
+/// Convert an entry into the HAR 1.2 entry format.
+/// <http://www.softwareishard.com/blog/har-12-spec/#entries>
+fn har_entry(entry: &NetworkEntry) -> Value {
+    let started = chrono::Utc
+        .timestamp_millis_opt(entry.started_ms as i64)
+        .single()
+        .unwrap_or_default()
+        .to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+
+    let query_string: Vec<Value> = url::Url::parse(&entry.url)
+        .map(|url| {
+            url.query_pairs()
+                .map(|(name, value)| json!({"name": name, "value": value}))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut request = json!({
+        "method": entry.method,
+        "url": entry.url,
+        "httpVersion": "",
+        "cookies": [],
+        "headers": har_headers(&entry.request_headers),
+        "queryString": query_string,
+        "headersSize": -1,
+        "bodySize": -1,
+    });
+    if let Some(body) = &entry.request_body {
+        let mime_type = header_value(&entry.request_headers, "content-type").unwrap_or_default();
+        request["postData"] = har_body(body, mime_type);
+    }
+
+    let mut content = json!({
+        "size": entry.size,
+        "mimeType": entry.mime_type,
+    });
+    if let Some(body) = &entry.response_body {
+        content = har_body(body, &entry.mime_type);
+        content["size"] = entry.size.into();
+    }
+
+    let mut har = json!({
+        "startedDateTime": started,
+        "time": entry.duration_ms,
+        "request": request,
+        "response": {
+            "status": entry.status,
+            "statusText": entry.status_text,
+            "httpVersion": "",
+            "cookies": [],
+            "headers": har_headers(&entry.response_headers),
+            "content": content,
+            "redirectURL": header_value(&entry.response_headers, "location").unwrap_or_default(),
+            "headersSize": -1,
+            "bodySize": entry.size,
+        },
+        "cache": {},
+        "timings": {
+            "send": 0,
+            "wait": entry.duration_ms,
+            "receive": 0,
+        },
+        "_resourceType": entry.resource_type,
+    });
+    if let Some(error) = &entry.error {
+        har["_error"] = error.as_str().into();
+    }
+    har
+}
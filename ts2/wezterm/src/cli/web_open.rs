@@ -3,6 +3,12 @@ use wezterm_client::client::Client;
 
 #[derive(Debug, Parser, Clone)]
 pub struct WebOpen {
+    /// The browser profile to use.
+    /// Request routing rules set with `wezterm cli web-route --profile`
+    /// apply to every browser pane opened with the same profile.
+    #[arg(long, default_value = mux::web::DEFAULT_PROFILE)]
+    profile: String,
+
     /// The URL to open
     url: String,
 }
@@ -14,6 +20,7 @@ impl WebOpen {
             .web_open(codec::WebOpen {
                 pane_id,
                 url: self.url.clone(),
+                profile: self.profile.clone(),
             })
             .await?;
         println!("{}", response.message);
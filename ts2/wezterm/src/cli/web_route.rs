@@ -0,0 +1,166 @@
+use anyhow::{anyhow, Context};
+use clap::Parser;
+use mux::pane::PaneId;
+use mux::web::{RouteAction, RouteResponse, RouteRule, RouteScope};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use wezterm_client::client::Client;
+
+#[derive(Debug, Parser, Clone)]
+pub struct WebRoute {
+    /// Specify the target pane.
+    /// The default is to use the current pane based on the
+    /// environment variable WEZTERM_PANE.
+    #[arg(long)]
+    pane_id: Option<PaneId>,
+
+    /// Apply the rules to every browser pane opened with this
+    /// profile, rather than to a single pane
+    #[arg(long, conflicts_with = "pane_id")]
+    profile: Option<String>,
+
+    /// Remove all of the rules
+    #[arg(long, conflicts_with = "rules")]
+    clear: bool,
+
+    /// A JSON file holding an array of rules that replaces the
+    /// current rules.  If omitted, the current rules are printed.
+    ///
+    /// Each rule has a `url` pattern, in which `*` matches any
+    /// sequence of characters, and at most one of:
+    /// `"block": true`, `"redirect": "<url>"` or `"file": "<path>"`.
+    /// `file` responds with the content of the file, optionally with
+    /// `status`, `mime_type` and `response_headers`; relative paths
+    /// are resolved against the directory containing the rules.
+    /// `headers` adds request headers.
+    /// The first rule that blocks, redirects or responds to a request
+    /// is used; headers are added by every matching rule.
+    rules: Option<PathBuf>,
+}
+
+/// The representation of a RouteRule in a rule file
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct RuleSpec {
+    url: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    block: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    redirect: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    file: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    status: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    mime_type: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    response_headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    headers: BTreeMap<String, String>,
+}
+
+impl RuleSpec {
+    fn into_rule(self, base_dir: &Path) -> anyhow::Result<RouteRule> {
+        if self.file.is_none()
+            && (self.status.is_some()
+                || self.mime_type.is_some()
+                || !self.response_headers.is_empty())
+        {
+            return Err(anyhow!(
+                "rule for {}: status, mime_type and response_headers require file",
+                self.url
+            ));
+        }
+
+        let action = match (self.block, self.redirect, self.file) {
+            (false, None, None) => RouteAction::Continue,
+            (true, None, None) => RouteAction::Block,
+            (false, Some(url), None) => RouteAction::Redirect(url),
+            (false, None, Some(file)) => {
+                let file = base_dir.join(file);
+                let file = file
+                    .canonicalize()
+                    .with_context(|| format!("rule for {}: {}", self.url, file.display()))?;
+                RouteAction::Fulfill(RouteResponse {
+                    status: self.status.unwrap_or(200),
+                    mime_type: self.mime_type.unwrap_or_default(),
+                    headers: self.response_headers.into_iter().collect(),
+                    file,
+                })
+            }
+            _ => {
+                return Err(anyhow!(
+                    "rule for {}: only one of block, redirect and file may be used",
+                    self.url
+                ))
+            }
+        };
+        Ok(RouteRule {
+            pattern: self.url,
+            action,
+            add_headers: self.headers.into_iter().collect(),
+        })
+    }
+
+    fn from_rule(rule: RouteRule) -> Self {
+        let mut spec = Self {
+            url: rule.pattern,
+            headers: rule.add_headers.into_iter().collect(),
+            ..Default::default()
+        };
+        match rule.action {
+            RouteAction::Continue => {}
+            RouteAction::Block => spec.block = true,
+            RouteAction::Redirect(url) => spec.redirect = Some(url),
+            RouteAction::Fulfill(response) => {
+                spec.file = Some(response.file);
+                spec.status = Some(response.status);
+                if !response.mime_type.is_empty() {
+                    spec.mime_type = Some(response.mime_type);
+                }
+                spec.response_headers = response.headers.into_iter().collect();
+            }
+        }
+        spec
+    }
+}
+
+fn load_rules(path: &Path) -> anyhow::Result<Vec<RouteRule>> {
+    let data = std::fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+    let specs: Vec<RuleSpec> =
+        serde_json::from_slice(&data).with_context(|| format!("parsing {}", path.display()))?;
+    let base_dir = path
+        .canonicalize()
+        .with_context(|| format!("resolving {}", path.display()))?;
+    let base_dir = base_dir.parent().unwrap_or(Path::new("/"));
+    specs
+        .into_iter()
+        .map(|spec| spec.into_rule(base_dir))
+        .collect()
+}
+
+impl WebRoute {
+    pub async fn run(&self, client: Client) -> anyhow::Result<()> {
+        let scope = match &self.profile {
+            Some(profile) => RouteScope::Profile(profile.clone()),
+            None => RouteScope::Pane(client.resolve_pane_id(self.pane_id).await?),
+        };
+
+        let rules = if self.clear {
+            Some(vec![])
+        } else {
+            self.rules.as_deref().map(load_rules).transpose()?
+        };
+
+        let response = client.web_routes(codec::WebRoutes { scope, rules }).await?;
+
+        let specs: Vec<RuleSpec> = response
+            .rules
+            .into_iter()
+            .map(RuleSpec::from_rule)
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&specs)?);
+        Ok(())
+    }
+}
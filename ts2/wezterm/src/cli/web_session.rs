@@ -0,0 +1,57 @@
+use clap::{Parser, ValueHint};
+use mux::web_session::{default_session_path, Session};
+use std::path::PathBuf;
+use wezterm_client::client::Client;
+
+#[derive(Debug, Parser, Clone)]
+pub struct WebSession {
+    #[command(subcommand)]
+    sub: WebSessionCommand,
+}
+
+#[derive(Debug, Parser, Clone)]
+enum WebSessionCommand {
+    /// Save the windows, tabs, splits and browser panes to a file
+    #[command(name = "save")]
+    Save {
+        /// The session file to write.
+        /// The default is session.json in the wezterm data directory.
+        #[arg(value_parser, value_hint=ValueHint::FilePath)]
+        file: Option<PathBuf>,
+    },
+
+    /// Recreate the windows saved in a session file.
+    /// Browsers in background tabs load their page when the tab
+    /// is first shown.
+    #[command(name = "restore")]
+    Restore {
+        /// The session file to read.
+        /// The default is session.json in the wezterm data directory.
+        #[arg(value_parser, value_hint=ValueHint::FilePath)]
+        file: Option<PathBuf>,
+    },
+}
+
+impl WebSession {
+    pub async fn run(&self, client: Client) -> anyhow::Result<()> {
+        match &self.sub {
+            WebSessionCommand::Save { file } => {
+                let path = file.clone().unwrap_or_else(default_session_path);
+                let response = client.web_session_save(codec::WebSessionSave {}).await?;
+                response.session.save(&path)?;
+                println!("{}", path.display());
+            }
+            WebSessionCommand::Restore { file } => {
+                let path = file.clone().unwrap_or_else(default_session_path);
+                let session = Session::load(&path)?;
+                let response = client
+                    .web_session_restore(codec::WebSessionRestore { session })
+                    .await?;
+                for window_id in response.window_ids {
+                    println!("{}", window_id);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -50,6 +50,53 @@ impl Default for Clipboard {
     }
 }
 
+/// The mime type used for text in the clipboard
+pub const TEXT_MIME_TYPE: &str = "text/plain;charset=utf-8";
+
+/// Returns true if the mime type, or X11 target name, refers to text.
+/// This accepts the same mime types as `wezterm_term::is_text_mime_type`,
+/// including `text/plain` with any parameters.
+pub fn is_text_mime_type(mime_type: &str) -> bool {
+    matches!(mime_type, "text/plain" | "UTF8_STRING" | "STRING" | "TEXT")
+        || mime_type.starts_with("text/plain;")
+}
+
+/// Clipboard contents in a particular format.
+/// This mirrors `wezterm_term::ClipboardData`; the window crate does
+/// not depend on the terminal model, so the gui converts between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardData {
+    pub mime_type: String,
+    pub data: Vec<u8>,
+}
+
+impl ClipboardData {
+    pub fn text(text: String) -> Self {
+        Self {
+            mime_type: TEXT_MIME_TYPE.to_string(),
+            data: text.into_bytes(),
+        }
+    }
+
+    pub fn is_text(&self) -> bool {
+        is_text_mime_type(&self.mime_type)
+    }
+
+    /// Returns the entry that can satisfy a request for `mime_type`.
+    /// Any textual entry satisfies a request for any textual type.
+    pub fn find<'a>(data: &'a [ClipboardData], mime_type: &str) -> Option<&'a ClipboardData> {
+        data.iter()
+            .find(|item| item.mime_type == mime_type)
+            .or_else(|| {
+                if is_text_mime_type(mime_type) {
+                    data.iter().find(|item| item.is_text())
+                } else {
+                    None
+                }
+            })
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Dimensions {
     pub pixel_width: usize,
@@ -319,6 +366,32 @@ pub trait WindowOps {
     /// Set some text in the clipboard
     fn set_clipboard(&self, clipboard: Clipboard, text: String);
 
+    /// Initiate transfer of data of a particular mime type from
+    /// the clipboard.
+    /// This is only implemented on backends that support data
+    /// other than text (X11 and Wayland).
+    fn get_clipboard_data(&self, _clipboard: Clipboard, mime_type: String) -> Future<Vec<u8>> {
+        Future::err(anyhow::anyhow!(
+            "{} cannot be read from the clipboard on this system",
+            mime_type
+        ))
+    }
+
+    /// Initiate a query for the mime types of the data that is
+    /// available in the clipboard.
+    /// Backends that only support text report only text.
+    fn get_clipboard_mime_types(&self, _clipboard: Clipboard) -> Future<Vec<String>> {
+        Future::ok(vec![TEXT_MIME_TYPE.to_string()])
+    }
+
+    /// Set data in one or more formats in the clipboard.
+    /// Backends that only support text set only the textual entry.
+    fn set_clipboard_data(&self, clipboard: Clipboard, data: Vec<ClipboardData>) {
+        if let Some(text) = data.into_iter().find(|item| item.is_text()) {
+            self.set_clipboard(clipboard, String::from_utf8_lossy(&text.data).into_owned());
+        }
+    }
+
     /// Set window level. Depending on the environment and user preferences
     fn set_window_level(&self, _level: WindowLevel) {}
 
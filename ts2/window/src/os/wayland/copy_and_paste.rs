@@ -10,9 +10,8 @@ use toolkit::primary_selection::selection::PrimarySelectionSourceHandler;
 use wayland_protocols::wp::primary_selection::zv1::client::zwp_primary_selection_device_v1::ZwpPrimarySelectionDeviceV1;
 use wayland_protocols::wp::primary_selection::zv1::client::zwp_primary_selection_source_v1::ZwpPrimarySelectionSourceV1;
 
-use crate::{Clipboard, ConnectionOps};
+use crate::{Clipboard, ClipboardData, ConnectionOps, TEXT_MIME_TYPE};
 
-use super::data_device::TEXT_MIME_TYPE;
 use super::state::WaylandState;
 
 #[derive(Default)]
@@ -33,7 +32,11 @@ impl CopyAndPaste {
         Arc::new(Mutex::new(Default::default()))
     }
 
-    pub(super) fn get_clipboard_data(&mut self, clipboard: Clipboard) -> anyhow::Result<ReadPipe> {
+    /// Returns the mime types that are offered by the clipboard
+    pub(super) fn get_clipboard_mime_types(
+        &self,
+        clipboard: Clipboard,
+    ) -> anyhow::Result<Vec<String>> {
         let conn = crate::Connection::get().unwrap().wayland();
         let wayland_state = conn.wayland_state.borrow();
         let primary_selection = if let Clipboard::PrimarySelection = clipboard {
@@ -48,7 +51,42 @@ impl CopyAndPaste {
                     .data()
                     .selection_offer()
                     .ok_or_else(|| anyhow!("no primary selection offer"))?;
-                let pipe = offer.receive(TEXT_MIME_TYPE.to_string())?;
+                Ok(offer.with_mime_types(|mime_types| mime_types.to_vec()))
+            }
+            None => {
+                let offer = self
+                    .data_offer
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("no data offer"))?;
+                Ok(offer.with_mime_types(|mime_types| mime_types.to_vec()))
+            }
+        }
+    }
+
+    pub(super) fn get_clipboard_data(
+        &mut self,
+        clipboard: Clipboard,
+        mime_type: &str,
+    ) -> anyhow::Result<ReadPipe> {
+        let conn = crate::Connection::get().unwrap().wayland();
+        let wayland_state = conn.wayland_state.borrow();
+        let primary_selection = if let Clipboard::PrimarySelection = clipboard {
+            wayland_state.primary_selection_device.as_ref()
+        } else {
+            None
+        };
+        let offers_mime_type = |mime_types: &[String]| mime_types.iter().any(|s| s == mime_type);
+
+        match primary_selection {
+            Some(primary_selection) => {
+                let offer = primary_selection
+                    .data()
+                    .selection_offer()
+                    .ok_or_else(|| anyhow!("no primary selection offer"))?;
+                if !offer.with_mime_types(offers_mime_type) {
+                    bail!("{} is not available from the primary selection", mime_type);
+                }
+                let pipe = offer.receive(mime_type.to_string())?;
                 Ok(pipe)
             }
             None => {
@@ -56,18 +94,34 @@ impl CopyAndPaste {
                     .data_offer
                     .as_ref()
                     .ok_or_else(|| anyhow!("no data offer"))?;
-                let pipe = offer.receive(TEXT_MIME_TYPE.to_string())?;
+                if !offer.with_mime_types(offers_mime_type) {
+                    bail!("{} is not available from the clipboard", mime_type);
+                }
+                let pipe = offer.receive(mime_type.to_string())?;
                 Ok(pipe)
             }
         }
     }
 
-    pub(super) fn set_clipboard_data(&mut self, clipboard: Clipboard, data: String) {
+    pub(super) fn set_clipboard_data(&mut self, clipboard: Clipboard, data: Vec<ClipboardData>) {
         let conn = crate::Connection::get().unwrap().wayland();
         let qh = conn.event_queue.borrow().handle();
         let mut wayland_state = conn.wayland_state.borrow_mut();
         let last_serial = *wayland_state.last_serial.borrow();
 
+        // Text is always offered using our canonical text mime type
+        let mut mime_types: Vec<String> = vec![];
+        for item in &data {
+            let mime_type = if item.is_text() {
+                TEXT_MIME_TYPE
+            } else {
+                item.mime_type.as_str()
+            };
+            if !mime_types.iter().any(|m| m == mime_type) {
+                mime_types.push(mime_type.to_string());
+            }
+        }
+
         let primary_selection = if let Clipboard::PrimarySelection = clipboard {
             wayland_state.primary_selection_device.as_ref()
         } else {
@@ -77,7 +131,7 @@ impl CopyAndPaste {
         match primary_selection {
             Some(primary_selection) => {
                 let manager = wayland_state.primary_selection_manager.as_ref().unwrap();
-                let source = manager.create_selection_source(&qh, [TEXT_MIME_TYPE]);
+                let source = manager.create_selection_source(&qh, mime_types);
                 source.set_selection(&primary_selection, last_serial);
                 wayland_state
                     .primary_selection_source
@@ -87,7 +141,7 @@ impl CopyAndPaste {
                 let data_device = &wayland_state.data_device;
                 let source = wayland_state
                     .data_device_manager_state
-                    .create_copy_paste_source(&qh, vec![TEXT_MIME_TYPE]);
+                    .create_copy_paste_source(&qh, mime_types);
                 source.set_selection(data_device.as_ref().unwrap(), last_serial);
                 wayland_state.copy_paste_source.replace((source, data));
             }
@@ -108,8 +162,8 @@ impl WaylandState {
     }
 }
 
-pub(super) fn write_selection_to_pipe(fd: WritePipe, text: &str) {
-    if let Err(e) = write_pipe_with_timeout(fd, text.as_bytes()) {
+pub(super) fn write_selection_to_pipe(fd: WritePipe, data: &[u8]) {
+    if let Err(e) = write_pipe_with_timeout(fd, data) {
         log::error!("while sending primary selection to pipe: {}", e);
     }
 }
@@ -171,15 +225,13 @@ impl PrimarySelectionSourceHandler for WaylandState {
         mime: String,
         write_pipe: toolkit::data_device_manager::WritePipe,
     ) {
-        if mime != TEXT_MIME_TYPE {
-            return;
-        };
-
         if let Some((ps_source, data)) = &self.primary_selection_source {
             if ps_source.inner() != source {
                 return;
             }
-            write_selection_to_pipe(write_pipe, data);
+            if let Some(item) = ClipboardData::find(data, &mime) {
+                write_selection_to_pipe(write_pipe, &item.data);
+            }
         }
     }
 
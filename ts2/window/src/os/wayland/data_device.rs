@@ -10,12 +10,12 @@ use wayland_client::Proxy;
 use crate::wayland::drag_and_drop::SurfaceAndOffer;
 use crate::wayland::pointer::PointerUserData;
 use crate::wayland::SurfaceUserData;
+use crate::ClipboardData;
 
 use super::copy_and_paste::write_selection_to_pipe;
 use super::drag_and_drop::{DragAndDrop, SurfaceAndPipe};
 use super::state::WaylandState;
 
-pub(super) const TEXT_MIME_TYPE: &str = "text/plain;charset=utf-8";
 pub(super) const URI_MIME_TYPE: &str = "text/uri-list";
 
 impl DataDeviceHandler for WaylandState {
@@ -108,10 +108,6 @@ impl DataDeviceHandler for WaylandState {
             }
         };
         if let Some(offer) = offer {
-            if !offer.with_mime_types(|mime_types| mime_types.iter().any(|s| s == TEXT_MIME_TYPE)) {
-                return;
-            }
-
             if let Some(copy_and_paste) = self.resolve_copy_and_paste() {
                 copy_and_paste.lock().unwrap().confirm_selection(offer);
             }
@@ -184,15 +180,13 @@ impl DataSourceHandler for WaylandState {
         mime: String,
         fd: WritePipe,
     ) {
-        if mime != TEXT_MIME_TYPE {
-            return;
-        }
-
         if let Some((cp_source, data)) = &self.copy_paste_source {
             if cp_source.inner() != source {
                 return;
             }
-            write_selection_to_pipe(fd, data);
+            if let Some(item) = ClipboardData::find(data, &mime) {
+                write_selection_to_pipe(fd, &item.data);
+            }
         }
     }
 
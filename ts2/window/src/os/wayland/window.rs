@@ -53,9 +53,9 @@ use wezterm_input_types::{
 use crate::wayland::WaylandConnection;
 use crate::x11::KeyboardWithFallback;
 use crate::{
-    Appearance, Clipboard, Connection, ConnectionOps, Dimensions, MouseCursor, Point, Rect,
-    RequestedWindowGeometry, ResizeIncrement, ResolvedGeometry, Window, WindowEvent,
-    WindowEventSender, WindowKeyEvent, WindowOps, WindowState,
+    Appearance, Clipboard, ClipboardData, Connection, ConnectionOps, Dimensions, MouseCursor,
+    Point, Rect, RequestedWindowGeometry, ResizeIncrement, ResolvedGeometry, Window, WindowEvent,
+    WindowEventSender, WindowKeyEvent, WindowOps, WindowState, TEXT_MIME_TYPE,
 };
 
 /// Wayland-specific coordinate conversion methods for Dimensions
@@ -451,7 +451,7 @@ impl WindowOps for WaylandWindow {
                 .copy_and_paste
                 .lock()
                 .unwrap()
-                .get_clipboard_data(clipboard)?;
+                .get_clipboard_data(clipboard, TEXT_MIME_TYPE)?;
             let promise = Arc::clone(&promise);
             std::thread::spawn(move || {
                 let mut promise = promise.lock().unwrap();
@@ -474,12 +474,60 @@ impl WindowOps for WaylandWindow {
     }
 
     fn set_clipboard(&self, clipboard: Clipboard, text: String) {
+        self.set_clipboard_data(clipboard, vec![ClipboardData::text(text)]);
+    }
+
+    fn get_clipboard_data(&self, clipboard: Clipboard, mime_type: String) -> Future<Vec<u8>> {
+        let mut promise = Promise::new();
+        let future = promise.get_future().unwrap();
+        let promise = Arc::new(Mutex::new(promise));
+        WaylandConnection::with_window_inner(self.0, move |inner| {
+            let read = inner
+                .copy_and_paste
+                .lock()
+                .unwrap()
+                .get_clipboard_data(clipboard, &mime_type)?;
+            let promise = Arc::clone(&promise);
+            std::thread::spawn(move || {
+                let mut promise = promise.lock().unwrap();
+                match read_pipe_bytes_with_timeout(read) {
+                    Ok(result) => {
+                        promise.ok(result);
+                    }
+                    Err(e) => {
+                        log::error!("while reading clipboard: {}", e);
+                        promise.err(anyhow!("{}", e));
+                    }
+                };
+            });
+            Ok(())
+        });
+        future
+    }
+
+    fn get_clipboard_mime_types(&self, clipboard: Clipboard) -> Future<Vec<String>> {
+        let mut promise = Promise::new();
+        let future = promise.get_future().unwrap();
+        let promise = Arc::new(Mutex::new(promise));
+        WaylandConnection::with_window_inner(self.0, move |inner| {
+            let mime_types = inner
+                .copy_and_paste
+                .lock()
+                .unwrap()
+                .get_clipboard_mime_types(clipboard);
+            promise.lock().unwrap().result(mime_types);
+            Ok(())
+        });
+        future
+    }
+
+    fn set_clipboard_data(&self, clipboard: Clipboard, data: Vec<ClipboardData>) {
         WaylandConnection::with_window_inner(self.0, move |inner| {
             inner
                 .copy_and_paste
                 .lock()
                 .unwrap()
-                .set_clipboard_data(clipboard, text);
+                .set_clipboard_data(clipboard, data);
             Ok(())
         });
     }
@@ -524,7 +572,11 @@ pub(crate) struct PendingEvent {
     pub(crate) window_state: Option<WindowState>,
 }
 
-pub(crate) fn read_pipe_with_timeout(mut file: ReadPipe) -> anyhow::Result<String> {
+pub(crate) fn read_pipe_with_timeout(file: ReadPipe) -> anyhow::Result<String> {
+    Ok(String::from_utf8(read_pipe_bytes_with_timeout(file)?)?)
+}
+
+pub(crate) fn read_pipe_bytes_with_timeout(mut file: ReadPipe) -> anyhow::Result<Vec<u8>> {
     let mut result = Vec::new();
 
     // set non-blocking I/O on the pipe
@@ -560,7 +612,7 @@ pub(crate) fn read_pipe_with_timeout(mut file: ReadPipe) -> anyhow::Result<Strin
         }
     }
 
-    Ok(String::from_utf8(result)?)
+    Ok(result)
 }
 
 pub struct WaylandWindowInner {
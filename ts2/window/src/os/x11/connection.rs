@@ -975,6 +975,11 @@ impl XConnection {
         name
     }
 
+    /// Returns the atom with the given name, creating it if needed
+    pub fn atom(&self, name: &str) -> anyhow::Result<Atom> {
+        Self::intern_atom(&self.conn, name)
+    }
+
     pub fn conn(&self) -> &xcb::Connection {
         &self.conn
     }
@@ -3,8 +3,8 @@ use crate::bitmaps::*;
 use crate::connection::ConnectionOps;
 use crate::os::{xkeysyms, Connection, Window};
 use crate::{
-    Appearance, Clipboard, DeadKeyStatus, Dimensions, MouseButtons, MouseCursor, MouseEvent,
-    MouseEventKind, MousePress, Point, Rect, RequestedWindowGeometry, ResizeIncrement,
+    Appearance, Clipboard, ClipboardData, DeadKeyStatus, Dimensions, MouseButtons, MouseCursor,
+    MouseEvent, MouseEventKind, MousePress, Point, Rect, RequestedWindowGeometry, ResizeIncrement,
     ResolvedGeometry, ScreenPoint, ScreenRect, WindowDecorations, WindowEvent, WindowEventSender,
     WindowOps, WindowState,
 };
@@ -31,22 +31,37 @@ use xcb::{Event, Xid};
 
 #[derive(Default)]
 struct CopyAndPaste {
-    clipboard_owned: Option<String>,
-    primary_selection_owned: Option<String>,
+    clipboard_owned: Option<Vec<ClipboardData>>,
+    primary_selection_owned: Option<Vec<ClipboardData>>,
     clipboard_request: Option<Promise<String>>,
     selection_request: Option<Promise<String>>,
+    clipboard_data_request: Option<DataRequest>,
+    selection_data_request: Option<DataRequest>,
     time: u32,
 }
 
+/// A request for something other than text from the selection owner
+struct DataRequest {
+    target: Atom,
+    promise: DataPromise,
+}
+
+enum DataPromise {
+    /// The data of the target
+    Data(Promise<Vec<u8>>),
+    /// The names of the targets that the owner offers
+    Targets(Promise<Vec<String>>),
+}
+
 impl CopyAndPaste {
-    fn clipboard(&self, clipboard: Clipboard) -> &Option<String> {
+    fn clipboard(&self, clipboard: Clipboard) -> &Option<Vec<ClipboardData>> {
         match clipboard {
             Clipboard::PrimarySelection => &self.primary_selection_owned,
             Clipboard::Clipboard => &self.clipboard_owned,
         }
     }
 
-    fn clipboard_mut(&mut self, clipboard: Clipboard) -> &mut Option<String> {
+    fn clipboard_mut(&mut self, clipboard: Clipboard) -> &mut Option<Vec<ClipboardData>> {
         match clipboard {
             Clipboard::PrimarySelection => &mut self.primary_selection_owned,
             Clipboard::Clipboard => &mut self.clipboard_owned,
@@ -59,6 +74,13 @@ impl CopyAndPaste {
             Clipboard::Clipboard => &mut self.clipboard_request,
         }
     }
+
+    fn data_request_mut(&mut self, clipboard: Clipboard) -> &mut Option<DataRequest> {
+        match clipboard {
+            Clipboard::PrimarySelection => &mut self.selection_data_request,
+            Clipboard::Clipboard => &mut self.clipboard_data_request,
+        }
+    }
 }
 
 struct DragAndDrop {
@@ -965,6 +987,7 @@ impl XWindowInner {
         if let Some(clipboard) = self.selection_atom_to_clipboard(request.selection()) {
             self.copy_and_paste.clipboard_mut(clipboard).take();
             self.copy_and_paste.request_mut(clipboard).take();
+            self.copy_and_paste.data_request_mut(clipboard).take();
             self.update_selection_owner(clipboard)?;
         }
 
@@ -985,9 +1008,22 @@ impl XWindowInner {
             conn.atom_clipboard,
         );
 
+        let owned = self
+            .selection_atom_to_clipboard(request.selection())
+            .and_then(|clipboard| self.copy_and_paste.clipboard(clipboard).as_ref());
+
         let selprop = if request.target() == conn.atom_targets {
             // They want to know which targets we support
-            let atoms: [Atom; 1] = [conn.atom_utf8_string];
+            let mut atoms: Vec<Atom> = vec![conn.atom_utf8_string];
+            for item in owned.into_iter().flatten() {
+                if item.is_text() {
+                    continue;
+                }
+                match conn.atom(&item.mime_type) {
+                    Ok(atom) => atoms.push(atom),
+                    Err(err) => log::error!("interning {}: {:#}", item.mime_type, err),
+                }
+            }
             log::trace!("SEL: window_id={window_id:?} requestor wants supported targets");
             conn.send_request_no_reply(&xcb::x::ChangeProperty {
                 mode: PropMode::Replace,
@@ -999,36 +1035,41 @@ impl XWindowInner {
 
             // let the requestor know that we set their property
             request.property()
-        } else if request.target() == conn.atom_utf8_string
-            || request.target() == xcb::x::ATOM_STRING
-        {
-            log::trace!("SEL: window_id={window_id:?} requestor wants string data");
-            if let Some(clipboard) = self.selection_atom_to_clipboard(request.selection()) {
-                // We'll accept requests for UTF-8 or STRING data.
-                // We don't and won't do any conversion from UTF-8 to
-                // whatever STRING represents; let's just assume that
-                // the other end is going to handle it correctly.
-                if let Some(text) = self.copy_and_paste.clipboard(clipboard) {
+        } else {
+            // We'll accept requests for UTF-8 or STRING data, which are
+            // satisfied by the text, as well as for any mime type that we
+            // hold data for.
+            // We don't and won't do any conversion from UTF-8 to
+            // whatever STRING represents; let's just assume that
+            // the other end is going to handle it correctly.
+            let target_name = conn.atom_name(request.target());
+            log::trace!("SEL: window_id={window_id:?} requestor wants {target_name}");
+            // Transferring more data than fits in a single request
+            // requires the INCR protocol, which we don't implement
+            let max_len = (conn.get_maximum_request_length() as usize * 4).saturating_sub(64);
+            match owned.and_then(|owned| ClipboardData::find(owned, &target_name)) {
+                Some(item) if item.data.len() <= max_len => {
                     conn.send_request_no_reply(&xcb::x::ChangeProperty {
                         mode: PropMode::Replace,
                         window: request.requestor(),
                         property: request.property(),
                         r#type: request.target(),
-                        data: text.as_bytes(),
+                        data: &item.data,
                     })?;
                     // let the requestor know that we set their property
                     request.property()
-                } else {
-                    // We have no clipboard so there is nothing to report
+                }
+                Some(item) => {
+                    log::warn!(
+                        "SEL: {} bytes of {} is too large to transfer",
+                        item.data.len(),
+                        item.mime_type
+                    );
                     xcb::x::ATOM_NONE
                 }
-            } else {
-                xcb::x::ATOM_NONE
+                // We have nothing to report for their request
+                None => xcb::x::ATOM_NONE,
             }
-        } else {
-            // We didn't support their request, so there is nothing
-            // we can report back to them.
-            xcb::x::ATOM_NONE
         };
         log::trace!(
             "SEL: window_id={window_id:?} responding with selprop={:?}",
@@ -1051,6 +1092,58 @@ impl XWindowInner {
         Ok(())
     }
 
+    /// Completes a request made by get_clipboard_data or
+    /// get_clipboard_mime_types
+    fn selection_data_notify(
+        &mut self,
+        clipboard: Clipboard,
+        selection: &xcb::x::SelectionNotifyEvent,
+    ) -> anyhow::Result<()> {
+        let conn = self.conn();
+        let request = match self.copy_and_paste.data_request_mut(clipboard).take() {
+            Some(request) => request,
+            None => return Ok(()),
+        };
+
+        let prop = if selection.property() == xcb::x::ATOM_NONE {
+            Err(anyhow!(
+                "{} is not available from the clipboard",
+                conn.atom_name(selection.target())
+            ))
+        } else {
+            let prop = conn
+                .send_and_wait_request(&xcb::x::GetProperty {
+                    delete: false,
+                    window: selection.requestor(),
+                    property: selection.property(),
+                    r#type: xcb::x::ATOM_ANY,
+                    long_offset: 0,
+                    long_length: u32::max_value(),
+                })
+                .context("getting clipboard property");
+            conn.send_request_no_reply(&xcb::x::DeleteProperty {
+                window: self.window_id,
+                property: conn.atom_xsel_data,
+            })?;
+            prop
+        };
+
+        match request.promise {
+            DataPromise::Data(mut promise) => {
+                promise.result(prop.map(|prop| prop.value::<u8>().to_vec()));
+            }
+            DataPromise::Targets(mut promise) => {
+                promise.result(prop.map(|prop| {
+                    prop.value::<Atom>()
+                        .iter()
+                        .map(|atom| conn.atom_name(*atom))
+                        .collect()
+                }));
+            }
+        }
+        Ok(())
+    }
+
     fn selection_notify(&mut self, selection: &xcb::x::SelectionNotifyEvent) -> anyhow::Result<()> {
         let conn = self.conn();
         let window_id = self.window_id;
@@ -1063,6 +1156,13 @@ impl XWindowInner {
         );
 
         if let Some(clipboard) = self.selection_atom_to_clipboard(selection.selection()) {
+            if matches!(
+                self.copy_and_paste.data_request_mut(clipboard),
+                Some(request) if request.target == selection.target()
+            ) {
+                return self.selection_data_notify(clipboard, selection);
+            }
+
             if selection.property() == xcb::x::ATOM_NONE {
                 if selection.target() == conn.atom_utf8_string {
                     log::trace!(
@@ -1348,6 +1448,43 @@ impl Ord for XWindow {
 }
 
 impl XWindow {
+    /// Ask the selection owner for `mime_type`, or for the list of
+    /// targets that it offers when `mime_type` is None
+    fn request_clipboard_target(
+        &self,
+        clipboard: Clipboard,
+        mime_type: Option<String>,
+        promise: DataPromise,
+    ) {
+        let window_id = self.0;
+        let mut promise = Some(promise);
+        XConnection::with_window_inner(window_id, move |inner| {
+            let conn = inner.conn();
+            let target = match &mime_type {
+                Some(mime_type) => conn.atom(mime_type)?,
+                None => conn.atom_targets,
+            };
+            inner
+                .copy_and_paste
+                .data_request_mut(clipboard)
+                .replace(DataRequest {
+                    target,
+                    promise: promise.take().unwrap(),
+                });
+            conn.send_request_no_reply_log(&xcb::x::ConvertSelection {
+                requestor: inner.window_id,
+                selection: match clipboard {
+                    Clipboard::Clipboard => conn.atom_clipboard,
+                    Clipboard::PrimarySelection => xcb::x::ATOM_PRIMARY,
+                },
+                target,
+                property: conn.atom_xsel_data,
+                time: inner.copy_and_paste.time,
+            });
+            Ok(())
+        });
+    }
+
     pub(crate) fn from_id(id: xcb::x::Window) -> Self {
         Self(id)
     }
@@ -2154,16 +2291,35 @@ impl WindowOps for XWindow {
 
     /// Set some text in the clipboard
     fn set_clipboard(&self, clipboard: Clipboard, text: String) {
+        self.set_clipboard_data(clipboard, vec![ClipboardData::text(text)]);
+    }
+
+    fn get_clipboard_data(&self, clipboard: Clipboard, mime_type: String) -> Future<Vec<u8>> {
+        let mut promise = Promise::new();
+        let future = promise.get_future().unwrap();
+        self.request_clipboard_target(clipboard, Some(mime_type), DataPromise::Data(promise));
+        future
+    }
+
+    fn get_clipboard_mime_types(&self, clipboard: Clipboard) -> Future<Vec<String>> {
+        let mut promise = Promise::new();
+        let future = promise.get_future().unwrap();
+        self.request_clipboard_target(clipboard, None, DataPromise::Targets(promise));
+        future
+    }
+
+    fn set_clipboard_data(&self, clipboard: Clipboard, data: Vec<ClipboardData>) {
         let window_id = self.0;
         XConnection::with_window_inner(window_id, move |inner| {
             log::trace!(
                 "SEL: window_id={window_id:?} now owns selection \
-                for {clipboard:?} {text:?}"
+                for {clipboard:?} {:?}",
+                data.iter().map(|item| &item.mime_type).collect::<Vec<_>>()
             );
             inner
                 .copy_and_paste
                 .clipboard_mut(clipboard)
-                .replace(text.clone());
+                .replace(data.clone());
             inner.update_selection_owner(clipboard)?;
             Ok(())
         });
@@ -9,8 +9,8 @@ use crate::os::x11::connection::XConnection;
 use crate::os::x11::window::XWindow;
 use crate::screen::Screens;
 use crate::{
-    Appearance, Clipboard, MouseCursor, Rect, RequestedWindowGeometry, ResizeIncrement,
-    ScreenPoint, WindowEvent, WindowOps,
+    Appearance, Clipboard, ClipboardData, MouseCursor, Rect, RequestedWindowGeometry,
+    ResizeIncrement, ScreenPoint, WindowEvent, WindowOps,
 };
 use async_trait::async_trait;
 use config::ConfigHandle;
@@ -408,4 +408,28 @@ impl WindowOps for Window {
             Self::Wayland(w) => w.set_clipboard(clipboard, text),
         }
     }
+
+    fn get_clipboard_data(&self, clipboard: Clipboard, mime_type: String) -> Future<Vec<u8>> {
+        match self {
+            Self::X11(x) => x.get_clipboard_data(clipboard, mime_type),
+            #[cfg(feature = "wayland")]
+            Self::Wayland(w) => w.get_clipboard_data(clipboard, mime_type),
+        }
+    }
+
+    fn get_clipboard_mime_types(&self, clipboard: Clipboard) -> Future<Vec<String>> {
+        match self {
+            Self::X11(x) => x.get_clipboard_mime_types(clipboard),
+            #[cfg(feature = "wayland")]
+            Self::Wayland(w) => w.get_clipboard_mime_types(clipboard),
+        }
+    }
+
+    fn set_clipboard_data(&self, clipboard: Clipboard, data: Vec<ClipboardData>) {
+        match self {
+            Self::X11(x) => x.set_clipboard_data(clipboard, data),
+            #[cfg(feature = "wayland")]
+            Self::Wayland(w) => w.set_clipboard_data(clipboard, data),
+        }
+    }
 }